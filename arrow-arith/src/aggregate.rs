@@ -19,13 +19,18 @@
 
 use arrow_array::cast::*;
 use arrow_array::iterator::ArrayIter;
+use arrow_array::types::{
+    Decimal32Type, Decimal64Type, Decimal128Type, Decimal256Type, DecimalType,
+    validate_decimal_precision_and_scale,
+};
 use arrow_array::*;
-use arrow_buffer::NullBuffer;
+use arrow_buffer::{NullBuffer, i256};
 use arrow_data::bit_iterator::try_for_each_valid_idx;
 use arrow_schema::*;
 use std::borrow::BorrowMut;
 use std::cmp::{self, Ordering};
 use std::ops::{BitAnd, BitOr, BitXor};
+use std::sync::Arc;
 use types::ByteViewType;
 
 /// An accumulator for primitive numeric values.
@@ -1028,6 +1033,246 @@ pub fn max<T: ArrowNumericType>(array: &PrimitiveArray<T>) -> Option<T::Native>
     aggregate::<T::Native, T, MaxAccumulator<T::Native>>(array)
 }
 
+/// The sum of the non-null values of a decimal array, along with its type
+struct DecimalSum {
+    sum: i256,
+    count: usize,
+    /// The index of the input type in [`DECIMAL_CANDIDATES`]
+    rank: usize,
+    precision: u8,
+    scale: i8,
+}
+
+fn decimal_sum(array: &dyn Array) -> Result<DecimalSum, ArrowError> {
+    fn sum_i256<T: DecimalType>(
+        array: &PrimitiveArray<T>,
+        to_i256: impl Fn(T::Native) -> i256,
+    ) -> Result<(i256, usize), ArrowError> {
+        let count = array.len() - array.null_count();
+        let sum = array.iter().flatten().try_fold(i256::ZERO, |acc, v| {
+            acc.checked_add(to_i256(v)).ok_or_else(|| {
+                ArrowError::ArithmeticOverflow(format!("Overflow summing {} values", T::PREFIX))
+            })
+        })?;
+        Ok((sum, count))
+    }
+
+    let ((sum, count), rank, precision, scale) = match array.data_type() {
+        DataType::Decimal32(p, s) => (
+            sum_i256(array.as_primitive::<Decimal32Type>(), |v| {
+                i256::from_i128(v as i128)
+            })?,
+            0,
+            *p,
+            *s,
+        ),
+        DataType::Decimal64(p, s) => (
+            sum_i256(array.as_primitive::<Decimal64Type>(), |v| {
+                i256::from_i128(v as i128)
+            })?,
+            1,
+            *p,
+            *s,
+        ),
+        DataType::Decimal128(p, s) => (
+            sum_i256(array.as_primitive::<Decimal128Type>(), i256::from_i128)?,
+            2,
+            *p,
+            *s,
+        ),
+        DataType::Decimal256(p, s) => (
+            sum_i256(array.as_primitive::<Decimal256Type>(), |v| v)?,
+            3,
+            *p,
+            *s,
+        ),
+        dt => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Expected a decimal array, got {dt}"
+            )));
+        }
+    };
+    Ok(DecimalSum {
+        sum,
+        count,
+        rank,
+        precision,
+        scale,
+    })
+}
+
+/// Returns a single value array of decimal type `T` containing `value` with `scale`
+/// and `precision` capped to `T::MAX_PRECISION`, or `None` if `value` or `scale`
+/// cannot be represented by this type
+fn try_decimal_scalar<T: DecimalType>(
+    value: Option<i256>,
+    precision: u8,
+    scale: i8,
+    convert: impl Fn(i256) -> Option<T::Native>,
+) -> Option<ArrayRef> {
+    let precision = precision.min(T::MAX_PRECISION);
+    if validate_decimal_precision_and_scale::<T>(precision, scale).is_err() {
+        return None;
+    }
+    let value = match value {
+        Some(v) => Some(convert(v).filter(|v| T::is_valid_decimal_precision(*v, precision))?),
+        None => None,
+    };
+    let array: PrimitiveArray<T> = std::iter::once(value).collect();
+    Some(Arc::new(
+        array.with_data_type(T::TYPE_CONSTRUCTOR(precision, scale)),
+    ))
+}
+
+type DecimalCandidate = fn(Option<i256>, u8, i8) -> Option<ArrayRef>;
+
+/// The decimal types, ordered from narrowest to widest
+const DECIMAL_CANDIDATES: [DecimalCandidate; 4] = [
+    |v, p, s| try_decimal_scalar::<Decimal32Type>(v, p, s, |v| v.to_i128()?.try_into().ok()),
+    |v, p, s| try_decimal_scalar::<Decimal64Type>(v, p, s, |v| v.to_i128()?.try_into().ok()),
+    |v, p, s| try_decimal_scalar::<Decimal128Type>(v, p, s, i256::to_i128),
+    |v, p, s| try_decimal_scalar::<Decimal256Type>(v, p, s, Some),
+];
+
+/// The maximum precision of each of the types in [`DECIMAL_CANDIDATES`]
+const DECIMAL_MAX_PRECISIONS: [u8; 4] = [
+    Decimal32Type::MAX_PRECISION,
+    Decimal64Type::MAX_PRECISION,
+    Decimal128Type::MAX_PRECISION,
+    Decimal256Type::MAX_PRECISION,
+];
+
+/// Returns the index in [`DECIMAL_CANDIDATES`] of the narrowest decimal type at
+/// least as wide as the type with index `rank` that supports `precision`, or of
+/// [`Decimal256Type`] if none does
+fn widen_decimal(rank: usize, precision: u8) -> usize {
+    (rank..DECIMAL_CANDIDATES.len())
+        .find(|&r| DECIMAL_MAX_PRECISIONS[r] >= precision)
+        .unwrap_or(DECIMAL_CANDIDATES.len() - 1)
+}
+
+/// Returns `value` as a [`Scalar`] of the decimal type with index `rank` in
+/// [`DECIMAL_CANDIDATES`], with `precision` capped to the maximum of this type
+///
+/// Returns an error if `value` does not fit this precision
+fn decimal_scalar(
+    value: Option<i256>,
+    rank: usize,
+    precision: u8,
+    scale: i8,
+) -> Result<Scalar<ArrayRef>, ArrowError> {
+    let precision = precision.min(DECIMAL_MAX_PRECISIONS[rank]);
+    DECIMAL_CANDIDATES[rank](value, precision, scale)
+        .map(Scalar::new)
+        .ok_or_else(|| {
+            ArrowError::ArithmeticOverflow(format!(
+                "Overflow computing decimal aggregate with precision {precision} and scale {scale}"
+            ))
+        })
+}
+
+/// Returns the sum of the non-null values of the decimal `array`, widening the
+/// result type to avoid overflow.
+///
+/// For an input of type `DecimalXX(p, s)` the result has the same scale `s` and a
+/// precision of `p + 10`. The result type is the narrowest decimal type at least as
+/// wide as `DecimalXX` that supports this precision, e.g. [`Decimal256Type`] for a
+/// [`Decimal128Type`] input with a precision above 28. The precision is capped to
+/// the maximum of [`Decimal256Type`]. The result type therefore only depends on the
+/// type of `array`, not on its values.
+///
+/// The result is null if the array is empty or only contains null values.
+///
+/// Returns an error if `array` is not a decimal array, or the sum does not fit the
+/// result type
+///
+/// # Example
+/// ```rust
+/// # use arrow_array::{Array, Decimal128Array};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Decimal256Type;
+/// # use arrow_arith::aggregate::sum_decimal;
+/// # use arrow_buffer::i256;
+/// # use arrow_schema::DataType;
+/// let max = 10_i128.pow(38) - 1;
+/// let array = Decimal128Array::from(vec![max, max])
+///     .with_precision_and_scale(38, 2)
+///     .unwrap();
+/// let sum = sum_decimal(&array).unwrap().into_inner();
+/// assert_eq!(sum.data_type(), &DataType::Decimal256(48, 2));
+/// let expected = i256::from_i128(max) * i256::from_i128(2);
+/// assert_eq!(sum.as_primitive::<Decimal256Type>().value(0), expected);
+/// ```
+pub fn sum_decimal(array: &dyn Array) -> Result<Scalar<ArrayRef>, ArrowError> {
+    let sum = decimal_sum(array)?;
+    let value = (sum.count != 0).then_some(sum.sum);
+    let precision = sum.precision.saturating_add(10);
+    let rank = widen_decimal(sum.rank, precision);
+    decimal_scalar(value, rank, precision, sum.scale)
+}
+
+/// Returns the average of the non-null values of the decimal `array`, widening the
+/// result type as necessary to avoid overflow.
+///
+/// For an input of type `DecimalXX(p, s)` the result has a precision of `p + 4`
+/// and a scale of `s + 4`. As for [`sum_decimal`], the result type is the narrowest
+/// decimal type at least as wide as `DecimalXX` that supports this precision, and
+/// the precision and scale are capped to the maximum of [`Decimal256Type`]. The
+/// average is rounded half away from zero to the result scale.
+///
+/// The result is null if the array is empty or only contains null values.
+///
+/// Returns an error if `array` is not a decimal array, or the average does not fit
+/// the result type
+///
+/// # Example
+/// ```rust
+/// # use arrow_array::{Array, Decimal128Array};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Decimal128Type;
+/// # use arrow_arith::aggregate::avg_decimal;
+/// # use arrow_schema::DataType;
+/// // 1.00, 2.00 and 2.00
+/// let array = Decimal128Array::from(vec![100, 200, 200])
+///     .with_precision_and_scale(10, 2)
+///     .unwrap();
+/// let avg = avg_decimal(&array).unwrap().into_inner();
+/// assert_eq!(avg.data_type(), &DataType::Decimal128(14, 6));
+/// // 1.666667
+/// assert_eq!(avg.as_primitive::<Decimal128Type>().value(0), 1_666_667);
+/// ```
+pub fn avg_decimal(array: &dyn Array) -> Result<Scalar<ArrayRef>, ArrowError> {
+    let sum = decimal_sum(array)?;
+    let precision = sum.precision.saturating_add(4);
+    let rank = widen_decimal(sum.rank, precision);
+    let max_scale = DECIMAL_MAX_PRECISIONS[rank] as i8;
+    let scale = sum.scale.saturating_add(4).min(max_scale);
+
+    let overflow = || {
+        ArrowError::ArithmeticOverflow(format!(
+            "Overflow computing average of {} decimal values",
+            sum.count
+        ))
+    };
+    let value = match sum.count {
+        0 => None,
+        count => {
+            let mul = i256::from_i128(10).pow_checked((scale - sum.scale) as u32)?;
+            let scaled = sum.sum.checked_mul(mul).ok_or_else(overflow)?;
+            let count = i256::from_i128(count as i128);
+            let quotient = scaled.checked_div(count).ok_or_else(overflow)?;
+            let remainder = scaled.checked_rem(count).ok_or_else(overflow)?;
+            // Round half away from zero
+            match remainder.wrapping_abs().wrapping_mul(i256::from_i128(2)) >= count {
+                true if scaled < i256::ZERO => Some(quotient - i256::ONE),
+                true => Some(quotient + i256::ONE),
+                false => Some(quotient),
+            }
+        }
+    };
+    decimal_scalar(value, rank, precision, scale)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2162,4 +2407,122 @@ mod tests {
         let result = max_array::<Int32Type, _>(typed_array);
         assert_eq!(result, Some(100));
     }
+
+    #[test]
+    fn test_sum_decimal() {
+        let array = Decimal128Array::from(vec![Some(150), None, Some(-25)])
+            .with_precision_and_scale(10, 2)
+            .unwrap();
+        let sum = sum_decimal(&array).unwrap().into_inner();
+        assert_eq!(sum.data_type(), &DataType::Decimal128(20, 2));
+        assert_eq!(sum.as_primitive::<Decimal128Type>().value(0), 125);
+
+        // The precision determines the result type
+        let max = 10_i128.pow(38) - 1;
+        let array = Decimal128Array::from(vec![max; 2])
+            .with_precision_and_scale(38, 0)
+            .unwrap();
+        let sum = sum_decimal(&array).unwrap().into_inner();
+        assert_eq!(sum.data_type(), &DataType::Decimal256(48, 0));
+        assert_eq!(
+            sum.as_primitive::<Decimal256Type>().value(0),
+            i256::from_i128(max) * i256::from_i128(2)
+        );
+
+        // The result type does not depend on the values
+        for values in [vec![1, 2], vec![999_999_999; 2]] {
+            let array = Decimal32Array::from(values).with_precision_and_scale(8, 2);
+            let sum = sum_decimal(&array.unwrap()).unwrap().into_inner();
+            assert_eq!(sum.data_type(), &DataType::Decimal64(18, 2));
+        }
+        let array = Decimal32Array::from(vec![999_999_999; 2]);
+        let sum = sum_decimal(&array).unwrap().into_inner();
+        assert_eq!(sum.data_type(), &DataType::Decimal128(19, 2));
+        assert_eq!(sum.as_primitive::<Decimal128Type>().value(0), 1_999_999_998);
+
+        let array = Decimal64Array::from(vec![None, None]);
+        let sum = sum_decimal(&array).unwrap().into_inner();
+        assert_eq!(sum.data_type(), &DataType::Decimal128(28, 6));
+        assert!(sum.is_null(0));
+
+        // The sum does not fit the capped precision
+        let max = i256::from_string(&"9".repeat(76)).unwrap();
+        let array = Decimal256Array::from(vec![max, i256::ONE])
+            .with_precision_and_scale(76, 0)
+            .unwrap();
+        let err = sum_decimal(&array).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Arithmetic overflow: Overflow computing decimal aggregate with precision 76 and scale 0"
+        );
+
+        let array = Decimal256Array::from(vec![i256::MAX, i256::ONE]);
+        let err = sum_decimal(&array).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Arithmetic overflow: Overflow summing Decimal256 values"
+        );
+
+        let err = sum_decimal(&Int32Array::from(vec![1])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Expected a decimal array, got Int32"
+        );
+    }
+
+    #[test]
+    fn test_avg_decimal() {
+        // -1.5 and -2.0 average to -1.75
+        let array = Decimal64Array::from(vec![Some(-15), None, Some(-20)])
+            .with_precision_and_scale(4, 1)
+            .unwrap();
+        let avg = avg_decimal(&array).unwrap().into_inner();
+        assert_eq!(avg.data_type(), &DataType::Decimal64(8, 5));
+        assert_eq!(avg.as_primitive::<Decimal64Type>().value(0), -175_000);
+
+        // -2/3 is rounded half away from zero
+        let array = Decimal32Array::from(vec![0, -1, -1])
+            .with_precision_and_scale(3, 0)
+            .unwrap();
+        let avg = avg_decimal(&array).unwrap().into_inner();
+        assert_eq!(avg.data_type(), &DataType::Decimal32(7, 4));
+        assert_eq!(avg.as_primitive::<Decimal32Type>().value(0), -6667);
+
+        // The precision determines the result type
+        let array = Decimal128Array::from(vec![1, 2])
+            .with_precision_and_scale(38, 36)
+            .unwrap();
+        let avg = avg_decimal(&array).unwrap().into_inner();
+        assert_eq!(avg.data_type(), &DataType::Decimal256(42, 40));
+        assert_eq!(
+            avg.as_primitive::<Decimal256Type>().value(0),
+            i256::from_i128(15_000)
+        );
+
+        // The scale is capped to the maximum of Decimal256
+        let array = Decimal256Array::from(vec![i256::ONE])
+            .with_precision_and_scale(74, 74)
+            .unwrap();
+        let avg = avg_decimal(&array).unwrap().into_inner();
+        assert_eq!(avg.data_type(), &DataType::Decimal256(76, 76));
+        assert_eq!(
+            avg.as_primitive::<Decimal256Type>().value(0),
+            i256::from_i128(100)
+        );
+
+        // The scaled sum is widened to Decimal256
+        let max = 10_i128.pow(38) - 1;
+        let array = Decimal128Array::from(vec![max; 3])
+            .with_precision_and_scale(38, 0)
+            .unwrap();
+        let avg = avg_decimal(&array).unwrap().into_inner();
+        assert_eq!(avg.data_type(), &DataType::Decimal256(42, 4));
+        assert_eq!(
+            avg.as_primitive::<Decimal256Type>().value(0),
+            i256::from_i128(max) * i256::from_i128(10_000)
+        );
+
+        let avg = avg_decimal(&Decimal128Array::from(Vec::<i128>::new())).unwrap();
+        assert!(avg.into_inner().is_null(0));
+    }
 }
@@ -30,4 +30,5 @@ pub mod arity;
 pub mod bitwise;
 pub mod boolean;
 pub mod numeric;
+pub mod promote;
 pub mod temporal;
@@ -27,8 +27,10 @@ use arrow_array::types::*;
 use arrow_array::*;
 use arrow_buffer::{ArrowNativeType, IntervalDayTime, IntervalMonthDayNano};
 use arrow_schema::{ArrowError, DataType, IntervalUnit, TimeUnit};
+use num_traits::AsPrimitive;
 
 use crate::arity::{binary, try_binary};
+use crate::promote::{decimal_scale, promote_array, promote_types};
 
 /// Perform `lhs + rhs`, returning an error on overflow
 pub fn add(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<ArrayRef, ArrowError> {
@@ -183,6 +185,58 @@ pub fn neg_wrapping(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
     }
 }
 
+/// Perform `lhs + rhs` after promoting them to a common type, returning an error on overflow
+///
+/// Operands of different numeric types are converted to [`promote_types`] of their types,
+/// without converting the inputs in the common cases of `Int32`, `Int64`, `Float64` and
+/// `Decimal128` operands. Operands of the same or non-numeric types are passed to [`add`].
+///
+/// ```
+/// # use arrow_array::{Decimal128Array, Float64Array, Int32Array, Int64Array};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::{Decimal128Type, Int64Type};
+/// # use arrow_arith::numeric::add_promoted;
+/// let a = Int32Array::from(vec![1, 2]);
+/// let b = Int64Array::from(vec![i64::MAX - 1, 3]);
+/// let result = add_promoted(&a, &b).unwrap();
+/// assert_eq!(result.as_primitive::<Int64Type>().values(), &[i64::MAX, 5]);
+///
+/// let c = Decimal128Array::from(vec![150, 225]).with_precision_and_scale(5, 2).unwrap();
+/// let result = add_promoted(&a, &c).unwrap();
+/// assert_eq!(result.as_primitive::<Decimal128Type>().value_as_string(1), "4.25");
+/// ```
+pub fn add_promoted(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    promoted_op(Op::Add, lhs, rhs)
+}
+
+/// Perform `lhs - rhs` after promoting them to a common type, returning an error on overflow
+///
+/// See [`add_promoted`] for how the operands are promoted
+pub fn sub_promoted(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    promoted_op(Op::Sub, lhs, rhs)
+}
+
+/// Perform `lhs * rhs` after promoting them to a common type, returning an error on overflow
+///
+/// See [`add_promoted`] for how the operands are promoted
+pub fn mul_promoted(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    promoted_op(Op::Mul, lhs, rhs)
+}
+
+/// Perform `lhs / rhs` after promoting them to a common type, see [`div`]
+///
+/// See [`add_promoted`] for how the operands are promoted
+pub fn div_promoted(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    promoted_op(Op::Div, lhs, rhs)
+}
+
+/// Perform `lhs % rhs` after promoting them to a common type, see [`rem`]
+///
+/// See [`add_promoted`] for how the operands are promoted
+pub fn rem_promoted(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    promoted_op(Op::Rem, lhs, rhs)
+}
+
 /// An enumeration of arithmetic operations
 ///
 /// This allows sharing the type dispatch logic across the various kernels
@@ -218,6 +272,18 @@ impl Op {
 
 /// Dispatch the given `op` to the appropriate specialized kernel
 fn arithmetic_op(op: Op, lhs: &dyn Datum, rhs: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    let (l, l_scalar) = lhs.get();
+    let (r, r_scalar) = rhs.get();
+    arithmetic_op_impl(op, l, l_scalar, r, r_scalar)
+}
+
+fn arithmetic_op_impl(
+    op: Op,
+    l: &dyn Array,
+    l_scalar: bool,
+    r: &dyn Array,
+    r_scalar: bool,
+) -> Result<ArrayRef, ArrowError> {
     use DataType::*;
     use IntervalUnit::*;
     use TimeUnit::*;
@@ -228,8 +294,6 @@ fn arithmetic_op(op: Op, lhs: &dyn Datum, rhs: &dyn Datum) -> Result<ArrayRef, A
         };
     }
 
-    let (l, l_scalar) = lhs.get();
-    let (r, r_scalar) = rhs.get();
     downcast_integer! {
         l.data_type(), r.data_type() => (integer_helper, op, l, l_scalar, r, r_scalar),
         (Float16, Float16) => float_op::<Float16Type>(op, l, l_scalar, r, r_scalar),
@@ -254,7 +318,7 @@ fn arithmetic_op(op: Op, lhs: &dyn Datum, rhs: &dyn Datum) -> Result<ArrayRef, A
         (Decimal256(_, _), Decimal256(_, _)) => decimal_op::<Decimal256Type>(op, l, l_scalar, r, r_scalar),
         (l_t, r_t) => match (l_t, r_t) {
             (Duration(_) | Interval(_), Date32 | Date64 | Timestamp(_, _)) if op.commutative() => {
-                arithmetic_op(op, rhs, lhs)
+                arithmetic_op_impl(op, r, r_scalar, l, l_scalar)
             }
             _ => Err(ArrowError::InvalidArgumentError(
               format!("Invalid arithmetic operation: {l_t} {op} {r_t}")
@@ -263,6 +327,106 @@ fn arithmetic_op(op: Op, lhs: &dyn Datum, rhs: &dyn Datum) -> Result<ArrayRef, A
     }
 }
 
+/// Dispatch the given `op` after promoting the operands to [`promote_types`] of their types
+fn promoted_op(op: Op, lhs: &dyn Datum, rhs: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    use DataType::*;
+
+    let (l, l_s) = lhs.get();
+    let (r, r_s) = rhs.get();
+    let (l_t, r_t) = (l.data_type(), r.data_type());
+    if l_t == r_t || !l_t.is_numeric() || !r_t.is_numeric() {
+        return arithmetic_op_impl(op, l, l_s, r, r_s);
+    }
+    let promoted = promote_types(l_t, r_t)?;
+
+    macro_rules! primitive {
+        ($f:ident, $t:ty, $l:ty, $r:ty) => {
+            $f::<$t, _, _>(
+                op,
+                l.as_primitive::<$l>(),
+                l_s,
+                |v| v.as_(),
+                r.as_primitive::<$r>(),
+                r_s,
+                |v| v.as_(),
+            )
+        };
+    }
+
+    // Compute the common cases without converting the operands
+    match (l_t, r_t, &promoted) {
+        (Int32, Int64, _) => primitive!(promoted_integer_op, Int64Type, Int32Type, Int64Type),
+        (Int64, Int32, _) => primitive!(promoted_integer_op, Int64Type, Int64Type, Int32Type),
+        (Int32, Float64, _) => primitive!(promoted_float_op, Float64Type, Int32Type, Float64Type),
+        (Float64, Int32, _) => primitive!(promoted_float_op, Float64Type, Float64Type, Int32Type),
+        (Int64, Float64, _) => primitive!(promoted_float_op, Float64Type, Int64Type, Float64Type),
+        (Float64, Int64, _) => primitive!(promoted_float_op, Float64Type, Float64Type, Int64Type),
+        (Float32, Float64, _) => {
+            primitive!(promoted_float_op, Float64Type, Float32Type, Float64Type)
+        }
+        (Float64, Float32, _) => {
+            primitive!(promoted_float_op, Float64Type, Float64Type, Float32Type)
+        }
+        (Int32 | Int64 | Decimal128(_, _), Int32 | Int64 | Decimal128(_, _), Decimal128(_, _)) => {
+            match l_t {
+                Int32 => decimal128_op(op, &promoted, l.as_primitive::<Int32Type>(), l_s, r, r_s),
+                Int64 => decimal128_op(op, &promoted, l.as_primitive::<Int64Type>(), l_s, r, r_s),
+                _ => decimal128_op(
+                    op,
+                    &promoted,
+                    l.as_primitive::<Decimal128Type>(),
+                    l_s,
+                    r,
+                    r_s,
+                ),
+            }
+        }
+        _ => {
+            let l = promote_array(l, &promoted)?;
+            let r = promote_array(r, &promoted)?;
+            arithmetic_op_impl(op, l.as_ref(), l_s, r.as_ref(), r_s)
+        }
+    }
+}
+
+/// Perform an arithmetic operation on `Int32`, `Int64` or `Decimal128` operands promoted to
+/// the `Decimal128` type `promoted`
+fn decimal128_op<L: ArrowPrimitiveType>(
+    op: Op,
+    promoted: &DataType,
+    l: &PrimitiveArray<L>,
+    l_s: bool,
+    r: &dyn Array,
+    r_s: bool,
+) -> Result<ArrayRef, ArrowError>
+where
+    L::Native: AsPrimitive<i128>,
+{
+    let scale = decimal_scale(promoted);
+    let l_mul = 10_i128.pow_checked((scale - decimal_scale(l.data_type())) as _)?;
+    let r_mul = 10_i128.pow_checked((scale - decimal_scale(r.data_type())) as _)?;
+
+    macro_rules! rhs {
+        ($r:ty) => {
+            promoted_decimal_op::<Decimal128Type, _, _>(
+                op,
+                (promoted, promoted),
+                l,
+                l_s,
+                |v| AsPrimitive::<i128>::as_(v).mul_checked(l_mul),
+                r.as_primitive::<$r>(),
+                r_s,
+                |v| AsPrimitive::<i128>::as_(v).mul_checked(r_mul),
+            )
+        };
+    }
+    match r.data_type() {
+        DataType::Int32 => rhs!(Int32Type),
+        DataType::Int64 => rhs!(Int64Type),
+        _ => rhs!(Decimal128Type),
+    }
+}
+
 /// Perform an infallible binary operation on potentially scalar inputs
 macro_rules! op {
     ($l:ident, $l_s:expr, $r:ident, $r_s:expr, $op:expr) => {
@@ -323,19 +487,34 @@ fn integer_op<T: ArrowPrimitiveType>(
 ) -> Result<ArrayRef, ArrowError> {
     let l = l.as_primitive::<T>();
     let r = r.as_primitive::<T>();
+    promoted_integer_op::<T, _, _>(op, l, l_s, |v| v, r, r_s, |v| v)
+}
+
+/// Perform an arithmetic operation on integers, converting the values of `l` and `r`
+/// to `T` with `lc` and `rc` respectively
+fn promoted_integer_op<T: ArrowPrimitiveType, L: ArrowPrimitiveType, R: ArrowPrimitiveType>(
+    op: Op,
+    l: &PrimitiveArray<L>,
+    l_s: bool,
+    lc: impl Fn(L::Native) -> T::Native,
+    r: &PrimitiveArray<R>,
+    r_s: bool,
+    rc: impl Fn(R::Native) -> T::Native,
+) -> Result<ArrayRef, ArrowError> {
     let array: PrimitiveArray<T> = match op {
-        Op::AddWrapping => op!(l, l_s, r, r_s, l.add_wrapping(r)),
-        Op::Add => try_op!(l, l_s, r, r_s, l.add_checked(r)),
-        Op::SubWrapping => op!(l, l_s, r, r_s, l.sub_wrapping(r)),
-        Op::Sub => try_op!(l, l_s, r, r_s, l.sub_checked(r)),
-        Op::MulWrapping => op!(l, l_s, r, r_s, l.mul_wrapping(r)),
-        Op::Mul => try_op!(l, l_s, r, r_s, l.mul_checked(r)),
-        Op::Div => try_op!(l, l_s, r, r_s, l.div_checked(r)),
+        Op::AddWrapping => op!(l, l_s, r, r_s, lc(l).add_wrapping(rc(r))),
+        Op::Add => try_op!(l, l_s, r, r_s, lc(l).add_checked(rc(r))),
+        Op::SubWrapping => op!(l, l_s, r, r_s, lc(l).sub_wrapping(rc(r))),
+        Op::Sub => try_op!(l, l_s, r, r_s, lc(l).sub_checked(rc(r))),
+        Op::MulWrapping => op!(l, l_s, r, r_s, lc(l).mul_wrapping(rc(r))),
+        Op::Mul => try_op!(l, l_s, r, r_s, lc(l).mul_checked(rc(r))),
+        Op::Div => try_op!(l, l_s, r, r_s, lc(l).div_checked(rc(r))),
         Op::Rem => try_op!(l, l_s, r, r_s, {
+            let r = rc(r);
             if r.is_zero() {
                 Err(ArrowError::DivideByZero)
             } else {
-                Ok(l.mod_wrapping(r))
+                Ok(lc(l).mod_wrapping(r))
             }
         }),
     };
@@ -352,12 +531,26 @@ fn float_op<T: ArrowPrimitiveType>(
 ) -> Result<ArrayRef, ArrowError> {
     let l = l.as_primitive::<T>();
     let r = r.as_primitive::<T>();
+    promoted_float_op::<T, _, _>(op, l, l_s, |v| v, r, r_s, |v| v)
+}
+
+/// Perform an arithmetic operation on floats, converting the values of `l` and `r`
+/// to `T` with `lc` and `rc` respectively
+fn promoted_float_op<T: ArrowPrimitiveType, L: ArrowPrimitiveType, R: ArrowPrimitiveType>(
+    op: Op,
+    l: &PrimitiveArray<L>,
+    l_s: bool,
+    lc: impl Fn(L::Native) -> T::Native,
+    r: &PrimitiveArray<R>,
+    r_s: bool,
+    rc: impl Fn(R::Native) -> T::Native,
+) -> Result<ArrayRef, ArrowError> {
     let array: PrimitiveArray<T> = match op {
-        Op::AddWrapping | Op::Add => op!(l, l_s, r, r_s, l.add_wrapping(r)),
-        Op::SubWrapping | Op::Sub => op!(l, l_s, r, r_s, l.sub_wrapping(r)),
-        Op::MulWrapping | Op::Mul => op!(l, l_s, r, r_s, l.mul_wrapping(r)),
-        Op::Div => op!(l, l_s, r, r_s, l.div_wrapping(r)),
-        Op::Rem => op!(l, l_s, r, r_s, l.mod_wrapping(r)),
+        Op::AddWrapping | Op::Add => op!(l, l_s, r, r_s, lc(l).add_wrapping(rc(r))),
+        Op::SubWrapping | Op::Sub => op!(l, l_s, r, r_s, lc(l).sub_wrapping(rc(r))),
+        Op::MulWrapping | Op::Mul => op!(l, l_s, r, r_s, lc(l).mul_wrapping(rc(r))),
+        Op::Div => op!(l, l_s, r, r_s, lc(l).div_wrapping(rc(r))),
+        Op::Rem => op!(l, l_s, r, r_s, lc(l).mod_wrapping(rc(r))),
     };
     Ok(Arc::new(array))
 }
@@ -795,8 +988,25 @@ fn decimal_op<T: DecimalType>(
 ) -> Result<ArrayRef, ArrowError> {
     let l = l.as_primitive::<T>();
     let r = r.as_primitive::<T>();
+    let types = (l.data_type(), r.data_type());
+    promoted_decimal_op::<T, _, _>(op, types, l, l_s, Ok, r, r_s, Ok)
+}
 
-    let (p1, s1, p2, s2) = match (l.data_type(), r.data_type()) {
+/// Perform arithmetic operation on decimal arrays, converting the values of `l` and `r`
+/// to `T` with `lc` and `rc` respectively, where `types` are the decimal types of the
+/// converted values
+#[allow(clippy::too_many_arguments)]
+fn promoted_decimal_op<T: DecimalType, L: ArrowPrimitiveType, R: ArrowPrimitiveType>(
+    op: Op,
+    types: (&DataType, &DataType),
+    l: &PrimitiveArray<L>,
+    l_s: bool,
+    lc: impl Fn(L::Native) -> Result<T::Native, ArrowError>,
+    r: &PrimitiveArray<R>,
+    r_s: bool,
+    rc: impl Fn(R::Native) -> Result<T::Native, ArrowError>,
+) -> Result<ArrayRef, ArrowError> {
+    let (p1, s1, p2, s2) = match types {
         (DataType::Decimal32(p1, s1), DataType::Decimal32(p2, s2)) => (p1, s1, p2, s2),
         (DataType::Decimal64(p1, s1), DataType::Decimal64(p2, s2)) => (p1, s1, p2, s2),
         (DataType::Decimal128(p1, s1), DataType::Decimal128(p2, s2)) => (p1, s1, p2, s2),
@@ -827,7 +1037,9 @@ fn decimal_op<T: DecimalType>(
                         l_s,
                         r,
                         r_s,
-                        l.mul_checked(l_mul)?.add_checked(r.mul_checked(r_mul)?)
+                        lc(l)?
+                            .mul_checked(l_mul)?
+                            .add_checked(rc(r)?.mul_checked(r_mul)?)
                     )
                 }
                 Op::Sub | Op::SubWrapping => {
@@ -836,7 +1048,9 @@ fn decimal_op<T: DecimalType>(
                         l_s,
                         r,
                         r_s,
-                        l.mul_checked(l_mul)?.sub_checked(r.mul_checked(r_mul)?)
+                        lc(l)?
+                            .mul_checked(l_mul)?
+                            .sub_checked(rc(r)?.mul_checked(r_mul)?)
                     )
                 }
                 _ => unreachable!(),
@@ -851,13 +1065,13 @@ fn decimal_op<T: DecimalType>(
                 // beyond the maximum, rounding is not acceptable and thus an error occurs
                 return Err(ArrowError::InvalidArgumentError(format!(
                     "Output scale of {} {op} {} would exceed max scale of {}",
-                    l.data_type(),
-                    r.data_type(),
+                    types.0,
+                    types.1,
                     T::MAX_SCALE
                 )));
             }
 
-            try_op!(l, l_s, r, r_s, l.mul_checked(r))
+            try_op!(l, l_s, r, r_s, lc(l)?.mul_checked(rc(r)?))
                 .with_precision_and_scale(result_precision, result_scale)?
         }
 
@@ -887,7 +1101,9 @@ fn decimal_op<T: DecimalType>(
                 l_s,
                 r,
                 r_s,
-                l.mul_checked(l_mul)?.div_checked(r.mul_checked(r_mul)?)
+                lc(l)?
+                    .mul_checked(l_mul)?
+                    .div_checked(rc(r)?.mul_checked(r_mul)?)
             )
             .with_precision_and_scale(result_precision, result_scale)?
         }
@@ -908,7 +1124,9 @@ fn decimal_op<T: DecimalType>(
                 l_s,
                 r,
                 r_s,
-                l.mul_checked(l_mul)?.mod_checked(r.mul_checked(r_mul)?)
+                lc(l)?
+                    .mul_checked(l_mul)?
+                    .mod_checked(rc(r)?.mul_checked(r_mul)?)
             )
             .with_precision_and_scale(result_precision, result_scale)?
         }
@@ -1988,4 +2206,75 @@ mod tests {
         test_month_day_nano_ops!(Date32Type, date_to_days);
         test_month_day_nano_ops!(Date64Type, date_to_millis);
     }
+
+    #[test]
+    fn test_promoted() {
+        let i32s = Int32Array::from(vec![Some(1), None, Some(-7), Some(i32::MAX)]);
+        let i64s = Int64Array::from(vec![Some(10), Some(2), None, Some(i64::MAX - 1)]);
+        let f32s = Float32Array::from(vec![0.5, 1.0, -2.0, 4.0]);
+        let f64s = Float64Array::from(vec![Some(0.25), None, Some(3.0), Some(-1.5)]);
+        let u8s = UInt8Array::from(vec![1, 2, 3, 255]);
+        let d128 = Decimal128Array::from(vec![Some(12345), Some(-1), None, Some(0)])
+            .with_precision_and_scale(10, 3)
+            .unwrap();
+        let d32 = Decimal32Array::from(vec![150, 225, -5, 1])
+            .with_precision_and_scale(5, 2)
+            .unwrap();
+        let ops = [Op::Add, Op::Sub, Op::Mul, Op::Div, Op::Rem];
+        let arrays: [&dyn Array; 7] = [&i32s, &i64s, &f32s, &f64s, &u8s, &d128, &d32];
+
+        // The result must match that of converting the operands to the promoted type
+        for op in ops {
+            for l in arrays {
+                for r in arrays {
+                    let promoted = promote_types(l.data_type(), r.data_type()).unwrap();
+                    let l_p = promote_array(l, &promoted).unwrap();
+                    let r_p = promote_array(r, &promoted).unwrap();
+                    let expected = arithmetic_op_impl(op, l_p.as_ref(), false, r_p.as_ref(), false);
+
+                    let actual = promoted_op(op, &l, &r);
+                    let ctx = format!("{} {op} {}", l.data_type(), r.data_type());
+                    match (actual, expected) {
+                        (Ok(a), Ok(e)) => assert_eq!(a.as_ref(), e.as_ref(), "{ctx}"),
+                        (Err(a), Err(e)) => assert_eq!(a.to_string(), e.to_string(), "{ctx}"),
+                        (a, e) => panic!("{ctx}: {a:?} != {e:?}"),
+                    }
+
+                    let l_scalar = Scalar::new(l.slice(0, 1));
+                    let actual = promoted_op(op, &l_scalar, &r);
+                    let expected = promoted_op(op, &l.slice(0, 1), &Scalar::new(r.slice(0, 1)));
+                    if let (Ok(a), Ok(e)) = (actual, expected) {
+                        assert_eq!(a.len(), r.len(), "{ctx}");
+                        assert_eq!(a.slice(0, 1).as_ref(), e.as_ref(), "{ctx}");
+                    }
+                }
+            }
+        }
+
+        let result = add_promoted(&i32s.slice(0, 3), &i64s.slice(0, 3)).unwrap();
+        let expected = Int64Array::from(vec![Some(11), None, None]);
+        assert_eq!(result.as_primitive::<Int64Type>(), &expected);
+        let err = add_promoted(&i64s, &Scalar::new(Int32Array::from(vec![2]))).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Arithmetic overflow: Overflow happened on: 9223372036854775806 + 2"
+        );
+
+        let result = mul_promoted(&d32, &i32s).unwrap();
+        assert_eq!(result.data_type(), &DataType::Decimal64(18, 4));
+
+        let result = add_promoted(&d128, &i64s).unwrap();
+        let result = result.as_primitive::<Decimal128Type>();
+        assert_eq!(result.data_type(), &DataType::Decimal128(23, 3));
+        assert_eq!(result.value_as_string(0), "22.345");
+
+        let result = sub_promoted(&f32s, &f64s).unwrap();
+        assert_eq!(result.as_primitive::<Float64Type>().value(0), 0.25);
+
+        let err = add_promoted(&i32s, &StringArray::from(vec!["a"; 4])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Invalid arithmetic operation: Int32 + Utf8"
+        );
+    }
 }
@@ -0,0 +1,459 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines [`promote_types`], the common type of numeric values of different types
+
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
+use arrow_array::*;
+use arrow_buffer::{ArrowNativeType, i256};
+use arrow_schema::{ArrowError, DataType};
+use half::f16;
+use num_traits::{AsPrimitive, ToPrimitive};
+
+/// Returns the type values of `lhs` and `rhs` are converted to when combined, for
+/// example by [`add_promoted`](crate::numeric::add_promoted)
+///
+/// The rules are:
+///
+/// * Identical types are unchanged
+/// * Integers of the same signedness promote to the wider of the two
+/// * A signed and an unsigned integer promote to the narrowest signed integer wider than the
+///   unsigned integer, or `Decimal128(20, 0)` for `UInt64`
+/// * Floats promote to the wider of the two
+/// * An integer and a float promote to the narrowest float, at least as wide as the float,
+///   that represents all the values of the integer, or `Float64` for 64-bit integers whose
+///   values are then rounded to the nearest `Float64`
+/// * An integer and a decimal promote as if the integer were a decimal of scale `0` and the
+///   precision of its largest value, e.g. `Decimal(10, 0)` for `Int32`
+/// * Decimals promote to the maximum scale of the two, with the precision required for the
+///   integer digits of both, of the narrowest decimal type at least as wide as both that
+///   supports this precision
+/// * A decimal and a float promote to `Float64`
+///
+/// ```
+/// # use arrow_arith::promote::promote_types;
+/// # use arrow_schema::DataType;
+/// let promoted = promote_types(&DataType::Int32, &DataType::UInt32).unwrap();
+/// assert_eq!(promoted, DataType::Int64);
+///
+/// let promoted = promote_types(&DataType::Int64, &DataType::Decimal32(5, 2)).unwrap();
+/// assert_eq!(promoted, DataType::Decimal128(21, 2));
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if either type is not numeric, or the precision of the promoted decimal
+/// exceeds that of `Decimal256`
+pub fn promote_types(lhs: &DataType, rhs: &DataType) -> Result<DataType, ArrowError> {
+    use DataType::*;
+
+    let err = || {
+        ArrowError::InvalidArgumentError(format!(
+            "Cannot promote {lhs} and {rhs} to a common numeric type"
+        ))
+    };
+    if !lhs.is_numeric() || !rhs.is_numeric() {
+        return Err(err());
+    }
+    if lhs == rhs {
+        return Ok(lhs.clone());
+    }
+
+    match (Numeric::of(lhs), Numeric::of(rhs)) {
+        (Numeric::Integer(l_bits, l_signed), Numeric::Integer(r_bits, r_signed)) => {
+            if l_signed == r_signed {
+                return Ok(integer_type(l_bits.max(r_bits), l_signed));
+            }
+            let (signed, unsigned) = match l_signed {
+                true => (l_bits, r_bits),
+                false => (r_bits, l_bits),
+            };
+            Ok(match signed > unsigned {
+                true => integer_type(signed, true),
+                false if unsigned < 64 => integer_type(unsigned * 2, true),
+                false => Decimal128(20, 0),
+            })
+        }
+        (Numeric::Float(l), Numeric::Float(r)) => Ok(float_type(l.max(r))),
+        (Numeric::Integer(bits, _), Numeric::Float(float))
+        | (Numeric::Float(float), Numeric::Integer(bits, _)) => {
+            // The number of bits of the significand of the narrowest float exact for `bits`
+            let exact = match bits {
+                8 => 16,
+                16 => 32,
+                _ => 64,
+            };
+            Ok(float_type(float.max(exact)))
+        }
+        (Numeric::Decimal(..), Numeric::Float(_)) | (Numeric::Float(_), Numeric::Decimal(..)) => {
+            Ok(Float64)
+        }
+        (l, r) => {
+            let (l_width, l_precision, l_scale) = l.decimal();
+            let (r_width, r_precision, r_scale) = r.decimal();
+            let scale = l_scale.max(r_scale);
+            let digits =
+                (l_precision as i16 - l_scale as i16).max(r_precision as i16 - r_scale as i16);
+            let precision = u8::try_from(digits + scale as i16).map_err(|_| err())?;
+
+            let width = l_width.max(r_width);
+            DECIMALS
+                .into_iter()
+                .skip(width)
+                .find(|(max, _)| precision <= *max)
+                .map(|(_, constructor)| constructor(precision, scale))
+                .ok_or_else(err)
+        }
+    }
+}
+
+/// The decimal types, from narrowest to widest
+type DecimalConstructor = fn(u8, i8) -> DataType;
+const DECIMALS: [(u8, DecimalConstructor); 4] = [
+    (
+        Decimal32Type::MAX_PRECISION,
+        Decimal32Type::TYPE_CONSTRUCTOR,
+    ),
+    (
+        Decimal64Type::MAX_PRECISION,
+        Decimal64Type::TYPE_CONSTRUCTOR,
+    ),
+    (
+        Decimal128Type::MAX_PRECISION,
+        Decimal128Type::TYPE_CONSTRUCTOR,
+    ),
+    (
+        Decimal256Type::MAX_PRECISION,
+        Decimal256Type::TYPE_CONSTRUCTOR,
+    ),
+];
+
+/// The numeric types, as inputs to [`promote_types`]
+#[derive(Debug, Copy, Clone)]
+enum Numeric {
+    /// An integer of the number of bits, and whether it is signed
+    Integer(u32, bool),
+    /// A float of the number of bits
+    Float(u32),
+    /// A decimal of the index of its type, from `0` for `Decimal32`, precision and scale
+    Decimal(usize, u8, i8),
+}
+
+impl Numeric {
+    /// Returns the [`Numeric`] of `data_type`, which must be numeric
+    fn of(data_type: &DataType) -> Self {
+        match data_type {
+            DataType::Int8 => Self::Integer(8, true),
+            DataType::Int16 => Self::Integer(16, true),
+            DataType::Int32 => Self::Integer(32, true),
+            DataType::Int64 => Self::Integer(64, true),
+            DataType::UInt8 => Self::Integer(8, false),
+            DataType::UInt16 => Self::Integer(16, false),
+            DataType::UInt32 => Self::Integer(32, false),
+            DataType::UInt64 => Self::Integer(64, false),
+            DataType::Float16 => Self::Float(16),
+            DataType::Float32 => Self::Float(32),
+            DataType::Float64 => Self::Float(64),
+            DataType::Decimal32(p, s) => Self::Decimal(0, *p, *s),
+            DataType::Decimal64(p, s) => Self::Decimal(1, *p, *s),
+            DataType::Decimal128(p, s) => Self::Decimal(2, *p, *s),
+            DataType::Decimal256(p, s) => Self::Decimal(3, *p, *s),
+            d => unreachable!("{d} is not numeric"),
+        }
+    }
+
+    /// Returns the decimal type index, precision and scale of an integer or decimal
+    fn decimal(self) -> (usize, u8, i8) {
+        match self {
+            Self::Integer(bits, signed) => {
+                // The number of decimal digits of the largest value
+                let precision = match (bits, signed) {
+                    (8, _) => 3,
+                    (16, _) => 5,
+                    (32, _) => 10,
+                    (_, true) => 19,
+                    (_, false) => 20,
+                };
+                (0, precision, 0)
+            }
+            Self::Decimal(width, precision, scale) => (width, precision, scale),
+            Self::Float(_) => unreachable!("float is not a decimal"),
+        }
+    }
+}
+
+fn integer_type(bits: u32, signed: bool) -> DataType {
+    match (bits, signed) {
+        (8, true) => DataType::Int8,
+        (16, true) => DataType::Int16,
+        (32, true) => DataType::Int32,
+        (64, true) => DataType::Int64,
+        (8, false) => DataType::UInt8,
+        (16, false) => DataType::UInt16,
+        (32, false) => DataType::UInt32,
+        _ => DataType::UInt64,
+    }
+}
+
+fn float_type(bits: u32) -> DataType {
+    match bits {
+        16 => DataType::Float16,
+        32 => DataType::Float32,
+        _ => DataType::Float64,
+    }
+}
+
+/// Returns the scale of `data_type`, or `0` if it is not a decimal
+pub(crate) fn decimal_scale(data_type: &DataType) -> i8 {
+    match data_type {
+        DataType::Decimal32(_, s)
+        | DataType::Decimal64(_, s)
+        | DataType::Decimal128(_, s)
+        | DataType::Decimal256(_, s) => *s,
+        _ => 0,
+    }
+}
+
+/// Converts `array` to `to_type`, the result of [`promote_types`] for its type
+pub(crate) fn promote_array(array: &dyn Array, to_type: &DataType) -> Result<ArrayRef, ArrowError> {
+    let scale = decimal_scale(to_type);
+    match to_type {
+        _ if array.data_type() == to_type => Ok(make_array(array.to_data())),
+        DataType::Int16 => Ok(to_primitive::<Int16Type>(array)),
+        DataType::Int32 => Ok(to_primitive::<Int32Type>(array)),
+        DataType::Int64 => Ok(to_primitive::<Int64Type>(array)),
+        DataType::UInt16 => Ok(to_primitive::<UInt16Type>(array)),
+        DataType::UInt32 => Ok(to_primitive::<UInt32Type>(array)),
+        DataType::UInt64 => Ok(to_primitive::<UInt64Type>(array)),
+        DataType::Float16 => Ok(to_primitive::<Float16Type>(array)),
+        DataType::Float32 => Ok(to_primitive::<Float32Type>(array)),
+        DataType::Float64 => Ok(to_primitive::<Float64Type>(array)),
+        DataType::Decimal32(_, _) => to_decimal::<Decimal32Type>(array, to_type, scale),
+        DataType::Decimal64(_, _) => to_decimal::<Decimal64Type>(array, to_type, scale),
+        DataType::Decimal128(_, _) => to_decimal::<Decimal128Type>(array, to_type, scale),
+        DataType::Decimal256(_, _) => to_decimal::<Decimal256Type>(array, to_type, scale),
+        _ => unreachable!("{to_type} is not a promoted type"),
+    }
+}
+
+/// Converts an integer, float or decimal `array` to an integer or float array of `O`
+fn to_primitive<O: ArrowPrimitiveType>(array: &dyn Array) -> ArrayRef
+where
+    i8: AsPrimitive<O::Native>,
+    i16: AsPrimitive<O::Native>,
+    i32: AsPrimitive<O::Native>,
+    i64: AsPrimitive<O::Native>,
+    u8: AsPrimitive<O::Native>,
+    u16: AsPrimitive<O::Native>,
+    u32: AsPrimitive<O::Native>,
+    u64: AsPrimitive<O::Native>,
+    f16: AsPrimitive<O::Native>,
+    f32: AsPrimitive<O::Native>,
+    f64: AsPrimitive<O::Native>,
+{
+    macro_rules! convert {
+        ($t:ty) => {{
+            let array: PrimitiveArray<O> = array.as_primitive::<$t>().unary(|v| v.as_());
+            Arc::new(array)
+        }};
+    }
+    macro_rules! decimal {
+        ($t:ty, $scale:expr) => {{
+            let div = 10_f64.powi(*$scale as i32);
+            let array: PrimitiveArray<O> = array
+                .as_primitive::<$t>()
+                .unary(|v| (v.to_f64().unwrap_or_default() / div).as_());
+            Arc::new(array)
+        }};
+    }
+    match array.data_type() {
+        DataType::Int8 => convert!(Int8Type),
+        DataType::Int16 => convert!(Int16Type),
+        DataType::Int32 => convert!(Int32Type),
+        DataType::Int64 => convert!(Int64Type),
+        DataType::UInt8 => convert!(UInt8Type),
+        DataType::UInt16 => convert!(UInt16Type),
+        DataType::UInt32 => convert!(UInt32Type),
+        DataType::UInt64 => convert!(UInt64Type),
+        DataType::Float16 => convert!(Float16Type),
+        DataType::Float32 => convert!(Float32Type),
+        DataType::Float64 => convert!(Float64Type),
+        DataType::Decimal32(_, s) => decimal!(Decimal32Type, s),
+        DataType::Decimal64(_, s) => decimal!(Decimal64Type, s),
+        DataType::Decimal128(_, s) => decimal!(Decimal128Type, s),
+        DataType::Decimal256(_, s) => decimal!(Decimal256Type, s),
+        d => unreachable!("{d} is not numeric"),
+    }
+}
+
+/// The native type of a decimal type
+trait DecimalNative: ArrowNativeTypeOp {
+    /// Returns `v` as `Self`, or `None` if it is out of range
+    fn from_i128(v: i128) -> Option<Self>;
+}
+
+impl DecimalNative for i32 {
+    fn from_i128(v: i128) -> Option<Self> {
+        v.try_into().ok()
+    }
+}
+
+impl DecimalNative for i64 {
+    fn from_i128(v: i128) -> Option<Self> {
+        v.try_into().ok()
+    }
+}
+
+impl DecimalNative for i128 {
+    fn from_i128(v: i128) -> Option<Self> {
+        Some(v)
+    }
+}
+
+impl DecimalNative for i256 {
+    fn from_i128(v: i128) -> Option<Self> {
+        Some(i256::from_i128(v))
+    }
+}
+
+/// Converts an integer or decimal `array` to a decimal array of `T` with `to_type` and `scale`
+fn to_decimal<T>(array: &dyn Array, to_type: &DataType, scale: i8) -> Result<ArrayRef, ArrowError>
+where
+    T: DecimalType,
+    T::Native: DecimalNative,
+{
+    let overflow = || {
+        ArrowError::ComputeError(format!(
+            "Overflow converting {} to {to_type}",
+            array.data_type()
+        ))
+    };
+    let mul =
+        T::Native::usize_as(10).pow_checked((scale - decimal_scale(array.data_type())) as _)?;
+
+    macro_rules! convert {
+        ($t:ty) => {
+            array.as_primitive::<$t>().try_unary::<_, T, _>(|v| {
+                T::Native::from_i128(v.as_())
+                    .ok_or_else(overflow)?
+                    .mul_checked(mul)
+            })?
+        };
+    }
+    let array: PrimitiveArray<T> = match array.data_type() {
+        DataType::Int8 => convert!(Int8Type),
+        DataType::Int16 => convert!(Int16Type),
+        DataType::Int32 => convert!(Int32Type),
+        DataType::Int64 => convert!(Int64Type),
+        DataType::UInt8 => convert!(UInt8Type),
+        DataType::UInt16 => convert!(UInt16Type),
+        DataType::UInt32 => convert!(UInt32Type),
+        DataType::UInt64 => convert!(UInt64Type),
+        DataType::Decimal32(_, _) => convert!(Decimal32Type),
+        DataType::Decimal64(_, _) => convert!(Decimal64Type),
+        DataType::Decimal128(_, _) => convert!(Decimal128Type),
+        // Decimal256 is only promoted to Decimal256
+        DataType::Decimal256(_, _) => array
+            .as_primitive::<T>()
+            .try_unary::<_, T, _>(|v| v.mul_checked(mul))?,
+        d => unreachable!("{d} is not promoted to a decimal"),
+    };
+    Ok(Arc::new(array.with_data_type(to_type.clone())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use DataType::*;
+
+    #[test]
+    fn test_promote_types() {
+        let cases = [
+            (Int8, Int8, Int8),
+            (Int8, Int32, Int32),
+            (UInt16, UInt64, UInt64),
+            (Int16, UInt8, Int16),
+            (Int8, UInt8, Int16),
+            (UInt32, Int32, Int64),
+            (UInt64, Int8, Decimal128(20, 0)),
+            (Float16, Float64, Float64),
+            (Int8, Float16, Float16),
+            (UInt16, Float16, Float32),
+            (Int32, Float32, Float64),
+            (Int64, Float64, Float64),
+            (Float64, Int8, Float64),
+            (Int32, Decimal128(10, 2), Decimal128(12, 2)),
+            (Int8, Decimal32(2, 1), Decimal32(4, 1)),
+            (Int32, Decimal32(5, 2), Decimal64(12, 2)),
+            (UInt64, Decimal128(38, 20), Decimal256(40, 20)),
+            (Decimal128(10, 2), Decimal128(12, 5), Decimal128(13, 5)),
+            (Decimal64(18, 2), Decimal32(9, 8), Decimal128(24, 8)),
+            (Decimal32(4, -2), Decimal32(3, 1), Decimal32(7, 1)),
+            (Decimal256(10, 2), Decimal32(3, 1), Decimal256(10, 2)),
+            (Decimal128(10, 2), Float32, Float64),
+        ];
+        for (l, r, expected) in cases {
+            assert_eq!(promote_types(&l, &r).unwrap(), expected, "{l} {r}");
+            assert_eq!(promote_types(&r, &l).unwrap(), expected, "{r} {l}");
+        }
+
+        let err = promote_types(&Decimal256(76, 0), &Decimal256(76, 76)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot promote Decimal256(76, 0) and Decimal256(76, 76) to a common numeric type"
+        );
+        let err = promote_types(&Utf8, &Utf8).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot promote Utf8 and Utf8 to a common numeric type"
+        );
+    }
+
+    #[test]
+    fn test_promote_array() {
+        let a = Int8Array::from(vec![Some(-3), None, Some(127)]);
+        let r = promote_array(&a, &Float16).unwrap();
+        let expected = [-3.0, 0.0, 127.0].map(f16::from_f32);
+        assert_eq!(r.as_primitive::<Float16Type>().values(), &expected);
+        assert_eq!(r.null_count(), 1);
+
+        let r = promote_array(&a, &Decimal32(5, 2)).unwrap();
+        assert_eq!(r.data_type(), &Decimal32(5, 2));
+        assert_eq!(
+            r.as_primitive::<Decimal32Type>().values(),
+            &[-300, 0, 12700]
+        );
+
+        let a = Decimal128Array::from(vec![12345, -1])
+            .with_precision_and_scale(10, 2)
+            .unwrap();
+        let r = promote_array(&a, &Decimal256(12, 4)).unwrap();
+        let values = r.as_primitive::<Decimal256Type>().values();
+        assert_eq!(values, &[i256::from(1234500), i256::from(-100)]);
+        let r = promote_array(&a, &Float64).unwrap();
+        assert_eq!(r.as_primitive::<Float64Type>().values(), &[123.45, -0.01]);
+
+        let a = Decimal32Array::from(vec![i32::MAX])
+            .with_precision_and_scale(9, 0)
+            .unwrap();
+        let err = promote_array(&a, &Decimal32(9, 2)).unwrap_err();
+        assert!(err.to_string().contains("Overflow"), "{err}");
+    }
+}
@@ -22,7 +22,9 @@ use std::sync::Arc;
 
 use arrow_array::cast::AsArray;
 use cast::as_primitive_array;
-use chrono::{Datelike, TimeZone, Timelike, Utc};
+use chrono::{Datelike, Months, TimeZone, Timelike, Utc, Weekday};
+
+use crate::arity;
 
 use arrow_array::temporal_conversions::{
     MICROSECONDS, MICROSECONDS_IN_DAY, MILLISECONDS, MILLISECONDS_IN_DAY, NANOSECONDS,
@@ -33,6 +35,7 @@ use arrow_array::temporal_conversions::{
 use arrow_array::timezone::Tz;
 use arrow_array::types::*;
 use arrow_array::*;
+use arrow_buffer::BooleanBuffer;
 use arrow_schema::{ArrowError, DataType, IntervalUnit, TimeUnit};
 
 /// Valid parts to extract from date/time/timestamp arrays.
@@ -735,6 +738,289 @@ macro_rules! return_compute_error_with {
 
 pub(crate) use return_compute_error_with;
 
+/// Returns the number of whole `part`s between the dates in `start` and `end`,
+/// following calendar semantics.
+///
+/// Supported parts are:
+/// - [`DatePart::Year`]: the number of whole years, i.e. whole months divided by 12
+/// - [`DatePart::Month`]: the largest number of months that can be added to `start`
+///   without passing `end`, where adding months to the last days of a month clamps
+///   to the end of the target month, e.g. `2024-01-31` to `2024-02-29` is one month
+/// - [`DatePart::Week`]: the number of whole weeks, i.e. days divided by 7
+/// - [`DatePart::Day`]: the number of days
+///
+/// The result is negative if `end` is before `start`, with
+/// `date_diff(part, start, end) == -date_diff(part, end, start)`.
+///
+/// `start` and `end` must be [`Date32Array`] or [`Date64Array`] of the same length.
+///
+/// ```
+/// # use arrow_array::{Date32Array, Int32Array};
+/// # use arrow_arith::temporal::{date_diff, DatePart};
+/// // 2024-01-31 and 2024-03-30
+/// let start = Date32Array::from(vec![19753]);
+/// let end = Date32Array::from(vec![19812]);
+/// assert_eq!(date_diff(DatePart::Month, &start, &end).unwrap(), Int32Array::from(vec![1]));
+/// assert_eq!(date_diff(DatePart::Week, &start, &end).unwrap(), Int32Array::from(vec![8]));
+/// assert_eq!(date_diff(DatePart::Day, &end, &start).unwrap(), Int32Array::from(vec![-59]));
+/// ```
+pub fn date_diff(
+    part: DatePart,
+    start: &dyn Array,
+    end: &dyn Array,
+) -> Result<Int32Array, ArrowError> {
+    let diff: fn(i32, i32) -> Option<i32> = match part {
+        DatePart::Year => |s, e| months_between(s, e).map(|m| m / 12),
+        DatePart::Month => months_between,
+        DatePart::Week => |s, e| e.checked_sub(s).map(|d| d / 7),
+        DatePart::Day => |s, e| e.checked_sub(s),
+        _ => return_compute_error_with!("date_diff does not support", part),
+    };
+    let start = to_date32(start)?;
+    let end = to_date32(end)?;
+    arity::try_binary(&start, &end, |s, e| {
+        diff(s, e).ok_or_else(|| {
+            ArrowError::ComputeError(format!("Overflow computing {part} difference"))
+        })
+    })
+}
+
+/// Returns the largest number of months that can be added to `start` without
+/// passing `end`, negated if `end < start`
+fn months_between(start: i32, end: i32) -> Option<i32> {
+    if end < start {
+        return months_between(end, start).map(|m| -m);
+    }
+    let s = Date32Type::to_naive_date_opt(start)?;
+    let e = Date32Type::to_naive_date_opt(end)?;
+    let months = (e.year() - s.year()) * 12 + e.month() as i32 - s.month() as i32;
+    match s.checked_add_months(Months::new(months as u32))? > e {
+        true => Some(months - 1),
+        false => Some(months),
+    }
+}
+
+/// Converts a [`Date32Array`] or [`Date64Array`] to a [`Date32Array`]
+fn to_date32(array: &dyn Array) -> Result<Date32Array, ArrowError> {
+    match array.data_type() {
+        DataType::Date32 => Ok(array.as_primitive::<Date32Type>().clone()),
+        DataType::Date64 => array.as_primitive::<Date64Type>().try_unary(|ms| {
+            i32::try_from(ms.div_euclid(MILLISECONDS_IN_DAY)).map_err(|_| {
+                ArrowError::ComputeError(format!("Date64 value {ms} out of range of Date32"))
+            })
+        }),
+        t => return_compute_error_with!("date_diff does not support", t),
+    }
+}
+
+/// A calendar of business days, used by [`add_business_days`] and [`business_day_diff`]
+///
+/// A day is a business day if it is not a weekend day, by default Saturday and
+/// Sunday, and not a holiday
+///
+/// ```
+/// # use arrow_arith::temporal::BusinessCalendar;
+/// # use arrow_buffer::BooleanBuffer;
+/// # use chrono::Weekday;
+/// // 2024-01-01 was a Monday, and a holiday
+/// let holidays = BooleanBuffer::collect_bool(366, |i| i == 0);
+/// let calendar = BusinessCalendar::new()
+///     .with_weekend([Weekday::Fri, Weekday::Sat])
+///     .with_holidays(19723, holidays);
+/// assert!(!calendar.is_business_day(19723));
+/// assert!(calendar.is_business_day(19729)); // Sunday
+/// assert!(!calendar.is_business_day(19728)); // Saturday
+/// ```
+#[derive(Debug, Clone)]
+pub struct BusinessCalendar {
+    /// Whether each day of the week, starting from Monday, is a weekend day
+    weekend: [bool; 7],
+    /// The number of non-weekend days in a week
+    days_per_week: i64,
+    /// The first date, as days since the UNIX epoch, covered by `holidays`
+    holidays_start: i64,
+    /// Bit `i` is set if `holidays_start + i` is a holiday
+    holidays: BooleanBuffer,
+}
+
+impl Default for BusinessCalendar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BusinessCalendar {
+    /// Create a new [`BusinessCalendar`] with Saturday and Sunday as the weekend,
+    /// and no holidays
+    pub fn new() -> Self {
+        Self {
+            weekend: [false, false, false, false, false, true, true],
+            days_per_week: 5,
+            holidays_start: 0,
+            holidays: BooleanBuffer::new_unset(0),
+        }
+    }
+
+    /// Set the days of the week that are not business days
+    pub fn with_weekend(mut self, weekend: impl IntoIterator<Item = Weekday>) -> Self {
+        self.weekend = [false; 7];
+        for day in weekend {
+            self.weekend[day.num_days_from_monday() as usize] = true;
+        }
+        self.days_per_week = self.weekend.iter().filter(|w| !**w).count() as i64;
+        self
+    }
+
+    /// Set the holidays, where bit `i` of `holidays` is set if the date `start + i`,
+    /// as days since the UNIX epoch, is a holiday
+    ///
+    /// Dates not covered by `holidays` are not holidays
+    pub fn with_holidays(mut self, start: i32, holidays: BooleanBuffer) -> Self {
+        self.holidays_start = start as i64;
+        self.holidays = holidays;
+        self
+    }
+
+    /// Returns true if `date`, as days since the UNIX epoch, is a business day
+    pub fn is_business_day(&self, date: i32) -> bool {
+        self.is_business_day_i64(date as i64)
+    }
+
+    fn is_business_day_i64(&self, date: i64) -> bool {
+        !self.weekend[Self::weekday(date)] && !self.is_holiday(date)
+    }
+
+    /// Returns the day of the week of `date`, where Monday is `0`
+    fn weekday(date: i64) -> usize {
+        // 1970-01-01 was a Thursday
+        (date + 3).rem_euclid(7) as usize
+    }
+
+    fn is_holiday(&self, date: i64) -> bool {
+        let idx = date - self.holidays_start;
+        (0..self.holidays.len() as i64).contains(&idx) && self.holidays.value(idx as usize)
+    }
+
+    /// Returns the number of business days in `[start, end)`
+    fn count(&self, start: i64, end: i64) -> i64 {
+        let days = end - start;
+        let first = Self::weekday(start);
+        let partial = (0..(days % 7) as usize)
+            .filter(|d| !self.weekend[(first + d) % 7])
+            .count() as i64;
+
+        let lo = start.max(self.holidays_start);
+        let hi = end.min(self.holidays_start + self.holidays.len() as i64);
+        let holidays = match lo < hi {
+            true => {
+                let offset = (lo - self.holidays_start) as usize;
+                let holidays = self.holidays.slice(offset, (hi - lo) as usize);
+                holidays
+                    .set_indices()
+                    .filter(|i| !self.weekend[Self::weekday(lo + *i as i64)])
+                    .count() as i64
+            }
+            false => 0,
+        };
+        (days / 7) * self.days_per_week + partial - holidays
+    }
+
+    /// Returns the `n`-th business day after `date`, or before `date` if `n` is negative
+    fn add(&self, date: i64, n: i64) -> Result<i64, ArrowError> {
+        if self.days_per_week == 0 && n != 0 {
+            return Err(ArrowError::ComputeError(
+                "Cannot add business days to a calendar without business days".to_string(),
+            ));
+        }
+        let step = n.signum();
+        let mut current = date;
+        let mut remaining = n.abs();
+        while remaining > 0 {
+            let weeks = remaining / self.days_per_week;
+            if weeks == 0 {
+                current += step;
+                remaining -= self.is_business_day_i64(current) as i64;
+                continue;
+            }
+            let next = current + step * weeks * 7;
+            remaining -= match step > 0 {
+                true => self.count(current + 1, next + 1),
+                false => self.count(next, current),
+            };
+            current = next;
+            if remaining == 0 {
+                // A whole number of weeks may end on a non-business day
+                while !self.is_business_day_i64(current) {
+                    current -= step;
+                }
+            }
+        }
+        Ok(current)
+    }
+}
+
+/// Adds `days` business days, according to `calendar`, to each date in `dates`
+///
+/// For positive `days` the result is the `days`-th business day after the date,
+/// and for negative `days` the `-days`-th business day before it. The date itself
+/// need not be a business day, and is returned unchanged if `days` is zero.
+///
+/// ```
+/// # use arrow_array::{Date32Array, Int32Array};
+/// # use arrow_arith::temporal::{add_business_days, BusinessCalendar};
+/// // 2024-01-05 was a Friday
+/// let dates = Date32Array::from(vec![19727, 19727, 19727]);
+/// let days = Int32Array::from(vec![1, 5, -1]);
+/// let result = add_business_days(&dates, &days, &BusinessCalendar::new()).unwrap();
+/// // Monday 2024-01-08, Friday 2024-01-12 and Thursday 2024-01-04
+/// assert_eq!(result, Date32Array::from(vec![19730, 19734, 19726]));
+/// ```
+pub fn add_business_days(
+    dates: &Date32Array,
+    days: &Int32Array,
+    calendar: &BusinessCalendar,
+) -> Result<Date32Array, ArrowError> {
+    arity::try_binary(dates, days, |date, n| {
+        let result = calendar.add(date as i64, n as i64)?;
+        i32::try_from(result).map_err(|_| {
+            ArrowError::ComputeError(format!("Overflow adding {n} business days to {date}"))
+        })
+    })
+}
+
+/// Returns the number of business days, according to `calendar`, from each date
+/// in `start` up to, but not including, the corresponding date in `end`
+///
+/// The result is negative if `end` is before `start`, with
+/// `business_day_diff(start, end) == -business_day_diff(end, start)`.
+///
+/// ```
+/// # use arrow_array::{Date32Array, Int32Array};
+/// # use arrow_arith::temporal::{business_day_diff, BusinessCalendar};
+/// // From Friday 2024-01-05 to Monday 2024-01-08, and Monday 2024-01-15
+/// let start = Date32Array::from(vec![19727, 19727]);
+/// let end = Date32Array::from(vec![19730, 19737]);
+/// let result = business_day_diff(&start, &end, &BusinessCalendar::new()).unwrap();
+/// assert_eq!(result, Int32Array::from(vec![1, 6]));
+/// ```
+pub fn business_day_diff(
+    start: &Date32Array,
+    end: &Date32Array,
+    calendar: &BusinessCalendar,
+) -> Result<Int32Array, ArrowError> {
+    arity::try_binary(start, end, |s, e| {
+        let (s, e) = (s as i64, e as i64);
+        let count = match s <= e {
+            true => calendar.count(s, e),
+            false => -calendar.count(e, s),
+        };
+        // The count is bounded by the number of days between two i32 dates
+        i32::try_from(count).map_err(|_| {
+            ArrowError::ComputeError(format!("Overflow counting business days from {s} to {e}"))
+        })
+    })
+}
+
 // Internal trait, which is used for mapping values from DateLike structures
 trait ChronoDateExt {
     /// Returns the day of week; Monday is encoded as `0`, Tuesday as `1`, etc.
@@ -2289,4 +2575,127 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_date_diff() {
+        let date = |y, m, d| {
+            Date32Type::from_naive_date(chrono::NaiveDate::from_ymd_opt(y, m, d).unwrap())
+        };
+        let start = Date32Array::from(vec![
+            Some(date(2024, 1, 31)),
+            Some(date(2020, 2, 29)),
+            Some(date(2024, 3, 15)),
+            None,
+            Some(date(2023, 5, 10)),
+        ]);
+        let end = Date32Array::from(vec![
+            Some(date(2024, 2, 29)),
+            Some(date(2021, 2, 28)),
+            Some(date(2023, 3, 16)),
+            Some(date(2024, 1, 1)),
+            Some(date(2023, 5, 10)),
+        ]);
+
+        let months = date_diff(DatePart::Month, &start, &end).unwrap();
+        let expected = Int32Array::from(vec![Some(1), Some(12), Some(-11), None, Some(0)]);
+        assert_eq!(months, expected);
+        let reversed = date_diff(DatePart::Month, &end, &start).unwrap();
+        assert_eq!(reversed, arity::unary(&months, |m: i32| -m));
+
+        let years = date_diff(DatePart::Year, &start, &end).unwrap();
+        let expected = Int32Array::from(vec![Some(0), Some(1), Some(0), None, Some(0)]);
+        assert_eq!(years, expected);
+
+        let weeks = date_diff(DatePart::Week, &start, &end).unwrap();
+        let expected = Int32Array::from(vec![Some(4), Some(52), Some(-52), None, Some(0)]);
+        assert_eq!(weeks, expected);
+
+        // Date64 values are truncated to whole days
+        let start64 = Date64Array::from(vec![0, -1]);
+        let end64 = Date64Array::from(vec![MILLISECONDS_IN_DAY * 3 - 1, 0]);
+        let days = date_diff(DatePart::Day, &start64, &end64).unwrap();
+        assert_eq!(days, Int32Array::from(vec![2, 1]));
+
+        let err = date_diff(DatePart::Hour, &start, &end).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: date_diff does not support: Hour"
+        );
+        let err = date_diff(DatePart::Day, &Int32Array::from(vec![1]), &end).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: date_diff does not support: Int32"
+        );
+    }
+
+    #[test]
+    fn test_business_days() {
+        // Mondays to Thursdays, with holidays on 12 days of the 1000 days
+        // starting at 2024-01-01, some of which fall on a weekend
+        let start = 19723;
+        let holidays = BooleanBuffer::collect_bool(1000, |i| i % 83 == 0);
+        let calendar = BusinessCalendar::new()
+            .with_weekend([Weekday::Fri, Weekday::Sat, Weekday::Sun])
+            .with_holidays(start, holidays.clone());
+
+        let is_business = |d: i32| {
+            let weekday = Date32Type::to_naive_date_opt(d).unwrap().weekday();
+            let idx = d - start;
+            let holiday = (0..1000).contains(&idx) && holidays.value(idx as usize);
+            !matches!(weekday, Weekday::Fri | Weekday::Sat | Weekday::Sun) && !holiday
+        };
+        let naive_diff = |s: i32, e: i32| match s <= e {
+            true => (s..e).filter(|d| is_business(*d)).count() as i32,
+            false => -((e..s).filter(|d| is_business(*d)).count() as i32),
+        };
+        let naive_add = |d: i32, n: i32| {
+            let (mut d, mut remaining) = (d, n.abs());
+            while remaining > 0 {
+                d += n.signum();
+                remaining -= is_business(d) as i32;
+            }
+            d
+        };
+
+        let dates: Vec<i32> = (start - 30..start + 1030).step_by(7).collect();
+        let offsets: Vec<i32> = (0..dates.len() as i32)
+            .map(|i| (i * 37) % 401 - 200)
+            .collect();
+        let dates = Date32Array::from(dates);
+        let offsets = Int32Array::from(offsets);
+        let added = add_business_days(&dates, &offsets, &calendar).unwrap();
+        let expected: Date32Array = arity::binary(&dates, &offsets, naive_add).unwrap();
+        assert_eq!(added, expected);
+
+        let ends = Date32Array::from_iter_values(dates.values().iter().rev().copied());
+        let diff = business_day_diff(&dates, &ends, &calendar).unwrap();
+        let expected: Int32Array = arity::binary(&dates, &ends, naive_diff).unwrap();
+        assert_eq!(diff, expected);
+
+        // Adding the difference to a business day arrives at the end, if a business day
+        for idx in 0..dates.len() {
+            let (s, e) = (dates.value(idx), ends.value(idx));
+            if is_business(s) && is_business(e) && s <= e {
+                assert_eq!(
+                    calendar.add(s as i64, diff.value(idx) as i64).unwrap(),
+                    e as i64
+                );
+            }
+        }
+
+        let calendar = BusinessCalendar::new().with_weekend([
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+            Weekday::Sat,
+            Weekday::Sun,
+        ]);
+        let err = add_business_days(&dates, &offsets, &calendar).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Cannot add business days to a calendar without business days"
+        );
+    }
 }
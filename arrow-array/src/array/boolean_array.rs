@@ -19,6 +19,7 @@ use crate::array::print_long_array;
 use crate::builder::BooleanBuilder;
 use crate::iterator::BooleanIter;
 use crate::{Array, ArrayAccessor, ArrayRef, Scalar};
+use arrow_buffer::bit_iterator::BitSliceIterator;
 use arrow_buffer::{BooleanBuffer, Buffer, MutableBuffer, NullBuffer, bit_util};
 use arrow_data::{ArrayData, ArrayDataBuilder};
 use arrow_schema::DataType;
@@ -212,6 +213,55 @@ impl BooleanArray {
         }
     }
 
+    /// Returns an iterator over the ranges of contiguous `true` values in this array
+    /// as `(start, end)` pairs, where `end` is exclusive
+    ///
+    /// Note: This method does not check for nulls, and null slots with a `true`
+    /// value are included. Clear the values of null slots first, e.g. with
+    /// `prep_null_mask_filter`, if nulls should be treated as `false`
+    ///
+    /// ```
+    /// # use arrow_array::BooleanArray;
+    /// let array = BooleanArray::from(vec![true, true, false, true, false, false, true]);
+    /// let slices: Vec<_> = array.set_slices().collect();
+    /// assert_eq!(slices, vec![(0, 2), (3, 4), (6, 7)]);
+    /// ```
+    pub fn set_slices(&self) -> BitSliceIterator<'_> {
+        self.values.set_slices()
+    }
+
+    /// Returns an iterator over the number of non null, true values in each
+    /// consecutive window of 64 values in this array
+    ///
+    /// The final window covers the remaining `len() % 64` values, if any
+    ///
+    /// See also [`BooleanBuffer::chunk_set_counts`]
+    pub fn chunk_true_counts(&self) -> impl Iterator<Item = u32> + '_ {
+        let values = self.values.bit_chunks();
+        let nulls = self.nulls.as_ref().map(|n| n.inner().bit_chunks());
+        let nulls = nulls
+            .map(|n| n.iter().chain(std::iter::once(n.remainder_bits())))
+            .into_iter()
+            .flatten()
+            .chain(std::iter::repeat(u64::MAX));
+
+        values
+            .iter()
+            .chain(std::iter::once(values.remainder_bits()))
+            .zip(nulls)
+            .take(self.len().div_ceil(64))
+            .map(|(v, n)| (v & n).count_ones())
+    }
+
+    /// Returns the fraction of values in this array that are non null and true,
+    /// or `0.0` if the array is empty
+    pub fn selectivity(&self) -> f64 {
+        match self.is_empty() {
+            true => 0.0,
+            false => self.true_count() as f64 / self.len() as f64,
+        }
+    }
+
     /// Returns the boolean value at index `i`.
     ///
     /// Note: This method does not check for nulls and the value is arbitrary
@@ -1170,6 +1220,37 @@ mod tests {
         assert!(!sliced.value(1));
     }
 
+    #[test]
+    fn test_set_slices_and_chunk_true_counts() {
+        let array = BooleanArray::from_iter((0..200).map(|i| match i % 10 {
+            0 => None,
+            1..=3 => Some(true),
+            _ => Some(i % 20 == 0),
+        }));
+        let slices: Vec<_> = array.slice(100, 30).set_slices().collect();
+        assert_eq!(slices, vec![(1, 4), (11, 14), (21, 24)]);
+
+        let counts: Vec<_> = array.chunk_true_counts().collect();
+        assert_eq!(counts, vec![21, 18, 19, 2]);
+        assert_eq!(counts.iter().sum::<u32>() as usize, array.true_count());
+        assert_eq!(array.selectivity(), 0.3);
+
+        let sliced = array.slice(3, 70);
+        let counts: Vec<_> = sliced.chunk_true_counts().collect();
+        assert_eq!(counts, vec![19, 2]);
+
+        let array = BooleanArray::from(vec![true; 65]);
+        assert_eq!(array.chunk_true_counts().collect::<Vec<_>>(), vec![64, 1]);
+        assert_eq!(array.selectivity(), 1.0);
+        assert_eq!(BooleanArray::from(Vec::<bool>::new()).selectivity(), 0.0);
+        assert_eq!(
+            BooleanArray::new_null(3)
+                .chunk_true_counts()
+                .collect::<Vec<_>>(),
+            vec![0]
+        );
+    }
+
     #[test]
     fn test_has_true_has_false_all_true() {
         let arr = BooleanArray::from(vec![true, true, true]);
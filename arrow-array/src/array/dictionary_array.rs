@@ -390,11 +390,25 @@ impl<K: ArrowDictionaryKeyType> DictionaryArray<K> {
         self.keys.is_empty()
     }
 
-    /// Currently exists for compatibility purposes with Arrow IPC.
+    /// Returns `true` if the values of this dictionary are ordered
+    ///
+    /// See [`Self::with_ordered`]
     pub fn is_ordered(&self) -> bool {
         self.is_ordered
     }
 
+    /// Returns this dictionary with its values marked as ordered or not
+    ///
+    /// Ordered values are strictly increasing and contain no nulls, and so comparing keys
+    /// is equivalent to comparing the values they reference. Kernels may rely on this to
+    /// compare keys directly, producing incorrect results if the values are not ordered.
+    ///
+    /// `arrow_ord::sort::order_dictionary` can be used to establish this ordering
+    pub fn with_ordered(mut self, is_ordered: bool) -> Self {
+        self.is_ordered = is_ordered;
+        self
+    }
+
     /// Return an iterator over the keys (indexes into the dictionary)
     pub fn keys_iter(&self) -> impl Iterator<Item = Option<usize>> + '_ {
         self.keys.iter().map(|key| key.map(|k| k.as_usize()))
@@ -18,7 +18,7 @@
 use crate::array::{get_offsets_from_buffer, make_array, print_long_array};
 use crate::builder::{ArrayBuilder, GenericListBuilder, PrimitiveBuilder};
 use crate::{
-    Array, ArrayAccessor, ArrayRef, ArrowPrimitiveType, FixedSizeListArray,
+    Array, ArrayAccessor, ArrayRef, ArrowPrimitiveType, FixedSizeListArray, GenericListViewArray,
     iterator::GenericListArrayIter, new_empty_array,
 };
 use arrow_buffer::{ArrowNativeType, NullBuffer, OffsetBuffer};
@@ -398,6 +398,45 @@ impl<OffsetSize: OffsetSizeTrait> GenericListArray<OffsetSize> {
         }
     }
 
+    /// Returns a zero-copy slice of this array whose [`Self::values`] only contain the
+    /// values referenced by its [`Self::offsets`], which then start at `0`
+    ///
+    /// Unlike [`Self::slice`], this drops the values before the first and after the last
+    /// list from [`Self::values`], for example to avoid processing the values not referenced
+    /// by a sliced array. Note the values are sliced rather than copied, and so their memory
+    /// is not released.
+    ///
+    /// ```
+    /// # use arrow_array::{Array, ListArray};
+    /// # use arrow_array::types::Int32Type;
+    /// let list = ListArray::from_iter_primitive::<Int32Type, _, _>([
+    ///     Some(vec![Some(0), Some(1)]),
+    ///     Some(vec![Some(2)]),
+    ///     Some(vec![Some(3), Some(4)]),
+    /// ]);
+    /// let sliced = list.slice(1, 1);
+    /// assert_eq!(sliced.values().len(), 5);
+    ///
+    /// let trimmed = sliced.trim();
+    /// assert_eq!(trimmed.values().len(), 1);
+    /// assert_eq!(trimmed.offsets().as_ref(), &[0, 1]);
+    /// assert_eq!(trimmed, sliced);
+    /// ```
+    pub fn trim(&self) -> Self {
+        let start = self.value_offsets[0];
+        let end = self.value_offsets[self.value_offsets.len() - 1];
+        let offsets = self.value_offsets.iter().map(|o| *o - start).collect();
+        Self {
+            data_type: self.data_type.clone(),
+            nulls: self.nulls.clone(),
+            values: self
+                .values
+                .slice(start.as_usize(), (end - start).as_usize()),
+            // Safety: subtracting the first offset preserves the ordering of valid offsets
+            value_offsets: unsafe { OffsetBuffer::new_unchecked(offsets) },
+        }
+    }
+
     /// Creates a [`GenericListArray`] from an iterator of primitive values
     /// # Example
     /// ```
@@ -502,6 +541,46 @@ impl<OffsetSize: OffsetSizeTrait> From<FixedSizeListArray> for GenericListArray<
     }
 }
 
+/// Converts a [`GenericListViewArray`] without copying its values, returning an error if
+/// its non-empty lists are not stored contiguously and in order in its values
+///
+/// See `arrow_cast::cast` to convert any [`GenericListViewArray`]
+impl<OffsetSize: OffsetSizeTrait> TryFrom<GenericListViewArray<OffsetSize>>
+    for GenericListArray<OffsetSize>
+{
+    type Error = ArrowError;
+
+    fn try_from(value: GenericListViewArray<OffsetSize>) -> Result<Self, Self::Error> {
+        let (field, offsets, sizes, values, nulls) = value.into_parts();
+        let zero = OffsetSize::usize_as(0);
+        let start = std::iter::zip(offsets.iter(), sizes.iter())
+            .find_map(|(o, s)| (*s != zero).then_some(*o))
+            .unwrap_or(zero);
+
+        let mut end = start;
+        let mut list_offsets = Vec::with_capacity(offsets.len() + 1);
+        list_offsets.push(zero);
+        for (idx, (offset, size)) in std::iter::zip(offsets.iter(), sizes.iter()).enumerate() {
+            if *size != zero {
+                if *offset != end {
+                    let prefix = OffsetSize::PREFIX;
+                    return Err(ArrowError::InvalidArgumentError(format!(
+                        "Cannot convert {prefix}ListViewArray to {prefix}ListArray without copying, \
+                        list {idx} does not follow the previous list"
+                    )));
+                }
+                end += *size;
+            }
+            list_offsets.push(end - start);
+        }
+
+        let values = values.slice(start.as_usize(), (end - start).as_usize());
+        // Safety: offsets are monotonically increasing as sizes are non-negative
+        let offsets = unsafe { OffsetBuffer::new_unchecked(list_offsets.into()) };
+        Self::try_new(field, offsets, values, nulls)
+    }
+}
+
 impl<OffsetSize: OffsetSizeTrait> GenericListArray<OffsetSize> {
     fn try_new_from_array_data(data: ArrayData) -> Result<Self, ArrowError> {
         let (data_type, len, nulls, offset, mut buffers, mut child_data) = data.into_parts();
@@ -688,7 +767,8 @@ mod tests {
     use crate::cast::AsArray;
     use crate::types::{Int8Type, Int32Type};
     use crate::{
-        BooleanArray, Int8Array, Int8DictionaryArray, Int32Array, Int64Array, StringArray,
+        BooleanArray, Int8Array, Int8DictionaryArray, Int32Array, Int64Array, ListViewArray,
+        StringArray,
     };
     use arrow_buffer::{Buffer, ScalarBuffer, bit_util};
     use arrow_schema::Field;
@@ -1402,4 +1482,63 @@ mod tests {
         assert_eq!(array.value_offsets(), expected_offsets);
         assert_eq!(array.values(), &expected_values);
     }
+
+    #[test]
+    fn test_trim() {
+        // [[0, 1, 2], [3, 4, 5], [6, 7]]
+        let list = create_from_buffers();
+        assert_eq!(list.trim(), list);
+
+        let trimmed = list.slice(1, 1).trim();
+        assert_eq!(trimmed, list.slice(1, 1));
+        assert_eq!(trimmed.offsets().as_ref(), &[0, 3]);
+        assert_eq!(
+            trimmed.values().as_primitive::<Int32Type>().values(),
+            &[3, 4, 5]
+        );
+
+        let trimmed = list.slice(3, 0).trim();
+        assert_eq!(trimmed.len(), 0);
+        assert_eq!(trimmed.values().len(), 0);
+    }
+
+    #[test]
+    fn test_try_from_list_view() {
+        let field = Arc::new(Field::new_list_field(DataType::Int32, true));
+        let values: ArrayRef = Arc::new(Int32Array::from(vec![0, 1, 2, 3, 4, 5, 6, 7]));
+        let list_view = |offsets: Vec<i32>, sizes: Vec<i32>, nulls: Option<NullBuffer>| {
+            ListViewArray::new(
+                field.clone(),
+                offsets.into(),
+                sizes.into(),
+                values.clone(),
+                nulls,
+            )
+        };
+
+        // [[1, 2], null, [], [3, 4, 5]]
+        let nulls = NullBuffer::from(vec![true, false, true, true]);
+        let view = list_view(vec![1, 7, 0, 3], vec![2, 0, 0, 3], Some(nulls.clone()));
+        let list = ListArray::try_from(view.clone()).unwrap();
+        assert_eq!(list.offsets().as_ref(), &[0, 2, 2, 2, 5]);
+        assert_eq!(
+            list.values().as_primitive::<Int32Type>().values(),
+            &[1, 2, 3, 4, 5]
+        );
+        assert_eq!(list.nulls(), Some(&nulls));
+        assert_eq!(ListViewArray::from(list), view);
+
+        // out of order and overlapping lists require copying
+        for (offsets, sizes) in [(vec![3, 0], vec![2, 3]), (vec![0, 1], vec![2, 2])] {
+            let err = ListArray::try_from(list_view(offsets, sizes, None)).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "Invalid argument error: Cannot convert ListViewArray to ListArray without copying, list 1 does not follow the previous list"
+            );
+        }
+
+        let list = ListArray::try_from(list_view(vec![5, 5], vec![0, 0], None)).unwrap();
+        assert_eq!(list.offsets().as_ref(), &[0, 0, 0]);
+        assert_eq!(list.values().len(), 0);
+    }
 }
@@ -16,6 +16,7 @@
 // under the License.
 
 use crate::array::print_long_array;
+use crate::record_batch::rename_fields;
 use crate::{Array, ArrayRef, RecordBatch, make_array, new_null_array};
 use arrow_buffer::{BooleanBuffer, Buffer, NullBuffer};
 use arrow_data::{ArrayData, ArrayDataBuilder};
@@ -340,6 +341,98 @@ impl StructArray {
         self.fields().find(field_name).map(|(_, field)| field)
     }
 
+    /// Replace the child at `index` with `column`, described by `field`
+    ///
+    /// The other children and the null buffer of this [`StructArray`] are preserved
+    ///
+    /// Returns an error if `index` is out of bounds, or for any reason that
+    /// [`Self::try_new_with_length`] would error
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{Array, Int32Array, Int64Array, StructArray};
+    /// # use arrow_schema::{DataType, Field};
+    /// let array = StructArray::from(vec![(
+    ///     Arc::new(Field::new("a", DataType::Int32, false)),
+    ///     Arc::new(Int32Array::from(vec![1, 2, 3])) as _,
+    /// )]);
+    /// let field = Field::new("a", DataType::Int64, false);
+    /// let array = array
+    ///     .set_column(0, field, Arc::new(Int64Array::from(vec![4, 5, 6])))
+    ///     .unwrap();
+    /// assert_eq!(array.field(0).data_type(), &DataType::Int64);
+    /// ```
+    pub fn set_column(
+        self,
+        index: usize,
+        field: impl Into<FieldRef>,
+        column: ArrayRef,
+    ) -> Result<Self, ArrowError> {
+        self.check_column_index(index, self.num_columns())?;
+        let len = self.len;
+        let (fields, mut columns, nulls) = self.into_parts();
+        let mut fields: Vec<FieldRef> = fields.iter().cloned().collect();
+        fields[index] = field.into();
+        columns[index] = column;
+        Self::try_new_with_length(fields.into(), columns, nulls, len)
+    }
+
+    /// Insert `column`, described by `field`, at `index`, shifting all children
+    /// after it to the right
+    ///
+    /// The null buffer of this [`StructArray`] is preserved
+    ///
+    /// Returns an error if `index > self.num_columns()`, or for any reason that
+    /// [`Self::try_new_with_length`] would error
+    pub fn add_column_at(
+        self,
+        index: usize,
+        field: impl Into<FieldRef>,
+        column: ArrayRef,
+    ) -> Result<Self, ArrowError> {
+        self.check_column_index(index, self.num_columns() + 1)?;
+        let len = self.len;
+        let (fields, mut columns, nulls) = self.into_parts();
+        let mut fields: Vec<FieldRef> = fields.iter().cloned().collect();
+        fields.insert(index, field.into());
+        columns.insert(index, column);
+        Self::try_new_with_length(fields.into(), columns, nulls, len)
+    }
+
+    /// Rename the children of this [`StructArray`] according to `names`, a mapping
+    /// from the current field name to the new field name
+    ///
+    /// The children, along with the data type, nullability and metadata of each
+    /// field, are preserved. Fields not present in `names` keep their current name
+    ///
+    /// Returns an error if a name in `names` does not match any field
+    pub fn rename_columns<I, K, V>(self, names: I) -> Result<Self, ArrowError>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: Into<String>,
+    {
+        let fields = rename_fields(self.fields(), names)?;
+        Ok(Self {
+            len: self.len,
+            data_type: DataType::Struct(fields),
+            nulls: self.nulls,
+            fields: self.fields,
+        })
+    }
+
+    fn check_column_index(&self, index: usize, len: usize) -> Result<(), ArrowError> {
+        match index < len {
+            true => Ok(()),
+            false => Err(ArrowError::InvalidArgumentError(format!(
+                "column index {index} out of bounds for struct array with {} fields",
+                self.num_columns()
+            ))),
+        }
+    }
+
     /// Returns a zero-copy slice of this array with the indicated offset and length.
     pub fn slice(&self, offset: usize, len: usize) -> Self {
         assert!(
@@ -1192,4 +1285,41 @@ mod tests {
         assert!(fields[0].is_nullable()); // was false, now true
         assert!(fields[1].is_nullable()); // was true, stays true
     }
+
+    #[test]
+    fn test_set_add_rename_columns() {
+        let ints: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+        let nulls = NullBuffer::from(vec![true, false, true]);
+        let array = StructArray::new(
+            Fields::from(vec![Field::new("a", DataType::Int32, false)]),
+            vec![ints.clone()],
+            Some(nulls.clone()),
+        );
+
+        let strings: ArrayRef = Arc::new(StringArray::from(vec!["x", "y", "z"]));
+        let array = array
+            .add_column_at(1, Field::new("b", DataType::Utf8, false), strings.clone())
+            .unwrap();
+        let array = array
+            .set_column(0, Field::new("c", DataType::Int32, true), ints.clone())
+            .unwrap();
+        let array = array.rename_columns([("b", "d")]).unwrap();
+        assert_eq!(array.column_names(), vec!["c", "d"]);
+        assert!(Arc::ptr_eq(array.column(0), &ints));
+        assert!(Arc::ptr_eq(array.column(1), &strings));
+        assert_eq!(array.nulls(), Some(&nulls));
+
+        let err = array.clone().rename_columns([("b", "x")]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: cannot rename unknown column 'b'"
+        );
+        let err = array
+            .add_column_at(3, Field::new("x", DataType::Int32, false), ints)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: column index 3 out of bounds for struct array with 2 fields"
+        );
+    }
 }
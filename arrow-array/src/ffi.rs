@@ -1858,6 +1858,56 @@ mod tests_from_ffi {
         );
     }
 
+    #[test]
+    fn test_round_trip_sliced_view_and_run_end_layouts() -> Result<()> {
+        use crate::builder::{ListViewBuilder, StringViewBuilder};
+        use crate::types::Int16Type;
+        use crate::{BinaryViewArray, RunArray};
+
+        // A string view array with several variadic buffers
+        let mut builder = StringViewBuilder::new().with_fixed_block_size(32);
+        for i in 0..20 {
+            match i % 4 {
+                0 => builder.append_null(),
+                1 => builder.append_value(format!("inlined {i}")),
+                _ => builder.append_value(format!("a string view longer than inline {i}")),
+            }
+        }
+        let strings = builder.finish();
+        assert!(strings.data_buffers().len() > 2);
+
+        let binaries =
+            BinaryViewArray::from_iter(strings.iter().map(|s| s.map(|s| s.repeat(2).into_bytes())));
+
+        let mut builder = ListViewBuilder::new(Int32Array::builder(0));
+        for i in 0..10 {
+            match i % 3 {
+                0 => builder.append_null(),
+                _ => builder.append_value((0..i).map(Some)),
+            }
+        }
+        let list_views = builder.finish();
+
+        let runs = RunArray::<Int16Type>::try_new(
+            &crate::Int16Array::from(vec![2, 5, 6, 10]),
+            &StringArray::from(vec![Some("a"), None, Some("b"), Some("c")]),
+        )
+        .unwrap();
+
+        let arrays: [ArrayRef; 4] = [
+            Arc::new(strings),
+            Arc::new(binaries),
+            Arc::new(list_views),
+            Arc::new(runs),
+        ];
+        for array in arrays {
+            test_round_trip(&array.to_data())?;
+            test_round_trip(&array.slice(3, 5).to_data())?;
+            test_round_trip(&array.slice(array.len(), 0).to_data())?;
+        }
+        Ok(())
+    }
+
     /// Helper trait to allow us to use easily strings as either BinaryViewType::Native or
     /// StringViewType::Native scalars.
     trait NativeFromStr {
@@ -161,10 +161,12 @@
 //!     array.as_primitive::<Float32Type>().values()
 //! }
 //! ```
-//! # Alternatives to ChunkedArray Support
+//! # ChunkedArray Support
 //!
-//! The Rust implementation does not provide the ChunkedArray abstraction implemented by the Python
-//! and C++ Arrow implementations. The recommended alternative is to use one of the following:
+//! This crate does not provide the ChunkedArray abstraction implemented by the Python and C++
+//! Arrow implementations. A simple version, along with a `Table` of [`RecordBatch`], is provided
+//! by [`arrow_select::chunked`] instead. Alternatively, data split across multiple arrays can be
+//! represented with one of the following:
 //! - `Vec<ArrayRef>` a simple, eager version of a `ChunkedArray`
 //! - `impl Iterator<Item=ArrayRef>` a lazy version of a `ChunkedArray`
 //! - `impl Stream<Item=ArrayRef>` a lazy async version of a `ChunkedArray`
@@ -220,6 +222,7 @@
 //! [`csv`]: https://docs.rs/arrow/latest/arrow/csv/index.html
 //! [DataFusion]: https://github.com/apache/arrow-datafusion
 //! [RecordBatchStream]: https://docs.rs/datafusion/latest/datafusion/execution/trait.RecordBatchStream.html
+//! [`arrow_select::chunked`]: https://docs.rs/arrow-select/latest/arrow_select/chunked/index.html
 
 #![doc(
     html_logo_url = "https://arrow.apache.org/img/arrow-logo_chevrons_black-txt_white-bg.svg",
@@ -20,7 +20,9 @@
 
 use crate::cast::AsArray;
 use crate::{Array, ArrayRef, StructArray, new_empty_array};
-use arrow_schema::{ArrowError, DataType, Field, FieldRef, Schema, SchemaBuilder, SchemaRef};
+use arrow_schema::{
+    ArrowError, DataType, Field, FieldRef, Fields, Schema, SchemaBuilder, SchemaRef,
+};
 use std::ops::Index;
 use std::sync::Arc;
 
@@ -672,6 +674,125 @@ impl RecordBatch {
         self.columns.remove(index)
     }
 
+    /// Replace the column at `index` with `column`, described by `field`
+    ///
+    /// The other columns and the schema metadata are preserved
+    ///
+    /// Returns an error if `index` is out of bounds, or `column` does not match
+    /// `field` or the row count of this [`RecordBatch`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{record_batch, Int64Array};
+    /// # use arrow_schema::{DataType, Field};
+    /// let batch = record_batch!(("a", Int32, [1, 2, 3]), ("b", Utf8, ["x", "y", "z"])).unwrap();
+    /// let field = Field::new("a", DataType::Int64, false);
+    /// let batch = batch
+    ///     .set_column(0, field, Arc::new(Int64Array::from(vec![4, 5, 6])))
+    ///     .unwrap();
+    /// assert_eq!(batch.schema().field(0).data_type(), &DataType::Int64);
+    /// ```
+    pub fn set_column(
+        self,
+        index: usize,
+        field: impl Into<FieldRef>,
+        column: ArrayRef,
+    ) -> Result<Self, ArrowError> {
+        self.check_column_index(index, self.num_columns())?;
+        let mut fields: Vec<FieldRef> = self.schema.fields().iter().cloned().collect();
+        let mut columns = self.columns;
+        fields[index] = field.into();
+        columns[index] = column;
+        let schema = Schema::new_with_metadata(fields, self.schema.metadata().clone());
+        let options = RecordBatchOptions::new().with_row_count(Some(self.row_count));
+        Self::try_new_impl(Arc::new(schema), columns, &options)
+    }
+
+    /// Insert `column`, described by `field`, at `index`, shifting all columns
+    /// after it to the right
+    ///
+    /// The schema metadata is preserved
+    ///
+    /// Returns an error if `index > self.num_columns()`, or `column` does not match
+    /// `field` or the row count of this [`RecordBatch`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{record_batch, BooleanArray};
+    /// # use arrow_schema::{DataType, Field};
+    /// let batch = record_batch!(("a", Int32, [1, 2, 3]), ("b", Utf8, ["x", "y", "z"])).unwrap();
+    /// let field = Field::new("flag", DataType::Boolean, false);
+    /// let batch = batch
+    ///     .add_column_at(1, field, Arc::new(BooleanArray::from(vec![true, false, true])))
+    ///     .unwrap();
+    /// assert_eq!(batch.schema().field(1).name(), "flag");
+    /// assert_eq!(batch.num_columns(), 3);
+    /// ```
+    pub fn add_column_at(
+        self,
+        index: usize,
+        field: impl Into<FieldRef>,
+        column: ArrayRef,
+    ) -> Result<Self, ArrowError> {
+        self.check_column_index(index, self.num_columns() + 1)?;
+        let mut fields: Vec<FieldRef> = self.schema.fields().iter().cloned().collect();
+        let mut columns = self.columns;
+        fields.insert(index, field.into());
+        columns.insert(index, column);
+        let schema = Schema::new_with_metadata(fields, self.schema.metadata().clone());
+        let options = RecordBatchOptions::new().with_row_count(Some(self.row_count));
+        Self::try_new_impl(Arc::new(schema), columns, &options)
+    }
+
+    /// Rename the columns of this [`RecordBatch`] according to `names`, a mapping
+    /// from the current column name to the new column name
+    ///
+    /// Only the schema is rebuilt; the columns, along with the data type, nullability
+    /// and metadata of each field, are preserved. Columns not present in `names`
+    /// keep their current name
+    ///
+    /// Returns an error if a name in `names` does not match any column
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # use arrow_array::record_batch;
+    /// let batch = record_batch!(("a", Int32, [1, 2, 3]), ("b", Utf8, ["x", "y", "z"])).unwrap();
+    /// let names = HashMap::from([("a", "id"), ("b", "name")]);
+    /// let batch = batch.rename_columns(names).unwrap();
+    /// assert_eq!(batch.schema().field(0).name(), "id");
+    /// assert_eq!(batch.schema().field(1).name(), "name");
+    /// ```
+    pub fn rename_columns<I, K, V>(self, names: I) -> Result<Self, ArrowError>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: Into<String>,
+    {
+        let fields = rename_fields(self.schema.fields(), names)?;
+        let schema = Schema::new_with_metadata(fields, self.schema.metadata().clone());
+        Ok(Self {
+            schema: Arc::new(schema),
+            columns: self.columns,
+            row_count: self.row_count,
+        })
+    }
+
+    fn check_column_index(&self, index: usize, len: usize) -> Result<(), ArrowError> {
+        match index < len {
+            true => Ok(()),
+            false => Err(ArrowError::InvalidArgumentError(format!(
+                "column index {index} out of bounds for record batch with {} columns",
+                self.num_columns()
+            ))),
+        }
+    }
+
     /// Return a new RecordBatch where each column is sliced
     /// according to `offset` and `length`
     ///
@@ -815,6 +936,25 @@ impl RecordBatch {
     }
 }
 
+/// Returns `fields` with the fields named by the keys of `names` renamed to the
+/// corresponding values, erroring if a key does not match any field
+pub(crate) fn rename_fields<I, K, V>(fields: &Fields, names: I) -> Result<Fields, ArrowError>
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: AsRef<str>,
+    V: Into<String>,
+{
+    let mut renamed: Vec<FieldRef> = fields.iter().cloned().collect();
+    for (from, to) in names {
+        let from = from.as_ref();
+        let (idx, field) = fields.find(from).ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!("cannot rename unknown column '{from}'"))
+        })?;
+        renamed[idx] = Arc::new(field.as_ref().clone().with_name(to));
+    }
+    Ok(renamed.into())
+}
+
 /// Options that control the behaviour used when creating a [`RecordBatch`].
 #[derive(Debug)]
 #[non_exhaustive]
@@ -1764,6 +1904,82 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_set_add_rename_columns() {
+        let metadata = HashMap::from([("foo".to_string(), "bar".to_string())]);
+        let field_metadata = HashMap::from([("k".to_string(), "v".to_string())]);
+        let schema = Schema::new(vec![
+            Field::new("id", DataType::Int32, false).with_metadata(field_metadata.clone()),
+            Field::new("bool", DataType::Boolean, false),
+        ])
+        .with_metadata(metadata);
+        let id: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+        let batch = RecordBatch::try_new(
+            Arc::new(schema),
+            vec![
+                id.clone(),
+                Arc::new(BooleanArray::from(vec![true, false, true])),
+            ],
+        )
+        .unwrap();
+
+        let strings: ArrayRef = Arc::new(StringArray::from(vec!["a", "b", "c"]));
+        let batch = batch
+            .set_column(1, Field::new("s", DataType::Utf8, false), strings.clone())
+            .unwrap();
+        let batch = batch
+            .add_column_at(
+                0,
+                Field::new("n", DataType::Int64, true),
+                Arc::new(Int64Array::from(vec![None, Some(1), None])),
+            )
+            .unwrap();
+        assert_eq!(batch.num_columns(), 3);
+        assert_eq!(batch.schema().field(0).name(), "n");
+        assert!(Arc::ptr_eq(batch.column(1), &id));
+        assert!(Arc::ptr_eq(batch.column(2), &strings));
+        assert_eq!(batch.schema().metadata()["foo"], "bar");
+
+        let batch = batch.rename_columns([("id", "key")]).unwrap();
+        assert_eq!(batch.schema().field(1).name(), "key");
+        assert_eq!(batch.schema().field(1).metadata(), &field_metadata);
+        assert!(Arc::ptr_eq(batch.column(1), &id));
+
+        let err = batch.clone().rename_columns([("id", "x")]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: cannot rename unknown column 'id'"
+        );
+
+        let err = batch
+            .clone()
+            .set_column(3, Field::new("x", DataType::Int32, false), id.clone())
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: column index 3 out of bounds for record batch with 3 columns"
+        );
+
+        let short: ArrayRef = Arc::new(Int32Array::from(vec![1, 2]));
+        let err = batch
+            .clone()
+            .add_column_at(3, Field::new("x", DataType::Int32, false), short)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: all columns in a record batch must have the specified row count"
+        );
+
+        let err = batch
+            .set_column(0, Field::new("x", DataType::Int64, false), id)
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("column types must match schema types"),
+            "{err}"
+        );
+    }
+
     #[test]
     fn test_normalize_nullable_struct() {
         let child = Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef;
@@ -1295,6 +1295,37 @@ fn ensure_names_match(
     }
 }
 
+/// Returns the attributes of `schema` if it is annotated with the `decimal` logical type
+fn decimal_attributes<'s, 'a>(schema: &'s Schema<'a>) -> Option<&'s Attributes<'a>> {
+    let attributes = match schema {
+        Schema::Type(t) => &t.attributes,
+        Schema::Complex(ComplexType::Fixed(f)) => &f.attributes,
+        _ => return None,
+    };
+    (attributes.logical_type == Some("decimal")).then_some(attributes)
+}
+
+/// Checks that decimals written with the writer's precision and scale can be read
+/// with the reader's.
+///
+/// The specification states that "two schemas that are decimal logical types match if
+/// their scales and precisions match". This is relaxed to also accept a reader precision
+/// greater than the writer's, as every value written then fits the reader's type
+/// unchanged, while the scales must still be equal.
+///
+/// <https://avro.apache.org/docs/1.11.1/specification/#decimal>
+fn ensure_decimals_compatible(writer: &Attributes, reader: &Attributes) -> Result<(), ArrowError> {
+    let (writer_precision, writer_scale, _) = parse_decimal_attributes(writer, None, false)?;
+    let (reader_precision, reader_scale, _) = parse_decimal_attributes(reader, None, false)?;
+    if writer_scale != reader_scale || reader_precision < writer_precision {
+        return Err(ArrowError::SchemaError(format!(
+            "Incompatible decimal writer=({writer_precision}, {writer_scale}), \
+             reader=({reader_precision}, {reader_scale})"
+        )));
+    }
+    Ok(())
+}
+
 fn primitive_of(schema: &Schema) -> Option<PrimitiveType> {
     match schema {
         Schema::TypeName(TypeName::Primitive(primitive)) => Some(*primitive),
@@ -1791,6 +1822,12 @@ impl<'a> Maker<'a> {
         reader_schema: &'s Schema<'a>,
         namespace: Option<&'a str>,
     ) -> Result<AvroDataType, ArrowError> {
+        if let (Some(writer), Some(reader)) = (
+            decimal_attributes(writer_schema),
+            decimal_attributes(reader_schema),
+        ) {
+            ensure_decimals_compatible(writer, reader)?;
+        }
         if let (Some(write_primitive), Some(read_primitive)) =
             (primitive_of(writer_schema), primitive_of(reader_schema))
         {
@@ -2375,7 +2412,7 @@ mod tests {
         Fixed, PrimitiveType, Record, Schema, Type, TypeName,
     };
     use indexmap::IndexMap;
-    use serde_json::{self, Value};
+    use serde_json::{self, Value, json};
 
     fn create_schema_with_logical_type(
         primitive_type: PrimitiveType,
@@ -3686,6 +3723,56 @@ mod tests {
         assert!(matches!(dt.codec(), Codec::Fixed(16)));
     }
 
+    #[test]
+    fn test_resolve_decimal_precision_and_scale() {
+        let decimal = |precision: u64, scale: u64| {
+            Schema::Type(Type {
+                r#type: TypeName::Primitive(PrimitiveType::Bytes),
+                attributes: Attributes {
+                    logical_type: Some("decimal"),
+                    additional: HashMap::from([
+                        ("precision", json!(precision)),
+                        ("scale", json!(scale)),
+                    ]),
+                },
+            })
+        };
+        let mut maker = Maker::new(false, false, Tz::default());
+        let dt = maker
+            .make_data_type(&decimal(10, 2), Some(&decimal(12, 2)), None)
+            .unwrap();
+        assert!(matches!(dt.codec(), Codec::Decimal(12, Some(2), None)));
+
+        let err = maker
+            .make_data_type(&decimal(10, 2), Some(&decimal(10, 3)), None)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Schema error: Incompatible decimal writer=(10, 2), reader=(10, 3)"
+        );
+        let err = maker
+            .make_data_type(&decimal(10, 2), Some(&decimal(8, 2)), None)
+            .unwrap_err();
+        assert!(err.to_string().contains("Incompatible decimal"));
+
+        let fixed = |precision: u64| {
+            Schema::Complex(ComplexType::Fixed(Fixed {
+                name: "Dec",
+                namespace: None,
+                aliases: vec![],
+                size: 8,
+                attributes: Attributes {
+                    logical_type: Some("decimal"),
+                    additional: HashMap::from([("precision", json!(precision))]),
+                },
+            }))
+        };
+        let err = maker
+            .make_data_type(&fixed(18), Some(&fixed(9)), None)
+            .unwrap_err();
+        assert!(err.to_string().contains("Incompatible decimal"));
+    }
+
     #[cfg(feature = "avro_custom_types")]
     #[test]
     fn test_interval_month_day_nano_custom_logical_type_fixed16() {
@@ -481,9 +481,11 @@
 use crate::codec::{AvroFieldBuilder, Tz};
 use crate::errors::AvroError;
 use crate::reader::header::read_header;
+#[cfg(feature = "async")]
+use crate::schema::AsyncSchemaLookup;
 use crate::schema::{
     AvroSchema, CONFLUENT_MAGIC, Fingerprint, FingerprintAlgorithm, SCHEMA_METADATA_KEY,
-    SINGLE_OBJECT_MAGIC, Schema, SchemaStore,
+    SINGLE_OBJECT_MAGIC, Schema, SchemaLookup, SchemaStore,
 };
 use arrow_array::{RecordBatch, RecordBatchReader};
 use arrow_schema::{ArrowError, SchemaRef};
@@ -492,6 +494,7 @@ use header::Header;
 use indexmap::IndexMap;
 use record::RecordDecoder;
 use std::io::BufRead;
+use std::sync::Arc;
 
 mod block;
 mod cursor;
@@ -648,6 +651,12 @@ pub struct Decoder {
     fingerprint_algorithm: FingerprintAlgorithm,
     pending_schema: Option<(Fingerprint, RecordDecoder)>,
     awaiting_body: bool,
+    /// The options used to build decoders for schemas registered after construction
+    builder: Option<ReaderBuilder>,
+    /// Whether to stop at an unknown fingerprint instead of returning an error
+    defer_unknown: bool,
+    /// An unknown fingerprint that stopped decoding when `defer_unknown` is set
+    missing_fingerprint: Option<Fingerprint>,
 }
 
 impl Decoder {
@@ -667,9 +676,17 @@ impl Decoder {
             fingerprint_algorithm,
             pending_schema: None,
             awaiting_body: false,
+            builder: None,
+            defer_unknown: false,
+            missing_fingerprint: None,
         }
     }
 
+    fn with_builder(mut self, builder: ReaderBuilder) -> Self {
+        self.builder = Some(builder);
+        self
+    }
+
     /// Returns the Arrow schema for the rows decoded by this decoder.
     ///
     /// **Note:** With single‑object or Confluent framing, the schema may change
@@ -700,7 +717,7 @@ impl Decoder {
     /// Returns an error if:
     ///
     /// * The input indicates an unknown fingerprint (not present in the provided
-    ///   `SchemaStore`;
+    ///   `SchemaStore` nor found by the `SchemaLookup`, if any);
     /// * The Avro body is malformed;
     /// * A strict‑mode union rule is violated (see `ReaderBuilder::with_strict_mode`).
     pub fn decode(&mut self, data: &[u8]) -> Result<usize, AvroError> {
@@ -811,10 +828,12 @@ impl Decoder {
         let new_fingerprint = fingerprint_from(fingerprint_bytes.try_into().unwrap());
         // If the fingerprint indicates a schema change, prepare to switch decoders.
         if self.active_fingerprint != Some(new_fingerprint) {
-            let Some(new_decoder) = self.cache.shift_remove(&new_fingerprint) else {
-                return Err(AvroError::ParseError(format!(
-                    "Unknown fingerprint: {new_fingerprint:?}"
-                )));
+            let new_decoder = match self.cache.shift_remove(&new_fingerprint) {
+                Some(decoder) => decoder,
+                None => match self.fetch_decoder(new_fingerprint)? {
+                    Some(decoder) => decoder,
+                    None => return Ok(None), // wait for the schema to be registered
+                },
             };
             self.pending_schema = Some((new_fingerprint, new_decoder));
             // If there are already decoded rows, we must flush them first.
@@ -826,6 +845,97 @@ impl Decoder {
         Ok(Some(N))
     }
 
+    // Builds a decoder for a fingerprint missing from the cache using the schema lookup.
+    //
+    // * Ok(None) – unknown fingerprint recorded in `missing_fingerprint` (`defer_unknown`).
+    fn fetch_decoder(
+        &mut self,
+        fingerprint: Fingerprint,
+    ) -> Result<Option<RecordDecoder>, AvroError> {
+        let lookup = self.builder.as_ref().and_then(|b| b.schema_lookup.as_ref());
+        if let Some(schema) = lookup.map(|l| l.fetch(&fingerprint)).transpose()?.flatten() {
+            return self.make_record_decoder(&schema).map(Some);
+        }
+        if self.defer_unknown {
+            self.missing_fingerprint = Some(fingerprint);
+            return Ok(None);
+        }
+        Err(AvroError::ParseError(format!(
+            "Unknown fingerprint: {fingerprint:?}"
+        )))
+    }
+
+    fn make_record_decoder(&self, schema: &AvroSchema) -> Result<RecordDecoder, AvroError> {
+        let builder = self.builder.as_ref().ok_or_else(|| {
+            AvroError::General("Schemas can only be registered with streaming decoders".into())
+        })?;
+        builder.make_stream_record_decoder(schema, builder.reader_schema.as_ref())
+    }
+
+    /// Registers the writer `schema` identified by `fingerprint`, so that rows framed with
+    /// it can be decoded.
+    ///
+    /// This allows schemas to be added after the decoder is built, for example when they
+    /// are fetched from a schema registry.
+    ///
+    /// # Errors
+    /// Returns an error if `fingerprint` identifies the schema of the rows currently
+    /// buffered, as these would otherwise be decoded with `schema`. Call `Self::flush`
+    /// before registering a new schema for it.
+    pub fn register_schema(
+        &mut self,
+        fingerprint: Fingerprint,
+        schema: &AvroSchema,
+    ) -> Result<(), AvroError> {
+        if self.active_fingerprint == Some(fingerprint) && !self.batch_is_empty() {
+            return Err(AvroError::General(format!(
+                "Cannot register the schema of fingerprint {fingerprint:?} while its rows are buffered, flush the decoder first"
+            )));
+        }
+        let decoder = self.make_record_decoder(schema)?;
+        match &mut self.pending_schema {
+            Some((pending, pending_decoder)) if *pending == fingerprint => {
+                *pending_decoder = decoder;
+            }
+            _ if self.active_fingerprint == Some(fingerprint) => self.active_decoder = decoder,
+            _ => {
+                self.cache.insert(fingerprint, decoder);
+            }
+        }
+        Ok(())
+    }
+
+    /// Feed a chunk of bytes into the decoder, fetching the writer schemas of unknown
+    /// fingerprints from `lookup`.
+    ///
+    /// This behaves like `Self::decode`, except that on encountering a fingerprint that is
+    /// neither registered nor found by the `SchemaLookup` of the builder, if any, the schema
+    /// is fetched from `lookup` and registered before decoding continues.
+    ///
+    /// # Errors
+    /// As `Self::decode`, or if `lookup` fails or does not know the fingerprint.
+    #[cfg(feature = "async")]
+    pub async fn decode_with_lookup(
+        &mut self,
+        data: &[u8],
+        lookup: &dyn AsyncSchemaLookup,
+    ) -> Result<usize, AvroError> {
+        let mut consumed = 0;
+        loop {
+            self.defer_unknown = true;
+            let decoded = self.decode(&data[consumed..]);
+            self.defer_unknown = false;
+            consumed += decoded?;
+            let Some(fingerprint) = self.missing_fingerprint.take() else {
+                return Ok(consumed);
+            };
+            let schema = lookup.fetch(&fingerprint).await?.ok_or_else(|| {
+                AvroError::ParseError(format!("Unknown fingerprint: {fingerprint:?}"))
+            })?;
+            self.register_schema(fingerprint, &schema)?;
+        }
+    }
+
     fn apply_pending_schema(&mut self) {
         if let Some((new_fingerprint, new_decoder)) = self.pending_schema.take() {
             if let Some(old_fingerprint) = self.active_fingerprint.replace(new_fingerprint) {
@@ -964,7 +1074,7 @@ impl Decoder {
 ///     .build_decoder()?;
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ReaderBuilder {
     batch_size: usize,
     strict_mode: bool,
@@ -974,6 +1084,7 @@ pub struct ReaderBuilder {
     projection: Option<Vec<usize>>,
     writer_schema_store: Option<SchemaStore>,
     active_fingerprint: Option<Fingerprint>,
+    schema_lookup: Option<Arc<dyn SchemaLookup>>,
 }
 
 impl Default for ReaderBuilder {
@@ -987,6 +1098,7 @@ impl Default for ReaderBuilder {
             projection: None,
             writer_schema_store: None,
             active_fingerprint: None,
+            schema_lookup: None,
         }
     }
 }
@@ -1002,6 +1114,7 @@ impl ReaderBuilder {
     /// * `projection = None`
     /// * `writer_schema_store = None`
     /// * `active_fingerprint = None`
+    /// * `schema_lookup = None`
     pub fn new() -> Self {
         Self::default()
     }
@@ -1080,7 +1193,9 @@ impl ReaderBuilder {
             AvroError::ParseError("Writer schema store required for raw Avro".into())
         })?;
         let fingerprints = store.fingerprints();
-        if fingerprints.is_empty() {
+        if fingerprints.is_empty()
+            && (self.schema_lookup.is_none() || self.active_fingerprint.is_none())
+        {
             return Err(AvroError::ParseError(
                 "Writer schema store must contain at least one schema".into(),
             ));
@@ -1091,11 +1206,6 @@ impl ReaderBuilder {
             .ok_or_else(|| {
                 AvroError::ParseError("Could not determine initial schema fingerprint".into())
             })?;
-        let projection = self.projection.as_deref();
-        let projected_reader_schema = match (projection, reader_schema) {
-            (Some(projection), Some(reader_schema)) => Some(reader_schema.project(projection)?),
-            _ => None,
-        };
         let mut cache = IndexMap::with_capacity(fingerprints.len().saturating_sub(1));
         let mut active_decoder: Option<RecordDecoder> = None;
         for fingerprint in store.fingerprints() {
@@ -1107,42 +1217,61 @@ impl ReaderBuilder {
                     )));
                 }
             };
-            let writer_schema = avro_schema.schema()?;
-            let record_decoder = match projection {
-                None => self.make_record_decoder_from_schemas(&writer_schema, reader_schema)?,
-                Some(projection) => {
-                    if let Some(ref pruned_reader_schema) = projected_reader_schema {
-                        self.make_record_decoder_from_schemas(
-                            &writer_schema,
-                            Some(pruned_reader_schema),
-                        )?
-                    } else {
-                        let derived_reader_schema = avro_schema.project(projection)?;
-                        self.make_record_decoder_from_schemas(
-                            &writer_schema,
-                            Some(&derived_reader_schema),
-                        )?
-                    }
-                }
-            };
+            let record_decoder = self.make_stream_record_decoder(avro_schema, reader_schema)?;
             if fingerprint == start_fingerprint {
                 active_decoder = Some(record_decoder);
             } else {
                 cache.insert(fingerprint, record_decoder);
             }
         }
-        let active_decoder = active_decoder.ok_or_else(|| {
-            AvroError::General(format!(
-                "Initial fingerprint {start_fingerprint:?} not found in schema store"
-            ))
-        })?;
+        let active_decoder = match active_decoder {
+            Some(decoder) => decoder,
+            None => {
+                let schema = self
+                    .schema_lookup
+                    .as_ref()
+                    .map(|lookup| lookup.fetch(&start_fingerprint))
+                    .transpose()?
+                    .flatten()
+                    .ok_or_else(|| {
+                        AvroError::General(format!(
+                            "Initial fingerprint {start_fingerprint:?} not found in schema store"
+                        ))
+                    })?;
+                self.make_stream_record_decoder(&schema, reader_schema)?
+            }
+        };
+        let mut builder = self.clone();
+        builder.writer_schema_store = None;
         Ok(Decoder::from_parts(
             self.batch_size,
             active_decoder,
             Some(start_fingerprint),
             cache,
             store.fingerprint_algorithm(),
-        ))
+        )
+        .with_builder(builder))
+    }
+
+    /// Returns a `RecordDecoder` for the writer schema `avro_schema` of a stream, applying
+    /// the projection to the reader schema, or to the writer schema if there is none
+    fn make_stream_record_decoder(
+        &self,
+        avro_schema: &AvroSchema,
+        reader_schema: Option<&AvroSchema>,
+    ) -> Result<RecordDecoder, AvroError> {
+        let writer_schema = avro_schema.schema()?;
+        match (self.projection.as_deref(), reader_schema) {
+            (None, _) => self.make_record_decoder_from_schemas(&writer_schema, reader_schema),
+            (Some(projection), Some(reader_schema)) => {
+                let pruned_reader_schema = reader_schema.project(projection)?;
+                self.make_record_decoder_from_schemas(&writer_schema, Some(&pruned_reader_schema))
+            }
+            (Some(projection), None) => {
+                let derived_reader_schema = avro_schema.project(projection)?;
+                self.make_record_decoder_from_schemas(&writer_schema, Some(&derived_reader_schema))
+            }
+        }
     }
 
     /// Sets the **row‑based batch size**.
@@ -1272,6 +1401,22 @@ impl ReaderBuilder {
         self
     }
 
+    /// Sets the `SchemaLookup` used to fetch the writer schemas of fingerprints not in
+    /// the writer schema store.
+    ///
+    /// This allows a `Decoder` for the **Confluent** wire format to decode messages framed
+    /// with schema ids that are registered after the decoder is built, by fetching their
+    /// schemas from the schema registry. Fetched schemas are cached by the `Decoder`.
+    ///
+    /// With a schema lookup and `Self::with_active_fingerprint`, the writer schema store
+    /// may be empty, and is then only used to select the fingerprint algorithm.
+    ///
+    /// Defaults to `None`.
+    pub fn with_schema_lookup(mut self, lookup: Arc<dyn SchemaLookup>) -> Self {
+        self.schema_lookup = Some(lookup);
+        self
+    }
+
     /// Sets the initial schema fingerprint for stream decoding.
     ///
     /// This can be useful for streams that **do not include** a fingerprint before the first
@@ -1305,7 +1450,9 @@ impl ReaderBuilder {
     ///
     /// # Requirements
     /// * `SchemaStore` **must** be provided via `Self::with_writer_schema_store`.
-    /// * The store should contain **all** fingerprints that may appear on the stream.
+    /// * The store should contain **all** fingerprints that may appear on the stream, unless
+    ///   their schemas can be fetched with `Self::with_schema_lookup` or are registered with
+    ///   `Decoder::register_schema`.
     ///
     /// # Errors
     /// * Returns [`ArrowError::InvalidArgumentError`] if the schema store is missing
@@ -1432,14 +1579,12 @@ mod test {
     #[cfg(feature = "snappy")]
     use arrow_array::builder::{Float64Builder, MapFieldNames};
     use arrow_array::cast::AsArray;
-    #[cfg(not(feature = "avro_custom_types"))]
-    use arrow_array::types::Int64Type;
     #[cfg(feature = "avro_custom_types")]
     use arrow_array::types::{
         DurationMicrosecondType, DurationMillisecondType, DurationNanosecondType,
         DurationSecondType,
     };
-    use arrow_array::types::{Int32Type, IntervalMonthDayNanoType};
+    use arrow_array::types::{Int32Type, Int64Type, IntervalMonthDayNanoType};
     use arrow_array::*;
     #[cfg(feature = "snappy")]
     use arrow_buffer::{Buffer, NullBuffer};
@@ -1454,6 +1599,8 @@ mod test {
         ArrowError, DataType, Field, FieldRef, Fields, IntervalUnit, Schema, UnionFields, UnionMode,
     };
     use bytes::Bytes;
+    #[cfg(feature = "async")]
+    use futures::FutureExt;
     use futures::executor::block_on;
     use futures::{Stream, StreamExt, TryStreamExt, stream};
     use serde_json::{Value, json};
@@ -2742,6 +2889,119 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_schema_lookup_fetches_unknown_id() {
+        let mut registry = SchemaStore::new_with_type(FingerprintAlgorithm::Id);
+        registry
+            .set(Fingerprint::Id(1), make_value_schema(PrimitiveType::Int))
+            .unwrap();
+        registry
+            .set(Fingerprint::Id(2), make_value_schema(PrimitiveType::Long))
+            .unwrap();
+        let mut decoder = ReaderBuilder::new()
+            .with_writer_schema_store(SchemaStore::new_with_type(FingerprintAlgorithm::Id))
+            .with_schema_lookup(Arc::new(registry))
+            .with_active_fingerprint(Fingerprint::Id(1))
+            .build_decoder()
+            .unwrap();
+        let input = [
+            make_message_id(1, 21),
+            make_message_id(2, 5),
+            make_message_id(2, 6),
+        ]
+        .concat();
+        let consumed = decoder.decode(&input).unwrap();
+        let batch = decoder.flush().unwrap().unwrap();
+        assert_eq!(batch.column(0).as_primitive::<Int32Type>().values(), &[21]);
+        decoder.decode(&input[consumed..]).unwrap();
+        let batch = decoder.flush().unwrap().unwrap();
+        assert_eq!(
+            batch.column(0).as_primitive::<Int64Type>().values(),
+            &[5, 6]
+        );
+
+        let err = decoder.decode(&make_message_id(3, 1)).unwrap_err();
+        assert!(err.to_string().contains("Unknown fingerprint"));
+    }
+
+    #[test]
+    fn test_register_schema() {
+        let mut store = SchemaStore::new_with_type(FingerprintAlgorithm::Id);
+        store
+            .set(Fingerprint::Id(1), make_value_schema(PrimitiveType::Int))
+            .unwrap();
+        let mut decoder = ReaderBuilder::new()
+            .with_writer_schema_store(store)
+            .build_decoder()
+            .unwrap();
+        decoder
+            .register_schema(Fingerprint::Id(2), &make_value_schema(PrimitiveType::Long))
+            .unwrap();
+        decoder.decode(&make_message_id(2, 7)).unwrap();
+
+        // The rows of the active schema must be flushed before it is replaced
+        let long_schema = make_value_schema(PrimitiveType::Long);
+        let err = decoder
+            .register_schema(Fingerprint::Id(2), &long_schema)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Avro error: Cannot register the schema of fingerprint Id(2) while its rows are buffered, flush the decoder first"
+        );
+        let batch = decoder.flush().unwrap().unwrap();
+        assert_eq!(batch.column(0).as_primitive::<Int64Type>().values(), &[7]);
+
+        decoder
+            .register_schema(Fingerprint::Id(2), &make_value_schema(PrimitiveType::Int))
+            .unwrap();
+        decoder.decode(&make_message_id(2, 8)).unwrap();
+        let batch = decoder.flush().unwrap().unwrap();
+        assert_eq!(batch.column(0).as_primitive::<Int32Type>().values(), &[8]);
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_decode_with_async_lookup() {
+        struct Registry(SchemaStore);
+
+        impl crate::schema::AsyncSchemaLookup for Registry {
+            fn fetch(
+                &self,
+                fingerprint: &Fingerprint,
+            ) -> futures::future::BoxFuture<'_, Result<Option<AvroSchema>, ArrowError>>
+            {
+                let fingerprint = *fingerprint;
+                async move { Ok(self.0.lookup(&fingerprint).cloned()) }.boxed()
+            }
+        }
+
+        let mut store = SchemaStore::new_with_type(FingerprintAlgorithm::Id);
+        store
+            .set(Fingerprint::Id(1), make_value_schema(PrimitiveType::Int))
+            .unwrap();
+        let mut registry = store.clone();
+        registry
+            .set(Fingerprint::Id(2), make_value_schema(PrimitiveType::Long))
+            .unwrap();
+        let registry = Registry(registry);
+        let mut decoder = ReaderBuilder::new()
+            .with_writer_schema_store(store)
+            .build_decoder()
+            .unwrap();
+
+        let input = [make_message_id(2, 5), make_message_id(1, 3)].concat();
+        let consumed = block_on(decoder.decode_with_lookup(&input, &registry)).unwrap();
+        let batch = decoder.flush().unwrap().unwrap();
+        assert_eq!(batch.column(0).as_primitive::<Int64Type>().values(), &[5]);
+        block_on(decoder.decode_with_lookup(&input[consumed..], &registry)).unwrap();
+        let batch = decoder.flush().unwrap().unwrap();
+        assert_eq!(batch.column(0).as_primitive::<Int32Type>().values(), &[3]);
+
+        let err =
+            block_on(decoder.decode_with_lookup(&make_message_id(3, 1), &registry)).unwrap_err();
+        assert!(err.to_string().contains("Unknown fingerprint"));
+    }
+
     #[test]
     fn test_handle_prefix_id_incomplete_magic() {
         let writer_schema = make_value_schema(PrimitiveType::Int);
@@ -962,6 +962,37 @@ impl SchemaStore {
     }
 }
 
+/// A source of writer schemas for fingerprints not registered in a [`SchemaStore`].
+///
+/// This allows a streaming [`Decoder`](crate::reader::Decoder) to resolve schemas on
+/// demand, for example by fetching them by id from a Confluent schema registry when a
+/// message framed with an unknown id is decoded.
+///
+/// See [`ReaderBuilder::with_schema_lookup`](crate::reader::ReaderBuilder::with_schema_lookup)
+pub trait SchemaLookup: Send + Sync + std::fmt::Debug {
+    /// Returns the schema identified by `fingerprint`, or `None` if it is unknown
+    fn fetch(&self, fingerprint: &Fingerprint) -> Result<Option<AvroSchema>, ArrowError>;
+}
+
+impl SchemaLookup for SchemaStore {
+    fn fetch(&self, fingerprint: &Fingerprint) -> Result<Option<AvroSchema>, ArrowError> {
+        Ok(self.lookup(fingerprint).cloned())
+    }
+}
+
+/// The asynchronous counterpart of [`SchemaLookup`], for schema sources that require
+/// network requests such as a schema registry client.
+///
+/// See [`Decoder::decode_with_lookup`](crate::reader::Decoder::decode_with_lookup)
+#[cfg(feature = "async")]
+pub trait AsyncSchemaLookup: Send + Sync {
+    /// Returns the schema identified by `fingerprint`, or `None` if it is unknown
+    fn fetch(
+        &self,
+        fingerprint: &Fingerprint,
+    ) -> futures::future::BoxFuture<'_, Result<Option<AvroSchema>, ArrowError>>;
+}
+
 fn quote(s: &str) -> Result<String, ArrowError> {
     serde_json::to_string(s)
         .map_err(|e| ArrowError::ComputeError(format!("Failed to quote string: {e}")))
@@ -22,6 +22,7 @@ use crate::errors::AvroError;
 use crate::schema::{Fingerprint, Nullability, Prefix};
 use arrow_array::Float16Array;
 use arrow_array::cast::AsArray;
+use arrow_array::downcast_dictionary_array;
 use arrow_array::types::{
     ArrowPrimitiveType, Date32Type, Date64Type, DurationMicrosecondType, DurationMillisecondType,
     DurationNanosecondType, DurationSecondType, Float16Type, Float32Type, Float64Type, Int8Type,
@@ -697,6 +698,9 @@ impl<'a> FieldEncoder<'a> {
                 };
                 build(array)?
             }
+            FieldPlan::Dictionary { value_plan } => {
+                Encoder::Dictionary(Box::new(DictionaryEncoder::try_new(array, value_plan)?))
+            }
             FieldPlan::Duration => match array.data_type() {
                 DataType::Interval(IntervalUnit::MonthDayNano) => {
                     Encoder::IntervalMonthDayNanoDuration(DurationEncoder(
@@ -798,6 +802,9 @@ enum FieldPlan {
         values_nullability: Option<Nullability>,
         value_plan: Box<FieldPlan>,
     },
+    /// Arrow `Dictionary` site without Avro enum symbols. Values are encoded per
+    /// logical row by looking up the row's key in the dictionary values with `value_plan`.
+    Dictionary { value_plan: Box<FieldPlan> },
     /// Standard Avro `duration` logical type (`fixed(12)`, three LE u32 values).
     /// Used when `Codec::Interval` is resolved, ensuring the 12-byte duration encoder
     /// is selected regardless of the `avro_custom_types` feature flag.
@@ -1037,6 +1044,16 @@ impl FieldPlan {
                 value_plan: Box::new(FieldPlan::build(value_site_dt, values_field.as_ref())?),
             });
         }
+        if let DataType::Dictionary(_, value_type) = arrow_field.data_type() {
+            if !matches!(avro_dt.codec(), Codec::Enum(_)) {
+                let values_field = arrow_field
+                    .clone()
+                    .with_data_type(value_type.as_ref().clone());
+                return Ok(FieldPlan::Dictionary {
+                    value_plan: Box::new(FieldPlan::build(avro_dt, &values_field)?),
+                });
+            }
+        }
         if let DataType::FixedSizeBinary(len) = arrow_field.data_type() {
             // Extension-based detection (only when the feature is enabled)
             let ext_is_uuid = {
@@ -1313,6 +1330,8 @@ enum Encoder<'a> {
     RunEncoded16(Box<RunEncodedEncoder16<'a>>),
     RunEncoded32(Box<RunEncodedEncoder32<'a>>),
     RunEncoded64(Box<RunEncodedEncoder64<'a>>),
+    /// Dictionary encoded values, written as the value each key refers to
+    Dictionary(Box<DictionaryEncoder<'a>>),
     Null,
     #[cfg(feature = "avro_custom_types")]
     Int8(Int8Encoder<'a>),
@@ -1413,6 +1432,7 @@ impl<'a> Encoder<'a> {
             Encoder::RunEncoded16(e) => (e).encode(out, idx),
             Encoder::RunEncoded32(e) => (e).encode(out, idx),
             Encoder::RunEncoded64(e) => (e).encode(out, idx),
+            Encoder::Dictionary(e) => (e).encode(out, idx),
             Encoder::Null => Ok(()),
             #[cfg(feature = "avro_custom_types")]
             Encoder::Int8(e) => e.encode(out, idx),
@@ -2390,6 +2410,44 @@ impl<'a, R: RunEndIndexType> RunEncodedEncoder<'a, R> {
     }
 }
 
+/// Encoder for Arrow `DictionaryArray` sites not mapped to an Avro enum.
+///
+/// Null keys are handled by the enclosing [`FieldEncoder`], and each non-null row is
+/// encoded as the dictionary value its key refers to.
+struct DictionaryEncoder<'a> {
+    keys: Vec<usize>,
+    values: FieldEncoder<'a>,
+}
+
+impl<'a> DictionaryEncoder<'a> {
+    fn try_new(array: &'a dyn Array, value_plan: &FieldPlan) -> Result<Self, AvroError> {
+        let keys: Vec<usize> = downcast_dictionary_array! {
+            array => array.keys().values().iter().map(|k| k.as_usize()).collect(),
+            other => {
+                return Err(AvroError::SchemaError(format!(
+                    "Avro dictionary site requires DataType::Dictionary, found: {other:?}"
+                )));
+            }
+        };
+        let values = array.as_any_dictionary().values();
+        if values.null_count() > 0 {
+            return Err(AvroError::InvalidArgument(
+                "Null dictionary values are not supported by the Avro writer, \
+                 null entries must be encoded as null keys"
+                    .into(),
+            ));
+        }
+        Ok(Self {
+            keys,
+            values: FieldEncoder::make_encoder(values.as_ref(), value_plan, None)?,
+        })
+    }
+
+    fn encode<W: Write + ?Sized>(&mut self, out: &mut W, idx: usize) -> Result<(), AvroError> {
+        self.values.encode(out, self.keys[idx])
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
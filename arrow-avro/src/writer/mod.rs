@@ -791,14 +791,14 @@ mod tests {
     use arrow_array::builder::{Int32Builder, ListBuilder};
     use arrow_array::cast::AsArray;
     #[cfg(feature = "avro_custom_types")]
-    use arrow_array::types::{Int16Type, Int64Type};
+    use arrow_array::types::Int64Type;
     use arrow_array::types::{
-        Int32Type, Time32MillisecondType, Time64MicrosecondType, TimestampMicrosecondType,
-        TimestampMillisecondType, TimestampNanosecondType,
+        Int16Type, Int32Type, Time32MillisecondType, Time64MicrosecondType,
+        TimestampMicrosecondType, TimestampMillisecondType, TimestampNanosecondType,
     };
     use arrow_array::{
-        Array, ArrayRef, BinaryArray, BooleanArray, Date32Array, Date64Array, Float16Array,
-        Int8Array, Int16Array, Int32Array, Int64Array, IntervalDayTimeArray,
+        Array, ArrayRef, BinaryArray, BooleanArray, Date32Array, Date64Array, DictionaryArray,
+        Float16Array, Int8Array, Int16Array, Int32Array, Int64Array, IntervalDayTimeArray,
         IntervalMonthDayNanoArray, IntervalYearMonthArray, PrimitiveArray, RecordBatch,
         StringArray, StructArray, Time32MillisecondArray, Time32SecondArray,
         Time64MicrosecondArray, Time64NanosecondArray, TimestampMillisecondArray,
@@ -4465,4 +4465,40 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_dictionary_roundtrip_writer() -> Result<(), AvroError> {
+        let strings: DictionaryArray<Int16Type> = vec![Some("a"), None, Some("b"), Some("a")]
+            .into_iter()
+            .collect();
+        let numbers = DictionaryArray::new(
+            UInt8Array::from(vec![1, 0, 1, 1]),
+            Arc::new(Int64Array::from(vec![10, 20])),
+        );
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("s", strings.data_type().clone(), true),
+            Field::new("n", numbers.data_type().clone(), false),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![Arc::new(strings) as ArrayRef, Arc::new(numbers) as ArrayRef],
+        )?;
+        let mut writer = AvroWriter::new(Vec::<u8>::new(), schema.as_ref().clone())?;
+        writer.write(&batch.slice(1, 3))?;
+        writer.finish()?;
+        let bytes = writer.into_inner();
+        let reader = ReaderBuilder::new().build(Cursor::new(bytes))?;
+        let batches = reader.collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(batches.len(), 1);
+        let out = &batches[0];
+        assert_eq!(
+            out.column(0).as_ref(),
+            &StringArray::from(vec![None, Some("b"), Some("a")]) as &dyn Array
+        );
+        assert_eq!(
+            out.column(1).as_ref(),
+            &Int64Array::from(vec![10, 20, 20]) as &dyn Array
+        );
+        Ok(())
+    }
 }
@@ -631,6 +631,27 @@ impl BooleanBuffer {
         BitSliceIterator::new(self.values(), self.bit_offset, self.bit_len)
     }
 
+    /// Returns an iterator over the number of set bits in each consecutive window
+    /// of 64 bits in this [`BooleanBuffer`]
+    ///
+    /// The final window covers the remaining `len() % 64` bits, if any. This can be
+    /// used to cheaply estimate the selectivity of regions of a selection mask
+    ///
+    /// ```
+    /// # use arrow_buffer::BooleanBuffer;
+    /// let buffer = BooleanBuffer::collect_bool(130, |i| i < 70 || i == 129);
+    /// let counts: Vec<_> = buffer.chunk_set_counts().collect();
+    /// assert_eq!(counts, vec![64, 6, 1]);
+    /// ```
+    pub fn chunk_set_counts(&self) -> impl Iterator<Item = u32> + '_ {
+        let chunks = self.bit_chunks();
+        chunks
+            .iter()
+            .chain(std::iter::once(chunks.remainder_bits()))
+            .take(self.bit_len.div_ceil(64))
+            .map(u64::count_ones)
+    }
+
     /// Block size for chunked fold operations in [`Self::has_true`] and [`Self::has_false`].
     /// Using `chunks_exact` with this size lets the compiler fully unroll the inner
     /// fold (no inner branch/loop), enabling short-circuit exits every N chunks.
@@ -1372,6 +1393,22 @@ mod tests {
         assert!(!arr.has_false());
     }
 
+    #[test]
+    fn test_chunk_set_counts() {
+        let buffer = BooleanBuffer::collect_bool(200, |i| i % 3 == 0);
+        let expected: Vec<u32> = (0..200)
+            .collect::<Vec<_>>()
+            .chunks(64)
+            .map(|c| c.iter().filter(|i| *i % 3 == 0).count() as u32)
+            .collect();
+        assert_eq!(buffer.chunk_set_counts().collect::<Vec<_>>(), expected);
+
+        let sliced = buffer.slice(5, 128);
+        let counts: Vec<_> = sliced.chunk_set_counts().collect();
+        assert_eq!(counts, vec![21, 22]);
+        assert_eq!(BooleanBuffer::new_set(0).chunk_set_counts().count(), 0);
+    }
+
     #[test]
     fn test_has_true_has_false_unaligned_slices() {
         let cases = [
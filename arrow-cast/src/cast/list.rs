@@ -362,6 +362,16 @@ where
     O::Native: OffsetSizeTrait,
 {
     let list_view = array.as_list_view::<I>();
+
+    // Avoid copying the values if the lists are stored contiguously and in order
+    if let Ok(list) = GenericListArray::<I>::try_from(list_view.clone()) {
+        let to_type = match O::Native::IS_LARGE {
+            true => DataType::LargeList(to.clone()),
+            false => DataType::List(to.clone()),
+        };
+        return cast_with_options(&list, &to_type, cast_options);
+    }
+
     let list_view_offsets = list_view.offsets();
     let sizes = list_view.sizes();
 
@@ -281,7 +281,9 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
             | BinaryView,
         ) => true,
         (Utf8 | LargeUtf8, Utf8View) => true,
-        (BinaryView, Binary | LargeBinary | Utf8 | LargeUtf8 | Utf8View) => true,
+        (BinaryView, Binary | LargeBinary | Utf8 | LargeUtf8 | Utf8View | FixedSizeBinary(_)) => {
+            true
+        }
         (Utf8View | Utf8 | LargeUtf8, _) => to_type.is_numeric(),
         (_, Utf8 | Utf8View | LargeUtf8) => from_type.is_primitive(),
 
@@ -1435,9 +1437,11 @@ pub fn cast_with_options(
                 cast_byte_container::<Utf8Type, LargeUtf8Type>(array.as_ref())
             }
             LargeBinary => cast_byte_container::<BinaryType, LargeBinaryType>(array),
-            FixedSizeBinary(size) => {
-                cast_binary_to_fixed_size_binary::<i32>(array, *size, cast_options)
-            }
+            FixedSizeBinary(size) => cast_binary_to_fixed_size_binary(
+                array.as_binary::<i32>().iter(),
+                *size,
+                cast_options,
+            ),
             BinaryView => Ok(Arc::new(BinaryViewArray::from(array.as_binary::<i32>()))),
             Utf8View => Ok(Arc::new(StringViewArray::from(
                 cast_binary_to_string::<i32>(array, cast_options)?.as_string::<i32>(),
@@ -1453,9 +1457,11 @@ pub fn cast_with_options(
             }
             LargeUtf8 => cast_binary_to_string::<i64>(array, cast_options),
             Binary => cast_byte_container::<LargeBinaryType, BinaryType>(array),
-            FixedSizeBinary(size) => {
-                cast_binary_to_fixed_size_binary::<i64>(array, *size, cast_options)
-            }
+            FixedSizeBinary(size) => cast_binary_to_fixed_size_binary(
+                array.as_binary::<i64>().iter(),
+                *size,
+                cast_options,
+            ),
             BinaryView => Ok(Arc::new(BinaryViewArray::from(array.as_binary::<i64>()))),
             Utf8View => {
                 let array = cast_binary_to_string::<i64>(array, cast_options)?;
@@ -1486,6 +1492,9 @@ pub fn cast_with_options(
             cast_binary_to_string::<i64>(&binary_arr, cast_options)
         }
         (BinaryView, Utf8View) => cast_binary_view_to_string_view(array, cast_options),
+        (BinaryView, FixedSizeBinary(size)) => {
+            cast_binary_to_fixed_size_binary(array.as_binary_view().iter(), *size, cast_options)
+        }
         (BinaryView, _) => Err(ArrowError::CastError(format!(
             "Casting from {from_type} to {to_type} not supported",
         ))),
@@ -2688,26 +2697,25 @@ where
     }
 }
 
-/// Helper function to cast from one `BinaryArray` or 'LargeBinaryArray' to 'FixedSizeBinaryArray'.
-fn cast_binary_to_fixed_size_binary<O: OffsetSizeTrait>(
-    array: &dyn Array,
+/// Helper function to cast the values of a `BinaryArray`, 'LargeBinaryArray' or
+/// 'BinaryViewArray' to 'FixedSizeBinaryArray'.
+fn cast_binary_to_fixed_size_binary<'a>(
+    values: impl ExactSizeIterator<Item = Option<&'a [u8]>>,
     byte_width: i32,
     cast_options: &CastOptions,
 ) -> Result<ArrayRef, ArrowError> {
-    let array = array.as_binary::<O>();
-    let mut builder = FixedSizeBinaryBuilder::with_capacity(array.len(), byte_width);
+    let mut builder = FixedSizeBinaryBuilder::with_capacity(values.len(), byte_width);
 
-    for i in 0..array.len() {
-        if array.is_null(i) {
-            builder.append_null();
-        } else {
-            match builder.append_value(array.value(i)) {
+    for value in values {
+        match value {
+            None => builder.append_null(),
+            Some(value) => match builder.append_value(value) {
                 Ok(_) => {}
                 Err(e) => match cast_options.safe {
                     true => builder.append_null(),
                     false => return Err(e),
                 },
-            }
+            },
         }
     }
 
@@ -5950,6 +5958,84 @@ mod tests {
         assert!(array_ref.is_err());
     }
 
+    #[test]
+    fn test_cast_binary_view_to_fixed_size_binary() {
+        let array: ArrayRef = Arc::new(BinaryViewArray::from(vec![
+            Some("Hiiii".as_bytes()),
+            None,
+            Some("a much longer value".as_bytes()),
+        ]));
+        assert!(can_cast_types(array.data_type(), &FixedSizeBinary(5)));
+
+        let result = cast(&array, &FixedSizeBinary(5)).unwrap();
+        let expected = FixedSizeBinaryArray::try_from_sparse_iter_with_size(
+            vec![Some("Hiiii".as_bytes()), None, None].into_iter(),
+            5,
+        )
+        .unwrap();
+        assert_eq!(result.as_fixed_size_binary(), &expected);
+
+        let options = CastOptions {
+            safe: false,
+            format_options: FormatOptions::default(),
+        };
+        let err = cast_with_options(&array, &FixedSizeBinary(5), &options).unwrap_err();
+        assert!(err.to_string().contains("Invalid argument error"), "{err}");
+    }
+
+    #[test]
+    fn test_can_cast_view_types_like_non_view_types() {
+        let targets = [
+            Boolean,
+            Int8,
+            Int16,
+            Int32,
+            Int64,
+            UInt8,
+            UInt16,
+            UInt32,
+            UInt64,
+            Float16,
+            Float32,
+            Float64,
+            Date32,
+            Date64,
+            Time32(TimeUnit::Second),
+            Time32(TimeUnit::Millisecond),
+            Time64(TimeUnit::Microsecond),
+            Time64(TimeUnit::Nanosecond),
+            Timestamp(TimeUnit::Second, None),
+            Timestamp(TimeUnit::Millisecond, Some("+01:00".into())),
+            Timestamp(TimeUnit::Microsecond, None),
+            Timestamp(TimeUnit::Nanosecond, Some("UTC".into())),
+            Duration(TimeUnit::Second),
+            Interval(IntervalUnit::YearMonth),
+            Interval(IntervalUnit::DayTime),
+            Interval(IntervalUnit::MonthDayNano),
+            Decimal32(9, 2),
+            Decimal64(18, 2),
+            Decimal128(38, 2),
+            Decimal256(76, 2),
+            Binary,
+            LargeBinary,
+            BinaryView,
+            FixedSizeBinary(3),
+            Utf8,
+            LargeUtf8,
+            Utf8View,
+            Dictionary(Box::new(Int32), Box::new(Utf8)),
+        ];
+        for target in &targets {
+            for (from, view) in [(Utf8, Utf8View), (Binary, BinaryView)] {
+                assert_eq!(
+                    can_cast_types(&from, target),
+                    can_cast_types(&view, target),
+                    "{from} and {view} to {target}"
+                );
+            }
+        }
+    }
+
     #[test]
     fn test_fixed_size_binary_to_binary() {
         let bytes_1 = "Hiiii".as_bytes();
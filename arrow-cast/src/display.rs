@@ -47,6 +47,20 @@ pub enum DurationFormat {
     ISO8601,
     /// A human readable representation - `198 days 16 hours 34 mins 15.407810000 secs`
     Pretty,
+    /// A compact human readable representation - `198d 16h 34m 15.40781s`
+    Compact,
+}
+
+/// Format for displaying intervals
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IntervalFormat {
+    /// A human readable representation - `1 mons 2 days 3 hours 4 mins 5.500000000 secs`
+    Pretty,
+    /// ISO 8601 - `P1M2DT3H4M5.5S`
+    ISO8601,
+    /// A compact human readable representation - `1mo 2d 3h 4m 5.5s`
+    Compact,
 }
 
 /// Options for formatting arrays
@@ -78,6 +92,8 @@ pub struct FormatOptions<'a> {
     time_format: TimeFormat<'a>,
     /// Duration format
     duration_format: DurationFormat,
+    /// Interval format
+    interval_format: IntervalFormat,
     /// Show types in visual representation batches
     types_info: bool,
     /// Whether string values should be quoted
@@ -103,6 +119,7 @@ impl PartialEq for FormatOptions<'_> {
             && self.timestamp_tz_format == other.timestamp_tz_format
             && self.time_format == other.time_format
             && self.duration_format == other.duration_format
+            && self.interval_format == other.interval_format
             && self.types_info == other.types_info
             && self.quoted_strings == other.quoted_strings
             && match (self.formatter_factory, other.formatter_factory) {
@@ -125,6 +142,7 @@ impl Hash for FormatOptions<'_> {
         self.timestamp_tz_format.hash(state);
         self.time_format.hash(state);
         self.duration_format.hash(state);
+        self.interval_format.hash(state);
         self.types_info.hash(state);
         self.quoted_strings.hash(state);
         self.formatter_factory
@@ -145,6 +163,7 @@ impl<'a> FormatOptions<'a> {
             timestamp_tz_format: None,
             time_format: None,
             duration_format: DurationFormat::ISO8601,
+            interval_format: IntervalFormat::Pretty,
             types_info: false,
             quoted_strings: false,
             formatter_factory: None,
@@ -215,6 +234,16 @@ impl<'a> FormatOptions<'a> {
         }
     }
 
+    /// Overrides the format used for interval columns
+    ///
+    /// Defaults to [`IntervalFormat::Pretty`]
+    pub const fn with_interval_format(self, interval_format: IntervalFormat) -> Self {
+        Self {
+            interval_format,
+            ..self
+        }
+    }
+
     /// Overrides if types should be shown
     ///
     /// Defaults to [`false`]
@@ -287,6 +316,11 @@ impl<'a> FormatOptions<'a> {
         self.duration_format
     }
 
+    /// Returns the [`IntervalFormat`] used for interval columns.
+    pub const fn interval_format(&self) -> IntervalFormat {
+        self.interval_format
+    }
+
     /// Returns true if type info should be included in a visual representation of batches.
     pub const fn types_info(&self) -> bool {
         self.types_info
@@ -852,6 +886,7 @@ macro_rules! duration_display {
                 match fmt {
                     DurationFormat::ISO8601 => write!(f, "{}", $convert(v))?,
                     DurationFormat::Pretty => duration_fmt!(f, v, $scale)?,
+                    DurationFormat::Compact => write_compact_duration(f, v, $scale)?,
                 }
                 Ok(())
             }
@@ -880,6 +915,7 @@ macro_rules! duration_option_display {
                         Some(_) => duration_fmt!(f, v, $scale)?,
                         None => write!(f, "<invalid>")?,
                     },
+                    DurationFormat::Compact => write_compact_duration(f, v, $scale)?,
                 }
                 Ok(())
             }
@@ -938,8 +974,116 @@ duration_option_display!(try_duration_ms_to_duration, DurationMillisecondType, 3
 duration_display!(duration_us_to_duration, DurationMicrosecondType, 6);
 duration_display!(duration_ns_to_duration, DurationNanosecondType, 9);
 
-impl DisplayIndex for &PrimitiveArray<IntervalYearMonthType> {
-    fn write(&self, idx: usize, f: &mut dyn Write) -> FormatResult {
+const NANOS_PER_SEC: i128 = 1_000_000_000;
+const NANOS_PER_DAY: i128 = 86_400 * NANOS_PER_SEC;
+
+/// Writes `value`, a duration in units of `10^-scale` seconds, as a compact human
+/// readable string
+fn write_compact_duration(f: &mut dyn Write, value: i64, scale: u32) -> FormatResult {
+    let nanos = value as i128 * 10_i128.pow(9 - scale);
+    let days = (nanos / NANOS_PER_DAY) as i64;
+    write_compact_interval(f, 0, days, nanos % NANOS_PER_DAY)
+}
+
+/// Writes the non-zero components of an interval as a compact human readable string,
+/// e.g. `1y 2mo 3d 4h 5m 6.5s`, or `0s` if all are zero
+fn write_compact_interval(f: &mut dyn Write, months: i32, days: i64, nanos: i128) -> FormatResult {
+    let (hours, mins, secs, subsec) = split_nanos(nanos);
+    let parts = [
+        ((months / 12) as i128, "y"),
+        ((months % 12) as i128, "mo"),
+        (days as i128, "d"),
+        (hours, "h"),
+        (mins, "m"),
+    ];
+    let mut prefix = "";
+    for (value, unit) in parts {
+        if value != 0 {
+            write!(f, "{prefix}{value}{unit}")?;
+            prefix = " ";
+        }
+    }
+    if secs != 0 || subsec != 0 || prefix.is_empty() {
+        write!(f, "{prefix}")?;
+        write_seconds(f, secs, subsec)?;
+        write!(f, "s")?;
+    }
+    Ok(())
+}
+
+/// Writes an interval as an ISO 8601 duration, e.g. `P1Y2M3DT4H5M6.5S`, or `PT0S`
+/// if all components are zero
+///
+/// As ISO 8601 does not define negative durations, each negative component is
+/// written with a leading `-`, e.g. `P-1DT-2H`
+fn write_iso8601_interval(f: &mut dyn Write, months: i32, days: i64, nanos: i128) -> FormatResult {
+    let (hours, mins, secs, subsec) = split_nanos(nanos);
+    write!(f, "P")?;
+    if months / 12 != 0 {
+        write!(f, "{}Y", months / 12)?;
+    }
+    if months % 12 != 0 {
+        write!(f, "{}M", months % 12)?;
+    }
+    if days != 0 {
+        write!(f, "{days}D")?;
+    }
+    if nanos != 0 || (months == 0 && days == 0) {
+        write!(f, "T")?;
+        if hours != 0 {
+            write!(f, "{hours}H")?;
+        }
+        if mins != 0 {
+            write!(f, "{mins}M")?;
+        }
+        if secs != 0 || subsec != 0 || nanos == 0 {
+            write_seconds(f, secs, subsec)?;
+            write!(f, "S")?;
+        }
+    }
+    Ok(())
+}
+
+/// Splits `nanos` into hours, minutes, seconds and nanoseconds, each with the sign of `nanos`
+fn split_nanos(nanos: i128) -> (i128, i128, i128, i128) {
+    let secs = nanos / NANOS_PER_SEC;
+    (
+        secs / 3600,
+        secs / 60 % 60,
+        secs % 60,
+        nanos % NANOS_PER_SEC,
+    )
+}
+
+/// Writes `secs` and `nanos`, which have the same sign, as a decimal number of
+/// seconds without trailing zeros, e.g. `-4.5`
+fn write_seconds(f: &mut dyn Write, secs: i128, nanos: i128) -> FormatResult {
+    let sign = if secs < 0 || nanos < 0 { "-" } else { "" };
+    write!(f, "{sign}{}", secs.abs())?;
+    let (mut nanos, mut width) = (nanos.abs(), 9);
+    if nanos != 0 {
+        while nanos % 10 == 0 {
+            nanos /= 10;
+            width -= 1;
+        }
+        write!(f, ".{nanos:0width$}")?;
+    }
+    Ok(())
+}
+
+impl<'a> DisplayIndexState<'a> for &'a PrimitiveArray<IntervalYearMonthType> {
+    type State = IntervalFormat;
+
+    fn prepare(&self, options: &FormatOptions<'a>) -> Result<Self::State, ArrowError> {
+        Ok(options.interval_format)
+    }
+
+    fn write(&self, fmt: &Self::State, idx: usize, f: &mut dyn Write) -> FormatResult {
+        match fmt {
+            IntervalFormat::ISO8601 => return write_iso8601_interval(f, self.value(idx), 0, 0),
+            IntervalFormat::Compact => return write_compact_interval(f, self.value(idx), 0, 0),
+            IntervalFormat::Pretty => {}
+        }
         let interval = self.value(idx) as f64;
         let years = (interval / 12_f64).floor();
         let month = interval - (years * 12_f64);
@@ -949,9 +1093,21 @@ impl DisplayIndex for &PrimitiveArray<IntervalYearMonthType> {
     }
 }
 
-impl DisplayIndex for &PrimitiveArray<IntervalDayTimeType> {
-    fn write(&self, idx: usize, f: &mut dyn Write) -> FormatResult {
+impl<'a> DisplayIndexState<'a> for &'a PrimitiveArray<IntervalDayTimeType> {
+    type State = IntervalFormat;
+
+    fn prepare(&self, options: &FormatOptions<'a>) -> Result<Self::State, ArrowError> {
+        Ok(options.interval_format)
+    }
+
+    fn write(&self, fmt: &Self::State, idx: usize, f: &mut dyn Write) -> FormatResult {
         let value = self.value(idx);
+        let (days, nanos) = (value.days as i64, value.milliseconds as i128 * 1_000_000);
+        match fmt {
+            IntervalFormat::ISO8601 => return write_iso8601_interval(f, 0, days, nanos),
+            IntervalFormat::Compact => return write_compact_interval(f, 0, days, nanos),
+            IntervalFormat::Pretty => {}
+        }
 
         if value.is_zero() {
             write!(f, "0 secs")?;
@@ -978,9 +1134,21 @@ impl DisplayIndex for &PrimitiveArray<IntervalDayTimeType> {
     }
 }
 
-impl DisplayIndex for &PrimitiveArray<IntervalMonthDayNanoType> {
-    fn write(&self, idx: usize, f: &mut dyn Write) -> FormatResult {
+impl<'a> DisplayIndexState<'a> for &'a PrimitiveArray<IntervalMonthDayNanoType> {
+    type State = IntervalFormat;
+
+    fn prepare(&self, options: &FormatOptions<'a>) -> Result<Self::State, ArrowError> {
+        Ok(options.interval_format)
+    }
+
+    fn write(&self, fmt: &Self::State, idx: usize, f: &mut dyn Write) -> FormatResult {
         let value = self.value(idx);
+        let (months, days, nanos) = (value.months, value.days as i64, value.nanoseconds as i128);
+        match fmt {
+            IntervalFormat::ISO8601 => return write_iso8601_interval(f, months, days, nanos),
+            IntervalFormat::Compact => return write_compact_interval(f, months, days, nanos),
+            IntervalFormat::Pretty => {}
+        }
 
         if value.is_zero() {
             write!(f, "0 secs")?;
@@ -1558,6 +1726,72 @@ mod tests {
         assert_eq!(pretty[5], "-45 days -14 hours -2 mins -34 secs");
     }
 
+    #[test]
+    fn test_array_value_to_string_duration_compact() {
+        let options = FormatOptions::new().with_duration_format(DurationFormat::Compact);
+
+        let array = DurationNanosecondArray::from(vec![
+            0,
+            1,
+            -1_500_000_000,
+            (45 * 60 * 60 * 24 + 14 * 60 * 60 + 34) * 1_000_000_000 + 120_000_000,
+            -(14 * 60 * 60 + 2 * 60) * 1_000_000_000,
+        ]);
+        let formatted = format_array(&array, &options);
+        assert_eq!(
+            formatted,
+            ["0s", "0.000000001s", "-1.5s", "45d 14h 34.12s", "-14h -2m"]
+        );
+
+        let array = DurationSecondArray::from(vec![i64::MAX, 60 * 60 * 24]);
+        let formatted = format_array(&array, &options);
+        assert_eq!(formatted, ["106751991167300d 15h 30m 7s", "1d"]);
+    }
+
+    #[test]
+    fn test_interval_format() {
+        let iso = FormatOptions::new().with_interval_format(IntervalFormat::ISO8601);
+        let compact = FormatOptions::new().with_interval_format(IntervalFormat::Compact);
+
+        let array = IntervalYearMonthArray::from(vec![0, 14, -3]);
+        assert_eq!(format_array(&array, &iso), ["PT0S", "P1Y2M", "P-3M"]);
+        assert_eq!(format_array(&array, &compact), ["0s", "1y 2mo", "-3mo"]);
+
+        let array = IntervalDayTimeArray::from(vec![
+            IntervalDayTime::new(0, 0),
+            IntervalDayTime::new(3, 3_723_500),
+            IntervalDayTime::new(-1, -500),
+        ]);
+        assert_eq!(
+            format_array(&array, &iso),
+            ["PT0S", "P3DT1H2M3.5S", "P-1DT-0.5S"]
+        );
+        assert_eq!(
+            format_array(&array, &compact),
+            ["0s", "3d 1h 2m 3.5s", "-1d -0.5s"]
+        );
+
+        let array = IntervalMonthDayNanoArray::from(vec![
+            IntervalMonthDayNano::new(0, 0, 0),
+            IntervalMonthDayNano::new(13, 2, 60_000_000_001),
+            IntervalMonthDayNano::new(1, 0, -7_200_000_000_000),
+        ]);
+        assert_eq!(
+            format_array(&array, &iso),
+            ["PT0S", "P1Y1M2DT1M0.000000001S", "P1MT-2H"]
+        );
+        assert_eq!(
+            format_array(&array, &compact),
+            ["0s", "1y 1mo 2d 1m 0.000000001s", "1mo -2h"]
+        );
+
+        // The default format is unchanged
+        assert_eq!(
+            format_array(&array, &FormatOptions::new())[1],
+            "13 mons 2 days 1 mins 0.000000001 secs"
+        );
+    }
+
     #[test]
     fn test_null() {
         let array = NullArray::new(2);
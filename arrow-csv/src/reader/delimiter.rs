@@ -0,0 +1,212 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Support for delimiters of multiple bytes, which are not supported by [`csv_core`]
+
+use arrow_schema::ArrowError;
+use std::io::Read;
+
+/// The byte multi-byte delimiters are translated to, the ASCII unit separator
+pub const TRANSLATED_DELIMITER: u8 = 0x1F;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum State {
+    /// At the start of a record
+    StartRecord,
+    /// At the start of a field
+    StartField,
+    /// Within an unquoted field
+    Unquoted,
+    /// Within a quoted field
+    Quoted,
+    /// After an escape character within a quoted field
+    Escaped,
+    /// After a quote within a quoted field, which either ends the quoted
+    /// field or is the first of a doubled quote
+    AfterQuote,
+    /// Within a comment line
+    Comment,
+}
+
+/// Translates occurrences of a delimiter of multiple bytes outside of quoted fields
+/// to [`TRANSLATED_DELIMITER`], so the output can be parsed with a single byte delimiter
+///
+/// Input is processed incrementally, with a delimiter possibly split across calls
+/// to [`Self::translate`]
+#[derive(Debug)]
+pub struct DelimiterTranslator {
+    delimiter: Vec<u8>,
+    quote: u8,
+    escape: Option<u8>,
+    terminator: Option<u8>,
+    comment: Option<u8>,
+    state: State,
+    /// The number of bytes of the delimiter matched by the last input bytes
+    matched: usize,
+}
+
+impl DelimiterTranslator {
+    pub fn new(
+        delimiter: Vec<u8>,
+        quote: u8,
+        escape: Option<u8>,
+        terminator: Option<u8>,
+        comment: Option<u8>,
+    ) -> Self {
+        Self {
+            delimiter,
+            quote,
+            escape,
+            terminator,
+            comment,
+            state: State::StartRecord,
+            matched: 0,
+        }
+    }
+
+    /// Translates `input` appending the output to `out`
+    ///
+    /// An empty `input` signals the end of the data
+    pub fn translate(&mut self, input: &[u8], out: &mut Vec<u8>) -> Result<(), ArrowError> {
+        out.reserve(input.len());
+        for b in input {
+            self.push(*b, out)?;
+        }
+        if input.is_empty() {
+            self.flush_matched(out)?;
+        }
+        Ok(())
+    }
+
+    fn push(&mut self, b: u8, out: &mut Vec<u8>) -> Result<(), ArrowError> {
+        match self.state {
+            State::Quoted => {
+                self.state = match b {
+                    b if Some(b) == self.escape => State::Escaped,
+                    b if b == self.quote => State::AfterQuote,
+                    _ => State::Quoted,
+                };
+                out.push(b);
+            }
+            State::Escaped => {
+                self.state = State::Quoted;
+                out.push(b);
+            }
+            State::Comment => {
+                if self.is_terminator(b) {
+                    self.state = State::StartRecord;
+                }
+                out.push(b);
+            }
+            _ if b == self.delimiter[self.matched] => {
+                self.matched += 1;
+                if self.matched == self.delimiter.len() {
+                    self.matched = 0;
+                    self.state = State::StartField;
+                    out.push(TRANSLATED_DELIMITER);
+                }
+            }
+            _ if self.matched != 0 => {
+                self.flush_matched(out)?;
+                self.push(b, out)?;
+            }
+            _ => self.push_literal(b, out)?,
+        }
+        Ok(())
+    }
+
+    /// Processes `b` outside of a quoted field as a byte not part of a delimiter
+    fn push_literal(&mut self, b: u8, out: &mut Vec<u8>) -> Result<(), ArrowError> {
+        let state = self.state;
+        self.state = match b {
+            TRANSLATED_DELIMITER => {
+                return Err(ArrowError::CsvError(format!(
+                    "Encountered byte {TRANSLATED_DELIMITER:#04x} outside of quotes, \
+                     which is not supported with a multi-byte delimiter"
+                )));
+            }
+            b if self.is_terminator(b) => State::StartRecord,
+            b if state == State::StartRecord && Some(b) == self.comment => State::Comment,
+            b if b == self.quote && state != State::Unquoted => State::Quoted,
+            _ => State::Unquoted,
+        };
+        out.push(b);
+        Ok(())
+    }
+
+    /// Outputs the bytes of a partially matched delimiter, which are not a delimiter
+    fn flush_matched(&mut self, out: &mut Vec<u8>) -> Result<(), ArrowError> {
+        let matched = std::mem::take(&mut self.matched);
+        if matched == 0 {
+            return Ok(());
+        }
+        // The first byte does not start a delimiter, but the following ones may
+        self.push_literal(self.delimiter[0], out)?;
+        for i in 1..matched {
+            self.push(self.delimiter[i], out)?;
+        }
+        Ok(())
+    }
+
+    fn is_terminator(&self, b: u8) -> bool {
+        match self.terminator {
+            Some(t) => b == t,
+            None => b == b'\n' || b == b'\r',
+        }
+    }
+}
+
+/// A [`Read`] translating the delimiter of the data read from `R`
+pub struct TranslatingRead<R> {
+    reader: R,
+    translator: DelimiterTranslator,
+    buffer: Vec<u8>,
+    offset: usize,
+    eof: bool,
+}
+
+impl<R: Read> TranslatingRead<R> {
+    pub fn new(reader: R, translator: DelimiterTranslator) -> Self {
+        Self {
+            reader,
+            translator,
+            buffer: vec![],
+            offset: 0,
+            eof: false,
+        }
+    }
+}
+
+impl<R: Read> Read for TranslatingRead<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let mut chunk = [0; 8192];
+        while self.offset == self.buffer.len() && !self.eof {
+            self.buffer.clear();
+            self.offset = 0;
+            let read = self.reader.read(&mut chunk)?;
+            self.eof = read == 0;
+            self.translator
+                .translate(&chunk[..read], &mut self.buffer)
+                .map_err(std::io::Error::other)?;
+        }
+        let available = &self.buffer[self.offset..];
+        let len = available.len().min(buf.len());
+        buf[..len].copy_from_slice(&available[..len]);
+        self.offset += len;
+        Ok(len)
+    }
+}
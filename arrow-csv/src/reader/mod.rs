@@ -163,8 +163,12 @@
 //! ```
 //!
 
+mod delimiter;
+mod parallel;
 mod records;
 
+pub use parallel::ParallelReader;
+
 use arrow_array::builder::{NullBuilder, PrimitiveBuilder};
 use arrow_array::types::*;
 use arrow_array::*;
@@ -173,12 +177,14 @@ use arrow_schema::*;
 use chrono::{TimeZone, Utc};
 use csv::StringRecord;
 use regex::{Regex, RegexSet};
+use std::collections::HashMap;
 use std::fmt::{self, Debug};
 use std::fs::File;
 use std::io::{BufRead, BufReader as StdBufReader, Read};
 use std::sync::{Arc, LazyLock};
 
 use crate::map_csv_error;
+use crate::reader::delimiter::{DelimiterTranslator, TRANSLATED_DELIMITER, TranslatingRead};
 use crate::reader::records::{RecordDecoder, StringRecords};
 use arrow_array::timezone::Tz;
 
@@ -202,6 +208,20 @@ static REGEX_SET: LazyLock<RegexSet> = LazyLock::new(|| {
 struct NullRegex(Option<Regex>);
 
 impl NullRegex {
+    /// Creates a [`NullRegex`] matching exactly one of `values`
+    fn from_values<S: AsRef<str>>(values: impl IntoIterator<Item = S>) -> Self {
+        let values: Vec<_> = values
+            .into_iter()
+            .map(|v| regex::escape(v.as_ref()))
+            .collect();
+        let pattern = match values.is_empty() {
+            // A pattern matching no value
+            true => r"[^\s\S]".to_string(),
+            false => format!("^(?:{})$", values.join("|")),
+        };
+        Self(Some(Regex::new(&pattern).unwrap()))
+    }
+
     /// Returns true if the value should be considered as `NULL` according to
     /// the provided regular expression.
     #[inline]
@@ -275,12 +295,15 @@ pub struct Format {
     header: bool,
     header_validation: bool,
     delimiter: Option<u8>,
+    multi_byte_delimiter: Option<Vec<u8>>,
     escape: Option<u8>,
     quote: Option<u8>,
     terminator: Option<u8>,
     comment: Option<u8>,
     null_regex: NullRegex,
+    column_null_regex: HashMap<usize, NullRegex>,
     truncated_rows: bool,
+    trim: bool,
 }
 
 impl Format {
@@ -305,6 +328,28 @@ impl Format {
     /// Specify a custom delimiter character, defaults to comma `','`
     pub fn with_delimiter(mut self, delimiter: u8) -> Self {
         self.delimiter = Some(delimiter);
+        self.multi_byte_delimiter = None;
+        self
+    }
+
+    /// Specify a delimiter of one or more bytes, such as `"||"`, defaults to comma `','`
+    ///
+    /// Delimiters of multiple bytes are translated to the ASCII unit separator `0x1F` before
+    /// parsing, and so data containing this byte outside of quoted fields is rejected.
+    ///
+    /// # Panics
+    ///
+    /// If `delimiter` is empty
+    pub fn with_multi_byte_delimiter(mut self, delimiter: impl Into<Vec<u8>>) -> Self {
+        let delimiter = delimiter.into();
+        match delimiter.as_slice() {
+            [] => panic!("CSV delimiter must not be empty"),
+            [delimiter] => return self.with_delimiter(*delimiter),
+            _ => {
+                self.delimiter = None;
+                self.multi_byte_delimiter = Some(delimiter);
+            }
+        }
         self
     }
 
@@ -340,6 +385,20 @@ impl Format {
         self
     }
 
+    /// Provide the values representing null in the column at index `column`, instead of
+    /// matching the regex provided by [`Self::with_null_regex`]
+    ///
+    /// Only values equal to one of `values` are null, for example `["", "NA", "N/A"]`
+    pub fn with_column_null_values<S: AsRef<str>>(
+        mut self,
+        column: usize,
+        values: impl IntoIterator<Item = S>,
+    ) -> Self {
+        let null_regex = NullRegex::from_values(values);
+        self.column_null_regex.insert(column, null_regex);
+        self
+    }
+
     /// Whether to allow truncated rows when parsing.
     ///
     /// By default this is set to `false` and will error if the CSV rows have different lengths.
@@ -351,6 +410,23 @@ impl Format {
         self
     }
 
+    /// Whether to trim leading and trailing whitespace from fields, including the header,
+    /// defaults to `false`
+    ///
+    /// Whitespace is trimmed before matching null values and parsing, and within quoted
+    /// fields as well as unquoted ones.
+    pub fn with_trim(mut self, trim: bool) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    /// Returns the [`NullRegex`] of the column at index `column`
+    fn null_regex(&self, column: usize) -> &NullRegex {
+        self.column_null_regex
+            .get(&column)
+            .unwrap_or(&self.null_regex)
+    }
+
     /// Infer schema of CSV records from the provided `reader`
     ///
     /// If `max_records` is `None`, all records will be read, otherwise up to `max_records`
@@ -394,7 +470,7 @@ impl Format {
             // they could be nullable
             for (i, column_type) in column_types.iter_mut().enumerate().take(header_length) {
                 if let Some(string) = record.get(i) {
-                    if !self.null_regex.is_null(string) {
+                    if !self.null_regex(i).is_null(string) {
                         column_type.update(string)
                     }
                 }
@@ -412,10 +488,13 @@ impl Format {
     }
 
     /// Build a [`csv::Reader`] for this [`Format`]
-    fn build_reader<R: Read>(&self, reader: R) -> csv::Reader<R> {
+    fn build_reader<'a, R: Read + 'a>(&self, reader: R) -> csv::Reader<Box<dyn Read + 'a>> {
         let mut builder = csv::ReaderBuilder::new();
         builder.has_headers(self.header);
         builder.flexible(self.truncated_rows);
+        if self.trim {
+            builder.trim(csv::Trim::All);
+        }
 
         if let Some(c) = self.delimiter {
             builder.delimiter(c);
@@ -430,7 +509,25 @@ impl Format {
         if let Some(comment) = self.comment {
             builder.comment(Some(comment));
         }
-        builder.from_reader(reader)
+        match self.build_translator() {
+            Some(translator) => {
+                builder.delimiter(TRANSLATED_DELIMITER);
+                builder.from_reader(Box::new(TranslatingRead::new(reader, translator)))
+            }
+            None => builder.from_reader(Box::new(reader)),
+        }
+    }
+
+    /// Build a [`DelimiterTranslator`] if this [`Format`] has a multi-byte delimiter
+    fn build_translator(&self) -> Option<DelimiterTranslator> {
+        let delimiter = self.multi_byte_delimiter.clone()?;
+        Some(DelimiterTranslator::new(
+            delimiter,
+            self.quote.unwrap_or(b'"'),
+            self.escape,
+            self.terminator,
+            self.comment,
+        ))
     }
 
     /// Build a [`csv_core::Reader`] for this [`Format`]
@@ -442,6 +539,9 @@ impl Format {
         if let Some(c) = self.delimiter {
             builder.delimiter(c);
         }
+        if self.multi_byte_delimiter.is_some() {
+            builder.delimiter(TRANSLATED_DELIMITER);
+        }
         if let Some(c) = self.quote {
             builder.quote(c);
         }
@@ -635,6 +735,9 @@ pub struct Decoder {
 
     /// Check if the string matches this pattern for `NULL`.
     null_regex: NullRegex,
+
+    /// Overrides of `null_regex` for specific columns
+    column_null_regex: HashMap<usize, NullRegex>,
 }
 
 impl Decoder {
@@ -695,6 +798,7 @@ impl Decoder {
             self.projection.as_ref(),
             self.line_number,
             &self.null_regex,
+            &self.column_null_regex,
         )?;
         self.line_number += rows.len();
         Ok(Some(batch))
@@ -732,6 +836,7 @@ fn parse(
     projection: Option<&Vec<usize>>,
     line_number: usize,
     null_regex: &NullRegex,
+    column_null_regex: &HashMap<usize, NullRegex>,
 ) -> Result<RecordBatch, ArrowError> {
     let projection: Vec<usize> = match projection {
         Some(v) => v.clone(),
@@ -743,6 +848,7 @@ fn parse(
         .map(|i| {
             let i = *i;
             let field = &fields[i];
+            let null_regex = column_null_regex.get(&i).unwrap_or(null_regex);
             match field.data_type() {
                 DataType::Boolean => build_boolean_array(line_number, rows, i, null_regex),
                 DataType::Decimal32(precision, scale) => build_decimal_array::<Decimal32Type>(
@@ -1145,7 +1251,7 @@ fn build_boolean_array(
 }
 
 /// Builder for CSV [`Reader`]s
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ReaderBuilder {
     /// Schema of the CSV file
     schema: SchemaRef,
@@ -1216,7 +1322,41 @@ impl ReaderBuilder {
 
     /// Set the CSV file's column delimiter as a byte character
     pub fn with_delimiter(mut self, delimiter: u8) -> Self {
-        self.format.delimiter = Some(delimiter);
+        self.format = self.format.with_delimiter(delimiter);
+        self
+    }
+
+    /// Set the CSV file's column delimiter as one or more bytes, such as `"||"`
+    ///
+    /// See [`Format::with_multi_byte_delimiter`] for details
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use arrow_array::cast::AsArray;
+    /// # use arrow_array::types::Int32Type;
+    /// # use arrow_csv::ReaderBuilder;
+    /// # use arrow_schema::{DataType, Field, Schema};
+    /// # use std::sync::Arc;
+    /// let data = "a || 1\n\"b||c\" || NA\n";
+    /// let schema = Schema::new(vec![
+    ///     Field::new("name", DataType::Utf8, false),
+    ///     Field::new("value", DataType::Int32, true),
+    /// ]);
+    /// let mut reader = ReaderBuilder::new(Arc::new(schema))
+    ///     .with_multi_byte_delimiter("||")
+    ///     .with_column_null_values(1, ["", "NA"])
+    ///     .with_trim(true)
+    ///     .build(data.as_bytes())
+    ///     .unwrap();
+    /// let batch = reader.next().unwrap().unwrap();
+    /// let names = batch.column(0).as_string::<i32>();
+    /// assert_eq!(names.iter().collect::<Vec<_>>(), [Some("a"), Some("b||c")]);
+    /// let values = batch.column(1).as_primitive::<Int32Type>();
+    /// assert_eq!(values.iter().collect::<Vec<_>>(), [Some(1), None]);
+    /// ```
+    pub fn with_multi_byte_delimiter(mut self, delimiter: impl Into<Vec<u8>>) -> Self {
+        self.format = self.format.with_multi_byte_delimiter(delimiter);
         self
     }
 
@@ -1250,6 +1390,26 @@ impl ReaderBuilder {
         self
     }
 
+    /// Provide the values representing null in the column at index `column`
+    ///
+    /// See [`Format::with_column_null_values`] for details
+    pub fn with_column_null_values<S: AsRef<str>>(
+        mut self,
+        column: usize,
+        values: impl IntoIterator<Item = S>,
+    ) -> Self {
+        self.format = self.format.with_column_null_values(column, values);
+        self
+    }
+
+    /// Set whether to trim leading and trailing whitespace from fields
+    ///
+    /// See [`Format::with_trim`] for details
+    pub fn with_trim(mut self, trim: bool) -> Self {
+        self.format.trim = trim;
+        self
+    }
+
     /// Set the batch size (number of records to load at one time)
     pub fn with_batch_size(mut self, batch_size: usize) -> Self {
         self.batch_size = batch_size;
@@ -1296,6 +1456,48 @@ impl ReaderBuilder {
         })
     }
 
+    /// Create a new [`ParallelReader`] decoding `reader` on up to `num_threads` threads
+    ///
+    /// Bounds set with [`Self::with_bounds`] are not supported
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use arrow_csv::ReaderBuilder;
+    /// # use arrow_schema::{DataType, Field, Schema};
+    /// # use std::sync::Arc;
+    /// let data = "a,b\n1,x\n2,\"y\nz\"\n";
+    /// let schema = Schema::new(vec![
+    ///     Field::new("a", DataType::Int32, false),
+    ///     Field::new("b", DataType::Utf8, false),
+    /// ]);
+    /// let reader = ReaderBuilder::new(Arc::new(schema))
+    ///     .with_header(true)
+    ///     .build_parallel(data.as_bytes(), 4)
+    ///     .unwrap();
+    /// let num_rows: usize = reader.map(|batch| batch.unwrap().num_rows()).sum();
+    /// assert_eq!(num_rows, 2);
+    /// ```
+    pub fn build_parallel<R: Read>(
+        self,
+        reader: R,
+        num_threads: usize,
+    ) -> Result<ParallelReader<R>, ArrowError> {
+        ParallelReader::try_new(reader, self, num_threads)
+    }
+
+    /// Returns the schema of the batches read, after any projection
+    fn projected_schema(&self) -> SchemaRef {
+        match &self.projection {
+            Some(projection) => {
+                let fields = self.schema.fields();
+                let projected = projection.iter().map(|i| fields[*i].clone());
+                Arc::new(Schema::new(projected.collect::<Fields>()))
+            }
+            None => self.schema.clone(),
+        }
+    }
+
     /// Builds a decoder that can be used to decode CSV from an arbitrary byte stream
     pub fn build_decoder(self) -> Decoder {
         let delimiter = self.format.build_parser();
@@ -1303,7 +1505,9 @@ impl ReaderBuilder {
             delimiter,
             self.schema.fields().len(),
             self.format.truncated_rows,
-        );
+        )
+        .with_trim(self.format.trim)
+        .with_translator(self.format.build_translator());
 
         let header = self.format.header as usize;
 
@@ -1322,6 +1526,7 @@ impl ReaderBuilder {
             projection: self.projection,
             batch_size: self.batch_size,
             null_regex: self.format.null_regex,
+            column_null_regex: self.format.column_null_regex,
         }
     }
 }
@@ -2988,4 +3193,110 @@ mod tests {
         assert_eq!(c2.value(1), "something_cannot_be_inlined");
         assert_eq!(c2.value(2), "bar");
     }
+
+    #[test]
+    fn test_multi_byte_delimiter() {
+        let csv = "# comment with \"unbalanced || quote\n\
+                   a||b|c||\"d||\"\"e\"\n\
+                   |||x|||y\n\
+                   ||z||\n";
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("c1", DataType::Utf8, true),
+            Field::new("c2", DataType::Utf8, true),
+            Field::new("c3", DataType::Utf8, true),
+        ]));
+        let expected = [
+            [Some("a"), Some("b|c"), Some("d||\"e")],
+            [None, Some("|x"), Some("|y")],
+            [None, Some("z"), None],
+        ];
+
+        for chunk_size in [1, 2, 3, 5, csv.len()] {
+            for batch_size in [1, 2, 3] {
+                let mut decoder = ReaderBuilder::new(schema.clone())
+                    .with_multi_byte_delimiter("||")
+                    .with_comment(b'#')
+                    .with_batch_size(batch_size)
+                    .build_decoder();
+
+                let mut rows = vec![];
+                let mut input = csv.as_bytes();
+                loop {
+                    let chunk = &input[..chunk_size.min(input.len())];
+                    let decoded = decoder.decode(chunk).unwrap();
+                    input = &input[decoded..];
+                    if decoded == 0 || decoder.capacity() == 0 {
+                        let Some(batch) = decoder.flush().unwrap() else {
+                            break;
+                        };
+                        for row in 0..batch.num_rows() {
+                            let row: Vec<_> = (0..3)
+                                .map(|c| {
+                                    let c = batch.column(c).as_string::<i32>();
+                                    c.is_valid(row).then(|| c.value(row).to_string())
+                                })
+                                .collect();
+                            rows.push(row);
+                        }
+                    }
+                }
+                let expected: Vec<Vec<_>> = expected
+                    .iter()
+                    .map(|r| r.iter().map(|v| v.map(String::from)).collect())
+                    .collect();
+                assert_eq!(rows, expected, "{chunk_size} {batch_size}");
+            }
+        }
+
+        let (schema, _) = Format::default()
+            .with_multi_byte_delimiter("||")
+            .with_header(true)
+            .infer_schema("a||b\n1||x\n".as_bytes(), None)
+            .unwrap();
+        assert_eq!(schema.field(0).name(), "a");
+        assert_eq!(schema.field(0).data_type(), &DataType::Int64);
+        assert_eq!(schema.field(1).data_type(), &DataType::Utf8);
+
+        let mut reader = ReaderBuilder::new(Arc::new(schema))
+            .with_multi_byte_delimiter("||")
+            .build("a\u{1F}||b\n".as_bytes())
+            .unwrap();
+        let err = reader.next().unwrap().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Csv error: Encountered byte 0x1f outside of quotes, which is not supported with a multi-byte delimiter"
+        );
+    }
+
+    #[test]
+    fn test_column_null_values_and_trim() {
+        let csv = " NA , NA ,1\n-, ,  2\n";
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("c1", DataType::Utf8, true),
+            Field::new("c2", DataType::Utf8, true),
+            Field::new("c3", DataType::Int32, true),
+        ]));
+
+        let mut reader = ReaderBuilder::new(schema)
+            .with_column_null_values(0, ["NA", "-"])
+            .with_column_null_values(1, Vec::<String>::new())
+            .with_trim(true)
+            .build(csv.as_bytes())
+            .unwrap();
+        let batch = reader.next().unwrap().unwrap();
+        let c1 = batch.column(0).as_string::<i32>();
+        assert_eq!(c1.iter().collect::<Vec<_>>(), [None, None]);
+        let c2 = batch.column(1).as_string::<i32>();
+        assert_eq!(c2.iter().collect::<Vec<_>>(), [Some("NA"), Some("")]);
+        let c3 = batch.column(2).as_primitive::<Int32Type>();
+        assert_eq!(c3.iter().collect::<Vec<_>>(), [Some(1), Some(2)]);
+
+        let (schema, _) = Format::default()
+            .with_column_null_values(0, ["NA"])
+            .with_trim(true)
+            .infer_schema(" NA , 1\n 2 ,NA\n".as_bytes(), None)
+            .unwrap();
+        assert_eq!(schema.field(0).data_type(), &DataType::Int64);
+        assert_eq!(schema.field(1).data_type(), &DataType::Utf8);
+    }
 }
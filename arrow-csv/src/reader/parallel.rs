@@ -0,0 +1,376 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Parallel decoding of CSV data, see [`ParallelReader`]
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::io::Read;
+
+use arrow_array::{RecordBatch, RecordBatchReader};
+use arrow_schema::{ArrowError, SchemaRef};
+
+use super::{Decoder, ReaderBuilder};
+
+/// The default target size in bytes of the ranges decoded by each thread
+const DEFAULT_RANGE_SIZE: usize = 8 * 1024 * 1024;
+
+/// A CSV reader decoding record-aligned byte ranges of its input concurrently
+///
+/// The input is read in blocks, each split into up to `num_threads` ranges ending on record
+/// boundaries, which are decoded concurrently on scoped threads. The resulting batches are
+/// yielded in the order of the input, with each range yielding at least one batch, so batches
+/// may have less than the batch size of the [`ReaderBuilder`] rows even before the end of the
+/// input.
+///
+/// Record boundaries are found by scanning for line terminators outside of quoted fields, and so
+/// quotes must only be used to delimit quoted fields. Line numbers in error messages are relative
+/// to the start of the range being decoded.
+///
+/// Created with [`ReaderBuilder::build_parallel`]
+pub struct ParallelReader<R> {
+    reader: R,
+    builder: ReaderBuilder,
+    num_threads: usize,
+    range_size: usize,
+    /// The input read but not yet decoded, starting at a record boundary
+    buffer: Vec<u8>,
+    /// Whether the header, if any, is yet to be skipped
+    skip_header: bool,
+    eof: bool,
+    /// Decoded batches yet to be returned
+    batches: VecDeque<RecordBatch>,
+    /// An error to return once the batches decoded before it are returned
+    error: Option<ArrowError>,
+}
+
+impl<R> fmt::Debug for ParallelReader<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ParallelReader")
+            .field("builder", &self.builder)
+            .field("num_threads", &self.num_threads)
+            .field("range_size", &self.range_size)
+            .finish()
+    }
+}
+
+impl<R: Read> ParallelReader<R> {
+    pub(super) fn try_new(
+        reader: R,
+        builder: ReaderBuilder,
+        num_threads: usize,
+    ) -> Result<Self, ArrowError> {
+        if num_threads == 0 {
+            return Err(ArrowError::InvalidArgumentError(
+                "Number of threads must be greater than 0".to_string(),
+            ));
+        }
+        if builder.bounds.is_some() {
+            return Err(ArrowError::InvalidArgumentError(
+                "Bounds are not supported by the parallel CSV reader".to_string(),
+            ));
+        }
+        Ok(Self {
+            reader,
+            skip_header: builder.format.header,
+            builder,
+            num_threads,
+            range_size: DEFAULT_RANGE_SIZE,
+            buffer: vec![],
+            eof: false,
+            batches: VecDeque::new(),
+            error: None,
+        })
+    }
+
+    /// Returns the schema of the batches read
+    pub fn schema(&self) -> SchemaRef {
+        self.builder.projected_schema()
+    }
+
+    /// Reads the next block of input, returning the end of its last complete record
+    fn fill(&mut self) -> Result<usize, ArrowError> {
+        let mut target = self.num_threads * self.range_size;
+        loop {
+            while self.buffer.len() < target && !self.eof {
+                let len = self.buffer.len();
+                self.buffer.resize(target, 0);
+                let read = self.reader.read(&mut self.buffer[len..])?;
+                self.buffer.truncate(len + read);
+                self.eof = read == 0;
+            }
+            if self.eof {
+                return Ok(self.buffer.len());
+            }
+            match self.last_boundary() {
+                Some(end) => return Ok(end),
+                // The block does not contain a complete record, read more
+                None => target += self.num_threads * self.range_size,
+            }
+        }
+    }
+
+    /// Returns the end of the last complete record in the buffer
+    fn last_boundary(&self) -> Option<usize> {
+        let mut last = None;
+        self.scan(&self.buffer, |end| last = Some(end));
+        last
+    }
+
+    /// Splits `data`, which ends on a record boundary, into ranges of about `range_size` bytes
+    fn split(&self, data: &[u8]) -> Vec<(usize, usize)> {
+        let mut ranges = vec![];
+        let mut start = 0;
+        self.scan(data, |end| {
+            if end - start >= self.range_size {
+                ranges.push((start, end));
+                start = end;
+            }
+        });
+        if start < data.len() {
+            ranges.push((start, data.len()));
+        }
+        ranges
+    }
+
+    /// Calls `f` with the end of each record in `data`, which starts on a record boundary
+    ///
+    /// As for the serial reader, without a custom terminator each `\r` and `\n` ends a record,
+    /// with the empty record between the `\r` and `\n` of a CRLF ignored by the [`Decoder`], and
+    /// comments always end at a `\n`
+    fn scan(&self, data: &[u8], mut f: impl FnMut(usize)) {
+        let format = &self.builder.format;
+        let quote = format.quote.unwrap_or(b'"');
+        let is_terminator = |b: u8| match format.terminator {
+            Some(t) => b == t,
+            None => b == b'\n' || b == b'\r',
+        };
+
+        let mut quoted = false;
+        let mut escaped = false;
+        let mut record_start = true;
+        let mut comment = false;
+        for (idx, b) in data.iter().enumerate() {
+            let b = *b;
+            if comment {
+                comment = b != b'\n';
+                record_start = !comment;
+                continue;
+            }
+            if record_start && format.comment == Some(b) {
+                comment = true;
+                continue;
+            }
+            record_start = false;
+            if escaped {
+                escaped = false;
+            } else if quoted && format.escape == Some(b) {
+                escaped = true;
+            } else if b == quote {
+                quoted = !quoted;
+            } else if is_terminator(b) && !quoted {
+                record_start = true;
+                f(idx + 1);
+            }
+        }
+    }
+
+    /// Returns a [`Decoder`] for a range of the input
+    fn decoder(&self, skip_header: bool) -> Decoder {
+        let mut builder = self.builder.clone();
+        if !skip_header {
+            builder.format.header = false;
+        }
+        builder.build_decoder()
+    }
+
+    /// Decodes the next block of input to `self.batches`
+    fn read_block(&mut self) -> Result<(), ArrowError> {
+        let end = self.fill()?;
+        let data = &self.buffer[..end];
+        let ranges = self.split(data);
+
+        let mut decoders: Vec<_> = (0..ranges.len())
+            .map(|i| self.decoder(i == 0 && self.skip_header))
+            .collect();
+        let results: Vec<Result<Vec<_>, _>> = std::thread::scope(|scope| {
+            let handles: Vec<_> = ranges
+                .iter()
+                .zip(&mut decoders)
+                .map(|((start, end), decoder)| {
+                    let range = &data[*start..*end];
+                    scope.spawn(move || decode_range(decoder, range))
+                })
+                .collect();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().expect("CSV decoding thread panicked"))
+                .collect()
+        });
+
+        if !ranges.is_empty() {
+            self.skip_header = false;
+        }
+        self.buffer.drain(..end);
+        // Return the batches of the ranges before any error
+        for result in results {
+            self.batches.extend(result?);
+        }
+        Ok(())
+    }
+}
+
+/// Decodes all the records in `range` with `decoder`
+fn decode_range(decoder: &mut Decoder, mut range: &[u8]) -> Result<Vec<RecordBatch>, ArrowError> {
+    let mut batches = vec![];
+    loop {
+        let decoded = decoder.decode(range)?;
+        range = &range[decoded..];
+        if decoded == 0 || decoder.capacity() == 0 {
+            match decoder.flush()? {
+                Some(batch) => batches.push(batch),
+                None if range.is_empty() => return Ok(batches),
+                None => {}
+            }
+        }
+    }
+}
+
+impl<R: Read> Iterator for ParallelReader<R> {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(batch) = self.batches.pop_front() {
+                return Some(Ok(batch));
+            }
+            if let Some(error) = self.error.take() {
+                return Some(Err(error));
+            }
+            if self.eof && self.buffer.is_empty() {
+                return None;
+            }
+            if let Err(error) = self.read_block() {
+                // Stop reading after an error
+                self.eof = true;
+                self.buffer.clear();
+                self.error = Some(error);
+            }
+        }
+    }
+}
+
+impl<R: Read> RecordBatchReader for ParallelReader<R> {
+    fn schema(&self) -> SchemaRef {
+        self.schema()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int32Type;
+    use arrow_schema::{DataType, Field, Schema};
+    use std::sync::Arc;
+
+    fn rows(batches: &[RecordBatch]) -> Vec<(i32, Option<&str>)> {
+        batches
+            .iter()
+            .flat_map(|b| {
+                let ids = b.column(0).as_primitive::<Int32Type>();
+                let names = b.column(1).as_string::<i32>();
+                ids.values().iter().copied().zip(names)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_parallel_reader() {
+        for eol in ["\n", "\r", "\r\n"] {
+            test_parallel_reader_line_ending(eol);
+        }
+    }
+
+    fn test_parallel_reader_line_ending(eol: &str) {
+        let mut csv = format!("id,name{eol}# a \"comment\n");
+        for i in 0..500 {
+            match i % 3 {
+                0 => csv.push_str(&format!("{i},\"multi\nline, \"\"{i}\"\"\"{eol}")),
+                1 => csv.push_str(&format!("{i},{eol}")),
+                _ => csv.push_str(&format!("{i},name {i}{eol}")),
+            }
+        }
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new("name", DataType::Utf8, true),
+        ]));
+        let builder = || {
+            ReaderBuilder::new(schema.clone())
+                .with_header(true)
+                .with_comment(b'#')
+                .with_batch_size(64)
+        };
+
+        let batches = builder()
+            .build(csv.as_bytes())
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let expected = rows(&batches);
+
+        for (num_threads, range_size) in [(1, 1), (3, 7), (4, 100), (8, 1 << 20)] {
+            let mut reader = builder()
+                .build_parallel(csv.as_bytes(), num_threads)
+                .unwrap();
+            reader.range_size = range_size;
+            if range_size < csv.len() {
+                assert!(reader.split(csv.as_bytes()).len() > 1);
+            }
+            let actual = reader.collect::<Result<Vec<_>, _>>().unwrap();
+            assert!(actual.iter().all(|b| b.num_rows() <= 64));
+            assert_eq!(rows(&actual), expected);
+        }
+
+        assert_eq!(expected.len(), 500);
+        assert_eq!(expected[3], (3, Some("multi\nline, \"3\"")));
+        assert_eq!(expected[4], (4, None));
+    }
+
+    #[test]
+    fn test_parallel_reader_errors() {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+
+        let err = ReaderBuilder::new(schema.clone())
+            .with_bounds(0, 10)
+            .build_parallel("1\n".as_bytes(), 2)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Bounds are not supported by the parallel CSV reader"
+        );
+
+        let mut reader = ReaderBuilder::new(schema)
+            .build_parallel("1\n2\nx\n4\n".as_bytes(), 2)
+            .unwrap();
+        reader.range_size = 2;
+        let results: Vec<_> = reader.collect();
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[1].as_ref().unwrap().num_rows(), 1);
+        assert!(results[2].is_err());
+    }
+}
@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use crate::reader::delimiter::DelimiterTranslator;
 use arrow_schema::ArrowError;
 use csv_core::{ReadRecordResult, Reader};
 
@@ -62,6 +63,15 @@ pub struct RecordDecoder {
     /// Default value is false
     /// When enabled fills in missing columns with null
     truncated_rows: bool,
+
+    /// Whether to trim leading and trailing whitespace from fields
+    trim: bool,
+
+    /// Translates a delimiter of multiple bytes before decoding, if any
+    translator: Option<DelimiterTranslator>,
+
+    /// Translated input not yet decoded
+    translated: Vec<u8>,
 }
 
 impl RecordDecoder {
@@ -77,9 +87,24 @@ impl RecordDecoder {
             data: vec![],
             num_rows: 0,
             truncated_rows,
+            trim: false,
+            translator: None,
+            translated: vec![],
         }
     }
 
+    /// Sets whether to trim leading and trailing whitespace from fields
+    pub fn with_trim(mut self, trim: bool) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    /// Sets the [`DelimiterTranslator`] to translate the input with before decoding
+    pub fn with_translator(mut self, translator: Option<DelimiterTranslator>) -> Self {
+        self.translator = translator;
+        self
+    }
+
     /// Decodes records from `input` returning the number of records and bytes read
     ///
     /// Note: this expects to be called with an empty `input` to signal EOF
@@ -88,6 +113,51 @@ impl RecordDecoder {
             return Ok((0, 0));
         }
 
+        if self.translator.is_none() {
+            return self.decode_records(input, to_read);
+        }
+
+        // Decode any translated bytes buffered by a previous call first, without
+        // consuming `input`, so that at most one input is buffered at a time
+        let mut translated = std::mem::take(&mut self.translated);
+        let mut read = 0;
+        if !translated.is_empty() {
+            let bytes;
+            (read, bytes) = self.decode_records(&translated, to_read)?;
+            translated.drain(..bytes);
+            if read == to_read {
+                self.translated = translated;
+                return Ok((read, 0));
+            }
+        }
+
+        // All of `input` is consumed, with the translated bytes not yet decoded
+        // being buffered until the next call
+        self.translator
+            .as_mut()
+            .unwrap()
+            .translate(input, &mut translated)?;
+        if translated.is_empty() && !input.is_empty() {
+            // An empty input would be interpreted as EOF
+            return Ok((read, input.len()));
+        }
+        let (decoded, bytes) = self.decode_records(&translated, to_read - read)?;
+        read += decoded;
+        translated.drain(..bytes);
+        if input.is_empty() && translated.is_empty() && bytes != 0 && read < to_read {
+            // Signal EOF now that the translated bytes have been decoded
+            read += self.decode_records(&[], to_read - read)?.0;
+        }
+        self.translated = translated;
+        Ok((read, input.len()))
+    }
+
+    /// Decodes records from `input` after any translation, see [`Self::decode`]
+    fn decode_records(
+        &mut self,
+        input: &[u8],
+        to_read: usize,
+    ) -> Result<(usize, usize), ArrowError> {
         // Reserve sufficient capacity in offsets
         self.offsets
             .resize(self.offsets_len + to_read * self.num_columns, 0);
@@ -246,6 +316,7 @@ impl RecordDecoder {
             num_columns: self.num_columns,
             offsets,
             data,
+            trim: self.trim,
         })
     }
 }
@@ -257,6 +328,7 @@ pub struct StringRecords<'a> {
     num_rows: usize,
     offsets: &'a [usize],
     data: &'a str,
+    trim: bool,
 }
 
 impl<'a> StringRecords<'a> {
@@ -265,6 +337,7 @@ impl<'a> StringRecords<'a> {
         StringRecord {
             data: self.data,
             offsets: &self.offsets[field_idx..field_idx + self.num_columns + 1],
+            trim: self.trim,
         }
     }
 
@@ -282,6 +355,7 @@ impl<'a> StringRecords<'a> {
 pub struct StringRecord<'a> {
     data: &'a str,
     offsets: &'a [usize],
+    trim: bool,
 }
 
 impl<'a> StringRecord<'a> {
@@ -291,7 +365,11 @@ impl<'a> StringRecord<'a> {
 
         // SAFETY:
         // Parsing produces offsets at valid byte boundaries
-        unsafe { self.data.get_unchecked(start..end) }
+        let field = unsafe { self.data.get_unchecked(start..end) };
+        match self.trim {
+            true => field.trim(),
+            false => field,
+        }
     }
 }
 
@@ -312,8 +390,9 @@ impl std::fmt::Display for StringRecord<'_> {
 
 #[cfg(test)]
 mod tests {
+    use crate::reader::delimiter::{DelimiterTranslator, TRANSLATED_DELIMITER};
     use crate::reader::records::RecordDecoder;
-    use csv_core::Reader;
+    use csv_core::{Reader, ReaderBuilder};
     use std::io::{BufRead, BufReader, Cursor};
 
     #[test]
@@ -405,6 +484,35 @@ mod tests {
         assert_eq!(bytes, csv.len());
     }
 
+    #[test]
+    fn test_translated_input_buffered_once() {
+        let translator = DelimiterTranslator::new(b"||".to_vec(), b'"', None, None, None);
+        let mut reader = ReaderBuilder::new();
+        reader.delimiter(TRANSLATED_DELIMITER);
+        let mut decoder =
+            RecordDecoder::new(reader.build(), 2, false).with_translator(Some(translator));
+
+        let csv: String = (0..100).map(|i| format!("{i}||v{i}\n")).collect();
+        let (read, bytes) = decoder.decode(csv.as_bytes(), 10).unwrap();
+        assert_eq!((read, bytes), (10, csv.len()));
+
+        // The buffered rows are decoded without consuming more input
+        let mut rows = 10;
+        while rows < 100 {
+            let (read, bytes) = decoder.decode(csv.as_bytes(), 10).unwrap();
+            assert_eq!((read, bytes), (10, 0));
+            assert!(decoder.translated.len() < csv.len());
+            rows += read;
+        }
+        assert!(decoder.translated.is_empty());
+        assert_eq!(decoder.decode(&[], 10).unwrap(), (0, 0));
+
+        let records = decoder.flush().unwrap();
+        assert_eq!(records.len(), 100);
+        let last = records.iter().last().unwrap();
+        assert_eq!((last.get(0), last.get(1)), ("99", "v99"));
+    }
+
     /// Regression test for an overflow path found by the `arrow-csv`
     /// cargo-fuzz harness being prototyped for #5332. Stages the
     /// `RecordDecoder` state directly so that rebasing the second row's
@@ -18,7 +18,8 @@
 //! CSV Writing: [`Writer`] and [`WriterBuilder`]
 //!
 //! This CSV writer allows Arrow data (in record batches) to be written as CSV files.
-//! The writer does not support writing `ListArray` and `StructArray`.
+//! By default the writer does not support writing nested types, such as `ListArray` and
+//! `StructArray`, see [`NestedFormat`] to write them.
 //!
 //! # Example
 //! ```
@@ -172,12 +173,56 @@
 //!     "\"product\",\"price\"\n\"apple\",\"1.5\"\n\"banana,organic\",\"2.25\"\n\"cherry\",\"3.0\"\n"
 //! );
 //! ```
+//!
+//! # Nested Types
+//!
+//! Columns of nested types, such as lists, structs and maps, can be written as the JSON text
+//! of their values with `NestedFormat::Json`, which requires the `json` feature, or with a
+//! custom [`NestedFormatter`]. The resulting cells are quoted according to the [`QuoteStyle`],
+//! like any other values.
+//!
+//! ```
+//! # #[cfg(feature = "json")] {
+//! # use arrow_array::*;
+//! # use arrow_array::types::Int32Type;
+//! # use arrow_csv::WriterBuilder;
+//! # use arrow_csv::writer::NestedFormat;
+//! # use arrow_schema::*;
+//! # use std::sync::Arc;
+//! let id = Int32Array::from(vec![1, 2, 3]);
+//! let tags = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+//!     Some(vec![Some(1), Some(2)]),
+//!     None,
+//!     Some(vec![None]),
+//! ]);
+//! let batch = RecordBatch::try_from_iter([
+//!     ("id", Arc::new(id) as ArrayRef),
+//!     ("tags", Arc::new(tags) as ArrayRef),
+//! ])
+//! .unwrap();
+//!
+//! let mut output = Vec::new();
+//! WriterBuilder::new()
+//!     .with_nested_format(NestedFormat::Json)
+//!     .build(&mut output)
+//!     .write(&batch)
+//!     .unwrap();
+//! assert_eq!(
+//!     String::from_utf8(output).unwrap(),
+//!     "id,tags\n1,\"[1,2]\"\n2,\n3,[null]\n"
+//! );
+//! # }
+//! ```
 
 use arrow_array::*;
 use arrow_cast::display::*;
+#[cfg(feature = "json")]
+use arrow_json::writer::{EncoderOptions, NullableEncoder, make_encoder};
 use arrow_schema::*;
 use csv::ByteRecord;
+use std::fmt::Debug;
 use std::io::Write;
+use std::sync::Arc;
 
 use crate::map_csv_error;
 const DEFAULT_NULL_VALUE: &str = "";
@@ -198,6 +243,37 @@ const DEFAULT_NULL_VALUE: &str = "";
 /// ```
 pub use csv::QuoteStyle;
 
+/// How the values of columns of nested types, such as lists, structs and maps, are written
+///
+/// Null values are written as the null value of the [`WriterBuilder`], whatever the format.
+#[derive(Debug, Clone, Default)]
+pub enum NestedFormat {
+    /// Return an error when writing nested types (default)
+    #[default]
+    Error,
+    /// Write the JSON text of the values, for example `[1,2]` or `{"a":1,"b":null}`
+    ///
+    /// Requires the `json` feature
+    #[cfg(feature = "json")]
+    Json,
+    /// Write the values with the provided [`NestedFormatter`]
+    Custom(Arc<dyn NestedFormatter>),
+}
+
+/// Formats the values of columns of nested types, see [`NestedFormat::Custom`]
+pub trait NestedFormatter: Debug + Send + Sync {
+    /// Writes the non-null value at index `idx` of `array` to `out`
+    fn format(&self, array: &dyn Array, idx: usize, out: &mut String) -> Result<(), ArrowError>;
+}
+
+/// Formats the values of a column
+enum ColumnFormatter<'a> {
+    Display(ArrayFormatter<'a>),
+    #[cfg(feature = "json")]
+    Json(NullableEncoder<'a>),
+    Custom(&'a dyn NestedFormatter, &'a dyn Array),
+}
+
 /// A CSV writer
 ///
 /// See the [module documentation](crate::writer) for examples.
@@ -225,6 +301,8 @@ pub struct Writer<W: Write> {
     ignore_leading_whitespace: bool,
     /// Whether to ignore trailing whitespace in string values
     ignore_trailing_whitespace: bool,
+    /// How to write the values of nested types
+    nested_format: NestedFormat,
 }
 
 impl<W: Write> Writer<W> {
@@ -255,46 +333,89 @@ impl<W: Write> Writer<W> {
             self.beginning = false;
         }
 
+        let null_value = self.null_value.as_deref().unwrap_or(DEFAULT_NULL_VALUE);
         let options = FormatOptions::default()
-            .with_null(self.null_value.as_deref().unwrap_or(DEFAULT_NULL_VALUE))
+            .with_null(null_value)
             .with_date_format(self.date_format.as_deref())
             .with_datetime_format(self.datetime_format.as_deref())
             .with_timestamp_format(self.timestamp_format.as_deref())
             .with_timestamp_tz_format(self.timestamp_tz_format.as_deref())
             .with_time_format(self.time_format.as_deref());
 
-        let converters = batch
+        let schema = batch.schema();
+        #[cfg(feature = "json")]
+        let json_options = EncoderOptions::default().with_explicit_nulls(true);
+
+        // `field` is only used to write nested types as JSON
+        #[cfg_attr(not(feature = "json"), allow(unused_variables))]
+        let mut converters = batch
             .columns()
             .iter()
-            .map(|a| {
-                if a.data_type().is_nested() {
-                    Err(ArrowError::CsvError(format!(
-                        "Nested type {} is not supported in CSV",
-                        a.data_type()
-                    )))
-                } else {
-                    ArrayFormatter::try_new(a.as_ref(), &options)
+            .zip(schema.fields())
+            .map(|(a, field)| match &self.nested_format {
+                _ if !a.data_type().is_nested() => Ok(ColumnFormatter::Display(
+                    ArrayFormatter::try_new(a.as_ref(), &options)?,
+                )),
+                NestedFormat::Error => Err(ArrowError::CsvError(format!(
+                    "Nested type {} is not supported in CSV",
+                    a.data_type()
+                ))),
+                #[cfg(feature = "json")]
+                NestedFormat::Json => Ok(ColumnFormatter::Json(make_encoder(
+                    field,
+                    a.as_ref(),
+                    &json_options,
+                )?)),
+                NestedFormat::Custom(formatter) => {
+                    Ok(ColumnFormatter::Custom(formatter.as_ref(), a.as_ref()))
                 }
             })
             .collect::<Result<Vec<_>, ArrowError>>()?;
 
         let mut buffer = String::with_capacity(1024);
+        #[cfg(feature = "json")]
+        let mut json_buffer = Vec::with_capacity(1024);
         let mut byte_record = ByteRecord::with_capacity(1024, converters.len());
 
         for row_idx in 0..batch.num_rows() {
             byte_record.clear();
-            for (col_idx, converter) in converters.iter().enumerate() {
-                buffer.clear();
-                converter.value(row_idx).write(&mut buffer).map_err(|e| {
+            for (col_idx, converter) in converters.iter_mut().enumerate() {
+                let map_err = |e| {
                     ArrowError::CsvError(format!(
                         "Error processing row {}, col {}: {e}",
                         row_idx + 1,
                         col_idx + 1
                     ))
-                })?;
-
-                let field_bytes =
-                    self.get_trimmed_field_bytes(&buffer, batch.column(col_idx).data_type());
+                };
+                buffer.clear();
+                let field_bytes = match converter {
+                    ColumnFormatter::Display(converter) => {
+                        converter
+                            .value(row_idx)
+                            .write(&mut buffer)
+                            .map_err(map_err)?;
+                        self.get_trimmed_field_bytes(&buffer, batch.column(col_idx).data_type())
+                    }
+                    #[cfg(feature = "json")]
+                    ColumnFormatter::Json(encoder) if encoder.is_null(row_idx) => {
+                        null_value.as_bytes()
+                    }
+                    #[cfg(feature = "json")]
+                    ColumnFormatter::Json(encoder) => {
+                        json_buffer.clear();
+                        encoder.encode(row_idx, &mut json_buffer);
+                        &json_buffer
+                    }
+                    ColumnFormatter::Custom(_, array) if array.is_null(row_idx) => {
+                        null_value.as_bytes()
+                    }
+                    ColumnFormatter::Custom(formatter, array) => {
+                        formatter
+                            .format(*array, row_idx, &mut buffer)
+                            .map_err(map_err)?;
+                        buffer.as_bytes()
+                    }
+                };
                 byte_record.push_field(field_bytes);
             }
 
@@ -380,6 +501,8 @@ pub struct WriterBuilder {
     ignore_trailing_whitespace: bool,
     /// The quoting style to use. Defaults to `QuoteStyle::Necessary`
     quote_style: QuoteStyle,
+    /// How to write the values of nested types. Defaults to `NestedFormat::Error`
+    nested_format: NestedFormat,
 }
 
 /// The line terminator to use when writing CSV files.
@@ -409,6 +532,7 @@ impl Default for WriterBuilder {
             ignore_leading_whitespace: false,
             ignore_trailing_whitespace: false,
             quote_style: QuoteStyle::default(),
+            nested_format: NestedFormat::default(),
         }
     }
 }
@@ -632,6 +756,17 @@ impl WriterBuilder {
         &self.terminator
     }
 
+    /// Set how to write the values of nested types, defaults to [`NestedFormat::Error`]
+    pub fn with_nested_format(mut self, nested_format: NestedFormat) -> Self {
+        self.nested_format = nested_format;
+        self
+    }
+
+    /// Get how to write the values of nested types
+    pub fn nested_format(&self) -> &NestedFormat {
+        &self.nested_format
+    }
+
     /// Create a new `Writer`
     pub fn build<W: Write>(self, writer: W) -> Writer<W> {
         let mut builder = csv::WriterBuilder::new();
@@ -661,6 +796,7 @@ impl WriterBuilder {
             null_value: self.null_value,
             ignore_leading_whitespace: self.ignore_leading_whitespace,
             ignore_trailing_whitespace: self.ignore_trailing_whitespace,
+            nested_format: self.nested_format,
         }
     }
 }
@@ -1426,4 +1562,75 @@ sed do eiusmod tempor,-556132.25,1,,2019-04-18T02:45:55.555,23:46:03,foo
             write_quote_style_with_null(&batch, QuoteStyle::Always, "NULL")
         );
     }
+
+    #[test]
+    fn test_write_csv_nested() {
+        let a = Int32Array::from(vec![Some(1), None, Some(3)]);
+        let b = StringArray::from(vec![Some("x,y"), Some("q"), None]);
+        let fields = Fields::from(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ]);
+        let nulls = arrow_buffer::NullBuffer::from(vec![true, true, false]);
+        let s = StructArray::new(fields, vec![Arc::new(a), Arc::new(b)], Some(nulls));
+        let batch = RecordBatch::try_from_iter([
+            ("id", Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef),
+            ("s", Arc::new(s) as ArrayRef),
+        ])
+        .unwrap();
+
+        let write = |builder: WriterBuilder| {
+            let mut writer = builder.build(Vec::new());
+            writer.write(&batch).map(|_| writer.into_inner())
+        };
+
+        let err = write(WriterBuilder::new()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Csv error: Nested type Struct(\"a\": Int32, \"b\": Utf8) is not supported in CSV"
+        );
+
+        #[cfg(feature = "json")]
+        {
+            let output = write(
+                WriterBuilder::new()
+                    .with_nested_format(NestedFormat::Json)
+                    .with_null("NULL".to_string()),
+            )
+            .unwrap();
+            assert_eq!(
+                String::from_utf8(output).unwrap(),
+                "id,s\n1,\"{\"\"a\"\":1,\"\"b\"\":\"\"x,y\"\"}\"\n2,\"{\"\"a\"\":null,\"\"b\"\":\"\"q\"\"}\"\n3,NULL\n"
+            );
+        }
+
+        #[derive(Debug)]
+        struct CountFormatter;
+
+        impl NestedFormatter for CountFormatter {
+            fn format(
+                &self,
+                array: &dyn Array,
+                idx: usize,
+                out: &mut String,
+            ) -> Result<(), ArrowError> {
+                let s = array.as_any().downcast_ref::<StructArray>().unwrap();
+                let valid = s.columns().iter().filter(|c| c.is_valid(idx)).count();
+                out.push_str(&format!("{valid} fields"));
+                Ok(())
+            }
+        }
+
+        let output = write(
+            WriterBuilder::new()
+                .with_nested_format(NestedFormat::Custom(Arc::new(CountFormatter)))
+                .with_quote_style(QuoteStyle::Always)
+                .with_line_terminator(Terminator::CRLF),
+        )
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "\"id\",\"s\"\r\n\"1\",\"2 fields\"\r\n\"2\",\"1 fields\"\r\n\"3\",\"\"\r\n"
+        );
+    }
 }
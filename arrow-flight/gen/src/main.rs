@@ -43,6 +43,16 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .truncate(true)
         .open("src/arrow.flight.protocol.rs")?;
     file.write_all("// This file was automatically generated through the build.rs script, and should not be edited.\n\n".as_bytes())?;
+    // the gRPC client and server require tonic, which is an optional dependency
+    let buffer = buffer
+        .replace(
+            "pub mod flight_service_client {",
+            "#[cfg(feature = \"grpc\")]\npub mod flight_service_client {",
+        )
+        .replace(
+            "pub mod flight_service_server {",
+            "#[cfg(feature = \"grpc\")]\npub mod flight_service_server {",
+        );
     file.write_all(buffer.as_bytes())?;
 
     let proto_dir = Path::new("../format");
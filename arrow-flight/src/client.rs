@@ -16,13 +16,16 @@
 // under the License.
 
 use crate::{
-    Action, ActionType, Criteria, Empty, FlightData, FlightDescriptor, FlightEndpoint, FlightInfo,
-    HandshakeRequest, PollInfo, PutResult, Ticket,
+    Action, ActionType, CloseSessionRequest, CloseSessionResult, Criteria, Empty, FlightData,
+    FlightDescriptor, FlightEndpoint, FlightInfo, GetSessionOptionsRequest,
+    GetSessionOptionsResult, HandshakeRequest, PollInfo, PutResult, SetSessionOptionsRequest,
+    SetSessionOptionsResult, Ticket,
     decode::FlightRecordBatchStream,
     flight_service_client::FlightServiceClient,
     r#gen::{CancelFlightInfoRequest, CancelFlightInfoResult, RenewFlightEndpointRequest},
     trailers::extract_lazy_trailers,
 };
+use arrow_array::RecordBatch;
 use arrow_schema::Schema;
 use bytes::Bytes;
 use futures::{
@@ -31,10 +34,14 @@ use futures::{
     stream::{self, BoxStream},
 };
 use prost::Message;
+use std::sync::Arc;
 use tonic::codegen::{Body, StdError};
 use tonic::{metadata::MetadataMap, transport::Channel};
 
+use crate::encode::FlightDataEncoderBuilder;
 use crate::error::{FlightError, Result};
+use crate::exchange::{ExchangeSender, exchange_channel};
+use crate::interceptor::{FlightInterceptor, Interceptors};
 use crate::streams::{FallibleRequestStream, FallibleTonicResponseStream};
 
 /// A "Mid level" [Apache Arrow Flight](https://arrow.apache.org/docs/format/Flight.html) client.
@@ -67,11 +74,14 @@ use crate::streams::{FallibleRequestStream, FallibleTonicResponseStream};
 /// assert_eq!(response, Bytes::from("Ho"));
 /// # }
 /// ```
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct FlightClient<T = Channel> {
     /// Optional grpc header metadata to include with each request
     metadata: MetadataMap,
 
+    /// Interceptors invoked for each request and message
+    interceptors: Interceptors,
+
     /// The inner client
     inner: FlightServiceClient<T>,
 }
@@ -92,10 +102,21 @@ where
     pub fn new_from_inner(inner: FlightServiceClient<T>) -> Self {
         Self {
             metadata: MetadataMap::new(),
+            interceptors: Interceptors::default(),
             inner,
         }
     }
 
+    /// Add a [`FlightInterceptor`] invoked for each subsequent request and
+    /// the [`FlightData`] messages sent and received by this client
+    ///
+    /// Interceptors are invoked in the order they are added, after the
+    /// [`Self::metadata`] has been added to each request.
+    pub fn with_interceptor(mut self, interceptor: impl FlightInterceptor) -> Self {
+        self.interceptors.push(Arc::new(interceptor));
+        self
+    }
+
     /// Return a reference to gRPC metadata included with each request
     pub fn metadata(&self) -> &MetadataMap {
         &self.metadata
@@ -156,7 +177,7 @@ where
         };
 
         // apply headers, etc
-        let request = self.make_request(stream::once(ready(request)));
+        let request = self.make_request(stream::once(ready(request)))?;
 
         let mut response_stream = self.inner.handshake(request).await?.into_inner();
 
@@ -211,16 +232,20 @@ where
     /// # }
     /// ```
     pub async fn do_get(&mut self, ticket: Ticket) -> Result<FlightRecordBatchStream> {
-        let request = self.make_request(ticket);
+        let request = self.make_request(ticket)?;
 
         let (md, response_stream, _ext) = self.inner.do_get(request).await?.into_parts();
         let (response_stream, trailers) = extract_lazy_trailers(response_stream);
 
-        Ok(FlightRecordBatchStream::new_from_flight_data(
-            response_stream.map_err(|status| status.into()),
+        let response_stream = self
+            .interceptors
+            .receive(response_stream.map_err(|status| status.into()));
+
+        Ok(
+            FlightRecordBatchStream::new_from_flight_data(response_stream)
+                .with_headers(md)
+                .with_trailers(trailers),
         )
-        .with_headers(md)
-        .with_trailers(trailers))
     }
 
     /// Make a `GetFlightInfo` call to the server with the provided
@@ -258,8 +283,12 @@ where
     ///   .expect("error fetching data");
     /// # }
     /// ```
-    pub async fn get_flight_info(&mut self, descriptor: FlightDescriptor) -> Result<FlightInfo> {
-        let request = self.make_request(descriptor);
+    pub async fn get_flight_info(
+        &mut self,
+        mut descriptor: FlightDescriptor,
+    ) -> Result<FlightInfo> {
+        self.interceptors.on_descriptor(&mut descriptor)?;
+        let request = self.make_request(descriptor)?;
 
         let response = self.inner.get_flight_info(request).await?.into_inner();
         Ok(response)
@@ -316,8 +345,9 @@ where
     ///   .expect("error fetching data");
     /// # }
     /// ```
-    pub async fn poll_flight_info(&mut self, descriptor: FlightDescriptor) -> Result<PollInfo> {
-        let request = self.make_request(descriptor);
+    pub async fn poll_flight_info(&mut self, mut descriptor: FlightDescriptor) -> Result<PollInfo> {
+        self.interceptors.on_descriptor(&mut descriptor)?;
+        let request = self.make_request(descriptor)?;
 
         let response = self.inner.poll_flight_info(request).await?.into_inner();
         Ok(response)
@@ -372,10 +402,10 @@ where
         let (sender, receiver) = futures::channel::oneshot::channel();
 
         // Intercepts client errors and sends them to the oneshot channel above
-        let request = Box::pin(request); // Pin to heap
+        let request = Box::pin(self.interceptors.send(request)); // Pin to heap
         let request_stream = FallibleRequestStream::new(sender, request);
 
-        let request = self.make_request(request_stream);
+        let request = self.make_request(request_stream)?;
         let response_stream = self.inner.do_put(request).await?.into_inner();
 
         // Forwards errors from the error oneshot with priority over responses from server
@@ -425,18 +455,76 @@ where
     ) -> Result<FlightRecordBatchStream> {
         let (sender, receiver) = futures::channel::oneshot::channel();
 
-        let request = Box::pin(request);
+        let request = Box::pin(self.interceptors.send(request));
         // Intercepts client errors and sends them to the oneshot channel above
         let request_stream = FallibleRequestStream::new(sender, request);
 
-        let request = self.make_request(request_stream);
+        let request = self.make_request(request_stream)?;
         let response_stream = self.inner.do_exchange(request).await?.into_inner();
 
         let response_stream = Box::pin(response_stream);
         let error_stream = FallibleTonicResponseStream::new(receiver, response_stream);
 
         // combine the response from the server and any error from the client
-        Ok(FlightRecordBatchStream::new_from_flight_data(error_stream))
+        let response_stream = self.interceptors.receive(error_stream);
+        Ok(FlightRecordBatchStream::new_from_flight_data(
+            response_stream,
+        ))
+    }
+
+    /// Start a `DoExchange` call for `descriptor`, sending batches encoded
+    /// with `encoder` through a channel of `capacity` batches (see
+    /// [`exchange_channel`]).
+    ///
+    /// Returns an [`ExchangeSender`] for the batches sent to the server and a
+    /// future that resolves to the batches returned by the server once the
+    /// server responds. As the server may wait for input before responding,
+    /// batches should be sent concurrently with awaiting the response, for
+    /// example from a separate task.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # async fn run() {
+    /// # use futures::TryStreamExt;
+    /// # use std::sync::Arc;
+    /// # use arrow_array::UInt64Array;
+    /// # use arrow_array::RecordBatch;
+    /// # use arrow_flight::{FlightClient, FlightDescriptor};
+    /// # use arrow_flight::encode::FlightDataEncoderBuilder;
+    /// # let batch = RecordBatch::try_from_iter(vec![
+    /// #  ("col2", Arc::new(UInt64Array::from_iter([10, 23, 33])) as _)
+    /// # ]).unwrap();
+    /// # let channel: tonic::transport::Channel = unimplemented!();
+    /// let mut client = FlightClient::new(channel);
+    ///
+    /// let descriptor = FlightDescriptor::new_cmd("EXCHANGE");
+    /// let (mut sender, response) = client.exchange(descriptor, FlightDataEncoderBuilder::new(), 2);
+    ///
+    /// // send the batches from a separate task
+    /// tokio::spawn(async move {
+    ///   sender.send(batch).await.expect("error sending batch");
+    /// });
+    ///
+    /// let response: Vec<RecordBatch> = response
+    ///   .await
+    ///   .expect("error calling do_exchange")
+    ///   .try_collect()
+    ///   .await
+    ///   .expect("error streaming data");
+    /// # }
+    /// ```
+    pub fn exchange(
+        &mut self,
+        descriptor: FlightDescriptor,
+        encoder: FlightDataEncoderBuilder,
+        capacity: usize,
+    ) -> (
+        ExchangeSender,
+        impl Future<Output = Result<FlightRecordBatchStream>> + '_,
+    ) {
+        let encoder = encoder.with_flight_descriptor(Some(descriptor));
+        let (sender, request) = exchange_channel(encoder, capacity);
+        (sender, self.do_exchange(request))
     }
 
     /// Make a `ListFlights` call to the server with the provided
@@ -470,7 +558,7 @@ where
             expression: expression.into(),
         };
 
-        let request = self.make_request(request);
+        let request = self.make_request(request)?;
 
         let response = self
             .inner
@@ -503,8 +591,9 @@ where
     ///   .expect("error making request");
     /// # }
     /// ```
-    pub async fn get_schema(&mut self, flight_descriptor: FlightDescriptor) -> Result<Schema> {
-        let request = self.make_request(flight_descriptor);
+    pub async fn get_schema(&mut self, mut flight_descriptor: FlightDescriptor) -> Result<Schema> {
+        self.interceptors.on_descriptor(&mut flight_descriptor)?;
+        let request = self.make_request(flight_descriptor)?;
 
         let schema_result = self.inner.get_schema(request).await?.into_inner();
 
@@ -537,7 +626,7 @@ where
     /// # }
     /// ```
     pub async fn list_actions(&mut self) -> Result<BoxStream<'static, Result<ActionType>>> {
-        let request = self.make_request(Empty {});
+        let request = self.make_request(Empty {})?;
 
         let action_stream = self
             .inner
@@ -575,7 +664,7 @@ where
     /// # }
     /// ```
     pub async fn do_action(&mut self, action: Action) -> Result<BoxStream<'static, Result<Bytes>>> {
-        let request = self.make_request(action);
+        let request = self.make_request(action)?;
 
         let result_stream = self
             .inner
@@ -670,20 +759,217 @@ where
         FlightEndpoint::decode(response).map_err(|e| FlightError::DecodeError(e.to_string()))
     }
 
+    /// Make a `SetSessionOptions` call to the server and return the
+    /// [`SetSessionOptionsResult`], which lists any options that could not be set.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # async fn run() {
+    /// # use arrow_flight::{FlightClient, SessionOptionValue, SetSessionOptionsRequest};
+    /// # let channel: tonic::transport::Channel = unimplemented!();
+    /// let mut client = FlightClient::new(channel);
+    ///
+    /// let request = SetSessionOptionsRequest::new([
+    ///     ("catalog", SessionOptionValue::from("main")),
+    /// ]);
+    /// let result = client
+    ///   .set_session_options(request)
+    ///   .await
+    ///   .expect("error setting session options");
+    /// assert!(result.errors.is_empty());
+    /// # }
+    /// ```
+    pub async fn set_session_options(
+        &mut self,
+        request: SetSessionOptionsRequest,
+    ) -> Result<SetSessionOptionsResult> {
+        let action = Action::new("SetSessionOptions", request.encode_to_vec());
+        let response = self.do_action(action).await?.try_next().await?;
+        let response = response.ok_or(FlightError::protocol(
+            "Received no response for set_session_options call",
+        ))?;
+        SetSessionOptionsResult::decode(response)
+            .map_err(|e| FlightError::DecodeError(e.to_string()))
+    }
+
+    /// Make a `GetSessionOptions` call to the server and return the
+    /// [`GetSessionOptionsResult`] with the current session options.
+    pub async fn get_session_options(
+        &mut self,
+        request: GetSessionOptionsRequest,
+    ) -> Result<GetSessionOptionsResult> {
+        let action = Action::new("GetSessionOptions", request.encode_to_vec());
+        let response = self.do_action(action).await?.try_next().await?;
+        let response = response.ok_or(FlightError::protocol(
+            "Received no response for get_session_options call",
+        ))?;
+        GetSessionOptionsResult::decode(response)
+            .map_err(|e| FlightError::DecodeError(e.to_string()))
+    }
+
+    /// Make a `CloseSession` call to the server and return the
+    /// [`CloseSessionResult`].
+    pub async fn close_session(
+        &mut self,
+        request: CloseSessionRequest,
+    ) -> Result<CloseSessionResult> {
+        let action = Action::new("CloseSession", request.encode_to_vec());
+        let response = self.do_action(action).await?.try_next().await?;
+        let response = response.ok_or(FlightError::protocol(
+            "Received no response for close_session call",
+        ))?;
+        CloseSessionResult::decode(response).map_err(|e| FlightError::DecodeError(e.to_string()))
+    }
+
     /// return a Request, adding any configured metadata
-    fn make_request<R>(&self, t: R) -> tonic::Request<R> {
+    fn make_request<R>(&self, t: R) -> Result<tonic::Request<R>> {
         // Pass along metadata
         let mut request = tonic::Request::new(t);
         *request.metadata_mut() = self.metadata.clone();
-        request
+        self.interceptors.on_request(request.metadata_mut())?;
+        Ok(request)
+    }
+}
+
+/// The [`Location`](crate::Location) URI indicating that the data of a
+/// [`FlightEndpoint`] can be fetched using the connection the [`FlightInfo`]
+/// was retrieved from
+pub const REUSE_CONNECTION_URI: &str = "arrow-flight-reuse-connection://?";
+
+impl FlightClient<Channel> {
+    /// Fetch the data of all [`FlightEndpoint`]s of `info`, making up to
+    /// `concurrency` `DoGet` calls in parallel, and returning a single stream
+    /// of [`RecordBatch`]es.
+    ///
+    /// Endpoints with no locations, or whose only location is
+    /// [`REUSE_CONNECTION_URI`], are fetched using this client. Otherwise a
+    /// new connection is made to the first location that can be connected to,
+    /// sending the same [`Self::metadata`] and using the same interceptors.
+    /// `grpc`, `grpc+tcp` and `grpc+tls` locations are supported.
+    ///
+    /// If [`FlightInfo::ordered`] is set the batches are returned in the order
+    /// of the endpoints, otherwise batches from different endpoints are
+    /// interleaved as they arrive.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # async fn run() {
+    /// # use arrow_flight::{FlightClient, FlightDescriptor};
+    /// # use futures::TryStreamExt;
+    /// # let channel: tonic::transport::Channel = unimplemented!();
+    /// let mut client = FlightClient::new(channel);
+    ///
+    /// let request = FlightDescriptor::new_cmd(b"MOAR DATA".to_vec());
+    /// let flight_info = client
+    ///   .get_flight_info(request)
+    ///   .await
+    ///   .expect("error getting flight info");
+    ///
+    /// // fetch up to 4 endpoints at once
+    /// let batches: Vec<_> = client
+    ///   .do_get_endpoints(flight_info, 4)
+    ///   .try_collect()
+    ///   .await
+    ///   .expect("error fetching data");
+    /// # }
+    /// ```
+    pub fn do_get_endpoints(
+        &self,
+        info: FlightInfo,
+        concurrency: usize,
+    ) -> BoxStream<'static, Result<RecordBatch>> {
+        let concurrency = concurrency.max(1);
+        let ordered = info.ordered;
+        let requests = info.endpoint.into_iter().map({
+            let client = self.clone();
+            move |endpoint| client.clone().do_get_endpoint(endpoint)
+        });
+
+        if ordered {
+            stream::iter(requests)
+                .buffered(concurrency)
+                .try_flatten()
+                .boxed()
+        } else {
+            stream::iter(requests)
+                .map(|request| stream::once(request).try_flatten().boxed())
+                .flatten_unordered(concurrency)
+                .boxed()
+        }
     }
+
+    /// Make a `DoGet` call for the ticket of `endpoint`, connecting to its
+    /// locations if necessary
+    async fn do_get_endpoint(
+        mut self,
+        endpoint: FlightEndpoint,
+    ) -> Result<FlightRecordBatchStream> {
+        let ticket = endpoint
+            .ticket
+            .ok_or_else(|| FlightError::protocol("FlightEndpoint is missing a ticket"))?;
+
+        let reuse = endpoint
+            .location
+            .iter()
+            .all(|location| location.uri == REUSE_CONNECTION_URI);
+        if !reuse {
+            let mut errors = vec![];
+            let mut channel = None;
+            for location in &endpoint.location {
+                match connect_location(&location.uri).await {
+                    Ok(c) => {
+                        channel = Some(c);
+                        break;
+                    }
+                    Err(e) => errors.push(format!("{}: {e}", location.uri)),
+                }
+            }
+            let channel = channel.ok_or_else(|| {
+                FlightError::protocol(format!(
+                    "Unable to connect to any location of FlightEndpoint: {}",
+                    errors.join(", ")
+                ))
+            })?;
+            self.inner = FlightServiceClient::new(channel);
+        }
+        self.do_get(ticket).await
+    }
+}
+
+/// Connect to the [`Location`](crate::Location) `uri`
+async fn connect_location(uri: &str) -> Result<Channel> {
+    if uri == REUSE_CONNECTION_URI {
+        return Err(FlightError::protocol(
+            "Can not create a new connection to reuse the existing connection",
+        ));
+    }
+    let (scheme, rest) = uri
+        .split_once("://")
+        .ok_or_else(|| FlightError::protocol(format!("Invalid location URI {uri}")))?;
+    let scheme = match scheme {
+        "grpc" | "grpc+tcp" | "http" => "http",
+        "grpc+tls" | "https" => "https",
+        scheme => {
+            return Err(FlightError::protocol(format!(
+                "Unsupported location scheme {scheme}"
+            )));
+        }
+    };
+    let endpoint = Channel::from_shared(format!("{scheme}://{rest}"))
+        .map_err(|e| FlightError::ExternalError(Box::new(e)))?;
+    endpoint
+        .connect()
+        .await
+        .map_err(|e| FlightError::ExternalError(Box::new(e)))
 }
 
 #[cfg(test)]
 mod tests {
     use super::FlightClient;
     use crate::encode::FlightDataEncoderBuilder;
+    use crate::error::FlightError;
     use crate::flight_service_server::{FlightService, FlightServiceServer};
+    use crate::interceptor::{FlightInterceptor, InterceptedFlightService};
     use crate::{
         Action, ActionType, Criteria, Empty, FlightData, FlightDescriptor, FlightInfo,
         HandshakeRequest, HandshakeResponse, PollInfo, PutResult, SchemaResult, Ticket,
@@ -692,6 +978,7 @@ mod tests {
     use bytes::Bytes;
     use futures::{StreamExt, TryStreamExt, stream::BoxStream};
     use std::net::SocketAddr;
+    use std::sync::atomic::{AtomicUsize, Ordering};
     use std::sync::{Arc, Mutex};
     use std::time::Duration;
     use tokio::net::TcpListener;
@@ -702,8 +989,9 @@ mod tests {
     use tonic::{Request, Response, Status, Streaming};
     use uuid::Uuid;
 
-    /// Minimal `FlightService` that records request metadata and serves a
-    /// configured `do_get` response. Other RPCs return `Unimplemented`.
+    /// Minimal `FlightService` that records request metadata, serves a
+    /// configured `do_get` response and echoes `do_put` and `do_exchange`
+    /// requests. Other RPCs return `Unimplemented`.
     #[derive(Debug, Clone, Default)]
     struct InterceptorTestServer {
         state: Arc<Mutex<InterceptorTestState>>,
@@ -795,9 +1083,13 @@ mod tests {
         }
         async fn do_put(
             &self,
-            _: Request<Streaming<FlightData>>,
+            request: Request<Streaming<FlightData>>,
         ) -> Result<Response<Self::DoPutStream>, Status> {
-            Err(Status::unimplemented(""))
+            self.save_metadata(&request);
+            let stream = request.into_inner().map_ok(|data| PutResult {
+                app_metadata: data.app_metadata,
+            });
+            Ok(Response::new(stream.boxed()))
         }
         async fn do_action(
             &self,
@@ -813,9 +1105,10 @@ mod tests {
         }
         async fn do_exchange(
             &self,
-            _: Request<Streaming<FlightData>>,
+            request: Request<Streaming<FlightData>>,
         ) -> Result<Response<Self::DoExchangeStream>, Status> {
-            Err(Status::unimplemented(""))
+            self.save_metadata(&request);
+            Ok(Response::new(request.into_inner().boxed()))
         }
     }
 
@@ -827,7 +1120,7 @@ mod tests {
     }
 
     impl InterceptorTestFixture {
-        async fn new(server: FlightServiceServer<InterceptorTestServer>) -> Self {
+        async fn new<S: FlightService>(server: FlightServiceServer<S>) -> Self {
             let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
             let addr = listener.local_addr().unwrap();
             let (tx, rx) = tokio::sync::oneshot::channel();
@@ -939,4 +1232,239 @@ mod tests {
 
         fixture.shutdown_and_wait().await;
     }
+
+    /// Client interceptor that sets a new bearer token on each request and
+    /// records the received messages
+    #[derive(Debug, Default)]
+    struct RecordingInterceptor {
+        requests: AtomicUsize,
+        received_bytes: AtomicUsize,
+        received_metadata: Mutex<Vec<Bytes>>,
+    }
+
+    impl FlightInterceptor for RecordingInterceptor {
+        fn on_request(&self, metadata: &mut MetadataMap) -> Result<(), FlightError> {
+            let n = self.requests.fetch_add(1, Ordering::Relaxed) + 1;
+            metadata.insert("authorization", format!("Bearer {n}").parse().unwrap());
+            Ok(())
+        }
+
+        fn on_receive(&self, data: &mut FlightData) -> Result<(), FlightError> {
+            let len = data.data_header.len() + data.data_body.len();
+            self.received_bytes.fetch_add(len, Ordering::Relaxed);
+            self.received_metadata
+                .lock()
+                .unwrap()
+                .push(data.app_metadata.clone());
+            Ok(())
+        }
+    }
+
+    /// Server interceptor that tags each sent message
+    #[derive(Debug)]
+    struct TaggingInterceptor;
+
+    impl FlightInterceptor for TaggingInterceptor {
+        fn on_send(&self, data: &mut FlightData) -> Result<(), FlightError> {
+            data.app_metadata = Bytes::from("tagged");
+            Ok(())
+        }
+    }
+
+    /// Server interceptor that tags each received message and descriptor
+    #[derive(Debug)]
+    struct ReceiveTaggingInterceptor;
+
+    impl FlightInterceptor for ReceiveTaggingInterceptor {
+        fn on_descriptor(&self, descriptor: &mut FlightDescriptor) -> Result<(), FlightError> {
+            descriptor.path.push("intercepted".to_string());
+            Ok(())
+        }
+
+        fn on_receive(&self, data: &mut FlightData) -> Result<(), FlightError> {
+            data.app_metadata = Bytes::from("received");
+            Ok(())
+        }
+    }
+
+    /// Client interceptor that rejects all received messages
+    #[derive(Debug)]
+    struct RejectingInterceptor;
+
+    impl FlightInterceptor for RejectingInterceptor {
+        fn on_receive(&self, _data: &mut FlightData) -> Result<(), FlightError> {
+            Err(FlightError::protocol("rejected by interceptor"))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_flight_interceptors() {
+        let test_server = InterceptorTestServer::default();
+        let service =
+            InterceptedFlightService::new(test_server.clone()).with_interceptor(TaggingInterceptor);
+        let fixture = InterceptorTestFixture::new(FlightServiceServer::new(service)).await;
+
+        let recorder = Arc::new(RecordingInterceptor::default());
+        let mut client =
+            FlightClient::new(fixture.channel().await).with_interceptor(Arc::clone(&recorder));
+
+        let batch = RecordBatch::try_from_iter(vec![(
+            "col",
+            Arc::new(UInt64Array::from_iter([1, 2, 3, 4])) as _,
+        )])
+        .unwrap();
+
+        for expected_token in ["Bearer 1", "Bearer 2"] {
+            test_server.set_do_get_response(vec![Ok(batch.clone())]);
+            let response: Vec<RecordBatch> = client
+                .do_get(Ticket::new("ticket"))
+                .await
+                .expect("error making do_get request")
+                .try_collect()
+                .await
+                .expect("error streaming data");
+            assert_eq!(response, vec![batch.clone()]);
+
+            let metadata = test_server.take_last_request_metadata().unwrap();
+            assert_eq!(metadata.get("authorization").unwrap(), expected_token);
+        }
+
+        assert!(recorder.received_bytes.load(Ordering::Relaxed) > 0);
+        let received_metadata = recorder.received_metadata.lock().unwrap().clone();
+        // a schema and a record batch message per call
+        assert_eq!(received_metadata.len(), 4);
+        assert!(received_metadata.iter().all(|m| m == "tagged"));
+
+        fixture.shutdown_and_wait().await;
+    }
+
+    #[tokio::test]
+    async fn test_flight_interceptor_error() {
+        let test_server = InterceptorTestServer::default();
+        let fixture =
+            InterceptorTestFixture::new(FlightServiceServer::new(test_server.clone())).await;
+        let mut client =
+            FlightClient::new(fixture.channel().await).with_interceptor(RejectingInterceptor);
+
+        let batch = RecordBatch::try_from_iter(vec![(
+            "col",
+            Arc::new(UInt64Array::from_iter([1, 2, 3, 4])) as _,
+        )])
+        .unwrap();
+        test_server.set_do_get_response(vec![Ok(batch)]);
+
+        let err = client
+            .do_get(Ticket::new("ticket"))
+            .await
+            .expect("error making do_get request")
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap_err();
+        assert_eq!(err.to_string(), "Protocol error: rejected by interceptor");
+
+        fixture.shutdown_and_wait().await;
+    }
+
+    #[tokio::test]
+    async fn test_flight_interceptor_receive_request() {
+        let service = InterceptedFlightService::new(InterceptorTestServer::default())
+            .with_interceptor(ReceiveTaggingInterceptor);
+        let fixture = InterceptorTestFixture::new(FlightServiceServer::new(service)).await;
+        let mut client = FlightClient::new(fixture.channel().await);
+
+        let batch = RecordBatch::try_from_iter(vec![(
+            "col",
+            Arc::new(UInt64Array::from_iter([1, 2, 3, 4])) as _,
+        )])
+        .unwrap();
+        let descriptor = FlightDescriptor::new_path(vec!["path".to_string()]);
+        let input = || {
+            FlightDataEncoderBuilder::new()
+                .with_flight_descriptor(Some(descriptor.clone()))
+                .build(futures::stream::iter([Ok(batch.clone())]))
+        };
+
+        let put_results: Vec<PutResult> = client
+            .do_put(input())
+            .await
+            .expect("error making do_put request")
+            .try_collect()
+            .await
+            .expect("error streaming put results");
+        // a schema and a record batch message
+        assert_eq!(put_results.len(), 2);
+        assert!(put_results.iter().all(|r| r.app_metadata == "received"));
+
+        let exchanged: Vec<FlightData> = client
+            .do_exchange(input())
+            .await
+            .expect("error making do_exchange request")
+            .into_inner()
+            .map_ok(|decoded| decoded.inner)
+            .try_collect()
+            .await
+            .expect("error streaming exchanged data");
+        assert_eq!(exchanged.len(), 2);
+        assert!(exchanged.iter().all(|d| d.app_metadata == "received"));
+        let path = &exchanged[0].flight_descriptor.as_ref().unwrap().path;
+        // tagged once as received, and again as sent in the response
+        assert_eq!(path, &["path", "intercepted", "intercepted"]);
+
+        fixture.shutdown_and_wait().await;
+    }
+
+    #[tokio::test]
+    async fn test_flight_interceptor_receive_concurrent_requests() {
+        let service = InterceptedFlightService::new(InterceptorTestServer::default())
+            .with_interceptor(ReceiveTaggingInterceptor);
+        let fixture = InterceptorTestFixture::new(FlightServiceServer::new(service)).await;
+        let channel = fixture.channel().await;
+
+        let exchange = |i: u64| {
+            let mut client = FlightClient::new(channel.clone());
+            async move {
+                let batch = RecordBatch::try_from_iter(vec![(
+                    "col",
+                    Arc::new(UInt64Array::from_iter_values(0..=i)) as _,
+                )])
+                .unwrap();
+                let descriptor = FlightDescriptor::new_path(vec![i.to_string()]);
+                let input: Vec<FlightData> = FlightDataEncoderBuilder::new()
+                    .with_flight_descriptor(Some(descriptor))
+                    .build(futures::stream::iter([Ok(batch)]))
+                    .try_collect()
+                    .await
+                    .unwrap();
+
+                let exchanged: Vec<FlightData> = client
+                    .do_exchange(futures::stream::iter(input.clone().into_iter().map(Ok)))
+                    .await
+                    .expect("error making do_exchange request")
+                    .into_inner()
+                    .map_ok(|decoded| decoded.inner)
+                    .try_collect()
+                    .await
+                    .expect("error streaming exchanged data");
+                (i, input, exchanged)
+            }
+        };
+
+        let results = futures::future::join_all((0..16).map(exchange)).await;
+        for (i, input, exchanged) in results {
+            // each response only contains the messages of its own request
+            assert_eq!(exchanged.len(), input.len());
+            let path = &exchanged[0].flight_descriptor.as_ref().unwrap().path;
+            assert_eq!(
+                path,
+                &[i.to_string(), "intercepted".into(), "intercepted".into()]
+            );
+            for (sent, received) in input.iter().zip(&exchanged) {
+                assert_eq!(sent.data_header, received.data_header);
+                assert_eq!(sent.data_body, received.data_body);
+                assert_eq!(received.app_metadata, "received");
+            }
+        }
+
+        fixture.shutdown_and_wait().await;
+    }
 }
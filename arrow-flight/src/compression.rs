@@ -0,0 +1,185 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Negotiation of IPC body compression
+//!
+//! [`FlightData`](crate::FlightData) bodies can be compressed with any
+//! [`CompressionType`] enabled with the `lz4` and `zstd` features of this
+//! crate, see [`FlightDataEncoderBuilder::try_with_compression`]. The decoder
+//! decompresses such bodies transparently, but fails if the codec is not
+//! enabled, so the codecs supported by the receiver must be known in advance.
+//!
+//! This module defines a simple convention for advertising codecs: a comma
+//! separated list of codec names, such as `zstd,lz4_frame`, in order of
+//! preference. The list can be sent as gRPC metadata with the
+//! [`IPC_COMPRESSION_HEADER`] key, for example by a client on each request
+//! or by a server in the response to a `Handshake`, or placed in an
+//! application defined field such as [`FlightInfo::app_metadata`].
+//!
+//! # Example
+//! ```
+//! # use arrow_flight::compression::*;
+//! # use arrow_flight::encode::FlightDataEncoderBuilder;
+//! # use arrow_ipc::CompressionType;
+//! // the client advertises the codecs it can decode, for example in the
+//! // IPC_COMPRESSION_HEADER of its requests
+//! let advertised = format_compression(&[CompressionType::ZSTD]);
+//!
+//! // the server chooses the first of its preferred codecs accepted by the client
+//! let accepted = parse_compression(&advertised);
+//! let compression = negotiate_compression(&supported_compression(), &accepted);
+//! let encoder = FlightDataEncoderBuilder::new().try_with_compression(compression);
+//! ```
+//!
+//! [`FlightDataEncoderBuilder::try_with_compression`]: crate::encode::FlightDataEncoderBuilder::try_with_compression
+//! [`FlightInfo::app_metadata`]: crate::FlightInfo::app_metadata
+
+use arrow_ipc::CompressionType;
+#[cfg(feature = "grpc")]
+use tonic::metadata::MetadataMap;
+
+#[cfg(feature = "grpc")]
+use crate::error::{FlightError, Result};
+
+/// The gRPC metadata key used to advertise the accepted [`CompressionType`]s
+pub const IPC_COMPRESSION_HEADER: &str = "arrow-flight-ipc-compression";
+
+/// Returns the [`CompressionType`]s enabled in this build, in order of preference
+pub fn supported_compression() -> Vec<CompressionType> {
+    [
+        #[cfg(feature = "zstd")]
+        CompressionType::ZSTD,
+        #[cfg(feature = "lz4")]
+        CompressionType::LZ4_FRAME,
+    ]
+    .to_vec()
+}
+
+/// Returns the name of `codec` used when advertising it, if any
+pub fn compression_name(codec: CompressionType) -> Option<&'static str> {
+    match codec {
+        CompressionType::LZ4_FRAME => Some("lz4_frame"),
+        CompressionType::ZSTD => Some("zstd"),
+        _ => None,
+    }
+}
+
+/// Formats `codecs` as a comma separated list of names
+pub fn format_compression(codecs: &[CompressionType]) -> String {
+    let names: Vec<_> = codecs.iter().filter_map(|c| compression_name(*c)).collect();
+    names.join(",")
+}
+
+/// Parses a comma separated list of codec names, ignoring any unknown names
+pub fn parse_compression(value: &str) -> Vec<CompressionType> {
+    value
+        .split(',')
+        .filter_map(|name| match name.trim().to_ascii_lowercase().as_str() {
+            "lz4_frame" | "lz4" => Some(CompressionType::LZ4_FRAME),
+            "zstd" => Some(CompressionType::ZSTD),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns the first of the `offered` codecs that is contained in `accepted`,
+/// or `None` if there is no such codec and the data should not be compressed
+pub fn negotiate_compression(
+    offered: &[CompressionType],
+    accepted: &[CompressionType],
+) -> Option<CompressionType> {
+    offered.iter().find(|c| accepted.contains(c)).copied()
+}
+
+/// Advertises the `codecs` in `metadata` with the [`IPC_COMPRESSION_HEADER`] key
+#[cfg(feature = "grpc")]
+pub fn set_accepted_compression(
+    metadata: &mut MetadataMap,
+    codecs: &[CompressionType],
+) -> Result<()> {
+    let value = format_compression(codecs)
+        .parse()
+        .map_err(|e| FlightError::ExternalError(Box::new(e)))?;
+    metadata.insert(IPC_COMPRESSION_HEADER, value);
+    Ok(())
+}
+
+/// Returns the codecs advertised in `metadata` with the
+/// [`IPC_COMPRESSION_HEADER`] key, or an empty list if there are none
+#[cfg(feature = "grpc")]
+pub fn accepted_compression(metadata: &MetadataMap) -> Vec<CompressionType> {
+    metadata
+        .get_all(IPC_COMPRESSION_HEADER)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(parse_compression)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_parse() {
+        let codecs = [CompressionType::ZSTD, CompressionType::LZ4_FRAME];
+        let formatted = format_compression(&codecs);
+        assert_eq!(formatted, "zstd,lz4_frame");
+        assert_eq!(parse_compression(&formatted), codecs);
+
+        assert_eq!(
+            parse_compression(" LZ4 , snappy,,zstd"),
+            [CompressionType::LZ4_FRAME, CompressionType::ZSTD]
+        );
+        assert!(parse_compression("").is_empty());
+    }
+
+    #[test]
+    fn test_negotiate() {
+        let offered = [CompressionType::ZSTD, CompressionType::LZ4_FRAME];
+        assert_eq!(
+            negotiate_compression(&offered, &[CompressionType::LZ4_FRAME]),
+            Some(CompressionType::LZ4_FRAME)
+        );
+        assert_eq!(
+            negotiate_compression(
+                &offered,
+                &[CompressionType::LZ4_FRAME, CompressionType::ZSTD]
+            ),
+            Some(CompressionType::ZSTD)
+        );
+        assert_eq!(negotiate_compression(&offered, &[]), None);
+        assert_eq!(negotiate_compression(&[], &offered), None);
+    }
+
+    #[test]
+    #[cfg(feature = "grpc")]
+    fn test_metadata() {
+        let mut metadata = MetadataMap::new();
+        assert!(accepted_compression(&metadata).is_empty());
+
+        set_accepted_compression(&mut metadata, &[CompressionType::LZ4_FRAME]).unwrap();
+        assert_eq!(
+            accepted_compression(&metadata),
+            [CompressionType::LZ4_FRAME]
+        );
+
+        // replaces the existing value
+        set_accepted_compression(&mut metadata, &[CompressionType::ZSTD]).unwrap();
+        assert_eq!(accepted_compression(&metadata), [CompressionType::ZSTD]);
+    }
+}
@@ -15,13 +15,17 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use crate::{FlightData, trailers::LazyTrailers, utils::flight_data_to_arrow_batch};
+use crate::FlightData;
+#[cfg(feature = "grpc")]
+use crate::trailers::LazyTrailers;
 use arrow_array::{ArrayRef, RecordBatch};
 use arrow_buffer::Buffer;
+use arrow_ipc::reader::IpcReadOptions;
 use arrow_schema::{Schema, SchemaRef};
 use bytes::Bytes;
 use futures::{Stream, StreamExt, ready, stream::BoxStream};
 use std::{collections::HashMap, fmt::Debug, pin::Pin, sync::Arc, task::Poll};
+#[cfg(feature = "grpc")]
 use tonic::metadata::MetadataMap;
 
 use crate::error::{FlightError, Result};
@@ -37,6 +41,7 @@ use crate::error::{FlightError, Result};
 ///
 /// # Example:
 /// ```no_run
+/// # #[cfg(feature = "grpc")]
 /// # async fn f() -> Result<(), arrow_flight::error::FlightError>{
 /// # use bytes::Bytes;
 /// // make a do_get request
@@ -82,9 +87,11 @@ use crate::error::{FlightError, Result};
 #[derive(Debug)]
 pub struct FlightRecordBatchStream {
     /// Optional grpc header metadata.
+    #[cfg(feature = "grpc")]
     headers: MetadataMap,
 
     /// Optional grpc trailer metadata.
+    #[cfg(feature = "grpc")]
     trailers: Option<LazyTrailers>,
 
     inner: FlightDataDecoder,
@@ -95,7 +102,9 @@ impl FlightRecordBatchStream {
     pub fn new(inner: FlightDataDecoder) -> Self {
         Self {
             inner,
+            #[cfg(feature = "grpc")]
             headers: MetadataMap::default(),
+            #[cfg(feature = "grpc")]
             trailers: None,
         }
     }
@@ -105,19 +114,17 @@ impl FlightRecordBatchStream {
     where
         S: Stream<Item = Result<FlightData>> + Send + 'static,
     {
-        Self {
-            inner: FlightDataDecoder::new(inner),
-            headers: MetadataMap::default(),
-            trailers: None,
-        }
+        Self::new(FlightDataDecoder::new(inner))
     }
 
     /// Record response headers.
+    #[cfg(feature = "grpc")]
     pub fn with_headers(self, headers: MetadataMap) -> Self {
         Self { headers, ..self }
     }
 
     /// Record response trailers.
+    #[cfg(feature = "grpc")]
     pub fn with_trailers(self, trailers: LazyTrailers) -> Self {
         Self {
             trailers: Some(trailers),
@@ -126,6 +133,7 @@ impl FlightRecordBatchStream {
     }
 
     /// Headers attached to this stream.
+    #[cfg(feature = "grpc")]
     pub fn headers(&self) -> &MetadataMap {
         &self.headers
     }
@@ -134,6 +142,7 @@ impl FlightRecordBatchStream {
     ///
     /// Note that this will return `None` until the entire stream is consumed.
     /// Only after calling `next()` returns `None`, might any available trailers be returned.
+    #[cfg(feature = "grpc")]
     pub fn trailers(&self) -> Option<MetadataMap> {
         self.trailers.as_ref().and_then(|trailers| trailers.get())
     }
@@ -221,6 +230,10 @@ impl futures::Stream for FlightRecordBatchStream {
 /// 1. Using this low level stream it is possible to receive a steam
 ///    of RecordBatches in FlightData that have different schemas by
 ///    handling multiple schema messages separately.
+///
+/// When receiving data from untrusted peers, use
+/// [`FlightDataDecoder::with_read_options`] to limit the resources used
+/// when decoding messages.
 pub struct FlightDataDecoder {
     /// Underlying data stream
     response: BoxStream<'static, Result<FlightData>>,
@@ -228,6 +241,8 @@ pub struct FlightDataDecoder {
     state: Option<FlightStreamState>,
     /// Seen the end of the inner stream?
     done: bool,
+    /// Limits enforced while decoding messages
+    read_options: IpcReadOptions,
 }
 
 impl Debug for FlightDataDecoder {
@@ -236,6 +251,7 @@ impl Debug for FlightDataDecoder {
             .field("response", &"<stream>")
             .field("state", &self.state)
             .field("done", &self.done)
+            .field("read_options", &self.read_options)
             .finish()
     }
 }
@@ -250,9 +266,18 @@ impl FlightDataDecoder {
             state: None,
             response: response.boxed(),
             done: false,
+            read_options: IpcReadOptions::default(),
         }
     }
 
+    /// Set the limits enforced while decoding messages (default: no limits)
+    ///
+    /// See [`IpcReadOptions`] for details
+    pub fn with_read_options(mut self, read_options: IpcReadOptions) -> Self {
+        self.read_options = read_options;
+        self
+    }
+
     /// Returns the current schema for this stream
     pub fn schema(&self) -> Option<&SchemaRef> {
         self.state.as_ref().map(|state| &state.schema)
@@ -262,6 +287,10 @@ impl FlightDataDecoder {
     /// state as necessary.
     fn extract_message(&mut self, data: FlightData) -> Result<Option<DecodedFlightData>> {
         use arrow_ipc::MessageHeader;
+        self.read_options
+            .check_buffer_size("Message metadata", data.data_header.len())?;
+        self.read_options
+            .check_buffer_size("Message body", data.data_body.len())?;
         let message = arrow_ipc::root_as_message(&data.data_header[..])
             .map_err(|e| FlightError::DecodeError(format!("Error decoding root message: {e}")))?;
 
@@ -270,6 +299,7 @@ impl FlightDataDecoder {
             MessageHeader::Schema => {
                 let schema = Schema::try_from(&data)
                     .map_err(|e| FlightError::DecodeError(format!("Error decoding schema: {e}")))?;
+                self.read_options.check_schema(&schema)?;
 
                 let schema = Arc::new(schema);
                 let dictionaries_by_field = HashMap::new();
@@ -296,12 +326,13 @@ impl FlightDataDecoder {
                     )
                 })?;
 
-                arrow_ipc::reader::read_dictionary(
+                arrow_ipc::reader::read_dictionary_with_options(
                     &buffer,
                     dictionary_batch,
                     &state.schema,
                     &mut state.dictionaries_by_field,
                     &message.version(),
+                    &self.read_options,
                 )
                 .map_err(|e| {
                     FlightError::DecodeError(format!("Error decoding ipc dictionary: {e}"))
@@ -319,10 +350,20 @@ impl FlightDataDecoder {
                     ));
                 };
 
-                let batch = flight_data_to_arrow_batch(
-                    &data,
+                let record_batch = message.header_as_record_batch().ok_or_else(|| {
+                    FlightError::protocol("Could not get record batch from RecordBatch message")
+                })?;
+
+                // The decoded arrays reference `data_body` directly, without
+                // copying, unless their buffers are not sufficiently aligned
+                let batch = arrow_ipc::reader::read_record_batch_with_options(
+                    &Buffer::from(data.data_body.clone()),
+                    record_batch,
                     Arc::clone(&state.schema),
                     &state.dictionaries_by_field,
+                    None,
+                    &message.version(),
+                    &self.read_options,
                 )
                 .map_err(|e| {
                     FlightError::DecodeError(format!("Error decoding ipc RecordBatch: {e}"))
@@ -19,7 +19,9 @@ use std::{collections::VecDeque, fmt::Debug, pin::Pin, sync::Arc, task::Poll};
 
 use crate::{FlightData, FlightDescriptor, SchemaAsIpc, error::Result};
 
+use arrow_array::cast::AsArray;
 use arrow_array::{Array, ArrayRef, RecordBatch, RecordBatchOptions, UnionArray};
+use arrow_ipc::CompressionType;
 use arrow_ipc::writer::{CompressionContext, DictionaryTracker, IpcDataGenerator, IpcWriteOptions};
 
 use arrow_schema::{DataType, Field, FieldRef, Fields, Schema, SchemaRef, UnionMode};
@@ -42,7 +44,9 @@ use futures::{Stream, StreamExt, ready, stream::BoxStream};
 ///    [`DictionaryArray`]s are converted to their underlying types prior to
 ///    transport.
 ///    When [`DictionaryHandling`] is [`DictionaryHandling::Resend`], Dictionary [`FlightData`] is sent with every
-///    [`RecordBatch`] that contains a [`DictionaryArray`](arrow_array::array::DictionaryArray).
+///    [`RecordBatch`] that contains a [`DictionaryArray`](arrow_array::array::DictionaryArray) whose values differ
+///    from those previously sent. [`DictionaryHandling::Delta`] instead only sends the new values when a
+///    dictionary extends the one previously sent.
 ///    See <https://github.com/apache/arrow-rs/issues/3389>.
 ///
 /// [`DictionaryArray`]: arrow_array::array::DictionaryArray
@@ -67,6 +71,7 @@ use futures::{Stream, StreamExt, ready, stream::BoxStream};
 ///  .build(input_stream);
 ///
 /// // Create a tonic `Response` that can be returned from a Flight server
+/// # #[cfg(feature = "grpc")]
 /// let response = tonic::Response::new(flight_data_stream);
 /// # }
 /// ```
@@ -192,8 +197,11 @@ impl FlightDataEncoderBuilder {
     /// (preserving order) into multiple [`FlightData`] objects to
     /// limit the size individual messages sent via gRPC.
     ///
-    /// The size is approximate because of the additional encoding
-    /// overhead on top of the underlying data buffers themselves.
+    /// Batches are first split based on the size of their buffers, and any
+    /// resulting batch whose encoded message still exceeds this size is
+    /// repeatedly split in half. A message may therefore only exceed this
+    /// size if it contains a single row that is larger on its own, or is a
+    /// dictionary batch, as dictionary values are not split.
     pub fn with_max_flight_data_size(mut self, max_flight_data_size: usize) -> Self {
         self.max_flight_data_size = max_flight_data_size;
         self
@@ -219,6 +227,24 @@ impl FlightDataEncoderBuilder {
         self
     }
 
+    /// Compress the bodies of the encoded [`FlightData`] with `compression`,
+    /// or disable compression if `None`.
+    ///
+    /// Returns an error if the [`IpcWriteOptions`] use a metadata version that
+    /// does not support compression, i.e. before V5.
+    ///
+    /// The codec must be enabled using the `lz4` or `zstd` feature of this
+    /// crate. This is not checked here: if the codec is not enabled, the
+    /// [`FlightDataEncoder`] returns an error when it encodes the first
+    /// [`RecordBatch`]. The receiver must also support the codec, see
+    /// [`crate::compression`] for how to negotiate it, and
+    /// [`crate::compression::supported_compression`] for the codecs enabled
+    /// in this build.
+    pub fn try_with_compression(mut self, compression: Option<CompressionType>) -> Result<Self> {
+        self.options = self.options.try_with_compression(compression)?;
+        Ok(self)
+    }
+
     /// Specify a schema for the RecordBatches being sent. If a schema
     /// is not specified, an encoded Schema message will be sent when
     /// the first [`RecordBatch`], if any, is encoded. Some clients
@@ -299,13 +325,19 @@ impl FlightDataEncoder {
         descriptor: Option<FlightDescriptor>,
         dictionary_handling: DictionaryHandling,
     ) -> Self {
+        let options = match dictionary_handling {
+            DictionaryHandling::Delta => {
+                options.with_dictionary_handling(arrow_ipc::writer::DictionaryHandling::Delta)
+            }
+            DictionaryHandling::Hydrate | DictionaryHandling::Resend => options,
+        };
         let mut encoder = Self {
             inner,
             schema: None,
             max_flight_data_size,
             encoder: FlightIpcEncoder::new(
                 options,
-                dictionary_handling != DictionaryHandling::Resend,
+                dictionary_handling == DictionaryHandling::Hydrate,
             ),
             app_metadata: Some(app_metadata),
             queue: VecDeque::new(),
@@ -342,7 +374,7 @@ impl FlightDataEncoder {
     fn encode_schema(&mut self, schema: &SchemaRef) -> SchemaRef {
         // The first message is the schema message, and all
         // batches have the same schema
-        let send_dictionaries = self.dictionary_handling == DictionaryHandling::Resend;
+        let send_dictionaries = self.dictionary_handling != DictionaryHandling::Hydrate;
         let schema = Arc::new(prepare_schema_for_flight(
             schema,
             &mut self.encoder.dictionary_tracker,
@@ -369,18 +401,54 @@ impl FlightDataEncoder {
         };
 
         let batch = match self.dictionary_handling {
-            DictionaryHandling::Resend => batch,
+            DictionaryHandling::Resend | DictionaryHandling::Delta => batch,
             DictionaryHandling::Hydrate => hydrate_dictionaries(&batch, schema)?,
         };
 
+        let num_rows = batch.num_rows();
         for batch in split_batch_for_grpc_response(batch, self.max_flight_data_size) {
-            let (flight_dictionaries, flight_batch) = self.encoder.encode_batch(&batch)?;
-            for dict in flight_dictionaries {
-                self.queue_message(dict);
-            }
-            self.queue_message(flight_batch);
+            let batch = match batch.num_rows() < num_rows {
+                true => gc_view_arrays(&batch)?,
+                false => batch,
+            };
+            self.encode_split_batch(batch)?;
+        }
+
+        Ok(())
+    }
+
+    /// Encodes batch into `FlightData` messages in self.queue, splitting it
+    /// in half until each encoded message fits within `max_flight_data_size`
+    /// or contains a single row
+    fn encode_split_batch(&mut self, batch: RecordBatch) -> Result<()> {
+        self.encode_split_batch_inner(batch, usize::MAX)
+    }
+
+    /// See [`Self::encode_split_batch`], where `parent_size` is the encoded size
+    /// of the batch this one was split from
+    fn encode_split_batch_inner(&mut self, batch: RecordBatch, parent_size: usize) -> Result<()> {
+        let (flight_dictionaries, flight_batch) = self.encoder.encode_batch(&batch)?;
+        // The dictionaries are now tracked by the encoder, and as the halves
+        // share the same dictionary values they will not be sent again
+        for dict in flight_dictionaries {
+            self.queue_message(dict);
         }
 
+        let num_rows = batch.num_rows();
+        let encoded_size = flight_batch.data_header.len() + flight_batch.data_body.len();
+        // Stop splitting if halving did not shrink the message by at least a quarter,
+        // as it is dominated by data shared by every slice, such as the data buffers
+        // of view arrays nested in other types
+        let shrinking = encoded_size < parent_size - parent_size / 4;
+        if num_rows > 1 && encoded_size > self.max_flight_data_size && shrinking {
+            let mid = num_rows / 2;
+            let first = gc_view_arrays(&batch.slice(0, mid))?;
+            self.encode_split_batch_inner(first, encoded_size)?;
+            let second = gc_view_arrays(&batch.slice(mid, num_rows - mid))?;
+            return self.encode_split_batch_inner(second, encoded_size);
+        }
+
+        self.queue_message(flight_batch);
         Ok(())
     }
 }
@@ -453,8 +521,9 @@ impl Stream for FlightDataEncoder {
 /// Note that since `dict_id` defined in the `Schema` is used as a key to associate dictionary values to their arrays it is required that each
 /// `DictionaryArray` in a `RecordBatch` have a unique `dict_id`.
 ///
-/// The current implementation does not support "delta" dictionaries so a new dictionary batch will be sent each time the encoder sees a
-/// dictionary which is not pointer-equal to the previously observed dictionary for a given `dict_id`.
+/// With [`DictionaryHandling::Resend`] a new dictionary batch will be sent each time the encoder sees a dictionary whose values differ
+/// from the previously observed dictionary for a given `dict_id`. With [`DictionaryHandling::Delta`], if the previous dictionary
+/// values are a prefix of the new ones, only the new values are sent, in a dictionary batch with the `isDelta` flag set.
 ///
 /// For clients which may not support `DictionaryEncoding`, the `DictionaryHandling::Hydrate` method will bypass the process defined above
 /// and "hydrate" any `DictionaryArray` in the batch to their underlying value type (e.g. `TypedDictionaryArray<'_, UInt32Type, Utf8Type>` will
@@ -480,6 +549,15 @@ pub enum DictionaryHandling {
     /// This requires identifying the different dictionaries in use and assigning
     //  them unique IDs
     Resend,
+    /// Like [`Self::Resend`], but when the values of a dictionary extend those
+    /// previously sent for the same field, only the new values are sent as a
+    /// delta dictionary batch. This minimizes the data sent for streams whose
+    /// dictionaries grow over time, such as those produced by a
+    /// [`StringDictionaryBuilder`] reused across batches, but requires the
+    /// receiver to support delta dictionaries.
+    ///
+    /// [`StringDictionaryBuilder`]: arrow_array::builder::StringDictionaryBuilder
+    Delta,
 }
 
 fn prepare_field_for_flight(
@@ -657,6 +735,26 @@ fn prepare_schema_for_flight(
     Schema::new(fields).with_metadata(schema.metadata().clone())
 }
 
+/// Compacts the top level view arrays of `batch`, so that a slice of them only
+/// encodes the data it references rather than every data buffer of the array
+fn gc_view_arrays(batch: &RecordBatch) -> Result<RecordBatch> {
+    let columns = batch
+        .columns()
+        .iter()
+        .map(|column| match column.data_type() {
+            DataType::Utf8View => Arc::new(column.as_string_view().gc()) as ArrayRef,
+            DataType::BinaryView => Arc::new(column.as_binary_view().gc()) as ArrayRef,
+            _ => Arc::clone(column),
+        })
+        .collect();
+    let options = RecordBatchOptions::new().with_row_count(Some(batch.num_rows()));
+    Ok(RecordBatch::try_new_with_options(
+        batch.schema(),
+        columns,
+        &options,
+    )?)
+}
+
 /// Split [`RecordBatch`] so it hopefully fits into a gRPC response.
 ///
 /// Data is zero-copy sliced into batches.
@@ -670,7 +768,7 @@ fn split_batch_for_grpc_response(
     let size = batch
         .columns()
         .iter()
-        .map(|col| col.get_buffer_memory_size())
+        .map(|col| size_excluding_dictionary_values(col.as_ref()))
         .sum::<usize>();
 
     let n_batches =
@@ -691,6 +789,15 @@ fn split_batch_for_grpc_response(
     })
 }
 
+/// Returns the memory size of `array`, excluding the values of a dictionary
+/// array as they are encoded in a separate message
+fn size_excluding_dictionary_values(array: &dyn Array) -> usize {
+    match array.as_any_dictionary_opt() {
+        Some(dictionary) => dictionary.keys().get_buffer_memory_size(),
+        None => array.get_buffer_memory_size(),
+    }
+}
+
 /// The data needed to encode a stream of flight data, holding on to
 /// shared Dictionaries.
 ///
@@ -784,10 +891,10 @@ fn hydrate_dictionary(array: &ArrayRef, data_type: &DataType) -> Result<ArrayRef
 
 #[cfg(test)]
 mod tests {
-    use crate::decode::{DecodedPayload, FlightDataDecoder};
+    use crate::decode::{DecodedPayload, FlightDataDecoder, FlightRecordBatchStream};
     use arrow_array::builder::{
         FixedSizeListBuilder, GenericByteDictionaryBuilder, GenericListViewBuilder, ListBuilder,
-        StringDictionaryBuilder, StructBuilder,
+        StringDictionaryBuilder, StringViewBuilder, StructBuilder,
     };
     use arrow_array::*;
     use arrow_array::{cast::downcast_array, types::*};
@@ -795,7 +902,9 @@ mod tests {
     use arrow_cast::pretty::pretty_format_batches;
     use arrow_ipc::{CompressionType, MetadataVersion};
     use arrow_schema::{UnionFields, UnionMode};
+    use arrow_select::concat::concat_batches;
     use builder::MapBuilder;
+    use futures::TryStreamExt;
     use std::collections::HashMap;
 
     use super::*;
@@ -893,6 +1002,113 @@ mod tests {
         verify_flight_round_trip(vec![batch1, batch2]).await;
     }
 
+    /// Encodes `batch` split with `max_flight_data_size`, returning the sizes of the
+    /// encoded record batch messages after verifying the batch round trips
+    async fn split_batch_sizes(batch: RecordBatch, max_flight_data_size: usize) -> Vec<usize> {
+        let encoded: Vec<_> = FlightDataEncoderBuilder::default()
+            .with_max_flight_data_size(max_flight_data_size)
+            .build(futures::stream::iter([Ok(batch.clone())]))
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        let sizes = encoded
+            .iter()
+            .filter(|data| {
+                let message = arrow_ipc::root_as_message(&data.data_header).unwrap();
+                message.header_type() == arrow_ipc::MessageHeader::RecordBatch
+            })
+            .map(|data| data.data_header.len() + data.data_body.len())
+            .collect();
+
+        let decoded: Vec<_> = FlightRecordBatchStream::new_from_flight_data(futures::stream::iter(
+            encoded.into_iter().map(Ok),
+        ))
+        .try_collect()
+        .await
+        .unwrap();
+        let decoded = concat_batches(&batch.schema(), &decoded).unwrap();
+        assert_eq!(decoded, batch);
+        sizes
+    }
+
+    #[tokio::test]
+    async fn test_split_view_arrays() {
+        // Every value is stored in the data buffers, which are shared by all slices
+        let values = (0..1024).map(|i| format!("{i:0>100}"));
+        let array = StringViewArray::from_iter_values(values);
+        let batch = RecordBatch::try_from_iter(vec![("view", Arc::new(array) as _)]).unwrap();
+
+        let max_flight_data_size = 8192;
+        let sizes = split_batch_sizes(batch, max_flight_data_size).await;
+        assert!(
+            sizes.iter().all(|s| *s <= max_flight_data_size),
+            "{sizes:?}"
+        );
+        // 1024 values of 116 bytes need at least 15 messages
+        assert!((15..=64).contains(&sizes.len()), "{}", sizes.len());
+
+        // Nested view arrays are not compacted, so splitting stops rather than
+        // producing a message per row
+        let values = (0..1024).map(|i| Some(vec![Some(format!("{i:0>100}"))]));
+        let mut builder = ListBuilder::new(StringViewBuilder::new());
+        builder.extend(values);
+        let batch =
+            RecordBatch::try_from_iter(vec![("list", Arc::new(builder.finish()) as _)]).unwrap();
+        let sizes = split_batch_sizes(batch, max_flight_data_size).await;
+        assert!(sizes.len() < 64, "{}", sizes.len());
+    }
+
+    #[tokio::test]
+    async fn test_split_dictionary_resend() {
+        // keys are small, but the values are large enough that an estimate
+        // including them would split the batch into a piece per row
+        let values = StringArray::from_iter_values((0..64).map(|i| format!("{i:0>64}")));
+        let keys = UInt16Array::from_iter_values((0..2048).map(|i| i % 64));
+        let array = DictionaryArray::new(keys, Arc::new(values));
+        let batch = RecordBatch::try_from_iter(vec![("dict", Arc::new(array) as _)]).unwrap();
+
+        let max_flight_data_size = 1024;
+        let encoded: Vec<_> = FlightDataEncoderBuilder::default()
+            .with_max_flight_data_size(max_flight_data_size)
+            .with_options(IpcWriteOptions::try_new(8, false, MetadataVersion::V5).unwrap())
+            .with_dictionary_handling(DictionaryHandling::Resend)
+            .build(futures::stream::iter([Ok(batch.clone())]))
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+
+        let mut num_dictionaries = 0;
+        let mut num_batches = 0;
+        for data in &encoded {
+            let message = arrow_ipc::root_as_message(&data.data_header).unwrap();
+            match message.header_type() {
+                arrow_ipc::MessageHeader::DictionaryBatch => num_dictionaries += 1,
+                arrow_ipc::MessageHeader::RecordBatch => {
+                    num_batches += 1;
+                    let size = data.data_header.len() + data.data_body.len();
+                    assert!(size <= max_flight_data_size, "{size}");
+                }
+                _ => {}
+            }
+        }
+        // the dictionary is only sent once, and 2048 2-byte keys need at least 4 batches
+        assert_eq!(num_dictionaries, 1);
+        assert!((4..=8).contains(&num_batches), "{num_batches}");
+
+        let decoded: Vec<_> = FlightRecordBatchStream::new_from_flight_data(futures::stream::iter(
+            encoded.into_iter().map(Ok),
+        ))
+        .try_collect()
+        .await
+        .unwrap();
+        let mut offset = 0;
+        for decoded in decoded {
+            assert_eq!(decoded, batch.slice(offset, decoded.num_rows()));
+            offset += decoded.num_rows();
+        }
+        assert_eq!(offset, batch.num_rows());
+    }
+
     #[tokio::test]
     async fn test_compression_round_trip() {
         // Round trip a batch through Flight with IPC body compression enabled. This exercises
@@ -914,6 +1130,53 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_try_with_compression() {
+        let batch = RecordBatch::try_from_iter(vec![(
+            "ints",
+            Arc::new(Int32Array::from_iter_values(std::iter::repeat_n(7, 1024))) as ArrayRef,
+        )])
+        .unwrap();
+
+        let encode = |builder: FlightDataEncoderBuilder| {
+            let stream = futures::stream::iter(vec![Ok(batch.clone())]);
+            builder.build(stream).try_collect::<Vec<_>>()
+        };
+        let uncompressed = encode(FlightDataEncoderBuilder::new()).await.unwrap();
+        let builder = FlightDataEncoderBuilder::new()
+            .try_with_compression(Some(CompressionType::ZSTD))
+            .unwrap();
+        let compressed = encode(builder).await.unwrap();
+        assert!(compressed[1].data_body.len() < uncompressed[1].data_body.len());
+
+        let decoded: Vec<_> = FlightRecordBatchStream::new_from_flight_data(futures::stream::iter(
+            compressed.into_iter().map(Ok),
+        ))
+        .try_collect()
+        .await
+        .unwrap();
+        assert_eq!(decoded, vec![batch.clone()]);
+
+        // disabling compression again
+        let builder = FlightDataEncoderBuilder::new()
+            .try_with_compression(Some(CompressionType::LZ4_FRAME))
+            .unwrap()
+            .try_with_compression(None)
+            .unwrap();
+        assert_eq!(encode(builder).await.unwrap(), uncompressed);
+
+        // compression requires metadata version V5
+        let options = IpcWriteOptions::try_new(8, false, arrow_ipc::MetadataVersion::V4).unwrap();
+        let err = FlightDataEncoderBuilder::new()
+            .with_options(options)
+            .try_with_compression(Some(CompressionType::ZSTD))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Arrow error: Invalid argument error: Compression only supported in metadata v5 and above"
+        );
+    }
+
     #[tokio::test]
     async fn test_dictionary_hydration_known_schema() {
         let arr1: DictionaryArray<UInt16Type> = vec!["a", "a", "b"].into_iter().collect();
@@ -987,6 +1250,72 @@ mod tests {
         verify_flight_round_trip(vec![batch1, batch2]).await;
     }
 
+    /// Encodes `batches` with `dictionary_handling`, returning the `isDelta`
+    /// flag and length of each dictionary batch, and the decoded batches
+    async fn encode_dictionaries(
+        batches: Vec<RecordBatch>,
+        dictionary_handling: DictionaryHandling,
+    ) -> (Vec<(bool, i64)>, Vec<RecordBatch>) {
+        let flight_data: Vec<_> = FlightDataEncoderBuilder::default()
+            .with_dictionary_handling(dictionary_handling)
+            .build(futures::stream::iter(batches.into_iter().map(Ok)))
+            .try_collect()
+            .await
+            .unwrap();
+
+        let dictionaries = flight_data
+            .iter()
+            .filter_map(|data| {
+                let message = arrow_ipc::root_as_message(&data.data_header).unwrap();
+                let dictionary = message.header_as_dictionary_batch()?;
+                Some((dictionary.isDelta(), dictionary.data().unwrap().length()))
+            })
+            .collect();
+
+        let decoded = FlightRecordBatchStream::new_from_flight_data(futures::stream::iter(
+            flight_data.into_iter().map(Ok),
+        ))
+        .try_collect()
+        .await
+        .unwrap();
+        (dictionaries, decoded)
+    }
+
+    #[tokio::test]
+    async fn test_dictionary_delta() {
+        let schema = Arc::new(Schema::new(vec![Field::new_dictionary(
+            "dict",
+            DataType::UInt16,
+            DataType::Utf8,
+            false,
+        )]));
+        let make_batch = |keys: Vec<u16>, values: Vec<&str>| {
+            let array =
+                DictionaryArray::new(UInt16Array::from(keys), Arc::new(StringArray::from(values)));
+            RecordBatch::try_new(schema.clone(), vec![Arc::new(array)]).unwrap()
+        };
+        let batches = vec![
+            make_batch(vec![0, 1, 0], vec!["a", "b"]),
+            // extends the previous dictionary
+            make_batch(vec![2, 1, 3], vec!["a", "b", "c", "d"]),
+            // same values as the previous dictionary
+            make_batch(vec![3, 3], vec!["a", "b", "c", "d"]),
+            // replaces the previous dictionary
+            make_batch(vec![0, 1], vec!["x", "y"]),
+        ];
+
+        let (dictionaries, decoded) =
+            encode_dictionaries(batches.clone(), DictionaryHandling::Delta).await;
+        assert_eq!(dictionaries, vec![(false, 2), (true, 2), (false, 2)]);
+        assert_eq!(decoded, batches);
+        assert_eq!(decoded[1].schema(), schema);
+
+        let (dictionaries, decoded) =
+            encode_dictionaries(batches.clone(), DictionaryHandling::Resend).await;
+        assert_eq!(dictionaries, vec![(false, 2), (false, 4), (false, 2)]);
+        assert_eq!(decoded, batches);
+    }
+
     #[tokio::test]
     async fn test_dictionary_list_hydration() {
         let mut builder = ListBuilder::new(StringDictionaryBuilder::<UInt16Type>::new());
@@ -1941,7 +2270,7 @@ mod tests {
         ])
         .unwrap();
 
-        verify_encoded_split(batch, 120).await;
+        verify_encoded_split(batch, 0).await;
     }
 
     #[tokio::test]
@@ -1950,9 +2279,8 @@ mod tests {
         let array = StringArray::from_iter_values((0..1024).map(|i| "*".repeat(i)));
         let batch = RecordBatch::try_from_iter(vec![("data", Arc::new(array) as _)]).unwrap();
 
-        // overage is much higher than ideal
-        // https://github.com/apache/arrow-rs/issues/3478
-        verify_encoded_split(batch, 4312).await;
+        // the longest rows exceed the smallest limit on their own
+        verify_encoded_split(batch, 168).await;
     }
 
     #[tokio::test]
@@ -1985,9 +2313,7 @@ mod tests {
         ])
         .unwrap();
 
-        // 5k over limit (which is 2x larger than limit of 5k)
-        // overage is much higher than ideal
-        // https://github.com/apache/arrow-rs/issues/3478
+        // the last row exceeds the smallest limit on its own
         verify_encoded_split(batch, 5808).await;
     }
 
@@ -2004,7 +2330,7 @@ mod tests {
 
         let batch = RecordBatch::try_from_iter(vec![("a1", Arc::new(array) as _)]).unwrap();
 
-        verify_encoded_split(batch, 56).await;
+        verify_encoded_split(batch, 0).await;
     }
 
     #[tokio::test]
@@ -2016,9 +2342,8 @@ mod tests {
 
         let batch = RecordBatch::try_from_iter(vec![("a1", Arc::new(array) as _)]).unwrap();
 
-        // overage is much higher than ideal
-        // https://github.com/apache/arrow-rs/issues/3478
-        verify_encoded_split(batch, 3336).await;
+        // the longest rows exceed the smallest limit on their own
+        verify_encoded_split(batch, 1192).await;
     }
 
     #[tokio::test]
@@ -2030,8 +2355,7 @@ mod tests {
 
         let batch = RecordBatch::try_from_iter(vec![("a1", Arc::new(array) as _)]).unwrap();
 
-        // overage is much higher than ideal
-        // https://github.com/apache/arrow-rs/issues/3478
+        // the longest rows exceed the smallest limit on their own
         verify_encoded_split(batch, 5288).await;
     }
 
@@ -2055,9 +2379,8 @@ mod tests {
         ])
         .unwrap();
 
-        // overage is much higher than ideal
-        // https://github.com/apache/arrow-rs/issues/3478
-        verify_encoded_split(batch, 4136).await;
+        // the longest rows exceed the smallest limit on their own
+        verify_encoded_split(batch, 1528).await;
     }
 
     /// Return size, in memory of flight data
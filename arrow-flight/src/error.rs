@@ -18,6 +18,8 @@
 use std::error::Error;
 
 use arrow_schema::ArrowError;
+#[cfg(feature = "grpc")]
+use prost::Message;
 
 /// Errors for the Apache Arrow Flight crate
 #[derive(Debug)]
@@ -27,6 +29,7 @@ pub enum FlightError {
     /// Returned when functionality is not yet available.
     NotYetImplemented(String),
     /// Error from the underlying tonic library
+    #[cfg(feature = "grpc")]
     Tonic(Box<tonic::Status>),
     /// Some unexpected message was received
     ProtocolError(String),
@@ -53,6 +56,7 @@ impl std::fmt::Display for FlightError {
         match self {
             FlightError::Arrow(source) => write!(f, "Arrow error: {source}"),
             FlightError::NotYetImplemented(desc) => write!(f, "Not yet implemented: {desc}"),
+            #[cfg(feature = "grpc")]
             FlightError::Tonic(source) => write!(f, "Tonic error: {source}"),
             FlightError::ProtocolError(desc) => write!(f, "Protocol error: {desc}"),
             FlightError::DecodeError(desc) => write!(f, "Decode error: {desc}"),
@@ -65,6 +69,7 @@ impl Error for FlightError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
             FlightError::Arrow(source) => Some(source),
+            #[cfg(feature = "grpc")]
             FlightError::Tonic(source) => Some(source),
             FlightError::ExternalError(source) => Some(source.as_ref()),
             _ => None,
@@ -72,6 +77,7 @@ impl Error for FlightError {
     }
 }
 
+#[cfg(feature = "grpc")]
 impl From<tonic::Status> for FlightError {
     fn from(status: tonic::Status) -> Self {
         Self::Tonic(Box::new(status))
@@ -92,6 +98,7 @@ impl From<ArrowError> for FlightError {
 
 // default conversion from FlightError to tonic treats everything
 // other than `Status` as an internal error
+#[cfg(feature = "grpc")]
 impl From<FlightError> for tonic::Status {
     fn from(value: FlightError) -> Self {
         match value {
@@ -108,6 +115,132 @@ impl From<FlightError> for tonic::Status {
 /// Result type for the Apache Arrow Flight crate
 pub type Result<T> = std::result::Result<T, FlightError>;
 
+/// The type URL of [`ErrorDetails`] when packed in a [`prost_types::Any`]
+pub const ERROR_DETAILS_TYPE_URL: &str = "type.googleapis.com/arrow.flight.protocol.ErrorDetails";
+
+/// Structured information about an error returned by a Flight server
+///
+/// Servers attach the details to a [`tonic::Status`] using
+/// [`Self::into_status`]. They are sent as a `google.rpc.Status` message in
+/// the `grpc-status-details-bin` trailer, containing the details packed in a
+/// `google.protobuf.Any` with [`ERROR_DETAILS_TYPE_URL`], which clients can
+/// read with [`FlightError::details`].
+///
+/// # Example
+/// ```
+/// # use arrow_flight::error::{ErrorDetails, FlightError};
+/// # #[cfg(feature = "grpc")]
+/// # {
+/// // on the server
+/// let details = ErrorDetails::new()
+///     .with_sql_state("40001")
+///     .with_retryable(true);
+/// let status = details.clone().into_status(tonic::Code::Aborted, "serialization failure");
+///
+/// // on the client
+/// let error = FlightError::from(status);
+/// assert_eq!(error.details(), Some(details));
+/// # }
+/// ```
+#[derive(Clone, PartialEq, Eq, ::prost::Message)]
+pub struct ErrorDetails {
+    /// The SQLSTATE code of the error, if any
+    #[prost(string, optional, tag = "1")]
+    pub sql_state: Option<String>,
+    /// Whether retrying the request may succeed
+    #[prost(bool, tag = "2")]
+    pub retryable: bool,
+    /// Whether some, but not all, results were sent before the error
+    #[prost(bool, tag = "3")]
+    pub partial_results: bool,
+    /// Application specific metadata
+    #[prost(map = "string, string", tag = "4")]
+    pub metadata: std::collections::HashMap<String, String>,
+}
+
+impl ErrorDetails {
+    /// Create a new, empty, [`ErrorDetails`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the SQLSTATE code of the error
+    pub fn with_sql_state(mut self, sql_state: impl Into<String>) -> Self {
+        self.sql_state = Some(sql_state.into());
+        self
+    }
+
+    /// Set whether retrying the request may succeed
+    pub fn with_retryable(mut self, retryable: bool) -> Self {
+        self.retryable = retryable;
+        self
+    }
+
+    /// Set whether some results were sent before the error
+    pub fn with_partial_results(mut self, partial_results: bool) -> Self {
+        self.partial_results = partial_results;
+        self
+    }
+
+    /// Add application specific metadata
+    pub fn with_metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.metadata.insert(key.into(), value.into());
+        self
+    }
+
+    /// Create a [`tonic::Status`] with `code` and `message` carrying these details
+    #[cfg(feature = "grpc")]
+    pub fn into_status(self, code: tonic::Code, message: impl Into<String>) -> tonic::Status {
+        let message = message.into();
+        let rpc_status = RpcStatus {
+            code: code as i32,
+            message: message.clone(),
+            details: vec![prost_types::Any {
+                type_url: ERROR_DETAILS_TYPE_URL.to_string(),
+                value: self.encode_to_vec(),
+            }],
+        };
+        tonic::Status::with_details(code, message, rpc_status.encode_to_vec().into())
+    }
+
+    /// Decode the [`ErrorDetails`] attached to `status`, if any
+    #[cfg(feature = "grpc")]
+    pub fn from_status(status: &tonic::Status) -> Option<Self> {
+        if status.details().is_empty() {
+            return None;
+        }
+        let rpc_status = RpcStatus::decode(status.details()).ok()?;
+        rpc_status
+            .details
+            .iter()
+            .find(|any| any.type_url == ERROR_DETAILS_TYPE_URL)
+            .and_then(|any| Self::decode(any.value.as_slice()).ok())
+    }
+}
+
+impl FlightError {
+    /// Returns the [`ErrorDetails`] sent by the server with this error, if any
+    pub fn details(&self) -> Option<ErrorDetails> {
+        match self {
+            #[cfg(feature = "grpc")]
+            FlightError::Tonic(status) => ErrorDetails::from_status(status),
+            _ => None,
+        }
+    }
+}
+
+/// The `google.rpc.Status` message, used by gRPC to send error details
+#[cfg(feature = "grpc")]
+#[derive(Clone, PartialEq, ::prost::Message)]
+struct RpcStatus {
+    #[prost(int32, tag = "1")]
+    code: i32,
+    #[prost(string, tag = "2")]
+    message: String,
+    #[prost(message, repeated, tag = "3")]
+    details: Vec<prost_types::Any>,
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -154,6 +287,36 @@ mod test {
         assert!(matches!(source, FlightError::DecodeError(_)));
     }
 
+    #[test]
+    #[cfg(feature = "grpc")]
+    fn test_error_details() {
+        let details = ErrorDetails::new()
+            .with_sql_state("42P01")
+            .with_partial_results(true)
+            .with_metadata("table", "foo");
+        let status = details
+            .clone()
+            .into_status(tonic::Code::NotFound, "table not found");
+        assert_eq!(status.code(), tonic::Code::NotFound);
+        assert_eq!(status.message(), "table not found");
+
+        let error = FlightError::from(status);
+        assert_eq!(error.details(), Some(details));
+
+        // no details
+        let error = FlightError::from(tonic::Status::internal("oops"));
+        assert_eq!(error.details(), None);
+        assert_eq!(FlightError::protocol("oops").details(), None);
+
+        // details of another type
+        let status = tonic::Status::with_details(
+            tonic::Code::Internal,
+            "oops",
+            bytes::Bytes::from_static(b"not a google.rpc.Status"),
+        );
+        assert_eq!(FlightError::from(status).details(), None);
+    }
+
     #[test]
     fn test_error_size() {
         // use Box in variants to keep this size down
@@ -0,0 +1,293 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Helpers for bidirectional `DoExchange` calls
+//!
+//! A `DoExchange` call sends a stream of [`RecordBatch`]es in each direction.
+//! [`exchange_channel`] pairs a bounded channel with a [`FlightDataEncoder`],
+//! returning an [`ExchangeSender`] that waits for capacity before accepting
+//! more batches, so that a fast producer can not buffer an unbounded amount of
+//! data ahead of the network.
+//!
+//! On the client [`FlightClient::exchange`](crate::FlightClient::exchange)
+//! starts a call using such a channel, and on the server
+//! [`ServerExchange::accept`] decodes the request and creates the response.
+
+use arrow_array::RecordBatch;
+use futures::channel::mpsc;
+use futures::stream::BoxStream;
+use futures::{SinkExt, Stream, StreamExt, TryStreamExt};
+use tonic::Status;
+
+use crate::decode::FlightRecordBatchStream;
+use crate::encode::{FlightDataEncoder, FlightDataEncoderBuilder};
+use crate::error::{FlightError, Result};
+use crate::{FlightData, FlightDescriptor};
+
+/// Sends [`RecordBatch`]es to one side of a `DoExchange` call
+///
+/// Created by [`exchange_channel`]. The stream ends once all clones of the
+/// sender are dropped or [`Self::close`] is called.
+#[derive(Debug, Clone)]
+pub struct ExchangeSender {
+    sender: mpsc::Sender<Result<RecordBatch>>,
+}
+
+impl ExchangeSender {
+    /// Send `batch`, waiting until there is capacity in the channel
+    ///
+    /// Returns an error if the stream has been closed or dropped
+    pub async fn send(&mut self, batch: RecordBatch) -> Result<()> {
+        self.send_inner(Ok(batch)).await
+    }
+
+    /// Terminate the stream with `error`
+    ///
+    /// Batches sent before the error are still sent
+    pub async fn abort(&mut self, error: FlightError) -> Result<()> {
+        self.send_inner(Err(error)).await?;
+        self.close();
+        Ok(())
+    }
+
+    /// Close the stream, after any batches already sent have been encoded
+    pub fn close(&mut self) {
+        self.sender.close_channel()
+    }
+
+    async fn send_inner(&mut self, item: Result<RecordBatch>) -> Result<()> {
+        self.sender
+            .send(item)
+            .await
+            .map_err(|_| FlightError::protocol("DoExchange stream closed"))
+    }
+}
+
+/// Create a channel for sending [`RecordBatch`]es with `encoder`
+///
+/// Returns the [`ExchangeSender`] and the [`FlightDataEncoder`] producing the
+/// encoded [`FlightData`]. Once `capacity` batches are buffered,
+/// [`ExchangeSender::send`] waits for the encoder to be polled.
+///
+/// Setting a schema with [`FlightDataEncoderBuilder::with_schema`] sends the
+/// schema as soon as the encoder is polled, allowing the other side to learn
+/// it before any batches are sent.
+pub fn exchange_channel(
+    encoder: FlightDataEncoderBuilder,
+    capacity: usize,
+) -> (ExchangeSender, FlightDataEncoder) {
+    let (sender, receiver) = mpsc::channel(capacity);
+    (ExchangeSender { sender }, encoder.build(receiver))
+}
+
+/// The server side of a `DoExchange` call
+///
+/// # Example
+/// ```no_run
+/// # use arrow_flight::FlightData;
+/// # use arrow_flight::encode::FlightDataEncoderBuilder;
+/// # use arrow_flight::exchange::ServerExchange;
+/// # use futures::StreamExt;
+/// # use futures::stream::BoxStream;
+/// # use tonic::{Request, Response, Status, Streaming};
+/// # type DoExchangeStream = BoxStream<'static, Result<FlightData, Status>>;
+/// async fn do_exchange(
+///     request: Request<Streaming<FlightData>>,
+/// ) -> Result<Response<DoExchangeStream>, Status> {
+///     let exchange =
+///         ServerExchange::accept(request.into_inner(), FlightDataEncoderBuilder::new(), 2)
+///             .await?;
+///     let ServerExchange { mut input, mut output, response, .. } = exchange;
+///
+///     // echo the batches back to the client
+///     tokio::spawn(async move {
+///         while let Some(batch) = input.next().await {
+///             let result = match batch {
+///                 Ok(batch) => output.send(batch).await,
+///                 Err(e) => output.abort(e).await,
+///             };
+///             if result.is_err() {
+///                 break;
+///             }
+///         }
+///     });
+///     Ok(Response::new(response))
+/// }
+/// ```
+pub struct ServerExchange {
+    /// The descriptor of the first message received from the client, if any
+    pub descriptor: Option<FlightDescriptor>,
+    /// The batches received from the client
+    pub input: FlightRecordBatchStream,
+    /// Sends batches to the client
+    pub output: ExchangeSender,
+    /// The encoded batches sent by `output`, to be returned from
+    /// [`FlightService::do_exchange`](crate::flight_service_server::FlightService::do_exchange)
+    pub response: BoxStream<'static, std::result::Result<FlightData, Status>>,
+}
+
+impl std::fmt::Debug for ServerExchange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ServerExchange")
+            .field("descriptor", &self.descriptor)
+            .field("input", &self.input)
+            .field("output", &self.output)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ServerExchange {
+    /// Accept a `DoExchange` `request`, encoding the response with `encoder`
+    /// (see [`exchange_channel`] for `capacity`)
+    ///
+    /// Waits for the first message from the client to read its
+    /// [`FlightDescriptor`]. A first message that carries only a descriptor
+    /// is not passed to [`Self::input`].
+    pub async fn accept<S>(
+        request: S,
+        encoder: FlightDataEncoderBuilder,
+        capacity: usize,
+    ) -> std::result::Result<Self, Status>
+    where
+        S: Stream<Item = std::result::Result<FlightData, Status>> + Send + 'static,
+    {
+        let mut request = request.boxed();
+        let (descriptor, first) = match request.next().await.transpose()? {
+            Some(data) if data.data_header.is_empty() => (data.flight_descriptor, None),
+            Some(data) => (data.flight_descriptor.clone(), Some(data)),
+            None => (None, None),
+        };
+        let request = futures::stream::iter(first.map(Ok)).chain(request);
+        let input =
+            FlightRecordBatchStream::new_from_flight_data(request.map_err(FlightError::from));
+
+        let (output, response) = exchange_channel(encoder, capacity);
+        Ok(Self {
+            descriptor,
+            input,
+            output,
+            response: response.map_err(Status::from).boxed(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::{ArrayRef, Int32Array};
+    use std::sync::Arc;
+
+    fn batch(values: &[i32]) -> RecordBatch {
+        let array = Arc::new(Int32Array::from(values.to_vec())) as ArrayRef;
+        RecordBatch::try_from_iter([("a", array)]).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_exchange_round_trip() {
+        let descriptor = FlightDescriptor::new_path(vec!["table".to_string()]);
+        let encoder =
+            FlightDataEncoderBuilder::new().with_flight_descriptor(Some(descriptor.clone()));
+        let (mut client_output, request) = exchange_channel(encoder, 1);
+
+        let batches = vec![batch(&[1, 2]), batch(&[3]), batch(&[4, 5, 6])];
+        let to_send = batches.clone();
+        let client = tokio::spawn(async move {
+            for batch in to_send {
+                client_output.send(batch).await.unwrap();
+            }
+        });
+
+        let exchange = ServerExchange::accept(
+            request.map_err(Status::from),
+            FlightDataEncoderBuilder::new(),
+            1,
+        )
+        .await
+        .unwrap();
+        assert_eq!(exchange.descriptor, Some(descriptor));
+
+        let ServerExchange {
+            input,
+            mut output,
+            response,
+            ..
+        } = exchange;
+        let server = tokio::spawn(async move {
+            let received: Vec<_> = input.try_collect().await.unwrap();
+            for batch in &received {
+                output.send(batch.slice(0, 1)).await.unwrap();
+            }
+            received
+        });
+
+        let response: Vec<_> =
+            FlightRecordBatchStream::new_from_flight_data(response.map_err(FlightError::from))
+                .try_collect()
+                .await
+                .unwrap();
+
+        client.await.unwrap();
+        assert_eq!(server.await.unwrap(), batches);
+        let expected: Vec<_> = batches.iter().map(|b| b.slice(0, 1)).collect();
+        assert_eq!(response, expected);
+    }
+
+    #[tokio::test]
+    async fn test_exchange_backpressure() {
+        let (mut sender, mut encoder) = exchange_channel(FlightDataEncoderBuilder::new(), 1);
+        sender.send(batch(&[1])).await.unwrap();
+
+        // the channel is full until the encoder is polled
+        let mut send = Box::pin(sender.send(batch(&[2])));
+        assert!(futures::FutureExt::now_or_never(send.as_mut()).is_none());
+
+        // schema and first batch
+        encoder.next().await.unwrap().unwrap();
+        encoder.next().await.unwrap().unwrap();
+        send.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_exchange_descriptor_only() {
+        let descriptor = FlightDescriptor::new_cmd("cmd");
+        let request =
+            futures::stream::iter([Ok(FlightData::new().with_descriptor(descriptor.clone()))]);
+        let exchange = ServerExchange::accept(request, FlightDataEncoderBuilder::new(), 1)
+            .await
+            .unwrap();
+        assert_eq!(exchange.descriptor, Some(descriptor));
+        let input: Vec<_> = exchange.input.try_collect().await.unwrap();
+        assert!(input.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_exchange_abort() {
+        let (mut sender, encoder) = exchange_channel(FlightDataEncoderBuilder::new(), 1);
+        let abort = tokio::spawn(async move {
+            sender.send(batch(&[1])).await.unwrap();
+            sender
+                .abort(FlightError::protocol("stopped"))
+                .await
+                .unwrap();
+        });
+        let result: Result<Vec<_>> = FlightRecordBatchStream::new_from_flight_data(encoder)
+            .try_collect()
+            .await;
+        abort.await.unwrap();
+        assert_eq!(result.unwrap_err().to_string(), "Protocol error: stopped");
+    }
+}
@@ -0,0 +1,379 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Per-message hooks for Flight clients and servers
+//!
+//! A [`FlightInterceptor`] is invoked for each request and each message that
+//! passes through a [`FlightClient`](crate::FlightClient) (see
+//! [`FlightClient::with_interceptor`](crate::FlightClient::with_interceptor))
+//! or an [`InterceptedFlightService`]. Unlike a tonic
+//! [`Interceptor`](tonic::service::Interceptor), which only sees the headers of
+//! each call, it can observe and modify the individual [`FlightData`] and
+//! [`FlightDescriptor`] messages of a stream, for example to attach tracing
+//! information, count transferred bytes or refresh credentials.
+
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use futures::{Stream, StreamExt, stream::BoxStream};
+use tonic::codec::{Codec, EncodeBody};
+use tonic::metadata::MetadataMap;
+use tonic::{Request, Response, Status, Streaming};
+use tonic_prost::ProstCodec;
+
+use crate::error::FlightError;
+use crate::flight_service_server::FlightService;
+use crate::{
+    Action, ActionType, Criteria, Empty, FlightData, FlightDescriptor, FlightInfo,
+    HandshakeRequest, HandshakeResponse, PollInfo, PutResult, SchemaResult, Ticket,
+};
+
+/// Hooks invoked for each request and message of a Flight call
+///
+/// All methods have a default implementation that does nothing, so
+/// implementations only need to override the hooks they are interested in.
+/// Returning an error from a hook aborts the request or stream with that error.
+///
+/// "Sent" and "received" are from the point of view of the side the
+/// interceptor is installed on: for a client, sent messages are those of the
+/// request stream, for a server, those of the response stream.
+///
+/// # Example: counting received bytes
+/// ```
+/// # use std::sync::atomic::{AtomicUsize, Ordering};
+/// # use arrow_flight::FlightData;
+/// # use arrow_flight::error::FlightError;
+/// # use arrow_flight::interceptor::FlightInterceptor;
+/// #[derive(Debug, Default)]
+/// struct ByteCounter {
+///     received: AtomicUsize,
+/// }
+///
+/// impl FlightInterceptor for ByteCounter {
+///     fn on_receive(&self, data: &mut FlightData) -> Result<(), FlightError> {
+///         let len = data.data_header.len() + data.data_body.len();
+///         self.received.fetch_add(len, Ordering::Relaxed);
+///         Ok(())
+///     }
+/// }
+/// ```
+pub trait FlightInterceptor: Debug + Send + Sync + 'static {
+    /// Called with the gRPC metadata of each request
+    ///
+    /// A client can use this to add or refresh headers, such as a bearer
+    /// token, before each call. A server can use it to inspect or validate the
+    /// headers of each incoming call.
+    fn on_request(&self, _metadata: &mut MetadataMap) -> Result<(), FlightError> {
+        Ok(())
+    }
+
+    /// Called with each [`FlightDescriptor`] sent by a client, or received by
+    /// a server, including the descriptor attached to a [`FlightData`]
+    fn on_descriptor(&self, _descriptor: &mut FlightDescriptor) -> Result<(), FlightError> {
+        Ok(())
+    }
+
+    /// Called with each [`FlightData`] before it is sent
+    fn on_send(&self, _data: &mut FlightData) -> Result<(), FlightError> {
+        Ok(())
+    }
+
+    /// Called with each [`FlightData`] after it is received, before it is
+    /// decoded
+    fn on_receive(&self, _data: &mut FlightData) -> Result<(), FlightError> {
+        Ok(())
+    }
+}
+
+impl<T: FlightInterceptor + ?Sized> FlightInterceptor for Arc<T> {
+    fn on_request(&self, metadata: &mut MetadataMap) -> Result<(), FlightError> {
+        self.as_ref().on_request(metadata)
+    }
+
+    fn on_descriptor(&self, descriptor: &mut FlightDescriptor) -> Result<(), FlightError> {
+        self.as_ref().on_descriptor(descriptor)
+    }
+
+    fn on_send(&self, data: &mut FlightData) -> Result<(), FlightError> {
+        self.as_ref().on_send(data)
+    }
+
+    fn on_receive(&self, data: &mut FlightData) -> Result<(), FlightError> {
+        self.as_ref().on_receive(data)
+    }
+}
+
+/// An ordered list of [`FlightInterceptor`]s, invoked in the order they were
+/// added
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Interceptors(Vec<Arc<dyn FlightInterceptor>>);
+
+impl Interceptors {
+    pub(crate) fn push(&mut self, interceptor: Arc<dyn FlightInterceptor>) {
+        self.0.push(interceptor)
+    }
+
+    pub(crate) fn on_request(&self, metadata: &mut MetadataMap) -> Result<(), FlightError> {
+        self.0.iter().try_for_each(|i| i.on_request(metadata))
+    }
+
+    pub(crate) fn on_descriptor(
+        &self,
+        descriptor: &mut FlightDescriptor,
+    ) -> Result<(), FlightError> {
+        self.0.iter().try_for_each(|i| i.on_descriptor(descriptor))
+    }
+
+    fn on_send(&self, data: &mut FlightData) -> Result<(), FlightError> {
+        if let Some(descriptor) = data.flight_descriptor.as_mut() {
+            self.on_descriptor(descriptor)?;
+        }
+        self.0.iter().try_for_each(|i| i.on_send(data))
+    }
+
+    fn on_receive(&self, data: &mut FlightData) -> Result<(), FlightError> {
+        self.0.iter().try_for_each(|i| i.on_receive(data))
+    }
+
+    /// Invokes [`FlightInterceptor::on_descriptor`] and
+    /// [`FlightInterceptor::on_receive`] for each message of a request
+    /// received by a server
+    fn on_receive_request(&self, data: &mut FlightData) -> Result<(), FlightError> {
+        if let Some(descriptor) = data.flight_descriptor.as_mut() {
+            self.on_descriptor(descriptor)?;
+        }
+        self.on_receive(data)
+    }
+
+    /// Invokes [`FlightInterceptor::on_send`] for each message of `stream`
+    pub(crate) fn send<S, E>(
+        &self,
+        stream: S,
+    ) -> impl Stream<Item = Result<FlightData, E>> + use<S, E>
+    where
+        S: Stream<Item = Result<FlightData, E>>,
+        E: From<FlightError>,
+    {
+        let interceptors = self.clone();
+        stream.map(move |data| {
+            let mut data = data?;
+            interceptors.on_send(&mut data)?;
+            Ok(data)
+        })
+    }
+
+    /// Invokes [`FlightInterceptor::on_receive`] for each message of `stream`
+    pub(crate) fn receive<S, E>(
+        &self,
+        stream: S,
+    ) -> impl Stream<Item = Result<FlightData, E>> + use<S, E>
+    where
+        S: Stream<Item = Result<FlightData, E>>,
+        E: From<FlightError>,
+    {
+        let interceptors = self.clone();
+        stream.map(move |data| {
+            let mut data = data?;
+            interceptors.on_receive(&mut data)?;
+            Ok(data)
+        })
+    }
+
+    /// Invokes [`Self::on_receive_request`] for each message of `stream`
+    ///
+    /// The [`FlightService`] trait requires the request streams of `DoPut` and
+    /// `DoExchange` to be a [`Streaming`], which can only be constructed from
+    /// a http body. The intercepted messages are therefore encoded into a new
+    /// body of the request, without any state shared with other requests.
+    fn receive_request(&self, stream: Streaming<FlightData>) -> Streaming<FlightData> {
+        let interceptors = self.clone();
+        let stream = stream.map(move |data| {
+            let mut data = data?;
+            interceptors.on_receive_request(&mut data)?;
+            Ok(data)
+        });
+        let mut codec = ProstCodec::<FlightData, FlightData>::default();
+        let body = EncodeBody::new_client(codec.encoder(), stream, None, None);
+        // The size of each message has already been limited by the server
+        Streaming::new_request(codec.decoder(), body, None, Some(usize::MAX))
+    }
+}
+
+/// A [`FlightService`] that invokes [`FlightInterceptor`]s around an inner
+/// service
+///
+/// For every call [`FlightInterceptor::on_request`] is invoked with the
+/// request headers. [`FlightInterceptor::on_descriptor`] is invoked with the
+/// descriptors of `GetFlightInfo`, `PollFlightInfo` and `GetSchema`, and
+/// [`FlightInterceptor::on_send`] with each [`FlightData`] of the `DoGet` and
+/// `DoExchange` responses.
+///
+/// [`FlightInterceptor::on_receive`] is invoked with each [`FlightData`] of
+/// the `DoPut` and `DoExchange` requests, after
+/// [`FlightInterceptor::on_descriptor`] for any descriptor it carries.
+///
+/// # Example
+/// ```no_run
+/// # use arrow_flight::flight_service_server::{FlightService, FlightServiceServer};
+/// # use arrow_flight::interceptor::{FlightInterceptor, InterceptedFlightService};
+/// # fn run(service: impl FlightService, interceptor: impl FlightInterceptor) {
+/// let service = InterceptedFlightService::new(service).with_interceptor(interceptor);
+/// let server = FlightServiceServer::new(service);
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct InterceptedFlightService<S> {
+    inner: S,
+    interceptors: Interceptors,
+}
+
+impl<S: FlightService> InterceptedFlightService<S> {
+    /// Wrap `inner` without any interceptors
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            interceptors: Interceptors::default(),
+        }
+    }
+
+    /// Add an interceptor, invoked after any previously added interceptors
+    pub fn with_interceptor(mut self, interceptor: impl FlightInterceptor) -> Self {
+        self.interceptors.push(Arc::new(interceptor));
+        self
+    }
+
+    /// Return a reference to the inner service
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    /// Consume self and return the inner service
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+
+    fn intercept_request<T>(&self, request: &mut Request<T>) -> Result<(), Status> {
+        Ok(self.interceptors.on_request(request.metadata_mut())?)
+    }
+
+    fn intercept_descriptor(&self, request: &mut Request<FlightDescriptor>) -> Result<(), Status> {
+        self.intercept_request(request)?;
+        Ok(self.interceptors.on_descriptor(request.get_mut())?)
+    }
+}
+
+fn boxed<T, S>(response: Response<S>) -> Response<BoxStream<'static, Result<T, Status>>>
+where
+    S: Stream<Item = Result<T, Status>> + Send + 'static,
+{
+    response.map(|s| s.boxed())
+}
+
+#[tonic::async_trait]
+impl<S: FlightService> FlightService for InterceptedFlightService<S> {
+    type HandshakeStream = BoxStream<'static, Result<HandshakeResponse, Status>>;
+    type ListFlightsStream = BoxStream<'static, Result<FlightInfo, Status>>;
+    type DoGetStream = BoxStream<'static, Result<FlightData, Status>>;
+    type DoPutStream = BoxStream<'static, Result<PutResult, Status>>;
+    type DoActionStream = BoxStream<'static, Result<crate::Result, Status>>;
+    type ListActionsStream = BoxStream<'static, Result<ActionType, Status>>;
+    type DoExchangeStream = BoxStream<'static, Result<FlightData, Status>>;
+
+    async fn handshake(
+        &self,
+        mut request: Request<Streaming<HandshakeRequest>>,
+    ) -> Result<Response<Self::HandshakeStream>, Status> {
+        self.intercept_request(&mut request)?;
+        Ok(boxed(self.inner.handshake(request).await?))
+    }
+
+    async fn list_flights(
+        &self,
+        mut request: Request<Criteria>,
+    ) -> Result<Response<Self::ListFlightsStream>, Status> {
+        self.intercept_request(&mut request)?;
+        Ok(boxed(self.inner.list_flights(request).await?))
+    }
+
+    async fn get_flight_info(
+        &self,
+        mut request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        self.intercept_descriptor(&mut request)?;
+        self.inner.get_flight_info(request).await
+    }
+
+    async fn poll_flight_info(
+        &self,
+        mut request: Request<FlightDescriptor>,
+    ) -> Result<Response<PollInfo>, Status> {
+        self.intercept_descriptor(&mut request)?;
+        self.inner.poll_flight_info(request).await
+    }
+
+    async fn get_schema(
+        &self,
+        mut request: Request<FlightDescriptor>,
+    ) -> Result<Response<SchemaResult>, Status> {
+        self.intercept_descriptor(&mut request)?;
+        self.inner.get_schema(request).await
+    }
+
+    async fn do_get(
+        &self,
+        mut request: Request<Ticket>,
+    ) -> Result<Response<Self::DoGetStream>, Status> {
+        self.intercept_request(&mut request)?;
+        let response = self.inner.do_get(request).await?;
+        Ok(boxed(response.map(|s| self.interceptors.send(s))))
+    }
+
+    async fn do_put(
+        &self,
+        mut request: Request<Streaming<FlightData>>,
+    ) -> Result<Response<Self::DoPutStream>, Status> {
+        self.intercept_request(&mut request)?;
+        let request = request.map(|s| self.interceptors.receive_request(s));
+        Ok(boxed(self.inner.do_put(request).await?))
+    }
+
+    async fn do_exchange(
+        &self,
+        mut request: Request<Streaming<FlightData>>,
+    ) -> Result<Response<Self::DoExchangeStream>, Status> {
+        self.intercept_request(&mut request)?;
+        let request = request.map(|s| self.interceptors.receive_request(s));
+        let response = self.inner.do_exchange(request).await?;
+        Ok(boxed(response.map(|s| self.interceptors.send(s))))
+    }
+
+    async fn do_action(
+        &self,
+        mut request: Request<Action>,
+    ) -> Result<Response<Self::DoActionStream>, Status> {
+        self.intercept_request(&mut request)?;
+        Ok(boxed(self.inner.do_action(request).await?))
+    }
+
+    async fn list_actions(
+        &self,
+        mut request: Request<Empty>,
+    ) -> Result<Response<Self::ListActionsStream>, Status> {
+        self.intercept_request(&mut request)?;
+        Ok(boxed(self.inner.list_actions(request).await?))
+    }
+}
@@ -29,13 +29,59 @@
 //!    for Flight gRPC protobuf messages, such as [`FlightData`], [`FlightInfo`],
 //!    [`Location`] and [`Ticket`].
 //!
-//! 2. Low level [tonic] generated [`flight_service_client`] and
-//!    [`flight_service_server`].
+//! 2. Low level [tonic] generated `flight_service_client` and
+//!    `flight_service_server`, and the mid level `FlightClient`. Requires the
+//!    `grpc` feature of this crate, which is enabled by default.
 //!
 //! 3. Support for [Flight SQL] in [`sql`]. Requires the
 //!    `flight-sql` feature of this crate to be activated.
 //!
 //! [Flight SQL]: https://arrow.apache.org/docs/format/FlightSql.html
+//!
+//! # Custom transports
+//!
+//! Disabling the default `grpc` feature removes the dependency on [tonic],
+//! leaving the Flight messages, the [`encode`] and [`decode`] modules, and
+//! the [`utils`], which can be used to exchange Flight data over any
+//! transport. As the messages implement [`prost::Message`] they can be framed
+//! with a length prefix, for example:
+//!
+//! ```
+//! # use std::sync::Arc;
+//! # use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+//! # use arrow_flight::FlightData;
+//! # use arrow_flight::decode::FlightRecordBatchStream;
+//! # use arrow_flight::encode::FlightDataEncoderBuilder;
+//! # use futures::TryStreamExt;
+//! # use prost::Message;
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() {
+//! # let batch = RecordBatch::try_from_iter([("a", Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef)]).unwrap();
+//! // encode the batches and write the messages to a buffer
+//! let input = futures::stream::iter([Ok(batch.clone())]);
+//! let messages: Vec<FlightData> = FlightDataEncoderBuilder::new()
+//!     .build(input)
+//!     .try_collect()
+//!     .await
+//!     .unwrap();
+//! let mut buffer = vec![];
+//! for message in &messages {
+//!     message.encode_length_delimited(&mut buffer).unwrap();
+//! }
+//!
+//! // read the messages back and decode the batches
+//! let mut remaining = buffer.as_slice();
+//! let mut received = vec![];
+//! while !remaining.is_empty() {
+//!     received.push(Ok(FlightData::decode_length_delimited(&mut remaining).unwrap()));
+//! }
+//! let batches: Vec<_> = FlightRecordBatchStream::new_from_flight_data(futures::stream::iter(received))
+//!     .try_collect()
+//!     .await
+//!     .unwrap();
+//! assert_eq!(batches, vec![batch]);
+//! # }
+//! ```
 
 #![doc(
     html_logo_url = "https://arrow.apache.org/img/arrow-logo_chevrons_black-txt_white-bg.svg",
@@ -72,7 +118,26 @@ pub mod flight_descriptor {
     pub use r#gen::flight_descriptor::DescriptorType;
 }
 
+/// Nested types of [`SessionOptionValue`].
+pub mod session_option_value {
+    use super::r#gen;
+    pub use r#gen::session_option_value::{OptionValue, StringListValue};
+}
+
+/// Nested types of [`SetSessionOptionsResult`].
+pub mod set_session_options_result {
+    use super::r#gen;
+    pub use r#gen::set_session_options_result::{Error, ErrorValue};
+}
+
+/// Nested types of [`CloseSessionResult`].
+pub mod close_session_result {
+    use super::r#gen;
+    pub use r#gen::close_session_result::Status;
+}
+
 /// Low Level [tonic] [`FlightServiceClient`](gen::flight_service_client::FlightServiceClient).
+#[cfg(feature = "grpc")]
 pub mod flight_service_client {
     use super::r#gen;
     pub use r#gen::flight_service_client::FlightServiceClient;
@@ -80,6 +145,7 @@ pub mod flight_service_client {
 
 /// Low Level [tonic] [`FlightServiceServer`](gen::flight_service_server::FlightServiceServer)
 /// and [`FlightService`](gen::flight_service_server::FlightService).
+#[cfg(feature = "grpc")]
 pub mod flight_service_server {
     use super::r#gen;
     pub use r#gen::flight_service_server::FlightService;
@@ -87,9 +153,13 @@ pub mod flight_service_server {
 }
 
 /// Mid Level [`FlightClient`]
+#[cfg(feature = "grpc")]
 pub mod client;
+#[cfg(feature = "grpc")]
 pub use client::FlightClient;
 
+pub mod compression;
+
 /// Decoder to create [`RecordBatch`](arrow_array::RecordBatch) streams from [`FlightData`] streams.
 /// See [`FlightRecordBatchStream`](decode::FlightRecordBatchStream).
 pub mod decode;
@@ -101,18 +171,31 @@ pub mod encode;
 /// Common error types
 pub mod error;
 
+#[cfg(feature = "grpc")]
+pub mod exchange;
+
+#[cfg(feature = "grpc")]
+pub mod interceptor;
+
+#[cfg(feature = "grpc")]
+pub mod put;
+
 pub use r#gen::Action;
 pub use r#gen::ActionType;
 pub use r#gen::BasicAuth;
 pub use r#gen::CancelFlightInfoRequest;
 pub use r#gen::CancelFlightInfoResult;
 pub use r#gen::CancelStatus;
+pub use r#gen::CloseSessionRequest;
+pub use r#gen::CloseSessionResult;
 pub use r#gen::Criteria;
 pub use r#gen::Empty;
 pub use r#gen::FlightData;
 pub use r#gen::FlightDescriptor;
 pub use r#gen::FlightEndpoint;
 pub use r#gen::FlightInfo;
+pub use r#gen::GetSessionOptionsRequest;
+pub use r#gen::GetSessionOptionsResult;
 pub use r#gen::HandshakeRequest;
 pub use r#gen::HandshakeResponse;
 pub use r#gen::Location;
@@ -121,15 +204,23 @@ pub use r#gen::PutResult;
 pub use r#gen::RenewFlightEndpointRequest;
 pub use r#gen::Result;
 pub use r#gen::SchemaResult;
+pub use r#gen::SessionOptionValue;
+pub use r#gen::SetSessionOptionsRequest;
+pub use r#gen::SetSessionOptionsResult;
 pub use r#gen::Ticket;
 
+#[cfg(feature = "object_store")]
+pub mod store;
+
 /// Helper to extract HTTP/gRPC trailers from a tonic stream.
+#[cfg(feature = "grpc")]
 mod trailers;
 
 pub mod utils;
 
 #[cfg(feature = "flight-sql")]
 pub mod sql;
+#[cfg(any(feature = "grpc", feature = "flight-sql"))]
 mod streams;
 
 use flight_descriptor::DescriptorType;
@@ -736,6 +827,120 @@ impl RenewFlightEndpointRequest {
     }
 }
 
+impl SessionOptionValue {
+    /// Create a valueless [`SessionOptionValue`], which requests the server
+    /// to unset the option when used in a [`SetSessionOptionsRequest`].
+    pub fn unset() -> Self {
+        Self { option_value: None }
+    }
+}
+
+impl From<session_option_value::OptionValue> for SessionOptionValue {
+    fn from(value: session_option_value::OptionValue) -> Self {
+        Self {
+            option_value: Some(value),
+        }
+    }
+}
+
+impl From<String> for SessionOptionValue {
+    fn from(value: String) -> Self {
+        session_option_value::OptionValue::StringValue(value).into()
+    }
+}
+
+impl From<&str> for SessionOptionValue {
+    fn from(value: &str) -> Self {
+        value.to_string().into()
+    }
+}
+
+impl From<bool> for SessionOptionValue {
+    fn from(value: bool) -> Self {
+        session_option_value::OptionValue::BoolValue(value).into()
+    }
+}
+
+impl From<i64> for SessionOptionValue {
+    fn from(value: i64) -> Self {
+        session_option_value::OptionValue::Int64Value(value).into()
+    }
+}
+
+impl From<f64> for SessionOptionValue {
+    fn from(value: f64) -> Self {
+        session_option_value::OptionValue::DoubleValue(value).into()
+    }
+}
+
+impl From<Vec<String>> for SessionOptionValue {
+    fn from(values: Vec<String>) -> Self {
+        session_option_value::OptionValue::StringListValue(session_option_value::StringListValue {
+            values,
+        })
+        .into()
+    }
+}
+
+impl SetSessionOptionsRequest {
+    /// Create a new [`SetSessionOptionsRequest`] from pairs of option names and values
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use arrow_flight::{SessionOptionValue, SetSessionOptionsRequest};
+    /// let request = SetSessionOptionsRequest::new([
+    ///     ("catalog", SessionOptionValue::from("main")),
+    ///     ("read_only", SessionOptionValue::from(true)),
+    /// ]);
+    /// ```
+    pub fn new<K: Into<String>>(
+        options: impl IntoIterator<Item = (K, SessionOptionValue)>,
+    ) -> Self {
+        Self {
+            session_options: options.into_iter().map(|(k, v)| (k.into(), v)).collect(),
+        }
+    }
+}
+
+impl SetSessionOptionsResult {
+    /// Create a new [`SetSessionOptionsResult`] from pairs of the names of the
+    /// options that could not be set and the reason
+    pub fn new<K: Into<String>>(
+        errors: impl IntoIterator<Item = (K, set_session_options_result::ErrorValue)>,
+    ) -> Self {
+        Self {
+            errors: errors
+                .into_iter()
+                .map(|(k, v)| {
+                    let error = set_session_options_result::Error { value: v as i32 };
+                    (k.into(), error)
+                })
+                .collect(),
+        }
+    }
+}
+
+impl GetSessionOptionsResult {
+    /// Create a new [`GetSessionOptionsResult`] from pairs of option names and values
+    pub fn new<K: Into<String>>(
+        options: impl IntoIterator<Item = (K, SessionOptionValue)>,
+    ) -> Self {
+        Self {
+            session_options: options.into_iter().map(|(k, v)| (k.into(), v)).collect(),
+        }
+    }
+}
+
+impl CloseSessionResult {
+    /// Create a new [`CloseSessionResult`] from the provided [`close_session_result::Status`].
+    pub fn new(status: close_session_result::Status) -> Self {
+        Self {
+            status: status as i32,
+        }
+    }
+}
+
 impl Action {
     /// Create a new Action with type and body
     pub fn new(action_type: impl Into<String>, body: impl Into<Bytes>) -> Self {
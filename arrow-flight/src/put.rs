@@ -0,0 +1,320 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Helpers for the server side of `DoPut` calls
+//!
+//! A `DoPut` call streams [`RecordBatch`]es from the client to the server, which
+//! responds with a stream of [`PutResult`]s. [`FlightDataSink`] decodes the
+//! request and calls an application provided function for each batch, sending a
+//! [`PutResult`] acknowledging each batch once it has been processed.
+//!
+//! Acknowledgements are sent in the order the batches were received, and carry
+//! the `app_metadata` returned by the application. Clients implementing
+//! exactly-once ingestion can, for example, send a sequence number as the
+//! `app_metadata` of each batch, which the server echoes back once the batch is
+//! durably stored, allowing the client to resend only unacknowledged batches.
+
+use std::future::Future;
+
+use arrow_array::RecordBatch;
+use bytes::Bytes;
+use futures::stream::BoxStream;
+use futures::{Stream, StreamExt, TryStreamExt};
+use tonic::Status;
+
+use crate::decode::{DecodedPayload, FlightDataDecoder};
+use crate::error::{FlightError, Result};
+use crate::{FlightData, FlightDescriptor, PutResult};
+
+/// A [`RecordBatch`] received by a `DoPut` call
+#[derive(Debug, Clone, PartialEq)]
+pub struct PutBatch {
+    /// The position of the batch in the stream, starting from `0`
+    pub sequence: u64,
+    /// The decoded batch
+    pub batch: RecordBatch,
+    /// The `app_metadata` of the [`FlightData`] message containing the batch
+    pub app_metadata: Bytes,
+}
+
+/// The server side of a `DoPut` call
+///
+/// # Example
+/// ```no_run
+/// # use arrow_flight::{FlightData, PutResult};
+/// # use arrow_flight::put::FlightDataSink;
+/// # use futures::stream::BoxStream;
+/// # use tonic::{Request, Response, Status, Streaming};
+/// # type DoPutStream = BoxStream<'static, Result<PutResult, Status>>;
+/// # async fn store(batch: arrow_array::RecordBatch) -> arrow_flight::error::Result<()> { Ok(()) }
+/// async fn do_put(
+///     request: Request<Streaming<FlightData>>,
+/// ) -> Result<Response<DoPutStream>, Status> {
+///     let sink = FlightDataSink::accept(request.into_inner()).await?;
+///
+///     // store each batch, acknowledging it with the sequence number sent by the client
+///     let response = sink.with_max_in_flight(4).process(|put| async move {
+///         store(put.batch).await?;
+///         Ok(put.app_metadata)
+///     });
+///     Ok(Response::new(response))
+/// }
+/// ```
+pub struct FlightDataSink {
+    descriptor: Option<FlightDescriptor>,
+    input: BoxStream<'static, Result<PutBatch>>,
+    max_in_flight: usize,
+}
+
+impl std::fmt::Debug for FlightDataSink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FlightDataSink")
+            .field("descriptor", &self.descriptor)
+            .field("max_in_flight", &self.max_in_flight)
+            .finish_non_exhaustive()
+    }
+}
+
+impl FlightDataSink {
+    /// Accept a `DoPut` `request`
+    ///
+    /// Waits for the first message from the client to read its
+    /// [`FlightDescriptor`]. Messages that carry no [`RecordBatch`], such as the
+    /// schema or a first message with only a descriptor, are not passed to the
+    /// application.
+    pub async fn accept<S>(request: S) -> std::result::Result<Self, Status>
+    where
+        S: Stream<Item = std::result::Result<FlightData, Status>> + Send + 'static,
+    {
+        let mut request = request.boxed();
+        let (descriptor, first) = match request.next().await.transpose()? {
+            Some(data) if data.data_header.is_empty() => (data.flight_descriptor, None),
+            Some(data) => (data.flight_descriptor.clone(), Some(data)),
+            None => (None, None),
+        };
+        let request = futures::stream::iter(first.map(Ok)).chain(request);
+
+        let input = FlightDataDecoder::new(request.map_err(FlightError::from))
+            .try_filter_map(|data| async move {
+                Ok(match data.payload {
+                    DecodedPayload::RecordBatch(batch) => Some((batch, data.inner.app_metadata)),
+                    DecodedPayload::None | DecodedPayload::Schema(_) => None,
+                })
+            })
+            .enumerate()
+            .map(|(sequence, result)| {
+                result.map(|(batch, app_metadata)| PutBatch {
+                    sequence: sequence as u64,
+                    batch,
+                    app_metadata,
+                })
+            })
+            .boxed();
+
+        Ok(Self {
+            descriptor,
+            input,
+            max_in_flight: 1,
+        })
+    }
+
+    /// The descriptor of the first message received from the client, if any
+    pub fn descriptor(&self) -> Option<&FlightDescriptor> {
+        self.descriptor.as_ref()
+    }
+
+    /// Sets the maximum number of batches processed concurrently by [`Self::process`]
+    ///
+    /// Acknowledgements are sent in order regardless. Defaults to `1`
+    pub fn with_max_in_flight(mut self, max_in_flight: usize) -> Self {
+        self.max_in_flight = max_in_flight.max(1);
+        self
+    }
+
+    /// Process each batch with `f`, returning the `DoPut` response
+    ///
+    /// A [`PutResult`] with the `app_metadata` returned by `f` is sent once `f`
+    /// completes for a batch. If decoding a batch or `f` fails, the error is
+    /// sent after the acknowledgements of the preceding batches and the
+    /// response ends, leaving any later batches unacknowledged.
+    pub fn process<F, Fut>(
+        self,
+        mut f: F,
+    ) -> BoxStream<'static, std::result::Result<PutResult, Status>>
+    where
+        F: FnMut(PutBatch) -> Fut + Send + 'static,
+        Fut: Future<Output = Result<Bytes>> + Send + 'static,
+    {
+        let mut failed = false;
+        self.input
+            .map(move |put| {
+                let processed = put.map(&mut f);
+                async move { processed?.await }
+            })
+            .buffered(self.max_in_flight)
+            .take_while(move |result| {
+                let done = failed;
+                failed |= result.is_err();
+                futures::future::ready(!done)
+            })
+            .map(|result| match result {
+                Ok(app_metadata) => Ok(PutResult { app_metadata }),
+                Err(e) => Err(e.into()),
+            })
+            .boxed()
+    }
+
+    /// Returns the received batches, for applications that send acknowledgements
+    /// themselves
+    pub fn into_stream(self) -> BoxStream<'static, Result<PutBatch>> {
+        self.input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encode::FlightDataEncoderBuilder;
+    use arrow_array::{ArrayRef, Int32Array};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    fn batch(values: &[i32]) -> RecordBatch {
+        let array = Arc::new(Int32Array::from(values.to_vec())) as ArrayRef;
+        RecordBatch::try_from_iter([("a", array)]).unwrap()
+    }
+
+    /// Encodes `batches`, setting the `app_metadata` of each to its position
+    async fn request(
+        descriptor: FlightDescriptor,
+        batches: Vec<RecordBatch>,
+    ) -> Vec<std::result::Result<FlightData, Status>> {
+        let encoded: Vec<_> = FlightDataEncoderBuilder::new()
+            .with_flight_descriptor(Some(descriptor))
+            .build(futures::stream::iter(batches.into_iter().map(Ok)))
+            .try_collect()
+            .await
+            .unwrap();
+        let mut sequence = 0;
+        encoded
+            .into_iter()
+            .map(|data| {
+                if data.data_body.is_empty() {
+                    return Ok(data);
+                }
+                sequence += 1;
+                Ok(data.with_app_metadata(format!("seq-{sequence}")))
+            })
+            .collect()
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_put_acknowledgements() {
+        let descriptor = FlightDescriptor::new_path(vec!["table".to_string()]);
+        let batches = vec![batch(&[1, 2]), batch(&[3]), batch(&[4, 5, 6])];
+        let request = request(descriptor.clone(), batches.clone()).await;
+
+        let sink = FlightDataSink::accept(futures::stream::iter(request))
+            .await
+            .unwrap();
+        assert_eq!(sink.descriptor(), Some(&descriptor));
+
+        // Later batches complete first, but are acknowledged in order
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let response: Vec<_> = sink
+            .with_max_in_flight(3)
+            .process(move |put| {
+                let sender = sender.clone();
+                async move {
+                    let delay = 100 - 50 * put.sequence;
+                    tokio::time::sleep(Duration::from_millis(delay)).await;
+                    sender.send(put.batch).unwrap();
+                    Ok(put.app_metadata)
+                }
+            })
+            .try_collect()
+            .await
+            .unwrap();
+
+        let acks: Vec<_> = response.iter().map(|r| r.app_metadata.clone()).collect();
+        assert_eq!(acks, ["seq-1", "seq-2", "seq-3"]);
+        let processed: Vec<_> = receiver.try_iter().collect();
+        let expected: Vec<_> = batches.into_iter().rev().collect();
+        assert_eq!(processed, expected);
+    }
+
+    #[tokio::test]
+    async fn test_put_error() {
+        let descriptor = FlightDescriptor::new_cmd("cmd");
+        let batches = vec![batch(&[1]), batch(&[2]), batch(&[3])];
+        let request = request(descriptor, batches).await;
+
+        let sink = FlightDataSink::accept(futures::stream::iter(request))
+            .await
+            .unwrap();
+        let response: Vec<_> = sink
+            .process(|put| async move {
+                match put.sequence {
+                    1 => Err(FlightError::protocol("rejected")),
+                    _ => Ok(Bytes::from(put.sequence.to_string())),
+                }
+            })
+            .collect()
+            .await;
+
+        assert_eq!(response.len(), 2);
+        assert_eq!(response[0].as_ref().unwrap().app_metadata, "0");
+        let err = response[1].as_ref().unwrap_err();
+        assert_eq!(err.message(), "rejected");
+    }
+
+    #[tokio::test]
+    async fn test_put_descriptor_only() {
+        let descriptor = FlightDescriptor::new_cmd("cmd");
+        let mut request = request(descriptor.clone(), vec![batch(&[1])]).await;
+        request.insert(0, Ok(FlightData::new().with_descriptor(descriptor.clone())));
+
+        let sink = FlightDataSink::accept(futures::stream::iter(request))
+            .await
+            .unwrap();
+        assert_eq!(sink.descriptor(), Some(&descriptor));
+        let received: Vec<_> = sink.into_stream().try_collect().await.unwrap();
+        assert_eq!(received.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_put_into_stream() {
+        let descriptor = FlightDescriptor::new_cmd("cmd");
+        let batches = vec![batch(&[1]), batch(&[2, 3])];
+        let request = request(descriptor, batches.clone()).await;
+
+        let sink = FlightDataSink::accept(futures::stream::iter(request))
+            .await
+            .unwrap();
+        let received: Vec<_> = sink.into_stream().try_collect().await.unwrap();
+        let expected: Vec<_> = batches
+            .into_iter()
+            .enumerate()
+            .map(|(i, batch)| PutBatch {
+                sequence: i as u64,
+                batch,
+                app_metadata: format!("seq-{}", i + 1).into(),
+            })
+            .collect();
+        assert_eq!(received, expected);
+    }
+}
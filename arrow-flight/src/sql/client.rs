@@ -28,6 +28,7 @@ use base64::prelude::BASE64_STANDARD;
 use bytes::Bytes;
 use std::collections::HashMap;
 use std::str::FromStr;
+use std::sync::Arc;
 use tonic::metadata::AsciiMetadataKey;
 
 use crate::decode::FlightRecordBatchStream;
@@ -37,7 +38,9 @@ use crate::error::Result;
 use crate::flight_service_client::FlightServiceClient;
 use crate::sql::r#gen::action_end_transaction_request::EndTransaction;
 use crate::sql::server::{
-    BEGIN_TRANSACTION, CLOSE_PREPARED_STATEMENT, CREATE_PREPARED_STATEMENT, END_TRANSACTION,
+    BEGIN_TRANSACTION, CANCEL_FLIGHT_INFO, CLOSE_PREPARED_STATEMENT, CLOSE_SESSION,
+    CREATE_PREPARED_STATEMENT, END_TRANSACTION, GET_SESSION_OPTIONS, RENEW_FLIGHT_ENDPOINT,
+    SET_SESSION_OPTIONS,
 };
 use crate::sql::{
     ActionBeginTransactionRequest, ActionBeginTransactionResult,
@@ -52,11 +55,14 @@ use crate::sql::{
 use crate::streams::FallibleRequestStream;
 use crate::trailers::extract_lazy_trailers;
 use crate::{
-    Action, FlightData, FlightDescriptor, FlightInfo, HandshakeRequest, HandshakeResponse,
-    IpcMessage, PutResult, Ticket,
+    Action, CancelFlightInfoRequest, CancelFlightInfoResult, CloseSessionRequest,
+    CloseSessionResult, FlightData, FlightDescriptor, FlightEndpoint, FlightInfo,
+    GetSessionOptionsRequest, GetSessionOptionsResult, HandshakeRequest, HandshakeResponse,
+    IpcMessage, PutResult, RenewFlightEndpointRequest, SetSessionOptionsRequest,
+    SetSessionOptionsResult, Ticket,
 };
-use arrow_array::RecordBatch;
-use arrow_schema::{ArrowError, Schema};
+use arrow_array::{Array, ArrayRef, Datum, RecordBatch};
+use arrow_schema::{ArrowError, Field, Schema};
 use futures::{Stream, TryStreamExt, stream};
 use prost::Message;
 use tonic::codegen::{Body, StdError};
@@ -228,18 +234,68 @@ where
     }
 
     /// Execute a bulk ingest on the server and return the number of records added
+    ///
+    /// The batches are encoded with a default [`FlightDataEncoderBuilder`], see
+    /// [`Self::execute_ingest_with_encoder`] to control how they are sent.
     pub async fn execute_ingest<S>(
         &mut self,
         command: CommandStatementIngest,
         stream: S,
     ) -> Result<i64>
+    where
+        S: Stream<Item = crate::error::Result<RecordBatch>> + Send + 'static,
+    {
+        self.execute_ingest_with_encoder(command, FlightDataEncoderBuilder::new(), stream)
+            .await
+    }
+
+    /// Execute a bulk ingest on the server using the provided `encoder` and
+    /// return the number of records added
+    ///
+    /// The encoder controls how the batches are sent: for example
+    /// [`FlightDataEncoderBuilder::with_max_flight_data_size`] bounds the size
+    /// of each message, splitting larger batches into several chunks, and
+    /// [`FlightDataEncoderBuilder::with_schema`] sends the schema up front so
+    /// that the server learns the table schema even if `stream` is empty. Any
+    /// flight descriptor set on `encoder` is replaced by one for `command`.
+    ///
+    /// All batches in `stream` must have the same schema as the first one.
+    ///
+    /// The record counts of all [`DoPutUpdateResult`]s returned by the server
+    /// are summed.
+    pub async fn execute_ingest_with_encoder<S>(
+        &mut self,
+        command: CommandStatementIngest,
+        encoder: FlightDataEncoderBuilder,
+        stream: S,
+    ) -> Result<i64>
     where
         S: Stream<Item = crate::error::Result<RecordBatch>> + Send + 'static,
     {
         let (sender, receiver) = futures::channel::oneshot::channel();
 
+        // Reject batches whose schema differs from that of the first batch,
+        // as the server can only ingest a single schema per request
+        let mut expected_schema: Option<SchemaRef> = None;
+        let stream = stream.and_then(move |batch| {
+            let result = match &expected_schema {
+                Some(schema) if schema != batch.schema_ref() => {
+                    Err(FlightError::Arrow(ArrowError::SchemaError(format!(
+                        "Ingested batches must all have the same schema, expected {schema:?} got {:?}",
+                        batch.schema()
+                    ))))
+                }
+                Some(_) => Ok(batch),
+                None => {
+                    expected_schema = Some(batch.schema());
+                    Ok(batch)
+                }
+            };
+            futures::future::ready(result)
+        });
+
         let descriptor = FlightDescriptor::new_cmd(command.as_any().encode_to_vec());
-        let flight_data = FlightDataEncoderBuilder::new()
+        let flight_data = encoder
             .with_flight_descriptor(Some(descriptor))
             .build(stream);
 
@@ -258,9 +314,12 @@ where
             return Err(FlightError::ExternalError(Box::new(msg)));
         }
 
-        let result = result.message().await?.unwrap();
-        let result: DoPutUpdateResult = Message::decode(&*result.app_metadata)?;
-        Ok(result.record_count)
+        let mut record_count = None;
+        while let Some(result) = result.message().await? {
+            let result: DoPutUpdateResult = Message::decode(&*result.app_metadata)?;
+            *record_count.get_or_insert(0) += result.record_count;
+        }
+        record_count.ok_or_else(|| FlightError::protocol("No DoPutUpdateResult received"))
     }
 
     /// Request a list of catalogs as tabular FlightInfo results
@@ -440,6 +499,63 @@ where
         Ok(())
     }
 
+    /// Request to cancel the execution of the query described by `request`.
+    pub async fn cancel_flight_info(
+        &mut self,
+        request: CancelFlightInfoRequest,
+    ) -> Result<CancelFlightInfoResult> {
+        self.do_flight_action(CANCEL_FLIGHT_INFO, request).await
+    }
+
+    /// Request to extend the expiration time of a [`FlightEndpoint`].
+    pub async fn renew_flight_endpoint(
+        &mut self,
+        request: RenewFlightEndpointRequest,
+    ) -> Result<FlightEndpoint> {
+        self.do_flight_action(RENEW_FLIGHT_ENDPOINT, request).await
+    }
+
+    /// Request to set options of the client session.
+    ///
+    /// The returned [`SetSessionOptionsResult`] lists the options that could not be set.
+    pub async fn set_session_options(
+        &mut self,
+        request: SetSessionOptionsRequest,
+    ) -> Result<SetSessionOptionsResult> {
+        self.do_flight_action(SET_SESSION_OPTIONS, request).await
+    }
+
+    /// Request the current options of the client session.
+    pub async fn get_session_options(&mut self) -> Result<GetSessionOptionsResult> {
+        self.do_flight_action(GET_SESSION_OPTIONS, GetSessionOptionsRequest {})
+            .await
+    }
+
+    /// Request to close the client session.
+    pub async fn close_session(&mut self) -> Result<CloseSessionResult> {
+        self.do_flight_action(CLOSE_SESSION, CloseSessionRequest {})
+            .await
+    }
+
+    /// Make a `DoAction` call defined by Flight, rather than FlightSQL, whose
+    /// messages are encoded directly instead of being wrapped in an [`Any`].
+    async fn do_flight_action<M: Message, R: Message + Default>(
+        &mut self,
+        action_type: &str,
+        request: M,
+    ) -> Result<R> {
+        let action = Action {
+            r#type: action_type.to_string(),
+            body: request.encode_to_vec().into(),
+        };
+        let req = self.set_request_headers(action.into_request())?;
+        let mut result = self.flight_client.do_action(req).await?.into_inner();
+        let result = result.message().await?.ok_or_else(|| {
+            FlightError::protocol(format!("Received no response for {action_type} call"))
+        })?;
+        R::decode(&*result.body).map_err(|e| FlightError::DecodeError(e.to_string()))
+    }
+
     /// Explicitly shut down and clean up the client.
     pub async fn close(&mut self) -> Result<()> {
         // TODO: consume self instead of &mut self to explicitly prevent reuse?
@@ -559,6 +675,82 @@ where
         Ok(())
     }
 
+    /// Bind a single set of positional `parameters`, sent to the server on the next
+    /// [`Self::execute`] or [`Self::execute_update`]
+    ///
+    /// Each parameter must be a scalar, such as those created by
+    /// [`PrimitiveArray::new_scalar`](arrow_array::PrimitiveArray::new_scalar). If the
+    /// server reported a [`Self::parameter_schema`], there must be one parameter for each
+    /// of its fields, and the parameters are cast to the types of the fields. Otherwise
+    /// the parameters are named `$1`, `$2`, ...
+    ///
+    /// Binding no parameters clears any previously bound parameters.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # async fn run(mut statement: arrow_flight::sql::client::PreparedStatement<tonic::transport::Channel>) {
+    /// use arrow_array::{Int64Array, StringArray};
+    /// // SELECT * FROM t WHERE id = ? AND name = ?
+    /// statement
+    ///     .bind(&[&Int64Array::new_scalar(1), &StringArray::new_scalar("foo")])
+    ///     .unwrap();
+    /// let flight_info = statement.execute().await.unwrap();
+    /// # }
+    /// ```
+    pub fn bind(&mut self, parameters: &[&dyn Datum]) -> Result<()> {
+        let columns = parameters
+            .iter()
+            .map(|p| scalar_to_array(*p))
+            .collect::<Result<Vec<_>>>()?;
+        self.parameter_binding = bind_columns(&self.parameter_schema, columns)?;
+        Ok(())
+    }
+
+    /// Bind a single set of `parameters` by the names of the fields of the
+    /// [`Self::parameter_schema`], see [`Self::bind`]
+    ///
+    /// Returns an error if a parameter of the schema has no value, or if a name is
+    /// not a parameter of the schema.
+    pub fn bind_named(&mut self, parameters: &[(&str, &dyn Datum)]) -> Result<()> {
+        if let Some((name, _)) = parameters
+            .iter()
+            .find(|(name, _)| self.parameter_schema.field_with_name(name).is_err())
+        {
+            return Err(invalid_parameters(format!("Unknown parameter {name}")));
+        }
+        let columns = self
+            .parameter_schema
+            .fields()
+            .iter()
+            .map(|field| {
+                let (_, value) = parameters
+                    .iter()
+                    .find(|(name, _)| name == field.name())
+                    .ok_or_else(|| {
+                        invalid_parameters(format!("Missing value for parameter {}", field.name()))
+                    })?;
+                scalar_to_array(*value)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        self.parameter_binding = bind_columns(&self.parameter_schema, columns)?;
+        Ok(())
+    }
+
+    /// Bind the sets of parameters in the rows of `batch`, see [`Self::bind`]
+    ///
+    /// Unlike [`Self::set_parameters`] the columns of `batch` are validated
+    /// against, and cast to, the [`Self::parameter_schema`]. If the server did not
+    /// report a parameter schema, `batch` is bound as is, keeping the names of its
+    /// columns.
+    pub fn bind_batch(&mut self, batch: RecordBatch) -> Result<()> {
+        if self.parameter_schema.fields().is_empty() {
+            self.parameter_binding = Some(batch);
+            return Ok(());
+        }
+        self.parameter_binding = bind_columns(&self.parameter_schema, batch.columns().to_vec())?;
+        Ok(())
+    }
+
     /// Submit parameters to the server, if any have been set on this prepared statement instance
     /// Updates our stored prepared statement handle with the handle given by the server response.
     async fn write_bind_params(&mut self) -> Result<()> {
@@ -619,6 +811,64 @@ where
     }
 }
 
+fn invalid_parameters(message: String) -> FlightError {
+    ArrowError::InvalidArgumentError(message).into()
+}
+
+/// Returns the single value of the scalar `datum` as an array
+fn scalar_to_array(datum: &dyn Datum) -> Result<ArrayRef> {
+    let (array, is_scalar) = datum.get();
+    if !is_scalar && array.len() != 1 {
+        return Err(invalid_parameters(format!(
+            "Expected a scalar parameter, got an array of length {}",
+            array.len()
+        )));
+    }
+    Ok(array.slice(0, 1))
+}
+
+/// Create a batch of the parameter `columns`, cast to the `parameter_schema`
+/// reported by the server if any, or `None` if there are no parameters
+fn bind_columns(parameter_schema: &Schema, columns: Vec<ArrayRef>) -> Result<Option<RecordBatch>> {
+    if parameter_schema.fields().is_empty() && columns.is_empty() {
+        return Ok(None);
+    }
+    if parameter_schema.fields().is_empty() {
+        let fields: Vec<_> = columns
+            .iter()
+            .enumerate()
+            .map(|(i, c)| Field::new(format!("${}", i + 1), c.data_type().clone(), true))
+            .collect();
+        return Ok(Some(RecordBatch::try_new(
+            Arc::new(Schema::new(fields)),
+            columns,
+        )?));
+    }
+
+    let fields = parameter_schema.fields();
+    if columns.len() != fields.len() {
+        return Err(invalid_parameters(format!(
+            "Expected {} parameters, got {}",
+            fields.len(),
+            columns.len()
+        )));
+    }
+    let columns = columns
+        .iter()
+        .zip(fields.iter())
+        .map(
+            |(column, field)| match column.data_type() == field.data_type() {
+                true => Ok(Arc::clone(column)),
+                false => arrow_cast::cast(column, field.data_type()),
+            },
+        )
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    Ok(Some(RecordBatch::try_new(
+        Arc::new(parameter_schema.clone()),
+        columns,
+    )?))
+}
+
 /// A polymorphic structure to natively represent different types of data contained in `FlightData`
 pub enum ArrowFlightData {
     /// A record batch
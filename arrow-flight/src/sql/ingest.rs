@@ -0,0 +1,212 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Helpers for [`CommandStatementIngest`] bulk ingestion requests.
+
+use bytes::Bytes;
+
+use crate::error::{FlightError, Result};
+use crate::sql::{
+    CommandStatementIngest, DoPutUpdateResult, TableDefinitionOptions, TableExistsOption,
+    TableNotExistOption,
+};
+
+/// How to load the data of a [`CommandStatementIngest`] into its target table,
+/// see [`CommandStatementIngest::ingest_mode`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IngestMode {
+    /// Create the table, which does not exist, and insert the data into it
+    Create,
+    /// Append the data to the existing table
+    Append,
+    /// Drop and recreate the existing table, and insert the data into it
+    Replace,
+}
+
+impl TableDefinitionOptions {
+    /// Create the options to handle a target table that does or does not exist
+    pub fn new(if_not_exist: TableNotExistOption, if_exists: TableExistsOption) -> Self {
+        Self {
+            if_not_exist: if_not_exist as i32,
+            if_exists: if_exists as i32,
+        }
+    }
+}
+
+impl CommandStatementIngest {
+    /// Create a request to load data into `table`, handling the table
+    /// definition as specified by `table_definition_options`
+    pub fn new(table: impl Into<String>, table_definition_options: TableDefinitionOptions) -> Self {
+        Self {
+            table_definition_options: Some(table_definition_options),
+            table: table.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the db_schema of the target table
+    pub fn with_schema(self, schema: impl Into<String>) -> Self {
+        Self {
+            schema: Some(schema.into()),
+            ..self
+        }
+    }
+
+    /// Sets the catalog of the target table
+    pub fn with_catalog(self, catalog: impl Into<String>) -> Self {
+        Self {
+            catalog: Some(catalog.into()),
+            ..self
+        }
+    }
+
+    /// Sets whether to store the data in a temporary table
+    pub fn with_temporary(self, temporary: bool) -> Self {
+        Self { temporary, ..self }
+    }
+
+    /// Sets the transaction to perform the ingestion in
+    pub fn with_transaction_id(self, transaction_id: impl Into<Bytes>) -> Self {
+        Self {
+            transaction_id: Some(transaction_id.into()),
+            ..self
+        }
+    }
+
+    /// Sets the backend-specific option `key` to `value`
+    pub fn with_option(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.options.insert(key.into(), value.into());
+        self
+    }
+
+    /// Returns how to load the data into the target table, given whether it exists
+    ///
+    /// Returns an error if the [`TableDefinitionOptions`] require the ingestion to fail,
+    /// or if the applicable option is unspecified or unknown, which servers must reject.
+    pub fn ingest_mode(&self, table_exists: bool) -> Result<IngestMode> {
+        let options = self.table_definition_options.unwrap_or_default();
+        if table_exists {
+            match TableExistsOption::try_from(options.if_exists) {
+                Ok(TableExistsOption::Append) => Ok(IngestMode::Append),
+                Ok(TableExistsOption::Replace) => Ok(IngestMode::Replace),
+                Ok(TableExistsOption::Fail) => Err(FlightError::protocol(format!(
+                    "table {} already exists",
+                    self.table
+                ))),
+                Ok(TableExistsOption::Unspecified) | Err(_) => Err(FlightError::protocol(format!(
+                    "invalid table exists option: {}",
+                    options.if_exists
+                ))),
+            }
+        } else {
+            match TableNotExistOption::try_from(options.if_not_exist) {
+                Ok(TableNotExistOption::Create) => Ok(IngestMode::Create),
+                Ok(TableNotExistOption::Fail) => Err(FlightError::protocol(format!(
+                    "table {} does not exist",
+                    self.table
+                ))),
+                Ok(TableNotExistOption::Unspecified) | Err(_) => Err(FlightError::protocol(
+                    format!("invalid table not exist option: {}", options.if_not_exist),
+                )),
+            }
+        }
+    }
+}
+
+impl DoPutUpdateResult {
+    /// Create the response to an update or ingestion that affected `record_count` rows
+    pub fn new(record_count: i64) -> Self {
+        Self { record_count }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sql::{Any, Command, ProstMessageExt};
+
+    #[test]
+    fn test_ingest_command() {
+        let options =
+            TableDefinitionOptions::new(TableNotExistOption::Create, TableExistsOption::Append);
+        let command = CommandStatementIngest::new("t", options)
+            .with_schema("s")
+            .with_catalog("c")
+            .with_temporary(true)
+            .with_transaction_id("txn")
+            .with_option("k", "v");
+
+        let decoded = match Command::try_from(command.as_any()).unwrap() {
+            Command::CommandStatementIngest(decoded) => decoded,
+            other => panic!("unexpected command {other:?}"),
+        };
+        assert_eq!(decoded, command);
+        assert_eq!(decoded.table, "t");
+        assert_eq!(decoded.schema.as_deref(), Some("s"));
+        assert_eq!(decoded.catalog.as_deref(), Some("c"));
+        assert!(decoded.temporary);
+        assert_eq!(decoded.transaction_id, Some(Bytes::from_static(b"txn")));
+        assert_eq!(decoded.options.get("k").map(String::as_str), Some("v"));
+    }
+
+    #[test]
+    fn test_ingest_mode() {
+        let mode = |if_not_exist, if_exists, table_exists| {
+            let options = TableDefinitionOptions::new(if_not_exist, if_exists);
+            CommandStatementIngest::new("t", options).ingest_mode(table_exists)
+        };
+
+        use TableExistsOption as E;
+        use TableNotExistOption as N;
+        assert_eq!(mode(N::Create, E::Fail, false).unwrap(), IngestMode::Create);
+        assert_eq!(mode(N::Fail, E::Append, true).unwrap(), IngestMode::Append);
+        assert_eq!(
+            mode(N::Fail, E::Replace, true).unwrap(),
+            IngestMode::Replace
+        );
+
+        let err = mode(N::Fail, E::Append, false).unwrap_err();
+        assert_eq!(err.to_string(), "Protocol error: table t does not exist");
+        let err = mode(N::Create, E::Fail, true).unwrap_err();
+        assert_eq!(err.to_string(), "Protocol error: table t already exists");
+        let err = mode(N::Unspecified, E::Append, false).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Protocol error: invalid table not exist option: 0"
+        );
+        let err = mode(N::Create, E::Unspecified, true).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Protocol error: invalid table exists option: 0"
+        );
+
+        // Servers must reject requests without table definition options
+        let command = CommandStatementIngest {
+            table: "t".to_string(),
+            ..Default::default()
+        };
+        assert!(command.ingest_mode(false).is_err());
+        assert!(command.ingest_mode(true).is_err());
+    }
+
+    #[test]
+    fn test_do_put_update_result() {
+        let result = DoPutUpdateResult::new(42);
+        let decoded: DoPutUpdateResult = Any::pack(&result).unwrap().unpack().unwrap().unwrap();
+        assert_eq!(decoded.record_count, 42);
+    }
+}
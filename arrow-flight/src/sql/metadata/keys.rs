@@ -0,0 +1,746 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`GetPrimaryKeysBuilder`] for building responses to [`CommandGetPrimaryKeys`] queries, and
+//! [`GetForeignKeysBuilder`] for building responses to [`CommandGetExportedKeys`],
+//! [`CommandGetImportedKeys`] and [`CommandGetCrossReference`] queries.
+//!
+//! [`CommandGetPrimaryKeys`]: crate::sql::CommandGetPrimaryKeys
+//! [`CommandGetExportedKeys`]: crate::sql::CommandGetExportedKeys
+//! [`CommandGetImportedKeys`]: crate::sql::CommandGetImportedKeys
+//! [`CommandGetCrossReference`]: crate::sql::CommandGetCrossReference
+
+use std::sync::Arc;
+
+use arrow_arith::boolean::and;
+use arrow_array::builder::{Int32Builder, StringBuilder, UInt8Builder};
+use arrow_array::{ArrayRef, RecordBatch, StringArray};
+use arrow_ord::cmp::eq;
+use arrow_schema::{DataType, Field, Schema, SchemaRef};
+use arrow_select::{filter::filter_record_batch, take::take};
+use once_cell::sync::Lazy;
+
+use super::lexsort_to_indices;
+use crate::error::*;
+use crate::sql::{
+    CommandGetCrossReference, CommandGetExportedKeys, CommandGetImportedKeys,
+    CommandGetPrimaryKeys, UpdateDeleteRules,
+};
+
+/// A builder for a [`CommandGetPrimaryKeys`] response.
+///
+/// Builds rows like this:
+///
+/// * catalog_name: utf8,
+/// * db_schema_name: utf8,
+/// * table_name: utf8 not null,
+/// * column_name: utf8 not null,
+/// * key_name: utf8,
+/// * key_sequence: int32 not null
+pub struct GetPrimaryKeysBuilder {
+    // Specifies the Catalog to search for the table.
+    // - An empty string retrieves those without a catalog.
+    // - If omitted the catalog name is not used to narrow the search.
+    catalog_filter: Option<String>,
+    // Specifies the schema to search for the table.
+    // - An empty string retrieves those without a schema.
+    // - If omitted the schema name is not used to narrow the search.
+    db_schema_filter: Option<String>,
+    // Specifies the table to get the primary keys for
+    table_filter: String,
+    // array builder for catalog names
+    catalog_name: StringBuilder,
+    // array builder for db schema names
+    db_schema_name: StringBuilder,
+    // array builder for table names
+    table_name: StringBuilder,
+    // array builder for column names
+    column_name: StringBuilder,
+    // array builder for key names
+    key_name: StringBuilder,
+    // array builder for the position of the columns in the keys
+    key_sequence: Int32Builder,
+}
+
+impl CommandGetPrimaryKeys {
+    /// Create a builder suitable for constructing a response
+    pub fn into_builder(self) -> GetPrimaryKeysBuilder {
+        self.into()
+    }
+}
+
+impl From<CommandGetPrimaryKeys> for GetPrimaryKeysBuilder {
+    fn from(value: CommandGetPrimaryKeys) -> Self {
+        Self::new(value.catalog, value.db_schema, value.table)
+    }
+}
+
+impl GetPrimaryKeysBuilder {
+    /// Create a new instance of [`GetPrimaryKeysBuilder`]
+    ///
+    /// # Parameters
+    ///
+    /// - `catalog`:  Specifies the Catalog to search for the table.
+    ///   - An empty string retrieves those without a catalog.
+    ///   - If omitted the catalog name is not used to narrow the search.
+    /// - `db_schema`: Specifies the schema to search for the table.
+    ///   - An empty string retrieves those without a schema.
+    ///   - If omitted the schema name is not used to narrow the search.
+    /// - `table`: Specifies the table to get the primary keys for.
+    ///
+    /// [`CommandGetPrimaryKeys`]: crate::sql::CommandGetPrimaryKeys
+    pub fn new(
+        catalog: Option<impl Into<String>>,
+        db_schema: Option<impl Into<String>>,
+        table: impl Into<String>,
+    ) -> Self {
+        Self {
+            catalog_filter: catalog.map(|v| v.into()),
+            db_schema_filter: db_schema.map(|v| v.into()),
+            table_filter: table.into(),
+            catalog_name: StringBuilder::new(),
+            db_schema_name: StringBuilder::new(),
+            table_name: StringBuilder::new(),
+            column_name: StringBuilder::new(),
+            key_name: StringBuilder::new(),
+            key_sequence: Int32Builder::new(),
+        }
+    }
+
+    /// Append a row
+    ///
+    /// In case the catalog or schema should be considered as empty, pass in an empty string '""'.
+    pub fn append(
+        &mut self,
+        catalog_name: impl AsRef<str>,
+        db_schema_name: impl AsRef<str>,
+        table_name: impl AsRef<str>,
+        column_name: impl AsRef<str>,
+        key_name: Option<impl AsRef<str>>,
+        key_sequence: i32,
+    ) {
+        self.catalog_name.append_value(catalog_name);
+        self.db_schema_name.append_value(db_schema_name);
+        self.table_name.append_value(table_name);
+        self.column_name.append_value(column_name);
+        self.key_name.append_option(key_name);
+        self.key_sequence.append_value(key_sequence);
+    }
+
+    /// builds a `RecordBatch` with the correct schema for a `CommandGetPrimaryKeys` response
+    pub fn build(self) -> Result<RecordBatch> {
+        let schema = self.schema();
+        let Self {
+            catalog_filter,
+            db_schema_filter,
+            table_filter,
+            mut catalog_name,
+            mut db_schema_name,
+            mut table_name,
+            mut column_name,
+            mut key_name,
+            mut key_sequence,
+        } = self;
+
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(catalog_name.finish()) as ArrayRef,
+                Arc::new(db_schema_name.finish()) as ArrayRef,
+                Arc::new(table_name.finish()) as ArrayRef,
+                Arc::new(column_name.finish()) as ArrayRef,
+                Arc::new(key_name.finish()) as ArrayRef,
+                Arc::new(key_sequence.finish()) as ArrayRef,
+            ],
+        )?;
+
+        let filters = [
+            (0, catalog_filter),
+            (1, db_schema_filter),
+            (2, Some(table_filter)),
+        ];
+
+        // Order filtered results by catalog_name, db_schema_name, table_name, key_name,
+        // then key_sequence
+        filter_and_sort(batch, filters, &[0, 1, 2, 4, 5])
+    }
+
+    /// Return the schema of the RecordBatch that will be returned
+    /// from [`CommandGetPrimaryKeys`]
+    pub fn schema(&self) -> SchemaRef {
+        get_primary_keys_schema()
+    }
+}
+
+/// A foreign key column appended to a [`GetForeignKeysBuilder`]
+///
+/// In case a catalog or schema should be considered as empty, use an empty string '""'.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForeignKeyColumn {
+    /// The catalog of the table with the referenced primary key
+    pub pk_catalog_name: String,
+    /// The schema of the table with the referenced primary key
+    pub pk_db_schema_name: String,
+    /// The table with the referenced primary key
+    pub pk_table_name: String,
+    /// The referenced primary key column
+    pub pk_column_name: String,
+    /// The catalog of the table with the foreign key
+    pub fk_catalog_name: String,
+    /// The schema of the table with the foreign key
+    pub fk_db_schema_name: String,
+    /// The table with the foreign key
+    pub fk_table_name: String,
+    /// The foreign key column
+    pub fk_column_name: String,
+    /// The position of the column in the key, starting from 1
+    pub key_sequence: i32,
+    /// The name of the foreign key, if any
+    pub fk_key_name: Option<String>,
+    /// The name of the primary key, if any
+    pub pk_key_name: Option<String>,
+    /// What happens to the foreign key when the primary key is updated
+    pub update_rule: UpdateDeleteRules,
+    /// What happens to the foreign key when the primary key is deleted
+    pub delete_rule: UpdateDeleteRules,
+}
+
+/// The side of the key whose columns the results of a [`GetForeignKeysBuilder`]
+/// are ordered by
+#[derive(Debug, Clone, Copy)]
+enum KeyOrder {
+    PrimaryKey,
+    ForeignKey,
+}
+
+/// A builder for a [`CommandGetExportedKeys`], [`CommandGetImportedKeys`] or
+/// [`CommandGetCrossReference`] response.
+///
+/// Builds rows like this:
+///
+/// * pk_catalog_name: utf8,
+/// * pk_db_schema_name: utf8,
+/// * pk_table_name: utf8 not null,
+/// * pk_column_name: utf8 not null,
+/// * fk_catalog_name: utf8,
+/// * fk_db_schema_name: utf8,
+/// * fk_table_name: utf8 not null,
+/// * fk_column_name: utf8 not null,
+/// * key_sequence: int32 not null,
+/// * fk_key_name: utf8,
+/// * pk_key_name: utf8,
+/// * update_rule: uint8 not null,
+/// * delete_rule: uint8 not null
+pub struct GetForeignKeysBuilder {
+    // Filters on the catalog, schema and table names of the primary key table
+    pk_filters: [Option<String>; 3],
+    // Filters on the catalog, schema and table names of the foreign key table
+    fk_filters: [Option<String>; 3],
+    // The columns to order the results by
+    order: KeyOrder,
+    // array builders for primary key catalog, schema, table and column names
+    pk_catalog_name: StringBuilder,
+    pk_db_schema_name: StringBuilder,
+    pk_table_name: StringBuilder,
+    pk_column_name: StringBuilder,
+    // array builders for foreign key catalog, schema, table and column names
+    fk_catalog_name: StringBuilder,
+    fk_db_schema_name: StringBuilder,
+    fk_table_name: StringBuilder,
+    fk_column_name: StringBuilder,
+    // array builder for the position of the columns in the keys
+    key_sequence: Int32Builder,
+    // array builders for key names
+    fk_key_name: StringBuilder,
+    pk_key_name: StringBuilder,
+    // array builders for update and delete rules
+    update_rule: UInt8Builder,
+    delete_rule: UInt8Builder,
+}
+
+impl CommandGetExportedKeys {
+    /// Create a builder suitable for constructing a response
+    pub fn into_builder(self) -> GetForeignKeysBuilder {
+        self.into()
+    }
+}
+
+impl From<CommandGetExportedKeys> for GetForeignKeysBuilder {
+    fn from(value: CommandGetExportedKeys) -> Self {
+        Self::new_exported_keys(value.catalog, value.db_schema, value.table)
+    }
+}
+
+impl CommandGetImportedKeys {
+    /// Create a builder suitable for constructing a response
+    pub fn into_builder(self) -> GetForeignKeysBuilder {
+        self.into()
+    }
+}
+
+impl From<CommandGetImportedKeys> for GetForeignKeysBuilder {
+    fn from(value: CommandGetImportedKeys) -> Self {
+        Self::new_imported_keys(value.catalog, value.db_schema, value.table)
+    }
+}
+
+impl CommandGetCrossReference {
+    /// Create a builder suitable for constructing a response
+    pub fn into_builder(self) -> GetForeignKeysBuilder {
+        self.into()
+    }
+}
+
+impl From<CommandGetCrossReference> for GetForeignKeysBuilder {
+    fn from(value: CommandGetCrossReference) -> Self {
+        Self::new_cross_reference(
+            value.pk_catalog,
+            value.pk_db_schema,
+            value.pk_table,
+            value.fk_catalog,
+            value.fk_db_schema,
+            value.fk_table,
+        )
+    }
+}
+
+impl GetForeignKeysBuilder {
+    fn new(
+        pk_filters: [Option<String>; 3],
+        fk_filters: [Option<String>; 3],
+        order: KeyOrder,
+    ) -> Self {
+        Self {
+            pk_filters,
+            fk_filters,
+            order,
+            pk_catalog_name: StringBuilder::new(),
+            pk_db_schema_name: StringBuilder::new(),
+            pk_table_name: StringBuilder::new(),
+            pk_column_name: StringBuilder::new(),
+            fk_catalog_name: StringBuilder::new(),
+            fk_db_schema_name: StringBuilder::new(),
+            fk_table_name: StringBuilder::new(),
+            fk_column_name: StringBuilder::new(),
+            key_sequence: Int32Builder::new(),
+            fk_key_name: StringBuilder::new(),
+            pk_key_name: StringBuilder::new(),
+            update_rule: UInt8Builder::new(),
+            delete_rule: UInt8Builder::new(),
+        }
+    }
+
+    /// Create a new instance of [`GetForeignKeysBuilder`] for a [`CommandGetExportedKeys`]
+    /// response, containing the foreign keys that reference the primary key of `table`,
+    /// ordered by the foreign key columns.
+    ///
+    /// # Parameters
+    ///
+    /// - `catalog`:  Specifies the Catalog to search for the primary key table.
+    ///   - An empty string retrieves those without a catalog.
+    ///   - If omitted the catalog name is not used to narrow the search.
+    /// - `db_schema`: Specifies the schema to search for the primary key table.
+    ///   - An empty string retrieves those without a schema.
+    ///   - If omitted the schema name is not used to narrow the search.
+    /// - `table`: Specifies the primary key table to get the foreign keys for.
+    pub fn new_exported_keys(
+        catalog: Option<impl Into<String>>,
+        db_schema: Option<impl Into<String>>,
+        table: impl Into<String>,
+    ) -> Self {
+        let filters = [
+            catalog.map(|v| v.into()),
+            db_schema.map(|v| v.into()),
+            Some(table.into()),
+        ];
+        Self::new(filters, Default::default(), KeyOrder::ForeignKey)
+    }
+
+    /// Create a new instance of [`GetForeignKeysBuilder`] for a [`CommandGetImportedKeys`]
+    /// response, containing the foreign keys of `table`, ordered by the primary key columns
+    /// they reference.
+    ///
+    /// # Parameters
+    ///
+    /// - `catalog`:  Specifies the Catalog to search for the foreign key table.
+    ///   - An empty string retrieves those without a catalog.
+    ///   - If omitted the catalog name is not used to narrow the search.
+    /// - `db_schema`: Specifies the schema to search for the foreign key table.
+    ///   - An empty string retrieves those without a schema.
+    ///   - If omitted the schema name is not used to narrow the search.
+    /// - `table`: Specifies the foreign key table to get the foreign keys for.
+    pub fn new_imported_keys(
+        catalog: Option<impl Into<String>>,
+        db_schema: Option<impl Into<String>>,
+        table: impl Into<String>,
+    ) -> Self {
+        let filters = [
+            catalog.map(|v| v.into()),
+            db_schema.map(|v| v.into()),
+            Some(table.into()),
+        ];
+        Self::new(Default::default(), filters, KeyOrder::PrimaryKey)
+    }
+
+    /// Create a new instance of [`GetForeignKeysBuilder`] for a [`CommandGetCrossReference`]
+    /// response, containing the foreign keys of `fk_table` that reference `pk_table`,
+    /// ordered by the primary key columns.
+    ///
+    /// The catalog and schema parameters are interpreted as for
+    /// [`Self::new_exported_keys`] and [`Self::new_imported_keys`].
+    pub fn new_cross_reference(
+        pk_catalog: Option<impl Into<String>>,
+        pk_db_schema: Option<impl Into<String>>,
+        pk_table: impl Into<String>,
+        fk_catalog: Option<impl Into<String>>,
+        fk_db_schema: Option<impl Into<String>>,
+        fk_table: impl Into<String>,
+    ) -> Self {
+        let pk_filters = [
+            pk_catalog.map(|v| v.into()),
+            pk_db_schema.map(|v| v.into()),
+            Some(pk_table.into()),
+        ];
+        let fk_filters = [
+            fk_catalog.map(|v| v.into()),
+            fk_db_schema.map(|v| v.into()),
+            Some(fk_table.into()),
+        ];
+        Self::new(pk_filters, fk_filters, KeyOrder::PrimaryKey)
+    }
+
+    /// Append a row
+    pub fn append(&mut self, column: ForeignKeyColumn) {
+        self.pk_catalog_name.append_value(column.pk_catalog_name);
+        self.pk_db_schema_name
+            .append_value(column.pk_db_schema_name);
+        self.pk_table_name.append_value(column.pk_table_name);
+        self.pk_column_name.append_value(column.pk_column_name);
+        self.fk_catalog_name.append_value(column.fk_catalog_name);
+        self.fk_db_schema_name
+            .append_value(column.fk_db_schema_name);
+        self.fk_table_name.append_value(column.fk_table_name);
+        self.fk_column_name.append_value(column.fk_column_name);
+        self.key_sequence.append_value(column.key_sequence);
+        self.fk_key_name.append_option(column.fk_key_name);
+        self.pk_key_name.append_option(column.pk_key_name);
+        self.update_rule.append_value(column.update_rule as u8);
+        self.delete_rule.append_value(column.delete_rule as u8);
+    }
+
+    /// builds a `RecordBatch` with the correct schema for a `CommandGetExportedKeys`,
+    /// `CommandGetImportedKeys` or `CommandGetCrossReference` response
+    pub fn build(self) -> Result<RecordBatch> {
+        let schema = self.schema();
+        let Self {
+            pk_filters,
+            fk_filters,
+            order,
+            mut pk_catalog_name,
+            mut pk_db_schema_name,
+            mut pk_table_name,
+            mut pk_column_name,
+            mut fk_catalog_name,
+            mut fk_db_schema_name,
+            mut fk_table_name,
+            mut fk_column_name,
+            mut key_sequence,
+            mut fk_key_name,
+            mut pk_key_name,
+            mut update_rule,
+            mut delete_rule,
+        } = self;
+
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(pk_catalog_name.finish()) as ArrayRef,
+                Arc::new(pk_db_schema_name.finish()) as ArrayRef,
+                Arc::new(pk_table_name.finish()) as ArrayRef,
+                Arc::new(pk_column_name.finish()) as ArrayRef,
+                Arc::new(fk_catalog_name.finish()) as ArrayRef,
+                Arc::new(fk_db_schema_name.finish()) as ArrayRef,
+                Arc::new(fk_table_name.finish()) as ArrayRef,
+                Arc::new(fk_column_name.finish()) as ArrayRef,
+                Arc::new(key_sequence.finish()) as ArrayRef,
+                Arc::new(fk_key_name.finish()) as ArrayRef,
+                Arc::new(pk_key_name.finish()) as ArrayRef,
+                Arc::new(update_rule.finish()) as ArrayRef,
+                Arc::new(delete_rule.finish()) as ArrayRef,
+            ],
+        )?;
+
+        // the catalog, schema and table name columns of each side of the key
+        let filters = [0, 1, 2]
+            .into_iter()
+            .zip(pk_filters)
+            .chain([4, 5, 6].into_iter().zip(fk_filters));
+
+        // Order filtered results by catalog name, schema name, table name, key name,
+        // then key_sequence of one side of the key
+        let sort_columns = match order {
+            KeyOrder::PrimaryKey => [0, 1, 2, 10, 8],
+            KeyOrder::ForeignKey => [4, 5, 6, 9, 8],
+        };
+        filter_and_sort(batch, filters, &sort_columns)
+    }
+
+    /// Return the schema of the RecordBatch that will be returned from
+    /// [`CommandGetExportedKeys`], [`CommandGetImportedKeys`] or [`CommandGetCrossReference`]
+    pub fn schema(&self) -> SchemaRef {
+        get_foreign_keys_schema()
+    }
+}
+
+/// Keeps the rows of `batch` whose string columns are equal to the provided
+/// filters, ordered by the values of the `sort_columns`
+fn filter_and_sort(
+    batch: RecordBatch,
+    filters: impl IntoIterator<Item = (usize, Option<String>)>,
+    sort_columns: &[usize],
+) -> Result<RecordBatch> {
+    // `AND` any filters together
+    let mut total_filter = None;
+    for (column, value) in filters {
+        let Some(value) = value else { continue };
+        let filter = eq(batch.column(column), &StringArray::new_scalar(value))?;
+        let new_filter = match total_filter {
+            Some(total_filter) => and(&total_filter, &filter)?,
+            None => filter,
+        };
+        total_filter = Some(new_filter);
+    }
+
+    // Apply the filters if needed
+    let filtered_batch = if let Some(filter) = total_filter {
+        filter_record_batch(&batch, &filter)?
+    } else {
+        batch
+    };
+
+    let sort_arrays: Vec<_> = sort_columns
+        .iter()
+        .map(|i| Arc::clone(filtered_batch.column(*i)))
+        .collect();
+    let indices = lexsort_to_indices(&sort_arrays);
+    let columns = filtered_batch
+        .columns()
+        .iter()
+        .map(|c| take(c, &indices, None))
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+    Ok(RecordBatch::try_new(filtered_batch.schema(), columns)?)
+}
+
+fn get_primary_keys_schema() -> SchemaRef {
+    Arc::clone(&GET_PRIMARY_KEYS_SCHEMA)
+}
+
+/// The schema for GetPrimaryKeys
+static GET_PRIMARY_KEYS_SCHEMA: Lazy<SchemaRef> = Lazy::new(|| {
+    Arc::new(Schema::new(vec![
+        Field::new("catalog_name", DataType::Utf8, true),
+        Field::new("db_schema_name", DataType::Utf8, true),
+        Field::new("table_name", DataType::Utf8, false),
+        Field::new("column_name", DataType::Utf8, false),
+        Field::new("key_name", DataType::Utf8, true),
+        Field::new("key_sequence", DataType::Int32, false),
+    ]))
+});
+
+fn get_foreign_keys_schema() -> SchemaRef {
+    Arc::clone(&GET_FOREIGN_KEYS_SCHEMA)
+}
+
+/// The schema for GetExportedKeys, GetImportedKeys and GetCrossReference
+static GET_FOREIGN_KEYS_SCHEMA: Lazy<SchemaRef> = Lazy::new(|| {
+    Arc::new(Schema::new(vec![
+        Field::new("pk_catalog_name", DataType::Utf8, true),
+        Field::new("pk_db_schema_name", DataType::Utf8, true),
+        Field::new("pk_table_name", DataType::Utf8, false),
+        Field::new("pk_column_name", DataType::Utf8, false),
+        Field::new("fk_catalog_name", DataType::Utf8, true),
+        Field::new("fk_db_schema_name", DataType::Utf8, true),
+        Field::new("fk_table_name", DataType::Utf8, false),
+        Field::new("fk_column_name", DataType::Utf8, false),
+        Field::new("key_sequence", DataType::Int32, false),
+        Field::new("fk_key_name", DataType::Utf8, true),
+        Field::new("pk_key_name", DataType::Utf8, true),
+        Field::new("update_rule", DataType::UInt8, false),
+        Field::new("delete_rule", DataType::UInt8, false),
+    ]))
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sql::metadata::tests::assert_batches_eq;
+
+    #[test]
+    fn test_primary_keys_filtered_and_sorted() {
+        let query = CommandGetPrimaryKeys {
+            catalog: Some("a_catalog".to_string()),
+            db_schema: None,
+            table: "a_table".to_string(),
+        };
+        let mut builder = query.into_builder();
+        builder.append("a_catalog", "b_schema", "a_table", "id", Some("pk"), 1);
+        builder.append("a_catalog", "a_schema", "a_table", "name", Some("pk"), 2);
+        builder.append("a_catalog", "a_schema", "a_table", "id", Some("pk"), 1);
+        builder.append("b_catalog", "a_schema", "a_table", "id", Some("pk"), 1);
+        builder.append("a_catalog", "a_schema", "b_table", "id", None::<&str>, 1);
+        let batch = builder.build().unwrap();
+
+        assert_eq!(batch.schema(), get_primary_keys_schema());
+        assert_batches_eq(
+            &[batch],
+            &[
+                "+--------------+----------------+------------+-------------+----------+--------------+",
+                "| catalog_name | db_schema_name | table_name | column_name | key_name | key_sequence |",
+                "+--------------+----------------+------------+-------------+----------+--------------+",
+                "| a_catalog    | a_schema       | a_table    | id          | pk       | 1            |",
+                "| a_catalog    | a_schema       | a_table    | name        | pk       | 2            |",
+                "| a_catalog    | b_schema       | a_table    | id          | pk       | 1            |",
+                "+--------------+----------------+------------+-------------+----------+--------------+",
+            ],
+        );
+    }
+
+    fn foreign_key(pk_table: &str, fk_table: &str, fk_key: &str, seq: i32) -> ForeignKeyColumn {
+        ForeignKeyColumn {
+            pk_catalog_name: "catalog".to_string(),
+            pk_db_schema_name: "schema".to_string(),
+            pk_table_name: pk_table.to_string(),
+            pk_column_name: format!("pk_{seq}"),
+            fk_catalog_name: "catalog".to_string(),
+            fk_db_schema_name: "schema".to_string(),
+            fk_table_name: fk_table.to_string(),
+            fk_column_name: format!("fk_{seq}"),
+            key_sequence: seq,
+            fk_key_name: Some(fk_key.to_string()),
+            pk_key_name: Some(format!("{pk_table}_pk")),
+            update_rule: UpdateDeleteRules::Cascade,
+            delete_rule: UpdateDeleteRules::SetNull,
+        }
+    }
+
+    /// Appends foreign keys between tables `a`, `b` and `c`, and returns
+    /// the (pk_table_name, fk_table_name, key_sequence) of the built batch
+    fn build_foreign_keys(mut builder: GetForeignKeysBuilder) -> Vec<(String, String, i32)> {
+        builder.append(foreign_key("a", "c", "c_a", 2));
+        builder.append(foreign_key("b", "a", "a_b", 1));
+        builder.append(foreign_key("a", "c", "c_a", 1));
+        builder.append(foreign_key("a", "b", "b_a", 1));
+        builder.append(foreign_key("c", "b", "b_c", 1));
+        let batch = builder.build().unwrap();
+        assert_eq!(batch.schema(), get_foreign_keys_schema());
+
+        let strings = |i: usize| {
+            batch
+                .column(i)
+                .as_any()
+                .downcast_ref::<StringArray>()
+                .unwrap()
+        };
+        let key_sequence = batch
+            .column(8)
+            .as_any()
+            .downcast_ref::<arrow_array::Int32Array>()
+            .unwrap();
+        (0..batch.num_rows())
+            .map(|i| {
+                (
+                    strings(2).value(i).to_string(),
+                    strings(6).value(i).to_string(),
+                    key_sequence.value(i),
+                )
+            })
+            .collect()
+    }
+
+    fn rows(rows: &[(&str, &str, i32)]) -> Vec<(String, String, i32)> {
+        rows.iter()
+            .map(|(pk, fk, seq)| (pk.to_string(), fk.to_string(), *seq))
+            .collect()
+    }
+
+    #[test]
+    fn test_exported_keys() {
+        let query = CommandGetExportedKeys {
+            catalog: None,
+            db_schema: Some("schema".to_string()),
+            table: "a".to_string(),
+        };
+        assert_eq!(
+            build_foreign_keys(query.into_builder()),
+            rows(&[("a", "b", 1), ("a", "c", 1), ("a", "c", 2)])
+        );
+    }
+
+    #[test]
+    fn test_imported_keys() {
+        let query = CommandGetImportedKeys {
+            catalog: Some("catalog".to_string()),
+            db_schema: None,
+            table: "b".to_string(),
+        };
+        assert_eq!(
+            build_foreign_keys(query.into_builder()),
+            rows(&[("a", "b", 1), ("c", "b", 1)])
+        );
+    }
+
+    #[test]
+    fn test_cross_reference() {
+        let query = CommandGetCrossReference {
+            pk_catalog: None,
+            pk_db_schema: None,
+            pk_table: "a".to_string(),
+            fk_catalog: None,
+            fk_db_schema: Some("other_schema".to_string()),
+            fk_table: "c".to_string(),
+        };
+        assert_eq!(build_foreign_keys(query.clone().into_builder()), rows(&[]));
+
+        let query = CommandGetCrossReference {
+            fk_db_schema: None,
+            ..query
+        };
+        assert_eq!(
+            build_foreign_keys(query.into_builder()),
+            rows(&[("a", "c", 1), ("a", "c", 2)])
+        );
+    }
+
+    #[test]
+    fn test_foreign_key_rules() {
+        let mut builder = GetForeignKeysBuilder::new_imported_keys(None::<&str>, None::<&str>, "c");
+        builder.append(foreign_key("a", "c", "c_a", 1));
+        let batch = builder.build().unwrap();
+
+        let rule = |i: usize| {
+            batch
+                .column(i)
+                .as_any()
+                .downcast_ref::<arrow_array::UInt8Array>()
+                .unwrap()
+                .value(0)
+        };
+        assert_eq!(rule(11), UpdateDeleteRules::Cascade as u8);
+        assert_eq!(rule(12), UpdateDeleteRules::SetNull as u8);
+    }
+}
@@ -21,17 +21,27 @@
 //! - [`GetCatalogsBuilder`] for building responses to [`CommandGetCatalogs`] queries.
 //! - [`GetDbSchemasBuilder`] for building responses to [`CommandGetDbSchemas`] queries.
 //! - [`GetTablesBuilder`]for building responses to [`CommandGetTables`] queries.
+//! - [`GetTableTypesBuilder`] for building responses to [`CommandGetTableTypes`] queries.
+//! - [`GetPrimaryKeysBuilder`] for building responses to [`CommandGetPrimaryKeys`] queries.
+//! - [`GetForeignKeysBuilder`] for building responses to [`CommandGetExportedKeys`],
+//!   [`CommandGetImportedKeys`] and [`CommandGetCrossReference`] queries.
 //! - [`SqlInfoDataBuilder`]for building responses to [`CommandGetSqlInfo`] queries.
 //! - [`XdbcTypeInfoDataBuilder`]for building responses to [`CommandGetXdbcTypeInfo`] queries.
 //!
 //! [`CommandGetCatalogs`]: crate::sql::CommandGetCatalogs
 //! [`CommandGetDbSchemas`]: crate::sql::CommandGetDbSchemas
 //! [`CommandGetTables`]: crate::sql::CommandGetTables
+//! [`CommandGetTableTypes`]: crate::sql::CommandGetTableTypes
+//! [`CommandGetPrimaryKeys`]: crate::sql::CommandGetPrimaryKeys
+//! [`CommandGetExportedKeys`]: crate::sql::CommandGetExportedKeys
+//! [`CommandGetImportedKeys`]: crate::sql::CommandGetImportedKeys
+//! [`CommandGetCrossReference`]: crate::sql::CommandGetCrossReference
 //! [`CommandGetSqlInfo`]: crate::sql::CommandGetSqlInfo
 //! [`CommandGetXdbcTypeInfo`]: crate::sql::CommandGetXdbcTypeInfo
 
 mod catalogs;
 mod db_schemas;
+mod keys;
 mod sql_info;
 mod table_types;
 mod tables;
@@ -39,7 +49,9 @@ mod xdbc_info;
 
 pub use catalogs::GetCatalogsBuilder;
 pub use db_schemas::GetDbSchemasBuilder;
+pub use keys::{ForeignKeyColumn, GetForeignKeysBuilder, GetPrimaryKeysBuilder};
 pub use sql_info::{SqlInfoData, SqlInfoDataBuilder};
+pub use table_types::GetTableTypesBuilder;
 pub use tables::GetTablesBuilder;
 pub use xdbc_info::{XdbcTypeInfo, XdbcTypeInfoData, XdbcTypeInfoDataBuilder};
 
@@ -26,6 +26,7 @@
 //!
 use std::sync::Arc;
 
+use arrow_arith::boolean::or;
 use arrow_array::builder::{BooleanBuilder, Int32Builder, ListBuilder, StringBuilder};
 use arrow_array::{ArrayRef, Int32Array, ListArray, RecordBatch, Scalar};
 use arrow_ord::cmp::eq;
@@ -95,14 +96,26 @@ pub struct XdbcTypeInfoData {
 impl XdbcTypeInfoData {
     /// Return the raw (not encoded) RecordBatch that will be returned
     /// from [`CommandGetXdbcTypeInfo`]
+    ///
+    /// If `data_type` is specified, only the types it matches are returned:
+    ///
+    /// - [`XdbcDataType::XdbcUnknownType`] matches all types, like `SQL_ALL_TYPES` in ODBC.
+    /// - [`XdbcDataType::XdbcDatetime`] and [`XdbcDataType::XdbcInterval`] also match
+    ///   the types with that `sql_data_type`, i.e. all the datetime or interval types.
+    /// - Any other value matches the types with that `data_type`.
     pub fn record_batch(&self, data_type: impl Into<Option<i32>>) -> Result<RecordBatch> {
-        if let Some(dt) = data_type.into() {
-            let scalar = Int32Array::from(vec![dt]);
-            let filter = eq(self.batch.column(1), &Scalar::new(&scalar))?;
-            Ok(filter_record_batch(&self.batch, &filter)?)
-        } else {
-            Ok(self.batch.clone())
+        let dt = match data_type.into() {
+            Some(dt) if dt != XdbcDataType::XdbcUnknownType as i32 => dt,
+            _ => return Ok(self.batch.clone()),
+        };
+
+        let scalar = Int32Array::from(vec![dt]);
+        let scalar = Scalar::new(&scalar);
+        let mut filter = eq(self.batch.column(1), &scalar)?;
+        if dt == XdbcDataType::XdbcDatetime as i32 || dt == XdbcDataType::XdbcInterval as i32 {
+            filter = or(&filter, &eq(self.batch.column(15), &scalar)?)?;
         }
+        Ok(filter_record_batch(&self.batch, &filter)?)
     }
 
     /// Return the schema of the RecordBatch that will be returned
@@ -353,6 +366,7 @@ static GET_XDBC_INFO_SCHEMA: Lazy<SchemaRef> = Lazy::new(|| {
 mod tests {
     use super::*;
     use crate::sql::metadata::tests::assert_batches_eq;
+    use arrow_array::StringArray;
 
     #[test]
     fn test_create_batch() {
@@ -444,4 +458,86 @@ mod tests {
         ];
         assert_batches_eq(&[batch], &expected);
     }
+
+    #[test]
+    fn test_data_type_filter() {
+        let info = |type_name: &str, data_type, sql_data_type, datetime_subcode| XdbcTypeInfo {
+            type_name: type_name.into(),
+            data_type,
+            nullable: Nullable::NullabilityNullable,
+            searchable: Searchable::Full,
+            sql_data_type,
+            datetime_subcode,
+            ..Default::default()
+        };
+
+        let mut builder = XdbcTypeInfoDataBuilder::new();
+        builder.append(info(
+            "TEXT",
+            XdbcDataType::XdbcVarchar,
+            XdbcDataType::XdbcVarchar,
+            None,
+        ));
+        builder.append(info(
+            "VARCHAR",
+            XdbcDataType::XdbcVarchar,
+            XdbcDataType::XdbcVarchar,
+            None,
+        ));
+        builder.append(info(
+            "TIMESTAMP",
+            XdbcDataType::XdbcTimestamp,
+            XdbcDataType::XdbcDatetime,
+            Some(XdbcDatetimeSubcode::XdbcSubcodeTimestamp),
+        ));
+        builder.append(info(
+            "DATE",
+            XdbcDataType::XdbcDate,
+            XdbcDataType::XdbcDatetime,
+            // The same value as SQL_CODE_DATE in ODBC
+            Some(XdbcDatetimeSubcode::XdbcSubcodeYear),
+        ));
+        builder.append(info(
+            "INTERVAL YEAR",
+            XdbcDataType::XdbcInterval,
+            XdbcDataType::XdbcInterval,
+            Some(XdbcDatetimeSubcode::XdbcSubcodeYear),
+        ));
+        let infos = builder.build().unwrap();
+
+        let type_names = |data_type: Option<XdbcDataType>| {
+            let command = CommandGetXdbcTypeInfo {
+                data_type: data_type.map(|dt| dt as i32),
+            };
+            let batch = command.into_builder(&infos).build().unwrap();
+            assert_eq!(batch.schema(), infos.schema());
+            let names = batch
+                .column(0)
+                .as_any()
+                .downcast_ref::<StringArray>()
+                .unwrap();
+            names
+                .iter()
+                .map(|n| n.unwrap().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        let all = ["INTERVAL YEAR", "TEXT", "VARCHAR", "DATE", "TIMESTAMP"];
+        assert_eq!(type_names(None), all);
+        assert_eq!(type_names(Some(XdbcDataType::XdbcUnknownType)), all);
+        assert_eq!(
+            type_names(Some(XdbcDataType::XdbcVarchar)),
+            ["TEXT", "VARCHAR"]
+        );
+        assert_eq!(type_names(Some(XdbcDataType::XdbcDate)), ["DATE"]);
+        assert_eq!(
+            type_names(Some(XdbcDataType::XdbcDatetime)),
+            ["DATE", "TIMESTAMP"]
+        );
+        assert_eq!(
+            type_names(Some(XdbcDataType::XdbcInterval)),
+            ["INTERVAL YEAR"]
+        );
+        assert!(type_names(Some(XdbcDataType::XdbcBigint)).is_empty());
+    }
 }
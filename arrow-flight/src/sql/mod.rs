@@ -28,6 +28,8 @@
 //! 3. A [`FlightSqlServiceClient`] for interacting with FlightSQL servers.
 //! 4. A [`FlightSqlService`] to help building FlightSQL servers from [`FlightService`].
 //! 5. Helpers to build responses for FlightSQL metadata APIs: [`metadata`]
+//! 6. Helpers for transaction, savepoint and bulk ingestion messages, such as
+//!    [`ActionEndTransactionRequest::try_action`] and [`CommandStatementIngest::ingest_mode`]
 //!
 //! [Flight SQL]: https://arrow.apache.org/docs/format/FlightSql.html
 //! [Apache Arrow]: https://arrow.apache.org
@@ -103,15 +105,22 @@ pub use r#gen::TicketStatementQuery;
 pub use r#gen::UpdateDeleteRules;
 pub use r#gen::XdbcDataType;
 pub use r#gen::XdbcDatetimeSubcode;
+pub use r#gen::action_end_savepoint_request::EndSavepoint;
 pub use r#gen::action_end_transaction_request::EndTransaction;
 pub use r#gen::command_statement_ingest::TableDefinitionOptions;
 pub use r#gen::command_statement_ingest::table_definition_options::{
     TableExistsOption, TableNotExistOption,
 };
 
+#[cfg(feature = "grpc")]
 pub mod client;
+mod ingest;
 pub mod metadata;
+#[cfg(feature = "grpc")]
 pub mod server;
+mod transactions;
+
+pub use ingest::IngestMode;
 
 pub use crate::streams::FallibleRequestStream;
 
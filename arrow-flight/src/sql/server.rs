@@ -33,13 +33,21 @@ use super::{
     CommandStatementUpdate, DoPutPreparedStatementResult, DoPutUpdateResult, ProstMessageExt,
     SqlInfo, TicketStatementQuery,
 };
+use crate::decode::FlightRecordBatchStream;
+use crate::error::FlightError;
 use crate::{
-    Action, ActionType, Criteria, Empty, FlightData, FlightDescriptor, FlightInfo,
-    HandshakeRequest, HandshakeResponse, PutResult, SchemaResult, Ticket,
-    flight_service_server::FlightService, r#gen::PollInfo,
+    Action, ActionType, CancelFlightInfoRequest, CancelFlightInfoResult, CloseSessionRequest,
+    CloseSessionResult, Criteria, Empty, FlightData, FlightDescriptor, FlightEndpoint, FlightInfo,
+    GetSessionOptionsRequest, GetSessionOptionsResult, HandshakeRequest, HandshakeResponse,
+    PutResult, RenewFlightEndpointRequest, SchemaResult, SetSessionOptionsRequest,
+    SetSessionOptionsResult, Ticket, flight_service_server::FlightService, r#gen::PollInfo,
 };
-use futures::{Stream, StreamExt, stream::Peekable};
+use arrow_array::{ArrayRef, RecordBatch, Scalar};
+use arrow_schema::{Schema, SchemaRef};
+use arrow_select::concat::concat_batches;
+use futures::{Stream, StreamExt, TryStreamExt, stream::Peekable};
 use prost::Message;
+use std::sync::Arc;
 use tonic::{Request, Response, Status, Streaming};
 
 pub(crate) static CREATE_PREPARED_STATEMENT: &str = "CreatePreparedStatement";
@@ -50,6 +58,11 @@ pub(crate) static END_TRANSACTION: &str = "EndTransaction";
 pub(crate) static BEGIN_SAVEPOINT: &str = "BeginSavepoint";
 pub(crate) static END_SAVEPOINT: &str = "EndSavepoint";
 pub(crate) static CANCEL_QUERY: &str = "CancelQuery";
+pub(crate) static CANCEL_FLIGHT_INFO: &str = "CancelFlightInfo";
+pub(crate) static RENEW_FLIGHT_ENDPOINT: &str = "RenewFlightEndpoint";
+pub(crate) static SET_SESSION_OPTIONS: &str = "SetSessionOptions";
+pub(crate) static GET_SESSION_OPTIONS: &str = "GetSessionOptions";
+pub(crate) static CLOSE_SESSION: &str = "CloseSession";
 
 /// Implements FlightSqlService to handle the flight sql protocol
 #[tonic::async_trait]
@@ -560,6 +573,61 @@ pub trait FlightSqlService: Sync + Send + Sized + 'static {
         ))
     }
 
+    /// Cancel the execution of a distributed query, identified by its [`FlightInfo`]
+    async fn do_action_cancel_flight_info(
+        &self,
+        _query: CancelFlightInfoRequest,
+        _request: Request<Action>,
+    ) -> Result<CancelFlightInfoResult, Status> {
+        Err(Status::unimplemented(
+            "do_action_cancel_flight_info has no default implementation",
+        ))
+    }
+
+    /// Extend the expiration time of a [`FlightEndpoint`]
+    async fn do_action_renew_flight_endpoint(
+        &self,
+        _query: RenewFlightEndpointRequest,
+        _request: Request<Action>,
+    ) -> Result<FlightEndpoint, Status> {
+        Err(Status::unimplemented(
+            "do_action_renew_flight_endpoint has no default implementation",
+        ))
+    }
+
+    /// Set options of the client session
+    async fn do_action_set_session_options(
+        &self,
+        _query: SetSessionOptionsRequest,
+        _request: Request<Action>,
+    ) -> Result<SetSessionOptionsResult, Status> {
+        Err(Status::unimplemented(
+            "do_action_set_session_options has no default implementation",
+        ))
+    }
+
+    /// Get the options of the client session
+    async fn do_action_get_session_options(
+        &self,
+        _query: GetSessionOptionsRequest,
+        _request: Request<Action>,
+    ) -> Result<GetSessionOptionsResult, Status> {
+        Err(Status::unimplemented(
+            "do_action_get_session_options has no default implementation",
+        ))
+    }
+
+    /// Close the client session
+    async fn do_action_close_session(
+        &self,
+        _query: CloseSessionRequest,
+        _request: Request<Action>,
+    ) -> Result<CloseSessionResult, Status> {
+        Err(Status::unimplemented(
+            "do_action_close_session has no default implementation",
+        ))
+    }
+
     /// do_exchange
     /// Implementors may override to handle additional calls to do_exchange()
     async fn do_exchange_fallback(
@@ -842,6 +910,41 @@ where
                 Response Message: ActionCancelQueryResult"
                 .into(),
         };
+        let cancel_flight_info_action_type = ActionType {
+            r#type: CANCEL_FLIGHT_INFO.to_string(),
+            description: "Explicitly cancel a running FlightInfo.\n
+                Request Message: CancelFlightInfoRequest\n
+                Response Message: CancelFlightInfoResult"
+                .into(),
+        };
+        let renew_flight_endpoint_action_type = ActionType {
+            r#type: RENEW_FLIGHT_ENDPOINT.to_string(),
+            description: "Extend expiration time of the given FlightEndpoint.\n
+                Request Message: RenewFlightEndpointRequest\n
+                Response Message: Renewed FlightEndpoint"
+                .into(),
+        };
+        let set_session_options_action_type = ActionType {
+            r#type: SET_SESSION_OPTIONS.to_string(),
+            description: "Set client session options.\n
+                Request Message: SetSessionOptionsRequest\n
+                Response Message: SetSessionOptionsResult"
+                .into(),
+        };
+        let get_session_options_action_type = ActionType {
+            r#type: GET_SESSION_OPTIONS.to_string(),
+            description: "Get current client session options.\n
+                Request Message: GetSessionOptionsRequest\n
+                Response Message: GetSessionOptionsResult"
+                .into(),
+        };
+        let close_session_action_type = ActionType {
+            r#type: CLOSE_SESSION.to_string(),
+            description: "Close the client session.\n
+                Request Message: CloseSessionRequest\n
+                Response Message: CloseSessionResult"
+                .into(),
+        };
         let mut actions: Vec<Result<ActionType, Status>> = vec![
             Ok(create_prepared_statement_action_type),
             Ok(close_prepared_statement_action_type),
@@ -851,6 +954,11 @@ where
             Ok(begin_savepoint_action_type),
             Ok(end_savepoint_action_type),
             Ok(cancel_query_action_type),
+            Ok(cancel_flight_info_action_type),
+            Ok(renew_flight_endpoint_action_type),
+            Ok(set_session_options_action_type),
+            Ok(get_session_options_action_type),
+            Ok(close_session_action_type),
         ];
 
         if let Some(mut custom_actions) = self.list_custom_actions().await {
@@ -975,6 +1083,32 @@ where
                 body: stmt.as_any().encode_to_vec().into(),
             })]);
             return Ok(Response::new(Box::pin(output)));
+        } else if request.get_ref().r#type == CANCEL_FLIGHT_INFO {
+            // the Flight actions are encoded directly rather than wrapped in `Any`
+            let cmd = CancelFlightInfoRequest::decode(&*request.get_ref().body)
+                .map_err(decode_error_to_status)?;
+            let result = self.do_action_cancel_flight_info(cmd, request).await?;
+            return Ok(Response::new(encode_action_result(result)));
+        } else if request.get_ref().r#type == RENEW_FLIGHT_ENDPOINT {
+            let cmd = RenewFlightEndpointRequest::decode(&*request.get_ref().body)
+                .map_err(decode_error_to_status)?;
+            let result = self.do_action_renew_flight_endpoint(cmd, request).await?;
+            return Ok(Response::new(encode_action_result(result)));
+        } else if request.get_ref().r#type == SET_SESSION_OPTIONS {
+            let cmd = SetSessionOptionsRequest::decode(&*request.get_ref().body)
+                .map_err(decode_error_to_status)?;
+            let result = self.do_action_set_session_options(cmd, request).await?;
+            return Ok(Response::new(encode_action_result(result)));
+        } else if request.get_ref().r#type == GET_SESSION_OPTIONS {
+            let cmd = GetSessionOptionsRequest::decode(&*request.get_ref().body)
+                .map_err(decode_error_to_status)?;
+            let result = self.do_action_get_session_options(cmd, request).await?;
+            return Ok(Response::new(encode_action_result(result)));
+        } else if request.get_ref().r#type == CLOSE_SESSION {
+            let cmd = CloseSessionRequest::decode(&*request.get_ref().body)
+                .map_err(decode_error_to_status)?;
+            let result = self.do_action_close_session(cmd, request).await?;
+            return Ok(Response::new(encode_action_result(result)));
         }
 
         self.do_action_fallback(request).await
@@ -1016,6 +1150,16 @@ fn arrow_error_to_status(err: arrow_schema::ArrowError) -> Status {
     Status::internal(format!("{err:?}"))
 }
 
+/// Returns a `DoAction` response stream of the single `result`, encoded without an `Any` wrapper
+fn encode_action_result<M: Message>(
+    result: M,
+) -> Pin<Box<dyn Stream<Item = Result<super::super::Result, Status>> + Send + 'static>> {
+    let output = futures::stream::iter(vec![Ok(super::super::r#gen::Result {
+        body: result.encode_to_vec().into(),
+    })]);
+    Box::pin(output)
+}
+
 /// A wrapper around [`Streaming<FlightData>`] that allows "peeking" at the
 /// message at the front of the stream without consuming it.
 ///
@@ -1102,3 +1246,86 @@ impl Stream for PeekableFlightDataStream {
         self.inner.poll_next_unpin(cx)
     }
 }
+
+/// The parameters bound to a prepared statement by a client
+///
+/// The parameters are sent in a `DoPut` request with a
+/// [`CommandPreparedStatementQuery`] or [`CommandPreparedStatementUpdate`], as a
+/// single set of parameters per row. Use [`Self::try_decode`] to read them, for
+/// example in [`FlightSqlService::do_put_prepared_statement_query`].
+///
+/// # Example
+/// ```no_run
+/// # use arrow_flight::sql::server::{PeekableFlightDataStream, PreparedStatementParameters};
+/// # use tonic::{Request, Status};
+/// # async fn do_put_prepared_statement_query(request: Request<PeekableFlightDataStream>) -> Result<(), Status> {
+/// let parameters = PreparedStatementParameters::try_decode(request.into_inner()).await?;
+/// for set in 0..parameters.num_sets() {
+///     let id = parameters.named_value(set, "id");
+///     // ...
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct PreparedStatementParameters {
+    batch: RecordBatch,
+}
+
+impl PreparedStatementParameters {
+    /// Create [`PreparedStatementParameters`] with a set of parameters for each row of `batch`
+    pub fn new(batch: RecordBatch) -> Self {
+        Self { batch }
+    }
+
+    /// Decode the parameters sent in the `DoPut` `request`
+    pub async fn try_decode(request: PeekableFlightDataStream) -> Result<Self, Status> {
+        let mut stream =
+            FlightRecordBatchStream::new_from_flight_data(request.map_err(FlightError::from));
+        let mut batches = vec![];
+        while let Some(batch) = stream.try_next().await? {
+            batches.push(batch);
+        }
+        let schema = match stream.schema() {
+            Some(schema) => Arc::clone(schema),
+            None => Arc::new(Schema::empty()),
+        };
+        let batch = concat_batches(&schema, &batches).map_err(arrow_error_to_status)?;
+        Ok(Self::new(batch))
+    }
+
+    /// The schema of the parameters
+    pub fn schema(&self) -> SchemaRef {
+        self.batch.schema()
+    }
+
+    /// The number of sets of parameters
+    pub fn num_sets(&self) -> usize {
+        self.batch.num_rows()
+    }
+
+    /// The value of the parameter at `index` of the set of parameters `set`, if any
+    pub fn value(&self, set: usize, index: usize) -> Option<Scalar<ArrayRef>> {
+        if set >= self.num_sets() {
+            return None;
+        }
+        let column = self.batch.columns().get(index)?;
+        Some(Scalar::new(column.slice(set, 1)))
+    }
+
+    /// The value of the parameter named `name` of the set of parameters `set`, if any
+    pub fn named_value(&self, set: usize, name: &str) -> Option<Scalar<ArrayRef>> {
+        let index = self.batch.schema_ref().index_of(name).ok()?;
+        self.value(set, index)
+    }
+
+    /// The parameters as a [`RecordBatch`] with a row for each set of parameters
+    pub fn batch(&self) -> &RecordBatch {
+        &self.batch
+    }
+
+    /// Consume self and return the parameters as a [`RecordBatch`]
+    pub fn into_batch(self) -> RecordBatch {
+        self.batch
+    }
+}
@@ -0,0 +1,162 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Helpers for the `BeginTransaction`, `EndTransaction`, `BeginSavepoint`
+//! and `EndSavepoint` actions.
+
+use bytes::Bytes;
+
+use crate::error::{FlightError, Result};
+use crate::sql::{
+    ActionBeginSavepointRequest, ActionBeginSavepointResult, ActionBeginTransactionResult,
+    ActionEndSavepointRequest, ActionEndTransactionRequest, EndSavepoint, EndTransaction,
+};
+
+impl ActionBeginTransactionResult {
+    /// Create the response to an `ActionBeginTransactionRequest` that began
+    /// the transaction `transaction_id`
+    pub fn new(transaction_id: impl Into<Bytes>) -> Self {
+        Self {
+            transaction_id: transaction_id.into(),
+        }
+    }
+}
+
+impl ActionEndTransactionRequest {
+    /// Create a request to commit or rollback the transaction `transaction_id`
+    pub fn new(transaction_id: impl Into<Bytes>, action: EndTransaction) -> Self {
+        Self {
+            transaction_id: transaction_id.into(),
+            action: action as i32,
+        }
+    }
+
+    /// Returns whether to commit or rollback the transaction
+    ///
+    /// Returns an error if the action is unspecified or unknown
+    pub fn try_action(&self) -> Result<EndTransaction> {
+        match EndTransaction::try_from(self.action) {
+            Ok(EndTransaction::Unspecified) | Err(_) => Err(FlightError::protocol(format!(
+                "invalid end transaction action: {}",
+                self.action
+            ))),
+            Ok(action) => Ok(action),
+        }
+    }
+}
+
+impl ActionBeginSavepointRequest {
+    /// Create a request to begin the savepoint `name` within the transaction `transaction_id`
+    pub fn new(transaction_id: impl Into<Bytes>, name: impl Into<String>) -> Self {
+        Self {
+            transaction_id: transaction_id.into(),
+            name: name.into(),
+        }
+    }
+}
+
+impl ActionBeginSavepointResult {
+    /// Create the response to an [`ActionBeginSavepointRequest`] that began
+    /// the savepoint `savepoint_id`
+    pub fn new(savepoint_id: impl Into<Bytes>) -> Self {
+        Self {
+            savepoint_id: savepoint_id.into(),
+        }
+    }
+}
+
+impl ActionEndSavepointRequest {
+    /// Create a request to release or rollback the savepoint `savepoint_id`
+    pub fn new(savepoint_id: impl Into<Bytes>, action: EndSavepoint) -> Self {
+        Self {
+            savepoint_id: savepoint_id.into(),
+            action: action as i32,
+        }
+    }
+
+    /// Returns whether to release or rollback the savepoint
+    ///
+    /// Returns an error if the action is unspecified or unknown
+    pub fn try_action(&self) -> Result<EndSavepoint> {
+        match EndSavepoint::try_from(self.action) {
+            Ok(EndSavepoint::Unspecified) | Err(_) => Err(FlightError::protocol(format!(
+                "invalid end savepoint action: {}",
+                self.action
+            ))),
+            Ok(action) => Ok(action),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sql::{Any, ProstMessageExt};
+
+    #[test]
+    fn test_begin_transaction_result() {
+        let result = ActionBeginTransactionResult::new(Bytes::from_static(b"txn"));
+        let any = Any::pack(&result).unwrap();
+        let decoded: ActionBeginTransactionResult = any.unpack().unwrap().unwrap();
+        assert_eq!(decoded.transaction_id, Bytes::from_static(b"txn"));
+    }
+
+    #[test]
+    fn test_end_transaction_action() {
+        for action in [EndTransaction::Commit, EndTransaction::Rollback] {
+            let request = ActionEndTransactionRequest::new(b"txn".to_vec(), action);
+            let decoded: ActionEndTransactionRequest = request.as_any().unpack().unwrap().unwrap();
+            assert_eq!(decoded.try_action().unwrap(), action);
+        }
+
+        let request = ActionEndTransactionRequest::new("txn", EndTransaction::Unspecified);
+        let err = request.try_action().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Protocol error: invalid end transaction action: 0"
+        );
+
+        let request = ActionEndTransactionRequest {
+            transaction_id: Bytes::from_static(b"txn"),
+            action: 42,
+        };
+        assert!(request.try_action().is_err());
+    }
+
+    #[test]
+    fn test_savepoint() {
+        let request = ActionBeginSavepointRequest::new("txn", "sp");
+        let decoded: ActionBeginSavepointRequest = request.as_any().unpack().unwrap().unwrap();
+        assert_eq!(decoded.transaction_id, Bytes::from_static(b"txn"));
+        assert_eq!(decoded.name, "sp");
+
+        let result = ActionBeginSavepointResult::new("sp-id");
+        assert_eq!(result.savepoint_id, Bytes::from_static(b"sp-id"));
+
+        for action in [EndSavepoint::Release, EndSavepoint::Rollback] {
+            let request = ActionEndSavepointRequest::new(result.savepoint_id.clone(), action);
+            assert_eq!(request.try_action().unwrap(), action);
+        }
+
+        let request = ActionEndSavepointRequest::new("sp-id", EndSavepoint::Unspecified);
+        let err = request.try_action().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Protocol error: invalid end savepoint action: 0"
+        );
+    }
+}
@@ -0,0 +1,683 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Serve Parquet and Arrow IPC files stored in an [`ObjectStore`]
+//!
+//! [`ObjectStoreFlightSource`] answers `GetFlightInfo`, `GetSchema` and
+//! `DoGet` requests for files in an [`ObjectStore`], reading only the byte
+//! ranges that are required:
+//!
+//! * Parquet files are read with [`ParquetObjectReader`], which fetches the
+//!   footer and then the column chunks of the selected row groups and columns
+//! * Arrow IPC files are read by fetching the footer, the dictionaries and
+//!   then each selected record batch
+//!
+//! An [`ObjectTicket`] identifies the file and the data to read from it, and
+//! is encoded as protobuf in the [`Ticket`] of a `DoGet` call. The
+//! [`FlightInfo`] returned by [`ObjectStoreFlightSource::get_flight_info`]
+//! contains one endpoint per Parquet row group, or per IPC record batch,
+//! allowing clients to read a file in parallel.
+//!
+//! Only the compression codecs enabled on the `parquet` crate can be read,
+//! see the `parquet` crate's features.
+//!
+//! # Example
+//! ```no_run
+//! # use std::sync::Arc;
+//! # use arrow_flight::{FlightData, Ticket};
+//! # use arrow_flight::encode::FlightDataEncoderBuilder;
+//! # use arrow_flight::error::Result;
+//! # use arrow_flight::store::ObjectStoreFlightSource;
+//! # use futures::stream::BoxStream;
+//! # use object_store::memory::InMemory;
+//! async fn do_get(
+//!     source: &ObjectStoreFlightSource,
+//!     ticket: Ticket,
+//! ) -> Result<BoxStream<'static, Result<FlightData>>> {
+//!     let batches = source.do_get(&ticket).await?;
+//!     // the schema is sent even if no batches are selected
+//!     let encoder = FlightDataEncoderBuilder::new()
+//!         .with_schema(batches.schema())
+//!         .build(batches);
+//!     Ok(Box::pin(encoder))
+//! }
+//! # let source = ObjectStoreFlightSource::new(Arc::new(InMemory::new()));
+//! ```
+//!
+//! [`ParquetObjectReader`]: parquet::arrow::async_reader::ParquetObjectReader
+
+use std::ops::Range;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use arrow_array::RecordBatch;
+use arrow_buffer::Buffer;
+use arrow_ipc::reader::{FileDecoder, read_footer_length};
+use arrow_ipc::{Block, MetadataVersion, root_as_footer};
+use arrow_schema::{Schema, SchemaRef};
+use bytes::Bytes;
+use futures::future::ready;
+use futures::stream::BoxStream;
+use futures::{Stream, StreamExt, TryStreamExt};
+use object_store::path::Path;
+use object_store::{ObjectStore, ObjectStoreExt};
+use parquet::arrow::ProjectionMask;
+use parquet::arrow::async_reader::{ParquetObjectReader, ParquetRecordBatchStreamBuilder};
+use prost::Message;
+
+use crate::error::{FlightError, Result};
+use crate::{FlightDescriptor, FlightEndpoint, FlightInfo, Ticket};
+
+/// The format of a file served by [`ObjectStoreFlightSource`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ObjectFormat {
+    /// An Apache Parquet file
+    Parquet,
+    /// An Arrow IPC file, sometimes called a Feather V2 file
+    Ipc,
+}
+
+impl ObjectFormat {
+    /// Returns the format of the file at `path` based on its extension, if known
+    ///
+    /// `.parquet` and `.parq` files are [`Self::Parquet`], `.arrow`, `.ipc`
+    /// and `.feather` files are [`Self::Ipc`]
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_ascii_lowercase().as_str() {
+            "parquet" | "parq" => Some(Self::Parquet),
+            "arrow" | "ipc" | "feather" => Some(Self::Ipc),
+            _ => None,
+        }
+    }
+}
+
+/// Identifies the data to read from a file in an [`ObjectStore`]
+///
+/// Encoded as protobuf in a [`Ticket`], see [`ObjectStoreFlightSource`]
+#[derive(Clone, PartialEq, Message)]
+pub struct ObjectTicket {
+    /// The path of the file in the store
+    #[prost(string, tag = "1")]
+    pub path: String,
+    /// The indexes of the Parquet row groups, or IPC record batches, to read.
+    /// If empty, the whole file is read
+    #[prost(uint64, repeated, tag = "2")]
+    pub row_groups: Vec<u64>,
+    /// The number of rows to skip, after selecting the row groups
+    #[prost(uint64, optional, tag = "3")]
+    pub offset: Option<u64>,
+    /// The maximum number of rows to return, after applying the offset
+    #[prost(uint64, optional, tag = "4")]
+    pub limit: Option<u64>,
+    /// The names of the top level columns to read, which are returned in the
+    /// order they appear in the file. If empty, all columns are read
+    #[prost(string, repeated, tag = "5")]
+    pub columns: Vec<String>,
+}
+
+impl ObjectTicket {
+    /// Create a ticket reading the whole file at `path`
+    pub fn new(path: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Only read the row groups, or IPC record batches, with the given indexes
+    pub fn with_row_groups(mut self, row_groups: impl IntoIterator<Item = u64>) -> Self {
+        self.row_groups = row_groups.into_iter().collect();
+        self
+    }
+
+    /// Skip the first `offset` selected rows
+    pub fn with_offset(mut self, offset: u64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Return at most `limit` rows
+    pub fn with_limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Only read the top level columns with the given names
+    pub fn with_columns(mut self, columns: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.columns = columns.into_iter().map(Into::into).collect();
+        self
+    }
+}
+
+impl From<ObjectTicket> for Ticket {
+    fn from(value: ObjectTicket) -> Self {
+        Ticket::new(value.encode_to_vec())
+    }
+}
+
+impl TryFrom<&Ticket> for ObjectTicket {
+    type Error = FlightError;
+
+    fn try_from(value: &Ticket) -> Result<Self> {
+        Self::decode(value.ticket.clone())
+            .map_err(|e| FlightError::DecodeError(format!("Invalid object ticket: {e}")))
+    }
+}
+
+/// Serves Parquet and Arrow IPC files from an [`ObjectStore`] as Flight streams
+///
+/// See the [module level documentation](self) for an example
+#[derive(Debug, Clone)]
+pub struct ObjectStoreFlightSource {
+    store: Arc<dyn ObjectStore>,
+    format: Option<ObjectFormat>,
+}
+
+impl ObjectStoreFlightSource {
+    /// Create a source serving the files in `store`
+    pub fn new(store: Arc<dyn ObjectStore>) -> Self {
+        Self {
+            store,
+            format: None,
+        }
+    }
+
+    /// Read all files as `format` instead of detecting the format from the
+    /// file extension with [`ObjectFormat::from_path`]
+    pub fn with_format(mut self, format: ObjectFormat) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Returns the [`Schema`] of the file at `path`
+    pub async fn get_schema(&self, path: &Path) -> Result<SchemaRef> {
+        match self.format(path)? {
+            ObjectFormat::Parquet => Ok(self.parquet_builder(path).await?.schema().clone()),
+            ObjectFormat::Ipc => Ok(self.ipc_footer(path).await?.schema),
+        }
+    }
+
+    /// Returns a [`FlightInfo`] for the file at `path`, with one
+    /// [`FlightEndpoint`] per Parquet row group or IPC record batch
+    ///
+    /// The number of records is only known for Parquet files
+    pub async fn get_flight_info(&self, path: &Path) -> Result<FlightInfo> {
+        let size = self.store.head(path).await.map_err(external)?.size;
+        let (schema, total_records, num_partitions) = match self.format(path)? {
+            ObjectFormat::Parquet => {
+                let builder = self.parquet_builder(path).await?;
+                let metadata = builder.metadata();
+                let total_records = metadata.file_metadata().num_rows();
+                let num_row_groups = metadata.num_row_groups();
+                (builder.schema().clone(), total_records, num_row_groups)
+            }
+            ObjectFormat::Ipc => {
+                let footer = self.ipc_footer(path).await?;
+                (footer.schema, -1, footer.record_batches.len())
+            }
+        };
+
+        let descriptor =
+            FlightDescriptor::new_path(path.parts().map(|p| p.as_ref().to_string()).collect());
+        let endpoints = (0..num_partitions as u64).map(|i| {
+            let ticket = ObjectTicket::new(path.as_ref()).with_row_groups([i]);
+            FlightEndpoint::new().with_ticket(ticket.into())
+        });
+
+        Ok(FlightInfo::new()
+            .try_with_schema(&schema)?
+            .with_descriptor(descriptor)
+            .with_endpoints(endpoints.collect())
+            .with_total_records(total_records)
+            .with_total_bytes(size as i64)
+            .with_ordered(true))
+    }
+
+    /// Reads the data identified by the [`ObjectTicket`] encoded in `ticket`
+    pub async fn do_get(&self, ticket: &Ticket) -> Result<ObjectRecordBatchStream> {
+        self.read(&ObjectTicket::try_from(ticket)?).await
+    }
+
+    /// Reads the data identified by `ticket`
+    pub async fn read(&self, ticket: &ObjectTicket) -> Result<ObjectRecordBatchStream> {
+        let path = Path::parse(&ticket.path).map_err(external)?;
+        let offset = ticket.offset.unwrap_or_default() as usize;
+        let limit = ticket.limit.map(|l| l as usize);
+        match self.format(&path)? {
+            ObjectFormat::Parquet => {
+                let mut builder = self.parquet_builder(&path).await?;
+                let num_row_groups = builder.metadata().num_row_groups();
+                if !ticket.row_groups.is_empty() {
+                    let row_groups = partitions(&ticket.row_groups, num_row_groups)?;
+                    builder = builder.with_row_groups(row_groups);
+                }
+                if !ticket.columns.is_empty() {
+                    let indices = column_indices(builder.schema(), &ticket.columns)?;
+                    let mask = ProjectionMask::roots(builder.parquet_schema(), indices);
+                    builder = builder.with_projection(mask);
+                }
+                builder = builder.with_offset(offset);
+                if let Some(limit) = limit {
+                    builder = builder.with_limit(limit);
+                }
+                let stream = builder.build().map_err(external)?;
+                Ok(ObjectRecordBatchStream {
+                    schema: stream.schema().clone(),
+                    inner: stream.map_err(external).boxed(),
+                })
+            }
+            ObjectFormat::Ipc => {
+                let footer = self.ipc_footer(&path).await?;
+                let blocks = match ticket.row_groups.is_empty() {
+                    true => footer.record_batches,
+                    false => partitions(&ticket.row_groups, footer.record_batches.len())?
+                        .into_iter()
+                        .map(|i| footer.record_batches[i])
+                        .collect(),
+                };
+
+                let mut schema = footer.schema.clone();
+                let mut decoder = FileDecoder::new(footer.schema, footer.version);
+                if !ticket.columns.is_empty() {
+                    let indices = column_indices(&schema, &ticket.columns)?;
+                    schema = Arc::new(schema.project(&indices)?);
+                    decoder = decoder.with_projection(indices);
+                }
+
+                let ranges: Vec<_> = footer.dictionaries.iter().map(block_range).collect();
+                let dictionaries = self
+                    .store
+                    .get_ranges(&path, &ranges)
+                    .await
+                    .map_err(external)?;
+                for (block, data) in footer.dictionaries.iter().zip(dictionaries) {
+                    decoder.read_dictionary(block, &Buffer::from(data))?;
+                }
+
+                let store = Arc::clone(&self.store);
+                let decoder = Arc::new(decoder);
+                let batches = futures::stream::iter(blocks)
+                    .then(move |block| {
+                        let store = Arc::clone(&store);
+                        let path = path.clone();
+                        async move {
+                            let data = store.get_range(&path, block_range(&block)).await;
+                            Ok::<_, FlightError>((block, data.map_err(external)?))
+                        }
+                    })
+                    .try_filter_map(move |(block, data)| {
+                        let batch = decoder.read_record_batch(&block, &Buffer::from(data));
+                        ready(batch.map_err(FlightError::from))
+                    });
+
+                Ok(ObjectRecordBatchStream {
+                    schema,
+                    inner: slice_batches(batches.boxed(), offset, limit),
+                })
+            }
+        }
+    }
+
+    fn format(&self, path: &Path) -> Result<ObjectFormat> {
+        self.format
+            .or_else(|| ObjectFormat::from_path(path))
+            .ok_or_else(|| {
+                FlightError::NotYetImplemented(format!("Unable to determine the format of {path}"))
+            })
+    }
+
+    async fn parquet_builder(
+        &self,
+        path: &Path,
+    ) -> Result<ParquetRecordBatchStreamBuilder<ParquetObjectReader>> {
+        let reader = ParquetObjectReader::new(Arc::clone(&self.store), path.clone());
+        ParquetRecordBatchStreamBuilder::new(reader)
+            .await
+            .map_err(external)
+    }
+
+    async fn ipc_footer(&self, path: &Path) -> Result<IpcFooter> {
+        let size = self.store.head(path).await.map_err(external)?.size;
+        let trailer_start = size
+            .checked_sub(10)
+            .ok_or_else(|| FlightError::DecodeError(format!("{path} is not an Arrow IPC file")))?;
+        let trailer = self
+            .store
+            .get_range(path, trailer_start..size)
+            .await
+            .map_err(external)?;
+        let footer_len = read_footer_length(trailer[..].try_into().unwrap())? as u64;
+        let footer_start = trailer_start.checked_sub(footer_len).ok_or_else(|| {
+            FlightError::DecodeError(format!("Invalid footer length {footer_len} in {path}"))
+        })?;
+        let data = self
+            .store
+            .get_range(path, footer_start..trailer_start)
+            .await
+            .map_err(external)?;
+        IpcFooter::try_new(&data)
+    }
+}
+
+/// A stream of [`RecordBatch`]es read by [`ObjectStoreFlightSource`]
+pub struct ObjectRecordBatchStream {
+    schema: SchemaRef,
+    inner: BoxStream<'static, Result<RecordBatch>>,
+}
+
+impl std::fmt::Debug for ObjectRecordBatchStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ObjectRecordBatchStream")
+            .field("schema", &self.schema)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ObjectRecordBatchStream {
+    /// Returns the schema of the [`RecordBatch`]es in this stream
+    pub fn schema(&self) -> SchemaRef {
+        Arc::clone(&self.schema)
+    }
+}
+
+impl Stream for ObjectRecordBatchStream {
+    type Item = Result<RecordBatch>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.poll_next_unpin(cx)
+    }
+}
+
+/// The contents of an IPC file footer needed to read its record batches
+struct IpcFooter {
+    schema: SchemaRef,
+    version: MetadataVersion,
+    dictionaries: Vec<Block>,
+    record_batches: Vec<Block>,
+}
+
+impl IpcFooter {
+    fn try_new(data: &Bytes) -> Result<Self> {
+        let footer = root_as_footer(data)
+            .map_err(|e| FlightError::DecodeError(format!("Unable to get root as footer: {e}")))?;
+        let schema = footer
+            .schema()
+            .ok_or_else(|| FlightError::DecodeError("IPC footer is missing a schema".into()))?;
+        let record_batches = footer.recordBatches().ok_or_else(|| {
+            FlightError::DecodeError("IPC footer is missing record batches".into())
+        })?;
+        Ok(Self {
+            schema: Arc::new(arrow_ipc::convert::fb_to_schema(schema)),
+            version: footer.version(),
+            dictionaries: footer.dictionaries().iter().flatten().copied().collect(),
+            record_batches: record_batches.iter().copied().collect(),
+        })
+    }
+}
+
+/// Returns the byte range of the IPC message described by `block`
+fn block_range(block: &Block) -> Range<u64> {
+    let start = block.offset() as u64;
+    let len = block.metaDataLength() as u64 + block.bodyLength() as u64;
+    start..start + len
+}
+
+/// Validates the partition indexes of a ticket, of which there are `len`
+fn partitions(indexes: &[u64], len: usize) -> Result<Vec<usize>> {
+    indexes
+        .iter()
+        .map(|i| {
+            usize::try_from(*i)
+                .ok()
+                .filter(|i| *i < len)
+                .ok_or_else(|| {
+                    FlightError::protocol(format!("Row group {i} out of bounds, file has {len}"))
+                })
+        })
+        .collect()
+}
+
+/// Returns the sorted indexes of the top level `columns` in `schema`
+fn column_indices(schema: &Schema, columns: &[String]) -> Result<Vec<usize>> {
+    let mut indices = columns
+        .iter()
+        .map(|c| schema.index_of(c))
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    indices.sort_unstable();
+    indices.dedup();
+    Ok(indices)
+}
+
+/// Skips the first `offset` rows of `batches` and returns at most `limit` rows
+fn slice_batches(
+    batches: BoxStream<'static, Result<RecordBatch>>,
+    offset: usize,
+    limit: Option<usize>,
+) -> BoxStream<'static, Result<RecordBatch>> {
+    if offset == 0 && limit.is_none() {
+        return batches;
+    }
+    batches
+        .scan((offset, limit), |(offset, limit), batch| {
+            if *limit == Some(0) {
+                return ready(None);
+            }
+            let batch = match batch {
+                Ok(batch) => batch,
+                Err(e) => return ready(Some(Err(e))),
+            };
+            let skip = batch.num_rows().min(*offset);
+            *offset -= skip;
+            let mut len = batch.num_rows() - skip;
+            if let Some(limit) = limit {
+                len = len.min(*limit);
+                *limit -= len;
+            }
+            ready(Some(Ok(batch.slice(skip, len))))
+        })
+        .try_filter(|batch| ready(batch.num_rows() > 0))
+        .boxed()
+}
+
+fn external(e: impl std::error::Error + Send + Sync + 'static) -> FlightError {
+    FlightError::ExternalError(Box::new(e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::types::Int32Type;
+    use arrow_array::{ArrayRef, DictionaryArray, Int64Array, StringArray};
+    use arrow_ipc::writer::FileWriter;
+    use arrow_select::concat::concat_batches;
+    use object_store::memory::InMemory;
+    use parquet::arrow::ArrowWriter;
+    use parquet::file::properties::WriterProperties;
+
+    fn batch(start: i64, len: usize) -> RecordBatch {
+        let ids: Int64Array = (start..start + len as i64).collect();
+        let names: StringArray = (0..len).map(|i| Some(format!("name{i}"))).collect();
+        let tags: DictionaryArray<Int32Type> = (0..len)
+            .map(|i| if i % 2 == 0 { "even" } else { "odd" })
+            .collect();
+        RecordBatch::try_from_iter([
+            ("id", Arc::new(ids) as ArrayRef),
+            ("name", Arc::new(names) as ArrayRef),
+            ("tag", Arc::new(tags) as ArrayRef),
+        ])
+        .unwrap()
+    }
+
+    /// Writes a Parquet and an IPC file with three batches of five rows
+    async fn source() -> (ObjectStoreFlightSource, Vec<RecordBatch>) {
+        let batches: Vec<_> = (0..3).map(|i| batch(i * 5, 5)).collect();
+        let schema = batches[0].schema();
+
+        let props = WriterProperties::builder()
+            .set_max_row_group_row_count(Some(5))
+            .build();
+        let mut parquet = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut parquet, schema.clone(), Some(props)).unwrap();
+        for batch in &batches {
+            writer.write(batch).unwrap();
+        }
+        writer.close().unwrap();
+
+        let mut ipc = Vec::new();
+        let mut writer = FileWriter::try_new(&mut ipc, &schema).unwrap();
+        for batch in &batches {
+            writer.write(batch).unwrap();
+        }
+        writer.finish().unwrap();
+        drop(writer);
+
+        let store = Arc::new(InMemory::new());
+        for (path, data) in [("data/t.parquet", parquet), ("data/t.arrow", ipc)] {
+            store.put(&Path::from(path), data.into()).await.unwrap();
+        }
+        (ObjectStoreFlightSource::new(store), batches)
+    }
+
+    async fn read(source: &ObjectStoreFlightSource, ticket: ObjectTicket) -> RecordBatch {
+        let stream = source.do_get(&ticket.into()).await.unwrap();
+        let schema = stream.schema();
+        let batches: Vec<_> = stream.try_collect().await.unwrap();
+        concat_batches(&schema, &batches).unwrap()
+    }
+
+    #[test]
+    fn test_format_from_path() {
+        let format = |p: &str| ObjectFormat::from_path(&Path::from(p));
+        assert_eq!(format("a/b.parquet"), Some(ObjectFormat::Parquet));
+        assert_eq!(format("a/b.ARROW"), Some(ObjectFormat::Ipc));
+        assert_eq!(format("b.feather"), Some(ObjectFormat::Ipc));
+        assert_eq!(format("b.csv"), None);
+        assert_eq!(format("b"), None);
+    }
+
+    #[test]
+    fn test_ticket_round_trip() {
+        let ticket = ObjectTicket::new("a/b.parquet")
+            .with_row_groups([1, 3])
+            .with_offset(2)
+            .with_limit(10)
+            .with_columns(["id"]);
+        let encoded = Ticket::from(ticket.clone());
+        assert_eq!(ObjectTicket::try_from(&encoded).unwrap(), ticket);
+
+        let err = ObjectTicket::try_from(&Ticket::new("\u{ff}")).unwrap_err();
+        assert!(err.to_string().contains("Invalid object ticket"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn test_schema_and_flight_info() {
+        let (source, batches) = source().await;
+        let schema = batches[0].schema();
+
+        for path in ["data/t.parquet", "data/t.arrow"] {
+            let path = Path::from(path);
+            let actual = source.get_schema(&path).await.unwrap();
+            assert_eq!(actual.fields(), schema.fields());
+
+            let info = source.get_flight_info(&path).await.unwrap();
+            assert_eq!(info.endpoint.len(), 3);
+            assert_eq!(
+                info.flight_descriptor.unwrap().path,
+                vec!["data".to_string(), path.filename().unwrap().to_string()]
+            );
+            let ticket = info.endpoint[2].ticket.as_ref().unwrap();
+            assert_eq!(
+                ObjectTicket::try_from(ticket).unwrap(),
+                ObjectTicket::new(path.as_ref()).with_row_groups([2])
+            );
+            assert!(info.total_bytes > 0);
+        }
+
+        let info = source
+            .get_flight_info(&Path::from("data/t.parquet"))
+            .await
+            .unwrap();
+        assert_eq!(info.total_records, 15);
+    }
+
+    #[tokio::test]
+    async fn test_do_get() {
+        let (source, batches) = source().await;
+        let expected = concat_batches(&batches[0].schema(), &batches).unwrap();
+
+        for path in ["data/t.parquet", "data/t.arrow"] {
+            let all = read(&source, ObjectTicket::new(path)).await;
+            assert_eq!(all.columns()[..2], expected.columns()[..2]);
+            assert_eq!(all.num_rows(), 15);
+
+            let ticket = ObjectTicket::new(path)
+                .with_row_groups([2, 0])
+                .with_columns(["name", "id"]);
+            let actual = read(&source, ticket).await;
+            // columns are returned in file order
+            assert_eq!(actual.schema().field(0).name(), "id");
+            let ids = actual.column(0).as_any().downcast_ref::<Int64Array>();
+            let ids: Vec<_> = ids.unwrap().values().to_vec();
+            // row groups are read in the requested order
+            assert_eq!(ids, (10..15).chain(0..5).collect::<Vec<_>>());
+
+            let ticket = ObjectTicket::new(path)
+                .with_offset(3)
+                .with_limit(9)
+                .with_columns(["id"]);
+            let actual = read(&source, ticket).await;
+            assert_eq!(actual.num_columns(), 1);
+            assert_eq!(
+                actual.column(0).as_ref(),
+                expected.column(0).slice(3, 9).as_ref()
+            );
+
+            let ticket = ObjectTicket::new(path).with_offset(20);
+            assert_eq!(read(&source, ticket).await.num_rows(), 0);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_do_get_errors() {
+        let (source, _) = source().await;
+
+        let ticket = ObjectTicket::new("data/t.parquet").with_row_groups([3]);
+        let err = source.do_get(&ticket.into()).await.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Protocol error: Row group 3 out of bounds, file has 3"
+        );
+
+        let ticket = ObjectTicket::new("data/t.arrow").with_columns(["missing"]);
+        let err = source.do_get(&ticket.into()).await.unwrap_err();
+        assert!(err.to_string().contains("missing"), "{err}");
+
+        let ticket = ObjectTicket::new("data/t.csv");
+        let err = source.do_get(&ticket.into()).await.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Not yet implemented: Unable to determine the format of data/t.csv"
+        );
+
+        // the format can be overridden
+        let source = source.with_format(ObjectFormat::Ipc);
+        let ticket = ObjectTicket::new("data/t.parquet");
+        let err = source.do_get(&ticket.into()).await.unwrap_err();
+        assert!(err.to_string().contains("footer"), "{err}");
+    }
+}
@@ -17,11 +17,11 @@
 
 //! [`FallibleRequestStream`] and [`FallibleTonicResponseStream`] adapters
 
+#[cfg(feature = "grpc")]
 use crate::error::FlightError;
-use futures::{
-    FutureExt, Stream, StreamExt,
-    channel::oneshot::{Receiver, Sender},
-};
+#[cfg(feature = "grpc")]
+use futures::{FutureExt, channel::oneshot::Receiver};
+use futures::{Stream, StreamExt, channel::oneshot::Sender};
 use std::pin::Pin;
 use std::task::{Poll, ready};
 
@@ -92,6 +92,7 @@ impl<T, E> Stream for FallibleRequestStream<T, E> {
 ///
 /// [`FlightClient::do_put`]: crate::FlightClient::do_put
 /// [`FlightClient::do_exchange`]: crate::FlightClient::do_exchange
+#[cfg(feature = "grpc")]
 pub(crate) struct FallibleTonicResponseStream<T> {
     /// Receiver for FlightError
     receiver: Receiver<FlightError>,
@@ -99,6 +100,7 @@ pub(crate) struct FallibleTonicResponseStream<T> {
     response_stream: Pin<Box<dyn Stream<Item = Result<T, tonic::Status>> + Send + 'static>>,
 }
 
+#[cfg(feature = "grpc")]
 impl<T> FallibleTonicResponseStream<T> {
     pub(crate) fn new(
         receiver: Receiver<FlightError>,
@@ -111,6 +113,7 @@ impl<T> FallibleTonicResponseStream<T> {
     }
 }
 
+#[cfg(feature = "grpc")]
 impl<T> Stream for FallibleTonicResponseStream<T> {
     type Item = Result<T, FlightError>;
 
@@ -17,7 +17,7 @@
 
 //! Utilities to assist with reading and writing Arrow data as Flight messages
 
-use crate::{FlightData, SchemaAsIpc};
+use crate::FlightData;
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -29,6 +29,9 @@ use arrow_ipc::{reader, root_as_message, writer, writer::IpcWriteOptions};
 use arrow_schema::{ArrowError, Schema, SchemaRef};
 
 /// Convert a slice of wire protocol `FlightData`s into a vector of `RecordBatch`es
+///
+/// The first `FlightData` must contain the schema. Dictionary batches, including
+/// delta dictionary batches, are applied to the record batches that follow them.
 pub fn flight_data_to_batches(flight_data: &[FlightData]) -> Result<Vec<RecordBatch>, ArrowError> {
     let schema = flight_data.first().ok_or_else(|| {
         ArrowError::CastError("Need at least one FlightData for schema".to_string())
@@ -43,15 +46,33 @@ pub fn flight_data_to_batches(flight_data: &[FlightData]) -> Result<Vec<RecordBa
     let schema = Arc::new(schema);
 
     let mut batches = vec![];
-    let dictionaries_by_id = HashMap::new();
+    let mut dictionaries_by_id = HashMap::new();
     for datum in flight_data[1..].iter() {
-        let batch = flight_data_to_arrow_batch(datum, schema.clone(), &dictionaries_by_id)?;
-        batches.push(batch);
+        let message = root_as_message(&datum.data_header[..]).map_err(|err| {
+            ArrowError::ParseError(format!("Unable to get root as message: {err:?}"))
+        })?;
+        match message.header_as_dictionary_batch() {
+            Some(dictionary_batch) => reader::read_dictionary(
+                &Buffer::from(datum.data_body.clone()),
+                dictionary_batch,
+                &schema,
+                &mut dictionaries_by_id,
+                &message.version(),
+            )?,
+            None => {
+                let batch = flight_data_to_arrow_batch(datum, schema.clone(), &dictionaries_by_id)?;
+                batches.push(batch);
+            }
+        }
     }
     Ok(batches)
 }
 
 /// Convert `FlightData` (with supplied schema and dictionaries) to an arrow `RecordBatch`.
+///
+/// The arrays of the returned batch reference the memory of `data.data_body`
+/// without copying it, unless a buffer is not sufficiently aligned for its
+/// data type, in which case that buffer is copied.
 pub fn flight_data_to_arrow_batch(
     data: &FlightData,
     schema: SchemaRef,
@@ -70,7 +91,7 @@ pub fn flight_data_to_arrow_batch(
         })
         .map(|batch| {
             reader::read_record_batch(
-                &Buffer::from(data.data_body.as_ref()),
+                &Buffer::from(data.data_body.clone()),
                 batch,
                 schema,
                 dictionaries_by_id,
@@ -81,36 +102,52 @@ pub fn flight_data_to_arrow_batch(
 }
 
 /// Convert `RecordBatch`es to wire protocol `FlightData`s
+///
+/// See [`batches_to_flight_data_with_options`] for details
 pub fn batches_to_flight_data(
     schema: &Schema,
     batches: Vec<RecordBatch>,
 ) -> Result<Vec<FlightData>, ArrowError> {
-    let options = IpcWriteOptions::default();
-    let schema_flight_data: FlightData = SchemaAsIpc::new(schema, &options).into();
-    let mut dictionaries = vec![];
-    let mut flight_data = vec![];
+    batches_to_flight_data_with_options(schema, batches, &IpcWriteOptions::default())
+}
 
+/// Convert `RecordBatch`es to wire protocol `FlightData`s using the provided
+/// [`IpcWriteOptions`]
+///
+/// Dictionary encoded columns are sent as such. The dictionaries are tracked
+/// across batches, and a dictionary batch is only sent before the first batch
+/// using a dictionary and whenever its values change. With
+/// [`DictionaryHandling::Delta`](writer::DictionaryHandling::Delta) a
+/// dictionary whose values extend those previously sent is sent as a delta
+/// containing only the new values.
+pub fn batches_to_flight_data_with_options(
+    schema: &Schema,
+    batches: Vec<RecordBatch>,
+    options: &IpcWriteOptions,
+) -> Result<Vec<FlightData>, ArrowError> {
     let data_gen = writer::IpcDataGenerator::default();
     let mut dictionary_tracker = writer::DictionaryTracker::new(false);
     let mut compression_context = CompressionContext::default();
 
+    // encoding the schema assigns the dictionary ids used to encode the batches
+    let schema_flight_data: FlightData = data_gen
+        .schema_to_bytes_with_dictionary_tracker(schema, &mut dictionary_tracker, options)
+        .into();
+    let mut flight_data = vec![schema_flight_data];
+
     for batch in batches.iter() {
         let (encoded_dictionaries, encoded_batch) = data_gen.encode(
             batch,
             &mut dictionary_tracker,
-            &options,
+            options,
             &mut compression_context,
         )?;
 
-        dictionaries.extend(encoded_dictionaries.into_iter().map(Into::into));
+        // dictionaries must precede the batch using them, as they may be
+        // replaced by a later batch
+        flight_data.extend(encoded_dictionaries.into_iter().map(Into::into));
         flight_data.push(encoded_batch.into());
     }
 
-    let mut stream = Vec::with_capacity(1 + dictionaries.len() + flight_data.len());
-
-    stream.push(schema_flight_data);
-    stream.extend(dictionaries);
-    stream.extend(flight_data);
-    let flight_data = stream;
     Ok(flight_data)
 }
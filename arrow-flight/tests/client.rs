@@ -22,10 +22,15 @@ mod common;
 use crate::common::fixture::TestFixture;
 use arrow_array::{RecordBatch, UInt64Array};
 use arrow_flight::{
-    Action, ActionType, CancelFlightInfoRequest, CancelFlightInfoResult, CancelStatus, Criteria,
-    Empty, FlightClient, FlightData, FlightDescriptor, FlightEndpoint, FlightInfo,
-    HandshakeRequest, HandshakeResponse, PollInfo, PutResult, RenewFlightEndpointRequest, Ticket,
-    decode::FlightRecordBatchStream, encode::FlightDataEncoderBuilder, error::FlightError,
+    Action, ActionType, CancelFlightInfoRequest, CancelFlightInfoResult, CancelStatus,
+    CloseSessionRequest, CloseSessionResult, Criteria, Empty, FlightClient, FlightData,
+    FlightDescriptor, FlightEndpoint, FlightInfo, GetSessionOptionsRequest,
+    GetSessionOptionsResult, HandshakeRequest, HandshakeResponse, PollInfo, PutResult,
+    RenewFlightEndpointRequest, SessionOptionValue, SetSessionOptionsRequest,
+    SetSessionOptionsResult, Ticket, close_session_result,
+    decode::FlightRecordBatchStream,
+    encode::FlightDataEncoderBuilder,
+    error::{ErrorDetails, FlightError},
 };
 use arrow_schema::{DataType, Field, Schema};
 use bytes::Bytes;
@@ -291,6 +296,38 @@ async fn test_do_get_error_in_record_batch_stream() {
     .await;
 }
 
+#[tokio::test]
+async fn test_do_get_error_details() {
+    do_test(|test_server, mut client| async move {
+        let batch = RecordBatch::try_from_iter(vec![(
+            "col",
+            Arc::new(UInt64Array::from_iter([1, 2, 3, 4])) as _,
+        )])
+        .unwrap();
+
+        let details = ErrorDetails::new()
+            .with_retryable(true)
+            .with_partial_results(true)
+            .with_metadata("node", "worker-1");
+        let e = details
+            .clone()
+            .into_status(tonic::Code::Unavailable, "worker lost");
+        test_server.set_do_get_response(vec![Ok(batch), Err(e.clone())]);
+
+        let response: Result<Vec<_>, FlightError> = client
+            .do_get(Ticket::new("ticket"))
+            .await
+            .expect("error making request")
+            .try_collect()
+            .await;
+
+        let response = response.unwrap_err();
+        assert_eq!(response.details(), Some(details));
+        expect_status(response, e);
+    })
+    .await;
+}
+
 #[tokio::test]
 async fn test_do_put() {
     do_test(|test_server, mut client| async move {
@@ -513,6 +550,64 @@ async fn test_do_exchange() {
     .await;
 }
 
+#[tokio::test]
+async fn test_exchange() {
+    do_test(|test_server, mut client| async move {
+        client.add_header("foo-header", "bar-header-value").unwrap();
+
+        let output_flight_data = test_flight_data2().await;
+        test_server
+            .set_do_exchange_response(output_flight_data.clone().into_iter().map(Ok).collect());
+
+        let batch = RecordBatch::try_from_iter(vec![(
+            "col",
+            Arc::new(UInt64Array::from_iter([1, 2, 3, 4])) as _,
+        )])
+        .unwrap();
+
+        let descriptor = FlightDescriptor::new_cmd("exchange");
+        let (mut sender, response) =
+            client.exchange(descriptor.clone(), FlightDataEncoderBuilder::new(), 1);
+
+        // the test server only responds once the whole request has been
+        // received, so the batches must be sent concurrently
+        let input = vec![batch.clone(), batch.slice(1, 2)];
+        let to_send = input.clone();
+        let send = tokio::spawn(async move {
+            for batch in to_send {
+                sender.send(batch).await.unwrap();
+            }
+        });
+
+        let response: Vec<_> = response
+            .await
+            .expect("error making request")
+            .try_collect()
+            .await
+            .expect("Error streaming data");
+        send.await.unwrap();
+
+        let expected_stream = futures::stream::iter(output_flight_data).map(Ok);
+        let expected_batches: Vec<_> =
+            FlightRecordBatchStream::new_from_flight_data(expected_stream)
+                .try_collect()
+                .await
+                .unwrap();
+        assert_eq!(response, expected_batches);
+
+        let request = test_server.take_do_exchange_request().unwrap();
+        assert_eq!(request[0].flight_descriptor, Some(descriptor));
+        let received: Vec<_> =
+            FlightRecordBatchStream::new_from_flight_data(futures::stream::iter(request).map(Ok))
+                .try_collect()
+                .await
+                .unwrap();
+        assert_eq!(received, input);
+        ensure_metadata(&client, &test_server);
+    })
+    .await;
+}
+
 #[tokio::test]
 async fn test_do_exchange_error() {
     do_test(|test_server, mut client| async move {
@@ -1079,6 +1174,198 @@ async fn test_renew_flight_endpoint_error_no_response() {
     .await;
 }
 
+#[tokio::test]
+async fn test_set_session_options() {
+    do_test(|test_server, mut client| async move {
+        client.add_header("foo-header", "bar-header-value").unwrap();
+
+        let expected_response = SetSessionOptionsResult::new([(
+            "unknown",
+            arrow_flight::set_session_options_result::ErrorValue::InvalidName,
+        )]);
+        let response = expected_response.encode_to_vec();
+        let response = Ok(arrow_flight::Result::new(response));
+        test_server.set_do_action_response(vec![response]);
+
+        let request = SetSessionOptionsRequest::new([
+            ("catalog", SessionOptionValue::from("main")),
+            ("unknown", SessionOptionValue::from(1_i64)),
+        ]);
+        let actual_response = client
+            .set_session_options(request.clone())
+            .await
+            .expect("error making request");
+
+        let expected_request = Action::new("SetSessionOptions", request.encode_to_vec());
+        assert_eq!(actual_response, expected_response);
+        assert_eq!(test_server.take_do_action_request(), Some(expected_request));
+        ensure_metadata(&client, &test_server);
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_get_session_options() {
+    do_test(|test_server, mut client| async move {
+        client.add_header("foo-header", "bar-header-value").unwrap();
+
+        let expected_response = GetSessionOptionsResult::new([
+            ("catalog", SessionOptionValue::from("main")),
+            (
+                "search_path",
+                SessionOptionValue::from(vec!["a".to_string(), "b".to_string()]),
+            ),
+        ]);
+        let response = expected_response.encode_to_vec();
+        let response = Ok(arrow_flight::Result::new(response));
+        test_server.set_do_action_response(vec![response]);
+
+        let request = GetSessionOptionsRequest {};
+        let actual_response = client
+            .get_session_options(request)
+            .await
+            .expect("error making request");
+
+        let expected_request = Action::new("GetSessionOptions", request.encode_to_vec());
+        assert_eq!(actual_response, expected_response);
+        assert_eq!(test_server.take_do_action_request(), Some(expected_request));
+        ensure_metadata(&client, &test_server);
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_close_session() {
+    do_test(|test_server, mut client| async move {
+        client.add_header("foo-header", "bar-header-value").unwrap();
+
+        let expected_response = CloseSessionResult::new(close_session_result::Status::Closed);
+        let response = expected_response.encode_to_vec();
+        let response = Ok(arrow_flight::Result::new(response));
+        test_server.set_do_action_response(vec![response]);
+
+        let request = CloseSessionRequest {};
+        let actual_response = client
+            .close_session(request)
+            .await
+            .expect("error making request");
+
+        let expected_request = Action::new("CloseSession", request.encode_to_vec());
+        assert_eq!(actual_response, expected_response);
+        assert_eq!(test_server.take_do_action_request(), Some(expected_request));
+        ensure_metadata(&client, &test_server);
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_close_session_error_no_response() {
+    do_test(|test_server, mut client| async move {
+        client.add_header("foo-header", "bar-header-value").unwrap();
+
+        test_server.set_do_action_response(vec![]);
+
+        let request = CloseSessionRequest {};
+        let err = client.close_session(request).await.unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Protocol error: Received no response for close_session call"
+        );
+        let expected_request = Action::new("CloseSession", request.encode_to_vec());
+        assert_eq!(test_server.take_do_action_request(), Some(expected_request));
+        ensure_metadata(&client, &test_server);
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_do_get_endpoints() {
+    let test_server = TestFlightServer::new();
+    let fixture = TestFixture::new(test_server.service()).await;
+    let other_server = TestFlightServer::new();
+    let other_fixture = TestFixture::new(other_server.service()).await;
+
+    let mut client = FlightClient::new(fixture.channel().await);
+    client.add_header("foo-header", "bar-header-value").unwrap();
+
+    let batch = |values: Vec<u64>| {
+        RecordBatch::try_from_iter(vec![("col", Arc::new(UInt64Array::from(values)) as _)]).unwrap()
+    };
+    test_server.set_do_get_response(vec![Ok(batch(vec![1, 2])), Ok(batch(vec![3]))]);
+    other_server.set_do_get_response(vec![Ok(batch(vec![4, 5]))]);
+
+    let info = FlightInfo::new()
+        .with_endpoint(FlightEndpoint::new().with_ticket(Ticket::new("first")))
+        .with_endpoint(
+            FlightEndpoint::new()
+                .with_ticket(Ticket::new("second"))
+                .with_location("grpc+tcp://127.0.0.1:1")
+                .with_location(format!("grpc+tcp://{}", other_fixture.addr)),
+        )
+        .with_ordered(true);
+
+    let batches: Vec<_> = client
+        .do_get_endpoints(info, 2)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(
+        batches,
+        vec![batch(vec![1, 2]), batch(vec![3]), batch(vec![4, 5])]
+    );
+
+    assert_eq!(
+        test_server.take_do_get_request(),
+        Some(Ticket::new("first"))
+    );
+    assert_eq!(
+        other_server.take_do_get_request(),
+        Some(Ticket::new("second"))
+    );
+    ensure_metadata(&client, &test_server);
+    ensure_metadata(&client, &other_server);
+
+    fixture.shutdown_and_wait().await;
+    other_fixture.shutdown_and_wait().await;
+}
+
+#[tokio::test]
+async fn test_do_get_endpoints_unordered() {
+    do_test(|test_server, client| async move {
+        let values = UInt64Array::from_iter([1, 2, 3]);
+        let batch = RecordBatch::try_from_iter(vec![("col", Arc::new(values) as _)]).unwrap();
+        test_server.set_do_get_response(vec![Ok(batch.clone())]);
+
+        let info = FlightInfo::new().with_endpoint(
+            FlightEndpoint::new()
+                .with_ticket(Ticket::new("ticket"))
+                .with_location(arrow_flight::client::REUSE_CONNECTION_URI),
+        );
+        let batches: Vec<_> = client
+            .do_get_endpoints(info, 2)
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(batches, vec![batch]);
+
+        // an endpoint without a ticket can not be fetched
+        let info = FlightInfo::new()
+            .with_endpoint(FlightEndpoint::new().with_ticket(Ticket::new("ticket")))
+            .with_endpoint(FlightEndpoint::new());
+        let err = client
+            .do_get_endpoints(info, 2)
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Protocol error: FlightEndpoint is missing a ticket"
+        );
+    })
+    .await;
+}
+
 async fn test_flight_data() -> Vec<FlightData> {
     let batch = RecordBatch::try_from_iter(vec![(
         "col",
@@ -15,7 +15,10 @@
 // specific language governing permissions and limitations
 // under the License.
 
+#[cfg(feature = "grpc")]
 pub mod fixture;
+#[cfg(feature = "grpc")]
 pub mod server;
+#[cfg(feature = "grpc")]
 pub mod trailers_layer;
 pub mod utils;
@@ -19,15 +19,21 @@
 
 use std::{collections::HashMap, sync::Arc};
 
-use arrow_array::{ArrayRef, RecordBatch};
+use arrow_array::{Array, ArrayRef, Int64Array, RecordBatch, RecordBatchOptions};
 use arrow_cast::pretty::pretty_format_batches;
 use arrow_flight::FlightDescriptor;
 use arrow_flight::flight_descriptor::DescriptorType;
+use arrow_flight::utils::{
+    batches_to_flight_data, batches_to_flight_data_with_options, flight_data_to_arrow_batch,
+    flight_data_to_batches,
+};
 use arrow_flight::{
     decode::{DecodedPayload, FlightDataDecoder, FlightRecordBatchStream},
     encode::FlightDataEncoderBuilder,
     error::FlightError,
 };
+use arrow_ipc::reader::IpcReadOptions;
+use arrow_ipc::writer::{DictionaryHandling, IpcWriteOptions};
 use arrow_schema::{DataType, Field, Fields, Schema, SchemaRef};
 use bytes::Bytes;
 use futures::{StreamExt, TryStreamExt};
@@ -97,6 +103,17 @@ async fn test_primitive_empty() {
     roundtrip(vec![batch, empty]).await;
 }
 
+#[tokio::test]
+async fn test_no_columns() {
+    let schema = Arc::new(Schema::empty());
+    let batch = |rows| {
+        let options = RecordBatchOptions::new().with_row_count(Some(rows));
+        RecordBatch::try_new_with_options(schema.clone(), vec![], &options).unwrap()
+    };
+
+    roundtrip(vec![batch(10), batch(0), batch(3)]).await;
+}
+
 #[tokio::test]
 async fn test_dictionary_one() {
     roundtrip_dictionary(vec![make_dictionary_batch(5)]).await;
@@ -414,6 +431,124 @@ async fn test_mismatched_schema_message() {
     .await;
 }
 
+#[tokio::test]
+async fn test_decoder_read_options() {
+    async fn do_test(batch: RecordBatch, options: IpcReadOptions, expected: &str) {
+        let encode_stream =
+            FlightDataEncoderBuilder::default().build(futures::stream::iter(vec![Ok(batch)]));
+        let decode_stream = FlightRecordBatchStream::new(
+            FlightDataDecoder::new(encode_stream).with_read_options(options),
+        );
+        let result: Result<Vec<_>, FlightError> = decode_stream.try_collect().await;
+
+        let err = result.unwrap_err().to_string();
+        assert!(
+            err.contains(expected),
+            "could not find '{expected}' in '{err}'"
+        );
+    }
+
+    do_test(
+        make_primitive_batch(5),
+        IpcReadOptions::new().with_max_batch_rows(4),
+        "Batch of 5 rows exceeds the maximum allowed number of rows of 4",
+    )
+    .await;
+
+    do_test(
+        make_primitive_batch(200),
+        IpcReadOptions::new().with_max_buffer_size(1024),
+        "Message body of",
+    )
+    .await;
+
+    let list = Field::new_list("l", Field::new_list_field(DataType::Int32, true), true);
+    let schema = Arc::new(Schema::new(vec![list]));
+    do_test(
+        RecordBatch::new_empty(schema),
+        IpcReadOptions::new().with_max_nesting_depth(1),
+        "Field l with nesting depth 2 exceeds the maximum allowed nesting depth of 1",
+    )
+    .await;
+
+    // within limits
+    let batch = make_primitive_batch(5);
+    let encode_stream =
+        FlightDataEncoderBuilder::default().build(futures::stream::iter(vec![Ok(batch.clone())]));
+    let options = IpcReadOptions::new()
+        .with_max_batch_rows(5)
+        .with_max_buffer_size(1024)
+        .with_max_nesting_depth(1);
+    let decode_stream = FlightRecordBatchStream::new(
+        FlightDataDecoder::new(encode_stream).with_read_options(options),
+    );
+    let output: Vec<_> = decode_stream.try_collect().await.unwrap();
+    assert_eq!(output, vec![batch]);
+}
+
+#[tokio::test]
+async fn test_utils_dictionary_roundtrip() {
+    // the dictionaries of the later batches extend, and then replace, the
+    // dictionary of the first batch
+    let input = vec![
+        make_dictionary_batch(5),
+        make_dictionary_batch(10),
+        make_dictionary_batch(3).slice(1, 2),
+    ];
+    let schema = input[0].schema();
+
+    for handling in [DictionaryHandling::Resend, DictionaryHandling::Delta] {
+        let options = IpcWriteOptions::default().with_dictionary_handling(handling);
+        let flight_data =
+            batches_to_flight_data_with_options(&schema, input.clone(), &options).unwrap();
+
+        let output = flight_data_to_batches(&flight_data).unwrap();
+        assert_eq!(output, input);
+
+        let output: Vec<_> = FlightRecordBatchStream::new_from_flight_data(
+            futures::stream::iter(flight_data).map(Ok),
+        )
+        .try_collect()
+        .await
+        .unwrap();
+        assert_eq!(output, input);
+    }
+
+    let flight_data = batches_to_flight_data(&schema, input.clone()).unwrap();
+    assert_eq!(flight_data_to_batches(&flight_data).unwrap(), input);
+}
+
+#[tokio::test]
+async fn test_zero_copy_decode() {
+    let values = Int64Array::from_iter_values(0..1024);
+    let input = RecordBatch::try_from_iter(vec![("a", Arc::new(values) as ArrayRef)]).unwrap();
+
+    let flight_data: Vec<_> = FlightDataEncoderBuilder::default()
+        .build(futures::stream::iter(vec![Ok(input.clone())]))
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(flight_data.len(), 2);
+    let body = flight_data[1].data_body.as_ptr_range();
+    let references_body = |batch: &RecordBatch| {
+        let values = batch.column(0).to_data().buffers()[0].as_ptr();
+        body.contains(&values)
+    };
+
+    let decoded =
+        flight_data_to_arrow_batch(&flight_data[1], input.schema(), &HashMap::new()).unwrap();
+    assert_eq!(decoded, input);
+    assert!(references_body(&decoded));
+
+    let decoded: Vec<_> =
+        FlightRecordBatchStream::new_from_flight_data(futures::stream::iter(flight_data).map(Ok))
+            .try_collect()
+            .await
+            .unwrap();
+    assert_eq!(decoded, vec![input]);
+    assert!(references_body(&decoded[0]));
+}
+
 /// Encodes input as a FlightData stream, and then decodes it using
 /// FlightRecordBatchStream and validates the decoded record batches
 /// match the input.
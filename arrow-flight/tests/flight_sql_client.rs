@@ -20,19 +20,31 @@ mod common;
 use crate::common::fixture::TestFixture;
 use crate::common::utils::make_primitive_batch;
 
-use arrow_array::RecordBatch;
+use arrow_array::{ArrayRef, Datum, Int64Array, RecordBatch, StringArray};
 use arrow_flight::decode::FlightRecordBatchStream;
 use arrow_flight::encode::FlightDataEncoderBuilder;
 use arrow_flight::error::FlightError;
 use arrow_flight::flight_service_server::FlightServiceServer;
 use arrow_flight::sql::client::FlightSqlServiceClient;
-use arrow_flight::sql::server::{FlightSqlService, PeekableFlightDataStream};
+use arrow_flight::sql::server::{
+    FlightSqlService, PeekableFlightDataStream, PreparedStatementParameters,
+};
 use arrow_flight::sql::{
-    ActionBeginTransactionRequest, ActionBeginTransactionResult, ActionEndTransactionRequest,
-    CommandStatementIngest, EndTransaction, FallibleRequestStream, ProstMessageExt, SqlInfo,
+    ActionBeginTransactionRequest, ActionBeginTransactionResult,
+    ActionCreatePreparedStatementRequest, ActionCreatePreparedStatementResult,
+    ActionEndTransactionRequest, CommandPreparedStatementQuery, CommandStatementIngest,
+    DoPutPreparedStatementResult, EndTransaction, FallibleRequestStream, ProstMessageExt, SqlInfo,
     TableDefinitionOptions, TableExistsOption, TableNotExistOption,
 };
-use arrow_flight::{Action, FlightData, FlightDescriptor};
+use arrow_flight::{
+    Action, CancelFlightInfoRequest, CancelFlightInfoResult, CancelStatus, CloseSessionRequest,
+    CloseSessionResult, FlightData, FlightDescriptor, FlightInfo, GetSessionOptionsRequest,
+    GetSessionOptionsResult, SessionOptionValue, SetSessionOptionsRequest, SetSessionOptionsResult,
+    close_session_result, set_session_options_result,
+};
+use arrow_flight::{IpcMessage, SchemaAsIpc};
+use arrow_ipc::writer::IpcWriteOptions;
+use arrow_schema::{DataType, Field, Schema};
 use futures::{StreamExt, TryStreamExt};
 use prost::Message;
 use std::collections::HashMap;
@@ -72,6 +84,222 @@ pub async fn test_begin_end_transaction() {
     );
 }
 
+#[tokio::test]
+pub async fn test_prepared_statement_bind() {
+    let test_server = FlightSqlServiceImpl::new();
+    let fixture = TestFixture::new(test_server.service()).await;
+    let channel = fixture.channel().await;
+    let mut flight_sql_client = FlightSqlServiceClient::new(channel);
+
+    let mut statement = flight_sql_client
+        .prepare(
+            "SELECT * FROM t WHERE id = $id AND name = $name".to_string(),
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        statement.parameter_schema().unwrap(),
+        &prepared_parameter_schema()
+    );
+
+    // positional parameters are cast to the parameter types
+    statement
+        .bind(&[
+            &arrow_array::Int32Array::new_scalar(1),
+            &StringArray::new_scalar("foo"),
+        ])
+        .unwrap();
+    statement.execute().await.unwrap();
+    let parameters = test_server.take_bound_parameters().await;
+    assert_eq!(parameters.num_sets(), 1);
+    let id = parameters.value(0, 0).unwrap();
+    let id = id.get().0.as_any().downcast_ref::<Int64Array>().unwrap();
+    assert_eq!(id.value(0), 1);
+    let name = parameters.named_value(0, "name").unwrap();
+    let name = name.get().0.as_any().downcast_ref::<StringArray>().unwrap();
+    assert_eq!(name.value(0), "foo");
+    assert!(parameters.value(1, 0).is_none());
+    assert!(parameters.named_value(0, "other").is_none());
+
+    // named parameters are reordered to match the schema
+    statement
+        .bind_named(&[
+            ("name", &StringArray::new_scalar("bar")),
+            ("id", &Int64Array::new_scalar(2)),
+        ])
+        .unwrap();
+    statement.execute().await.unwrap();
+    let expected = RecordBatch::try_new(
+        Arc::new(prepared_parameter_schema()),
+        vec![
+            Arc::new(Int64Array::from(vec![2])) as ArrayRef,
+            Arc::new(StringArray::from(vec!["bar"])),
+        ],
+    )
+    .unwrap();
+    assert_eq!(
+        test_server.take_bound_parameters().await.into_batch(),
+        expected
+    );
+
+    // multiple sets of parameters
+    let batch = RecordBatch::try_from_iter([
+        ("a", Arc::new(Int64Array::from(vec![3, 4])) as ArrayRef),
+        ("b", Arc::new(StringArray::from(vec!["x", "y"]))),
+    ])
+    .unwrap();
+    statement.bind_batch(batch).unwrap();
+    statement.execute().await.unwrap();
+    let parameters = test_server.take_bound_parameters().await;
+    assert_eq!(parameters.num_sets(), 2);
+    assert_eq!(parameters.schema().as_ref(), &prepared_parameter_schema());
+
+    // invalid bindings
+    let err = statement.bind(&[&Int64Array::new_scalar(1)]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Arrow error: Invalid argument error: Expected 2 parameters, got 1"
+    );
+    let err = statement
+        .bind_named(&[("id", &Int64Array::new_scalar(1))])
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Arrow error: Invalid argument error: Missing value for parameter name"
+    );
+    let err = statement
+        .bind_named(&[
+            ("id", &Int64Array::new_scalar(1)),
+            ("name", &StringArray::new_scalar("foo")),
+            ("other", &StringArray::new_scalar("foo")),
+        ])
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Arrow error: Invalid argument error: Unknown parameter other"
+    );
+    let err = statement
+        .bind(&[
+            &Int64Array::from(vec![1, 2]),
+            &StringArray::new_scalar("foo"),
+        ])
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Arrow error: Invalid argument error: Expected a scalar parameter, got an array of length 2"
+    );
+}
+
+#[tokio::test]
+pub async fn test_prepared_statement_bind_without_parameter_schema() {
+    let test_server = FlightSqlServiceImpl::new();
+    let fixture = TestFixture::new(test_server.service()).await;
+    let channel = fixture.channel().await;
+    let mut flight_sql_client = FlightSqlServiceClient::new(channel);
+
+    let mut statement = flight_sql_client
+        .prepare("SELECT * FROM t WHERE id = :id".to_string(), None)
+        .await
+        .unwrap();
+    assert!(statement.parameter_schema().unwrap().fields().is_empty());
+
+    // the names of the columns of a batch are kept
+    let batch =
+        RecordBatch::try_from_iter([("id", Arc::new(Int64Array::from(vec![1, 2])) as ArrayRef)])
+            .unwrap();
+    statement.bind_batch(batch.clone()).unwrap();
+    statement.execute().await.unwrap();
+    assert_eq!(
+        test_server.take_bound_parameters().await.into_batch(),
+        batch
+    );
+
+    // positional parameters are named by their position
+    statement.bind(&[&Int64Array::new_scalar(3)]).unwrap();
+    statement.execute().await.unwrap();
+    let parameters = test_server.take_bound_parameters().await;
+    assert_eq!(parameters.schema().field(0).name(), "$1");
+
+    // binding no parameters clears the parameters
+    statement.bind(&[]).unwrap();
+    statement.execute().await.unwrap();
+    assert!(test_server.bound_parameters.lock().await.is_none());
+}
+
+fn prepared_parameter_schema() -> Schema {
+    Schema::new(vec![
+        Field::new("id", DataType::Int64, true),
+        Field::new("name", DataType::Utf8, true),
+    ])
+}
+
+#[tokio::test]
+pub async fn test_session_options() {
+    let test_server = FlightSqlServiceImpl::new();
+    let fixture = TestFixture::new(test_server.service()).await;
+    let channel = fixture.channel().await;
+    let mut flight_sql_client = FlightSqlServiceClient::new(channel);
+
+    let request = SetSessionOptionsRequest::new([
+        ("catalog", SessionOptionValue::from("main")),
+        ("read_only", SessionOptionValue::from(true)),
+        ("", SessionOptionValue::from(1_i64)),
+    ]);
+    let result = flight_sql_client
+        .set_session_options(request)
+        .await
+        .unwrap();
+    assert_eq!(
+        result,
+        SetSessionOptionsResult::new([("", set_session_options_result::ErrorValue::InvalidName)])
+    );
+
+    // unset an option
+    let request = SetSessionOptionsRequest::new([("read_only", SessionOptionValue::unset())]);
+    flight_sql_client
+        .set_session_options(request)
+        .await
+        .unwrap();
+
+    let result = flight_sql_client.get_session_options().await.unwrap();
+    assert_eq!(
+        result,
+        GetSessionOptionsResult::new([("catalog", SessionOptionValue::from("main"))])
+    );
+
+    let result = flight_sql_client.close_session().await.unwrap();
+    assert_eq!(
+        result,
+        CloseSessionResult::new(close_session_result::Status::Closed)
+    );
+    let result = flight_sql_client.get_session_options().await.unwrap();
+    assert!(result.session_options.is_empty());
+}
+
+#[tokio::test]
+pub async fn test_cancel_flight_info() {
+    let test_server = FlightSqlServiceImpl::new();
+    let fixture = TestFixture::new(test_server.service()).await;
+    let channel = fixture.channel().await;
+    let mut flight_sql_client = FlightSqlServiceClient::new(channel);
+
+    let request = CancelFlightInfoRequest::new(FlightInfo::new());
+    let result = flight_sql_client.cancel_flight_info(request).await.unwrap();
+    assert_eq!(result, CancelFlightInfoResult::new(CancelStatus::Cancelled));
+
+    // not implemented by the test server
+    let request = arrow_flight::RenewFlightEndpointRequest::new(Default::default());
+    let err = flight_sql_client
+        .renew_flight_endpoint(request)
+        .await
+        .unwrap_err();
+    let FlightError::Tonic(status) = err else {
+        panic!("unexpected error {err}")
+    };
+    assert_eq!(status.code(), tonic::Code::Unimplemented);
+}
+
 #[tokio::test]
 pub async fn test_execute_ingest() {
     let test_server = FlightSqlServiceImpl::new();
@@ -120,6 +348,62 @@ pub async fn test_execute_ingest_error() {
     );
 }
 
+#[tokio::test]
+pub async fn test_execute_ingest_with_encoder() {
+    let test_server = FlightSqlServiceImpl::new();
+    let fixture = TestFixture::new(test_server.service()).await;
+    let channel = fixture.channel().await;
+    let mut flight_sql_client = FlightSqlServiceClient::new(channel);
+    let batch = make_primitive_batch(200);
+    // a small max message size splits the batch into several chunks
+    let encoder = FlightDataEncoderBuilder::new().with_max_flight_data_size(256);
+    let actual_rows = flight_sql_client
+        .execute_ingest_with_encoder(
+            make_ingest_command(),
+            encoder,
+            futures::stream::iter(vec![Ok(batch.clone())]),
+        )
+        .await
+        .expect("ingest should succeed");
+    assert_eq!(actual_rows, 200);
+
+    let ingested_batches = test_server.ingested_batches.lock().await.clone();
+    assert!(ingested_batches.len() > 1);
+    let mut offset = 0;
+    for ingested in &ingested_batches {
+        assert_eq!(ingested, &batch.slice(offset, ingested.num_rows()));
+        offset += ingested.num_rows();
+    }
+    assert_eq!(offset, 200);
+
+    // an empty stream still sends the schema when provided
+    let encoder = FlightDataEncoderBuilder::new().with_schema(batch.schema());
+    let actual_rows = flight_sql_client
+        .execute_ingest_with_encoder(make_ingest_command(), encoder, futures::stream::empty())
+        .await
+        .expect("ingest should succeed");
+    assert_eq!(actual_rows, 0);
+}
+
+#[tokio::test]
+pub async fn test_execute_ingest_schema_mismatch() {
+    let test_server = FlightSqlServiceImpl::new();
+    let fixture = TestFixture::new(test_server.service()).await;
+    let channel = fixture.channel().await;
+    let mut flight_sql_client = FlightSqlServiceClient::new(channel);
+    let batch = make_primitive_batch(5);
+    let batches = vec![Ok(batch.clone()), Ok(batch.project(&[0]).unwrap())];
+    let err = flight_sql_client
+        .execute_ingest(make_ingest_command(), futures::stream::iter(batches))
+        .await
+        .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("Ingested batches must all have the same schema"),
+        "{err}"
+    );
+}
+
 #[tokio::test]
 pub async fn test_do_put_empty_stream() {
     // Test for https://github.com/apache/arrow-rs/issues/7329
@@ -225,6 +509,8 @@ fn make_ingest_command() -> CommandStatementIngest {
 pub struct FlightSqlServiceImpl {
     transactions: Arc<Mutex<HashMap<String, ()>>>,
     ingested_batches: Arc<Mutex<Vec<RecordBatch>>>,
+    session_options: Arc<Mutex<HashMap<String, SessionOptionValue>>>,
+    bound_parameters: Arc<Mutex<Option<PreparedStatementParameters>>>,
 }
 
 impl FlightSqlServiceImpl {
@@ -232,9 +518,20 @@ impl FlightSqlServiceImpl {
         Self {
             transactions: Arc::new(Mutex::new(HashMap::new())),
             ingested_batches: Arc::new(Mutex::new(Vec::new())),
+            session_options: Arc::new(Mutex::new(HashMap::new())),
+            bound_parameters: Arc::new(Mutex::new(None)),
         }
     }
 
+    /// Take the parameters bound to the last prepared statement
+    pub async fn take_bound_parameters(&self) -> PreparedStatementParameters {
+        self.bound_parameters
+            .lock()
+            .await
+            .take()
+            .expect("no bound parameters")
+    }
+
     /// Return an [`FlightServiceServer`] that can be used with a
     /// [`Server`](tonic::transport::Server)
     pub fn service(&self) -> FlightServiceServer<Self> {
@@ -287,6 +584,95 @@ impl FlightSqlService for FlightSqlServiceImpl {
         Ok(())
     }
 
+    async fn do_action_create_prepared_statement(
+        &self,
+        query: ActionCreatePreparedStatementRequest,
+        _request: Request<Action>,
+    ) -> Result<ActionCreatePreparedStatementResult, Status> {
+        // only report a parameter schema for queries with `$` parameters
+        let schema = match query.query.contains('$') {
+            true => prepared_parameter_schema(),
+            false => Schema::empty(),
+        };
+        let message: IpcMessage = SchemaAsIpc::new(&schema, &IpcWriteOptions::default())
+            .try_into()
+            .map_err(|e| Status::internal(format!("{e}")))?;
+        Ok(ActionCreatePreparedStatementResult {
+            prepared_statement_handle: "handle".into(),
+            dataset_schema: Default::default(),
+            parameter_schema: message.0,
+        })
+    }
+
+    async fn get_flight_info_prepared_statement(
+        &self,
+        _query: CommandPreparedStatementQuery,
+        _request: Request<FlightDescriptor>,
+    ) -> Result<tonic::Response<FlightInfo>, Status> {
+        Ok(tonic::Response::new(FlightInfo::new()))
+    }
+
+    async fn do_put_prepared_statement_query(
+        &self,
+        query: CommandPreparedStatementQuery,
+        request: Request<PeekableFlightDataStream>,
+    ) -> Result<DoPutPreparedStatementResult, Status> {
+        let parameters = PreparedStatementParameters::try_decode(request.into_inner()).await?;
+        *self.bound_parameters.lock().await = Some(parameters);
+        Ok(DoPutPreparedStatementResult {
+            prepared_statement_handle: Some(query.prepared_statement_handle),
+        })
+    }
+
+    async fn do_action_cancel_flight_info(
+        &self,
+        _query: CancelFlightInfoRequest,
+        _request: Request<Action>,
+    ) -> Result<CancelFlightInfoResult, Status> {
+        Ok(CancelFlightInfoResult::new(CancelStatus::Cancelled))
+    }
+
+    async fn do_action_set_session_options(
+        &self,
+        query: SetSessionOptionsRequest,
+        _request: Request<Action>,
+    ) -> Result<SetSessionOptionsResult, Status> {
+        let mut options = self.session_options.lock().await;
+        let mut errors = vec![];
+        for (name, value) in query.session_options {
+            if name.is_empty() {
+                errors.push((name, set_session_options_result::ErrorValue::InvalidName));
+            } else if value.option_value.is_none() {
+                options.remove(&name);
+            } else {
+                options.insert(name, value);
+            }
+        }
+        Ok(SetSessionOptionsResult::new(errors))
+    }
+
+    async fn do_action_get_session_options(
+        &self,
+        _query: GetSessionOptionsRequest,
+        _request: Request<Action>,
+    ) -> Result<GetSessionOptionsResult, Status> {
+        let options = self.session_options.lock().await.clone();
+        Ok(GetSessionOptionsResult {
+            session_options: options,
+        })
+    }
+
+    async fn do_action_close_session(
+        &self,
+        _query: CloseSessionRequest,
+        _request: Request<Action>,
+    ) -> Result<CloseSessionResult, Status> {
+        self.session_options.lock().await.clear();
+        Ok(CloseSessionResult::new(
+            close_session_result::Status::Closed,
+        ))
+    }
+
     async fn register_sql_info(&self, _id: i32, _result: &SqlInfo) {}
 
     async fn do_put_statement_ingest(
@@ -294,7 +294,14 @@ pub fn data_type_to_json(data_type: &DataType) -> serde_json::Value {
             json!({"name": "fixedsizebinary", "byteWidth": byte_width})
         }
         DataType::Struct(_) => json!({"name": "struct"}),
-        DataType::Union(_, _) => json!({"name": "union"}),
+        DataType::Union(fields, mode) => {
+            let mode = match mode {
+                UnionMode::Sparse => "SPARSE",
+                UnionMode::Dense => "DENSE",
+            };
+            let type_ids: Vec<_> = fields.iter().map(|(id, _)| id).collect();
+            json!({"name": "union", "mode": mode, "typeIds": type_ids})
+        }
         DataType::List(_) => json!({ "name": "list"}),
         DataType::LargeList(_) => json!({ "name": "largelist"}),
         DataType::ListView(_) => json!({ "name": "listview"}),
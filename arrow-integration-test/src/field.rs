@@ -303,13 +303,14 @@ pub fn field_to_json(field: &Field) -> serde_json::Value {
         | DataType::LargeListView(field)
         | DataType::FixedSizeList(field, _)
         | DataType::Map(field, _) => vec![field_to_json(field)],
+        DataType::Union(fields, _) => fields.iter().map(|(_, f)| field_to_json(f)).collect(),
         DataType::RunEndEncoded(run_ends, values) => {
             vec![field_to_json(run_ends), field_to_json(values)]
         }
         _ => vec![],
     };
 
-    match field.data_type() {
+    let mut json = match field.data_type() {
         DataType::Dictionary(index_type, value_type) => {
             #[allow(deprecated)]
             let dict_id = field.dict_id().unwrap();
@@ -331,7 +332,17 @@ pub fn field_to_json(field: &Field) -> serde_json::Value {
             "type": data_type_to_json(field.data_type()),
             "children": children
         }),
+    };
+
+    if !field.metadata().is_empty() {
+        let mut metadata: Vec<_> = field.metadata().iter().collect();
+        metadata.sort_unstable();
+        json["metadata"] = metadata
+            .into_iter()
+            .map(|(k, v)| serde_json::json!({"key": k, "value": v}))
+            .collect();
     }
+    json
 }
 
 #[cfg(test)]
@@ -605,4 +616,25 @@ mod tests {
 
         assert_eq!(expected, dt);
     }
+
+    #[test]
+    fn union_field_roundtrip() {
+        let f = Field::new_union(
+            "my_union",
+            vec![5, 7],
+            vec![
+                Field::new("f1", DataType::Int32, true),
+                Field::new("f2", DataType::Utf8, true),
+            ],
+            UnionMode::Dense,
+        )
+        .with_metadata(HashMap::from([("k".to_string(), "v".to_string())]));
+
+        let value = field_to_json(&f);
+        assert_eq!(
+            value["metadata"],
+            serde_json::json!([{"key": "k", "value": "v"}])
+        );
+        assert_eq!(field_from_json(&value).unwrap(), f);
+    }
 }
@@ -15,19 +15,34 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! Partial support for the [Apache Arrow JSON test data format](https://github.com/apache/arrow/blob/master/docs/source/format/Integration.rst#json-test-data-format)
+//! Support for the [Apache Arrow JSON test data format](https://github.com/apache/arrow/blob/master/docs/source/format/Integration.rst#json-test-data-format)
 //!
-//! These utilities define structs that read the integration JSON format for integration testing purposes.
+//! These utilities define structs that read and write the integration JSON format, which the
+//! Arrow implementations use to verify that they agree on the contents of IPC data.
 //!
 //! This is not a canonical format, but provides a human-readable way of verifying language implementations
 //!
-//! <div class="warning">
+//! # Example
 //!
-//! This crate is **only intended for integration testing the
-//! [Arrow project](https://github.com/apache/arrow-rs)**. It is not [intended for usage outside of
-//! this context](https://github.com/apache/arrow-rs/issues/8684#issuecomment-3433193158).
+//! ```
+//! # use std::sync::Arc;
+//! # use arrow::array::{ArrayRef, Int32Array, RecordBatch, StringArray};
+//! # use arrow_integration_test::ArrowJson;
+//! let batch = RecordBatch::try_from_iter([
+//!     ("a", Arc::new(Int32Array::from(vec![Some(1), None])) as ArrayRef),
+//!     ("b", Arc::new(StringArray::from(vec!["x", "y"])) as ArrayRef),
+//! ])
+//! .unwrap();
 //!
-//! </div>
+//! // Write the batches to the JSON test data format
+//! let json = ArrowJson::try_from_batches(&batch.schema(), &[batch.clone()]).unwrap();
+//! let text = serde_json::to_string(&json).unwrap();
+//!
+//! // Read them back
+//! let json: ArrowJson = serde_json::from_str(&text).unwrap();
+//! assert_eq!(json.schema.to_arrow_schema().unwrap(), *batch.schema());
+//! assert_eq!(json.get_record_batches().unwrap(), vec![batch]);
+//! ```
 
 #![doc(
     html_logo_url = "https://arrow.apache.org/img/arrow-logo_chevrons_black-txt_white-bg.svg",
@@ -53,10 +68,12 @@ use arrow::util::bit_util;
 mod datatype;
 mod field;
 mod schema;
+mod writer;
 
 pub use datatype::*;
 pub use field::*;
 pub use schema::*;
+pub use writer::*;
 
 /// A struct that represents an Arrow file with a schema and record batches
 ///
@@ -168,7 +185,7 @@ pub struct DictionaryIndexType {
 }
 
 /// A struct that partially reads the Arrow JSON record batch
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 pub struct ArrowJsonBatch {
     count: usize,
     /// The columns in the record batch
@@ -176,7 +193,7 @@ pub struct ArrowJsonBatch {
 }
 
 /// A struct that partially reads the Arrow JSON dictionary batch
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 #[allow(non_snake_case)]
 pub struct ArrowJsonDictionaryBatch {
     /// The unique identifier for the dictionary
@@ -186,7 +203,7 @@ pub struct ArrowJsonDictionaryBatch {
 }
 
 /// A struct that partially reads the Arrow JSON column/array
-#[derive(Deserialize, Serialize, Clone, Debug)]
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct ArrowJsonColumn {
     name: String,
     /// The number of elements in the column
@@ -279,7 +296,8 @@ impl ArrowJsonSchema {
         true
     }
 
-    fn to_arrow_schema(&self) -> Result<Schema> {
+    /// Convert to an Arrow [`Schema`]
+    pub fn to_arrow_schema(&self) -> Result<Schema> {
         let arrow_fields: Result<Vec<_>> = self
             .fields
             .iter()
@@ -1207,7 +1225,8 @@ impl ArrowJsonBatch {
     /// this crate is only intended for use within the Arrow project itself.
     ///
     /// Right now, this function only supports `DataType::Int8` columns. Other data types will lead
-    /// to an empty `ArrowJsonColumn`.
+    /// to an empty `ArrowJsonColumn`. Use [`record_batch_to_json`] or
+    /// [`ArrowJson::try_from_batches`] for a complete conversion.
     ///
     /// </div>
     pub fn from_batch(batch: &RecordBatch) -> ArrowJsonBatch {
@@ -0,0 +1,777 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Conversion of Arrow arrays into the JSON test data format
+
+use crate::{
+    ArrowJson, ArrowJsonBatch, ArrowJsonColumn, ArrowJsonDictionaryBatch, ArrowJsonSchema,
+    field_to_json,
+};
+use arrow::array::*;
+use arrow::buffer::ScalarBuffer;
+use arrow::datatypes::*;
+use arrow::error::{ArrowError, Result};
+use arrow_buffer::{ArrowNativeType, IntervalDayTime, IntervalMonthDayNano, OffsetBuffer};
+use serde_json::{Map as SJMap, Value, json};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+impl ArrowJson {
+    /// Create an [`ArrowJson`] from a schema and the [`RecordBatch`]es that conform to it
+    ///
+    /// Dictionary ids are assigned to the dictionary-encoded fields of `schema` in depth-first
+    /// order, replacing any ids already present. As the JSON format cannot represent dictionary
+    /// replacement, each dictionary-encoded column must have the same dictionary values in
+    /// every batch.
+    ///
+    /// The result can be serialized with `serde_json`, and read back with
+    /// [`ArrowJson::get_record_batches`].
+    pub fn try_from_batches(schema: &Schema, batches: &[RecordBatch]) -> Result<Self> {
+        let mut next_dict_id = 0;
+        let fields: Fields = schema
+            .fields()
+            .iter()
+            .map(|f| assign_dict_ids(f, &mut next_dict_id))
+            .collect();
+        let schema = Schema::new_with_metadata(fields, schema.metadata().clone());
+
+        let mut dictionaries = HashMap::new();
+        let batches = batches
+            .iter()
+            .map(|batch| record_batch_to_json(&schema, batch, &mut dictionaries))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut dictionaries: Vec<_> = dictionaries.into_values().collect();
+        dictionaries.sort_unstable_by_key(|d| d.id);
+
+        Ok(Self {
+            schema: ArrowJsonSchema::try_from_arrow_schema(&schema)?,
+            batches,
+            dictionaries: (!dictionaries.is_empty()).then_some(dictionaries),
+        })
+    }
+}
+
+impl ArrowJsonSchema {
+    /// Create an [`ArrowJsonSchema`] from an Arrow [`Schema`]
+    ///
+    /// Dictionary-encoded fields are written with the dictionary id of the [`Field`]
+    pub fn try_from_arrow_schema(schema: &Schema) -> Result<Self> {
+        let fields = schema
+            .fields()
+            .iter()
+            .map(|f| {
+                serde_json::from_value(field_to_json(f))
+                    .map_err(|error| ArrowError::JsonError(error.to_string()))
+            })
+            .collect::<Result<_>>()?;
+
+        let mut metadata: Vec<_> = schema
+            .metadata()
+            .iter()
+            .map(|(k, v)| {
+                HashMap::from([
+                    ("key".to_string(), k.clone()),
+                    ("value".to_string(), v.clone()),
+                ])
+            })
+            .collect();
+        metadata.sort_unstable_by(|a, b| a["key"].cmp(&b["key"]));
+
+        Ok(Self {
+            fields,
+            metadata: (!metadata.is_empty()).then_some(metadata),
+        })
+    }
+}
+
+/// Returns a copy of `field` with dictionary ids assigned to it and its
+/// descendants in depth-first order, starting from `next_dict_id`
+fn assign_dict_ids(field: &Field, next_dict_id: &mut i64) -> FieldRef {
+    let data_type = match field.data_type() {
+        DataType::Dictionary(key, value) => {
+            let dict_id = *next_dict_id;
+            *next_dict_id += 1;
+            let value =
+                assign_dict_ids(&Field::new("", value.as_ref().clone(), true), next_dict_id);
+            #[allow(deprecated)]
+            let f = Field::new_dict(
+                field.name(),
+                DataType::Dictionary(key.clone(), Box::new(value.data_type().clone())),
+                field.is_nullable(),
+                dict_id,
+                field.dict_is_ordered().unwrap_or_default(),
+            )
+            .with_metadata(field.metadata().clone());
+            return Arc::new(f);
+        }
+        DataType::Struct(f) => {
+            DataType::Struct(f.iter().map(|f| assign_dict_ids(f, next_dict_id)).collect())
+        }
+        DataType::Union(f, mode) => DataType::Union(
+            f.iter()
+                .map(|(id, f)| (id, assign_dict_ids(f, next_dict_id)))
+                .collect(),
+            *mode,
+        ),
+        DataType::List(f) => DataType::List(assign_dict_ids(f, next_dict_id)),
+        DataType::LargeList(f) => DataType::LargeList(assign_dict_ids(f, next_dict_id)),
+        DataType::ListView(f) => DataType::ListView(assign_dict_ids(f, next_dict_id)),
+        DataType::LargeListView(f) => DataType::LargeListView(assign_dict_ids(f, next_dict_id)),
+        DataType::FixedSizeList(f, size) => {
+            DataType::FixedSizeList(assign_dict_ids(f, next_dict_id), *size)
+        }
+        DataType::Map(f, sorted) => DataType::Map(assign_dict_ids(f, next_dict_id), *sorted),
+        DataType::RunEndEncoded(run_ends, values) => {
+            DataType::RunEndEncoded(run_ends.clone(), assign_dict_ids(values, next_dict_id))
+        }
+        d => d.clone(),
+    };
+    Arc::new(field.clone().with_data_type(data_type))
+}
+
+/// Generates an Arrow JSON batch from a [`RecordBatch`], given the schema to write it with
+///
+/// The dictionaries of any dictionary-encoded columns are added to `dictionaries`, keyed
+/// by the dictionary id of the corresponding [`Field`] in `schema`. Returns an error if
+/// `dictionaries` already contains different values for the same id.
+pub fn record_batch_to_json(
+    schema: &Schema,
+    batch: &RecordBatch,
+    dictionaries: &mut HashMap<i64, ArrowJsonDictionaryBatch>,
+) -> Result<ArrowJsonBatch> {
+    if schema.fields().len() != batch.num_columns() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Schema has {} fields but batch has {} columns",
+            schema.fields().len(),
+            batch.num_columns()
+        )));
+    }
+
+    let columns = schema
+        .fields()
+        .iter()
+        .zip(batch.columns())
+        .map(|(field, array)| array_to_json(field, array.as_ref(), dictionaries))
+        .collect::<Result<_>>()?;
+
+    Ok(ArrowJsonBatch {
+        count: batch.num_rows(),
+        columns,
+    })
+}
+
+/// Generates a partially typed JSON column from an Arrow array
+///
+/// This is the inverse of [`array_from_json`](crate::array_from_json). The dictionaries of
+/// any dictionary-encoded arrays are added to `dictionaries`, keyed by the dictionary id of
+/// `field` or its descendants.
+pub fn array_to_json(
+    field: &Field,
+    array: &dyn Array,
+    dictionaries: &mut HashMap<i64, ArrowJsonDictionaryBatch>,
+) -> Result<ArrowJsonColumn> {
+    if field.data_type() != array.data_type() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Field {} has data type {} but array has data type {}",
+            field.name(),
+            field.data_type(),
+            array.data_type()
+        )));
+    }
+
+    let mut column = ArrowJsonColumn {
+        name: field.name().clone(),
+        count: array.len(),
+        validity: None,
+        data: None,
+        offset: None,
+        type_id: None,
+        size: None,
+        views: None,
+        variadic_data_buffers: None,
+        children: None,
+    };
+
+    match field.data_type() {
+        DataType::Null => {}
+        DataType::Boolean => {
+            column.validity = Some(validity(array));
+            let values = array.as_boolean().values();
+            column.data = Some(values.iter().map(Value::Bool).collect());
+        }
+        DataType::Int8 => column.set_data::<i8>(array, Value::from),
+        DataType::Int16 => column.set_data::<i16>(array, Value::from),
+        DataType::Int32
+        | DataType::Date32
+        | DataType::Time32(_)
+        | DataType::Interval(IntervalUnit::YearMonth) => column.set_data::<i32>(array, Value::from),
+        DataType::Int64
+        | DataType::Date64
+        | DataType::Time64(_)
+        | DataType::Timestamp(_, _)
+        | DataType::Duration(_) => column.set_data::<i64>(array, to_string),
+        DataType::UInt8 => column.set_data::<u8>(array, Value::from),
+        DataType::UInt16 => column.set_data::<u16>(array, Value::from),
+        DataType::UInt32 => column.set_data::<u32>(array, Value::from),
+        DataType::UInt64 => column.set_data::<u64>(array, to_string),
+        DataType::Float32 => column.set_data::<f32>(array, Value::from),
+        DataType::Float64 => column.set_data::<f64>(array, Value::from),
+        DataType::Interval(IntervalUnit::DayTime) => column.set_data::<IntervalDayTime>(
+            array,
+            |v| json!({"days": v.days, "milliseconds": v.milliseconds}),
+        ),
+        DataType::Interval(IntervalUnit::MonthDayNano) => column.set_data::<IntervalMonthDayNano>(
+            array,
+            |v| json!({"months": v.months, "days": v.days, "nanoseconds": v.nanoseconds}),
+        ),
+        DataType::Decimal32(_, _) => column.set_data::<i32>(array, to_string),
+        DataType::Decimal64(_, _) => column.set_data::<i64>(array, to_string),
+        DataType::Decimal128(_, _) => column.set_data::<i128>(array, to_string),
+        DataType::Decimal256(_, _) => column.set_data::<i256>(array, to_string),
+        DataType::Binary => {
+            let array = array.as_binary::<i32>();
+            column.set_bytes(array, array.offsets(), |v| hex::encode_upper(v).into());
+        }
+        DataType::LargeBinary => {
+            let array = array.as_binary::<i64>();
+            column.set_bytes(array, array.offsets(), |v| hex::encode_upper(v).into());
+        }
+        DataType::Utf8 => {
+            let array = array.as_string::<i32>();
+            column.set_bytes(array, array.offsets(), Value::from);
+        }
+        DataType::LargeUtf8 => {
+            let array = array.as_string::<i64>();
+            column.set_bytes(array, array.offsets(), Value::from);
+        }
+        DataType::FixedSizeBinary(_) => {
+            let array = array.as_fixed_size_binary();
+            column.validity = Some(validity(array));
+            column.data = Some(
+                (0..array.len())
+                    .map(|i| hex::encode_upper(array.value(i)).into())
+                    .collect(),
+            );
+        }
+        DataType::Utf8View => {
+            let array = array.as_string_view();
+            column.set_views(array, |v| String::from_utf8_lossy(v).into());
+        }
+        DataType::BinaryView => {
+            let array = array.as_binary_view();
+            column.set_views(array, |v| hex::encode_upper(v).into());
+        }
+        DataType::List(child) => {
+            let array = array.as_list::<i32>();
+            column.set_list(child, array, array.offsets(), array.values(), dictionaries)?;
+        }
+        DataType::LargeList(child) => {
+            let array = array.as_list::<i64>();
+            column.set_list(child, array, array.offsets(), array.values(), dictionaries)?;
+        }
+        DataType::Map(child, _) => {
+            let array = array.as_map();
+            let entries: ArrayRef = Arc::new(array.entries().clone());
+            column.set_list(child, array, array.offsets(), &entries, dictionaries)?;
+        }
+        DataType::ListView(child) => {
+            let array = array.as_list_view::<i32>();
+            column.validity = Some(validity(array));
+            column.offset = Some(array.offsets().iter().map(|v| Value::from(*v)).collect());
+            column.size = Some(array.sizes().iter().map(|v| Value::from(*v)).collect());
+            column.children = Some(vec![array_to_json(child, array.values(), dictionaries)?]);
+        }
+        DataType::LargeListView(child) => {
+            let array = array.as_list_view::<i64>();
+            column.validity = Some(validity(array));
+            column.offset = Some(array.offsets().iter().map(to_string).collect());
+            column.size = Some(array.sizes().iter().map(to_string).collect());
+            column.children = Some(vec![array_to_json(child, array.values(), dictionaries)?]);
+        }
+        DataType::FixedSizeList(child, _) => {
+            let array = array.as_fixed_size_list();
+            column.validity = Some(validity(array));
+            column.children = Some(vec![array_to_json(child, array.values(), dictionaries)?]);
+        }
+        DataType::Struct(fields) => {
+            let array = array.as_struct();
+            column.validity = Some(validity(array));
+            column.children = Some(
+                fields
+                    .iter()
+                    .zip(array.columns())
+                    .map(|(f, c)| array_to_json(f, c.as_ref(), dictionaries))
+                    .collect::<Result<_>>()?,
+            );
+        }
+        DataType::Union(fields, mode) => {
+            let array = array.as_union();
+            column.type_id = Some(array.type_ids().to_vec());
+            if *mode == UnionMode::Dense {
+                let offsets = array.offsets().unwrap();
+                column.offset = Some(offsets.iter().map(|v| Value::from(*v)).collect());
+            }
+            column.children = Some(
+                fields
+                    .iter()
+                    .map(|(type_id, f)| array_to_json(f, array.child(type_id), dictionaries))
+                    .collect::<Result<_>>()?,
+            );
+        }
+        DataType::RunEndEncoded(run_ends_field, values_field) => {
+            let (run_ends, values) = match run_ends_field.data_type() {
+                DataType::Int16 => run_array_children::<Int16Type>(array),
+                DataType::Int32 => run_array_children::<Int32Type>(array),
+                DataType::Int64 => run_array_children::<Int64Type>(array),
+                t => {
+                    return Err(ArrowError::JsonError(format!(
+                        "run ends type {t} not supported"
+                    )));
+                }
+            };
+            column.children = Some(vec![
+                array_to_json(run_ends_field, run_ends.as_ref(), dictionaries)?,
+                array_to_json(values_field, values.as_ref(), dictionaries)?,
+            ]);
+        }
+        DataType::Dictionary(key_type, value_type) => {
+            let array = array.as_any_dictionary();
+            let key_field = Field::new(field.name(), key_type.as_ref().clone(), true);
+            let keys = array_to_json(&key_field, array.keys(), dictionaries)?;
+            column.validity = keys.validity;
+            column.data = keys.data;
+
+            // note: the JSON format does not record the nullability of dictionary values
+            let value_field = Field::new(field.name(), value_type.as_ref().clone(), true);
+            let values = array_to_json(&value_field, array.values().as_ref(), dictionaries)?;
+            let data = ArrowJsonBatch {
+                count: values.count,
+                columns: vec![values],
+            };
+
+            #[allow(deprecated)]
+            let id = field
+                .dict_id()
+                .expect("Dictionary fields must have a dict_id value");
+            match dictionaries.get(&id) {
+                Some(existing) if existing.data != data => {
+                    return Err(ArrowError::InvalidArgumentError(format!(
+                        "Dictionary replacement is not supported: found different values for dictionary {id}"
+                    )));
+                }
+                Some(_) => {}
+                None => {
+                    dictionaries.insert(id, ArrowJsonDictionaryBatch { id, data });
+                }
+            }
+        }
+        t => {
+            return Err(ArrowError::JsonError(format!(
+                "data type {t} not supported"
+            )));
+        }
+    }
+
+    Ok(column)
+}
+
+impl ArrowJsonColumn {
+    /// Sets the validity and data of a fixed-width column
+    fn set_data<T: ArrowNativeType>(&mut self, array: &dyn Array, to_json: impl Fn(T) -> Value) {
+        self.validity = Some(validity(array));
+        let values = fixed_values::<T>(array);
+        self.data = Some(values.iter().map(|v| to_json(*v)).collect());
+    }
+
+    /// Sets the validity, offsets and data of a variable-width column
+    fn set_bytes<A, O>(
+        &mut self,
+        array: A,
+        offsets: &OffsetBuffer<O>,
+        to_json: impl Fn(A::Item) -> Value,
+    ) where
+        A: ArrayAccessor,
+        O: OffsetSizeTrait + Into<Value>,
+    {
+        self.validity = Some(validity(&array));
+        self.offset = Some(normalize_offsets(offsets).0);
+        self.data = Some((0..array.len()).map(|i| to_json(array.value(i))).collect());
+    }
+
+    /// Sets the validity, views and variadic data buffers of a view column
+    fn set_views<T: ByteViewType + ?Sized>(
+        &mut self,
+        array: &GenericByteViewArray<T>,
+        inlined: impl Fn(&[u8]) -> Value,
+    ) {
+        self.validity = Some(validity(array));
+        let views = array.views().iter().map(|view| {
+            let size = *view as u32;
+            let mut obj = SJMap::new();
+            obj.insert("SIZE".to_string(), size.into());
+            if size <= 12 {
+                let bytes = view.to_le_bytes();
+                obj.insert("INLINED".to_string(), inlined(&bytes[4..4 + size as usize]));
+            } else {
+                let prefix = ((*view >> 32) as u32).to_le_bytes();
+                obj.insert("PREFIX_HEX".to_string(), hex::encode_upper(prefix).into());
+                obj.insert("BUFFER_INDEX".to_string(), ((*view >> 64) as u32).into());
+                obj.insert("OFFSET".to_string(), ((*view >> 96) as u32).into());
+            }
+            Value::Object(obj)
+        });
+        self.views = Some(views.collect());
+        self.variadic_data_buffers =
+            Some(array.data_buffers().iter().map(hex::encode_upper).collect());
+    }
+
+    /// Sets the validity, offsets and child of a list or map column
+    fn set_list<O>(
+        &mut self,
+        child: &Field,
+        array: &dyn Array,
+        offsets: &OffsetBuffer<O>,
+        values: &ArrayRef,
+        dictionaries: &mut HashMap<i64, ArrowJsonDictionaryBatch>,
+    ) -> Result<()>
+    where
+        O: OffsetSizeTrait + Into<Value>,
+    {
+        self.validity = Some(validity(array));
+        let (offsets, start, end) = normalize_offsets(offsets);
+        self.offset = Some(offsets);
+        let values = values.slice(start, end - start);
+        self.children = Some(vec![array_to_json(child, values.as_ref(), dictionaries)?]);
+        Ok(())
+    }
+}
+
+/// Returns the validity of `array`, where `1` indicates a valid slot
+fn validity(array: &dyn Array) -> Vec<u8> {
+    match array.nulls() {
+        Some(nulls) => nulls.iter().map(u8::from).collect(),
+        None => vec![1; array.len()],
+    }
+}
+
+/// Returns the values of a fixed-width array, accounting for its offset
+fn fixed_values<T: ArrowNativeType>(array: &dyn Array) -> ScalarBuffer<T> {
+    let data = array.to_data();
+    ScalarBuffer::new(data.buffers()[0].clone(), data.offset(), data.len())
+}
+
+/// JSON numbers cannot represent 64-bit and wider integers, which are written as strings
+fn to_string<T: ToString>(v: T) -> Value {
+    Value::String(v.to_string())
+}
+
+/// Returns the JSON representation of `offsets` rebased to start at zero,
+/// along with the range of child values they reference
+fn normalize_offsets<O>(offsets: &OffsetBuffer<O>) -> (Vec<Value>, usize, usize)
+where
+    O: OffsetSizeTrait + Into<Value>,
+{
+    let start = offsets[0];
+    let end = offsets[offsets.len() - 1];
+    let json = offsets
+        .iter()
+        .map(|o| match O::IS_LARGE {
+            true => to_string(o.as_usize() - start.as_usize()),
+            false => (*o - start).into(),
+        })
+        .collect();
+    (json, start.as_usize(), end.as_usize())
+}
+
+/// Returns the run ends and values of a [`RunArray`], accounting for its offset
+fn run_array_children<R: RunEndIndexType>(array: &dyn Array) -> (ArrayRef, ArrayRef) {
+    let array = array.as_run::<R>();
+    let run_ends = array.run_ends();
+    if run_ends.is_empty() {
+        return (
+            Arc::new(PrimitiveArray::<R>::from_iter_values([])),
+            array.values().slice(0, 0),
+        );
+    }
+
+    let start = run_ends.get_start_physical_index();
+    let end = run_ends.get_end_physical_index();
+    let ends = run_ends.values()[start..=end].iter().map(|e| {
+        let e = (e.as_usize() - run_ends.offset()).min(run_ends.len());
+        R::Native::usize_as(e)
+    });
+    (
+        Arc::new(PrimitiveArray::<R>::from_iter_values(ends)),
+        array.values().slice(start, end - start + 1),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::buffer::{NullBuffer, OffsetBuffer};
+    use std::fs::File;
+    use std::io::Read;
+
+    /// Converts `batches` to JSON text and back
+    fn roundtrip(schema: &Schema, batches: &[RecordBatch]) -> (Schema, Vec<RecordBatch>) {
+        let json = ArrowJson::try_from_batches(schema, batches).unwrap();
+        let text = serde_json::to_string(&json).unwrap();
+        let json: ArrowJson = serde_json::from_str(&text).unwrap();
+        (
+            json.schema.to_arrow_schema().unwrap(),
+            json.get_record_batches().unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_roundtrip_integration_file() {
+        let mut file = File::open("data/integration.json").unwrap();
+        let mut json = String::new();
+        file.read_to_string(&mut json).unwrap();
+        let arrow_json: ArrowJson = serde_json::from_str(&json).unwrap();
+        let schema = arrow_json.schema.to_arrow_schema().unwrap();
+        let batches = arrow_json.get_record_batches().unwrap();
+
+        let (actual_schema, actual_batches) = roundtrip(&schema, &batches);
+        assert_eq!(actual_schema, schema);
+        assert_eq!(actual_batches, batches);
+    }
+
+    #[test]
+    fn test_roundtrip_types() {
+        let nulls = NullBuffer::from(vec![true, false, true, true]);
+
+        let binary = BinaryArray::from(vec![Some(b"ab".as_ref()), None, Some(b""), Some(b"\xff")]);
+        let large_utf8 = LargeStringArray::from(vec![Some("a"), None, Some("bc"), Some("")]);
+        let fixed_binary = FixedSizeBinaryArray::try_from_sparse_iter_with_size(
+            vec![Some([1u8, 2]), None, Some([3, 4]), Some([5, 6])].into_iter(),
+            2,
+        )
+        .unwrap();
+        let utf8_view = StringViewArray::from(vec![
+            Some("short"),
+            None,
+            Some("a string that is too long to be inlined"),
+            Some(""),
+        ]);
+        let decimal32 = Decimal32Array::from(vec![Some(1), None, Some(-2), Some(3)])
+            .with_precision_and_scale(9, 2)
+            .unwrap();
+        let decimal64 = Decimal64Array::from(vec![Some(1), None, Some(-2), Some(i64::MAX)])
+            .with_precision_and_scale(18, 2)
+            .unwrap();
+        let decimal128 = Decimal128Array::from(vec![Some(1), None, Some(-2), Some(i128::MAX)])
+            .with_precision_and_scale(38, 2)
+            .unwrap();
+        let decimal256 = Decimal256Array::from(vec![
+            Some(i256::ONE),
+            None,
+            Some(i256::MINUS_ONE),
+            Some(i256::MAX),
+        ])
+        .with_precision_and_scale(76, 2)
+        .unwrap();
+        let day_time = IntervalDayTimeArray::from(vec![
+            Some(IntervalDayTime::new(1, 2)),
+            None,
+            Some(IntervalDayTime::new(-1, -2)),
+            Some(IntervalDayTime::ZERO),
+        ]);
+        let month_day_nano = IntervalMonthDayNanoArray::from(vec![
+            Some(IntervalMonthDayNano::new(1, 2, 3)),
+            None,
+            Some(IntervalMonthDayNano::new(-1, -2, i64::MIN)),
+            Some(IntervalMonthDayNano::ZERO),
+        ]);
+        let uint64 = UInt64Array::from(vec![Some(u64::MAX), None, Some(0), Some(1)]);
+        let nulls_array = NullArray::new(4);
+
+        let dictionary: DictionaryArray<Int16Type> = vec![Some("a"), None, Some("b"), Some("a")]
+            .into_iter()
+            .collect();
+        let list_values: DictionaryArray<Int8Type> =
+            vec!["x", "y", "x", "z", "x"].into_iter().collect();
+        let list_of_dictionary = ListArray::new(
+            Arc::new(Field::new_list_field(list_values.data_type().clone(), true)),
+            OffsetBuffer::from_lengths([2, 0, 1, 2]),
+            Arc::new(list_values),
+            Some(nulls.clone()),
+        );
+
+        let fixed_size_list = FixedSizeListArray::new(
+            Arc::new(Field::new_list_field(DataType::Int32, true)),
+            2,
+            Arc::new(Int32Array::from(vec![1, 2, 3, 4, 5, 6, 7, 8])),
+            Some(nulls.clone()),
+        );
+
+        let map = {
+            let keys = StringArray::from(vec!["a", "b", "c"]);
+            let values = Int32Array::from(vec![Some(1), None, Some(3)]);
+            let entries = StructArray::from(vec![
+                (
+                    Arc::new(Field::new("keys", DataType::Utf8, false)),
+                    Arc::new(keys) as ArrayRef,
+                ),
+                (
+                    Arc::new(Field::new("values", DataType::Int32, true)),
+                    Arc::new(values) as ArrayRef,
+                ),
+            ]);
+            MapArray::new(
+                Arc::new(Field::new("entries", entries.data_type().clone(), false)),
+                OffsetBuffer::from_lengths([1, 0, 2, 0]),
+                entries,
+                Some(nulls.clone()),
+                false,
+            )
+        };
+
+        let union_fields: UnionFields = [
+            (0, Arc::new(Field::new("a", DataType::Int32, true))),
+            (3, Arc::new(Field::new("b", DataType::Utf8, true))),
+        ]
+        .into_iter()
+        .collect();
+        let sparse_union = UnionArray::try_new(
+            union_fields.clone(),
+            vec![0, 3, 3, 0].into(),
+            None,
+            vec![
+                Arc::new(Int32Array::from(vec![Some(1), None, None, Some(4)])),
+                Arc::new(StringArray::from(vec![None, Some("b"), None, None])),
+            ],
+        )
+        .unwrap();
+        let dense_union = UnionArray::try_new(
+            union_fields,
+            vec![0, 3, 3, 0].into(),
+            Some(vec![0, 0, 1, 1].into()),
+            vec![
+                Arc::new(Int32Array::from(vec![Some(1), None])),
+                Arc::new(StringArray::from(vec![Some("b"), Some("c")])),
+            ],
+        )
+        .unwrap();
+
+        let batch = RecordBatch::try_from_iter([
+            ("binary", Arc::new(binary) as ArrayRef),
+            ("large_utf8", Arc::new(large_utf8)),
+            ("fixed_binary", Arc::new(fixed_binary)),
+            ("utf8_view", Arc::new(utf8_view)),
+            ("decimal32", Arc::new(decimal32)),
+            ("decimal64", Arc::new(decimal64)),
+            ("decimal128", Arc::new(decimal128)),
+            ("decimal256", Arc::new(decimal256)),
+            ("day_time", Arc::new(day_time)),
+            ("month_day_nano", Arc::new(month_day_nano)),
+            ("uint64", Arc::new(uint64)),
+            ("nulls", Arc::new(nulls_array)),
+            ("dictionary", Arc::new(dictionary)),
+            ("list_of_dictionary", Arc::new(list_of_dictionary)),
+            ("fixed_size_list", Arc::new(fixed_size_list)),
+            ("map", Arc::new(map)),
+            ("sparse_union", Arc::new(sparse_union)),
+            ("dense_union", Arc::new(dense_union)),
+        ])
+        .unwrap();
+        let schema = Schema::new_with_metadata(
+            batch.schema().fields().clone(),
+            HashMap::from([("k".to_string(), "v".to_string())]),
+        );
+        let batch = batch.with_schema(Arc::new(schema.clone())).unwrap();
+
+        let (actual_schema, actual_batches) = roundtrip(&schema, &[batch.clone(), batch.clone()]);
+        assert_eq!(actual_schema, schema);
+        assert_eq!(actual_batches, vec![batch.clone(), batch.clone()]);
+
+        // Sliced arrays are written from their offset
+        let sliced = batch.slice(1, 2);
+        let (_, actual_batches) = roundtrip(&schema, std::slice::from_ref(&sliced));
+        assert_eq!(actual_batches, vec![sliced]);
+    }
+
+    #[test]
+    fn test_roundtrip_sliced_run_array() {
+        let values = StringArray::from(vec![Some("a"), None, Some("b")]);
+        let array =
+            RunArray::<Int32Type>::try_new(&Int32Array::from(vec![2, 5, 6]), &values).unwrap();
+        let batch = RecordBatch::try_from_iter([("ree", Arc::new(array) as ArrayRef)]).unwrap();
+
+        for (offset, len) in [(0, 6), (1, 3), (2, 3), (5, 1), (3, 0)] {
+            let sliced = batch.slice(offset, len);
+            let (_, actual_batches) = roundtrip(&batch.schema(), std::slice::from_ref(&sliced));
+            assert_eq!(actual_batches, vec![sliced]);
+        }
+    }
+
+    #[test]
+    fn test_dictionary_ids() {
+        let dictionary: DictionaryArray<Int32Type> = vec!["a", "b"].into_iter().collect();
+        let other: DictionaryArray<Int32Type> = vec!["c", "d"].into_iter().collect();
+        let batch = RecordBatch::try_from_iter([
+            ("a", Arc::new(dictionary) as ArrayRef),
+            ("b", Arc::new(other) as ArrayRef),
+        ])
+        .unwrap();
+
+        let json =
+            ArrowJson::try_from_batches(&batch.schema(), std::slice::from_ref(&batch)).unwrap();
+        let ids: Vec<_> = json
+            .dictionaries
+            .as_ref()
+            .unwrap()
+            .iter()
+            .map(|d| d.id)
+            .collect();
+        assert_eq!(ids, vec![0, 1]);
+        assert_eq!(json.get_record_batches().unwrap(), vec![batch]);
+    }
+
+    #[test]
+    fn test_dictionary_replacement() {
+        let schema = Schema::new(vec![Field::new(
+            "a",
+            DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8)),
+            true,
+        )]);
+        let first: DictionaryArray<Int32Type> = vec!["a", "b"].into_iter().collect();
+        let second: DictionaryArray<Int32Type> = vec!["c", "d"].into_iter().collect();
+        let batches = [first, second].map(|array| {
+            RecordBatch::try_new(Arc::new(schema.clone()), vec![Arc::new(array)]).unwrap()
+        });
+
+        let err = ArrowJson::try_from_batches(&schema, &batches).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Dictionary replacement is not supported: found different values for dictionary 0"
+        );
+    }
+
+    #[test]
+    fn test_array_to_json_type_mismatch() {
+        let field = Field::new("a", DataType::Int64, true);
+        let array = Int32Array::from(vec![1, 2]);
+        let err = array_to_json(&field, &array, &mut HashMap::new()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Field a has data type Int64 but array has data type Int32"
+        );
+    }
+}
@@ -176,9 +176,12 @@ impl CompressionCodec {
     /// [8 bytes]:         uncompressed length
     /// [remaining bytes]: compressed data stream
     /// ```
+    ///
+    /// Returns an error if the uncompressed length exceeds `max_decompressed_size`
     pub(crate) fn decompress_to_buffer(
         &self,
         input: &Buffer,
+        max_decompressed_size: usize,
         context: &mut DecompressionContext,
     ) -> Result<Buffer, ArrowError> {
         // read the first 8 bytes to determine if the data is
@@ -191,6 +194,11 @@ impl CompressionCodec {
             // no compression
             input.slice(LENGTH_OF_PREFIX_DATA as usize)
         } else if let Ok(decompressed_length) = usize::try_from(decompressed_length) {
+            if decompressed_length > max_decompressed_size {
+                return Err(ArrowError::IpcError(format!(
+                    "Decompressed buffer of {decompressed_length} bytes exceeds the maximum allowed buffer size of {max_decompressed_size} bytes"
+                )));
+            }
             // decompress data using the codec
             let input_data = &input[(LENGTH_OF_PREFIX_DATA as usize)..];
             let v = self.decompress(input_data, decompressed_length as _, context)?;
@@ -151,6 +151,19 @@ pub fn metadata_to_fb<'a>(
     fbb.create_vector(&custom_metadata)
 }
 
+/// Convert a flatbuffer key-value metadata list into a [HashMap]
+///
+/// Entries without a key or value are skipped
+pub(crate) fn fb_to_metadata(
+    metadata: Option<Vector<'_, ForwardsUOffset<KeyValue<'_>>>>,
+) -> HashMap<String, String> {
+    metadata
+        .into_iter()
+        .flatten()
+        .filter_map(|kv| Some((kv.key()?.to_string(), kv.value()?.to_string())))
+        .collect()
+}
+
 /// Adds a [Schema] to a flatbuffer and returns the offset
 pub fn schema_to_fb_offset<'a>(
     fbb: &mut FlatBufferBuilder<'a>,
@@ -179,16 +192,7 @@ impl From<crate::Field<'_>> for Field {
             )
         };
 
-        let mut metadata_map = HashMap::default();
-        if let Some(list) = field.custom_metadata() {
-            for kv in list {
-                if let (Some(k), Some(v)) = (kv.key(), kv.value()) {
-                    metadata_map.insert(k.to_string(), v.to_string());
-                }
-            }
-        }
-
-        arrow_field.with_metadata(metadata_map)
+        arrow_field.with_metadata(fb_to_metadata(field.custom_metadata()))
     }
 }
 
@@ -208,21 +212,7 @@ pub fn fb_to_schema(fb: crate::Schema) -> Schema {
         fields.push(c_field.into());
     }
 
-    let mut metadata: HashMap<String, String> = HashMap::default();
-    if let Some(md_fields) = fb.custom_metadata() {
-        let len = md_fields.len();
-        for i in 0..len {
-            let kv = md_fields.get(i);
-            let k_str = kv.key();
-            let v_str = kv.value();
-            if let Some(k) = k_str {
-                if let Some(v) = v_str {
-                    metadata.insert(k.to_string(), v.to_string());
-                }
-            }
-        }
-    }
-    Schema::new_with_metadata(fields, metadata)
+    Schema::new_with_metadata(fields, fb_to_metadata(fb.custom_metadata()))
 }
 
 /// Try deserialize flat buffer format bytes into a schema
@@ -27,6 +27,16 @@
 mod stream;
 pub use stream::*;
 
+#[cfg(feature = "async")]
+mod async_reader;
+#[cfg(feature = "async")]
+pub use async_reader::*;
+
+#[cfg(feature = "object_store")]
+mod store;
+#[cfg(feature = "object_store")]
+pub use store::*;
+
 use arrow_select::concat;
 
 use flatbuffers::{VectorIter, VerifierOptions};
@@ -47,6 +57,160 @@ use crate::r#gen::Message::{self};
 use crate::{Block, CONTINUATION_MARKER, FieldNode, MetadataVersion};
 use DataType::*;
 
+/// Limits enforced while reading IPC data, see [`IpcReadOptions::new`]
+///
+/// Length fields read from IPC data, such as the size of a message or the
+/// uncompressed length of a buffer, are checked against these limits before
+/// any memory is allocated for them, returning an error if they are exceeded.
+/// This protects services that read untrusted IPC data from excessive memory
+/// use caused by malicious or corrupt inputs.
+///
+/// By default no limits are enforced.
+///
+/// ```
+/// # use arrow_ipc::reader::{IpcReadOptions, StreamReader};
+/// # fn read(input: &[u8]) {
+/// let options = IpcReadOptions::new()
+///     .with_max_buffer_size(64 * 1024 * 1024)
+///     .with_max_batch_rows(1_000_000)
+///     .with_max_nesting_depth(16);
+/// let reader = StreamReader::try_new_with_options(input, None, options).unwrap();
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IpcReadOptions {
+    /// See [`IpcReadOptions::with_max_buffer_size`]
+    max_buffer_size: usize,
+    /// See [`IpcReadOptions::with_max_batch_rows`]
+    max_batch_rows: usize,
+    /// See [`IpcReadOptions::with_max_nesting_depth`]
+    max_nesting_depth: usize,
+}
+
+impl Default for IpcReadOptions {
+    fn default() -> Self {
+        Self {
+            max_buffer_size: usize::MAX,
+            max_batch_rows: usize::MAX,
+            max_nesting_depth: usize::MAX,
+        }
+    }
+}
+
+impl IpcReadOptions {
+    /// Create a new [`IpcReadOptions`] without any limits
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the maximum size in bytes of a single allocation made while reading,
+    /// this applies to IPC message metadata and bodies, the file footer, and
+    /// decompressed buffers
+    pub fn with_max_buffer_size(mut self, max_buffer_size: usize) -> Self {
+        self.max_buffer_size = max_buffer_size;
+        self
+    }
+
+    /// Set the maximum number of rows of a record batch or dictionary batch
+    pub fn with_max_batch_rows(mut self, max_batch_rows: usize) -> Self {
+        self.max_batch_rows = max_batch_rows;
+        self
+    }
+
+    /// Set the maximum nesting depth of the fields in a schema, where a schema
+    /// of only primitive fields has a depth of `1`, and a `List<Int32>` field a
+    /// depth of `2`
+    ///
+    /// Note that the number of nested flatbuffer tables is additionally limited
+    /// when parsing messages
+    pub fn with_max_nesting_depth(mut self, max_nesting_depth: usize) -> Self {
+        self.max_nesting_depth = max_nesting_depth;
+        self
+    }
+
+    /// Returns the maximum size in bytes of a single allocation made while reading
+    pub fn max_buffer_size(&self) -> usize {
+        self.max_buffer_size
+    }
+
+    /// Returns the maximum number of rows of a record batch or dictionary batch
+    pub fn max_batch_rows(&self) -> usize {
+        self.max_batch_rows
+    }
+
+    /// Returns the maximum nesting depth of the fields in a schema
+    pub fn max_nesting_depth(&self) -> usize {
+        self.max_nesting_depth
+    }
+
+    /// Returns an error if an allocation of `len` bytes for `what`, which is
+    /// used in the error message, exceeds the maximum buffer size
+    pub fn check_buffer_size(&self, what: &str, len: usize) -> Result<(), ArrowError> {
+        if len > self.max_buffer_size {
+            return Err(ArrowError::IpcError(format!(
+                "{what} of {len} bytes exceeds the maximum allowed buffer size of {} bytes",
+                self.max_buffer_size
+            )));
+        }
+        Ok(())
+    }
+
+    /// Returns an error if `rows` exceeds the maximum number of rows of a batch
+    pub(crate) fn check_batch_rows(&self, rows: i64) -> Result<(), ArrowError> {
+        match usize::try_from(rows) {
+            Ok(rows) if rows <= self.max_batch_rows => Ok(()),
+            Ok(rows) => Err(ArrowError::IpcError(format!(
+                "Batch of {rows} rows exceeds the maximum allowed number of rows of {}",
+                self.max_batch_rows
+            ))),
+            Err(_) => Err(ArrowError::IpcError(format!(
+                "Invalid batch length: {rows}"
+            ))),
+        }
+    }
+
+    /// Returns an error if the fields of `schema` exceed the maximum nesting depth
+    pub fn check_schema(&self, schema: &Schema) -> Result<(), ArrowError> {
+        for field in schema.fields() {
+            let depth = data_type_depth(field.data_type());
+            if depth > self.max_nesting_depth {
+                return Err(ArrowError::IpcError(format!(
+                    "Field {} with nesting depth {depth} exceeds the maximum allowed nesting depth of {}",
+                    field.name(),
+                    self.max_nesting_depth
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The nesting depth of `data_type`, see [`IpcReadOptions::with_max_nesting_depth`]
+fn data_type_depth(data_type: &DataType) -> usize {
+    let child_depth = match data_type {
+        List(f)
+        | LargeList(f)
+        | ListView(f)
+        | LargeListView(f)
+        | FixedSizeList(f, _)
+        | Map(f, _) => data_type_depth(f.data_type()),
+        Struct(fields) => fields
+            .iter()
+            .map(|f| data_type_depth(f.data_type()))
+            .max()
+            .unwrap_or_default(),
+        Union(fields, _) => fields
+            .iter()
+            .map(|(_, f)| data_type_depth(f.data_type()))
+            .max()
+            .unwrap_or_default(),
+        Dictionary(_, value) => return data_type_depth(value),
+        RunEndEncoded(_, values) => data_type_depth(values.data_type()),
+        _ => 0,
+    };
+    child_depth + 1
+}
+
 /// Read a buffer based on offset and length
 /// From <https://github.com/apache/arrow/blob/6a936c4ff5007045e86f65f1a6b6c3c955ad5103/format/Message.fbs#L58>
 /// Each constituent buffer is first compressed with the indicated
@@ -61,15 +225,18 @@ fn read_buffer(
     a_data: &Buffer,
     compression_codec: Option<CompressionCodec>,
     decompression_context: &mut DecompressionContext,
+    read_options: &IpcReadOptions,
 ) -> Result<Buffer, ArrowError> {
     let start_offset = buf.offset() as usize;
     let buf_data = a_data.slice_with_length(start_offset, buf.length() as usize);
     // corner case: empty buffer
     match (buf_data.is_empty(), compression_codec) {
         (true, _) | (_, None) => Ok(buf_data),
-        (false, Some(decompressor)) => {
-            decompressor.decompress_to_buffer(&buf_data, decompression_context)
-        }
+        (false, Some(decompressor)) => decompressor.decompress_to_buffer(
+            &buf_data,
+            read_options.max_buffer_size(),
+            decompression_context,
+        ),
     }
 }
 impl RecordBatchDecoder<'_> {
@@ -470,6 +637,8 @@ pub struct RecordBatchDecoder<'a> {
     ///
     /// See [`FileDecoder::with_skip_validation`] for details.
     skip_validation: UnsafeFlag,
+    /// Limits enforced while decoding, see [`RecordBatchDecoder::with_read_options`]
+    read_options: IpcReadOptions,
 }
 
 impl<'a> RecordBatchDecoder<'a> {
@@ -506,6 +675,7 @@ impl<'a> RecordBatchDecoder<'a> {
             projection: None,
             require_alignment: false,
             skip_validation: UnsafeFlag::new(),
+            read_options: IpcReadOptions::default(),
         })
     }
 
@@ -544,8 +714,18 @@ impl<'a> RecordBatchDecoder<'a> {
         self
     }
 
+    /// Set the limits enforced while decoding (default: no limits)
+    ///
+    /// See [`IpcReadOptions`] for details
+    pub fn with_read_options(mut self, read_options: IpcReadOptions) -> Self {
+        self.read_options = read_options;
+        self
+    }
+
     /// Read the record batch, consuming the reader
     fn read_record_batch(mut self) -> Result<RecordBatch, ArrowError> {
+        self.read_options.check_batch_rows(self.batch.length())?;
+
         let mut variadic_counts: VecDeque<i64> = self
             .batch
             .variadicBufferCounts()
@@ -629,6 +809,7 @@ impl<'a> RecordBatchDecoder<'a> {
             self.data,
             self.compression,
             &mut self.decompression_context,
+            &self.read_options,
         )
     }
 
@@ -771,10 +952,33 @@ pub fn read_record_batch(
     dictionaries_by_id: &HashMap<i64, ArrayRef>,
     projection: Option<&[usize]>,
     metadata: &MetadataVersion,
+) -> Result<RecordBatch, ArrowError> {
+    read_record_batch_with_options(
+        buf,
+        batch,
+        schema,
+        dictionaries_by_id,
+        projection,
+        metadata,
+        &IpcReadOptions::default(),
+    )
+}
+
+/// Creates a record batch as [`read_record_batch`], returning an error if any
+/// of the limits of `read_options` are exceeded
+pub fn read_record_batch_with_options(
+    buf: &Buffer,
+    batch: crate::RecordBatch,
+    schema: SchemaRef,
+    dictionaries_by_id: &HashMap<i64, ArrayRef>,
+    projection: Option<&[usize]>,
+    metadata: &MetadataVersion,
+    read_options: &IpcReadOptions,
 ) -> Result<RecordBatch, ArrowError> {
     RecordBatchDecoder::try_new(buf, batch, schema, dictionaries_by_id, metadata)?
         .with_projection(projection)
         .with_require_alignment(false)
+        .with_read_options(*read_options)
         .read_record_batch()
 }
 
@@ -786,6 +990,26 @@ pub fn read_dictionary(
     schema: &Schema,
     dictionaries_by_id: &mut HashMap<i64, ArrayRef>,
     metadata: &MetadataVersion,
+) -> Result<(), ArrowError> {
+    read_dictionary_with_options(
+        buf,
+        batch,
+        schema,
+        dictionaries_by_id,
+        metadata,
+        &IpcReadOptions::default(),
+    )
+}
+
+/// Read the dictionary as [`read_dictionary`], returning an error if any
+/// of the limits of `read_options` are exceeded
+pub fn read_dictionary_with_options(
+    buf: &Buffer,
+    batch: crate::DictionaryBatch,
+    schema: &Schema,
+    dictionaries_by_id: &mut HashMap<i64, ArrayRef>,
+    metadata: &MetadataVersion,
+    read_options: &IpcReadOptions,
 ) -> Result<(), ArrowError> {
     read_dictionary_impl(
         buf,
@@ -795,9 +1019,11 @@ pub fn read_dictionary(
         metadata,
         false,
         UnsafeFlag::new(),
+        read_options,
     )
 }
 
+#[allow(clippy::too_many_arguments)]
 fn read_dictionary_impl(
     buf: &Buffer,
     batch: crate::DictionaryBatch,
@@ -806,6 +1032,7 @@ fn read_dictionary_impl(
     metadata: &MetadataVersion,
     require_alignment: bool,
     skip_validation: UnsafeFlag,
+    read_options: &IpcReadOptions,
 ) -> Result<(), ArrowError> {
     let id = batch.id();
 
@@ -817,6 +1044,7 @@ fn read_dictionary_impl(
         metadata,
         require_alignment,
         skip_validation,
+        read_options,
     )?;
 
     update_dictionaries(dictionaries_by_id, batch.isDelta(), id, dictionary_values)?;
@@ -864,6 +1092,7 @@ fn update_dictionaries(
 /// Given a dictionary batch IPC message/body along with the full state of a
 /// stream including schema, dictionary cache, metadata, and other flags, this
 /// function will parse the buffer into an array of dictionary values.
+#[allow(clippy::too_many_arguments)]
 fn get_dictionary_values(
     buf: &Buffer,
     batch: crate::DictionaryBatch,
@@ -872,6 +1101,7 @@ fn get_dictionary_values(
     metadata: &MetadataVersion,
     require_alignment: bool,
     skip_validation: UnsafeFlag,
+    read_options: &IpcReadOptions,
 ) -> Result<ArrayRef, ArrowError> {
     let id = batch.id();
     #[allow(deprecated)]
@@ -898,6 +1128,7 @@ fn get_dictionary_values(
             )?
             .with_require_alignment(require_alignment)
             .with_skip_validation(skip_validation)
+            .with_read_options(*read_options)
             .read_record_batch()?;
 
             Some(record_batch.column(0).clone())
@@ -912,17 +1143,36 @@ fn get_dictionary_values(
 }
 
 /// Read the data for a given block
-fn read_block<R: Read + Seek>(mut reader: R, block: &Block) -> Result<Buffer, ArrowError> {
+pub(crate) fn read_block<R: Read + Seek>(
+    mut reader: R,
+    block: &Block,
+    read_options: &IpcReadOptions,
+) -> Result<Buffer, ArrowError> {
+    let total_len = block_len(block)?;
+    read_options.check_buffer_size("Block", total_len)?;
     reader.seek(SeekFrom::Start(block.offset() as u64))?;
-    let body_len = block.bodyLength().to_usize().unwrap();
-    let metadata_len = block.metaDataLength().to_usize().unwrap();
-    let total_len = body_len.checked_add(metadata_len).unwrap();
 
     let mut buf = MutableBuffer::from_len_zeroed(total_len);
     reader.read_exact(&mut buf)?;
     Ok(buf.into())
 }
 
+/// Returns the total length of the metadata and body of `block`
+pub(crate) fn block_len(block: &Block) -> Result<usize, ArrowError> {
+    let body_len = block.bodyLength().to_usize();
+    let metadata_len = block.metaDataLength().to_usize();
+    body_len
+        .zip(metadata_len)
+        .and_then(|(body_len, metadata_len)| body_len.checked_add(metadata_len))
+        .ok_or_else(|| {
+            ArrowError::IpcError(format!(
+                "Invalid block with metadata length {} and body length {}",
+                block.metaDataLength(),
+                block.bodyLength()
+            ))
+        })
+}
+
 /// Parse an encapsulated message
 ///
 /// <https://arrow.apache.org/docs/format/Columnar.html#encapsulated-message-format>
@@ -952,6 +1202,54 @@ pub fn read_footer_length(buf: [u8; 10]) -> Result<usize, ArrowError> {
         .map_err(|_| ArrowError::ParseError(format!("Invalid footer length: {footer_len}")))
 }
 
+/// A [`RecordBatch`] along with the custom metadata of the IPC message it was read from
+pub type RecordBatchWithCustomMetadata = (RecordBatch, HashMap<String, String>);
+
+/// The decoded contents of an IPC file footer
+pub(crate) struct FileFooter {
+    pub(crate) schema: Schema,
+    pub(crate) version: MetadataVersion,
+    pub(crate) dictionaries: Vec<Block>,
+    pub(crate) record_batches: Vec<Block>,
+    pub(crate) custom_metadata: HashMap<String, String>,
+}
+
+/// Decode the footer flatbuffer of an IPC file, i.e. the `footer_len` bytes
+/// preceding the trailing footer length and `b"ARROW1"`
+pub(crate) fn decode_footer(
+    footer_data: &[u8],
+    verifier_options: &VerifierOptions,
+) -> Result<FileFooter, ArrowError> {
+    let footer = crate::root_as_footer_with_opts(verifier_options, footer_data)
+        .map_err(|err| ArrowError::ParseError(format!("Unable to get root as footer: {err:?}")))?;
+
+    let blocks = footer.recordBatches().ok_or_else(|| {
+        ArrowError::ParseError("Unable to get record batches from IPC Footer".to_string())
+    })?;
+
+    let ipc_schema = footer.schema().unwrap();
+    if !ipc_schema.endianness().equals_to_target_endianness() {
+        return Err(ArrowError::IpcError(
+            "the endianness of the source system does not match the endianness of the target system.".to_owned()
+        ));
+    }
+
+    let schema = crate::convert::fb_to_schema(ipc_schema);
+
+    let custom_metadata = crate::convert::fb_to_metadata(footer.custom_metadata());
+
+    Ok(FileFooter {
+        schema,
+        version: footer.version(),
+        dictionaries: footer
+            .dictionaries()
+            .map(|d| d.iter().copied().collect())
+            .unwrap_or_default(),
+        record_batches: blocks.iter().copied().collect(),
+        custom_metadata,
+    })
+}
+
 /// A low-level, push-based interface for reading an IPC file
 ///
 /// For a higher-level interface see [`FileReader`]
@@ -1023,7 +1321,9 @@ pub struct FileDecoder {
     version: MetadataVersion,
     projection: Option<Vec<usize>>,
     require_alignment: bool,
+    buffer_alignment: Option<usize>,
     skip_validation: UnsafeFlag,
+    read_options: IpcReadOptions,
 }
 
 impl FileDecoder {
@@ -1035,7 +1335,9 @@ impl FileDecoder {
             dictionaries: Default::default(),
             projection: None,
             require_alignment: false,
+            buffer_alignment: None,
             skip_validation: UnsafeFlag::new(),
+            read_options: IpcReadOptions::default(),
         }
     }
 
@@ -1045,6 +1347,14 @@ impl FileDecoder {
         self
     }
 
+    /// Specify the limits enforced while decoding (default: no limits)
+    ///
+    /// See [`IpcReadOptions`] for details
+    pub fn with_read_options(mut self, read_options: IpcReadOptions) -> Self {
+        self.read_options = read_options;
+        self
+    }
+
     /// Specifies if the array data in input buffers is required to be properly aligned.
     ///
     /// If `require_alignment` is true, this decoder will return an error if any array data in the
@@ -1062,6 +1372,30 @@ impl FileDecoder {
         self
     }
 
+    /// Specifies the alignment, in bytes from the start of the file, that each block and
+    /// each buffer within it must have (default: `None`)
+    ///
+    /// If set, reading a block whose offset, body or buffers are not a multiple of
+    /// `buffer_alignment` returns an error. Files written with
+    /// [`IpcWriteOptions::try_with_alignment`] satisfy this for the same alignment, which
+    /// guarantees each buffer of a memory mapped file is aligned in memory, for example
+    /// to copy it directly to a GPU.
+    ///
+    /// This only applies to the Arrow File format, whose footer records the offset of
+    /// each block in the file. Messages of the Arrow Stream format have no known offset,
+    /// so [`StreamDecoder`] and [`StreamReader`] do not verify the alignment of buffers.
+    ///
+    /// Returns an error if `buffer_alignment` is not a power of two.
+    ///
+    /// [`IpcWriteOptions::try_with_alignment`]: crate::writer::IpcWriteOptions::try_with_alignment
+    pub fn try_with_buffer_alignment(
+        mut self,
+        buffer_alignment: Option<usize>,
+    ) -> Result<Self, ArrowError> {
+        self.buffer_alignment = validate_buffer_alignment(buffer_alignment)?;
+        Ok(self)
+    }
+
     /// Specifies if validation should be skipped when reading data (defaults to `false`)
     ///
     /// # Safety
@@ -1077,6 +1411,35 @@ impl FileDecoder {
         self
     }
 
+    /// Returns an error if `block` or any of `buffers` are not aligned to
+    /// [`Self::try_with_buffer_alignment`]
+    fn check_buffer_alignment<'a>(
+        &self,
+        block: &Block,
+        buffers: impl IntoIterator<Item = &'a crate::Buffer>,
+    ) -> Result<(), ArrowError> {
+        let Some(alignment) = self.buffer_alignment else {
+            return Ok(());
+        };
+        let body = block.offset() + block.metaDataLength() as i64;
+        let offsets = [block.offset(), body]
+            .into_iter()
+            .chain(buffers.into_iter().map(|b| body + b.offset()));
+        for offset in offsets {
+            if offset % alignment as i64 != 0 {
+                return Err(ArrowError::IpcError(format!(
+                    "IPC buffer at file offset {offset} is not aligned to {alignment} bytes"
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// The dictionaries read so far, indexed by dictionary id
+    pub(crate) fn dictionaries(&self) -> &HashMap<i64, ArrayRef> {
+        &self.dictionaries
+    }
+
     fn read_message<'a>(&self, buf: &'a [u8]) -> Result<Message::Message<'a>, ArrowError> {
         let message = parse_message(buf)?;
 
@@ -1095,6 +1458,8 @@ impl FileDecoder {
         match message.header_type() {
             crate::MessageHeader::DictionaryBatch => {
                 let batch = message.header_as_dictionary_batch().unwrap();
+                let buffers = batch.data().and_then(|d| d.buffers());
+                self.check_buffer_alignment(block, buffers.iter().flatten())?;
                 read_dictionary_impl(
                     &buf.slice(block.metaDataLength() as _),
                     batch,
@@ -1103,6 +1468,7 @@ impl FileDecoder {
                     &message.version(),
                     self.require_alignment,
                     self.skip_validation.clone(),
+                    &self.read_options,
                 )
             }
             t => Err(ArrowError::ParseError(format!(
@@ -1117,6 +1483,18 @@ impl FileDecoder {
         block: &Block,
         buf: &Buffer,
     ) -> Result<Option<RecordBatch>, ArrowError> {
+        Ok(self
+            .read_record_batch_with_custom_metadata(block, buf)?
+            .map(|(batch, _)| batch))
+    }
+
+    /// Read the RecordBatch with the given block and data buffer, along with the
+    /// custom metadata of its IPC message
+    pub fn read_record_batch_with_custom_metadata(
+        &self,
+        block: &Block,
+        buf: &Buffer,
+    ) -> Result<Option<RecordBatchWithCustomMetadata>, ArrowError> {
         let message = self.read_message(buf)?;
         match message.header_type() {
             crate::MessageHeader::Schema => Err(ArrowError::IpcError(
@@ -1126,8 +1504,9 @@ impl FileDecoder {
                 let batch = message.header_as_record_batch().ok_or_else(|| {
                     ArrowError::IpcError("Unable to read IPC message as record batch".to_string())
                 })?;
+                self.check_buffer_alignment(block, batch.buffers().iter().flatten())?;
                 // read the block that makes up the record batch into a buffer
-                RecordBatchDecoder::try_new(
+                let batch = RecordBatchDecoder::try_new(
                     &buf.slice(block.metaDataLength() as _),
                     batch,
                     self.schema.clone(),
@@ -1137,8 +1516,10 @@ impl FileDecoder {
                 .with_projection(self.projection.as_deref())
                 .with_require_alignment(self.require_alignment)
                 .with_skip_validation(self.skip_validation.clone())
-                .read_record_batch()
-                .map(Some)
+                .with_read_options(self.read_options)
+                .read_record_batch()?;
+                let custom_metadata = crate::convert::fb_to_metadata(message.custom_metadata());
+                Ok(Some((batch, custom_metadata)))
             }
             crate::MessageHeader::NONE => Ok(None),
             t => Err(ArrowError::InvalidArgumentError(format!(
@@ -1148,6 +1529,16 @@ impl FileDecoder {
     }
 }
 
+/// Returns `buffer_alignment` if it is `None` or a power of two, or an error otherwise
+fn validate_buffer_alignment(buffer_alignment: Option<usize>) -> Result<Option<usize>, ArrowError> {
+    match buffer_alignment {
+        Some(alignment) if !alignment.is_power_of_two() => Err(ArrowError::InvalidArgumentError(
+            format!("Buffer alignment must be a power of two, got {alignment}"),
+        )),
+        _ => Ok(buffer_alignment),
+    }
+}
+
 /// Build an Arrow [`FileReader`] with custom options.
 #[derive(Debug)]
 pub struct FileReaderBuilder {
@@ -1157,6 +1548,10 @@ pub struct FileReaderBuilder {
     max_footer_fb_tables: usize,
     /// Passed through to construct [`VerifierOptions`]
     max_footer_fb_depth: usize,
+    /// Limits enforced while reading, see [`FileReaderBuilder::with_read_options`]
+    read_options: IpcReadOptions,
+    /// See [`FileReaderBuilder::try_with_buffer_alignment`]
+    buffer_alignment: Option<usize>,
 }
 
 impl Default for FileReaderBuilder {
@@ -1166,6 +1561,8 @@ impl Default for FileReaderBuilder {
             max_footer_fb_tables: verifier_options.max_tables,
             max_footer_fb_depth: verifier_options.max_depth,
             projection: None,
+            read_options: IpcReadOptions::default(),
+            buffer_alignment: None,
         }
     }
 }
@@ -1218,6 +1615,36 @@ impl FileReaderBuilder {
         self
     }
 
+    /// Limits enforced while reading the file (default: no limits)
+    ///
+    /// See [`IpcReadOptions`] for details
+    pub fn with_read_options(mut self, read_options: IpcReadOptions) -> Self {
+        self.read_options = read_options;
+        self
+    }
+
+    /// Verify that each buffer is aligned to `buffer_alignment` bytes from the start of
+    /// the file (default: `None`)
+    ///
+    /// Returns an error if `buffer_alignment` is not a power of two, see
+    /// [`FileDecoder::try_with_buffer_alignment`] for details
+    pub fn try_with_buffer_alignment(
+        mut self,
+        buffer_alignment: Option<usize>,
+    ) -> Result<Self, ArrowError> {
+        self.buffer_alignment = validate_buffer_alignment(buffer_alignment)?;
+        Ok(self)
+    }
+
+    /// The [`VerifierOptions`] used to parse the footer
+    fn verifier_options(&self) -> VerifierOptions {
+        VerifierOptions {
+            max_tables: self.max_footer_fb_tables,
+            max_depth: self.max_footer_fb_depth,
+            ..Default::default()
+        }
+    }
+
     /// Build [`FileReader`] with given reader.
     pub fn build<R: Read + Seek>(self, mut reader: R) -> Result<FileReader<R>, ArrowError> {
         // Space for ARROW_MAGIC (6 bytes) and length (4 bytes)
@@ -1226,66 +1653,38 @@ impl FileReaderBuilder {
         reader.read_exact(&mut buffer)?;
 
         let footer_len = read_footer_length(buffer)?;
+        self.read_options.check_buffer_size("Footer", footer_len)?;
 
         // read footer
         let mut footer_data = vec![0; footer_len];
         reader.seek(SeekFrom::End(-10 - footer_len as i64))?;
         reader.read_exact(&mut footer_data)?;
 
-        let verifier_options = VerifierOptions {
-            max_tables: self.max_footer_fb_tables,
-            max_depth: self.max_footer_fb_depth,
-            ..Default::default()
-        };
-        let footer = crate::root_as_footer_with_opts(&verifier_options, &footer_data[..]).map_err(
-            |err| ArrowError::ParseError(format!("Unable to get root as footer: {err:?}")),
-        )?;
+        let footer = decode_footer(&footer_data, &self.verifier_options())?;
+        self.read_options.check_schema(&footer.schema)?;
+        let total_blocks = footer.record_batches.len();
 
-        let blocks = footer.recordBatches().ok_or_else(|| {
-            ArrowError::ParseError("Unable to get record batches from IPC Footer".to_string())
-        })?;
-
-        let total_blocks = blocks.len();
-
-        let ipc_schema = footer.schema().unwrap();
-        if !ipc_schema.endianness().equals_to_target_endianness() {
-            return Err(ArrowError::IpcError(
-                "the endianness of the source system does not match the endianness of the target system.".to_owned()
-            ));
-        }
-
-        let schema = crate::convert::fb_to_schema(ipc_schema);
-
-        let mut custom_metadata = HashMap::new();
-        if let Some(fb_custom_metadata) = footer.custom_metadata() {
-            for kv in fb_custom_metadata.into_iter() {
-                custom_metadata.insert(
-                    kv.key().unwrap().to_string(),
-                    kv.value().unwrap().to_string(),
-                );
-            }
-        }
-
-        let mut decoder = FileDecoder::new(Arc::new(schema), footer.version());
+        let mut decoder = FileDecoder::new(Arc::new(footer.schema), footer.version)
+            .with_read_options(self.read_options);
+        // already validated by `Self::try_with_buffer_alignment`
+        decoder.buffer_alignment = self.buffer_alignment;
         if let Some(projection) = self.projection {
             decoder = decoder.with_projection(projection)
         }
 
         // Create an array of optional dictionary value arrays, one per field.
-        if let Some(dictionaries) = footer.dictionaries() {
-            for block in dictionaries {
-                let buf = read_block(&mut reader, block)?;
-                decoder.read_dictionary(block, &buf)?;
-            }
+        for block in &footer.dictionaries {
+            let buf = read_block(&mut reader, block, &self.read_options)?;
+            decoder.read_dictionary(block, &buf)?;
         }
 
         Ok(FileReader {
             reader,
-            blocks: blocks.iter().copied().collect(),
+            blocks: footer.record_batches,
             current_block: 0,
             total_blocks,
             decoder,
-            custom_metadata,
+            custom_metadata: footer.custom_metadata,
         })
     }
 }
@@ -1426,12 +1825,28 @@ impl<R: Read + Seek> FileReader<R> {
     }
 
     fn maybe_next(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
+        Ok(self.next_with_custom_metadata()?.map(|(batch, _)| batch))
+    }
+
+    /// Read the next [`RecordBatch`] along with the custom metadata of its IPC message,
+    /// as written by [`FileWriter::write_with_custom_metadata`]
+    ///
+    /// Returns `Ok(None)` once all batches have been read
+    ///
+    /// [`FileWriter::write_with_custom_metadata`]: crate::writer::FileWriter::write_with_custom_metadata
+    pub fn next_with_custom_metadata(
+        &mut self,
+    ) -> Result<Option<RecordBatchWithCustomMetadata>, ArrowError> {
+        if self.current_block >= self.total_blocks {
+            return Ok(None);
+        }
         let block = &self.blocks[self.current_block];
         self.current_block += 1;
 
         // read length
-        let buffer = read_block(&mut self.reader, block)?;
-        self.decoder.read_record_batch(block, &buffer)
+        let buffer = read_block(&mut self.reader, block, &self.decoder.read_options)?;
+        self.decoder
+            .read_record_batch_with_custom_metadata(block, &buffer)
     }
 
     /// Gets a reference to the underlying reader.
@@ -1574,7 +1989,19 @@ impl<R: Read> StreamReader<R> {
         reader: R,
         projection: Option<Vec<usize>>,
     ) -> Result<StreamReader<R>, ArrowError> {
-        let mut msg_reader = MessageReader::new(reader);
+        Self::try_new_with_options(reader, projection, IpcReadOptions::default())
+    }
+
+    /// Try to create a new stream reader, returning an error when reading data that
+    /// exceeds any of the limits of `read_options`
+    ///
+    /// See [`IpcReadOptions`] and [`StreamReader::try_new`] for details
+    pub fn try_new_with_options(
+        reader: R,
+        projection: Option<Vec<usize>>,
+        read_options: IpcReadOptions,
+    ) -> Result<StreamReader<R>, ArrowError> {
+        let mut msg_reader = MessageReader::new(reader, read_options);
         let message = msg_reader.maybe_next()?;
         let Some((message, _)) = message else {
             return Err(ArrowError::IpcError(
@@ -1593,6 +2020,7 @@ impl<R: Read> StreamReader<R> {
             ArrowError::ParseError("Failed to parse schema from message header".to_string())
         })?;
         let schema = crate::convert::fb_to_schema(schema);
+        read_options.check_schema(&schema)?;
 
         // Create an array of optional dictionary value arrays, one per field.
         let dictionaries_by_id = HashMap::new();
@@ -1635,6 +2063,18 @@ impl<R: Read> StreamReader<R> {
     }
 
     fn maybe_next(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
+        Ok(self.next_with_custom_metadata()?.map(|(batch, _)| batch))
+    }
+
+    /// Read the next [`RecordBatch`] along with the custom metadata of its IPC message,
+    /// as written by [`StreamWriter::write_with_custom_metadata`]
+    ///
+    /// Returns `Ok(None)` at the end of the stream
+    ///
+    /// [`StreamWriter::write_with_custom_metadata`]: crate::writer::StreamWriter::write_with_custom_metadata
+    pub fn next_with_custom_metadata(
+        &mut self,
+    ) -> Result<Option<RecordBatchWithCustomMetadata>, ArrowError> {
         if self.finished {
             return Ok(None);
         }
@@ -1654,8 +2094,8 @@ impl<R: Read> StreamReader<R> {
                         "Expected a record batch, but found a schema".to_string(),
                     ));
                 }
-                IpcMessage::RecordBatch(record_batch) => {
-                    return Ok(Some(record_batch));
+                IpcMessage::RecordBatch(record_batch, custom_metadata) => {
+                    return Ok(Some((record_batch, custom_metadata)));
                 }
                 IpcMessage::DictionaryBatch { .. } => {
                     continue;
@@ -1672,6 +2112,7 @@ impl<R: Read> StreamReader<R> {
     /// This is useful primarily for testing reader/writer behaviors as it
     /// allows a full view into the messages that have been written to a stream.
     pub(crate) fn next_ipc_message(&mut self) -> Result<Option<IpcMessage>, ArrowError> {
+        let read_options = self.reader.read_options;
         let message = self.reader.maybe_next()?;
         let Some((message, body)) = message else {
             // If the message is None, we have reached the end of the stream.
@@ -1703,8 +2144,10 @@ impl<R: Read> StreamReader<R> {
                 .with_projection(self.projection.as_ref().map(|x| x.0.as_ref()))
                 .with_require_alignment(false)
                 .with_skip_validation(self.skip_validation.clone())
+                .with_read_options(read_options)
                 .read_record_batch()?;
-                IpcMessage::RecordBatch(record_batch)
+                let custom_metadata = crate::convert::fb_to_metadata(message.custom_metadata());
+                IpcMessage::RecordBatch(record_batch, custom_metadata)
             }
             Message::MessageHeader::DictionaryBatch => {
                 let dict = message.header_as_dictionary_batch().ok_or_else(|| {
@@ -1722,6 +2165,7 @@ impl<R: Read> StreamReader<R> {
                     &version,
                     false,
                     self.skip_validation.clone(),
+                    &read_options,
                 )?;
 
                 update_dictionaries(
@@ -1795,7 +2239,7 @@ impl<R: Read> RecordBatchReader for StreamReader<R> {
 #[allow(dead_code)]
 pub(crate) enum IpcMessage {
     Schema(arrow_schema::Schema),
-    RecordBatch(RecordBatch),
+    RecordBatch(RecordBatch, HashMap<String, String>),
     DictionaryBatch {
         id: i64,
         is_delta: bool,
@@ -1808,13 +2252,15 @@ pub(crate) enum IpcMessage {
 struct MessageReader<R> {
     reader: R,
     buf: Vec<u8>,
+    read_options: IpcReadOptions,
 }
 
 impl<R: Read> MessageReader<R> {
-    fn new(reader: R) -> Self {
+    fn new(reader: R, read_options: IpcReadOptions) -> Self {
         Self {
             reader,
             buf: Vec::new(),
+            read_options,
         }
     }
 
@@ -1833,6 +2279,8 @@ impl<R: Read> MessageReader<R> {
         let Some(meta_len) = meta_len else {
             return Ok(None);
         };
+        self.read_options
+            .check_buffer_size("Message metadata", meta_len)?;
 
         self.buf.resize(meta_len, 0);
         self.reader.read_exact(&mut self.buf)?;
@@ -1841,7 +2289,16 @@ impl<R: Read> MessageReader<R> {
             ArrowError::ParseError(format!("Unable to get root as message: {err:?}"))
         })?;
 
-        let mut buf = MutableBuffer::from_len_zeroed(message.bodyLength() as usize);
+        let body_len = usize::try_from(message.bodyLength()).map_err(|_| {
+            ArrowError::ParseError(format!(
+                "Invalid message body length: {}",
+                message.bodyLength()
+            ))
+        })?;
+        self.read_options
+            .check_buffer_size("Message body", body_len)?;
+
+        let mut buf = MutableBuffer::from_len_zeroed(body_len);
         self.reader.read_exact(&mut buf)?;
 
         Ok(Some((message, buf)))
@@ -2514,6 +2971,66 @@ mod tests {
         assert_eq!(reader.custom_metadata(), &test_metadata);
     }
 
+    #[test]
+    fn test_roundtrip_message_custom_metadata() {
+        let dict: DictionaryArray<Int32Type> = vec!["a", "b", "a"].into_iter().collect();
+        let field_metadata = HashMap::from([("field".to_string(), "dict".to_string())]);
+        let schema_metadata = HashMap::from([("schema".to_string(), "s".to_string())]);
+        let schema = Arc::new(Schema::new_with_metadata(
+            vec![Field::new("dict", dict.data_type().clone(), true).with_metadata(field_metadata)],
+            schema_metadata,
+        ));
+        let batch = RecordBatch::try_new(schema.clone(), vec![Arc::new(dict)]).unwrap();
+
+        let messages = [
+            HashMap::from([("batch".to_string(), "0".to_string())]),
+            HashMap::new(),
+            HashMap::from([
+                ("batch".to_string(), "2".to_string()),
+                ("lineage".to_string(), "abc".to_string()),
+            ]),
+        ];
+
+        let mut buf = Vec::new();
+        let mut writer = crate::writer::StreamWriter::try_new(&mut buf, &schema).unwrap();
+        for metadata in &messages {
+            writer.write_with_custom_metadata(&batch, metadata).unwrap();
+        }
+        writer.finish().unwrap();
+        drop(writer);
+
+        let mut reader = StreamReader::try_new(std::io::Cursor::new(buf), None).unwrap();
+        assert_eq!(reader.schema(), schema);
+        for expected in &messages {
+            let (read, metadata) = reader.next_with_custom_metadata().unwrap().unwrap();
+            assert_eq!(read, batch);
+            assert_eq!(read.schema().metadata(), schema.metadata());
+            assert_eq!(
+                read.schema().field(0).metadata(),
+                schema.field(0).metadata()
+            );
+            assert_eq!(&metadata, expected);
+        }
+        assert!(reader.next_with_custom_metadata().unwrap().is_none());
+
+        let mut buf = Vec::new();
+        let mut writer = crate::writer::FileWriter::try_new(&mut buf, &schema).unwrap();
+        for metadata in &messages {
+            writer.write_with_custom_metadata(&batch, metadata).unwrap();
+        }
+        writer.finish().unwrap();
+        drop(writer);
+
+        let mut reader = FileReader::try_new(std::io::Cursor::new(buf), None).unwrap();
+        assert_eq!(reader.schema(), schema);
+        for expected in &messages {
+            let (read, metadata) = reader.next_with_custom_metadata().unwrap().unwrap();
+            assert_eq!(read, batch);
+            assert_eq!(&metadata, expected);
+        }
+        assert!(reader.next_with_custom_metadata().unwrap().is_none());
+    }
+
     #[test]
     fn test_roundtrip_nested_dict() {
         let inner: DictionaryArray<Int32Type> = vec!["a", "b", "a"].into_iter().collect();
@@ -2980,6 +3497,11 @@ mod tests {
         let input_batch = RecordBatch::try_new_with_options(schema, vec![], &options).unwrap();
         let output_batch = roundtrip_ipc_stream(&input_batch);
         assert_eq!(input_batch, output_batch);
+        assert_eq!(input_batch, roundtrip_ipc(&input_batch));
+
+        let sliced_batch = input_batch.slice(2, 5);
+        assert_eq!(roundtrip_ipc(&sliced_batch).num_rows(), 5);
+        assert_eq!(roundtrip_ipc_stream(&sliced_batch).num_rows(), 5);
     }
 
     #[test]
@@ -3446,6 +3968,206 @@ mod tests {
         assert!(reader.is_err());
     }
 
+    #[test]
+    fn test_read_options_max_buffer_size() {
+        let array = Int32Array::from_iter_values(0..1024);
+        let batch = RecordBatch::try_from_iter([("a", Arc::new(array) as ArrayRef)]).unwrap();
+        let options = IpcReadOptions::new().with_max_buffer_size(1024);
+
+        let stream = write_stream(&batch);
+        let mut reader =
+            StreamReader::try_new_with_options(stream.as_slice(), None, options).unwrap();
+        let err = reader.next().unwrap().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Ipc error: Message body of 4224 bytes exceeds the maximum allowed buffer size of 1024 bytes"
+        );
+
+        let mut decoder = StreamDecoder::new().with_read_options(options);
+        let err = decoder.decode(&mut Buffer::from_vec(stream)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Ipc error: Message body of 4224 bytes exceeds the maximum allowed buffer size of 1024 bytes"
+        );
+
+        let file = write_ipc(&batch);
+        let mut reader = FileReaderBuilder::new()
+            .with_read_options(options)
+            .build(Cursor::new(file))
+            .unwrap();
+        let err = reader.next().unwrap().unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("exceeds the maximum allowed buffer size of 1024 bytes"),
+            "{err}"
+        );
+
+        let options = IpcReadOptions::new().with_max_buffer_size(8192);
+        let stream = write_stream(&batch);
+        let mut reader =
+            StreamReader::try_new_with_options(stream.as_slice(), None, options).unwrap();
+        assert_eq!(reader.next().unwrap().unwrap(), batch);
+    }
+
+    #[test]
+    fn test_read_options_corrupt_lengths() {
+        // A message claiming ~2GB of metadata should error before allocating
+        let mut buf = vec![];
+        buf.extend(CONTINUATION_MARKER);
+        buf.extend(i32::MAX.to_le_bytes());
+
+        let options = IpcReadOptions::new().with_max_buffer_size(1024 * 1024);
+        let err = StreamReader::try_new_with_options(Cursor::new(&buf), None, options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Ipc error: Message metadata of 2147483647 bytes exceeds the maximum allowed buffer size of 1048576 bytes"
+        );
+
+        let mut decoder = StreamDecoder::new().with_read_options(options);
+        let err = decoder.decode(&mut Buffer::from_vec(buf)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Ipc error: Message metadata of 2147483647 bytes exceeds the maximum allowed buffer size of 1048576 bytes"
+        );
+
+        // A file claiming a ~2GB footer should error before allocating
+        let batch = RecordBatch::try_from_iter([(
+            "a",
+            Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef,
+        )])
+        .unwrap();
+        let mut file = write_ipc(&batch);
+        let len = file.len();
+        file[len - 10..len - 6].copy_from_slice(&i32::MAX.to_le_bytes());
+        let err = FileReaderBuilder::new()
+            .with_read_options(options)
+            .build(Cursor::new(file))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Ipc error: Footer of 2147483647 bytes exceeds the maximum allowed buffer size of 1048576 bytes"
+        );
+    }
+
+    #[test]
+    fn test_read_options_max_batch_rows() {
+        let array = Int32Array::from(vec![1, 2, 3, 4, 5]);
+        let batch = RecordBatch::try_from_iter([("a", Arc::new(array) as ArrayRef)]).unwrap();
+        let options = IpcReadOptions::new().with_max_batch_rows(4);
+        let expected = "Ipc error: Batch of 5 rows exceeds the maximum allowed number of rows of 4";
+
+        let stream = write_stream(&batch);
+        let mut reader =
+            StreamReader::try_new_with_options(stream.as_slice(), None, options).unwrap();
+        let err = reader.next().unwrap().unwrap_err();
+        assert_eq!(err.to_string(), expected);
+
+        let mut decoder = StreamDecoder::new().with_read_options(options);
+        let err = decoder.decode(&mut Buffer::from_vec(stream)).unwrap_err();
+        assert_eq!(err.to_string(), expected);
+
+        let file = write_ipc(&batch);
+        let mut reader = FileReaderBuilder::new()
+            .with_read_options(options)
+            .build(Cursor::new(file))
+            .unwrap();
+        let err = reader.next().unwrap().unwrap_err();
+        assert_eq!(err.to_string(), expected);
+
+        // Dictionary batches are also limited
+        let values = StringArray::from_iter_values(["a", "b", "c", "d", "e"]);
+        let keys = Int8Array::from(vec![0, 1]);
+        let dict = DictionaryArray::new(keys, Arc::new(values));
+        let batch = RecordBatch::try_from_iter([("d", Arc::new(dict) as ArrayRef)]).unwrap();
+        let stream = write_stream(&batch);
+        let mut reader =
+            StreamReader::try_new_with_options(stream.as_slice(), None, options).unwrap();
+        let err = reader.next().unwrap().unwrap_err();
+        assert_eq!(err.to_string(), expected);
+    }
+
+    #[test]
+    fn test_read_options_max_nesting_depth() {
+        let inner = Field::new_list("inner", Field::new_list_field(DataType::Int32, true), true);
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new_list("b", inner, true),
+        ]));
+        let batch = RecordBatch::new_empty(schema);
+        let options = IpcReadOptions::new().with_max_nesting_depth(2);
+        let expected = "Ipc error: Field b with nesting depth 3 exceeds the maximum allowed nesting depth of 2";
+
+        let stream = write_stream(&batch);
+        let err = StreamReader::try_new_with_options(stream.as_slice(), None, options).unwrap_err();
+        assert_eq!(err.to_string(), expected);
+
+        let mut decoder = StreamDecoder::new().with_read_options(options);
+        let err = decoder
+            .decode(&mut Buffer::from_vec(stream.clone()))
+            .unwrap_err();
+        assert_eq!(err.to_string(), expected);
+
+        let err = FileReaderBuilder::new()
+            .with_read_options(options)
+            .build(Cursor::new(write_ipc(&batch)))
+            .unwrap_err();
+        assert_eq!(err.to_string(), expected);
+
+        let options = options.with_max_nesting_depth(3);
+        let mut reader =
+            StreamReader::try_new_with_options(stream.as_slice(), None, options).unwrap();
+        assert_eq!(reader.next().unwrap().unwrap(), batch);
+    }
+
+    #[test]
+    fn test_data_type_depth() {
+        let list = DataType::new_list(DataType::Int32, true);
+        let dict = DataType::Dictionary(Box::new(DataType::Int8), Box::new(list.clone()));
+        let fields = Fields::from(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", dict.clone(), true),
+        ]);
+        assert_eq!(data_type_depth(&DataType::Int32), 1);
+        assert_eq!(data_type_depth(&list), 2);
+        assert_eq!(data_type_depth(&dict), 2);
+        assert_eq!(data_type_depth(&DataType::Struct(fields.clone())), 3);
+        assert_eq!(data_type_depth(&DataType::Struct(Fields::empty())), 1);
+        assert_eq!(
+            data_type_depth(&DataType::new_large_list(DataType::Struct(fields), true)),
+            4
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "lz4")]
+    fn test_read_options_max_decompressed_size() {
+        // Compresses to far less than the uncompressed 40000 bytes
+        let array = Int32Array::from(vec![0; 10_000]);
+        let batch = RecordBatch::try_from_iter([("a", Arc::new(array) as ArrayRef)]).unwrap();
+        let write_options = IpcWriteOptions::default()
+            .try_with_compression(Some(crate::CompressionType::LZ4_FRAME))
+            .unwrap();
+        let mut stream = vec![];
+        let mut writer = crate::writer::StreamWriter::try_new_with_options(
+            &mut stream,
+            &batch.schema(),
+            write_options,
+        )
+        .unwrap();
+        writer.write(&batch).unwrap();
+        writer.finish().unwrap();
+        drop(writer);
+
+        let options = IpcReadOptions::new().with_max_buffer_size(4096);
+        let mut reader =
+            StreamReader::try_new_with_options(stream.as_slice(), None, options).unwrap();
+        let err = reader.next().unwrap().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Ipc error: Decompressed buffer of 40000 bytes exceeds the maximum allowed buffer size of 4096 bytes"
+        );
+    }
+
     /// Per the IPC specification, dictionary batches may be omitted for
     /// dictionary-encoded columns where all values are null.  The C++
     /// implementation relies on this and does not emit a dictionary batch
@@ -0,0 +1,632 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::{HashMap, VecDeque};
+use std::fmt::{Debug, Formatter};
+use std::io::SeekFrom;
+use std::ops::Range;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use arrow_array::RecordBatch;
+use arrow_buffer::Buffer;
+use arrow_schema::{ArrowError, SchemaRef};
+use bytes::Bytes;
+use flatbuffers::VerifierOptions;
+use futures::future::{BoxFuture, FutureExt};
+use futures::stream::Stream;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt};
+
+use crate::Block;
+use crate::reader::{FileDecoder, IpcReadOptions, block_len, decode_footer, read_footer_length};
+
+/// The default number of record batch blocks [`FileStream`] fetches ahead of decoding
+pub const DEFAULT_READAHEAD: usize = 4;
+
+/// The asynchronous interface used by [`FileStream`] to read IPC files
+///
+/// Notes:
+///
+/// 1. There is a default implementation for types that implement [`AsyncRead`]
+///    and [`AsyncSeek`], for example [`tokio::fs::File`].
+///
+/// 2. [`IpcObjectReader`], available when the `object_store` crate feature
+///    is enabled, implements this interface for [`ObjectStore`].
+///
+/// [`IpcObjectReader`]: crate::reader::IpcObjectReader
+/// [`ObjectStore`]: object_store::ObjectStore
+/// [`tokio::fs::File`]: https://docs.rs/tokio/latest/tokio/fs/struct.File.html
+pub trait AsyncFileReader: Send {
+    /// Retrieve the bytes in `range`
+    fn get_bytes(&mut self, range: Range<u64>) -> BoxFuture<'_, Result<Bytes, ArrowError>>;
+
+    /// Retrieve multiple byte ranges. The default implementation will call `get_bytes` sequentially
+    fn get_byte_ranges(
+        &mut self,
+        ranges: Vec<Range<u64>>,
+    ) -> BoxFuture<'_, Result<Vec<Bytes>, ArrowError>> {
+        async move {
+            let mut result = Vec::with_capacity(ranges.len());
+
+            for range in ranges.into_iter() {
+                let data = self.get_bytes(range).await?;
+                result.push(data);
+            }
+
+            Ok(result)
+        }
+        .boxed()
+    }
+
+    /// Retrieve the last `suffix` bytes of the file
+    ///
+    /// This is used to read the footer without knowing the length of the file
+    fn get_suffix(&mut self, suffix: usize) -> BoxFuture<'_, Result<Bytes, ArrowError>>;
+}
+
+impl AsyncFileReader for Box<dyn AsyncFileReader + '_> {
+    fn get_bytes(&mut self, range: Range<u64>) -> BoxFuture<'_, Result<Bytes, ArrowError>> {
+        self.as_mut().get_bytes(range)
+    }
+
+    fn get_byte_ranges(
+        &mut self,
+        ranges: Vec<Range<u64>>,
+    ) -> BoxFuture<'_, Result<Vec<Bytes>, ArrowError>> {
+        self.as_mut().get_byte_ranges(ranges)
+    }
+
+    fn get_suffix(&mut self, suffix: usize) -> BoxFuture<'_, Result<Bytes, ArrowError>> {
+        self.as_mut().get_suffix(suffix)
+    }
+}
+
+impl<T: AsyncRead + AsyncSeek + Unpin + Send> AsyncFileReader for T {
+    fn get_bytes(&mut self, range: Range<u64>) -> BoxFuture<'_, Result<Bytes, ArrowError>> {
+        async move {
+            self.seek(SeekFrom::Start(range.start)).await?;
+            read_exact(self, range.end - range.start).await
+        }
+        .boxed()
+    }
+
+    fn get_suffix(&mut self, suffix: usize) -> BoxFuture<'_, Result<Bytes, ArrowError>> {
+        async move {
+            self.seek(SeekFrom::End(-(suffix as i64))).await?;
+            read_exact(self, suffix as u64).await
+        }
+        .boxed()
+    }
+}
+
+/// Read exactly `to_read` bytes from the current position of `reader`
+async fn read_exact<T: AsyncRead + Unpin>(
+    reader: &mut T,
+    to_read: u64,
+) -> Result<Bytes, ArrowError> {
+    let capacity = usize::try_from(to_read)
+        .map_err(|_| ArrowError::IpcError(format!("Unable to read {to_read} bytes")))?;
+    let mut buffer = Vec::with_capacity(capacity);
+    let read = reader.take(to_read).read_to_end(&mut buffer).await?;
+    if read as u64 != to_read {
+        return Err(ArrowError::IpcError(format!(
+            "Unexpected EOF: expected to read {to_read} bytes, got {read}"
+        )));
+    }
+    Ok(buffer.into())
+}
+
+/// The range of bytes in the file occupied by `block`
+fn block_range(block: &Block) -> Range<u64> {
+    let start = block.offset() as u64;
+    start..start + block.metaDataLength() as u64 + block.bodyLength() as u64
+}
+
+/// Build a [`FileStream`] with custom options.
+///
+/// This is the asynchronous counterpart of [`FileReaderBuilder`]
+///
+/// # Example
+/// ```
+/// # use std::io::Cursor;
+/// # use arrow_array::record_batch;
+/// # use arrow_ipc::reader::FileStreamBuilder;
+/// # use arrow_ipc::writer::FileWriter;
+/// # use futures::TryStreamExt;
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// # let batch = record_batch!(("a", Int32, [1, 2, 3])).unwrap();
+/// # let mut file = vec![]; // mimic a file for the example
+/// # {
+/// #  let mut writer = FileWriter::try_new(&mut file, &batch.schema()).unwrap();
+/// #  writer.write(&batch).unwrap();
+/// #  writer.write(&batch).unwrap();
+/// #  writer.finish().unwrap();
+/// # }
+/// // Any type implementing `AsyncRead + AsyncSeek`, e.g. `tokio::fs::File`
+/// let input = Cursor::new(file);
+/// let stream = FileStreamBuilder::new()
+///     // fetch up to 8 record batches ahead of the one being decoded
+///     .with_readahead(8)
+///     .build(input)
+///     .await
+///     .unwrap();
+/// assert_eq!(stream.num_batches(), 2);
+/// let batches: Vec<_> = stream.try_collect().await.unwrap();
+/// assert_eq!(batches, vec![batch.clone(), batch]);
+/// # }
+/// ```
+///
+/// [`FileReaderBuilder`]: crate::reader::FileReaderBuilder
+#[derive(Debug)]
+pub struct FileStreamBuilder {
+    /// Optional projection for which columns to load (zero-based column indices)
+    projection: Option<Vec<usize>>,
+    /// The maximum number of record batch blocks to fetch ahead of decoding
+    readahead: usize,
+    /// Passed through to construct [`VerifierOptions`]
+    max_footer_fb_tables: usize,
+    /// Passed through to construct [`VerifierOptions`]
+    max_footer_fb_depth: usize,
+    /// Limits enforced while reading, see [`FileStreamBuilder::with_read_options`]
+    read_options: IpcReadOptions,
+}
+
+impl Default for FileStreamBuilder {
+    fn default() -> Self {
+        let verifier_options = VerifierOptions::default();
+        Self {
+            max_footer_fb_tables: verifier_options.max_tables,
+            max_footer_fb_depth: verifier_options.max_depth,
+            projection: None,
+            readahead: DEFAULT_READAHEAD,
+            read_options: IpcReadOptions::default(),
+        }
+    }
+}
+
+impl FileStreamBuilder {
+    /// Options for creating a new [`FileStream`].
+    ///
+    /// To convert a builder into a stream, call [`FileStreamBuilder::build`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Optional projection for which columns to load (zero-based column indices).
+    pub fn with_projection(mut self, projection: Vec<usize>) -> Self {
+        self.projection = Some(projection);
+        self
+    }
+
+    /// The maximum number of record batch blocks to fetch ahead of the one
+    /// being decoded, defaults to [`DEFAULT_READAHEAD`].
+    ///
+    /// Blocks are requested together with [`AsyncFileReader::get_byte_ranges`],
+    /// allowing implementations to fetch them concurrently. Larger values hide
+    /// more I/O latency at the cost of buffering more undecoded data in memory.
+    ///
+    /// A value of `0` is treated as `1`.
+    pub fn with_readahead(mut self, readahead: usize) -> Self {
+        self.readahead = readahead.max(1);
+        self
+    }
+
+    /// Flatbuffers option for parsing the footer, see
+    /// [`FileReaderBuilder::with_max_footer_fb_tables`] for details.
+    ///
+    /// [`FileReaderBuilder::with_max_footer_fb_tables`]: crate::reader::FileReaderBuilder::with_max_footer_fb_tables
+    pub fn with_max_footer_fb_tables(mut self, max_footer_fb_tables: usize) -> Self {
+        self.max_footer_fb_tables = max_footer_fb_tables;
+        self
+    }
+
+    /// Flatbuffers option for parsing the footer, see
+    /// [`FileReaderBuilder::with_max_footer_fb_depth`] for details.
+    ///
+    /// [`FileReaderBuilder::with_max_footer_fb_depth`]: crate::reader::FileReaderBuilder::with_max_footer_fb_depth
+    pub fn with_max_footer_fb_depth(mut self, max_footer_fb_depth: usize) -> Self {
+        self.max_footer_fb_depth = max_footer_fb_depth;
+        self
+    }
+
+    /// Limits enforced while reading the file (default: no limits)
+    ///
+    /// The sizes of all blocks are checked when reading the footer, before any
+    /// of them are fetched, see [`IpcReadOptions`] for details
+    pub fn with_read_options(mut self, read_options: IpcReadOptions) -> Self {
+        self.read_options = read_options;
+        self
+    }
+
+    /// Build a [`FileStream`] with the given input, reading the footer and
+    /// all dictionaries of the file.
+    pub async fn build<T: AsyncFileReader + 'static>(
+        self,
+        mut input: T,
+    ) -> Result<FileStream<T>, ArrowError> {
+        // Space for ARROW_MAGIC (6 bytes) and length (4 bytes)
+        let trailer = input.get_suffix(10).await?;
+        let trailer: [u8; 10] = trailer[..].try_into().map_err(|_| {
+            ArrowError::IpcError(format!(
+                "Expected 10 byte IPC file trailer, got {}",
+                trailer.len()
+            ))
+        })?;
+        let footer_len = read_footer_length(trailer)?;
+        self.read_options.check_buffer_size("Footer", footer_len)?;
+
+        let footer_data = input.get_suffix(footer_len + 10).await?;
+        if footer_data.len() != footer_len + 10 {
+            return Err(ArrowError::IpcError(format!(
+                "Expected {} byte IPC file footer, got {}",
+                footer_len + 10,
+                footer_data.len()
+            )));
+        }
+
+        let verifier_options = VerifierOptions {
+            max_tables: self.max_footer_fb_tables,
+            max_depth: self.max_footer_fb_depth,
+            ..Default::default()
+        };
+        let footer = decode_footer(&footer_data[..footer_len], &verifier_options)?;
+        self.read_options.check_schema(&footer.schema)?;
+        for block in footer.dictionaries.iter().chain(&footer.record_batches) {
+            self.read_options
+                .check_buffer_size("Block", block_len(block)?)?;
+        }
+
+        let mut decoder = FileDecoder::new(Arc::new(footer.schema), footer.version)
+            .with_read_options(self.read_options);
+        if let Some(projection) = self.projection {
+            decoder = decoder.with_projection(projection)
+        }
+
+        let ranges = footer.dictionaries.iter().map(block_range).collect();
+        let dictionaries = input.get_byte_ranges(ranges).await?;
+        for (block, data) in footer.dictionaries.iter().zip(dictionaries) {
+            decoder.read_dictionary(block, &Buffer::from(data))?;
+        }
+
+        Ok(FileStream {
+            state: StreamState::Idle(input),
+            blocks: footer.record_batches,
+            next_block: 0,
+            fetching: 0..0,
+            buffered: VecDeque::new(),
+            readahead: self.readahead,
+            decoder,
+            custom_metadata: footer.custom_metadata,
+        })
+    }
+}
+
+enum StreamState<T> {
+    /// No request is in flight
+    Idle(T),
+    /// Fetching the blocks of [`FileStream::fetching`]
+    Fetching(BoxFuture<'static, (T, Result<Vec<Bytes>, ArrowError>)>),
+    /// An error occurred fetching data
+    Error,
+}
+
+/// An asynchronous [`Stream`] of [`RecordBatch`] read from an Arrow IPC file
+///
+/// Created with [`FileStreamBuilder`]. Record batch blocks listed in the file
+/// footer are fetched ahead of decoding, in requests of up to
+/// [`FileStreamBuilder::with_readahead`] blocks, so that I/O overlaps with
+/// decoding and consumption of previous batches.
+pub struct FileStream<T> {
+    state: StreamState<T>,
+    /// Record batch blocks from the footer
+    blocks: Vec<Block>,
+    /// Index of the next block in `blocks` to fetch
+    next_block: usize,
+    /// Indices of the blocks in `blocks` currently being fetched
+    fetching: Range<usize>,
+    /// Blocks that have been fetched but not yet decoded
+    buffered: VecDeque<(Block, Bytes)>,
+    readahead: usize,
+    decoder: FileDecoder,
+    custom_metadata: HashMap<String, String>,
+}
+
+impl<T> Debug for FileStream<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FileStream")
+            .field("blocks", &self.blocks)
+            .field("next_block", &self.next_block)
+            .field("fetching", &self.fetching)
+            .field("buffered", &self.buffered.len())
+            .field("readahead", &self.readahead)
+            .field("decoder", &self.decoder)
+            .field("custom_metadata", &self.custom_metadata)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> FileStream<T> {
+    /// Return user defined customized metadata
+    pub fn custom_metadata(&self) -> &HashMap<String, String> {
+        &self.custom_metadata
+    }
+
+    /// Return the number of batches in the file
+    pub fn num_batches(&self) -> usize {
+        self.blocks.len()
+    }
+
+    /// Return the schema of the file
+    pub fn schema(&self) -> SchemaRef {
+        self.decoder.schema.clone()
+    }
+}
+
+impl<T: AsyncFileReader + 'static> FileStream<T> {
+    /// Start fetching the next blocks if no request is in flight, and fewer
+    /// than `readahead` blocks are buffered
+    fn maybe_fetch(&mut self) {
+        let remaining = self.readahead.saturating_sub(self.buffered.len());
+        if remaining == 0 || self.next_block == self.blocks.len() {
+            return;
+        }
+        let StreamState::Idle(_) = &self.state else {
+            return;
+        };
+        let StreamState::Idle(mut input) = std::mem::replace(&mut self.state, StreamState::Error)
+        else {
+            unreachable!()
+        };
+
+        let end = self.blocks.len().min(self.next_block + remaining);
+        self.fetching = self.next_block..end;
+        self.next_block = end;
+
+        let ranges = self.blocks[self.fetching.clone()]
+            .iter()
+            .map(block_range)
+            .collect();
+        self.state = StreamState::Fetching(
+            async move {
+                let result = input.get_byte_ranges(ranges).await;
+                (input, result)
+            }
+            .boxed(),
+        );
+    }
+}
+
+impl<T: AsyncFileReader + Unpin + 'static> Stream for FileStream<T> {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        loop {
+            this.maybe_fetch();
+
+            if let StreamState::Fetching(fut) = &mut this.state {
+                if let Poll::Ready((input, result)) = fut.poll_unpin(cx) {
+                    match result {
+                        Ok(data) => {
+                            this.state = StreamState::Idle(input);
+                            let blocks = &this.blocks[this.fetching.clone()];
+                            this.buffered.extend(blocks.iter().copied().zip(data));
+                        }
+                        Err(e) => {
+                            this.buffered.clear();
+                            return Poll::Ready(Some(Err(e)));
+                        }
+                    }
+                    continue;
+                }
+            }
+
+            if let Some((block, data)) = this.buffered.pop_front() {
+                let result = this.decoder.read_record_batch(&block, &Buffer::from(data));
+                if !matches!(result, Ok(Some(_))) {
+                    // Stop reading after an error or an empty message
+                    this.buffered.clear();
+                    this.next_block = this.blocks.len();
+                }
+                return Poll::Ready(result.transpose());
+            }
+
+            return match this.state {
+                StreamState::Fetching(_) => Poll::Pending,
+                StreamState::Idle(_) | StreamState::Error => Poll::Ready(None),
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reader::FileReader;
+    use crate::writer::FileWriter;
+    use arrow_array::types::Int32Type;
+    use arrow_array::{ArrayRef, DictionaryArray, Int32Array, StringArray};
+    use futures::TryStreamExt;
+    use std::io::Cursor;
+
+    fn write_file(num_batches: usize) -> (Vec<RecordBatch>, Vec<u8>) {
+        let batches: Vec<_> = (0..num_batches as i32)
+            .map(|i| {
+                let ints = Int32Array::from_iter_values(i * 10..i * 10 + 5);
+                let dict: DictionaryArray<Int32Type> =
+                    ["a", "b", "c", "a", "b"].into_iter().collect();
+                RecordBatch::try_from_iter([
+                    ("ints", Arc::new(ints) as ArrayRef),
+                    ("dict", Arc::new(dict) as ArrayRef),
+                    ("str", Arc::new(StringArray::from(vec!["x"; 5])) as ArrayRef),
+                ])
+                .unwrap()
+            })
+            .collect();
+
+        let mut buf = Vec::new();
+        let mut writer = FileWriter::try_new(&mut buf, &batches[0].schema()).unwrap();
+        writer.write_metadata("k", "v");
+        for batch in &batches {
+            writer.write(batch).unwrap();
+        }
+        writer.finish().unwrap();
+        drop(writer);
+        (batches, buf)
+    }
+
+    /// An [`AsyncFileReader`] that records the requests made to it
+    struct CountingReader {
+        inner: Cursor<Vec<u8>>,
+        requests: Arc<std::sync::Mutex<Vec<usize>>>,
+    }
+
+    impl AsyncFileReader for CountingReader {
+        fn get_bytes(&mut self, range: Range<u64>) -> BoxFuture<'_, Result<Bytes, ArrowError>> {
+            self.inner.get_bytes(range)
+        }
+
+        fn get_byte_ranges(
+            &mut self,
+            ranges: Vec<Range<u64>>,
+        ) -> BoxFuture<'_, Result<Vec<Bytes>, ArrowError>> {
+            self.requests.lock().unwrap().push(ranges.len());
+            self.inner.get_byte_ranges(ranges)
+        }
+
+        fn get_suffix(&mut self, suffix: usize) -> BoxFuture<'_, Result<Bytes, ArrowError>> {
+            self.inner.get_suffix(suffix)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_file_stream() {
+        let (batches, buf) = write_file(10);
+
+        for readahead in [0, 1, 3, 10, 20] {
+            let stream = FileStreamBuilder::new()
+                .with_readahead(readahead)
+                .build(Cursor::new(buf.clone()))
+                .await
+                .unwrap();
+            assert_eq!(stream.num_batches(), 10);
+            assert_eq!(stream.schema(), batches[0].schema());
+            assert_eq!(stream.custom_metadata().get("k").unwrap(), "v");
+
+            let read: Vec<_> = stream.try_collect().await.unwrap();
+            assert_eq!(read, batches);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_file_stream_readahead() {
+        let (batches, buf) = write_file(10);
+        let requests = Arc::new(std::sync::Mutex::new(vec![]));
+        let input = CountingReader {
+            inner: Cursor::new(buf),
+            requests: Arc::clone(&requests),
+        };
+
+        let stream = FileStreamBuilder::new()
+            .with_readahead(4)
+            .build(input)
+            .await
+            .unwrap();
+        let read: Vec<_> = stream.try_collect().await.unwrap();
+        assert_eq!(read, batches);
+
+        // One request for the dictionaries, then at most 4 blocks per request
+        let requests = requests.lock().unwrap().clone();
+        assert_eq!(requests[0], 1);
+        assert_eq!(requests[1], 4);
+        assert!(requests[1..].iter().all(|r| *r <= 4), "{requests:?}");
+        assert_eq!(requests[1..].iter().sum::<usize>(), 10);
+    }
+
+    #[tokio::test]
+    async fn test_file_stream_projection() {
+        let (batches, buf) = write_file(3);
+        let stream = FileStreamBuilder::new()
+            .with_projection(vec![1, 2])
+            .build(Cursor::new(buf.clone()))
+            .await
+            .unwrap();
+        let read: Vec<_> = stream.try_collect().await.unwrap();
+
+        let expected: Vec<_> = FileReader::try_new(Cursor::new(buf), Some(vec![1, 2]))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(read, expected);
+        assert_eq!(read[0], batches[0].project(&[1, 2]).unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_file_stream_invalid() {
+        let (_, mut buf) = write_file(1);
+
+        let truncated = buf[..8].to_vec();
+        FileStreamBuilder::new()
+            .build(Cursor::new(truncated))
+            .await
+            .unwrap_err();
+
+        let len = buf.len();
+        buf[len - 1] = b'X';
+        let err = FileStreamBuilder::new()
+            .build(Cursor::new(buf))
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Arrow file does not contain correct footer"),
+            "{err}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_file_stream_read_options() {
+        let (_, buf) = write_file(3);
+
+        // Block sizes are checked before fetching any data
+        let options = IpcReadOptions::new().with_max_buffer_size(64);
+        let err = FileStreamBuilder::new()
+            .with_read_options(options)
+            .build(Cursor::new(buf.clone()))
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("exceeds the maximum allowed buffer size of 64 bytes"),
+            "{err}"
+        );
+
+        let options = IpcReadOptions::new().with_max_batch_rows(4);
+        let stream = FileStreamBuilder::new()
+            .with_read_options(options)
+            .build(Cursor::new(buf))
+            .await
+            .unwrap();
+        let err = stream.try_collect::<Vec<_>>().await.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Ipc error: Batch of 5 rows exceeds the maximum allowed number of rows of 4"
+        );
+    }
+}
@@ -0,0 +1,128 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::ops::Range;
+use std::sync::Arc;
+
+use arrow_schema::ArrowError;
+use bytes::Bytes;
+use futures::future::{BoxFuture, FutureExt, TryFutureExt};
+use object_store::path::Path;
+use object_store::{GetOptions, GetRange, ObjectStore, ObjectStoreExt};
+
+use crate::reader::AsyncFileReader;
+
+/// Reads an Arrow IPC file stored in an [`ObjectStore`]
+///
+/// Multiple ranges requested through [`AsyncFileReader::get_byte_ranges`]
+/// are fetched with [`ObjectStore::get_ranges`], which coalesces and
+/// concurrently fetches them.
+///
+/// ```no_run
+/// # use std::sync::Arc;
+/// # use arrow_ipc::reader::{FileStreamBuilder, IpcObjectReader};
+/// # use futures::TryStreamExt;
+/// # use object_store::memory::InMemory;
+/// # async fn example() {
+/// let store = Arc::new(InMemory::new());
+/// let reader = IpcObjectReader::new(store, "data.arrow".into());
+/// let stream = FileStreamBuilder::new().build(reader).await.unwrap();
+/// let batches: Vec<_> = stream.try_collect().await.unwrap();
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct IpcObjectReader {
+    store: Arc<dyn ObjectStore>,
+    path: Path,
+}
+
+impl IpcObjectReader {
+    /// Creates a new [`IpcObjectReader`] for the provided [`ObjectStore`] and [`Path`]
+    pub fn new(store: Arc<dyn ObjectStore>, path: Path) -> Self {
+        Self { store, path }
+    }
+}
+
+fn to_arrow_error(e: object_store::Error) -> ArrowError {
+    ArrowError::ExternalError(Box::new(e))
+}
+
+impl AsyncFileReader for IpcObjectReader {
+    fn get_bytes(&mut self, range: Range<u64>) -> BoxFuture<'_, Result<Bytes, ArrowError>> {
+        self.store
+            .get_range(&self.path, range)
+            .map_err(to_arrow_error)
+            .boxed()
+    }
+
+    fn get_byte_ranges(
+        &mut self,
+        ranges: Vec<Range<u64>>,
+    ) -> BoxFuture<'_, Result<Vec<Bytes>, ArrowError>> {
+        async move { self.store.get_ranges(&self.path, &ranges).await }
+            .map_err(to_arrow_error)
+            .boxed()
+    }
+
+    fn get_suffix(&mut self, suffix: usize) -> BoxFuture<'_, Result<Bytes, ArrowError>> {
+        let options = GetOptions {
+            range: Some(GetRange::Suffix(suffix as u64)),
+            ..Default::default()
+        };
+        async move {
+            let response = self.store.get_opts(&self.path, options).await?;
+            response.bytes().await
+        }
+        .map_err(to_arrow_error)
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reader::FileStreamBuilder;
+    use crate::writer::FileWriter;
+    use arrow_array::{RecordBatch, record_batch};
+    use futures::TryStreamExt;
+    use object_store::memory::InMemory;
+
+    #[tokio::test]
+    async fn test_object_store() {
+        let batch = record_batch!(("a", Int32, [1, 2, 3]), ("b", Utf8, ["x", "y", "z"])).unwrap();
+        let mut buf = Vec::new();
+        let mut writer = FileWriter::try_new(&mut buf, &batch.schema()).unwrap();
+        for _ in 0..5 {
+            writer.write(&batch).unwrap();
+        }
+        writer.finish().unwrap();
+        drop(writer);
+
+        let store = Arc::new(InMemory::new());
+        let path = Path::from("test.arrow");
+        store.put(&path, buf.into()).await.unwrap();
+
+        let reader = IpcObjectReader::new(store, path);
+        let stream = FileStreamBuilder::new()
+            .with_readahead(2)
+            .build(reader)
+            .await
+            .unwrap();
+        let read: Vec<RecordBatch> = stream.try_collect().await.unwrap();
+        assert_eq!(read, vec![batch; 5]);
+    }
+}
@@ -25,7 +25,7 @@ use arrow_data::UnsafeFlag;
 use arrow_schema::{ArrowError, SchemaRef};
 
 use crate::convert::MessageBuffer;
-use crate::reader::{RecordBatchDecoder, read_dictionary_impl};
+use crate::reader::{IpcReadOptions, RecordBatchDecoder, read_dictionary_impl};
 use crate::{CONTINUATION_MARKER, MessageHeader};
 
 /// A low-level interface for reading [`RecordBatch`] data from a stream of bytes
@@ -48,6 +48,8 @@ pub struct StreamDecoder {
     /// See [`StreamDecoder::with_skip_validation`] for details.
     ///
     skip_validation: UnsafeFlag,
+    /// Limits enforced while decoding, see [`StreamDecoder::with_read_options`]
+    read_options: IpcReadOptions,
 }
 
 #[derive(Debug)]
@@ -108,6 +110,15 @@ impl StreamDecoder {
         self
     }
 
+    /// Specifies the limits enforced while decoding (default: no limits)
+    ///
+    /// Message lengths are checked before any data is buffered, see
+    /// [`IpcReadOptions`] for details
+    pub fn with_read_options(mut self, read_options: IpcReadOptions) -> Self {
+        self.read_options = read_options;
+        self
+    }
+
     /// Return the schema if decoded, else None.
     pub fn schema(&self) -> Option<SchemaRef> {
         self.schema.as_ref().map(|schema| schema.clone())
@@ -181,6 +192,8 @@ impl StreamDecoder {
                             self.state = DecoderState::Finished;
                             continue;
                         }
+                        self.read_options
+                            .check_buffer_size("Message metadata", size as usize)?;
                         self.state = DecoderState::Message { size };
                     }
                 }
@@ -203,7 +216,14 @@ impl StreamDecoder {
                 }
                 DecoderState::Body { message } => {
                     let message = message.as_ref();
-                    let body_length = message.bodyLength() as usize;
+                    let body_length = usize::try_from(message.bodyLength()).map_err(|_| {
+                        ArrowError::IpcError(format!(
+                            "Invalid message body length: {}",
+                            message.bodyLength()
+                        ))
+                    })?;
+                    self.read_options
+                        .check_buffer_size("Message body", body_length)?;
 
                     let body = if self.buf.is_empty() && buffer.len() >= body_length {
                         let body = buffer.slice_with_length(0, body_length);
@@ -231,6 +251,7 @@ impl StreamDecoder {
 
                             let ipc_schema = message.header_as_schema().unwrap();
                             let schema = crate::convert::fb_to_schema(ipc_schema);
+                            self.read_options.check_schema(&schema)?;
                             self.state = DecoderState::default();
                             self.schema = Some(Arc::new(schema));
                         }
@@ -247,6 +268,7 @@ impl StreamDecoder {
                                 &version,
                             )?
                             .with_require_alignment(self.require_alignment)
+                            .with_read_options(self.read_options)
                             .read_record_batch()?;
                             self.state = DecoderState::default();
                             return Ok(Some(batch));
@@ -264,6 +286,7 @@ impl StreamDecoder {
                                 &version,
                                 self.require_alignment,
                                 self.skip_validation.clone(),
+                                &self.read_options,
                             )?;
                             self.state = DecoderState::default();
                         }
@@ -252,7 +252,7 @@ fn run_sequence_test(batches: &[RecordBatch], sequence: &[MessageType], options:
             IpcMessage::Schema(_) => {
                 assert_eq!(expected, &MessageType::Schema, "Expected schema message");
             }
-            IpcMessage::RecordBatch(_) => {
+            IpcMessage::RecordBatch(..) => {
                 assert_eq!(
                     expected,
                     &MessageType::RecordBatch,
@@ -343,6 +343,34 @@ fn test_deltas_with_file() {
     run_parity_test(&build_batches(batches));
 }
 
+/// FileWriter writes deltas for extended dictionaries even when configured to
+/// resend them, as replacement isn't permitted in the file format.
+#[test]
+fn test_file_resend_writes_deltas() {
+    let batches = build_batches(&[&["A"], &["A", "B"], &["A", "B", "C"]]);
+    let options = IpcWriteOptions::default().with_dictionary_handling(DictionaryHandling::Resend);
+    let file_buf = write_all_to_file(options.clone(), &batches);
+
+    for (batch, expected) in get_file_batches(file_buf).zip(&batches) {
+        assert_eq!(
+            dict_to_vec(&extract_dictionary(expected)),
+            dict_to_vec(&extract_dictionary(&batch))
+        );
+    }
+
+    // Dictionaries that are not extensions of the previous one are still an error
+    let first = build_batches(&[&["A"]]).remove(0);
+    let replaced = build_batches(&[&["B"]]).remove(0);
+    let mut buf: Vec<u8> = Vec::new();
+    let mut writer = FileWriter::try_new_with_options(&mut buf, &first.schema(), options).unwrap();
+    writer.write(&first).unwrap();
+    let err = writer.write(&replaced).unwrap_err();
+    assert!(
+        err.to_string().contains("Dictionary replacement detected"),
+        "{err}"
+    );
+}
+
 #[test]
 fn test_deltas_with_in_struct() {
     let batches: &[&[&str]] = &[&["A"], &["A", "B"], &["A", "B", "C"], &["A", "B", "C", "D"]];
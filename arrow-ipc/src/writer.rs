@@ -26,7 +26,8 @@
 
 use std::cmp::min;
 use std::collections::HashMap;
-use std::io::{BufWriter, Write};
+use std::fs::File;
+use std::io::{BufWriter, Read, Seek, SeekFrom, Write};
 use std::mem::size_of;
 use std::sync::Arc;
 
@@ -45,6 +46,7 @@ use crate::CONTINUATION_MARKER;
 use crate::compression::CompressionCodec;
 pub use crate::compression::CompressionContext;
 use crate::convert::IpcSchemaEncoder;
+use crate::reader::{FileDecoder, IpcReadOptions, decode_footer, read_block, read_footer_length};
 
 /// IPC write options used to control the behaviour of the [`IpcDataGenerator`]
 #[derive(Debug, Clone)]
@@ -215,6 +217,30 @@ impl IpcWriteOptions {
         }
     }
 
+    /// Sets the alignment of buffers, which must be 8, 16, 32 or 64 bytes (default: 64)
+    ///
+    /// Each message header and buffer is padded so that, when writing the Arrow File
+    /// format, every buffer starts at a multiple of `alignment` bytes from the start of
+    /// the file. See [`FileDecoder::try_with_buffer_alignment`] to verify this when reading.
+    ///
+    /// [`FileDecoder::try_with_buffer_alignment`]: crate::reader::FileDecoder::try_with_buffer_alignment
+    pub fn try_with_alignment(mut self, alignment: usize) -> Result<Self, ArrowError> {
+        self.alignment = match alignment {
+            8 | 16 | 32 | 64 => alignment as u8,
+            _ => {
+                return Err(ArrowError::InvalidArgumentError(
+                    "Alignment should be 8, 16, 32, or 64.".to_string(),
+                ));
+            }
+        };
+        Ok(self)
+    }
+
+    /// Returns the alignment of buffers in bytes
+    pub fn alignment(&self) -> usize {
+        self.alignment as usize
+    }
+
     /// Configure how dictionaries are handled in IPC messages
     pub fn with_dictionary_handling(mut self, dictionary_handling: DictionaryHandling) -> Self {
         self.dictionary_handling = dictionary_handling;
@@ -559,6 +585,7 @@ impl IpcDataGenerator {
         let mut arrow_data = Vec::new();
         let (ipc_message, _, tail_pad) = self.record_batch_to_bytes(
             batch,
+            &HashMap::new(),
             write_options,
             compression_context,
             &mut IpcBodySink::Write(&mut arrow_data),
@@ -604,6 +631,7 @@ impl IpcDataGenerator {
     fn write<W: Write>(
         &self,
         batch: &RecordBatch,
+        custom_metadata: &HashMap<String, String>,
         dictionary_tracker: &mut DictionaryTracker,
         write_options: &IpcWriteOptions,
         compression_context: &mut CompressionContext,
@@ -629,6 +657,7 @@ impl IpcDataGenerator {
         let mut encoded_buffers: Vec<EncodedBuffer> = Vec::with_capacity(capacity);
         let (ipc_message, body_len, tail_pad) = self.record_batch_to_bytes(
             batch,
+            custom_metadata,
             write_options,
             compression_context,
             &mut IpcBodySink::Collect(&mut encoded_buffers),
@@ -681,13 +710,14 @@ impl IpcDataGenerator {
     }
 
     /// Encodes a `RecordBatch` into a flatbuffer IPC message and fills `sink` with the
-    /// serialised buffer data.
+    /// serialised buffer data. Any `custom_metadata` is attached to the message.
     ///
     /// Returns `(ipc_message, body_len, tail_pad)`: the flatbuffer header bytes, the
     /// total body length including trailing padding, and the trailing alignment padding byte count.
     fn record_batch_to_bytes(
         &self,
         batch: &RecordBatch,
+        custom_metadata: &HashMap<String, String>,
         write_options: &IpcWriteOptions,
         compression_context: &mut CompressionContext,
         sink: &mut IpcBodySink<'_>,
@@ -749,12 +779,17 @@ impl IpcDataGenerator {
             }
             batch_builder.finish().as_union_value()
         };
+        let fb_custom_metadata = (!custom_metadata.is_empty())
+            .then(|| crate::convert::metadata_to_fb(&mut fbb, custom_metadata));
         // create an crate::Message
         let mut message = crate::MessageBuilder::new(&mut fbb);
         message.add_version(write_options.metadata_version);
         message.add_header_type(crate::MessageHeader::RecordBatch);
         message.add_bodyLength(body_len as i64);
         message.add_header(root);
+        if let Some(fb_custom_metadata) = fb_custom_metadata {
+            message.add_custom_metadata(fb_custom_metadata);
+        }
         let root = message.finish();
         fbb.finish(root, None);
 
@@ -955,6 +990,12 @@ fn into_zero_offset_run_array<R: RunEndIndexType>(
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum DictionaryHandling {
     /// Send the entire dictionary every time it is encountered (default)
+    ///
+    /// As the [IPC File Format] does not allow dictionaries to be replaced, the
+    /// [`FileWriter`] will still write a delta for dictionaries that only
+    /// have new values appended.
+    ///
+    /// [IPC File Format]: https://arrow.apache.org/docs/format/Columnar.html#ipc-file-format
     #[default]
     Resend,
     /// Send only new dictionary values since the last batch (delta encoding)
@@ -1076,10 +1117,10 @@ impl DictionaryTracker {
     ///   with completely different data, or if the data is a delta of the existing,
     ///   but with `dict_handling` set to `DictionaryHandling::Resend`
     /// * `Ok(Dictionary::Delta)` - If the dictionary was previously written, but
-    ///   the new data is a delta of the old and the `dict_handling` is set to
-    ///   `DictionaryHandling::Delta`
-    /// * `Err(e)` - If the dictionary was previously written with different data,
-    ///   and `error_on_replacement` is set to `true`.
+    ///   the new data is a delta of the old and either the `dict_handling` is set to
+    ///   `DictionaryHandling::Delta` or `error_on_replacement` is set to `true`
+    /// * `Err(e)` - If the dictionary was previously written with different data
+    ///   that is not a delta of the old, and `error_on_replacement` is set to `true`.
     pub fn insert_column(
         &mut self,
         dict_id: i64,
@@ -1110,7 +1151,7 @@ impl DictionaryTracker {
 
         const REPLACEMENT_ERROR: &str = "Dictionary replacement detected when writing IPC file format. \
                  Arrow IPC files only support a single dictionary for a given field \
-                 across all batches.";
+                 across all batches, which may only be extended with new values.";
 
         match comparison {
             DictionaryComparison::NotEqual => {
@@ -1124,17 +1165,14 @@ impl DictionaryTracker {
                 Ok(DictionaryUpdate::Replaced)
             }
             DictionaryComparison::Delta => match dict_handling {
-                DictionaryHandling::Resend => {
-                    if self.error_on_replacement {
-                        return Err(ArrowError::InvalidArgumentError(
-                            REPLACEMENT_ERROR.to_string(),
-                        ));
-                    }
-
+                // Replacing a dictionary is an error when `error_on_replacement`
+                // is set (e.g. the IPC file format), but the format still allows
+                // extending one, so emit a delta in that case instead of failing
+                DictionaryHandling::Resend if !self.error_on_replacement => {
                     self.written.insert(dict_id, new_data);
                     Ok(DictionaryUpdate::Replaced)
                 }
-                DictionaryHandling::Delta => {
+                DictionaryHandling::Resend | DictionaryHandling::Delta => {
                     let delta =
                         new_values.slice(old_values.len(), new_values.len() - old_values.len());
                     self.written.insert(dict_id, new_data);
@@ -1250,6 +1288,170 @@ impl<W: Write> FileWriter<BufWriter<W>> {
     }
 }
 
+impl FileWriter<File> {
+    /// Try to open an existing Arrow IPC file to append record batches to it
+    ///
+    /// The footer of `file` is read, and subsequently written batches are added
+    /// after it, leaving the existing blocks and footer in place. A new footer,
+    /// describing both the existing and the appended batches, is written by
+    /// [`FileWriter::finish`]. Until then `file` is not a valid Arrow IPC file once
+    /// a batch has been written, but it can be restored by truncating it to its
+    /// original length.
+    ///
+    /// The existing custom metadata of the file is preserved, and the
+    /// existing dictionaries are used to determine which dictionaries, or
+    /// deltas to them, need to be written for the appended batches.
+    ///
+    /// ```
+    /// # use std::fs::File;
+    /// # use arrow_array::record_batch;
+    /// # use arrow_ipc::reader::FileReader;
+    /// # use arrow_ipc::writer::FileWriter;
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let path = dir.path().join("example.arrow");
+    /// let batch = record_batch!(("a", Int32, [1, 2, 3])).unwrap();
+    ///
+    /// let mut writer = FileWriter::try_new(File::create(&path).unwrap(), &batch.schema()).unwrap();
+    /// writer.write(&batch).unwrap();
+    /// writer.finish().unwrap();
+    ///
+    /// // Re-open the file to add a second batch
+    /// let file = File::options().read(true).write(true).open(&path).unwrap();
+    /// let mut writer = FileWriter::try_new_append(file, &batch.schema()).unwrap();
+    /// writer.write(&batch).unwrap();
+    /// writer.finish().unwrap();
+    ///
+    /// let reader = FileReader::try_new(File::open(&path).unwrap(), None).unwrap();
+    /// assert_eq!(reader.num_batches(), 2);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// An ['Err'](Result::Err) is returned if `file` is not a valid Arrow IPC file,
+    /// or its schema does not have the same fields as `schema`.
+    pub fn try_new_append(file: File, schema: &Schema) -> Result<Self, ArrowError> {
+        Self::try_new_append_with_options(file, schema, IpcWriteOptions::default())
+    }
+
+    /// Try to open an existing Arrow IPC file to append record batches to it with
+    /// [`IpcWriteOptions`], see [`FileWriter::try_new_append`] for details
+    ///
+    /// # Errors
+    ///
+    /// In addition to the errors of [`FileWriter::try_new_append`], an
+    /// ['Err'](Result::Err) is returned if the metadata version of `write_options`
+    /// differs from that of the file.
+    pub fn try_new_append_with_options(
+        mut file: File,
+        schema: &Schema,
+        write_options: IpcWriteOptions,
+    ) -> Result<Self, ArrowError> {
+        let mut magic = [0; 6];
+        file.seek(SeekFrom::Start(0))?;
+        file.read_exact(&mut magic)?;
+        if magic != super::ARROW_MAGIC {
+            return Err(ArrowError::ParseError(
+                "Arrow file does not contain correct header".to_string(),
+            ));
+        }
+
+        let mut trailer = [0; 10];
+        let file_len = file.seek(SeekFrom::End(-10))?;
+        file.read_exact(&mut trailer)?;
+        let footer_len = read_footer_length(trailer)?;
+        let footer_start = file_len.checked_sub(footer_len as u64).ok_or_else(|| {
+            ArrowError::ParseError(format!(
+                "Invalid footer length {footer_len} for file of {} bytes",
+                file_len + 10
+            ))
+        })?;
+
+        let mut footer_data = vec![0; footer_len];
+        file.seek(SeekFrom::Start(footer_start))?;
+        file.read_exact(&mut footer_data)?;
+        let footer = decode_footer(&footer_data, &Default::default())?;
+
+        if footer.version != write_options.metadata_version {
+            return Err(ArrowError::IpcError(format!(
+                "Cannot append to Arrow file with metadata version {:?} using metadata version {:?}",
+                footer.version, write_options.metadata_version
+            )));
+        }
+        if footer.schema.fields() != schema.fields() {
+            return Err(ArrowError::SchemaError(format!(
+                "Cannot append to Arrow file as its schema {} does not match {}",
+                footer.schema, schema
+            )));
+        }
+
+        let schema = Arc::new(footer.schema);
+        let mut decoder = FileDecoder::new(Arc::clone(&schema), footer.version);
+        for block in &footer.dictionaries {
+            let buf = read_block(&mut file, block, &IpcReadOptions::default())?;
+            decoder.read_dictionary(block, &buf)?;
+        }
+
+        // Assign dictionary IDs by traversing the schema as `finish` does, which
+        // must match the IDs of the existing footer for its dictionaries to be reused
+        let mut dictionary_tracker = DictionaryTracker::new(true);
+        IpcSchemaEncoder::new()
+            .with_dictionary_tracker(&mut dictionary_tracker)
+            .schema_to_fb(&schema);
+        let fb_footer = crate::root_as_footer(&footer_data).map_err(|err| {
+            ArrowError::ParseError(format!("Unable to get root as footer: {err:?}"))
+        })?;
+        let mut file_dict_ids = vec![];
+        for field in fb_footer.schema().and_then(|s| s.fields()).iter().flatten() {
+            collect_dict_ids(field, &mut file_dict_ids);
+        }
+        if dictionary_tracker.dict_id() != file_dict_ids {
+            return Err(ArrowError::IpcError(
+                "Cannot append to Arrow file with non-sequential dictionary IDs".to_string(),
+            ));
+        }
+        for dict_id in file_dict_ids {
+            if let Some(values) = decoder.dictionaries().get(&dict_id) {
+                let keys = PrimitiveArray::<Int32Type>::from(Vec::<i32>::new());
+                let dictionary = DictionaryArray::new(keys, Arc::clone(values));
+                dictionary_tracker
+                    .written
+                    .insert(dict_id, dictionary.into_data());
+            }
+        }
+
+        // Blocks are only referenced by offset, and so are appended after the
+        // existing footer, which remains intact until the new one is written
+        let data_end = file_len + 10;
+        let block_offsets = data_end.next_multiple_of(write_options.alignment as u64);
+        file.seek(SeekFrom::Start(block_offsets))?;
+
+        Ok(Self {
+            writer: file,
+            write_options,
+            schema,
+            block_offsets: block_offsets as usize,
+            dictionary_blocks: footer.dictionaries,
+            record_blocks: footer.record_batches,
+            finished: false,
+            dictionary_tracker,
+            custom_metadata: footer.custom_metadata,
+            data_gen: IpcDataGenerator::default(),
+            compression_context: CompressionContext::default(),
+        })
+    }
+}
+
+/// Appends the dictionary IDs of the flatbuffer `field` and its children, in the
+/// order they are assigned by [`IpcSchemaEncoder`] when traversing the schema
+fn collect_dict_ids(field: crate::Field, dict_ids: &mut Vec<i64>) {
+    for child in field.children().iter().flatten() {
+        collect_dict_ids(child, dict_ids);
+    }
+    if let Some(dictionary) = field.dictionary() {
+        dict_ids.push(dictionary.id());
+    }
+}
+
 impl<W: Write> FileWriter<W> {
     /// Try to create a new writer, with the schema written as part of the header
     ///
@@ -1311,6 +1513,19 @@ impl<W: Write> FileWriter<W> {
 
     /// Write a record batch to the file
     pub fn write(&mut self, batch: &RecordBatch) -> Result<(), ArrowError> {
+        self.write_with_custom_metadata(batch, &HashMap::new())
+    }
+
+    /// Write a record batch to the file, attaching `custom_metadata` to its IPC message
+    ///
+    /// Unlike [`Self::write_metadata`], which is written once in the file footer,
+    /// this metadata is specific to the given batch and can be read back with
+    /// [`FileReader::next_with_custom_metadata`](crate::reader::FileReader::next_with_custom_metadata)
+    pub fn write_with_custom_metadata(
+        &mut self,
+        batch: &RecordBatch,
+        custom_metadata: &HashMap<String, String>,
+    ) -> Result<(), ArrowError> {
         if self.finished {
             return Err(ArrowError::IpcError(
                 "Cannot write record batch to file writer as it is closed".to_string(),
@@ -1319,6 +1534,7 @@ impl<W: Write> FileWriter<W> {
 
         let meta = self.data_gen.write(
             batch,
+            custom_metadata,
             &mut self.dictionary_tracker,
             &self.write_options,
             &mut self.compression_context,
@@ -1586,6 +1802,18 @@ impl<W: Write> StreamWriter<W> {
 
     /// Write a record batch to the stream
     pub fn write(&mut self, batch: &RecordBatch) -> Result<(), ArrowError> {
+        self.write_with_custom_metadata(batch, &HashMap::new())
+    }
+
+    /// Write a record batch to the stream, attaching `custom_metadata` to its IPC message
+    ///
+    /// This can be read back with
+    /// [`StreamReader::next_with_custom_metadata`](crate::reader::StreamReader::next_with_custom_metadata)
+    pub fn write_with_custom_metadata(
+        &mut self,
+        batch: &RecordBatch,
+        custom_metadata: &HashMap<String, String>,
+    ) -> Result<(), ArrowError> {
         if self.finished {
             return Err(ArrowError::IpcError(
                 "Cannot write record batch to stream writer as it is closed".to_string(),
@@ -1594,6 +1822,7 @@ impl<W: Write> StreamWriter<W> {
 
         self.data_gen.write(
             batch,
+            custom_metadata,
             &mut self.dictionary_tracker,
             &self.write_options,
             &mut self.compression_context,
@@ -4086,6 +4315,84 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_file_buffer_alignment() {
+        let dict: DictionaryArray<Int32Type> =
+            vec!["a", "bb", "a", "ccc", "bb"].into_iter().collect();
+        let batch = RecordBatch::try_from_iter([
+            (
+                "a",
+                Arc::new(Int8Array::from(vec![1, 2, 3, 4, 5])) as ArrayRef,
+            ),
+            (
+                "b",
+                Arc::new(StringArray::from(vec!["x", "y", "z", "w", "v"])) as _,
+            ),
+            ("c", Arc::new(dict) as _),
+        ])
+        .unwrap();
+
+        let write = |alignment| {
+            let options = IpcWriteOptions::default()
+                .try_with_alignment(alignment)
+                .unwrap();
+            assert_eq!(options.alignment(), alignment);
+            let mut writer =
+                FileWriter::try_new_with_options(Vec::new(), batch.schema_ref(), options).unwrap();
+            writer.write(&batch).unwrap();
+            writer.write(&batch.slice(1, 3)).unwrap();
+            writer.finish().unwrap();
+            writer.into_inner().unwrap()
+        };
+
+        let file = write(64);
+        let reader = FileReaderBuilder::new()
+            .try_with_buffer_alignment(Some(64))
+            .unwrap()
+            .build(Cursor::new(file))
+            .unwrap();
+        let read: Vec<_> = reader.collect::<Result<_, _>>().unwrap();
+        assert_eq!(read, [batch.clone(), batch.slice(1, 3)]);
+
+        let file = write(8);
+        let err = FileReaderBuilder::new()
+            .try_with_buffer_alignment(Some(64))
+            .unwrap()
+            .build(Cursor::new(file.clone()))
+            .and_then(|reader| reader.collect::<Result<Vec<_>, _>>())
+            .unwrap_err();
+        assert!(err.to_string().contains("not aligned to 64 bytes"), "{err}");
+
+        let reader = FileReaderBuilder::new()
+            .try_with_buffer_alignment(Some(8))
+            .unwrap()
+            .build(Cursor::new(file))
+            .unwrap();
+        assert_eq!(reader.count(), 2);
+
+        let err = IpcWriteOptions::default()
+            .try_with_alignment(128)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Alignment should be 8, 16, 32, or 64."
+        );
+
+        for alignment in [0, 24] {
+            let err = FileReaderBuilder::new()
+                .try_with_buffer_alignment(Some(alignment))
+                .unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                format!(
+                    "Invalid argument error: Buffer alignment must be a power of two, got {alignment}"
+                )
+            );
+        }
+        let decoder = FileDecoder::new(batch.schema(), MetadataVersion::V5);
+        assert!(decoder.try_with_buffer_alignment(Some(3)).is_err());
+    }
+
     #[test]
     fn test_decimal128_alignment8_is_unaligned() {
         const IPC_ALIGNMENT: usize = 8;
@@ -4509,4 +4816,245 @@ mod tests {
         let read_batch = reader.next().unwrap().unwrap();
         assert_eq!(read_batch, batch2);
     }
+
+    /// Read all batches of `file` from the start
+    fn read_file(file: &mut File) -> (Vec<RecordBatch>, HashMap<String, String>) {
+        file.rewind().unwrap();
+        let reader = FileReader::try_new(file, None).unwrap();
+        let metadata = reader.custom_metadata().clone();
+        let batches = reader.collect::<Result<_, _>>().unwrap();
+        (batches, metadata)
+    }
+
+    #[test]
+    fn test_file_append() {
+        let batch1 = RecordBatch::try_from_iter_with_nullable([(
+            "a",
+            Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef,
+            true,
+        )])
+        .unwrap();
+        let batch2 = RecordBatch::try_from_iter_with_nullable([(
+            "a",
+            Arc::new(Int32Array::from(vec![Some(4), None])) as ArrayRef,
+            true,
+        )])
+        .unwrap();
+        let schema = batch1.schema();
+
+        let mut file = tempfile::tempfile().unwrap();
+        let mut writer = FileWriter::try_new(&mut file, &schema).unwrap();
+        writer.write_metadata("k1", "v1");
+        writer.write(&batch1).unwrap();
+        writer.finish().unwrap();
+        drop(writer);
+
+        // Append multiple times
+        for i in 0..2 {
+            let mut writer =
+                FileWriter::try_new_append(file.try_clone().unwrap(), &schema).unwrap();
+            writer.write_metadata(format!("k{}", i + 2), "v");
+            writer.write(&batch2).unwrap();
+            writer.write(&batch1).unwrap();
+            writer.finish().unwrap();
+        }
+
+        let (batches, metadata) = read_file(&mut file);
+        assert_eq!(
+            batches,
+            vec![
+                batch1.clone(),
+                batch2.clone(),
+                batch1.clone(),
+                batch2,
+                batch1
+            ]
+        );
+        assert_eq!(metadata.len(), 3);
+        assert_eq!(metadata["k1"], "v1");
+    }
+
+    #[test]
+    fn test_file_append_without_batches() {
+        let batch = RecordBatch::try_from_iter([(
+            "a",
+            Arc::new(StringArray::from(vec!["a", "b"])) as ArrayRef,
+        )])
+        .unwrap();
+
+        for alignment in [8, 64] {
+            let options = IpcWriteOptions::try_new(alignment, false, MetadataVersion::V5).unwrap();
+            let mut file = tempfile::tempfile().unwrap();
+            let mut writer =
+                FileWriter::try_new_with_options(&mut file, &batch.schema(), options.clone())
+                    .unwrap();
+            writer.finish().unwrap();
+            drop(writer);
+
+            // Appending no batches preserves the file
+            let mut writer = FileWriter::try_new_append_with_options(
+                file.try_clone().unwrap(),
+                &batch.schema(),
+                options.clone(),
+            )
+            .unwrap();
+            writer.finish().unwrap();
+            assert!(read_file(&mut file).0.is_empty());
+
+            let mut writer = FileWriter::try_new_append_with_options(
+                file.try_clone().unwrap(),
+                &batch.schema(),
+                options,
+            )
+            .unwrap();
+            writer.write(&batch).unwrap();
+            writer.finish().unwrap();
+            assert_eq!(read_file(&mut file).0, vec![batch.clone()]);
+        }
+    }
+
+    #[test]
+    fn test_file_append_dictionaries() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new_dictionary("d", DataType::Int8, DataType::Utf8, true),
+            Field::new_list(
+                "l",
+                Field::new_dictionary("item", DataType::Int8, DataType::Utf8, true),
+                true,
+            ),
+        ]));
+        let make_batch = |values: Vec<&str>, keys: Vec<i8>| {
+            let values: ArrayRef = Arc::new(StringArray::from(values));
+            let dict = DictionaryArray::new(Int8Array::from(keys.clone()), values.clone());
+            let list_values = DictionaryArray::new(Int8Array::from(keys), values);
+            let offsets = arrow_buffer::OffsetBuffer::from_lengths(vec![1; list_values.len()]);
+            let list = ListArray::new(
+                Arc::new(Field::new_dictionary(
+                    "item",
+                    DataType::Int8,
+                    DataType::Utf8,
+                    true,
+                )),
+                offsets,
+                Arc::new(list_values),
+                None,
+            );
+            RecordBatch::try_new(schema.clone(), vec![Arc::new(dict), Arc::new(list)]).unwrap()
+        };
+
+        let batch1 = make_batch(vec!["a", "b"], vec![0, 1, 0]);
+        let batch2 = make_batch(vec!["a", "b"], vec![1, 1]);
+        let batch3 = make_batch(vec!["a", "b", "c"], vec![2, 0]);
+
+        let mut file = tempfile::tempfile().unwrap();
+        let mut writer = FileWriter::try_new(&mut file, &schema).unwrap();
+        writer.write(&batch1).unwrap();
+        writer.finish().unwrap();
+        drop(writer);
+
+        let mut writer = FileWriter::try_new_append(file.try_clone().unwrap(), &schema).unwrap();
+        writer.write(&batch2).unwrap();
+        // An extension to the existing dictionaries is written as a delta
+        writer.write(&batch3).unwrap();
+        writer.finish().unwrap();
+        assert_eq!(writer.dictionary_blocks.len(), 4);
+
+        let mut writer = FileWriter::try_new_append(file.try_clone().unwrap(), &schema).unwrap();
+        let err = writer.write(&make_batch(vec!["x"], vec![0])).unwrap_err();
+        assert!(
+            err.to_string().contains("Dictionary replacement detected"),
+            "{err}"
+        );
+        // Nothing is written for the rejected batch
+        writer.finish().unwrap();
+
+        let (batches, _) = read_file(&mut file);
+        assert_eq!(batches, vec![batch1, batch2, batch3]);
+    }
+
+    #[test]
+    fn test_file_append_unfinished() {
+        let batch = RecordBatch::try_from_iter([(
+            "a",
+            Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef,
+        )])
+        .unwrap();
+        let mut file = tempfile::tempfile().unwrap();
+        let mut writer = FileWriter::try_new(&mut file, &batch.schema()).unwrap();
+        writer.write(&batch).unwrap();
+        writer.finish().unwrap();
+        drop(writer);
+        let len = file.metadata().unwrap().len();
+
+        // The file is untouched until a batch is written
+        let writer = FileWriter::try_new_append(file.try_clone().unwrap(), &batch.schema());
+        drop(writer.unwrap());
+        assert_eq!(file.metadata().unwrap().len(), len);
+        assert_eq!(read_file(&mut file).0, vec![batch.clone()]);
+
+        // The existing footer is kept, so truncating an unfinished append restores the file
+        let mut writer =
+            FileWriter::try_new_append(file.try_clone().unwrap(), &batch.schema()).unwrap();
+        writer.write(&batch).unwrap();
+        drop(writer);
+        assert!(file.metadata().unwrap().len() > len);
+        file.set_len(len).unwrap();
+        assert_eq!(read_file(&mut file).0, vec![batch.clone()]);
+
+        let mut writer =
+            FileWriter::try_new_append(file.try_clone().unwrap(), &batch.schema()).unwrap();
+        writer.write(&batch).unwrap();
+        writer.finish().unwrap();
+        assert_eq!(read_file(&mut file).0, vec![batch.clone(), batch]);
+    }
+
+    #[test]
+    fn test_file_append_errors() {
+        let batch = RecordBatch::try_from_iter([(
+            "a",
+            Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef,
+        )])
+        .unwrap();
+        let mut file = tempfile::tempfile().unwrap();
+        let mut writer = FileWriter::try_new(&mut file, &batch.schema()).unwrap();
+        writer.write(&batch).unwrap();
+        writer.finish().unwrap();
+        drop(writer);
+
+        let schema = Schema::new(vec![Field::new("b", DataType::Int32, true)]);
+        let err = FileWriter::try_new_append(file.try_clone().unwrap(), &schema)
+            .err()
+            .unwrap();
+        assert!(
+            err.to_string()
+                .contains("Cannot append to Arrow file as its schema"),
+            "{err}"
+        );
+
+        let options = IpcWriteOptions::try_new(8, false, MetadataVersion::V4).unwrap();
+        let err = FileWriter::try_new_append_with_options(
+            file.try_clone().unwrap(),
+            &batch.schema(),
+            options,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Ipc error: Cannot append to Arrow file with metadata version V5 using metadata version V4"
+        );
+
+        // The file is unchanged on error
+        assert_eq!(read_file(&mut file).0, vec![batch]);
+
+        let mut stream = tempfile::tempfile().unwrap();
+        let mut writer = StreamWriter::try_new(&mut stream, &schema).unwrap();
+        writer.finish().unwrap();
+        drop(writer);
+        let err = FileWriter::try_new_append(stream, &schema).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Arrow file does not contain correct header"
+        );
+    }
 }
@@ -18,18 +18,35 @@
 use std::marker::PhantomData;
 use std::sync::Arc;
 
-use arrow_array::ArrayRef;
 use arrow_array::builder::PrimitiveBuilder;
 use arrow_array::types::DecimalType;
+use arrow_array::{ArrayRef, ArrowNativeTypeOp};
+use arrow_buffer::ArrowNativeType;
 use arrow_cast::parse::parse_decimal;
 use arrow_schema::ArrowError;
 
 use crate::reader::tape::{Tape, TapeElement};
 use crate::reader::{ArrayDecoder, DecoderContext};
 
+/// How to decode decimal values with more fractional digits than the scale of the
+/// target decimal type, see [`ReaderBuilder::with_decimal_rounding`]
+///
+/// [`ReaderBuilder::with_decimal_rounding`]: crate::ReaderBuilder::with_decimal_rounding
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum DecimalRounding {
+    /// Discard the excess digits, rounding towards zero
+    #[default]
+    Truncate,
+    /// Round to the nearest value, with ties rounded away from zero
+    HalfAwayFromZero,
+    /// Return an error if any of the excess digits are non-zero
+    Error,
+}
+
 pub struct DecimalArrayDecoder<D: DecimalType> {
     precision: u8,
     scale: i8,
+    rounding: DecimalRounding,
     ignore_type_conflicts: bool,
     // Invariant and Send
     phantom: PhantomData<fn(D) -> D>,
@@ -40,12 +57,66 @@ impl<D: DecimalType> DecimalArrayDecoder<D> {
         Self {
             precision,
             scale,
+            rounding: ctx.decimal_rounding(),
             ignore_type_conflicts: ctx.ignore_type_conflicts(),
             phantom: PhantomData,
         }
     }
 }
 
+impl<D: DecimalType> DecimalArrayDecoder<D> {
+    fn parse(&self, s: &str) -> Result<D::Native, ArrowError> {
+        // `parse_decimal` truncates any digits beyond the scale
+        let truncated = parse_decimal::<D>(s, self.precision, self.scale)?;
+        let Some((first, non_zero)) = excess_digits(s, self.scale) else {
+            return Ok(truncated);
+        };
+        match self.rounding {
+            DecimalRounding::Truncate => Ok(truncated),
+            DecimalRounding::HalfAwayFromZero if first < 5 => Ok(truncated),
+            DecimalRounding::HalfAwayFromZero => {
+                let one = D::Native::usize_as(1);
+                let rounded = match s.starts_with('-') {
+                    true => truncated.sub_checked(one)?,
+                    false => truncated.add_checked(one)?,
+                };
+                D::validate_decimal_precision(rounded, self.precision, self.scale)?;
+                Ok(rounded)
+            }
+            DecimalRounding::Error if non_zero => Err(ArrowError::ParseError(format!(
+                "{s} cannot be represented with scale {} without rounding",
+                self.scale
+            ))),
+            DecimalRounding::Error => Ok(truncated),
+        }
+    }
+}
+
+/// Returns the first of the digits of the decimal number `s` beyond `scale`, and whether
+/// any of them are non-zero, or `None` if there are no such digits or `s` is invalid
+fn excess_digits(s: &str, scale: i8) -> Option<(u8, bool)> {
+    let s = s.strip_prefix(['-', '+']).unwrap_or(s);
+    let (mantissa, exponent) = match s.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => (mantissa, exponent.parse::<i64>().ok()?),
+        None => (s, 0),
+    };
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let excess = fraction.len() as i64 - exponent - scale as i64;
+    if excess <= 0 {
+        return None;
+    }
+    let digits = integer.bytes().chain(fraction.bytes());
+    let len = integer.len() + fraction.len();
+    // Any excess digits beyond those of the mantissa are leading zeros
+    let skip = len.saturating_sub(excess as usize);
+    let mut excess_digits = digits.skip(skip).map(|b| b.wrapping_sub(b'0'));
+    let first = match excess as usize > len {
+        true => 0,
+        false => excess_digits.next()?,
+    };
+    Some((first, first != 0 || excess_digits.any(|d| d != 0)))
+}
+
 impl<D> ArrayDecoder for DecimalArrayDecoder<D>
 where
     D: DecimalType,
@@ -91,7 +162,7 @@ where
                 _ => return Err(tape.error(*p, "decimal")),
             };
 
-            match parse_decimal::<D>(value, self.precision, self.scale) {
+            match self.parse(value) {
                 Ok(value) => builder.append_value(value),
                 Err(_) if self.ignore_type_conflicts => builder.append_null(),
                 Err(e) => return Err(e),
@@ -0,0 +1,356 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use arrow_array::RecordBatch;
+use arrow_schema::{ArrowError, Fields, Schema, SchemaRef};
+
+use crate::reader::ReaderBuilder;
+use crate::reader::schema::{infer_tape_fields, widen_fields};
+use crate::reader::tape::TapeDecoder;
+
+/// A [`Decoder`] for JSON that infers the schema while decoding
+///
+/// Unlike [`infer_json_schema`], which requires a separate pass over the data and
+/// fails on conflicting types, [`InferringDecoder`] widens the inferred schema as
+/// records are decoded:
+///
+/// * A field that is only ever `null` is inferred as `Null`, and takes the type of
+///   the first non-null value
+/// * `Int64` and `Float64` are widened to `Float64`
+/// * Lists are unified by widening their element types
+/// * Objects are unified by merging their fields, all fields are nullable
+/// * All other conflicts, including between lists or objects and scalars, are
+///   widened to `Utf8`, with values of other types decoded as their JSON text, see
+///   [`ReaderBuilder::with_raw_json_fallback`]
+///
+/// Like [`Decoder`], records may span multiple lines, or share a line, and each
+/// batch is inferred and decoded from the same parsed tape without materializing
+/// the records as [`serde_json::Value`].
+///
+/// Each batch returned by [`Self::flush`] has the schema inferred from the records
+/// decoded so far, so batches may have different schemas. [`Self::finish`] returns
+/// the final schema, and if [`Self::with_retain_records`] is set, all the batches
+/// decoded again with that schema.
+///
+/// ```
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Float64Type;
+/// # use arrow_json::reader::InferringDecoder;
+/// # use arrow_schema::DataType;
+/// let mut decoder = InferringDecoder::new().with_retain_records(true);
+/// decoder.decode(b"{\"a\": 1}\n{\"a\": 2}\n").unwrap();
+/// let batch = decoder.flush().unwrap().unwrap();
+/// assert_eq!(batch.schema().field(0).data_type(), &DataType::Int64);
+///
+/// decoder.decode(b"{\"a\": 2.5, \"b\": true}").unwrap();
+/// let batch = decoder.flush().unwrap().unwrap();
+/// assert_eq!(batch.schema().field(0).data_type(), &DataType::Float64);
+///
+/// // earlier batches are decoded again with the final schema
+/// let (schema, batches) = decoder.finish().unwrap();
+/// assert_eq!(schema.fields().len(), 2);
+/// assert_eq!(batches.len(), 1);
+/// assert_eq!(batches[0].num_rows(), 3);
+/// assert_eq!(batches[0].column(0).as_primitive::<Float64Type>().value(0), 1.0);
+/// ```
+///
+/// [`Decoder`]: super::Decoder
+/// [`infer_json_schema`]: super::infer_json_schema
+pub struct InferringDecoder {
+    tape_decoder: TapeDecoder,
+    batch_size: usize,
+    retain_records: bool,
+    /// The inferred fields of the records
+    fields: Fields,
+    /// The records already flushed as newline delimited JSON, if `retain_records` is set
+    retained: String,
+}
+
+impl std::fmt::Debug for InferringDecoder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InferringDecoder")
+            .field("batch_size", &self.batch_size)
+            .field("retain_records", &self.retain_records)
+            .field("fields", &self.fields)
+            .finish()
+    }
+}
+
+impl Default for InferringDecoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InferringDecoder {
+    /// Create a new [`InferringDecoder`] with a batch size of 1024
+    pub fn new() -> Self {
+        Self::new_with_batch_size(1024)
+    }
+
+    fn new_with_batch_size(batch_size: usize) -> Self {
+        Self {
+            tape_decoder: TapeDecoder::new(batch_size, 0),
+            batch_size,
+            retain_records: false,
+            fields: Fields::empty(),
+            retained: String::new(),
+        }
+    }
+
+    /// Sets the batch size in rows to read
+    pub fn with_batch_size(self, batch_size: usize) -> Self {
+        Self {
+            retain_records: self.retain_records,
+            ..Self::new_with_batch_size(batch_size)
+        }
+    }
+
+    /// Sets whether to retain the records of flushed batches, so that they can be
+    /// returned by [`Self::finish`] decoded with the final schema
+    ///
+    /// Note that this retains all the decoded records in memory, as compact JSON.
+    pub fn with_retain_records(self, retain_records: bool) -> Self {
+        Self {
+            retain_records,
+            ..self
+        }
+    }
+
+    /// Returns the schema inferred from the records flushed so far
+    pub fn schema(&self) -> SchemaRef {
+        Arc::new(Schema::new(self.fields.clone()))
+    }
+
+    /// Returns the number of records decoded but not yet flushed, including the
+    /// partially decoded record (if any)
+    pub fn num_buffered_rows(&self) -> usize {
+        self.tape_decoder.num_buffered_rows()
+    }
+
+    /// True if the decoder is currently part way through decoding a record
+    pub fn has_partial_record(&self) -> bool {
+        self.tape_decoder.has_partial_row()
+    }
+
+    /// Read JSON records from `buf`, returning the number of bytes read
+    ///
+    /// Like [`Decoder::decode`], this returns once `batch_size` records have been
+    /// buffered, in which case [`Self::flush`] should be called. Any remaining
+    /// bytes should be included in the next call to [`Self::decode`].
+    ///
+    /// [`Decoder::decode`]: super::Decoder::decode
+    pub fn decode(&mut self, buf: &[u8]) -> Result<usize, ArrowError> {
+        self.tape_decoder.decode(buf)
+    }
+
+    /// Flushes the buffered records to a [`RecordBatch`] with the schema inferred
+    /// from the records decoded so far, returning `None` if there are none
+    ///
+    /// Like [`Decoder::flush`], this returns an error if called part way through
+    /// decoding a record.
+    ///
+    /// [`Decoder::flush`]: super::Decoder::flush
+    pub fn flush(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
+        let tape = self.tape_decoder.finish()?;
+        if tape.num_rows() == 0 {
+            return Ok(None);
+        }
+
+        // First offset is null sentinel
+        let mut cur_idx = 1;
+        for _ in 0..tape.num_rows() {
+            self.fields = widen_fields(&self.fields, &infer_tape_fields(&tape, cur_idx)?);
+            cur_idx = match self.retain_records {
+                true => {
+                    let next_idx = tape.serialize_json(&mut self.retained, cur_idx);
+                    self.retained.push('\n');
+                    next_idx
+                }
+                false => tape.next(cur_idx, "row")?,
+            };
+        }
+
+        let batch = self
+            .decoder(self.tape_decoder.num_buffered_rows())?
+            .decode_tape(&tape)?;
+        self.tape_decoder.clear();
+        Ok(batch)
+    }
+
+    /// Flushes any buffered records, and returns the final inferred schema along
+    /// with the batches not yet flushed, preceded by all the flushed batches if
+    /// [`Self::with_retain_records`] is set, all decoded with the final schema
+    pub fn finish(mut self) -> Result<(SchemaRef, Vec<RecordBatch>), ArrowError> {
+        let last = self.flush()?;
+        if !self.retain_records {
+            return Ok((self.schema(), last.into_iter().collect()));
+        }
+
+        let mut decoder = self.decoder(self.batch_size)?;
+        let mut batches = vec![];
+        let mut buf = self.retained.as_bytes();
+        loop {
+            let read = decoder.decode(buf)?;
+            buf = &buf[read..];
+            match decoder.flush()? {
+                Some(batch) => batches.push(batch),
+                None => break,
+            }
+        }
+        Ok((self.schema(), batches))
+    }
+
+    /// Returns a [`Decoder`] for the inferred schema
+    ///
+    /// [`Decoder`]: super::Decoder
+    fn decoder(&self, batch_size: usize) -> Result<super::Decoder, ArrowError> {
+        ReaderBuilder::new(self.schema())
+            .with_batch_size(batch_size)
+            .with_raw_json_fallback(true)
+            .build_decoder()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::{Float64Type, Int64Type};
+    use arrow_array::{Array, StructArray};
+    use arrow_schema::{DataType, Field};
+
+    fn list_of(data_type: DataType) -> DataType {
+        DataType::List(Arc::new(Field::new_list_field(data_type, true)))
+    }
+
+    #[test]
+    fn test_inferring_decoder() {
+        let mut decoder = InferringDecoder::new().with_batch_size(2);
+        let data = b"{\"a\": 1, \"b\": null}\n\n{\"a\": 2, \"b\": [1]}\n{\"a\": 3.5, \"b\": [2.5]}";
+
+        let read = decoder.decode(data).unwrap();
+        assert_eq!(decoder.num_buffered_rows(), 2);
+        let batch = decoder.flush().unwrap().unwrap();
+        assert_eq!(batch.schema().field(0).data_type(), &DataType::Int64);
+        assert_eq!(
+            batch.schema().field(1).data_type(),
+            &list_of(DataType::Int64)
+        );
+        assert_eq!(
+            batch.column(0).as_primitive::<Int64Type>().values(),
+            &[1, 2]
+        );
+        assert!(batch.column(1).is_null(0));
+
+        assert_eq!(decoder.decode(&data[read..]).unwrap(), data.len() - read);
+        assert_eq!(decoder.num_buffered_rows(), 1);
+
+        let (schema, batches) = decoder.finish().unwrap();
+        assert_eq!(schema.field(0).data_type(), &DataType::Float64);
+        assert_eq!(schema.field(1).data_type(), &list_of(DataType::Float64));
+        assert_eq!(batches.len(), 1);
+        assert_eq!(
+            batches[0].column(0).as_primitive::<Float64Type>().value(0),
+            3.5
+        );
+    }
+
+    #[test]
+    fn test_inferring_decoder_conflicts() {
+        let mut decoder = InferringDecoder::new().with_retain_records(true);
+        let data = concat!(
+            "{\"a\": {\"x\": 1}, \"b\": true}\n",
+            "{\"a\": {\"y\": \"s\"}, \"b\": 1}\n",
+            "{\"a\": [1], \"c\": \"z\"}\n",
+        );
+        decoder.decode(data.as_bytes()).unwrap();
+        decoder.flush().unwrap().unwrap();
+
+        let (schema, batches) = decoder.finish().unwrap();
+        let expected = Schema::new(vec![
+            Field::new("a", DataType::Utf8, true),
+            Field::new("b", DataType::Utf8, true),
+            Field::new("c", DataType::Utf8, true),
+        ]);
+        assert_eq!(schema.as_ref(), &expected);
+        assert_eq!(batches.len(), 1);
+        let batch = StructArray::from(batches[0].clone());
+        let a = batch.column(0).as_string::<i32>();
+        assert_eq!(
+            a.iter().collect::<Vec<_>>(),
+            [Some(r#"{"x":1}"#), Some(r#"{"y":"s"}"#), Some("[1]")]
+        );
+        let b = batch.column(1).as_string::<i32>();
+        assert_eq!(
+            b.iter().collect::<Vec<_>>(),
+            [Some("true"), Some("1"), None]
+        );
+    }
+
+    #[test]
+    fn test_inferring_decoder_invalid() {
+        let mut decoder = InferringDecoder::new();
+        decoder.decode(b"[1, 2]\n").unwrap();
+        let err = decoder.flush().unwrap_err();
+        assert_eq!(err.to_string(), "Json error: expected object got [1, 2]");
+
+        let mut decoder = InferringDecoder::new();
+        decoder.decode(b"{\"a\": 1").unwrap();
+        let err = decoder.flush().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Json error: Truncated record whilst reading number"
+        );
+    }
+
+    #[test]
+    fn test_inferring_decoder_multiline() {
+        let mut decoder = InferringDecoder::new().with_retain_records(true);
+        let data = r#"{"a": 1, "b": "x\"y"} {"a": 2}
+        {
+            "a": [1, 2],
+            "b": {"c": null}
+        }"#;
+        for chunk in data.as_bytes().chunks(5) {
+            assert_eq!(decoder.decode(chunk).unwrap(), chunk.len());
+            if decoder.num_buffered_rows() == 2 && !decoder.has_partial_record() {
+                decoder.flush().unwrap().unwrap();
+            }
+        }
+
+        let (schema, batches) = decoder.finish().unwrap();
+        let expected = Schema::new(vec![
+            Field::new("a", DataType::Utf8, true),
+            Field::new("b", DataType::Utf8, true),
+        ]);
+        assert_eq!(schema.as_ref(), &expected);
+        assert_eq!(batches.len(), 1);
+        let a = batches[0].column(0).as_string::<i32>();
+        assert_eq!(
+            a.iter().collect::<Vec<_>>(),
+            [Some("1"), Some("2"), Some("[1,2]")]
+        );
+        let b = batches[0].column(1).as_string::<i32>();
+        assert_eq!(
+            b.iter().collect::<Vec<_>>(),
+            [Some("x\"y"), None, Some(r#"{"c":null}"#)]
+        );
+    }
+}
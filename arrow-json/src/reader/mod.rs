@@ -151,6 +151,7 @@ use crate::reader::binary_array::{
 };
 use crate::reader::boolean_array::BooleanArrayDecoder;
 use crate::reader::decimal_array::DecimalArrayDecoder;
+pub use crate::reader::decimal_array::DecimalRounding;
 use crate::reader::list_array::{
     FixedSizeListArrayDecoder, ListArrayDecoder, ListViewArrayDecoder,
 };
@@ -161,15 +162,17 @@ use crate::reader::run_end_array::RunEndEncodedArrayDecoder;
 use crate::reader::string_array::StringArrayDecoder;
 use crate::reader::string_view_array::StringViewArrayDecoder;
 use crate::reader::struct_array::StructArrayDecoder;
-use crate::reader::tape::{Tape, TapeDecoder};
+use crate::reader::tape::{Projection, Tape, TapeDecoder};
 use crate::reader::timestamp_array::TimestampArrayDecoder;
 
+pub use infer::InferringDecoder;
 pub use schema::*;
 pub use value_iter::ValueIter;
 
 mod binary_array;
 mod boolean_array;
 mod decimal_array;
+mod infer;
 mod list_array;
 mod map_array;
 mod null_array;
@@ -190,6 +193,9 @@ pub struct ReaderBuilder {
     coerce_primitive: bool,
     strict_mode: bool,
     ignore_type_conflicts: bool,
+    raw_json_fallback: bool,
+    schema_projection: bool,
+    decimal_rounding: DecimalRounding,
     is_field: bool,
     struct_mode: StructMode,
 
@@ -211,6 +217,9 @@ impl ReaderBuilder {
             coerce_primitive: false,
             strict_mode: false,
             ignore_type_conflicts: false,
+            raw_json_fallback: false,
+            schema_projection: false,
+            decimal_rounding: Default::default(),
             is_field: false,
             struct_mode: Default::default(),
             schema,
@@ -253,6 +262,9 @@ impl ReaderBuilder {
             coerce_primitive: false,
             strict_mode: false,
             ignore_type_conflicts: false,
+            raw_json_fallback: false,
+            schema_projection: false,
+            decimal_rounding: Default::default(),
             is_field: true,
             struct_mode: Default::default(),
             schema: Arc::new(Schema::new([field.into()])),
@@ -314,6 +326,128 @@ impl ReaderBuilder {
         }
     }
 
+    /// Sets whether string columns should decode JSON values of any other type, including
+    /// objects and lists, as their JSON text instead of returning an error.
+    ///
+    /// This allows ingesting fields of unknown or heterogeneous structure, for example
+    /// from a stream of events whose schema drifts over time, by declaring them as `Utf8`,
+    /// `LargeUtf8` or `Utf8View`. JSON strings are decoded as their value, as without this
+    /// option, and `null` as a NULL. The JSON text of other values can later be parsed,
+    /// for example into a Variant column.
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::cast::AsArray;
+    /// # use arrow_json::ReaderBuilder;
+    /// # use arrow_schema::{DataType, Field, Schema};
+    /// let data = r#"
+    /// {"id": 1, "payload": {"a": [1, true]}}
+    /// {"id": 2, "payload": "text"}
+    /// {"id": 3, "payload": 4.5}
+    /// "#;
+    /// let schema = Schema::new(vec![
+    ///     Field::new("id", DataType::Int32, false),
+    ///     Field::new("payload", DataType::Utf8, true),
+    /// ]);
+    /// let mut reader = ReaderBuilder::new(Arc::new(schema))
+    ///     .with_raw_json_fallback(true)
+    ///     .build(data.as_bytes())
+    ///     .unwrap();
+    /// let batch = reader.next().unwrap().unwrap();
+    /// let payload = batch.column(1).as_string::<i32>();
+    /// assert_eq!(payload.value(0), r#"{"a":[1,true]}"#);
+    /// assert_eq!(payload.value(1), "text");
+    /// assert_eq!(payload.value(2), "4.5");
+    /// ```
+    pub fn with_raw_json_fallback(self, raw_json_fallback: bool) -> Self {
+        Self {
+            raw_json_fallback,
+            ..self
+        }
+    }
+
+    /// Sets whether the decoder should skip over the values of object fields not present in
+    /// the schema while tokenizing, instead of decoding them and later ignoring them.
+    ///
+    /// This allows efficiently decoding only selected nested paths of large records, such
+    /// as the `geometry.coordinates` and `properties.name` of GeoJSON features, by providing
+    /// a schema containing only these paths. The fields of structs, including within lists,
+    /// are projected, while the values of all other types, such as maps, are fully decoded.
+    ///
+    /// Skipped values are not validated beyond matching their brackets and quotes, and so
+    /// invalid JSON within them may not be reported. This has no effect if `strict_mode`
+    /// is set or `struct_mode` is not [`StructMode::ObjectOnly`].
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::cast::AsArray;
+    /// # use arrow_array::types::Float64Type;
+    /// # use arrow_json::ReaderBuilder;
+    /// # use arrow_schema::{DataType, Field, Fields, Schema};
+    /// let data = r#"
+    /// {"type": "Feature", "geometry": {"type": "Point", "coordinates": [1.5, 2.5]}, "properties": {"name": "a", "tags": {"x": [1, {"y": 2}]}}}
+    /// "#;
+    /// let coordinates = Field::new_list("coordinates", Field::new_list_field(DataType::Float64, false), false);
+    /// let schema = Schema::new(vec![
+    ///     Field::new_struct("geometry", vec![coordinates], false),
+    ///     Field::new_struct("properties", vec![Field::new("name", DataType::Utf8, true)], false),
+    /// ]);
+    /// let mut reader = ReaderBuilder::new(Arc::new(schema))
+    ///     .with_schema_projection(true)
+    ///     .build(data.as_bytes())
+    ///     .unwrap();
+    /// let batch = reader.next().unwrap().unwrap();
+    /// let geometry = batch.column(0).as_struct();
+    /// let coordinates = geometry.column(0).as_list::<i32>().value(0);
+    /// assert_eq!(coordinates.as_primitive::<Float64Type>().values(), &[1.5, 2.5]);
+    /// let properties = batch.column(1).as_struct();
+    /// assert_eq!(properties.column(0).as_string::<i32>().value(0), "a");
+    /// ```
+    pub fn with_schema_projection(self, schema_projection: bool) -> Self {
+        Self {
+            schema_projection,
+            ..self
+        }
+    }
+
+    /// Sets how decimal values with more fractional digits than the scale of their
+    /// decimal column are decoded, defaults to [`DecimalRounding::Truncate`]
+    ///
+    /// Decimals are parsed exactly from the text of JSON numbers and strings, and so
+    /// values with up to the scale of the column in fractional digits are not affected.
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::cast::AsArray;
+    /// # use arrow_array::types::Decimal128Type;
+    /// # use arrow_json::ReaderBuilder;
+    /// # use arrow_json::reader::DecimalRounding;
+    /// # use arrow_schema::{DataType, Field, Schema};
+    /// let data = r#"{"d": 12345678901234567890.125}
+    /// {"d": "-0.135"}"#;
+    /// let schema = Schema::new(vec![Field::new("d", DataType::Decimal128(38, 2), true)]);
+    /// let mut reader = ReaderBuilder::new(Arc::new(schema.clone()))
+    ///     .with_decimal_rounding(DecimalRounding::HalfAwayFromZero)
+    ///     .build(data.as_bytes())
+    ///     .unwrap();
+    /// let batch = reader.next().unwrap().unwrap();
+    /// let d = batch.column(0).as_primitive::<Decimal128Type>();
+    /// assert_eq!(d.value_as_string(0), "12345678901234567890.13");
+    /// assert_eq!(d.value_as_string(1), "-0.14");
+    ///
+    /// let mut reader = ReaderBuilder::new(Arc::new(schema))
+    ///     .with_decimal_rounding(DecimalRounding::Error)
+    ///     .build(data.as_bytes())
+    ///     .unwrap();
+    /// assert!(reader.next().unwrap().is_err());
+    /// ```
+    pub fn with_decimal_rounding(self, decimal_rounding: DecimalRounding) -> Self {
+        Self {
+            decimal_rounding,
+            ..self
+        }
+    }
+
     /// Create a [`Reader`] with the provided [`BufRead`]
     pub fn build<R: BufRead>(self, reader: R) -> Result<Reader<R>, ArrowError> {
         Ok(Reader {
@@ -338,15 +472,24 @@ impl ReaderBuilder {
             strict_mode: self.strict_mode,
             struct_mode: self.struct_mode,
             ignore_type_conflicts: self.ignore_type_conflicts,
+            raw_json_fallback: self.raw_json_fallback,
+            decimal_rounding: self.decimal_rounding,
         };
         let decoder = ctx.make_decoder(data_type.as_ref(), nullable)?;
 
         let num_fields = self.schema.flattened_fields().len();
+        let projection = match self.schema_projection
+            && !self.strict_mode
+            && self.struct_mode == StructMode::ObjectOnly
+        {
+            true => Projection::try_new(data_type.as_ref()),
+            false => None,
+        };
 
         Ok(Decoder {
             decoder,
             is_field: self.is_field,
-            tape_decoder: TapeDecoder::new(self.batch_size, num_fields),
+            tape_decoder: TapeDecoder::new(self.batch_size, num_fields).with_projection(projection),
             batch_size: self.batch_size,
             schema: self.schema,
         })
@@ -676,32 +819,51 @@ impl Decoder {
     /// i.e. [`Self::has_partial_record`] is true.
     pub fn flush(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
         let tape = self.tape_decoder.finish()?;
-
-        if tape.num_rows() == 0 {
-            return Ok(None);
+        let batch = decode_rows(self.decoder.as_mut(), &tape, self.is_field, &self.schema)?;
+        if batch.is_some() {
+            self.tape_decoder.clear();
         }
+        Ok(batch)
+    }
 
-        // First offset is null sentinel
-        let mut next_object = 1;
-        let pos: Vec<_> = (0..tape.num_rows())
-            .map(|_| {
-                let next = tape.next(next_object, "row").unwrap();
-                std::mem::replace(&mut next_object, next)
-            })
-            .collect();
+    /// Decodes the rows of a [`Tape`] decoded by another [`TapeDecoder`]
+    pub(crate) fn decode_tape(
+        &mut self,
+        tape: &Tape<'_>,
+    ) -> Result<Option<RecordBatch>, ArrowError> {
+        decode_rows(self.decoder.as_mut(), tape, self.is_field, &self.schema)
+    }
+}
 
-        let decoded = self.decoder.decode(&tape, &pos)?;
-        self.tape_decoder.clear();
+/// Decodes the rows of `tape` to a [`RecordBatch`] with `schema`, returning `None`
+/// if there are no rows
+fn decode_rows(
+    decoder: &mut dyn ArrayDecoder,
+    tape: &Tape<'_>,
+    is_field: bool,
+    schema: &SchemaRef,
+) -> Result<Option<RecordBatch>, ArrowError> {
+    if tape.num_rows() == 0 {
+        return Ok(None);
+    }
+
+    // First offset is null sentinel
+    let mut next_object = 1;
+    let pos: Vec<_> = (0..tape.num_rows())
+        .map(|_| {
+            let next = tape.next(next_object, "row").unwrap();
+            std::mem::replace(&mut next_object, next)
+        })
+        .collect();
 
-        let batch = match self.is_field {
-            true => RecordBatch::try_new(self.schema.clone(), vec![decoded])?,
-            false => {
-                RecordBatch::from(decoded.as_struct().clone()).with_schema(self.schema.clone())?
-            }
-        };
+    let decoded = decoder.decode(tape, &pos)?;
 
-        Ok(Some(batch))
-    }
+    let batch = match is_field {
+        true => RecordBatch::try_new(schema.clone(), vec![decoded])?,
+        false => RecordBatch::from(decoded.as_struct().clone()).with_schema(schema.clone())?,
+    };
+
+    Ok(Some(batch))
 }
 
 trait ArrayDecoder: Send {
@@ -722,6 +884,10 @@ pub struct DecoderContext {
     struct_mode: StructMode,
     /// Whether to treat columns with incompatible types as missing (i.e. NULL)
     ignore_type_conflicts: bool,
+    /// Whether to decode non-string values as JSON text in string columns
+    raw_json_fallback: bool,
+    /// How to decode decimals with more fractional digits than their scale
+    decimal_rounding: DecimalRounding,
 }
 
 impl DecoderContext {
@@ -745,6 +911,16 @@ impl DecoderContext {
         self.ignore_type_conflicts
     }
 
+    /// Returns whether to decode non-string values as JSON text in string columns
+    pub fn raw_json_fallback(&self) -> bool {
+        self.raw_json_fallback
+    }
+
+    /// Returns how to decode decimals with more fractional digits than their scale
+    pub fn decimal_rounding(&self) -> DecimalRounding {
+        self.decimal_rounding
+    }
+
     /// Create a decoder for a type.
     ///
     /// This is the standard way to create child decoders from within a decoder
@@ -1459,6 +1635,106 @@ mod tests {
         test_decimal::<Decimal256Type>(DataType::Decimal256(10, 2));
     }
 
+    #[test]
+    fn test_decimal_rounding() {
+        let buf = r#"
+        {"a": 1.005, "b": "99999999999999999999999999999999999.995"}
+        {"a": -1.005, "b": "-0.0049"}
+        {"a": 1.0049, "b": 1.250e-1}
+        {"a": "2.5e-3", "b": 12345e-5}
+        {"a": 1.10000, "b": -7}
+        "#;
+        let read = |rounding, a: DataType, b: DataType| {
+            let schema = Schema::new(vec![Field::new("a", a, true), Field::new("b", b, true)]);
+            let mut decoder = ReaderBuilder::new(Arc::new(schema))
+                .with_decimal_rounding(rounding)
+                .build_decoder()
+                .unwrap();
+            decoder.decode(buf.as_bytes())?;
+            Ok::<_, ArrowError>(decoder.flush()?.unwrap())
+        };
+        let strings = |array: &ArrayRef| -> Vec<String> {
+            let formatter = ArrayFormatter::try_new(array, &Default::default()).unwrap();
+            (0..array.len())
+                .map(|i| formatter.value(i).to_string())
+                .collect()
+        };
+
+        let batch = read(
+            DecimalRounding::Truncate,
+            DataType::Decimal32(5, 2),
+            DataType::Decimal128(38, 2),
+        )
+        .unwrap();
+        assert_eq!(
+            strings(batch.column(0)),
+            ["1.00", "-1.00", "1.00", "0.00", "1.10"]
+        );
+        assert_eq!(
+            strings(batch.column(1)),
+            [
+                "99999999999999999999999999999999999.99",
+                "0.00",
+                "0.12",
+                "0.12",
+                "-7.00"
+            ]
+        );
+
+        let batch = read(
+            DecimalRounding::HalfAwayFromZero,
+            DataType::Decimal32(5, 2),
+            DataType::Decimal256(39, 2),
+        )
+        .unwrap();
+        assert_eq!(
+            strings(batch.column(0)),
+            ["1.01", "-1.01", "1.00", "0.00", "1.10"]
+        );
+        assert_eq!(
+            strings(batch.column(1)),
+            [
+                "100000000000000000000000000000000000.00",
+                "0.00",
+                "0.13",
+                "0.12",
+                "-7.00"
+            ]
+        );
+
+        let err = read(
+            DecimalRounding::HalfAwayFromZero,
+            DataType::Decimal32(5, 2),
+            DataType::Decimal128(37, 2),
+        )
+        .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("too large to store in a Decimal128 of precision 37")
+        );
+
+        let err = read(
+            DecimalRounding::Error,
+            DataType::Decimal64(5, 3),
+            DataType::Decimal128(38, 4),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: 1.0049 cannot be represented with scale 3 without rounding"
+        );
+        let batch = read(
+            DecimalRounding::Error,
+            DataType::Decimal64(5, 4),
+            DataType::Decimal256(40, 5),
+        )
+        .unwrap();
+        assert_eq!(
+            strings(batch.column(0)),
+            ["1.0050", "-1.0050", "1.0049", "0.0025", "1.1000"]
+        );
+    }
+
     fn test_timestamp<T: ArrowTimestampType>() {
         let buf = r#"
         {"a": 1, "b": "2020-09-08T13:42:29.190855+00:00", "c": 38.30, "d": "1997-01-31T09:26:56.123"}
@@ -3474,6 +3750,63 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_raw_json_fallback() {
+        let buf = r#"
+        {"a": "x", "b": {"c": [1, 2.5e1, null], "d": "q\"\\\n"}}
+        {"a": {}, "b": []}
+        {"a": null, "b": [{"e": false}, -12]}
+        {"b": true}
+        "#;
+        let expected_a = [Some("x"), Some("{}"), None, None];
+        let expected_b = [
+            Some(r#"{"c":[1,2.5e1,null],"d":"q\"\\\n"}"#),
+            Some("[]"),
+            Some(r#"[{"e":false},-12]"#),
+            Some("true"),
+        ];
+
+        for data_type in [DataType::Utf8, DataType::LargeUtf8, DataType::Utf8View] {
+            let schema = Arc::new(Schema::new(vec![
+                Field::new("a", data_type.clone(), true),
+                Field::new("b", data_type.clone(), true),
+            ]));
+            let batches = ReaderBuilder::new(schema)
+                .with_raw_json_fallback(true)
+                .build(Cursor::new(buf.as_bytes()))
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            assert_eq!(batches.len(), 1);
+
+            let a = arrow_cast::cast(batches[0].column(0), &DataType::Utf8).unwrap();
+            let b = arrow_cast::cast(batches[0].column(1), &DataType::Utf8).unwrap();
+            assert_eq!(a.as_string::<i32>().iter().collect::<Vec<_>>(), expected_a);
+            assert_eq!(b.as_string::<i32>().iter().collect::<Vec<_>>(), expected_b);
+
+            // the JSON text of nested values round trips
+            let value: serde_json::Value =
+                serde_json::from_str(b.as_string::<i32>().value(0)).unwrap();
+            assert_eq!(value, json!({"c": [1, 25.0, null], "d": "q\"\\\n"}));
+        }
+    }
+
+    #[test]
+    fn test_raw_json_fallback_serialize() {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Utf8, true)]));
+        let mut decoder = ReaderBuilder::new(schema)
+            .with_raw_json_fallback(true)
+            .build_decoder()
+            .unwrap();
+        decoder
+            .serialize(&[json!({"a": {"b": 1, "c": 1.5}}), json!({"a": "s"})])
+            .unwrap();
+        let batch = decoder.flush().unwrap().unwrap();
+        let a = batch.column(0).as_string::<i32>();
+        assert_eq!(a.value(0), r#"{"b":1,"c":1.5}"#);
+        assert_eq!(a.value(1), "s");
+    }
+
     #[test]
     fn test_ignore_type_conflicts_disabled() {
         let fields = [
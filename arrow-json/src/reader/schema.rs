@@ -16,6 +16,7 @@
 // under the License.
 
 use super::ValueIter;
+use super::tape::{Tape, TapeElement};
 use arrow_schema::{ArrowError, DataType, Field, Fields, Schema};
 use indexmap::map::IndexMap as HashMap;
 use indexmap::set::IndexSet as HashSet;
@@ -108,6 +109,94 @@ fn coerce_data_type(dt: Vec<&DataType>) -> DataType {
     })
 }
 
+/// Returns the narrowest type that values of both `a` and `b` can be decoded as
+///
+/// * Lists are widened by widening their element types
+/// * Objects are widened by merging their fields, see [`widen_fields`]
+/// * Scalars are coerced as in [`coerce_data_type`]
+/// * All other conflicts, including between lists or objects and scalars, are
+///   widened to `Utf8`
+pub(crate) fn widen_data_type(a: &DataType, b: &DataType) -> DataType {
+    match (a, b) {
+        (DataType::Null, other) | (other, DataType::Null) => other.clone(),
+        (DataType::List(a), DataType::List(b)) => {
+            list_type_of(widen_data_type(a.data_type(), b.data_type()))
+        }
+        (DataType::Struct(a), DataType::Struct(b)) => DataType::Struct(widen_fields(a, b)),
+        (DataType::List(_) | DataType::Struct(_), _)
+        | (_, DataType::List(_) | DataType::Struct(_)) => DataType::Utf8,
+        (a, b) => coerce_data_type(vec![a, b]),
+    }
+}
+
+/// Merges the fields of `a` and `b` by name, widening the types of fields in both
+pub(crate) fn widen_fields(a: &Fields, b: &Fields) -> Fields {
+    let mut fields: Vec<Field> = a.iter().map(|f| f.as_ref().clone()).collect();
+    b.iter()
+        .for_each(|f| merge_field(&mut fields, f.as_ref().clone()));
+    fields.into()
+}
+
+fn merge_field(fields: &mut Vec<Field>, field: Field) {
+    match fields.iter_mut().find(|f| f.name() == field.name()) {
+        Some(existing) => {
+            let data_type = widen_data_type(existing.data_type(), field.data_type());
+            existing.set_data_type(data_type);
+        }
+        None => fields.push(field),
+    }
+}
+
+/// Infers the fields of the object at index `idx` of `tape`
+pub(crate) fn infer_tape_fields(tape: &Tape<'_>, idx: u32) -> Result<Fields, ArrowError> {
+    let TapeElement::StartObject(end) = tape.get(idx) else {
+        return Err(tape.error(idx, "object"));
+    };
+    let mut fields = vec![];
+    let mut cur_idx = idx + 1;
+    while cur_idx < end {
+        let name = match tape.get(cur_idx) {
+            TapeElement::String(s) => tape.get_string(s),
+            _ => return Err(tape.error(cur_idx, "field name")),
+        };
+        let (data_type, next_idx) = infer_tape_type(tape, cur_idx + 1)?;
+        merge_field(&mut fields, Field::new(name, data_type, true));
+        cur_idx = next_idx;
+    }
+    Ok(fields.into())
+}
+
+/// Infers the type of the value at index `idx` of `tape`, returning it along with
+/// the index of the next value
+fn infer_tape_type(tape: &Tape<'_>, idx: u32) -> Result<(DataType, u32), ArrowError> {
+    let data_type = match tape.get(idx) {
+        TapeElement::StartObject(_) => DataType::Struct(infer_tape_fields(tape, idx)?),
+        TapeElement::StartList(end) => {
+            let mut element = DataType::Null;
+            let mut cur_idx = idx + 1;
+            while cur_idx < end {
+                let (data_type, next_idx) = infer_tape_type(tape, cur_idx)?;
+                element = widen_data_type(&element, &data_type);
+                cur_idx = next_idx;
+            }
+            list_type_of(element)
+        }
+        TapeElement::String(_) => DataType::Utf8,
+        TapeElement::Number(n) => match tape.get_string(n).parse::<i64>() {
+            Ok(_) => DataType::Int64,
+            Err(_) => DataType::Float64,
+        },
+        TapeElement::I64(_) | TapeElement::I32(_) => DataType::Int64,
+        TapeElement::F64(_) | TapeElement::F32(_) => DataType::Float64,
+        TapeElement::True | TapeElement::False => DataType::Boolean,
+        TapeElement::Null => DataType::Null,
+        TapeElement::EndObject(_) | TapeElement::EndList(_) => {
+            return Err(tape.error(idx, "value"));
+        }
+    };
+    Ok((data_type, tape.next(idx, "value")?))
+}
+
 fn generate_datatype(t: &InferredType) -> Result<DataType, ArrowError> {
     Ok(match t {
         InferredType::Scalar(hs) => coerce_data_type(hs.iter().collect()),
@@ -600,6 +689,29 @@ mod tests {
         assert_eq!(small_field.data_type(), &DataType::Float64);
     }
 
+    #[test]
+    fn test_widen_data_type() {
+        let widen = widen_data_type;
+        assert_eq!(widen(&DataType::Null, &DataType::Int64), DataType::Int64);
+        assert_eq!(
+            widen(&DataType::Int64, &DataType::Float64),
+            DataType::Float64
+        );
+        assert_eq!(widen(&DataType::Float64, &DataType::Utf8), DataType::Utf8);
+        assert_eq!(widen(&DataType::Boolean, &DataType::Int64), DataType::Utf8);
+        assert_eq!(
+            widen(
+                &list_type_of(DataType::Int64),
+                &list_type_of(DataType::Float64)
+            ),
+            list_type_of(DataType::Float64)
+        );
+        assert_eq!(
+            widen(&list_type_of(DataType::Int64), &DataType::Int64),
+            DataType::Utf8
+        );
+    }
+
     #[test]
     fn test_coercion_scalar_and_list() {
         assert_eq!(
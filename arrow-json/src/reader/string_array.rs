@@ -33,6 +33,7 @@ const FALSE: &str = "false";
 pub struct StringArrayDecoder<O: OffsetSizeTrait> {
     coerce_primitive: bool,
     ignore_type_conflicts: bool,
+    raw_json_fallback: bool,
     phantom: PhantomData<O>,
 }
 
@@ -41,6 +42,7 @@ impl<O: OffsetSizeTrait> StringArrayDecoder<O> {
         Self {
             coerce_primitive: ctx.coerce_primitive(),
             ignore_type_conflicts: ctx.ignore_type_conflicts(),
+            raw_json_fallback: ctx.raw_json_fallback(),
             phantom: Default::default(),
         }
     }
@@ -75,6 +77,10 @@ impl<O: OffsetSizeTrait> ArrayDecoder for StringArrayDecoder<O> {
                     // An arbitrary estimate
                     data_capacity += 10;
                 }
+                _ if self.raw_json_fallback => {
+                    // An arbitrary estimate of the length of each element
+                    data_capacity += (tape.next(*p, "string")? - *p) as usize * 8;
+                }
                 _ if self.ignore_type_conflicts => {}
                 _ => {
                     return Err(tape.error(*p, "string"));
@@ -93,6 +99,7 @@ impl<O: OffsetSizeTrait> ArrayDecoder for StringArrayDecoder<O> {
 
         let mut float_formatter = ryu::Buffer::new();
         let mut int_formatter = itoa::Buffer::new();
+        let mut json_buf = String::new();
 
         for p in pos {
             match tape.get(*p) {
@@ -129,6 +136,11 @@ impl<O: OffsetSizeTrait> ArrayDecoder for StringArrayDecoder<O> {
                     }
                     _ => unreachable!(),
                 },
+                _ if self.raw_json_fallback => {
+                    json_buf.clear();
+                    tape.serialize_json(&mut json_buf, *p);
+                    builder.append_value(&json_buf);
+                }
                 _ if self.ignore_type_conflicts => builder.append_null(),
                 _ => unreachable!(),
             }
@@ -32,6 +32,7 @@ const FALSE: &str = "false";
 pub struct StringViewArrayDecoder {
     coerce_primitive: bool,
     ignore_type_conflicts: bool,
+    raw_json_fallback: bool,
 }
 
 impl StringViewArrayDecoder {
@@ -39,6 +40,7 @@ impl StringViewArrayDecoder {
         Self {
             coerce_primitive: ctx.coerce_primitive(),
             ignore_type_conflicts: ctx.ignore_type_conflicts(),
+            raw_json_fallback: ctx.raw_json_fallback(),
         }
     }
 }
@@ -104,6 +106,10 @@ impl ArrayDecoder for StringViewArrayDecoder {
                 TapeElement::F64(_) if coerce => {
                     data_capacity += 10;
                 }
+                _ if self.raw_json_fallback => {
+                    // An arbitrary estimate of the length of each element
+                    data_capacity += (tape.next(p, "string")? - p) as usize * 8;
+                }
                 _ if self.ignore_type_conflicts => {} // treat type conflicts like nulls
                 _ => {
                     return Err(tape.error(p, "string"));
@@ -161,6 +167,11 @@ impl ArrayDecoder for StringViewArrayDecoder {
                     }
                     _ => unreachable!(),
                 },
+                _ if self.raw_json_fallback => {
+                    tmp_buf.clear();
+                    tape.serialize_json(&mut tmp_buf, p);
+                    builder.append_value(&tmp_buf);
+                }
                 _ if self.ignore_type_conflicts => {
                     builder.append_null();
                 }
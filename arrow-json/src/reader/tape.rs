@@ -16,9 +16,10 @@
 // under the License.
 
 use crate::reader::serializer::TapeSerializer;
-use arrow_schema::ArrowError;
+use arrow_schema::{ArrowError, DataType};
 use memchr::memchr2;
 use serde_core::Serialize;
+use std::collections::HashMap;
 use std::fmt::Write;
 
 /// We decode JSON to a flattened tape representation,
@@ -205,6 +206,47 @@ impl<'a> Tape<'a> {
         idx + 1
     }
 
+    /// Serialize the tape element at index `idx` to `out` as compact JSON, returning the
+    /// next field index
+    ///
+    /// Unlike [`Self::serialize`], which is used for error messages, this escapes strings
+    /// and separates object fields so that the output is valid JSON
+    pub fn serialize_json(&self, out: &mut String, idx: u32) -> u32 {
+        match self.get(idx) {
+            TapeElement::StartObject(end) => {
+                out.push('{');
+                let mut cur_idx = idx + 1;
+                while cur_idx < end {
+                    if cur_idx > idx + 1 {
+                        out.push(',');
+                    }
+                    cur_idx = self.serialize_json(out, cur_idx);
+                    out.push(':');
+                    cur_idx = self.serialize_json(out, cur_idx);
+                }
+                out.push('}');
+                end + 1
+            }
+            TapeElement::StartList(end) => {
+                out.push('[');
+                let mut cur_idx = idx + 1;
+                while cur_idx < end {
+                    if cur_idx > idx + 1 {
+                        out.push(',');
+                    }
+                    cur_idx = self.serialize_json(out, cur_idx);
+                }
+                out.push(']');
+                end + 1
+            }
+            TapeElement::String(s) => {
+                write_escaped(out, self.get_string(s));
+                idx + 1
+            }
+            _ => self.serialize(out, idx),
+        }
+    }
+
     /// Returns an error reading index `idx`
     pub fn error(&self, idx: u32, expected: &str) -> ArrowError {
         let mut out = String::with_capacity(64);
@@ -213,6 +255,61 @@ impl<'a> Tape<'a> {
     }
 }
 
+/// The object fields to decode, as a tree of field names with a node for each
+/// projected object, see [`TapeDecoder::with_projection`]
+#[derive(Debug, Default)]
+pub struct Projection {
+    /// The children of each node, by field name
+    nodes: Vec<HashMap<Vec<u8>, u32>>,
+}
+
+impl Projection {
+    /// The index of a node decoding all fields
+    const ALL: u32 = u32::MAX;
+
+    /// Create a [`Projection`] of the fields of `data_type`, returning `None` if
+    /// all fields are decoded
+    ///
+    /// The fields of structs, including within lists, are projected, while the
+    /// values of all other types, such as maps, are fully decoded
+    pub fn try_new(data_type: &DataType) -> Option<Self> {
+        let mut projection = Self::default();
+        let root = projection.add(data_type);
+        (root == 0).then_some(projection)
+    }
+
+    fn add(&mut self, data_type: &DataType) -> u32 {
+        match data_type {
+            DataType::Struct(fields) => {
+                let idx = self.nodes.len();
+                self.nodes.push(HashMap::with_capacity(fields.len()));
+                for field in fields {
+                    let child = self.add(field.data_type());
+                    let name = field.name().as_bytes().to_vec();
+                    self.nodes[idx].insert(name, child);
+                }
+                idx as u32
+            }
+            DataType::List(f)
+            | DataType::LargeList(f)
+            | DataType::ListView(f)
+            | DataType::LargeListView(f)
+            | DataType::FixedSizeList(f, _) => self.add(f.data_type()),
+            DataType::RunEndEncoded(_, values) => self.add(values.data_type()),
+            _ => Self::ALL,
+        }
+    }
+
+    /// Returns the node of the field `name` of the object decoded with `node`,
+    /// or `None` if it is not projected
+    fn field(&self, node: u32, name: &[u8]) -> Option<u32> {
+        match node {
+            Self::ALL => Some(Self::ALL),
+            node => self.nodes[node as usize].get(name).copied(),
+        }
+    }
+}
+
 /// States based on <https://www.json.org/json-en.html>
 #[derive(Debug, Copy, Clone)]
 enum DecoderState {
@@ -225,7 +322,10 @@ enum DecoderState {
     /// Contains index of start [`TapeElement::StartList`]
     List(u32),
     String,
-    Value,
+    /// Decoding a value
+    ///
+    /// Contains the node of the [`Projection`] to decode it with, or [`Projection::ALL`]
+    Value(u32),
     Number,
     Colon,
     Escape,
@@ -237,6 +337,16 @@ enum DecoderState {
     ///
     /// Consists of `(literal, decoded length)`
     Literal(Literal, u8),
+    /// Skipping the value of a field that is not projected
+    SkipValue,
+    /// Skipping a number or literal
+    SkipScalar,
+    /// Skipping a string
+    SkipString,
+    /// Skipping the escaped character of a string
+    SkipEscape,
+    /// Skipping a list or object, with the given nesting depth
+    SkipNested(u32),
 }
 
 impl DecoderState {
@@ -245,12 +355,17 @@ impl DecoderState {
             DecoderState::Object(_) => "object",
             DecoderState::List(_) => "list",
             DecoderState::String => "string",
-            DecoderState::Value => "value",
+            DecoderState::Value(_) => "value",
             DecoderState::Number => "number",
             DecoderState::Colon => "colon",
             DecoderState::Escape => "escape",
             DecoderState::Unicode(_, _, _) => "unicode literal",
             DecoderState::Literal(d, _) => d.as_str(),
+            DecoderState::SkipValue
+            | DecoderState::SkipScalar
+            | DecoderState::SkipString
+            | DecoderState::SkipEscape
+            | DecoderState::SkipNested(_) => "skipped value",
         }
     }
 }
@@ -315,6 +430,13 @@ pub struct TapeDecoder {
 
     /// A stack of [`DecoderState`]
     stack: Vec<DecoderState>,
+
+    /// The fields to decode, if not all
+    projection: Option<Projection>,
+
+    /// A stack of the [`Projection`] nodes of the objects and lists being decoded,
+    /// only maintained if `projection` is set
+    projection_stack: Vec<u32>,
 }
 
 impl TapeDecoder {
@@ -335,7 +457,38 @@ impl TapeDecoder {
             cur_row: 0,
             bytes: Vec::with_capacity(num_fields * 2 * 8),
             stack: Vec::with_capacity(10),
+            projection: None,
+            projection_stack: vec![],
+        }
+    }
+
+    /// Only decode the object fields in `projection`, skipping over the values of
+    /// other fields without decoding them to the tape
+    ///
+    /// Note that skipped values are not fully validated
+    pub fn with_projection(mut self, projection: Option<Projection>) -> Self {
+        self.projection = projection;
+        self
+    }
+
+    /// Called once the colon following the field name at the end of the tape has
+    /// been decoded, to skip the value of the field if it is not projected
+    fn project_field(&mut self, projection: &Projection) {
+        let node = *self.projection_stack.last().unwrap();
+        if node == Projection::ALL {
+            return;
         }
+        let start = self.offsets[self.offsets.len() - 2];
+        *self.stack.last_mut().unwrap() = match projection.field(node, &self.bytes[start..]) {
+            Some(child) => DecoderState::Value(child),
+            None => {
+                // Remove the field name from the tape
+                self.elements.pop();
+                self.offsets.pop();
+                self.bytes.truncate(start);
+                DecoderState::SkipValue
+            }
+        };
     }
 
     pub fn decode(&mut self, buf: &[u8]) -> Result<usize, ArrowError> {
@@ -352,7 +505,11 @@ impl TapeDecoder {
 
                     // Start of row
                     self.cur_row += 1;
-                    self.stack.push(DecoderState::Value);
+                    let root = match self.projection {
+                        Some(_) => 0,
+                        None => Projection::ALL,
+                    };
+                    self.stack.push(DecoderState::Value(root));
                     self.stack.last_mut().unwrap()
                 }
             };
@@ -363,7 +520,8 @@ impl TapeDecoder {
                     iter.advance_until(|b| !json_whitespace(b) && b != b',');
                     match next!(iter) {
                         b'"' => {
-                            self.stack.push(DecoderState::Value);
+                            // The projection of the value is set once the field name is decoded
+                            self.stack.push(DecoderState::Value(Projection::ALL));
                             self.stack.push(DecoderState::Colon);
                             self.stack.push(DecoderState::String);
                         }
@@ -373,6 +531,9 @@ impl TapeDecoder {
                             self.elements[start_idx as usize] = TapeElement::StartObject(end_idx);
                             self.elements.push(TapeElement::EndObject(start_idx));
                             self.stack.pop();
+                            if self.projection.is_some() {
+                                self.projection_stack.pop();
+                            }
                         }
                         b => return Err(err(b, "parsing object")),
                     }
@@ -388,8 +549,15 @@ impl TapeDecoder {
                             self.elements[start_idx as usize] = TapeElement::StartList(end_idx);
                             self.elements.push(TapeElement::EndList(start_idx));
                             self.stack.pop();
+                            if self.projection.is_some() {
+                                self.projection_stack.pop();
+                            }
+                        }
+                        Some(_) => {
+                            let node = self.projection_stack.last().copied();
+                            let node = node.unwrap_or(Projection::ALL);
+                            self.stack.push(DecoderState::Value(node))
                         }
-                        Some(_) => self.stack.push(DecoderState::Value),
                         None => break,
                     }
                 }
@@ -409,7 +577,10 @@ impl TapeDecoder {
                         b => unreachable!("{}", b),
                     }
                 }
-                state @ DecoderState::Value => {
+                state @ DecoderState::Value(_) => {
+                    let DecoderState::Value(node) = *state else {
+                        unreachable!()
+                    };
                     iter.skip_whitespace();
                     *state = match next!(iter) {
                         b'"' => DecoderState::String,
@@ -423,11 +594,17 @@ impl TapeDecoder {
                         b'[' => {
                             let idx = self.elements.len() as u32;
                             self.elements.push(TapeElement::StartList(u32::MAX));
+                            if self.projection.is_some() {
+                                self.projection_stack.push(node);
+                            }
                             DecoderState::List(idx)
                         }
                         b'{' => {
                             let idx = self.elements.len() as u32;
                             self.elements.push(TapeElement::StartObject(u32::MAX));
+                            if self.projection.is_some() {
+                                self.projection_stack.push(node);
+                            }
                             DecoderState::Object(idx)
                         }
                         b => return Err(err(b, "parsing value")),
@@ -449,10 +626,57 @@ impl TapeDecoder {
                 DecoderState::Colon => {
                     iter.skip_whitespace();
                     match next!(iter) {
-                        b':' => self.stack.pop(),
+                        b':' => {
+                            self.stack.pop();
+                            if let Some(projection) = self.projection.take() {
+                                self.project_field(&projection);
+                                self.projection = Some(projection);
+                            }
+                        }
                         b => return Err(err(b, "parsing colon")),
                     };
                 }
+                state @ DecoderState::SkipValue => {
+                    iter.skip_whitespace();
+                    *state = match next!(iter) {
+                        b'"' => DecoderState::SkipString,
+                        b'[' | b'{' => DecoderState::SkipNested(1),
+                        b @ (b',' | b']' | b'}') => return Err(err(b, "parsing value")),
+                        _ => DecoderState::SkipScalar,
+                    };
+                }
+                DecoderState::SkipScalar => {
+                    iter.advance_until(|b| json_whitespace(b) || matches!(b, b',' | b']' | b'}'));
+                    if !iter.is_empty() {
+                        self.stack.pop();
+                    }
+                }
+                DecoderState::SkipString => {
+                    iter.skip_chrs(b'\\', b'"');
+                    match next!(iter) {
+                        b'\\' => self.stack.push(DecoderState::SkipEscape),
+                        _ => {
+                            self.stack.pop();
+                        }
+                    }
+                }
+                DecoderState::SkipEscape => {
+                    next!(iter);
+                    self.stack.pop();
+                }
+                DecoderState::SkipNested(depth) => {
+                    iter.advance_until(|b| matches!(b, b'"' | b'[' | b'{' | b']' | b'}'));
+                    match next!(iter) {
+                        b'"' => self.stack.push(DecoderState::SkipString),
+                        b'[' | b'{' => *depth += 1,
+                        _ => {
+                            *depth -= 1;
+                            if *depth == 0 {
+                                self.stack.pop();
+                            }
+                        }
+                    }
+                }
                 DecoderState::Literal(literal, idx) => {
                     let bytes = literal.bytes();
                     let expected = bytes.iter().skip(*idx as usize).copied();
@@ -698,6 +922,27 @@ impl Iterator for BufIter<'_> {
 
 impl ExactSizeIterator for BufIter<'_> {}
 
+/// Writes `s` to `out` as a quoted and escaped JSON string
+fn write_escaped(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{08}' => out.push_str("\\b"),
+            '\u{0c}' => out.push_str("\\f"),
+            c if c < ' ' => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
 /// Returns an error for a given byte `b` and context `ctx`
 fn err(b: u8, ctx: &str) -> ArrowError {
     ArrowError::JsonError(format!(
@@ -743,6 +988,7 @@ fn parse_hex(b: u8) -> Result<u8, ArrowError> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use arrow_schema::{Field, Fields};
 
     #[test]
     fn test_sizes() {
@@ -962,6 +1208,72 @@ mod tests {
         assert_eq!(err, "Json error: Encountered truncated UTF-8 sequence");
     }
 
+    /// Decodes `json` in chunks of `chunk_size` bytes, returning the JSON of each row
+    fn decode_projected(json: &str, data_type: &DataType, chunk_size: usize) -> Vec<String> {
+        let projection = Projection::try_new(data_type);
+        let mut decoder = TapeDecoder::new(16, 2).with_projection(projection);
+        for chunk in json.as_bytes().chunks(chunk_size) {
+            decoder.decode(chunk).unwrap();
+        }
+        let tape = decoder.finish().unwrap();
+        let mut idx = 1;
+        (0..tape.num_rows())
+            .map(|_| {
+                let mut out = String::new();
+                idx = tape.serialize_json(&mut out, idx);
+                out
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_projection() {
+        let a = r#"
+        {"a": {"b": 1, "c": [1, {"d": "}]"}]}, "e": "\"{", "f": [{"g": true, "h": {}}], "b": null}
+        {"f": [{"h": [[], "\\"]}, {"g": 1.5e3}], "i": -12, "a": {"c": {"b": [2]}}}
+        {"e": {"\u00e9": [null]}, "a": {}, "j": "\ud83d\ude00"}
+        "#;
+        let fields = Fields::from(vec![
+            Field::new_struct("a", vec![Field::new("b", DataType::Int64, true)], true),
+            Field::new_list(
+                "f",
+                Field::new_struct("item", vec![Field::new("g", DataType::Boolean, true)], true),
+                true,
+            ),
+            Field::new("i", DataType::Int64, true),
+        ]);
+        let data_type = DataType::Struct(fields);
+        let expected = [
+            r#"{"a":{"b":1},"f":[{"g":true}]}"#,
+            r#"{"f":[{},{"g":1.5e3}],"i":-12,"a":{}}"#,
+            r#"{"a":{}}"#,
+        ];
+        for chunk_size in [1, 2, 7, a.len()] {
+            assert_eq!(decode_projected(a, &data_type, chunk_size), expected);
+        }
+
+        // Values of types other than structs and lists are fully decoded
+        let map = Field::new_map(
+            "a",
+            "entries",
+            Field::new("keys", DataType::Utf8, false),
+            Field::new("values", DataType::Int64, true),
+            false,
+            true,
+        );
+        let data_type = DataType::Struct(vec![map].into());
+        let rows = decode_projected(a, &data_type, a.len());
+        assert_eq!(rows[0], r#"{"a":{"b":1,"c":[1,{"d":"}]"}]}}"#);
+
+        // Skipped values must still be delimited
+        let mut decoder = TapeDecoder::new(16, 2).with_projection(Projection::try_new(&data_type));
+        let err = decoder.decode(br#"{"b": , "a": {}}"#).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Json error: Encountered unexpected ',' whilst parsing value"
+        );
+    }
+
     #[test]
     fn test_invalid_surrogates() {
         let mut decoder = TapeDecoder::new(16, 2);
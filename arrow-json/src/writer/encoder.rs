@@ -22,12 +22,34 @@ use arrow_array::cast::AsArray;
 use arrow_array::types::*;
 use arrow_array::*;
 use arrow_buffer::{ArrowNativeType, NullBuffer, OffsetBuffer, ScalarBuffer};
+use arrow_cast::base64::{BASE64_STANDARD, Engine};
 use arrow_cast::display::{ArrayFormatter, FormatOptions};
-use arrow_schema::{ArrowError, DataType, FieldRef};
+use arrow_schema::{ArrowError, DataType, FieldRef, TimeUnit};
 use half::f16;
 use lexical_core::FormattedSize;
 use serde_core::Serializer;
 
+/// How binary values are encoded as JSON strings
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BinaryEncoding {
+    /// Lowercase hexadecimal, e.g. `"0aff"`
+    #[default]
+    Hex,
+    /// Standard base64 with padding, e.g. `"Cv8="`
+    Base64,
+}
+
+/// How timestamp values are encoded in JSON
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TimestampEncoding {
+    /// Strings formatted with the configured timestamp formats, RFC3339 by default
+    #[default]
+    Formatted,
+    /// Integers counting the given units since the UNIX epoch, regardless of the
+    /// timezone of the timestamps
+    Epoch(TimeUnit),
+}
+
 /// Configuration options for the JSON encoder.
 #[derive(Debug, Clone, Default)]
 pub struct EncoderOptions {
@@ -47,6 +69,10 @@ pub struct EncoderOptions {
     timestamp_tz_format: Option<String>,
     /// Optional time format for time arrays
     time_format: Option<String>,
+    /// How to encode binary arrays
+    binary_encoding: BinaryEncoding,
+    /// How to encode timestamp arrays
+    timestamp_encoding: TimestampEncoding,
 }
 
 impl EncoderOptions {
@@ -137,6 +163,28 @@ impl EncoderOptions {
     pub fn timestamp_tz_format(&self) -> Option<&str> {
         self.timestamp_tz_format.as_deref()
     }
+
+    /// Set how to encode binary arrays
+    pub fn with_binary_encoding(mut self, binary_encoding: BinaryEncoding) -> Self {
+        self.binary_encoding = binary_encoding;
+        self
+    }
+
+    /// Get how to encode binary arrays, defaults to [`BinaryEncoding::Hex`]
+    pub fn binary_encoding(&self) -> BinaryEncoding {
+        self.binary_encoding
+    }
+
+    /// Set how to encode timestamp arrays
+    pub fn with_timestamp_encoding(mut self, timestamp_encoding: TimestampEncoding) -> Self {
+        self.timestamp_encoding = timestamp_encoding;
+        self
+    }
+
+    /// Get how to encode timestamp arrays, defaults to [`TimestampEncoding::Formatted`]
+    pub fn timestamp_encoding(&self) -> TimestampEncoding {
+        self.timestamp_encoding
+    }
 }
 
 /// A trait to create custom encoders for specific data types.
@@ -348,7 +396,8 @@ pub fn make_encoder<'a>(
         }
         DataType::BinaryView => {
             let array = array.as_binary_view();
-            NullableEncoder::new(Box::new(BinaryViewEncoder(array)), array.nulls().cloned())
+            let encoder = BinaryViewEncoder(array, options.binary_encoding());
+            NullableEncoder::new(Box::new(encoder), array.nulls().cloned())
         }
         DataType::List(_) => {
             let array = array.as_list::<i32>();
@@ -395,17 +444,20 @@ pub fn make_encoder<'a>(
 
         DataType::FixedSizeBinary(_) => {
             let array = array.as_fixed_size_binary();
-            NullableEncoder::new(Box::new(BinaryEncoder::new(array)) as _, array.nulls().cloned())
+            let encoder = BinaryEncoder::new(array, options.binary_encoding());
+            NullableEncoder::new(Box::new(encoder) as _, array.nulls().cloned())
         }
 
         DataType::Binary => {
             let array: &BinaryArray = array.as_binary();
-            NullableEncoder::new(Box::new(BinaryEncoder::new(array)), array.nulls().cloned())
+            let encoder = BinaryEncoder::new(array, options.binary_encoding());
+            NullableEncoder::new(Box::new(encoder), array.nulls().cloned())
         }
 
         DataType::LargeBinary => {
             let array: &LargeBinaryArray = array.as_binary();
-            NullableEncoder::new(Box::new(BinaryEncoder::new(array)), array.nulls().cloned())
+            let encoder = BinaryEncoder::new(array, options.binary_encoding());
+            NullableEncoder::new(Box::new(encoder), array.nulls().cloned())
         }
 
         DataType::Struct(fields) => {
@@ -431,6 +483,13 @@ pub fn make_encoder<'a>(
             let formatter = JsonArrayFormatter::new(ArrayFormatter::try_new(array, &options)?);
             NullableEncoder::new(Box::new(RawArrayFormatter(formatter)) as Box<dyn Encoder + 'a>, nulls)
         }
+        DataType::Timestamp(unit, _) if matches!(options.timestamp_encoding(), TimestampEncoding::Epoch(_)) => {
+            let TimestampEncoding::Epoch(to) = options.timestamp_encoding() else {
+                unreachable!()
+            };
+            let encoder = epoch_encoder(array, *unit, to)?;
+            NullableEncoder::new(Box::new(encoder) as Box<dyn Encoder + 'a>, nulls)
+        }
         d => match d.is_temporal() {
             true => {
                 // Note: the implementation of Encoder for ArrayFormatter assumes it does not produce
@@ -462,14 +521,74 @@ fn encode_string(s: &str, out: &mut Vec<u8>) {
     serializer.serialize_str(s).unwrap();
 }
 
-fn encode_binary(bytes: &[u8], out: &mut Vec<u8>) {
+fn encode_binary(bytes: &[u8], encoding: BinaryEncoding, out: &mut Vec<u8>) {
     out.push(b'"');
-    for byte in bytes {
-        write!(out, "{byte:02x}").unwrap();
+    match encoding {
+        BinaryEncoding::Hex => {
+            for byte in bytes {
+                // this write is infallible
+                write!(out, "{byte:02x}").unwrap();
+            }
+        }
+        BinaryEncoding::Base64 => {
+            let start = out.len();
+            out.resize(start + bytes.len().div_ceil(3) * 4, 0);
+            let written = BASE64_STANDARD
+                .encode_slice(bytes, &mut out[start..])
+                .unwrap();
+            out.truncate(start + written);
+        }
     }
     out.push(b'"');
 }
 
+/// Returns the number of nanoseconds in `unit`
+fn nanos_per(unit: TimeUnit) -> i64 {
+    match unit {
+        TimeUnit::Second => 1_000_000_000,
+        TimeUnit::Millisecond => 1_000_000,
+        TimeUnit::Microsecond => 1_000,
+        TimeUnit::Nanosecond => 1,
+    }
+}
+
+/// Creates an encoder for the timestamps of `array`, in `from` units, as integers in `to`
+/// units since the epoch, rounding towards negative infinity
+fn epoch_encoder(
+    array: &dyn Array,
+    from: TimeUnit,
+    to: TimeUnit,
+) -> Result<PrimitiveEncoder<i64>, ArrowError> {
+    let (from_nanos, to_nanos) = (nanos_per(from), nanos_per(to));
+    let values = arrow_cast::cast(array, &DataType::Int64)?;
+    let values = values.as_primitive::<Int64Type>();
+    let values = if from_nanos >= to_nanos {
+        let multiplier = from_nanos / to_nanos;
+        values
+            .iter()
+            .map(|v| match v {
+                Some(v) => v.checked_mul(multiplier).ok_or_else(|| {
+                    ArrowError::JsonError(format!(
+                        "Overflow encoding timestamp {v} as {to:?} since the epoch"
+                    ))
+                }),
+                None => Ok(0),
+            })
+            .collect::<Result<Vec<_>, _>>()?
+    } else {
+        let divisor = to_nanos / from_nanos;
+        values
+            .values()
+            .iter()
+            .map(|v| v.div_euclid(divisor))
+            .collect()
+    };
+    Ok(PrimitiveEncoder {
+        values: values.into(),
+        buffer: i64::init_buffer(),
+    })
+}
+
 struct FieldEncoder<'a> {
     field: FieldRef,
     encoder: NullableEncoder<'a>,
@@ -639,11 +758,11 @@ impl Encoder for StringViewEncoder<'_> {
     }
 }
 
-struct BinaryViewEncoder<'a>(&'a BinaryViewArray);
+struct BinaryViewEncoder<'a>(&'a BinaryViewArray, BinaryEncoding);
 
 impl Encoder for BinaryViewEncoder<'_> {
     fn encode(&mut self, idx: usize, out: &mut Vec<u8>) {
-        encode_binary(self.0.value(idx), out);
+        encode_binary(self.0.value(idx), self.1, out);
     }
 }
 
@@ -870,15 +989,15 @@ impl Encoder for MapEncoder<'_> {
 }
 
 /// New-type wrapper for encoding the binary types in arrow: `Binary`, `LargeBinary`
-/// and `FixedSizeBinary` as hex strings in JSON.
-struct BinaryEncoder<B>(B);
+/// and `FixedSizeBinary` as hex or base64 strings in JSON.
+struct BinaryEncoder<B>(B, BinaryEncoding);
 
 impl<'a, B> BinaryEncoder<B>
 where
     B: ArrayAccessor<Item = &'a [u8]>,
 {
-    fn new(array: B) -> Self {
-        Self(array)
+    fn new(array: B, encoding: BinaryEncoding) -> Self {
+        Self(array, encoding)
     }
 }
 
@@ -887,11 +1006,6 @@ where
     B: ArrayAccessor<Item = &'a [u8]>,
 {
     fn encode(&mut self, idx: usize, out: &mut Vec<u8>) {
-        out.push(b'"');
-        for byte in self.0.value(idx) {
-            // this write is infallible
-            write!(out, "{byte:02x}").unwrap();
-        }
-        out.push(b'"');
+        encode_binary(self.0.value(idx), self.1, out);
     }
 }
@@ -112,7 +112,10 @@ use crate::StructMode;
 use arrow_array::*;
 use arrow_schema::*;
 
-pub use encoder::{Encoder, EncoderFactory, EncoderOptions, NullableEncoder, make_encoder};
+pub use encoder::{
+    BinaryEncoding, Encoder, EncoderFactory, EncoderOptions, NullableEncoder, TimestampEncoding,
+    make_encoder,
+};
 
 /// This trait defines how to format a sequence of JSON objects to a
 /// byte stream.
@@ -309,6 +312,37 @@ impl WriterBuilder {
         self
     }
 
+    /// Set how binary arrays are encoded, defaults to [`BinaryEncoding::Hex`]
+    ///
+    /// Binary values, which may not be valid UTF-8, are always encoded as JSON strings.
+    pub fn with_binary_encoding(mut self, binary_encoding: BinaryEncoding) -> Self {
+        self.0 = self.0.with_binary_encoding(binary_encoding);
+        self
+    }
+
+    /// Set how timestamp arrays are encoded, defaults to [`TimestampEncoding::Formatted`]
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{RecordBatch, TimestampMicrosecondArray};
+    /// # use arrow_json::writer::{LineDelimited, TimestampEncoding, WriterBuilder};
+    /// # use arrow_schema::TimeUnit;
+    /// let ts = TimestampMicrosecondArray::from(vec![1_700_000_000_123_456]).with_timezone("+02:00");
+    /// let batch = RecordBatch::try_from_iter([("ts", Arc::new(ts) as _)]).unwrap();
+    ///
+    /// let mut buf = vec![];
+    /// let mut writer = WriterBuilder::new()
+    ///     .with_timestamp_encoding(TimestampEncoding::Epoch(TimeUnit::Millisecond))
+    ///     .build::<_, LineDelimited>(&mut buf);
+    /// writer.write(&batch).unwrap();
+    /// writer.finish().unwrap();
+    /// assert_eq!(String::from_utf8(buf).unwrap(), "{\"ts\":1700000000123}\n");
+    /// ```
+    pub fn with_timestamp_encoding(mut self, timestamp_encoding: TimestampEncoding) -> Self {
+        self.0 = self.0.with_timestamp_encoding(timestamp_encoding);
+        self
+    }
+
     /// Create a new `Writer` with specified `JsonFormat` and builder options.
     pub fn build<W, F>(self, writer: W) -> Writer<W, F>
     where
@@ -1889,6 +1923,66 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_writer_binary_base64() {
+        let values: [Option<&[u8]>; 4] = [Some(b""), Some(b"a"), Some(b"\xff\x00\x10\x80"), None];
+        for batch in [
+            build_array_binary::<i32>(&values),
+            build_array_binary::<i64>(&values),
+            build_array_binary_view(&values),
+        ] {
+            let mut buf = Vec::new();
+            let mut writer = WriterBuilder::new()
+                .with_binary_encoding(BinaryEncoding::Base64)
+                .build::<_, LineDelimited>(&mut buf);
+            writer.write(&batch).unwrap();
+            writer.finish().unwrap();
+            assert_eq!(
+                String::from_utf8(buf).unwrap(),
+                "{\"bytes\":\"\"}\n{\"bytes\":\"YQ==\"}\n{\"bytes\":\"/wAQgA==\"}\n{}\n"
+            );
+        }
+    }
+
+    #[test]
+    fn test_writer_timestamp_epoch() {
+        let ts = TimestampMillisecondArray::from(vec![Some(-1), None, Some(1_500)]);
+        let batch = RecordBatch::try_from_iter([("ts", Arc::new(ts) as ArrayRef)]).unwrap();
+
+        let write = |unit| {
+            let mut buf = Vec::new();
+            let mut writer = WriterBuilder::new()
+                .with_explicit_nulls(true)
+                .with_timestamp_encoding(TimestampEncoding::Epoch(unit))
+                .build::<_, LineDelimited>(&mut buf);
+            writer.write(&batch)?;
+            writer.finish()?;
+            Ok::<_, ArrowError>(String::from_utf8(buf).unwrap())
+        };
+        assert_eq!(
+            write(TimeUnit::Second).unwrap(),
+            "{\"ts\":-1}\n{\"ts\":null}\n{\"ts\":1}\n"
+        );
+        assert_eq!(
+            write(TimeUnit::Microsecond).unwrap(),
+            "{\"ts\":-1000}\n{\"ts\":null}\n{\"ts\":1500000}\n"
+        );
+
+        let ts = TimestampSecondArray::from(vec![i64::MAX]);
+        let batch = RecordBatch::try_from_iter([("ts", Arc::new(ts) as ArrayRef)]).unwrap();
+        let mut writer = WriterBuilder::new()
+            .with_timestamp_encoding(TimestampEncoding::Epoch(TimeUnit::Nanosecond))
+            .build::<_, LineDelimited>(vec![]);
+        let err = writer.write(&batch).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Json error: Overflow encoding timestamp {} as Nanosecond since the epoch",
+                i64::MAX
+            )
+        );
+    }
+
     #[test]
     fn test_writer_fixed_size_binary() {
         // set up schema:
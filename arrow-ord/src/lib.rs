@@ -52,6 +52,7 @@
 pub mod cmp;
 #[doc(hidden)]
 pub mod comparison;
+pub mod map;
 pub mod ord;
 pub mod partition;
 pub mod rank;
@@ -0,0 +1,231 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Kernels for accessing the keys, values and entries of a [`MapArray`]
+
+use std::sync::Arc;
+
+use arrow_array::{Array, ArrayRef, Datum, ListArray, MapArray, UInt32Array};
+use arrow_schema::{ArrowError, DataType, Field};
+use arrow_select::take::take;
+
+use crate::cmp::eq;
+
+/// Returns the keys of each map in `map` as a [`ListArray`]
+///
+/// The result has the same offsets and nulls as `map`, and the keys are not copied
+pub fn map_keys(map: &MapArray) -> ListArray {
+    let field = Field::new_list_field(map.key_type().clone(), false);
+    ListArray::new(
+        Arc::new(field),
+        map.offsets().clone(),
+        Arc::clone(map.keys()),
+        map.nulls().cloned(),
+    )
+}
+
+/// Returns the values of each map in `map` as a [`ListArray`]
+///
+/// The result has the same offsets and nulls as `map`, and the values are not copied
+pub fn map_values(map: &MapArray) -> ListArray {
+    let nullable = map.entries_fields().1.is_nullable();
+    let field = Field::new_list_field(map.value_type().clone(), nullable);
+    ListArray::new(
+        Arc::new(field),
+        map.offsets().clone(),
+        Arc::clone(map.values()),
+        map.nulls().cloned(),
+    )
+}
+
+/// Returns the entries of each map in `map` as a [`ListArray`] of [`StructArray`]
+/// with a key and a value field
+///
+/// The result has the same offsets and nulls as `map`, and the entries are not copied
+///
+/// [`StructArray`]: arrow_array::StructArray
+pub fn map_entries(map: &MapArray) -> ListArray {
+    let field = match map.data_type() {
+        DataType::Map(field, _) => Arc::clone(field),
+        _ => unreachable!("MapArray must have Map data type"),
+    };
+    ListArray::new(
+        field,
+        map.offsets().clone(),
+        Arc::new(map.entries().clone()),
+        map.nulls().cloned(),
+    )
+}
+
+/// Returns the value of the first entry of each map in `map` whose key is equal to
+/// `key`, which is either a scalar or an array of the same length as `map`
+///
+/// The result is null where the map is null, the key is null, or the map contains
+/// no entry with the key, as well as where the value of the matching entry is null.
+///
+/// ```
+/// # use arrow_array::{Array, Int32Array, Scalar, StringArray};
+/// # use arrow_array::builder::{Int32Builder, MapBuilder, StringBuilder};
+/// # use arrow_ord::map::element_at;
+/// let mut builder = MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+/// builder.keys().append_value("a");
+/// builder.values().append_value(1);
+/// builder.keys().append_value("b");
+/// builder.values().append_value(2);
+/// builder.append(true).unwrap();
+/// builder.keys().append_value("b");
+/// builder.values().append_value(3);
+/// builder.append(true).unwrap();
+/// builder.append(false).unwrap();
+/// let map = builder.finish();
+///
+/// let key = Scalar::new(StringArray::from(vec!["a"]));
+/// let values = element_at(&map, &key).unwrap();
+/// assert_eq!(values.as_ref(), &Int32Array::from(vec![Some(1), None, None]));
+///
+/// let keys = StringArray::from(vec![Some("b"), Some("b"), Some("b")]);
+/// let values = element_at(&map, &keys).unwrap();
+/// assert_eq!(values.as_ref(), &Int32Array::from(vec![Some(2), Some(3), None]));
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if `key` is an array of a different length to `map`, or the keys
+/// of `map` cannot be compared with `key`
+pub fn element_at(map: &MapArray, key: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    let (key_array, is_scalar) = key.get();
+    if !is_scalar && key_array.len() != map.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Cannot lookup {} keys in a map array of length {}",
+            key_array.len(),
+            map.len()
+        )));
+    }
+
+    let offsets = map.value_offsets();
+    let start = offsets[0] as usize;
+    let keys = map.keys().slice(start, offsets[map.len()] as usize - start);
+    let matches = match is_scalar {
+        true => eq(&keys, key)?,
+        false => {
+            // Repeat the key of each row for each of its entries
+            let rows: UInt32Array = (0..map.len())
+                .flat_map(|i| std::iter::repeat_n(i as u32, map.value_length(i) as usize))
+                .collect();
+            eq(&keys, &take(key_array, &rows, None)?)?
+        }
+    };
+
+    let indices: UInt32Array = offsets
+        .windows(2)
+        .enumerate()
+        .map(|(i, w)| {
+            if map.is_null(i) {
+                return None;
+            }
+            (w[0] as usize..w[1] as usize)
+                .find(|j| matches.is_valid(j - start) && matches.value(j - start))
+                .map(|j| j as u32)
+        })
+        .collect();
+    take(map.values(), &indices, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::builder::{Int32Builder, MapBuilder, StringBuilder};
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int32Type;
+    use arrow_array::{Int32Array, Scalar, StringArray};
+
+    fn test_map() -> MapArray {
+        let mut builder = MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+        for (entries, valid) in [
+            (vec![("a", Some(1)), ("b", None)], true),
+            (vec![], false),
+            (vec![("c", Some(3)), ("a", Some(4)), ("a", Some(5))], true),
+            (vec![], true),
+        ] {
+            for (k, v) in entries {
+                builder.keys().append_value(k);
+                builder.values().append_option(v);
+            }
+            builder.append(valid).unwrap();
+        }
+        builder.finish()
+    }
+
+    #[test]
+    fn test_map_keys_values_entries() {
+        let map = test_map().slice(1, 3);
+
+        let keys = map_keys(&map);
+        assert_eq!(keys.len(), 3);
+        assert!(keys.is_null(0));
+        assert_eq!(
+            keys.value(1).as_ref(),
+            &StringArray::from(vec!["c", "a", "a"])
+        );
+        assert!(keys.value(2).is_empty());
+
+        let values = map_values(&map);
+        assert!(values.is_null(0));
+        assert_eq!(
+            values.value(1).as_primitive::<Int32Type>(),
+            &Int32Array::from(vec![3, 4, 5])
+        );
+
+        let entries = map_entries(&map);
+        assert!(entries.is_null(0));
+        assert_eq!(entries.value(1).as_struct(), &map.value(1));
+        assert_eq!(entries.value_type(), map.entries().data_type().clone());
+    }
+
+    #[test]
+    fn test_element_at() {
+        let map = test_map();
+
+        let key = Scalar::new(StringArray::from(vec!["a"]));
+        let result = element_at(&map, &key).unwrap();
+        let expected = Int32Array::from(vec![Some(1), None, Some(4), None]);
+        assert_eq!(result.as_primitive::<Int32Type>(), &expected);
+
+        let result = element_at(&map.slice(2, 2), &key).unwrap();
+        assert_eq!(result.as_primitive::<Int32Type>(), &expected.slice(2, 2));
+
+        // Null values, null keys and missing keys all produce nulls
+        let keys = StringArray::from(vec![Some("b"), Some("a"), None, Some("c")]);
+        let result = element_at(&map, &keys).unwrap();
+        let expected = Int32Array::from(vec![None, None, None, None]);
+        assert_eq!(result.as_primitive::<Int32Type>(), &expected);
+
+        let keys = StringArray::from(vec!["z", "z", "c", "z"]);
+        let result = element_at(&map, &keys).unwrap();
+        let expected = Int32Array::from(vec![None, None, Some(3), None]);
+        assert_eq!(result.as_primitive::<Int32Type>(), &expected);
+
+        let err = element_at(&map, &StringArray::from(vec!["a"])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot lookup 1 keys in a map array of length 4"
+        );
+
+        let key = Scalar::new(Int32Array::from(vec![1]));
+        element_at(&map, &key).unwrap_err();
+    }
+}
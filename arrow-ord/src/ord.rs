@@ -177,11 +177,22 @@ fn compare_dict<K: ArrowDictionaryKeyType>(
     let left = left.as_dictionary::<K>();
     let right = right.as_dictionary::<K>();
 
-    let c_opts = child_opts(opts);
-    let cmp = make_comparator(left.values().as_ref(), right.values().as_ref(), c_opts)?;
     let left_keys = left.keys().values().clone();
     let right_keys = right.keys().values().clone();
 
+    // Keys of ordered dictionaries sharing the same values order the same as the values
+    if left.is_ordered()
+        && right.is_ordered()
+        && left.values().to_data().ptr_eq(&right.values().to_data())
+    {
+        return Ok(compare(left, right, opts, move |i, j| {
+            left_keys[i].as_usize().cmp(&right_keys[j].as_usize())
+        }));
+    }
+
+    let c_opts = child_opts(opts);
+    let cmp = make_comparator(left.values().as_ref(), right.values().as_ref(), c_opts)?;
+
     let f = compare(left, right, opts, move |i, j| {
         let l = left_keys[i].as_usize();
         let r = right_keys[j].as_usize();
@@ -1830,6 +1841,31 @@ mod tests {
         assert_eq!(cmp(3, 3), Ordering::Greater);
     }
 
+    #[test]
+    fn test_ordered_dict() {
+        let values: ArrayRef = Arc::new(StringArray::from(vec!["a", "b", "c"]));
+        let keys = Int32Array::from(vec![Some(2), None, Some(0), Some(1)]);
+        let array1 = DictionaryArray::new(keys, values.clone()).with_ordered(true);
+        let keys = Int32Array::from(vec![Some(1), Some(0), None, Some(1)]);
+        let array2 = DictionaryArray::new(keys, values).with_ordered(true);
+
+        let cmp = make_comparator(&array1, &array2, SortOptions::default()).unwrap();
+        assert_eq!(Ordering::Greater, cmp(0, 0));
+        assert_eq!(Ordering::Less, cmp(1, 0));
+        assert_eq!(Ordering::Equal, cmp(1, 2));
+        assert_eq!(Ordering::Less, cmp(2, 0));
+        assert_eq!(Ordering::Equal, cmp(3, 3));
+
+        let opts = SortOptions {
+            descending: true,
+            nulls_first: false,
+        };
+        let cmp = make_comparator(&array1, &array2, opts).unwrap();
+        assert_eq!(Ordering::Less, cmp(0, 0));
+        assert_eq!(Ordering::Greater, cmp(1, 0));
+        assert_eq!(Ordering::Greater, cmp(2, 0));
+    }
+
     #[test]
     fn test_run_end_encoded_different_types() {
         // Test with different run end types - should fail
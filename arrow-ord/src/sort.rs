@@ -551,6 +551,14 @@ fn sort_dictionary<K: ArrowDictionaryKeyType>(
     limit: Option<usize>,
 ) -> Result<UInt32Array, ArrowError> {
     let keys: &PrimitiveArray<K> = dict.keys();
+    if dict.is_ordered() {
+        // Keys order the same as the values they reference
+        let mut valids = value_indices
+            .into_iter()
+            .map(|index| (index, keys.value(index as usize).as_usize()))
+            .collect::<Vec<(u32, usize)>>();
+        return Ok(sort_impl(options, &mut valids, &null_indices, limit, |a, b| a.cmp(&b)).into());
+    }
     let rank = child_rank(dict.values().as_ref(), options)?;
 
     // create tuples that are used for sorting
@@ -565,6 +573,77 @@ fn sort_dictionary<K: ArrowDictionaryKeyType>(
     Ok(sort_impl(options, &mut valids, &null_indices, limit, |a, b| a.cmp(&b)).into())
 }
 
+/// Returns `true` if the values of `dict` are strictly increasing and contain no nulls,
+/// and so may be marked as ordered with [`DictionaryArray::with_ordered`]
+pub fn is_dictionary_ordered<K: ArrowDictionaryKeyType>(
+    dict: &DictionaryArray<K>,
+) -> Result<bool, ArrowError> {
+    let values = dict.values().as_ref();
+    if values.logical_null_count() != 0 {
+        return Ok(false);
+    }
+    let cmp = make_comparator(values, values, SortOptions::default())?;
+    Ok((1..values.len()).all(|i| cmp(i - 1, i).is_lt()))
+}
+
+/// Returns a dictionary equivalent to `dict` whose values are ordered
+///
+/// The values are sorted and deduplicated, with keys remapped accordingly and keys
+/// referencing null values replaced by null keys. The returned dictionary is marked
+/// as ordered, allowing [`sort_to_indices`] and [`make_comparator`] to compare its keys
+/// directly.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{DictionaryArray, StringArray, Int8Array, types::Int8Type};
+/// # use arrow_ord::sort::{order_dictionary, sort_to_indices};
+/// let values = StringArray::from(vec!["c", "a", "b", "a"]);
+/// let keys = Int8Array::from(vec![0, 3, 2]);
+/// let dict = DictionaryArray::<Int8Type>::new(keys, Arc::new(values));
+///
+/// let ordered = order_dictionary(&dict).unwrap();
+/// assert!(ordered.is_ordered());
+/// assert_eq!(ordered.keys(), &Int8Array::from(vec![2, 0, 1]));
+///
+/// let indices = sort_to_indices(&ordered, None, None).unwrap();
+/// assert_eq!(indices.values(), &[1, 2, 0]);
+/// ```
+pub fn order_dictionary<K: ArrowDictionaryKeyType>(
+    dict: &DictionaryArray<K>,
+) -> Result<DictionaryArray<K>, ArrowError> {
+    if is_dictionary_ordered(dict)? {
+        return Ok(dict.clone().with_ordered(true));
+    }
+
+    let values = dict.values().as_ref();
+    let options = SortOptions {
+        descending: false,
+        nulls_first: false,
+    };
+    let sorted = sort_to_indices(values, Some(options), None)?;
+    let valid = values.len() - values.logical_null_count();
+    let cmp = make_comparator(values, values, options)?;
+
+    // The new key of each value, or `None` for null values
+    let mut remap = vec![None; values.len()];
+    let mut take_indices = Vec::with_capacity(valid);
+    for (idx, &i) in sorted.values()[..valid].iter().enumerate() {
+        let i = i as usize;
+        if idx == 0 || cmp(sorted.value(idx - 1) as usize, i).is_ne() {
+            take_indices.push(i as u32);
+        }
+        remap[i] = Some(K::Native::usize_as(take_indices.len() - 1));
+    }
+
+    let keys: PrimitiveArray<K> = dict
+        .keys()
+        .iter()
+        .map(|k| k.and_then(|k| remap[k.as_usize()]))
+        .collect();
+    let values = take(values, &UInt32Array::from(take_indices), None)?;
+    Ok(DictionaryArray::try_new(keys, values)?.with_ordered(true))
+}
+
 fn sort_list<O: OffsetSizeTrait>(
     array: &GenericListArray<O>,
     value_indices: Vec<u32>,
@@ -5284,4 +5363,69 @@ mod tests {
         assert_eq!(sorted_strings, expected);
         assert_eq!(sorted_strings.len(), limit);
     }
+
+    #[test]
+    fn test_sort_ordered_dictionary() {
+        let values = StringArray::from(vec![Some("d"), None, Some("b"), Some("a"), Some("b")]);
+        let keys = Int32Array::from(vec![
+            Some(0),
+            Some(1),
+            None,
+            Some(2),
+            Some(3),
+            Some(4),
+            Some(0),
+            Some(3),
+        ]);
+        let dict = DictionaryArray::<Int32Type>::new(keys, Arc::new(values));
+        assert!(!is_dictionary_ordered(&dict).unwrap());
+
+        let ordered = order_dictionary(&dict).unwrap();
+        assert!(ordered.is_ordered());
+        assert!(is_dictionary_ordered(&ordered).unwrap());
+        assert_eq!(
+            ordered.values().as_ref(),
+            &StringArray::from(vec!["a", "b", "d"])
+        );
+        let expected_keys = Int32Array::from(vec![
+            Some(2),
+            None,
+            None,
+            Some(1),
+            Some(0),
+            Some(1),
+            Some(2),
+            Some(0),
+        ]);
+        assert_eq!(ordered.keys(), &expected_keys);
+
+        // Ordering an ordered dictionary is a no-op
+        let again = order_dictionary(&ordered).unwrap();
+        assert_eq!(again.keys(), ordered.keys());
+
+        let sorted_values = |array: &dyn Array, options, limit| {
+            let indices = sort_to_indices(array, options, limit).unwrap();
+            let taken = take(array, &indices, None).unwrap();
+            let taken = taken.as_dictionary::<Int32Type>();
+            let strings = taken.downcast_dict::<StringArray>().unwrap();
+            strings
+                .into_iter()
+                .map(|s| s.map(str::to_string))
+                .collect::<Vec<_>>()
+        };
+        for descending in [false, true] {
+            for nulls_first in [false, true] {
+                let options = Some(SortOptions {
+                    descending,
+                    nulls_first,
+                });
+                for limit in [None, Some(3)] {
+                    assert_eq!(
+                        sorted_values(&ordered, options, limit),
+                        sorted_values(&dict, options, limit)
+                    );
+                }
+            }
+        }
+    }
 }
@@ -122,6 +122,10 @@ if PYARROW_MAJOR_VER >= 16:
             pa.large_list_view(pa.uint64()),
             pa.list_view(pa.string()),
             pa.large_list_view(pa.string()),
+            pa.string_view(),
+            pa.binary_view(),
+            pa.run_end_encoded(pa.int32(), pa.string()),
+            pa.run_end_encoded(pa.int64(), pa.list_view(pa.int32())),
         ]
     )
 
@@ -379,6 +383,44 @@ def test_list_view_array():
     del b
 
 
+@pytest.mark.skipif(PYARROW_PRE_16, reason="requires pyarrow 16")
+@pytest.mark.parametrize("datatype", [pa.string_view(), pa.binary_view()])
+def test_byte_view_array(datatype):
+    """
+    Python -> Rust -> Python
+    """
+    # Values longer than 12 bytes are stored in variadic buffers, of which
+    # concatenating chunks yields several
+    values = [None, "inlined", "a value longer than twelve bytes", ""]
+    chunks = [pa.array([f"{v} {i}" if v else v for v in values], datatype) for i in range(3)]
+    a = pa.concat_arrays(chunks)
+    for array in [a, a.slice(3, 6)]:
+        b = rust.round_trip_array(array)
+        b.validate(full=True)
+        assert array.to_pylist() == b.to_pylist()
+        assert array.type == b.type
+    del a
+    del b
+
+
+@pytest.mark.skipif(PYARROW_PRE_16, reason="requires pyarrow 16")
+def test_run_end_encoded_array():
+    """
+    Python -> Rust -> Python
+    """
+    a = pa.RunEndEncodedArray.from_arrays(
+        pa.array([2, 3, 6], pa.int32()),
+        pa.array(["a", None, "b"]),
+    )
+    for array in [a, a.slice(1, 4)]:
+        b = rust.round_trip_array(array)
+        b.validate(full=True)
+        assert array.to_pylist() == b.to_pylist()
+        assert array.type == b.type
+    del a
+    del b
+
+
 def test_map_array():
     """
     Python -> Rust -> Python
@@ -18,7 +18,7 @@
 use crate::array::PrimitiveArray;
 use crate::null_sentinel;
 use arrow_array::builder::BufferBuilder;
-use arrow_array::{ArrowPrimitiveType, BooleanArray, FixedSizeBinaryArray};
+use arrow_array::{Array, ArrowPrimitiveType, BooleanArray, FixedSizeBinaryArray};
 use arrow_buffer::{
     ArrowNativeType, BooleanBuffer, Buffer, IntervalDayTime, IntervalMonthDayNano, MutableBuffer,
     NullBuffer, bit_util, i256,
@@ -323,6 +323,33 @@ pub fn encode_boolean_not_null(
     }
 }
 
+/// Encodes the values at the provided indices of a fixed width array, with `value`
+/// returning the value at an index
+pub fn encode_indices<'a, T: FixedLengthEncoding>(
+    data: &mut [u8],
+    offsets: impl Iterator<Item = (&'a mut usize, usize)>,
+    nulls: Option<&NullBuffer>,
+    value: impl Fn(usize) -> T,
+    opts: SortOptions,
+) {
+    for (offset, idx) in offsets {
+        let end_offset = *offset + T::ENCODED_LEN;
+        if nulls.is_none_or(|n| n.is_valid(idx)) {
+            let to_write = &mut data[*offset..end_offset];
+            to_write[0] = 1;
+            let mut encoded = value(idx).encode();
+            if opts.descending {
+                // Flip bits to reverse order
+                encoded.as_mut().iter_mut().for_each(|v| *v = !*v)
+            }
+            to_write[1..].copy_from_slice(encoded.as_ref())
+        } else {
+            data[*offset] = null_sentinel(opts);
+        }
+        *offset = end_offset;
+    }
+}
+
 pub fn encode_fixed_size_binary(
     data: &mut [u8],
     offsets: &mut [usize],
@@ -347,6 +374,31 @@ pub fn encode_fixed_size_binary(
     }
 }
 
+/// Encodes the values at the provided indices of a [`FixedSizeBinaryArray`]
+pub fn encode_fixed_size_binary_indices<'a>(
+    data: &mut [u8],
+    offsets: impl Iterator<Item = (&'a mut usize, usize)>,
+    array: &FixedSizeBinaryArray,
+    opts: SortOptions,
+) {
+    let len = array.value_length() as usize;
+    for (offset, idx) in offsets {
+        let end_offset = *offset + len + 1;
+        if array.is_valid(idx) {
+            let to_write = &mut data[*offset..end_offset];
+            to_write[0] = 1;
+            to_write[1..].copy_from_slice(array.value(idx));
+            if opts.descending {
+                // Flip bits to reverse order
+                to_write[1..].iter_mut().for_each(|v| *v = !*v)
+            }
+        } else {
+            data[*offset] = null_sentinel(opts);
+        }
+        *offset = end_offset;
+    }
+}
+
 /// Splits `len` bytes from `src`
 #[inline]
 fn split_off<'a>(src: &mut &'a [u8], len: usize) -> &'a [u8] {
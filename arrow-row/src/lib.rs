@@ -959,6 +959,137 @@ impl RowConverter {
             "rows were not produced by this RowConverter"
         );
 
+        let encoders = self.encoders(columns)?;
+        let write_offset = rows.num_rows();
+        let lengths = row_lengths(columns, &encoders);
+        let total = lengths.extend_offsets(rows.offsets[write_offset], &mut rows.offsets);
+        rows.buffer.resize(total, 0);
+
+        for ((column, field), encoder) in columns.iter().zip(self.fields.iter()).zip(encoders) {
+            // We encode a column at a time to minimise dispatch overheads
+            encode_column(
+                &mut rows.buffer,
+                &mut rows.offsets[write_offset..],
+                column.as_ref(),
+                field.options,
+                &encoder,
+            )
+        }
+
+        if cfg!(debug_assertions) {
+            assert_eq!(*rows.offsets.last().unwrap(), rows.buffer.len());
+            rows.offsets
+                .windows(2)
+                .for_each(|w| assert!(w[0] <= w[1], "offsets should be monotonic"));
+        }
+
+        Ok(())
+    }
+
+    /// Convert the rows at `indices` of [`ArrayRef`] columns appending to an existing [`Rows`]
+    ///
+    /// This is equivalent to [`Self::append`] with the columns taken at `indices`, but only
+    /// encodes the selected rows, for example those passing a filter or the top rows of a
+    /// sort. Null indices are not supported.
+    ///
+    /// # Panics
+    ///
+    /// Panics if
+    /// * The schema of `columns` does not match that provided to [`RowConverter::new`]
+    /// * The provided [`Rows`] were not created by this [`RowConverter`]
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::cast::AsArray;
+    /// # use arrow_array::{StringArray, UInt32Array};
+    /// # use arrow_row::{RowConverter, SortField};
+    /// # use arrow_schema::DataType;
+    /// #
+    /// let converter = RowConverter::new(vec![SortField::new(DataType::Utf8)]).unwrap();
+    /// let array = StringArray::from(vec!["a", "b", "c", "d"]);
+    ///
+    /// let mut rows = converter.empty_rows(2, 16);
+    /// let indices = UInt32Array::from(vec![3, 1]);
+    /// converter.append_rows_into(&mut rows, &[Arc::new(array)], &indices).unwrap();
+    ///
+    /// let back = converter.convert_rows(&rows).unwrap();
+    /// let values: Vec<_> = back[0].as_string::<i32>().iter().map(Option::unwrap).collect();
+    /// assert_eq!(&values, &["d", "b"]);
+    /// ```
+    pub fn append_rows_into(
+        &self,
+        rows: &mut Rows,
+        columns: &[ArrayRef],
+        indices: &UInt32Array,
+    ) -> Result<(), ArrowError> {
+        if indices.null_count() > 0 {
+            return Err(ArrowError::InvalidArgumentError(
+                "RowConverter indices must not contain nulls".to_string(),
+            ));
+        }
+        assert!(
+            Arc::ptr_eq(&rows.config.fields, &self.fields),
+            "rows were not produced by this RowConverter"
+        );
+
+        let encoders = self.encoders(columns)?;
+        let num_rows = columns.first().map(|x| x.len()).unwrap_or(0);
+        let indices = indices.values();
+        if let Some(idx) = indices.iter().find(|idx| idx.as_usize() >= num_rows) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "RowConverter index out of bounds: {idx} >= {num_rows}"
+            )));
+        }
+
+        let write_offset = rows.num_rows();
+        let lengths = row_lengths(columns, &encoders).take(indices);
+        let total = lengths.extend_offsets(rows.offsets[write_offset], &mut rows.offsets);
+        rows.buffer.resize(total, 0);
+
+        for ((column, field), encoder) in columns.iter().zip(self.fields.iter()).zip(encoders) {
+            encode_column_indices(
+                &mut rows.buffer,
+                &mut rows.offsets[write_offset..],
+                column.as_ref(),
+                field.options,
+                &encoder,
+                indices,
+            )
+        }
+
+        Ok(())
+    }
+
+    /// Returns the number of bytes by which converting `columns` with [`Self::append`]
+    /// grows [`Rows`], including the row offsets
+    ///
+    /// This can be used to reserve memory before converting columns, for example to
+    /// enforce a memory budget. As the rows of nested and dictionary columns are computed
+    /// to determine their lengths, this is relatively expensive for such columns.
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::StringArray;
+    /// # use arrow_row::{RowConverter, SortField};
+    /// # use arrow_schema::DataType;
+    /// #
+    /// let converter = RowConverter::new(vec![SortField::new(DataType::Utf8)]).unwrap();
+    /// let columns = [Arc::new(StringArray::from(vec!["hello", "world"])) as _];
+    ///
+    /// let size = converter.estimate_size(&columns).unwrap();
+    /// let rows = converter.convert_columns(&columns).unwrap();
+    /// let data_len: usize = rows.lengths().sum();
+    /// assert_eq!(size, data_len + 2 * std::mem::size_of::<usize>());
+    /// ```
+    pub fn estimate_size(&self, columns: &[ArrayRef]) -> Result<usize, ArrowError> {
+        let encoders = self.encoders(columns)?;
+        let num_rows = columns.first().map(|x| x.len()).unwrap_or(0);
+        let data_len = row_lengths(columns, &encoders).total();
+        Ok(data_len + num_rows * std::mem::size_of::<usize>())
+    }
+
+    /// Validates `columns` against the fields of this converter, returning their encoders
+    fn encoders<'a>(&'a self, columns: &'a [ArrayRef]) -> Result<Vec<Encoder<'a>>, ArrowError> {
         if columns.len() != self.fields.len() {
             return Err(ArrowError::InvalidArgumentError(format!(
                 "Incorrect number of arrays provided to RowConverter, expected {} got {}",
@@ -976,7 +1107,7 @@ impl RowConverter {
             }
         }
 
-        let encoders = columns
+        columns
             .iter()
             .zip(&self.codecs)
             .zip(self.fields.iter())
@@ -990,32 +1121,7 @@ impl RowConverter {
                 }
                 codec.encoder(column.as_ref())
             })
-            .collect::<Result<Vec<_>, _>>()?;
-
-        let write_offset = rows.num_rows();
-        let lengths = row_lengths(columns, &encoders);
-        let total = lengths.extend_offsets(rows.offsets[write_offset], &mut rows.offsets);
-        rows.buffer.resize(total, 0);
-
-        for ((column, field), encoder) in columns.iter().zip(self.fields.iter()).zip(encoders) {
-            // We encode a column at a time to minimise dispatch overheads
-            encode_column(
-                &mut rows.buffer,
-                &mut rows.offsets[write_offset..],
-                column.as_ref(),
-                field.options,
-                &encoder,
-            )
-        }
-
-        if cfg!(debug_assertions) {
-            assert_eq!(*rows.offsets.last().unwrap(), rows.buffer.len());
-            rows.offsets
-                .windows(2)
-                .for_each(|w| assert!(w[0] <= w[1], "offsets should be monotonic"));
-        }
-
-        Ok(())
+            .collect()
     }
 
     /// Convert [`Rows`] columns into [`ArrayRef`]
@@ -1628,6 +1734,34 @@ impl LengthTracker {
         }
     }
 
+    /// Returns the sum of the tracked row lengths
+    fn total(&self) -> usize {
+        match self {
+            LengthTracker::Fixed { length, num_rows } => length * num_rows,
+            LengthTracker::Variable {
+                fixed_length,
+                lengths,
+            } => lengths.iter().sum::<usize>() + fixed_length * lengths.len(),
+        }
+    }
+
+    /// Returns the tracked lengths of the rows at `indices`
+    fn take(&self, indices: &[u32]) -> Self {
+        match self {
+            LengthTracker::Fixed { length, .. } => LengthTracker::Fixed {
+                length: *length,
+                num_rows: indices.len(),
+            },
+            LengthTracker::Variable {
+                fixed_length,
+                lengths,
+            } => LengthTracker::Variable {
+                fixed_length: *fixed_length,
+                lengths: indices.iter().map(|idx| lengths[idx.as_usize()]).collect(),
+            },
+        }
+    }
+
     /// Initializes the offsets using the tracked lengths. Returns the sum of the
     /// lengths of the rows added.
     ///
@@ -2056,6 +2190,174 @@ fn encode_column(
     }
 }
 
+/// Encodes the rows at `indices` of a column to the provided [`Rows`], incrementing the
+/// offsets as it progresses
+///
+/// This is equivalent to [`encode_column`] on the result of taking `indices` from `column`,
+/// without materializing the taken column
+fn encode_column_indices(
+    data: &mut [u8],
+    offsets: &mut [usize],
+    column: &dyn Array,
+    opts: SortOptions,
+    encoder: &Encoder<'_>,
+    indices: &[u32],
+) {
+    let offsets = offsets
+        .iter_mut()
+        .skip(1)
+        .zip(indices.iter().map(|x| x.as_usize()));
+    match encoder {
+        Encoder::Stateless => {
+            downcast_primitive_array! {
+                column => {
+                    let values = column.values();
+                    fixed::encode_indices(data, offsets, column.nulls(), |idx| values[idx], opts)
+                }
+                DataType::Null => {
+                    for (offset, _) in offsets {
+                        variable::encode_null_value(&mut data[*offset..], opts);
+                        *offset += 2;
+                    }
+                }
+                DataType::Boolean => {
+                    let values = column.as_boolean().values();
+                    fixed::encode_indices(data, offsets, column.nulls(), |idx| values.value(idx), opts)
+                }
+                DataType::Binary => encode_bytes_indices(data, offsets, as_generic_binary_array::<i32>(column), opts),
+                DataType::LargeBinary => encode_bytes_indices(data, offsets, as_generic_binary_array::<i64>(column), opts),
+                DataType::BinaryView => encode_bytes_indices(data, offsets, column.as_binary_view(), opts),
+                DataType::Utf8 => encode_bytes_indices(data, offsets, column.as_string::<i32>(), opts),
+                DataType::LargeUtf8 => encode_bytes_indices(data, offsets, column.as_string::<i64>(), opts),
+                DataType::Utf8View => encode_bytes_indices(data, offsets, column.as_string_view(), opts),
+                DataType::FixedSizeBinary(_) => {
+                    let array = column.as_any().downcast_ref().unwrap();
+                    fixed::encode_fixed_size_binary_indices(data, offsets, array, opts)
+                }
+                _ => unimplemented!("unsupported data type: {}", column.data_type()),
+            }
+        }
+        Encoder::Dictionary(values, null) => {
+            downcast_dictionary_array! {
+                column => {
+                    let keys = column.keys();
+                    for (offset, idx) in offsets {
+                        let row = match keys.is_valid(idx) {
+                            true => values.row(keys.value(idx).as_usize()).data,
+                            false => null.data,
+                        };
+                        let end_offset = *offset + row.len();
+                        data[*offset..end_offset].copy_from_slice(row);
+                        *offset = end_offset;
+                    }
+                }
+                _ => unreachable!()
+            }
+        }
+        Encoder::Struct(rows, null) => {
+            let array = as_struct_array(column);
+            let null_sentinel = null_sentinel(opts);
+            for (offset, idx) in offsets {
+                let (row, sentinel) = match array.is_valid(idx) {
+                    // Struct([]) arrays have no child rows
+                    true if rows.num_rows() == 0 => (&[][..], 0x01),
+                    true => (rows.row(idx).data, 0x01),
+                    false => (null.data, null_sentinel),
+                };
+                let end_offset = *offset + 1 + row.len();
+                data[*offset] = sentinel;
+                data[*offset + 1..end_offset].copy_from_slice(row);
+                *offset = end_offset;
+            }
+        }
+        Encoder::List(rows) => match column.data_type() {
+            DataType::List(_) => {
+                list::encode_indices(data, offsets, rows, opts, as_list_array(column))
+            }
+            DataType::LargeList(_) => {
+                list::encode_indices(data, offsets, rows, opts, as_large_list_array(column))
+            }
+            DataType::ListView(_) => {
+                let list_view = column.as_list_view::<i32>();
+                let (min_offset, _) = compute_list_view_bounds(list_view);
+                list::encode_list_view_indices(data, offsets, rows, opts, list_view, min_offset)
+            }
+            DataType::LargeListView(_) => {
+                let list_view = column.as_list_view::<i64>();
+                let (min_offset, _) = compute_list_view_bounds(list_view);
+                list::encode_list_view_indices(data, offsets, rows, opts, list_view, min_offset)
+            }
+            DataType::FixedSizeList(_, _) => {
+                let array = as_fixed_size_list_array(column);
+                list::encode_fixed_size_list_indices(data, offsets, rows, opts, array)
+            }
+            _ => unreachable!(),
+        },
+        Encoder::RunEndEncoded(rows) => match column.data_type() {
+            DataType::RunEndEncoded(r, _) => match r.data_type() {
+                DataType::Int16 => {
+                    run::encode_indices(data, offsets, rows, opts, column.as_run::<Int16Type>())
+                }
+                DataType::Int32 => {
+                    run::encode_indices(data, offsets, rows, opts, column.as_run::<Int32Type>())
+                }
+                DataType::Int64 => {
+                    run::encode_indices(data, offsets, rows, opts, column.as_run::<Int64Type>())
+                }
+                _ => unreachable!("Unsupported run end index type: {r:?}"),
+            },
+            _ => unreachable!(),
+        },
+        Encoder::Union {
+            child_rows,
+            field_to_type_ids,
+            type_ids,
+            offsets: offsets_buf,
+        } => {
+            let mut type_id_to_field_idx = [0usize; 128];
+            for (field_idx, &type_id) in field_to_type_ids.iter().enumerate() {
+                type_id_to_field_idx[type_id as usize] = field_idx;
+            }
+
+            for (offset, idx) in offsets {
+                let type_id = type_ids[idx];
+                let field_idx = type_id_to_field_idx[type_id as usize];
+
+                let child_row_idx = offsets_buf.as_ref().map(|o| o[idx] as usize).unwrap_or(idx);
+                let child_bytes = child_rows[field_idx].row(child_row_idx).data;
+
+                data[*offset] = match opts.descending {
+                    true => !(type_id as u8),
+                    false => type_id as u8,
+                };
+
+                let child_start = *offset + 1;
+                let child_end = child_start + child_bytes.len();
+                data[child_start..child_end].copy_from_slice(child_bytes);
+
+                *offset = child_end;
+            }
+        }
+    }
+}
+
+/// Encodes the values at the provided indices of a byte array
+fn encode_bytes_indices<'a, A>(
+    data: &mut [u8],
+    offsets: impl Iterator<Item = (&'a mut usize, usize)>,
+    array: A,
+    opts: SortOptions,
+) where
+    A: ArrayAccessor,
+    A::Item: AsRef<[u8]>,
+{
+    for (offset, idx) in offsets {
+        let value = array.is_valid(idx).then(|| array.value(idx));
+        let value = value.as_ref().map(|x| x.as_ref());
+        *offset += variable::encode_one(&mut data[*offset..], value, opts);
+    }
+}
+
 /// Encode dictionary values not preserving the dictionary encoding
 pub fn encode_dictionary_values<K: ArrowDictionaryKeyType>(
     data: &mut [u8],
@@ -4733,6 +5035,153 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_estimate_size_and_append_rows_into() {
+        let mut list = ListBuilder::new(Int32Builder::new());
+        list.append_value([Some(1), None]);
+        list.append_null();
+        list.append_value([Some(3)]);
+        list.append_value([]);
+        let columns = [
+            Arc::new(Int32Array::from(vec![Some(1), None, Some(3), Some(4)])) as ArrayRef,
+            Arc::new(StringArray::from(vec![
+                Some("a"),
+                Some("bcdef"),
+                None,
+                Some(""),
+            ])),
+            Arc::new(
+                vec![Some("x"), None, Some("yz"), Some("x")]
+                    .into_iter()
+                    .collect::<DictionaryArray<Int32Type>>(),
+            ),
+            Arc::new(list.finish()),
+        ];
+        let fields = columns
+            .iter()
+            .map(|c| SortField::new(c.data_type().clone()))
+            .collect();
+        let converter = RowConverter::new(fields).unwrap();
+
+        let estimate = converter.estimate_size(&columns).unwrap();
+        let expected = converter.convert_columns(&columns).unwrap();
+        let data_len: usize = expected.lengths().sum();
+        assert_eq!(estimate, data_len + 4 * std::mem::size_of::<usize>());
+        assert!(converter.estimate_size(&columns[..1]).is_err());
+
+        let mut rows = converter.empty_rows(0, 0);
+        let indices = UInt32Array::from(vec![3, 0, 0]);
+        converter
+            .append_rows_into(&mut rows, &columns, &indices)
+            .unwrap();
+        converter
+            .append_rows_into(&mut rows, &columns, &UInt32Array::from(vec![2]))
+            .unwrap();
+        let rows: Vec<_> = rows.iter().collect();
+        assert_eq!(
+            rows,
+            [
+                expected.row(3),
+                expected.row(0),
+                expected.row(0),
+                expected.row(2)
+            ]
+        );
+
+        let mut rows = converter.empty_rows(0, 0);
+        let err = converter
+            .append_rows_into(&mut rows, &columns, &UInt32Array::from(vec![None, Some(1)]))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: RowConverter indices must not contain nulls"
+        );
+    }
+
+    #[test]
+    fn test_append_rows_into_matches_take() {
+        let run = RunArray::<Int32Type>::try_new(
+            &Int32Array::from(vec![2, 3, 7]),
+            &StringArray::from(vec![Some("a"), None, Some("b")]),
+        )
+        .unwrap();
+        let mut fixed_list = FixedSizeListBuilder::new(Int32Builder::new(), 2);
+        for i in 0..6 {
+            fixed_list.values().append_value(i);
+            fixed_list.values().append_null();
+            fixed_list.append(i % 3 != 0);
+        }
+        let structs = StructArray::from(vec![(
+            Arc::new(Field::new("b", DataType::Boolean, true)),
+            Arc::new(BooleanArray::from(vec![
+                Some(true),
+                None,
+                Some(false),
+                Some(true),
+                None,
+                Some(false),
+            ])) as ArrayRef,
+        )]);
+        let columns = [
+            Arc::new(Float64Array::from(vec![
+                Some(1.5),
+                None,
+                Some(-2.0),
+                Some(0.0),
+                None,
+                Some(3.0),
+            ])) as ArrayRef,
+            Arc::new(BinaryViewArray::from(vec![
+                Some(b"a".as_slice()),
+                Some(b"a string longer than twelve bytes"),
+                None,
+                Some(b""),
+                Some(b"b"),
+                None,
+            ])),
+            Arc::new(
+                FixedSizeBinaryArray::try_from_sparse_iter_with_size(
+                    vec![Some([1, 2]), None, Some([3, 4]), Some([5, 6]), None, None].into_iter(),
+                    2,
+                )
+                .unwrap(),
+            ),
+            Arc::new(structs),
+            Arc::new(fixed_list.finish()),
+            Arc::new(run.slice(2, 5)),
+            Arc::new(NullArray::new(6)),
+        ];
+        let columns: Vec<_> = columns.iter().map(|c| c.slice(0, 5)).collect();
+        let fields = columns
+            .iter()
+            .map(|c| {
+                SortField::new_with_options(c.data_type().clone(), SortOptions::default().desc())
+            })
+            .collect();
+        let converter = RowConverter::new(fields).unwrap();
+
+        let indices = UInt32Array::from(vec![4, 0, 2, 2, 1, 3]);
+        let taken: Vec<_> = columns
+            .iter()
+            .map(|c| arrow_select::take::take(c.as_ref(), &indices, None).unwrap())
+            .collect();
+        let expected = converter.convert_columns(&taken).unwrap();
+
+        let mut rows = converter.empty_rows(0, 0);
+        converter
+            .append_rows_into(&mut rows, &columns, &indices)
+            .unwrap();
+        assert!(rows.iter().eq(expected.iter()));
+
+        let err = converter
+            .append_rows_into(&mut rows, &columns, &UInt32Array::from(vec![5]))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: RowConverter index out of bounds: 5 >= 5"
+        );
+    }
+
     #[test]
     fn test_append_codec_dictionary_binary() {
         use DataType::*;
@@ -72,6 +72,27 @@ pub fn encode<O: OffsetSizeTrait>(
         });
 }
 
+/// Encodes the values at the provided indices of a `GenericListArray`
+///
+/// `rows` should contain the encoded child elements
+pub fn encode_indices<'a, O: OffsetSizeTrait>(
+    data: &mut [u8],
+    offsets: impl Iterator<Item = (&'a mut usize, usize)>,
+    rows: &Rows,
+    opts: SortOptions,
+    array: &GenericListArray<O>,
+) {
+    let value_offsets = array.value_offsets();
+    let shift = value_offsets[0].as_usize();
+
+    for (offset, idx) in offsets {
+        let start = value_offsets[idx].as_usize() - shift;
+        let end = value_offsets[idx + 1].as_usize() - shift;
+        let range = array.is_valid(idx).then_some(start..end);
+        *offset += encode_one(&mut data[*offset..], rows, range, opts)
+    }
+}
+
 #[inline]
 fn encode_one(
     out: &mut [u8],
@@ -254,6 +275,35 @@ pub fn encode_fixed_size_list(
         })
 }
 
+/// Encodes the values at the provided indices of a `FixedSizeListArray`
+///
+/// `rows` should contain the encoded child elements
+pub fn encode_fixed_size_list_indices<'a>(
+    data: &mut [u8],
+    offsets: impl Iterator<Item = (&'a mut usize, usize)>,
+    rows: &Rows,
+    opts: SortOptions,
+    array: &FixedSizeListArray,
+) {
+    let null_sentinel = null_sentinel(opts);
+    let value_length = array.value_length().as_usize();
+    for (offset, idx) in offsets {
+        if !array.is_valid(idx) {
+            data[*offset] = null_sentinel;
+            *offset += 1;
+            continue;
+        }
+        data[*offset] = 0x01;
+        *offset += 1;
+        for child_idx in (idx * value_length)..(idx + 1) * value_length {
+            let row = rows.row(child_idx);
+            let end_offset = *offset + row.as_ref().len();
+            data[*offset..end_offset].copy_from_slice(row.as_ref());
+            *offset = end_offset;
+        }
+    }
+}
+
 /// Decodes a fixed size list array from `rows` with the provided `options`
 ///
 /// # Safety
@@ -397,6 +447,35 @@ pub fn encode_list_view<O: OffsetSizeTrait>(
         });
 }
 
+/// Encodes the values at the provided indices of a `GenericListViewArray`
+///
+/// `rows` should contain the encoded child elements
+pub fn encode_list_view_indices<'a, O: OffsetSizeTrait>(
+    data: &mut [u8],
+    out_offsets: impl Iterator<Item = (&'a mut usize, usize)>,
+    rows: &Rows,
+    opts: SortOptions,
+    array: &GenericListViewArray<O>,
+    shift: usize,
+) {
+    let offsets = array.value_offsets();
+    let sizes = array.value_sizes();
+
+    for (offset, idx) in out_offsets {
+        let size = sizes[idx].as_usize();
+        let range = array.is_valid(idx).then(|| {
+            // Empty lists may have arbitrary offsets, see `encode_list_view`
+            let start = if size > 0 {
+                offsets[idx].as_usize() - shift
+            } else {
+                0
+            };
+            start..start + size
+        });
+        *offset += encode_one(&mut data[*offset..], rows, range, opts)
+    }
+}
+
 /// Decodes a `GenericListViewArray` from `rows` with the provided `options`
 ///
 /// # Safety
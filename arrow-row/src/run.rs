@@ -79,6 +79,24 @@ pub fn encode<R: RunEndIndexType>(
     }
 }
 
+/// Encodes the values at the provided indices of a `RunEndEncodedArray`
+///
+/// `rows` should contain the encoded values
+pub fn encode_indices<'a, R: RunEndIndexType>(
+    data: &mut [u8],
+    offsets: impl Iterator<Item = (&'a mut usize, usize)>,
+    rows: &Rows,
+    opts: SortOptions,
+    array: &RunArray<R>,
+) {
+    // `rows` are encoded from the values of the sliced array
+    let start = array.get_start_physical_index();
+    for (offset, idx) in offsets {
+        let row = rows.row(array.get_physical_index(idx) - start);
+        *offset += variable::encode_one(&mut data[*offset..], Some(row.data), opts);
+    }
+}
+
 /// Decodes a RunEndEncodedArray from `rows` with the provided `options`
 ///
 /// # Safety
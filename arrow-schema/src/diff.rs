@@ -0,0 +1,588 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Comparison of [`Schema`]s, see [`Schema::diff`] and [`SchemaCompatibility`]
+
+use std::fmt;
+use std::sync::Arc;
+
+use crate::{ArrowError, DataType, FieldRef, Fields, Schema};
+
+/// A change to a field between two [`Schema`]s, see [`FieldDiff`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FieldChange {
+    /// The field is only present in the new schema
+    Added(FieldRef),
+    /// The field is only present in the old schema
+    Removed(FieldRef),
+    /// The data type of the field changed
+    DataType {
+        /// The old data type
+        from: DataType,
+        /// The new data type
+        to: DataType,
+    },
+    /// The nullability of the field changed
+    Nullability {
+        /// The old nullability
+        from: bool,
+        /// The new nullability
+        to: bool,
+    },
+    /// The metadata of the field changed
+    Metadata,
+    /// The position of the field, relative to the other fields present in both
+    /// schemas, changed
+    ///
+    /// The moved fields are a smallest set of fields such that the other fields
+    /// present in both schemas keep their relative order
+    Position {
+        /// The index of the field in the old schema
+        from: usize,
+        /// The index of the field in the new schema
+        to: usize,
+    },
+}
+
+impl fmt::Display for FieldChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Added(field) => write!(f, "added with data type {}", field.data_type()),
+            Self::Removed(_) => write!(f, "removed"),
+            Self::DataType { from, to } => {
+                write!(f, "data type changed from {from} to {to}")
+            }
+            Self::Nullability { from, to } => {
+                write!(f, "nullability changed from {from} to {to}")
+            }
+            Self::Metadata => write!(f, "metadata changed"),
+            Self::Position { from, to } => {
+                write!(f, "moved from position {from} to {to}")
+            }
+        }
+    }
+}
+
+/// A [`FieldChange`] to the field at [`Self::path`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldDiff {
+    /// The names of the field's enclosing fields, outermost first, followed by the
+    /// name of the field itself
+    ///
+    /// The enclosing fields of the elements of a list, or the entries of a map, include
+    /// the list or map field, e.g. `["l", "item"]` for the elements of list `l`
+    pub path: Vec<String>,
+    /// The change to the field
+    pub change: FieldChange,
+}
+
+impl FieldDiff {
+    fn new(path: &[String], name: &str, change: FieldChange) -> Self {
+        let mut path = path.to_vec();
+        path.push(name.to_string());
+        Self { path, change }
+    }
+}
+
+impl fmt::Display for FieldDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "field \"{}\" {}", self.path.join("."), self.change)
+    }
+}
+
+/// The differences between two [`Schema`]s, see [`Schema::diff`]
+///
+/// Fields are matched by name, with the fields of nested structs, and the child
+/// fields of lists and maps, compared recursively
+///
+/// ```
+/// # use arrow_schema::*;
+/// let old = Schema::new(vec![
+///     Field::new("a", DataType::Int32, false),
+///     Field::new("b", DataType::Utf8, true),
+/// ]);
+/// let new = Schema::new(vec![
+///     Field::new("a", DataType::Int32, true),
+///     Field::new("c", DataType::Utf8, true),
+/// ]);
+///
+/// let diff = old.diff(&new);
+/// assert_eq!(
+///     diff.to_string(),
+///     "field \"a\" nullability changed from false to true, \
+///      field \"b\" removed, \
+///      field \"c\" added with data type Utf8"
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SchemaDiff {
+    fields: Vec<FieldDiff>,
+    metadata_changed: bool,
+}
+
+impl SchemaDiff {
+    pub(crate) fn new(from: &Schema, to: &Schema) -> Self {
+        let mut fields = vec![];
+        diff_fields(&mut vec![], &from.fields, &to.fields, &mut fields);
+        Self {
+            fields,
+            metadata_changed: from.metadata != to.metadata,
+        }
+    }
+
+    /// Returns true if there are no differences
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty() && !self.metadata_changed
+    }
+
+    /// Returns the differences between the fields of the schemas
+    pub fn fields(&self) -> &[FieldDiff] {
+        &self.fields
+    }
+
+    /// Returns true if the top-level metadata of the schemas differs
+    pub fn metadata_changed(&self) -> bool {
+        self.metadata_changed
+    }
+}
+
+impl fmt::Display for SchemaDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut prefix = "";
+        for field in &self.fields {
+            write!(f, "{prefix}{field}")?;
+            prefix = ", ";
+        }
+        if self.metadata_changed {
+            write!(f, "{prefix}schema metadata changed")?;
+        }
+        Ok(())
+    }
+}
+
+fn diff_fields(path: &mut Vec<String>, from: &Fields, to: &Fields, out: &mut Vec<FieldDiff>) {
+    // The indices in `to` of the fields of `from` also present in `to`, the fields
+    // outside of a longest increasing subsequence of these have moved
+    let to_indices: Vec<usize> = from
+        .iter()
+        .filter_map(|f| to.find(f.name()).map(|(idx, _)| idx))
+        .collect();
+    let mut unmoved = longest_increasing(&to_indices).into_iter();
+
+    for (from_idx, f) in from.iter().enumerate() {
+        let name = f.name();
+        let Some((to_idx, t)) = to.find(name) else {
+            out.push(FieldDiff::new(
+                path,
+                name,
+                FieldChange::Removed(Arc::clone(f)),
+            ));
+            continue;
+        };
+        if unmoved.next() == Some(false) {
+            let change = FieldChange::Position {
+                from: from_idx,
+                to: to_idx,
+            };
+            out.push(FieldDiff::new(path, name, change));
+        }
+        diff_field(path, f, t, out);
+    }
+
+    for t in to.iter().filter(|t| from.find(t.name()).is_none()) {
+        out.push(FieldDiff::new(
+            path,
+            t.name(),
+            FieldChange::Added(Arc::clone(t)),
+        ));
+    }
+}
+
+/// Compares the field `from` with the field `to` it was matched with
+fn diff_field(path: &mut Vec<String>, from: &FieldRef, to: &FieldRef, out: &mut Vec<FieldDiff>) {
+    let name = from.name();
+    path.push(name.clone());
+    let nested = match (from.data_type(), to.data_type()) {
+        (DataType::Struct(from), DataType::Struct(to)) => {
+            diff_fields(path, from, to, out);
+            true
+        }
+        (DataType::List(from), DataType::List(to))
+        | (DataType::LargeList(from), DataType::LargeList(to))
+        | (DataType::ListView(from), DataType::ListView(to))
+        | (DataType::LargeListView(from), DataType::LargeListView(to))
+            if from.name() == to.name() =>
+        {
+            diff_field(path, from, to, out);
+            true
+        }
+        (DataType::FixedSizeList(from, from_size), DataType::FixedSizeList(to, to_size))
+            if from_size == to_size && from.name() == to.name() =>
+        {
+            diff_field(path, from, to, out);
+            true
+        }
+        (DataType::Map(from, from_sorted), DataType::Map(to, to_sorted))
+            if from_sorted == to_sorted && from.name() == to.name() =>
+        {
+            diff_field(path, from, to, out);
+            true
+        }
+        _ => false,
+    };
+    path.pop();
+
+    if !nested && from.data_type() != to.data_type() {
+        let change = FieldChange::DataType {
+            from: from.data_type().clone(),
+            to: to.data_type().clone(),
+        };
+        out.push(FieldDiff::new(path, name, change));
+    }
+    if from.is_nullable() != to.is_nullable() {
+        let change = FieldChange::Nullability {
+            from: from.is_nullable(),
+            to: to.is_nullable(),
+        };
+        out.push(FieldDiff::new(path, name, change));
+    }
+    if from.metadata() != to.metadata() {
+        out.push(FieldDiff::new(path, name, FieldChange::Metadata));
+    }
+}
+
+/// Returns whether each value of `values` belongs to a longest strictly increasing
+/// subsequence of `values`
+fn longest_increasing(values: &[usize]) -> Vec<bool> {
+    // `tails[k]` is the index of the smallest last value of an increasing
+    // subsequence of length `k + 1`, and `previous[i]` the index of the value
+    // preceding `values[i]` in the subsequence ending with it
+    let mut tails: Vec<usize> = vec![];
+    let mut previous = vec![None; values.len()];
+    for (idx, value) in values.iter().enumerate() {
+        let len = tails.partition_point(|&t| values[t] < *value);
+        previous[idx] = len.checked_sub(1).map(|l| tails[l]);
+        match tails.get_mut(len) {
+            Some(tail) => *tail = idx,
+            None => tails.push(idx),
+        }
+    }
+
+    let mut in_subsequence = vec![false; values.len()];
+    let mut next = tails.last().copied();
+    while let Some(idx) = next {
+        in_subsequence[idx] = true;
+        next = previous[idx];
+    }
+    in_subsequence
+}
+
+/// A policy determining which differences between two [`Schema`]s are compatible
+///
+/// By default, as returned by [`SchemaCompatibility::exact`], any difference reported
+/// by [`Schema::diff`] is incompatible
+///
+/// ```
+/// # use arrow_schema::*;
+/// let table = Schema::new(vec![Field::new("a", DataType::Int32, false)]);
+/// let batch = Schema::new(vec![
+///     Field::new("a", DataType::Int32, true),
+///     Field::new("b", DataType::Utf8, true),
+/// ]);
+///
+/// let exact = SchemaCompatibility::exact();
+/// assert_eq!(exact.check(&table, &batch).fields().len(), 2);
+///
+/// let relaxed = exact
+///     .with_allow_nullable_widening(true)
+///     .with_allow_added_nullable_fields(true);
+/// relaxed.validate(&table, &batch).unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SchemaCompatibility {
+    ignore_metadata: bool,
+    allow_nullable_widening: bool,
+    allow_added_nullable_fields: bool,
+}
+
+impl SchemaCompatibility {
+    /// Create a [`SchemaCompatibility`] that considers any difference incompatible
+    pub fn exact() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether differences in schema and field metadata are ignored
+    ///
+    /// Defaults to `false`
+    pub fn with_ignore_metadata(mut self, ignore_metadata: bool) -> Self {
+        self.ignore_metadata = ignore_metadata;
+        self
+    }
+
+    /// Sets whether a non-nullable field may become nullable
+    ///
+    /// Defaults to `false`
+    pub fn with_allow_nullable_widening(mut self, allow_nullable_widening: bool) -> Self {
+        self.allow_nullable_widening = allow_nullable_widening;
+        self
+    }
+
+    /// Sets whether nullable fields may be added, including to nested structs, such
+    /// as the struct elements of a list
+    ///
+    /// Defaults to `false`
+    pub fn with_allow_added_nullable_fields(mut self, allow_added_nullable_fields: bool) -> Self {
+        self.allow_added_nullable_fields = allow_added_nullable_fields;
+        self
+    }
+
+    /// Returns the differences between `from` and `to` not permitted by this policy
+    ///
+    /// `to` is compatible with `from` if the returned [`SchemaDiff`] is empty
+    pub fn check(&self, from: &Schema, to: &Schema) -> SchemaDiff {
+        let mut diff = from.diff(to);
+        diff.fields.retain(|d| !self.allows(&d.change));
+        diff.metadata_changed &= !self.ignore_metadata;
+        diff
+    }
+
+    /// Returns an error describing the incompatible differences if `to` is not
+    /// compatible with `from`
+    pub fn validate(&self, from: &Schema, to: &Schema) -> Result<(), ArrowError> {
+        let diff = self.check(from, to);
+        match diff.is_empty() {
+            true => Ok(()),
+            false => Err(ArrowError::SchemaError(format!(
+                "Incompatible schema: {diff}"
+            ))),
+        }
+    }
+
+    fn allows(&self, change: &FieldChange) -> bool {
+        match change {
+            FieldChange::Metadata => self.ignore_metadata,
+            FieldChange::Nullability {
+                from: false,
+                to: true,
+            } => self.allow_nullable_widening,
+            FieldChange::Added(f) => f.is_nullable() && self.allow_added_nullable_fields,
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Field;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_schema_diff() {
+        let metadata = HashMap::from([("k".to_string(), "v".to_string())]);
+        let old = Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new_struct(
+                "s",
+                vec![
+                    Field::new("x", DataType::Int64, false),
+                    Field::new("y", DataType::Utf8, true),
+                ],
+                true,
+            ),
+            Field::new("b", DataType::Utf8, true),
+            Field::new("c", DataType::Utf8, true),
+        ]);
+        assert!(old.diff(&old).is_empty());
+
+        let new = Schema::new(vec![
+            Field::new("a", DataType::Int64, false),
+            Field::new("c", DataType::Utf8, true).with_metadata(metadata.clone()),
+            Field::new("d", DataType::Boolean, false),
+            Field::new_struct(
+                "s",
+                vec![
+                    Field::new("x", DataType::Int64, true),
+                    Field::new("z", DataType::Utf8, true),
+                ],
+                true,
+            ),
+        ])
+        .with_metadata(metadata);
+
+        let diff = old.diff(&new);
+        assert!(diff.metadata_changed());
+        assert_eq!(
+            diff.to_string(),
+            "field \"a\" data type changed from Int32 to Int64, \
+             field \"s\" moved from position 1 to 3, \
+             field \"s.x\" nullability changed from false to true, \
+             field \"s.y\" removed, \
+             field \"s.z\" added with data type Utf8, \
+             field \"b\" removed, \
+             field \"c\" metadata changed, \
+             field \"d\" added with data type Boolean, \
+             schema metadata changed"
+        );
+        assert_eq!(diff.fields()[0].path, ["a"]);
+        assert_eq!(diff.fields()[2].path, ["s", "x"]);
+    }
+
+    #[test]
+    fn test_schema_diff_moves() {
+        let schema = |names: &[&str]| {
+            Schema::new(
+                names
+                    .iter()
+                    .map(|n| Field::new(*n, DataType::Int32, false))
+                    .collect::<Vec<_>>(),
+            )
+        };
+        let old = schema(&["a", "b", "c", "d", "e"]);
+
+        // Only the fields moved relative to the others are reported
+        let diff = old.diff(&schema(&["e", "a", "b", "c", "d"]));
+        assert_eq!(diff.to_string(), "field \"e\" moved from position 4 to 0");
+
+        let diff = old.diff(&schema(&["b", "c", "d", "e", "a"]));
+        assert_eq!(diff.to_string(), "field \"a\" moved from position 0 to 4");
+
+        let diff = old.diff(&schema(&["a", "d", "c", "x", "b", "e"]));
+        assert_eq!(
+            diff.to_string(),
+            "field \"b\" moved from position 1 to 4, \
+             field \"c\" moved from position 2 to 2, \
+             field \"x\" added with data type Int32"
+        );
+
+        assert!(longest_increasing(&[]).is_empty());
+        assert_eq!(
+            longest_increasing(&[3, 0, 4, 1, 2]),
+            [false, true, false, true, true]
+        );
+    }
+
+    #[test]
+    fn test_schema_diff_nested() {
+        let schema = |x: DataType, value: Field| {
+            let item = Field::new_struct("item", vec![Field::new("x", x, true)], true);
+            let key = Field::new("key", DataType::Utf8, false);
+            Schema::new(vec![
+                Field::new_list("l", item, true),
+                Field::new_map("m", "entries", key, value, false, true),
+            ])
+        };
+        let old = schema(DataType::Int32, Field::new("value", DataType::Int32, false));
+        let new = schema(DataType::Int64, Field::new("value", DataType::Int32, true));
+
+        let diff = old.diff(&new);
+        assert_eq!(
+            diff.to_string(),
+            "field \"l.item.x\" data type changed from Int32 to Int64, \
+             field \"m.entries.value\" nullability changed from false to true"
+        );
+        assert_eq!(diff.fields()[0].path, ["l", "item", "x"]);
+
+        // A field added to the struct elements of a list
+        let item = Field::new_struct(
+            "item",
+            vec![
+                Field::new("x", DataType::Int32, true),
+                Field::new("y", DataType::Utf8, true),
+            ],
+            true,
+        );
+        let key = Field::new("key", DataType::Utf8, false);
+        let value = Field::new("value", DataType::Int32, false);
+        let added = Schema::new(vec![
+            Field::new_list("l", item, true),
+            Field::new_map("m", "entries", key, value, false, true),
+        ]);
+        let relaxed = SchemaCompatibility::exact().with_allow_added_nullable_fields(true);
+        assert_eq!(
+            old.diff(&added).to_string(),
+            "field \"l.item.y\" added with data type Utf8"
+        );
+        relaxed.validate(&old, &added).unwrap();
+
+        // Lists with differently named elements are not compared recursively
+        let renamed = Schema::new(vec![Field::new_list(
+            "l",
+            Field::new("element", DataType::Int32, true),
+            true,
+        )]);
+        let list = Schema::new(vec![Field::new_list(
+            "l",
+            Field::new("item", DataType::Int32, true),
+            true,
+        )]);
+        let diff = list.diff(&renamed);
+        assert_eq!(diff.fields().len(), 1);
+        assert!(matches!(
+            diff.fields()[0].change,
+            FieldChange::DataType { .. }
+        ));
+    }
+
+    #[test]
+    fn test_schema_compatibility() {
+        let old = Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Utf8, true),
+        ]);
+        let metadata = HashMap::from([("k".to_string(), "v".to_string())]);
+        let new = Schema::new(vec![
+            Field::new("a", DataType::Int32, true).with_metadata(metadata.clone()),
+            Field::new("c", DataType::Utf8, true),
+            Field::new("b", DataType::Utf8, true),
+        ])
+        .with_metadata(metadata);
+
+        let exact = SchemaCompatibility::exact();
+        assert_eq!(exact.check(&old, &new).fields().len(), 3);
+        let err = exact.validate(&old, &new).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Schema error: Incompatible schema: \
+             field \"a\" nullability changed from false to true, \
+             field \"a\" metadata changed, \
+             field \"c\" added with data type Utf8, \
+             schema metadata changed"
+        );
+
+        let relaxed = exact
+            .with_ignore_metadata(true)
+            .with_allow_nullable_widening(true)
+            .with_allow_added_nullable_fields(true);
+        relaxed.validate(&old, &new).unwrap();
+
+        // Narrowing nullability, removing fields and adding non-nullable fields
+        // are never compatible
+        let diff = relaxed.check(&new, &old);
+        assert_eq!(
+            diff.to_string(),
+            "field \"a\" nullability changed from true to false, field \"c\" removed"
+        );
+        let added = Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Utf8, true),
+            Field::new("c", DataType::Utf8, false),
+        ]);
+        assert_eq!(relaxed.check(&old, &added).fields().len(), 1);
+    }
+}
@@ -0,0 +1,340 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Stable fingerprints of [`DataType`], [`Field`] and [`Schema`]
+
+use std::collections::HashMap;
+
+use crate::{DataType, Field, Fields, IntervalUnit, Schema, TimeUnit, UnionMode};
+
+/// The version of the encoding used to compute [`DataType::fingerprint`],
+/// [`Field::fingerprint`] and [`Schema::fingerprint`]
+///
+/// Fingerprints computed with the same version are identical across processes,
+/// platforms and releases. The version, and consequently all fingerprints, will only
+/// change in a breaking release, for example if a new [`DataType`] is added
+pub const FINGERPRINT_VERSION: u8 = 1;
+
+impl DataType {
+    /// Returns a 128-bit fingerprint of this [`DataType`], including any nested fields
+    ///
+    /// Unlike [`Hash`], the fingerprint is stable across processes and releases, see
+    /// [`FINGERPRINT_VERSION`], and so can be persisted or shared, for example as a
+    /// cache key. It is not a cryptographic hash.
+    pub fn fingerprint(&self) -> u128 {
+        let mut f = Fingerprinter::new(0);
+        f.data_type(self);
+        f.finish()
+    }
+}
+
+impl Field {
+    /// Returns a 128-bit fingerprint of this [`Field`]'s name, data type, nullability,
+    /// dictionary ordering and metadata
+    ///
+    /// See [`DataType::fingerprint`]
+    pub fn fingerprint(&self) -> u128 {
+        let mut f = Fingerprinter::new(1);
+        f.field(self);
+        f.finish()
+    }
+}
+
+impl Schema {
+    /// Returns a 128-bit fingerprint of this [`Schema`]'s fields and metadata
+    ///
+    /// See [`DataType::fingerprint`]
+    ///
+    /// ```
+    /// # use arrow_schema::*;
+    /// let a = Schema::new(vec![Field::new("a", DataType::Int32, true)]);
+    /// let b = Schema::new(vec![Field::new("a", DataType::Int64, true)]);
+    /// assert_eq!(a.fingerprint(), a.clone().fingerprint());
+    /// assert_ne!(a.fingerprint(), b.fingerprint());
+    /// ```
+    pub fn fingerprint(&self) -> u128 {
+        let mut f = Fingerprinter::new(2);
+        f.fields(&self.fields);
+        f.metadata(&self.metadata);
+        f.finish()
+    }
+}
+
+/// Computes a 128-bit FNV-1a hash of a canonical encoding of a type
+///
+/// Every variable length value is prefixed with its length, so distinct types
+/// have distinct encodings
+struct Fingerprinter(u128);
+
+impl Fingerprinter {
+    const OFFSET: u128 = 0x6c62272e07bb014262b821756295c58d;
+    const PRIME: u128 = 0x0000000001000000000000000000013b;
+
+    fn new(kind: u8) -> Self {
+        let mut f = Self(Self::OFFSET);
+        f.bytes(&[FINGERPRINT_VERSION, kind]);
+        f
+    }
+
+    fn finish(self) -> u128 {
+        self.0
+    }
+
+    fn bytes(&mut self, bytes: &[u8]) {
+        for b in bytes {
+            self.0 = (self.0 ^ *b as u128).wrapping_mul(Self::PRIME);
+        }
+    }
+
+    fn u8(&mut self, v: u8) {
+        self.bytes(&[v])
+    }
+
+    fn len(&mut self, len: usize) {
+        self.bytes(&(len as u64).to_le_bytes())
+    }
+
+    fn str(&mut self, s: &str) {
+        self.len(s.len());
+        self.bytes(s.as_bytes())
+    }
+
+    fn metadata(&mut self, metadata: &HashMap<String, String>) {
+        let mut entries: Vec<_> = metadata.iter().collect();
+        entries.sort_unstable();
+        self.len(entries.len());
+        for (k, v) in entries {
+            self.str(k);
+            self.str(v);
+        }
+    }
+
+    fn field(&mut self, field: &Field) {
+        self.str(field.name());
+        self.data_type(field.data_type());
+        self.u8(field.is_nullable() as u8);
+        self.u8(field.dict_is_ordered().unwrap_or_default() as u8);
+        self.metadata(field.metadata());
+    }
+
+    fn fields(&mut self, fields: &Fields) {
+        self.len(fields.len());
+        fields.iter().for_each(|f| self.field(f));
+    }
+
+    fn time_unit(&mut self, unit: &TimeUnit) {
+        self.u8(match unit {
+            TimeUnit::Second => 0,
+            TimeUnit::Millisecond => 1,
+            TimeUnit::Microsecond => 2,
+            TimeUnit::Nanosecond => 3,
+        })
+    }
+
+    fn decimal(&mut self, tag: u8, precision: u8, scale: i8) {
+        self.bytes(&[tag, precision, scale as u8])
+    }
+
+    fn data_type(&mut self, data_type: &DataType) {
+        // The tags must never be changed or reused without bumping FINGERPRINT_VERSION
+        match data_type {
+            DataType::Null => self.u8(0),
+            DataType::Boolean => self.u8(1),
+            DataType::Int8 => self.u8(2),
+            DataType::Int16 => self.u8(3),
+            DataType::Int32 => self.u8(4),
+            DataType::Int64 => self.u8(5),
+            DataType::UInt8 => self.u8(6),
+            DataType::UInt16 => self.u8(7),
+            DataType::UInt32 => self.u8(8),
+            DataType::UInt64 => self.u8(9),
+            DataType::Float16 => self.u8(10),
+            DataType::Float32 => self.u8(11),
+            DataType::Float64 => self.u8(12),
+            DataType::Timestamp(unit, tz) => {
+                self.u8(13);
+                self.time_unit(unit);
+                match tz {
+                    Some(tz) => {
+                        self.u8(1);
+                        self.str(tz)
+                    }
+                    None => self.u8(0),
+                }
+            }
+            DataType::Date32 => self.u8(14),
+            DataType::Date64 => self.u8(15),
+            DataType::Time32(unit) => {
+                self.u8(16);
+                self.time_unit(unit)
+            }
+            DataType::Time64(unit) => {
+                self.u8(17);
+                self.time_unit(unit)
+            }
+            DataType::Duration(unit) => {
+                self.u8(18);
+                self.time_unit(unit)
+            }
+            DataType::Interval(unit) => self.bytes(&[
+                19,
+                match unit {
+                    IntervalUnit::YearMonth => 0,
+                    IntervalUnit::DayTime => 1,
+                    IntervalUnit::MonthDayNano => 2,
+                },
+            ]),
+            DataType::Binary => self.u8(20),
+            DataType::FixedSizeBinary(size) => {
+                self.u8(21);
+                self.bytes(&size.to_le_bytes())
+            }
+            DataType::LargeBinary => self.u8(22),
+            DataType::BinaryView => self.u8(23),
+            DataType::Utf8 => self.u8(24),
+            DataType::LargeUtf8 => self.u8(25),
+            DataType::Utf8View => self.u8(26),
+            DataType::List(f) => {
+                self.u8(27);
+                self.field(f)
+            }
+            DataType::ListView(f) => {
+                self.u8(28);
+                self.field(f)
+            }
+            DataType::FixedSizeList(f, size) => {
+                self.u8(29);
+                self.field(f);
+                self.bytes(&size.to_le_bytes())
+            }
+            DataType::LargeList(f) => {
+                self.u8(30);
+                self.field(f)
+            }
+            DataType::LargeListView(f) => {
+                self.u8(31);
+                self.field(f)
+            }
+            DataType::Struct(fields) => {
+                self.u8(32);
+                self.fields(fields)
+            }
+            DataType::Union(fields, mode) => {
+                self.u8(33);
+                self.len(fields.len());
+                for (type_id, f) in fields.iter() {
+                    self.u8(type_id as u8);
+                    self.field(f);
+                }
+                self.u8(match mode {
+                    UnionMode::Sparse => 0,
+                    UnionMode::Dense => 1,
+                })
+            }
+            DataType::Dictionary(key, value) => {
+                self.u8(34);
+                self.data_type(key);
+                self.data_type(value)
+            }
+            DataType::Decimal32(p, s) => self.decimal(35, *p, *s),
+            DataType::Decimal64(p, s) => self.decimal(36, *p, *s),
+            DataType::Decimal128(p, s) => self.decimal(37, *p, *s),
+            DataType::Decimal256(p, s) => self.decimal(38, *p, *s),
+            DataType::Map(f, sorted) => {
+                self.u8(39);
+                self.field(f);
+                self.u8(*sorted as u8)
+            }
+            DataType::RunEndEncoded(run_ends, values) => {
+                self.u8(40);
+                self.field(run_ends);
+                self.field(values)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_fingerprint_stable() {
+        // These values must not change unless FINGERPRINT_VERSION is incremented
+        assert_eq!(
+            DataType::Int32.fingerprint(),
+            0xa68baf087d8b5822836dbc78c5654ab8
+        );
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new_list("b", Field::new_list_field(DataType::Utf8, true), true)
+                .with_metadata(HashMap::from([("k".to_string(), "v".to_string())])),
+        ]);
+        assert_eq!(schema.fingerprint(), 0x8fa6e3dc36f07dc92e14d8901b45a671);
+    }
+
+    #[test]
+    fn test_fingerprint_distinct() {
+        let types = [
+            DataType::Int32,
+            DataType::Int64,
+            DataType::Timestamp(TimeUnit::Second, None),
+            DataType::Timestamp(TimeUnit::Second, Some("UTC".into())),
+            DataType::Timestamp(TimeUnit::Millisecond, None),
+            DataType::Decimal128(10, 2),
+            DataType::Decimal128(10, 3),
+            DataType::Decimal256(10, 2),
+            DataType::new_list(DataType::Int32, true),
+            DataType::new_list(DataType::Int32, false),
+            DataType::new_large_list(DataType::Int32, true),
+            DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8)),
+            DataType::Dictionary(Box::new(DataType::Int16), Box::new(DataType::Utf8)),
+            DataType::Struct(Fields::empty()),
+            DataType::Struct(vec![Field::new("a", DataType::Int32, true)].into()),
+            DataType::Struct(vec![Field::new("b", DataType::Int32, true)].into()),
+        ];
+        for (i, a) in types.iter().enumerate() {
+            for b in &types[i + 1..] {
+                assert_ne!(a.fingerprint(), b.fingerprint(), "{a} {b}");
+            }
+        }
+
+        let field = Field::new("a", DataType::Int32, true);
+        let metadata = HashMap::from([("k".to_string(), "v".to_string())]);
+        assert_ne!(field.fingerprint(), DataType::Int32.fingerprint());
+        assert_ne!(
+            field.fingerprint(),
+            field.clone().with_nullable(false).fingerprint()
+        );
+        assert_ne!(
+            field.fingerprint(),
+            field.clone().with_metadata(metadata.clone()).fingerprint()
+        );
+
+        let schema = Schema::new(vec![field.clone()]);
+        assert_eq!(
+            schema.fingerprint(),
+            Schema::new(vec![Arc::new(field)]).fingerprint()
+        );
+        assert_ne!(
+            schema.fingerprint(),
+            schema.clone().with_metadata(metadata).fingerprint()
+        );
+        assert_ne!(schema.fingerprint(), Schema::empty().fingerprint());
+    }
+}
@@ -30,11 +30,15 @@ pub use datatype::*;
 use std::fmt::Display;
 mod datatype_display;
 mod datatype_parse;
+mod diff;
+pub use diff::*;
 mod error;
 pub use error::*;
 pub mod extension;
 mod field;
 pub use field::*;
+mod fingerprint;
+pub use fingerprint::FINGERPRINT_VERSION;
 mod fields;
 pub use fields::*;
 mod schema;
@@ -22,7 +22,7 @@ use std::sync::Arc;
 
 use crate::error::ArrowError;
 use crate::field::Field;
-use crate::{DataType, FieldRef, Fields};
+use crate::{DataType, FieldRef, Fields, SchemaDiff};
 
 /// A builder to facilitate building a [`Schema`] from iteratively from [`FieldRef`]
 #[derive(Debug, Default)]
@@ -501,6 +501,14 @@ impl Schema {
         Some((idx, field.as_ref()))
     }
 
+    /// Returns the differences between `self` and `other`
+    ///
+    /// See [`SchemaCompatibility`](crate::SchemaCompatibility) to determine if these
+    /// differences are compatible
+    pub fn diff(&self, other: &Schema) -> SchemaDiff {
+        SchemaDiff::new(self, other)
+    }
+
     /// Check to see if `self` is a superset of `other` schema.
     ///
     /// In particular returns true if `self.metadata` is a superset of `other.metadata`
@@ -0,0 +1,705 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines [`ChunkedArray`] and [`Table`], sequences of arrays and batches of the same type
+//!
+//! These allow working with data split across multiple arrays, such as the result of reading
+//! a file, without first concatenating it into a single array. Slicing and filtering are
+//! applied to each chunk in turn, preserving the chunks of the input, whereas take gathers
+//! values from any chunk and so returns a single chunk.
+
+use std::sync::Arc;
+
+use arrow_array::{
+    Array, ArrayRef, BooleanArray, RecordBatch, RecordBatchOptions, RecordBatchReader,
+    downcast_integer_array, new_empty_array, new_null_array,
+};
+use arrow_buffer::ArrowNativeType;
+use arrow_schema::{ArrowError, DataType, SchemaRef};
+
+use crate::concat::{concat, concat_batches};
+use crate::filter::{filter, filter_record_batch};
+use crate::interleave::interleave;
+
+/// A logical array of [`DataType`] made up of a sequence of [`ArrayRef`] chunks
+///
+/// This is the equivalent of the `ChunkedArray` of the C++ and Python Arrow implementations.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, BooleanArray, Int32Array};
+/// # use arrow_schema::DataType;
+/// # use arrow_select::chunked::ChunkedArray;
+/// let chunks: Vec<ArrayRef> = vec![
+///     Arc::new(Int32Array::from(vec![1, 2, 3])),
+///     Arc::new(Int32Array::from(vec![4, 5])),
+/// ];
+/// let array = ChunkedArray::try_new(DataType::Int32, chunks).unwrap();
+/// assert_eq!(array.len(), 5);
+///
+/// // Slicing returns zero-copy slices of the chunks spanned
+/// let sliced = array.slice(2, 2);
+/// assert_eq!(sliced.num_chunks(), 2);
+///
+/// let predicate = BooleanArray::from(vec![true, false, true, false, true]);
+/// let filtered = array.filter(&predicate).unwrap();
+/// assert_eq!(filtered.concat().unwrap().as_ref(), &Int32Array::from(vec![1, 3, 5]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ChunkedArray {
+    data_type: DataType,
+    chunks: Vec<ArrayRef>,
+}
+
+impl ChunkedArray {
+    /// Create a new [`ChunkedArray`] of `data_type` from `chunks`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of `chunks` is not of `data_type`
+    pub fn try_new(data_type: DataType, chunks: Vec<ArrayRef>) -> Result<Self, ArrowError> {
+        if let Some(chunk) = chunks.iter().find(|c| c.data_type() != &data_type) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Chunk of type {} does not match ChunkedArray of type {data_type}",
+                chunk.data_type()
+            )));
+        }
+        Ok(Self { data_type, chunks })
+    }
+
+    /// Create a new [`ChunkedArray`] of `data_type` with no chunks
+    pub fn new_empty(data_type: DataType) -> Self {
+        Self {
+            data_type,
+            chunks: vec![],
+        }
+    }
+
+    /// Returns the [`DataType`] of this [`ChunkedArray`]
+    pub fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    /// Returns the chunks of this [`ChunkedArray`]
+    pub fn chunks(&self) -> &[ArrayRef] {
+        &self.chunks
+    }
+
+    /// Returns the number of chunks of this [`ChunkedArray`]
+    pub fn num_chunks(&self) -> usize {
+        self.chunks.len()
+    }
+
+    /// Returns the total number of values in this [`ChunkedArray`]
+    pub fn len(&self) -> usize {
+        self.chunks.iter().map(|c| c.len()).sum()
+    }
+
+    /// Returns true if this [`ChunkedArray`] contains no values
+    pub fn is_empty(&self) -> bool {
+        self.chunks.iter().all(|c| c.is_empty())
+    }
+
+    /// Returns the total number of null values in this [`ChunkedArray`]
+    pub fn null_count(&self) -> usize {
+        self.chunks.iter().map(|c| c.null_count()).sum()
+    }
+
+    /// Returns an iterator over the chunks of this [`ChunkedArray`]
+    pub fn iter(&self) -> std::slice::Iter<'_, ArrayRef> {
+        self.chunks.iter()
+    }
+
+    /// Consumes this [`ChunkedArray`], returning its chunks
+    pub fn into_chunks(self) -> Vec<ArrayRef> {
+        self.chunks
+    }
+
+    /// Returns a zero-copy slice of `length` values starting at `offset`
+    ///
+    /// The result contains a slice of each chunk spanned by the range
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset + length > self.len()`
+    pub fn slice(&self, offset: usize, length: usize) -> Self {
+        let chunks = slice_ranges(self.chunks.iter().map(|c| c.len()), offset, length)
+            .into_iter()
+            .map(|(idx, offset, length)| self.chunks[idx].slice(offset, length))
+            .collect();
+        Self {
+            data_type: self.data_type.clone(),
+            chunks,
+        }
+    }
+
+    /// Returns the values selected by `predicate`, see [`filter`]
+    ///
+    /// Each chunk is filtered by the corresponding slice of `predicate`, with chunks
+    /// containing no selected values omitted from the result
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `predicate` does not have the same length as this [`ChunkedArray`]
+    pub fn filter(&self, predicate: &BooleanArray) -> Result<Self, ArrowError> {
+        let mut chunks = filter_chunks(
+            &self.chunks,
+            predicate,
+            |c| c.len(),
+            |c, p| filter(c.as_ref(), p),
+        )?;
+        chunks.retain(|c| !c.is_empty());
+        Ok(Self {
+            data_type: self.data_type.clone(),
+            chunks,
+        })
+    }
+
+    /// Returns the values at `indices`, see [`take`](crate::take::take)
+    ///
+    /// `indices` must be an array of integers, indexing into the values of all chunks as
+    /// if they were a single array. Null indices produce null values. The result has a
+    /// single chunk.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `indices` is not an array of integers or an index is out of bounds
+    pub fn take(&self, indices: &dyn Array) -> Result<Self, ArrowError> {
+        let locations = locate(self.chunks.iter().map(|c| c.len()), indices)?;
+        let chunks: Vec<_> = self.chunks.iter().map(|c| c.as_ref()).collect();
+        let chunk = take_chunks(&self.data_type, &chunks, &locations)?;
+        Ok(Self {
+            data_type: self.data_type.clone(),
+            chunks: vec![chunk],
+        })
+    }
+
+    /// Concatenates the chunks of this [`ChunkedArray`] into a single array, see [`concat()`]
+    pub fn concat(&self) -> Result<ArrayRef, ArrowError> {
+        match self.chunks.as_slice() {
+            [] => Ok(new_empty_array(&self.data_type)),
+            [chunk] => Ok(Arc::clone(chunk)),
+            chunks => {
+                let chunks: Vec<_> = chunks.iter().map(|c| c.as_ref()).collect();
+                concat(&chunks)
+            }
+        }
+    }
+}
+
+impl From<ArrayRef> for ChunkedArray {
+    fn from(array: ArrayRef) -> Self {
+        Self {
+            data_type: array.data_type().clone(),
+            chunks: vec![array],
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a ChunkedArray {
+    type Item = &'a ArrayRef;
+    type IntoIter = std::slice::Iter<'a, ArrayRef>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// A logical [`RecordBatch`] made up of a sequence of [`RecordBatch`] with the same schema
+///
+/// This is the equivalent of the `Table` of the C++ and Python Arrow implementations.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array, RecordBatch, UInt32Array};
+/// # use arrow_select::chunked::Table;
+/// let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+/// let b: ArrayRef = Arc::new(Int32Array::from(vec![4, 5]));
+/// let batches = vec![
+///     RecordBatch::try_from_iter([("a", a)]).unwrap(),
+///     RecordBatch::try_from_iter([("a", b)]).unwrap(),
+/// ];
+/// let table = Table::try_new(batches[0].schema(), batches).unwrap();
+/// assert_eq!(table.num_rows(), 5);
+///
+/// let taken = table.take(&UInt32Array::from(vec![4, 0])).unwrap();
+/// let column = taken.column(0).concat().unwrap();
+/// assert_eq!(column.as_ref(), &Int32Array::from(vec![5, 1]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Table {
+    schema: SchemaRef,
+    batches: Vec<RecordBatch>,
+}
+
+impl Table {
+    /// Create a new [`Table`] with `schema` from `batches`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the schema of any of `batches` is not `schema`
+    pub fn try_new(schema: SchemaRef, batches: Vec<RecordBatch>) -> Result<Self, ArrowError> {
+        if let Some(batch) = batches.iter().find(|b| b.schema_ref() != &schema) {
+            return Err(ArrowError::SchemaError(format!(
+                "Batch schema {} does not match Table schema {schema}",
+                batch.schema_ref()
+            )));
+        }
+        Ok(Self { schema, batches })
+    }
+
+    /// Create a new [`Table`] with `schema` and no batches
+    pub fn new_empty(schema: SchemaRef) -> Self {
+        Self {
+            schema,
+            batches: vec![],
+        }
+    }
+
+    /// Create a new [`Table`] from the batches read from `reader`
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error returned by `reader`
+    pub fn try_from_reader(reader: impl RecordBatchReader) -> Result<Self, ArrowError> {
+        let schema = reader.schema();
+        let batches = reader.collect::<Result<_, _>>()?;
+        Self::try_new(schema, batches)
+    }
+
+    /// Returns the schema of this [`Table`]
+    pub fn schema(&self) -> SchemaRef {
+        Arc::clone(&self.schema)
+    }
+
+    /// Returns a reference to the schema of this [`Table`]
+    pub fn schema_ref(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    /// Returns the batches of this [`Table`]
+    pub fn batches(&self) -> &[RecordBatch] {
+        &self.batches
+    }
+
+    /// Returns the number of batches of this [`Table`]
+    pub fn num_batches(&self) -> usize {
+        self.batches.len()
+    }
+
+    /// Returns the total number of rows in this [`Table`]
+    pub fn num_rows(&self) -> usize {
+        self.batches.iter().map(|b| b.num_rows()).sum()
+    }
+
+    /// Returns the number of columns in this [`Table`]
+    pub fn num_columns(&self) -> usize {
+        self.schema.fields().len()
+    }
+
+    /// Returns the column at position `index` as a [`ChunkedArray`] with a chunk per batch
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds
+    pub fn column(&self, index: usize) -> ChunkedArray {
+        ChunkedArray {
+            data_type: self.schema.field(index).data_type().clone(),
+            chunks: self
+                .batches
+                .iter()
+                .map(|b| Arc::clone(b.column(index)))
+                .collect(),
+        }
+    }
+
+    /// Returns the column named `name` as a [`ChunkedArray`], if any
+    pub fn column_by_name(&self, name: &str) -> Option<ChunkedArray> {
+        let (index, _) = self.schema.column_with_name(name)?;
+        Some(self.column(index))
+    }
+
+    /// Returns an iterator over the batches of this [`Table`]
+    pub fn iter(&self) -> std::slice::Iter<'_, RecordBatch> {
+        self.batches.iter()
+    }
+
+    /// Consumes this [`Table`], returning its batches
+    pub fn into_batches(self) -> Vec<RecordBatch> {
+        self.batches
+    }
+
+    /// Returns a zero-copy slice of `length` rows starting at `offset`
+    ///
+    /// The result contains a slice of each batch spanned by the range
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset + length > self.num_rows()`
+    pub fn slice(&self, offset: usize, length: usize) -> Self {
+        let batches = slice_ranges(self.batches.iter().map(|b| b.num_rows()), offset, length)
+            .into_iter()
+            .map(|(idx, offset, length)| self.batches[idx].slice(offset, length))
+            .collect();
+        Self {
+            schema: self.schema(),
+            batches,
+        }
+    }
+
+    /// Returns the rows selected by `predicate`, see [`filter_record_batch`]
+    ///
+    /// Each batch is filtered by the corresponding slice of `predicate`, with batches
+    /// containing no selected rows omitted from the result
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `predicate` does not have the same length as this [`Table`]
+    pub fn filter(&self, predicate: &BooleanArray) -> Result<Self, ArrowError> {
+        let mut batches = filter_chunks(
+            &self.batches,
+            predicate,
+            |b| b.num_rows(),
+            filter_record_batch,
+        )?;
+        batches.retain(|b| b.num_rows() != 0);
+        Ok(Self {
+            schema: self.schema(),
+            batches,
+        })
+    }
+
+    /// Returns the rows at `indices`, see [`take_record_batch`](crate::take::take_record_batch)
+    ///
+    /// `indices` must be an array of integers, indexing into the rows of all batches as
+    /// if they were a single batch. Null indices produce rows of null values. The result
+    /// has a single batch.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `indices` is not an array of integers or an index is out of bounds
+    pub fn take(&self, indices: &dyn Array) -> Result<Self, ArrowError> {
+        let locations = locate(self.batches.iter().map(|b| b.num_rows()), indices)?;
+        let columns = self
+            .schema
+            .fields()
+            .iter()
+            .enumerate()
+            .map(|(idx, field)| {
+                let chunks: Vec<_> = self
+                    .batches
+                    .iter()
+                    .map(|b| b.column(idx).as_ref())
+                    .collect();
+                take_chunks(field.data_type(), &chunks, &locations)
+            })
+            .collect::<Result<_, _>>()?;
+
+        let options = RecordBatchOptions::new().with_row_count(Some(locations.len()));
+        let batch = RecordBatch::try_new_with_options(self.schema(), columns, &options)?;
+        Ok(Self {
+            schema: self.schema(),
+            batches: vec![batch],
+        })
+    }
+
+    /// Concatenates the batches of this [`Table`] into a single batch, see [`concat_batches`]
+    pub fn concat(&self) -> Result<RecordBatch, ArrowError> {
+        match self.batches.as_slice() {
+            [batch] => Ok(batch.clone()),
+            batches => concat_batches(&self.schema, batches),
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a Table {
+    type Item = &'a RecordBatch;
+    type IntoIter = std::slice::Iter<'a, RecordBatch>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Returns the `(chunk, offset, length)` of each non-empty part of the chunks with lengths
+/// `lens` spanned by `length` values starting at `offset`
+fn slice_ranges(
+    lens: impl Iterator<Item = usize>,
+    offset: usize,
+    length: usize,
+) -> Vec<(usize, usize, usize)> {
+    let mut ranges = vec![];
+    let mut start = 0;
+    let end = offset + length;
+    for (idx, len) in lens.enumerate() {
+        let chunk_end = start + len;
+        if start.max(offset) < end.min(chunk_end) {
+            let chunk_offset = offset.saturating_sub(start);
+            ranges.push((idx, chunk_offset, end.min(chunk_end) - start - chunk_offset));
+        }
+        start = chunk_end;
+    }
+    assert!(
+        end <= start,
+        "the length + offset of the sliced ChunkedArray cannot exceed the existing length"
+    );
+    ranges
+}
+
+/// Filters each of `chunks` with the corresponding slice of `predicate`
+fn filter_chunks<T>(
+    chunks: &[T],
+    predicate: &BooleanArray,
+    len: impl Fn(&T) -> usize,
+    filter: impl Fn(&T, &BooleanArray) -> Result<T, ArrowError>,
+) -> Result<Vec<T>, ArrowError> {
+    let total = chunks.iter().map(&len).sum::<usize>();
+    if predicate.len() != total {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Filter predicate of length {} does not match length {total}",
+            predicate.len()
+        )));
+    }
+    let mut offset = 0;
+    chunks
+        .iter()
+        .map(|chunk| {
+            let len = len(chunk);
+            let predicate = predicate.slice(offset, len);
+            offset += len;
+            filter(chunk, &predicate)
+        })
+        .collect()
+}
+
+/// Returns the `(chunk, index)` of each of `indices` into the chunks with lengths `lens`,
+/// or `None` for null indices
+fn locate(
+    lens: impl Iterator<Item = usize>,
+    indices: &dyn Array,
+) -> Result<Vec<Option<(usize, usize)>>, ArrowError> {
+    // The index of the first value of each chunk
+    let mut starts = vec![];
+    let mut total = 0;
+    for len in lens {
+        starts.push(total);
+        total += len;
+    }
+
+    downcast_integer_array!(
+        indices => indices
+            .iter()
+            .map(|index| {
+                index
+                    .map(|index| {
+                        let i = index.to_usize().filter(|i| *i < total).ok_or_else(|| {
+                            ArrowError::ComputeError(format!(
+                                "Array index out of bounds, cannot get item at index {index} from {total} entries"
+                            ))
+                        })?;
+                        // The last chunk starting at or before `i`, skipping empty chunks
+                        let chunk = starts.partition_point(|s| *s <= i) - 1;
+                        Ok((chunk, i - starts[chunk]))
+                    })
+                    .transpose()
+            })
+            .collect(),
+        d => Err(ArrowError::InvalidArgumentError(format!(
+            "Indices must be an array of integers, got {d}"
+        )))
+    )
+}
+
+/// Returns the values of `chunks` at `locations`, with `None` producing a null value
+fn take_chunks(
+    data_type: &DataType,
+    chunks: &[&dyn Array],
+    locations: &[Option<(usize, usize)>],
+) -> Result<ArrayRef, ArrowError> {
+    if locations.is_empty() {
+        return Ok(new_empty_array(data_type));
+    }
+    // Null indices take the value of an additional chunk containing a single null
+    let nulls = new_null_array(data_type, 1);
+    let mut values = chunks.to_vec();
+    values.push(nulls.as_ref());
+    let indices: Vec<_> = locations
+        .iter()
+        .map(|l| l.unwrap_or((chunks.len(), 0)))
+        .collect();
+    interleave(&values, &indices)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int32Type;
+    use arrow_array::{Int32Array, Int64Array, StringArray, UInt8Array};
+    use arrow_schema::{Field, Schema};
+
+    fn int32(values: &[Option<i32>]) -> ArrayRef {
+        Arc::new(Int32Array::from(values.to_vec()))
+    }
+
+    fn values(array: &ChunkedArray) -> Vec<Option<i32>> {
+        array
+            .iter()
+            .flat_map(|c| c.as_primitive::<Int32Type>().iter())
+            .collect()
+    }
+
+    #[test]
+    fn test_chunked_array() {
+        let chunks = vec![
+            int32(&[Some(0), None, Some(2)]),
+            int32(&[]),
+            int32(&[Some(3), Some(4)]),
+            int32(&[None]),
+        ];
+        let array = ChunkedArray::try_new(DataType::Int32, chunks).unwrap();
+        assert_eq!(array.len(), 6);
+        assert_eq!(array.null_count(), 2);
+        assert_eq!(array.num_chunks(), 4);
+
+        let sliced = array.slice(1, 4);
+        let lens: Vec<_> = sliced.iter().map(|c| c.len()).collect();
+        assert_eq!(lens, vec![2, 2]);
+        assert_eq!(values(&sliced), vec![None, Some(2), Some(3), Some(4)]);
+        assert_eq!(array.slice(4, 0).num_chunks(), 0);
+        assert_eq!(array.slice(0, 6).num_chunks(), 3);
+
+        let predicate = BooleanArray::from(vec![true, true, false, false, true, true]);
+        let filtered = array.filter(&predicate).unwrap();
+        assert_eq!(filtered.num_chunks(), 3);
+        assert_eq!(values(&filtered), vec![Some(0), None, Some(4), None]);
+
+        let predicate = BooleanArray::from(vec![false, false, false, true, true, false]);
+        let filtered = array.filter(&predicate).unwrap();
+        assert_eq!(filtered.num_chunks(), 1);
+        assert_eq!(values(&filtered), vec![Some(3), Some(4)]);
+
+        let err = array.filter(&BooleanArray::from(vec![true])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Filter predicate of length 1 does not match length 6"
+        );
+
+        let indices = Int64Array::from(vec![Some(5), None, Some(3), Some(0), Some(2)]);
+        let taken = array.take(&indices).unwrap();
+        assert_eq!(taken.num_chunks(), 1);
+        assert_eq!(values(&taken), vec![None, None, Some(3), Some(0), Some(2)]);
+        assert_eq!(array.take(&UInt8Array::from(vec![0; 0])).unwrap().len(), 0);
+
+        let err = array.take(&Int64Array::from(vec![6])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Array index out of bounds, cannot get item at index 6 from 6 entries"
+        );
+        let err = array.take(&Int64Array::from(vec![-1])).unwrap_err();
+        assert!(err.to_string().contains("cannot get item at index -1"));
+        let err = array.take(&StringArray::from(vec!["a"])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Indices must be an array of integers, got Utf8"
+        );
+
+        let concat = array.concat().unwrap();
+        assert_eq!(concat.len(), 6);
+        let empty = ChunkedArray::new_empty(DataType::Int32);
+        assert_eq!(empty.concat().unwrap().len(), 0);
+        assert!(empty.take(&Int64Array::from(vec![None])).is_ok());
+
+        let err = ChunkedArray::try_new(DataType::Int64, vec![int32(&[])]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Chunk of type Int32 does not match ChunkedArray of type Int64"
+        );
+    }
+
+    #[test]
+    fn test_table() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ]));
+        let batch = |a: &[Option<i32>], b: Vec<&str>| {
+            let b = Arc::new(StringArray::from(b));
+            RecordBatch::try_new(schema.clone(), vec![int32(a), b]).unwrap()
+        };
+        let batches = vec![
+            batch(&[Some(0), Some(1)], vec!["x", "y"]),
+            batch(&[None, Some(3), Some(4)], vec!["z", "w", "v"]),
+        ];
+        let table = Table::try_new(schema.clone(), batches).unwrap();
+        assert_eq!(table.num_rows(), 5);
+        assert_eq!(table.num_columns(), 2);
+        assert_eq!(
+            values(&table.column(0)),
+            vec![Some(0), Some(1), None, Some(3), Some(4)]
+        );
+        assert_eq!(table.column_by_name("b").unwrap().num_chunks(), 2);
+        assert!(table.column_by_name("c").is_none());
+
+        let sliced = table.slice(1, 2);
+        assert_eq!(sliced.num_batches(), 2);
+        assert_eq!(values(&sliced.column(0)), vec![Some(1), None]);
+
+        let predicate =
+            BooleanArray::from(vec![Some(false), None, Some(true), Some(false), Some(true)]);
+        let filtered = table.filter(&predicate).unwrap();
+        assert_eq!(filtered.num_batches(), 1);
+        assert_eq!(values(&filtered.column(0)), vec![None, Some(4)]);
+
+        let taken = table
+            .take(&Int32Array::from(vec![Some(4), None, Some(1)]))
+            .unwrap();
+        assert_eq!(taken.num_batches(), 1);
+        assert_eq!(values(&taken.column(0)), vec![Some(4), None, Some(1)]);
+        let b = taken.batches()[0].column(1).as_string::<i32>();
+        assert_eq!(
+            b.iter().collect::<Vec<_>>(),
+            vec![Some("v"), None, Some("y")]
+        );
+
+        let concat = table.concat().unwrap();
+        assert_eq!(concat.num_rows(), 5);
+        assert_eq!(
+            Table::new_empty(schema.clone())
+                .concat()
+                .unwrap()
+                .num_rows(),
+            0
+        );
+
+        let other = RecordBatch::try_from_iter([("a", int32(&[]))]).unwrap();
+        let err = Table::try_new(schema, vec![other]).unwrap_err();
+        assert!(err.to_string().starts_with("Schema error: Batch schema"));
+    }
+
+    #[test]
+    fn test_table_no_columns() {
+        let schema = Arc::new(Schema::empty());
+        let options = RecordBatchOptions::new().with_row_count(Some(3));
+        let batch = RecordBatch::try_new_with_options(schema.clone(), vec![], &options).unwrap();
+        let table = Table::try_new(schema, vec![batch.clone(), batch]).unwrap();
+        assert_eq!(table.num_rows(), 6);
+        assert_eq!(table.slice(2, 3).num_rows(), 3);
+        let taken = table.take(&Int32Array::from(vec![5, 0])).unwrap();
+        assert_eq!(taken.num_rows(), 2);
+    }
+}
@@ -42,8 +42,10 @@ use arrow_buffer::{
 };
 use arrow_data::ArrayDataBuilder;
 use arrow_data::transform::{Capacities, MutableArrayData};
-use arrow_schema::{ArrowError, DataType, FieldRef, Fields, SchemaRef};
-use std::{collections::HashSet, ops::Add, sync::Arc};
+use arrow_schema::{ArrowError, DataType, Field, FieldRef, Fields, Schema, SchemaRef};
+use num_traits::AsPrimitive;
+use std::collections::{HashMap, HashSet};
+use std::{ops::Add, sync::Arc};
 
 fn binary_capacity<T: ByteArrayType>(arrays: &[&dyn Array]) -> Capacities {
     let mut item_capacity = 0;
@@ -127,13 +129,18 @@ fn concat_dictionaries<K: ArrowDictionaryKeyType>(
 
     let nulls = has_nulls.then(|| {
         let mut nulls = BooleanBufferBuilder::new(output_len);
+        let mut null_count = 0;
         for d in &dictionaries {
             match d.nulls() {
-                Some(n) => nulls.append_buffer(n.inner()),
+                Some(n) => {
+                    null_count += n.null_count();
+                    nulls.append_buffer(n.inner())
+                }
                 None => nulls.append_n(d.len(), true),
             }
         }
-        NullBuffer::new(nulls.finish())
+        // SAFETY: the null count is the sum of those of the concatenated buffers
+        unsafe { NullBuffer::new_unchecked(nulls.finish(), null_count) }
     });
 
     let keys = PrimitiveArray::<K>::try_new(key_values.into(), nulls)?;
@@ -165,13 +172,18 @@ fn concat_lists<OffsetSize: OffsetSizeTrait>(
 
     let lists_nulls = list_has_nulls.then(|| {
         let mut nulls = BooleanBufferBuilder::new(output_len);
+        let mut null_count = 0;
         for l in &lists {
             match l.nulls() {
-                Some(n) => nulls.append_buffer(n.inner()),
+                Some(n) => {
+                    null_count += n.null_count();
+                    nulls.append_buffer(n.inner())
+                }
                 None => nulls.append_n(l.len(), true),
             }
         }
-        NullBuffer::new(nulls.finish())
+        // SAFETY: the null count is the sum of those of the concatenated buffers
+        unsafe { NullBuffer::new_unchecked(nulls.finish(), null_count) }
     });
 
     // If any of the lists have slices, we need to slice the values
@@ -230,13 +242,18 @@ fn concat_maps(
 
     let map_nulls = map_has_nulls.then(|| {
         let mut nulls = BooleanBufferBuilder::new(output_len);
+        let mut null_count = 0;
         for m in &maps {
             match m.nulls() {
-                Some(n) => nulls.append_buffer(n.inner()),
+                Some(n) => {
+                    null_count += n.null_count();
+                    nulls.append_buffer(n.inner())
+                }
                 None => nulls.append_n(m.len(), true),
             }
         }
-        NullBuffer::new(nulls.finish())
+        // SAFETY: the null count is the sum of those of the concatenated buffers
+        unsafe { NullBuffer::new_unchecked(nulls.finish(), null_count) }
     });
 
     // If any of the maps have slices, we need to slice the entries
@@ -292,13 +309,18 @@ fn concat_list_view<OffsetSize: OffsetSizeTrait>(
 
     let lists_nulls = list_has_nulls.then(|| {
         let mut nulls = BooleanBufferBuilder::new(output_len);
+        let mut null_count = 0;
         for l in &lists {
             match l.nulls() {
-                Some(n) => nulls.append_buffer(n.inner()),
+                Some(n) => {
+                    null_count += n.null_count();
+                    nulls.append_buffer(n.inner())
+                }
                 None => nulls.append_n(l.len(), true),
             }
         }
-        NullBuffer::new(nulls.finish())
+        // SAFETY: the null count is the sum of those of the concatenated buffers
+        unsafe { NullBuffer::new_unchecked(nulls.finish(), null_count) }
     });
 
     let values: Vec<&dyn Array> = lists.iter().map(|l| l.values().as_ref()).collect();
@@ -381,13 +403,18 @@ fn concat_structs(arrays: &[&dyn Array], fields: &Fields) -> Result<ArrayRef, Ar
 
     let nulls = has_nulls.then(|| {
         let mut b = BooleanBufferBuilder::new(len);
+        let mut null_count = 0;
         for s in &structs {
             match s.nulls() {
-                Some(n) => b.append_buffer(n.inner()),
+                Some(n) => {
+                    null_count += n.null_count();
+                    b.append_buffer(n.inner())
+                }
                 None => b.append_n(s.len(), true),
             }
         }
-        NullBuffer::new(b.finish())
+        // SAFETY: the null count is the sum of those of the concatenated buffers
+        unsafe { NullBuffer::new_unchecked(b.finish(), null_count) }
     });
 
     let column_concat_result = (0..fields.len())
@@ -492,6 +519,10 @@ fn get_capacity(arrays: &[&dyn Array], data_type: &DataType) -> Capacities {
 }
 
 /// Concatenate multiple [Array] of the same type into a single [ArrayRef].
+///
+/// For dictionary, list, map and struct arrays the null count of the output is
+/// the sum of the null counts of the inputs, so the concatenated validity
+/// bitmap is not counted again.
 pub fn concat(arrays: &[&dyn Array]) -> Result<ArrayRef, ArrowError> {
     if arrays.is_empty() {
         return Err(ArrowError::ComputeError(
@@ -634,6 +665,327 @@ pub fn concat_batches<'a>(
     RecordBatch::try_new(schema.clone(), arrays)
 }
 
+/// How the metadata of the schemas and fields of batches is combined by
+/// [`concat_batches_with_options`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MetadataMode {
+    /// Return an error if the metadata of the batches differs
+    #[default]
+    Strict,
+    /// Discard the metadata, the output has no metadata
+    Discard,
+    /// Merge the metadata, returning an error if a key has conflicting values
+    Merge,
+}
+
+/// Options that define how [`concat_batches_with_options`] combines the schemas of batches
+#[derive(Debug, Clone, Default)]
+pub struct ConcatBatchesOptions {
+    /// How the schema and field metadata of the batches is combined
+    pub metadata: MetadataMode,
+    /// Whether to coerce columns of different types to a common type
+    ///
+    /// If enabled, numeric columns are widened to a type able to represent the values
+    /// of all batches, the keys and values of dictionary columns are coerced likewise,
+    /// and columns of [`DataType::Null`] are coerced to the type of the other batches.
+    /// If not enabled, the types of the columns of all batches must be equal.
+    pub coerce_types: bool,
+}
+
+/// Concatenates `batches` together into a single [`RecordBatch`], deriving the
+/// output schema from the schemas of the batches
+///
+/// Unlike [`concat_batches`], the schemas of the batches may differ as allowed by
+/// `options`. The batches must have the same number of columns with the same names,
+/// and a column of the output is nullable if it is nullable in any batch.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{Array, Int32Array, Int64Array, RecordBatch};
+/// # use arrow_schema::DataType;
+/// # use arrow_select::concat::{ConcatBatchesOptions, concat_batches_with_options};
+/// let a = RecordBatch::try_from_iter([("a", Arc::new(Int32Array::from(vec![1, 2])) as _)]).unwrap();
+/// let b = RecordBatch::try_from_iter([("a", Arc::new(Int64Array::from(vec![3])) as _)]).unwrap();
+///
+/// let options = ConcatBatchesOptions {
+///     coerce_types: true,
+///     ..Default::default()
+/// };
+/// let batch = concat_batches_with_options([&a, &b], &options).unwrap();
+/// assert_eq!(batch.column(0).data_type(), &DataType::Int64);
+/// assert_eq!(batch.num_rows(), 3);
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if `batches` is empty, or if the schemas of the batches cannot be
+/// combined, reporting every incompatible column.
+pub fn concat_batches_with_options<'a>(
+    batches: impl IntoIterator<Item = &'a RecordBatch>,
+    options: &ConcatBatchesOptions,
+) -> Result<RecordBatch, ArrowError> {
+    let batches: Vec<&RecordBatch> = batches.into_iter().collect();
+    let schema = unify_schemas(&batches, options)?;
+
+    if schema.fields().is_empty() {
+        return concat_batches(&schema, batches);
+    }
+    let mut arrays = Vec::with_capacity(schema.fields().len());
+    for (i, field) in schema.fields().iter().enumerate() {
+        let columns = batches
+            .iter()
+            .map(|batch| coerce_array(batch.column(i), field.data_type()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let columns: Vec<_> = columns.iter().map(|c| c.as_ref()).collect();
+        arrays.push(concat(&columns)?);
+    }
+    RecordBatch::try_new(schema, arrays)
+}
+
+/// Returns the schema of the concatenation of `batches`
+fn unify_schemas(
+    batches: &[&RecordBatch],
+    options: &ConcatBatchesOptions,
+) -> Result<SchemaRef, ArrowError> {
+    let Some((first, rest)) = batches.split_first() else {
+        return Err(ArrowError::InvalidArgumentError(
+            "concat_batches_with_options requires at least one batch".to_string(),
+        ));
+    };
+    let first = first.schema();
+    let mut fields: Vec<Field> = first.fields().iter().map(|f| f.as_ref().clone()).collect();
+    let mut metadata = first.metadata().clone();
+    let mut errors = vec![];
+
+    for batch in rest {
+        let schema = batch.schema();
+        if schema.fields().len() != fields.len() {
+            return Err(ArrowError::SchemaError(format!(
+                "Cannot concatenate batches with {} and {} columns",
+                fields.len(),
+                schema.fields().len()
+            )));
+        }
+        if let Err(e) = merge_metadata(&mut metadata, schema.metadata(), options.metadata) {
+            errors.push(format!("schema: {e}"));
+        }
+        for (i, (field, other)) in fields.iter_mut().zip(schema.fields()).enumerate() {
+            if let Err(e) = merge_field(field, other, options) {
+                errors.push(format!("column {i} \"{}\": {e}", field.name()));
+            }
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(ArrowError::SchemaError(format!(
+            "Cannot concatenate batches with incompatible schemas: {}",
+            errors.join(", ")
+        )));
+    }
+    if options.metadata == MetadataMode::Discard {
+        metadata.clear();
+        fields
+            .iter_mut()
+            .for_each(|f| f.set_metadata(HashMap::new()));
+    }
+    Ok(Arc::new(Schema::new_with_metadata(fields, metadata)))
+}
+
+/// Merges `other` into `field`
+fn merge_field(
+    field: &mut Field,
+    other: &Field,
+    options: &ConcatBatchesOptions,
+) -> Result<(), String> {
+    if field.name() != other.name() {
+        return Err(format!("name differs from \"{}\"", other.name()));
+    }
+    let data_type = match options.coerce_types {
+        true => coerce_types(field.data_type(), other.data_type()),
+        false => (field.data_type() == other.data_type()).then(|| other.data_type().clone()),
+    };
+    let Some(data_type) = data_type else {
+        return Err(format!(
+            "incompatible types {} and {}",
+            field.data_type(),
+            other.data_type()
+        ));
+    };
+    let mut metadata = field.metadata().clone();
+    merge_metadata(&mut metadata, other.metadata(), options.metadata)?;
+
+    field.set_data_type(data_type);
+    field.set_nullable(field.is_nullable() || other.is_nullable());
+    field.set_metadata(metadata);
+    Ok(())
+}
+
+/// Merges the metadata `other` into `metadata` as specified by `mode`
+fn merge_metadata(
+    metadata: &mut HashMap<String, String>,
+    other: &HashMap<String, String>,
+    mode: MetadataMode,
+) -> Result<(), String> {
+    match mode {
+        MetadataMode::Strict if metadata != other => Err("metadata differs".to_string()),
+        MetadataMode::Merge => {
+            for (key, value) in other {
+                match metadata.get(key) {
+                    Some(v) if v != value => {
+                        return Err(format!(
+                            "metadata key \"{key}\" has conflicting values \"{v}\" and \"{value}\""
+                        ));
+                    }
+                    Some(_) => {}
+                    None => {
+                        metadata.insert(key.clone(), value.clone());
+                    }
+                }
+            }
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Returns the type both `a` and `b` can be coerced to without loss, if any
+fn coerce_types(a: &DataType, b: &DataType) -> Option<DataType> {
+    match (a, b) {
+        _ if a == b => Some(a.clone()),
+        (DataType::Null, t) | (t, DataType::Null) => Some(t.clone()),
+        (DataType::Dictionary(a_key, a_value), DataType::Dictionary(b_key, b_value)) => {
+            let key = coerce_types(a_key, b_key).filter(|k| k.is_dictionary_key_type())?;
+            let value = coerce_types(a_value, b_value)?;
+            Some(DataType::Dictionary(Box::new(key), Box::new(value)))
+        }
+        _ => coerce_numeric(a, b),
+    }
+}
+
+/// Returns the numeric type able to exactly represent the values of both `a` and `b`
+fn coerce_numeric(a: &DataType, b: &DataType) -> Option<DataType> {
+    use DataType::*;
+
+    // The kind of a numeric type, 0 for unsigned, 1 for signed and 2 for floating
+    // point, and its width in bits
+    let info = |t: &DataType| match t {
+        UInt8 => Some((0, 8)),
+        UInt16 => Some((0, 16)),
+        UInt32 => Some((0, 32)),
+        UInt64 => Some((0, 64)),
+        Int8 => Some((1, 8)),
+        Int16 => Some((1, 16)),
+        Int32 => Some((1, 32)),
+        Int64 => Some((1, 64)),
+        Float32 => Some((2, 32)),
+        Float64 => Some((2, 64)),
+        _ => None,
+    };
+    let (a, b) = (info(a)?, info(b)?);
+    let (kind, bits) = match (a.min(b), a.max(b)) {
+        ((k1, b1), (k2, b2)) if k1 == k2 => (k1, b1.max(b2)),
+        // Unsigned and signed integers, requiring a wider signed type than the unsigned
+        ((0, u), (1, s)) => (1, s.max(u * 2)),
+        // Floats represent integers of up to half their width exactly
+        ((_, i), (2, f)) => (2, f.max(i * 2)),
+        _ => unreachable!(),
+    };
+    Some(match (kind, bits) {
+        (0, 8) => UInt8,
+        (0, 16) => UInt16,
+        (0, 32) => UInt32,
+        (0, 64) => UInt64,
+        (1, 8) => Int8,
+        (1, 16) => Int16,
+        (1, 32) => Int32,
+        (1, 64) => Int64,
+        (2, 32) => Float32,
+        (2, 64) => Float64,
+        _ => return None,
+    })
+}
+
+/// Coerces `array` to `data_type`, which must have been returned by [`coerce_types`]
+fn coerce_array(array: &ArrayRef, data_type: &DataType) -> Result<ArrayRef, ArrowError> {
+    if array.data_type() == data_type {
+        return Ok(Arc::clone(array));
+    }
+    match data_type {
+        _ if array.data_type() == &DataType::Null => Ok(new_null_array(data_type, array.len())),
+        DataType::Dictionary(key, value) => {
+            let dict = array.as_any_dictionary();
+            let keys = coerce_array(&make_array(dict.keys().to_data()), key)?;
+            let values = coerce_array(dict.values(), value)?;
+            macro_rules! dict_helper {
+                ($t:ty) => {
+                    Arc::new(DictionaryArray::<$t>::try_new(
+                        keys.as_primitive::<$t>().clone(),
+                        values,
+                    )?)
+                };
+            }
+            Ok(downcast_integer! {
+                key.as_ref() => (dict_helper),
+                _ => unreachable!("illegal dictionary key type {key}")
+            })
+        }
+        DataType::UInt16 => widen_to::<UInt16Type>(array),
+        DataType::UInt32 => widen_to::<UInt32Type>(array),
+        DataType::UInt64 => widen_to::<UInt64Type>(array),
+        DataType::Int16 => widen_to::<Int16Type>(array),
+        DataType::Int32 => widen_to::<Int32Type>(array),
+        DataType::Int64 => widen_to::<Int64Type>(array),
+        DataType::Float32 => widen_to::<Float32Type>(array),
+        DataType::Float64 => widen_to::<Float64Type>(array),
+        _ => Err(ArrowError::InvalidArgumentError(format!(
+            "Cannot coerce {} to {data_type}",
+            array.data_type()
+        ))),
+    }
+}
+
+/// Converts the numeric `array` to an array of `O`
+fn widen_to<O>(array: &ArrayRef) -> Result<ArrayRef, ArrowError>
+where
+    O: ArrowPrimitiveType,
+    u8: AsPrimitive<O::Native>,
+    u16: AsPrimitive<O::Native>,
+    u32: AsPrimitive<O::Native>,
+    u64: AsPrimitive<O::Native>,
+    i8: AsPrimitive<O::Native>,
+    i16: AsPrimitive<O::Native>,
+    i32: AsPrimitive<O::Native>,
+    i64: AsPrimitive<O::Native>,
+    f32: AsPrimitive<O::Native>,
+{
+    fn widen<I, O>(array: &ArrayRef) -> ArrayRef
+    where
+        I: ArrowPrimitiveType,
+        O: ArrowPrimitiveType,
+        I::Native: AsPrimitive<O::Native>,
+    {
+        Arc::new(array.as_primitive::<I>().unary::<_, O>(|v| v.as_()))
+    }
+
+    Ok(match array.data_type() {
+        DataType::UInt8 => widen::<UInt8Type, O>(array),
+        DataType::UInt16 => widen::<UInt16Type, O>(array),
+        DataType::UInt32 => widen::<UInt32Type, O>(array),
+        DataType::UInt64 => widen::<UInt64Type, O>(array),
+        DataType::Int8 => widen::<Int8Type, O>(array),
+        DataType::Int16 => widen::<Int16Type, O>(array),
+        DataType::Int32 => widen::<Int32Type, O>(array),
+        DataType::Int64 => widen::<Int64Type, O>(array),
+        DataType::Float32 => widen::<Float32Type, O>(array),
+        t => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot coerce {t} to {}",
+                O::DATA_TYPE
+            )));
+        }
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1244,6 +1596,63 @@ mod tests {
         assert_eq!(arr.null_count(), 0);
     }
 
+    #[test]
+    fn test_concat_null_count_mixed_and_sliced() {
+        fn assert_null_count(array: &dyn Array) {
+            let nulls = array.nulls().unwrap();
+            let expected = nulls.len() - nulls.inner().count_set_bits();
+            assert_eq!(array.null_count(), expected);
+            assert_eq!(nulls.null_count(), expected);
+        }
+
+        let fields = Fields::from(vec![Field::new("a", DataType::Int32, true)]);
+        let values: ArrayRef = Arc::new(Int32Array::from_iter_values(0..6));
+        let with_nulls = StructArray::new(
+            fields.clone(),
+            vec![values.clone()],
+            Some(NullBuffer::from(vec![
+                true, false, true, false, false, true,
+            ])),
+        );
+        let without_nulls = StructArray::new(fields, vec![values], None);
+        let arr = concat(&[
+            &with_nulls.slice(1, 4),
+            &without_nulls.slice(2, 3),
+            &with_nulls.slice(3, 2),
+        ])
+        .unwrap();
+        assert_eq!(arr.null_count(), 5);
+        assert_null_count(&arr);
+
+        let with_nulls = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1)]),
+            None,
+            Some(vec![]),
+            None,
+            Some(vec![Some(2), None]),
+        ]);
+        let without_nulls = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(3)]),
+            Some(vec![None]),
+        ]);
+        let arr = concat(&[&without_nulls, &with_nulls.slice(1, 3), &with_nulls]).unwrap();
+        assert_eq!(arr.null_count(), 4);
+        assert_null_count(&arr);
+
+        let with_nulls: DictionaryArray<Int8Type> = vec![Some("a"), None, Some("b"), None, None]
+            .into_iter()
+            .collect();
+        let without_nulls: DictionaryArray<Int8Type> = vec!["c", "a"].into_iter().collect();
+        let arr = concat(&[
+            &with_nulls.slice(2, 3),
+            &without_nulls,
+            &with_nulls.slice(0, 2),
+        ])
+        .unwrap();
+        assert_eq!(arr.null_count(), 3);
+        assert_null_count(&arr);
+    }
+
     #[test]
     fn test_concat_struct_no_fields() {
         let input_1 = StructArray::new_empty_fields(10, None);
@@ -1577,6 +1986,130 @@ mod tests {
         );
     }
 
+    #[test]
+    fn concat_record_batches_with_options() {
+        let meta = |k: &str, v: &str| HashMap::from([(k.to_string(), v.to_string())]);
+        let schema1 = Schema::new_with_metadata(
+            vec![
+                Field::new("a", DataType::Int32, false).with_metadata(meta("f", "1")),
+                Field::new_dictionary("b", DataType::Int8, DataType::UInt8, false),
+                Field::new("c", DataType::Null, true),
+            ],
+            meta("x", "1"),
+        );
+        let schema2 = Schema::new_with_metadata(
+            vec![
+                Field::new("a", DataType::UInt32, true),
+                Field::new_dictionary("b", DataType::UInt8, DataType::Int16, false),
+                Field::new("c", DataType::Utf8, false),
+            ],
+            meta("y", "2"),
+        );
+        let batch1 = RecordBatch::try_new(
+            Arc::new(schema1),
+            vec![
+                Arc::new(Int32Array::from(vec![-1, 2])),
+                Arc::new(DictionaryArray::<Int8Type>::new(
+                    Int8Array::from(vec![1, 0]),
+                    Arc::new(UInt8Array::from(vec![200, 3])),
+                )),
+                Arc::new(NullArray::new(2)),
+            ],
+        )
+        .unwrap();
+        let batch2 = RecordBatch::try_new(
+            Arc::new(schema2),
+            vec![
+                Arc::new(UInt32Array::from(vec![Some(u32::MAX), None])),
+                Arc::new(DictionaryArray::<UInt8Type>::new(
+                    UInt8Array::from(vec![0, 0]),
+                    Arc::new(Int16Array::from(vec![-300])),
+                )),
+                Arc::new(StringArray::from(vec!["foo", "bar"])),
+            ],
+        )
+        .unwrap();
+
+        let error = concat_batches_with_options([&batch1, &batch2], &Default::default());
+        assert_eq!(
+            error.unwrap_err().to_string(),
+            "Schema error: Cannot concatenate batches with incompatible schemas: \
+             schema: metadata differs, column 0 \"a\": incompatible types Int32 and UInt32, \
+             column 1 \"b\": incompatible types Dictionary(Int8, UInt8) and Dictionary(UInt8, Int16), \
+             column 2 \"c\": incompatible types Null and Utf8"
+        );
+
+        let options = ConcatBatchesOptions {
+            metadata: MetadataMode::Merge,
+            coerce_types: true,
+        };
+        let batch = concat_batches_with_options([&batch1, &batch2], &options).unwrap();
+        let schema = batch.schema();
+        assert_eq!(
+            schema.metadata(),
+            &HashMap::from([
+                ("x".to_string(), "1".to_string()),
+                ("y".to_string(), "2".to_string())
+            ])
+        );
+        assert_eq!(
+            schema.field(0),
+            &Field::new("a", DataType::Int64, true).with_metadata(meta("f", "1"))
+        );
+        assert_eq!(
+            schema.field(1),
+            &Field::new_dictionary("b", DataType::Int16, DataType::Int16, false)
+        );
+        assert_eq!(schema.field(2), &Field::new("c", DataType::Utf8, true));
+
+        let a = batch.column(0).as_primitive::<Int64Type>();
+        assert_eq!(
+            a,
+            &Int64Array::from(vec![Some(-1), Some(2), Some(u32::MAX as i64), None])
+        );
+        let b = batch.column(1).as_dictionary::<Int16Type>();
+        let b: Vec<_> = b
+            .downcast_dict::<Int16Array>()
+            .unwrap()
+            .into_iter()
+            .collect();
+        assert_eq!(b, vec![Some(3), Some(200), Some(-300), Some(-300)]);
+        let c = batch.column(2).as_string::<i32>();
+        assert_eq!(
+            c,
+            &StringArray::from(vec![None, None, Some("foo"), Some("bar")])
+        );
+
+        let options = ConcatBatchesOptions {
+            metadata: MetadataMode::Discard,
+            coerce_types: true,
+        };
+        let batch = concat_batches_with_options([&batch1, &batch2], &options).unwrap();
+        assert!(batch.schema().metadata().is_empty());
+        assert!(batch.schema().field(0).metadata().is_empty());
+
+        let batch3 = RecordBatch::try_from_iter([
+            ("a", Arc::new(UInt64Array::from(vec![1])) as ArrayRef),
+            ("b", Arc::new(Float32Array::from(vec![1.0])) as ArrayRef),
+            ("d", Arc::new(Int32Array::from(vec![1])) as ArrayRef),
+        ])
+        .unwrap();
+        let error = concat_batches_with_options([&batch1, &batch3], &options).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Schema error: Cannot concatenate batches with incompatible schemas: \
+             column 0 \"a\": incompatible types Int32 and UInt64, \
+             column 1 \"b\": incompatible types Dictionary(Int8, UInt8) and Float32, \
+             column 2 \"c\": name differs from \"d\""
+        );
+
+        let error = concat_batches_with_options([], &options).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid argument error: concat_batches_with_options requires at least one batch"
+        );
+    }
+
     #[test]
     fn concat_capacity() {
         let a = Int32Array::from_iter_values(0..100);
@@ -24,8 +24,9 @@ use crate::interleave::interleave;
 use ahash::RandomState;
 use arrow_array::builder::BooleanBufferBuilder;
 use arrow_array::types::{
-    ArrowDictionaryKeyType, ArrowPrimitiveType, BinaryType, ByteArrayType, LargeBinaryType,
-    LargeUtf8Type, Utf8Type,
+    ArrowDictionaryKeyType, ArrowPrimitiveType, BinaryType, ByteArrayType, Int8Type, Int16Type,
+    Int32Type, Int64Type, LargeBinaryType, LargeUtf8Type, UInt8Type, UInt16Type, UInt32Type,
+    UInt64Type, Utf8Type,
 };
 use arrow_array::{
     AnyDictionaryArray, Array, ArrayRef, ArrowNativeTypeOp, BooleanArray, DictionaryArray,
@@ -91,6 +92,136 @@ pub fn garbage_collect_any_dictionary(
     )
 }
 
+/// Unifies the dictionaries of `dictionaries` into a single shared values array,
+/// remapping the keys of each [`DictionaryArray`] accordingly
+///
+/// Returns one [`DictionaryArray`] per input, each with the same logical values
+/// as the corresponding input, and all sharing the same values array. Values not
+/// referenced by any key are dropped, and a best-effort is made to deduplicate the
+/// remaining values; however, the unified values may still contain duplicates.
+///
+/// Returns an error if the value types of `dictionaries` differ, the value type
+/// is not a primitive, string or binary type, or the unified values would
+/// overflow the key type `K`
+///
+/// ```
+/// # use arrow_array::{DictionaryArray, StringArray};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_select::dictionary::unify_dictionaries;
+/// let a: DictionaryArray<Int32Type> = vec!["x", "y", "x"].into_iter().collect();
+/// let b: DictionaryArray<Int32Type> = vec!["y", "z"].into_iter().collect();
+///
+/// let unified = unify_dictionaries(&[&a, &b]).unwrap();
+/// assert_eq!(unified[0].values(), unified[1].values());
+/// assert_eq!(unified[0].values().as_string::<i32>(), &StringArray::from(vec!["x", "y", "z"]));
+/// assert_eq!(unified[1].keys().values(), &[1, 2]);
+/// ```
+pub fn unify_dictionaries<K: ArrowDictionaryKeyType>(
+    dictionaries: &[&DictionaryArray<K>],
+) -> Result<Vec<DictionaryArray<K>>, ArrowError> {
+    let Some(first) = dictionaries.first() else {
+        return Ok(vec![]);
+    };
+    let value_type = first.values().data_type();
+    if let Some(d) = dictionaries
+        .iter()
+        .find(|d| d.values().data_type() != value_type)
+    {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Cannot unify dictionaries with different value types, {value_type} and {}",
+            d.values().data_type()
+        )));
+    }
+    if !matches!(
+        value_type,
+        DataType::Utf8 | DataType::LargeUtf8 | DataType::Binary | DataType::LargeBinary
+    ) && !value_type.is_primitive()
+    {
+        return Err(ArrowError::NotYetImplemented(format!(
+            "Unifying dictionaries with value type {value_type} is not implemented"
+        )));
+    }
+
+    let merged = merge_dictionary_values(dictionaries, None)?;
+    dictionaries
+        .iter()
+        .zip(&merged.key_mappings)
+        .map(|(dictionary, mapping)| {
+            let keys = dictionary.keys().unary(|key| {
+                // Null keys may have an arbitrary value
+                mapping
+                    .get(key.as_usize())
+                    .copied()
+                    .unwrap_or(K::Native::ZERO)
+            });
+            DictionaryArray::try_new(keys, Arc::clone(&merged.values))
+        })
+        .collect()
+}
+
+/// Minimizes a dictionary array by removing unreferenced values, as
+/// [`garbage_collect_any_dictionary`], and narrowing the key type to the
+/// smallest integer type of the same signedness that can index the remaining values
+///
+/// ```
+/// # use arrow_array::{Array, DictionaryArray, Int64Array, StringArray};
+/// # use arrow_array::types::Int64Type;
+/// # use arrow_schema::DataType;
+/// # use arrow_select::dictionary::minimize_dictionary;
+/// let values = StringArray::from_iter_values((0..1000).map(|i| format!("v{i}")));
+/// let keys = Int64Array::from(vec![10, 999, 10]);
+/// let dictionary = DictionaryArray::<Int64Type>::new(keys, std::sync::Arc::new(values));
+///
+/// let minimized = minimize_dictionary(&dictionary).unwrap();
+/// let expected = DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8));
+/// assert_eq!(minimized.data_type(), &expected);
+/// ```
+pub fn minimize_dictionary(dictionary: &dyn AnyDictionaryArray) -> Result<ArrayRef, ArrowError> {
+    // FIXME: this is a workaround for MSRV Rust versions below 1.86 where trait upcasting is not stable.
+    // From 1.86 onward, `&dyn AnyDictionaryArray` can be directly passed to `downcast_dictionary_array!`.
+    let dictionary = &*dictionary.slice(0, dictionary.len());
+    downcast_dictionary_array!(
+        dictionary => minimize_dictionary_impl(dictionary),
+        _ => unreachable!("have a dictionary array")
+    )
+}
+
+fn minimize_dictionary_impl<K: ArrowDictionaryKeyType>(
+    dictionary: &DictionaryArray<K>,
+) -> Result<ArrayRef, ArrowError> {
+    let dictionary = garbage_collect_dictionary(dictionary)?;
+    let max_key = dictionary.values().len().saturating_sub(1);
+    match K::DATA_TYPE.is_signed_integer() {
+        true if max_key <= i8::MAX as usize => narrow_keys::<K, Int8Type>(dictionary),
+        true if max_key <= i16::MAX as usize => narrow_keys::<K, Int16Type>(dictionary),
+        true if max_key <= i32::MAX as usize => narrow_keys::<K, Int32Type>(dictionary),
+        true => narrow_keys::<K, Int64Type>(dictionary),
+        false if max_key <= u8::MAX as usize => narrow_keys::<K, UInt8Type>(dictionary),
+        false if max_key <= u16::MAX as usize => narrow_keys::<K, UInt16Type>(dictionary),
+        false if max_key <= u32::MAX as usize => narrow_keys::<K, UInt32Type>(dictionary),
+        false => narrow_keys::<K, UInt64Type>(dictionary),
+    }
+}
+
+/// Converts the keys of `dictionary` to `T`, which must be able to represent
+/// every valid key
+fn narrow_keys<K: ArrowDictionaryKeyType, T: ArrowDictionaryKeyType>(
+    dictionary: DictionaryArray<K>,
+) -> Result<ArrayRef, ArrowError> {
+    if K::DATA_TYPE == T::DATA_TYPE {
+        return Ok(Arc::new(dictionary));
+    }
+    let keys = dictionary.keys().unary::<_, T>(|key| {
+        // Null keys may have an arbitrary value
+        T::Native::from_usize(key.as_usize()).unwrap_or(T::Native::ZERO)
+    });
+    Ok(Arc::new(DictionaryArray::try_new(
+        keys,
+        Arc::clone(dictionary.values()),
+    )?))
+}
+
 /// A best effort interner that maintains a fixed number of buckets
 /// and interns keys based on their hash value
 ///
@@ -378,7 +509,9 @@ mod tests {
     use arrow_array::cast::as_string_array;
     use arrow_array::types::Int8Type;
     use arrow_array::types::Int32Type;
-    use arrow_array::{DictionaryArray, Int8Array, Int32Array, StringArray};
+    use arrow_array::{
+        DictionaryArray, Int8Array, Int32Array, StringArray, UInt16Array, UInt32Array,
+    };
     use arrow_buffer::{BooleanBuffer, Buffer, NullBuffer, OffsetBuffer};
     use std::sync::Arc;
 
@@ -455,6 +588,78 @@ mod tests {
         assert_eq!(gc, expected);
     }
 
+    #[test]
+    fn test_unify_dictionaries() {
+        let a = DictionaryArray::<Int8Type>::new(
+            Int8Array::from(vec![Some(0), None, Some(2)]),
+            Arc::new(StringArray::from(vec!["a", "unused", "b"])),
+        );
+        let b = DictionaryArray::<Int8Type>::new(
+            Int8Array::from(vec![1, 0, 1]),
+            Arc::new(StringArray::from(vec![Some("c"), None])),
+        );
+
+        let unified = unify_dictionaries(&[&a, &b]).unwrap();
+        assert_eq!(unified.len(), 2);
+        assert!(Arc::ptr_eq(unified[0].values(), unified[1].values()));
+
+        let values = unified[0].values().as_string::<i32>();
+        assert_eq!(
+            values,
+            &StringArray::from(vec![Some("a"), Some("b"), Some("c"), None])
+        );
+        assert_eq!(
+            unified[0].keys(),
+            &Int8Array::from(vec![Some(0), None, Some(1)])
+        );
+        assert_eq!(unified[1].keys(), &Int8Array::from(vec![3, 2, 3]));
+
+        assert!(unify_dictionaries::<Int8Type>(&[]).unwrap().is_empty());
+
+        let c = DictionaryArray::<Int8Type>::new(
+            Int8Array::from(vec![0]),
+            Arc::new(Int32Array::from(vec![1])),
+        );
+        let err = unify_dictionaries(&[&a, &c]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot unify dictionaries with different value types, Utf8 and Int32"
+        );
+    }
+
+    #[test]
+    fn test_minimize_dictionary() {
+        let values = StringArray::from_iter_values((0..300).map(|i| i.to_string()));
+        let keys = Int32Array::from(vec![Some(5), None, Some(299), Some(5)]);
+        let dict = DictionaryArray::<Int32Type>::new(keys, Arc::new(values));
+
+        let minimized = minimize_dictionary(&dict).unwrap();
+        let minimized = minimized.as_dictionary::<Int8Type>();
+        assert_eq!(
+            minimized.keys(),
+            &Int8Array::from(vec![Some(0), None, Some(1), Some(0)])
+        );
+        assert_eq!(
+            minimized.values().as_string::<i32>(),
+            &StringArray::from(vec!["5", "299"])
+        );
+
+        // Unsigned keys stay unsigned
+        let keys = UInt32Array::from_iter_values((0..300).rev());
+        let values = Int32Array::from_iter_values(0..300);
+        let dict = DictionaryArray::<UInt32Type>::new(keys, Arc::new(values));
+        let minimized = minimize_dictionary(&dict).unwrap();
+        let minimized = minimized.as_dictionary::<UInt16Type>();
+        assert_eq!(
+            minimized.keys(),
+            &UInt16Array::from_iter_values((0..300).rev())
+        );
+
+        // Already minimal
+        let minimized = minimize_dictionary(minimized).unwrap();
+        assert_eq!(minimized.as_dictionary::<UInt16Type>().len(), 300);
+    }
+
     #[test]
     fn test_merge_strings() {
         let a = DictionaryArray::<Int32Type>::from_iter(["a", "b", "a", "b", "d", "c", "e"]);
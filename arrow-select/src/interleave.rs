@@ -839,6 +839,17 @@ pub fn interleave_record_batch(
     indices: &[(usize, usize)],
 ) -> Result<RecordBatch, ArrowError> {
     let schema = record_batches[0].schema();
+    if schema.fields().is_empty() {
+        // There are no columns to check the indices against
+        for &(batch, row) in indices {
+            let num_rows = record_batches.get(batch).map(|b| b.num_rows());
+            if num_rows.is_none_or(|num_rows| row >= num_rows) {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Interleave index ({batch}, {row}) out of bounds"
+                )));
+            }
+        }
+    }
     let columns = (0..schema.fields().len())
         .map(|i| {
             let column_values: Vec<&dyn Array> = record_batches
@@ -848,7 +859,8 @@ pub fn interleave_record_batch(
             interleave(&column_values, indices)
         })
         .collect::<Result<Vec<_>, _>>()?;
-    RecordBatch::try_new(schema, columns)
+    let options = RecordBatchOptions::new().with_row_count(Some(indices.len()));
+    RecordBatch::try_new_with_options(schema, columns, &options)
 }
 
 #[cfg(test)]
@@ -860,7 +872,7 @@ mod tests {
     };
     use arrow_array::types::{Decimal128Type, Int8Type, TimestampMicrosecondType};
     use arrow_buffer::ScalarBuffer;
-    use arrow_schema::{Field, TimeUnit};
+    use arrow_schema::{Field, Schema, TimeUnit};
 
     #[test]
     fn test_primitive() {
@@ -2040,4 +2052,25 @@ mod tests {
             &[3]
         );
     }
+
+    #[test]
+    fn test_interleave_record_batch_no_columns() {
+        let schema = Arc::new(Schema::empty());
+        let batch = |rows| {
+            let options = RecordBatchOptions::new().with_row_count(Some(rows));
+            RecordBatch::try_new_with_options(schema.clone(), vec![], &options).unwrap()
+        };
+        let (a, b) = (batch(2), batch(3));
+        let result = interleave_record_batch(&[&a, &b], &[(1, 2), (0, 1), (1, 0), (0, 0)]).unwrap();
+        assert_eq!(result.num_columns(), 0);
+        assert_eq!(result.num_rows(), 4);
+
+        for (index, expected) in [((0, 2), "(0, 2)"), ((2, 0), "(2, 0)")] {
+            let err = interleave_record_batch(&[&a, &b], &[(1, 2), index]).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                format!("Invalid argument error: Interleave index {expected} out of bounds")
+            );
+        }
+    }
 }
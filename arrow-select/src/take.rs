@@ -1136,12 +1136,20 @@ pub fn take_record_batch(
     record_batch: &RecordBatch,
     indices: &dyn Array,
 ) -> Result<RecordBatch, ArrowError> {
+    if record_batch.num_columns() == 0 {
+        // There are no columns to check the indices against
+        downcast_integer_array!(
+            indices => check_bounds(record_batch.num_rows(), indices)?,
+            d => return Err(ArrowError::InvalidArgumentError(format!("Take only supported for integers, got {d:?}")))
+        )
+    }
     let columns = record_batch
         .columns()
         .iter()
         .map(|c| take(c, indices, None))
         .collect::<Result<Vec<_>, _>>()?;
-    RecordBatch::try_new(record_batch.schema(), columns)
+    let options = RecordBatchOptions::new().with_row_count(Some(indices.len()));
+    RecordBatch::try_new_with_options(record_batch.schema(), columns, &options)
 }
 
 #[cfg(test)]
@@ -1150,7 +1158,7 @@ mod tests {
     use arrow_array::builder::*;
     use arrow_buffer::{IntervalDayTime, IntervalMonthDayNano};
     use arrow_data::ArrayData;
-    use arrow_schema::{Field, Fields, TimeUnit, UnionFields};
+    use arrow_schema::{Field, Fields, Schema, TimeUnit, UnionFields};
     use num_traits::ToPrimitive;
 
     fn test_take_decimal_arrays(
@@ -2929,4 +2937,22 @@ mod tests {
         assert_eq!(run_result.run_ends().len(), 0);
         assert_eq!(run_result.values().len(), 0);
     }
+
+    #[test]
+    fn test_take_record_batch_no_columns() {
+        let options = RecordBatchOptions::new().with_row_count(Some(5));
+        let batch =
+            RecordBatch::try_new_with_options(Arc::new(Schema::empty()), vec![], &options).unwrap();
+        let indices = UInt32Array::from(vec![Some(4), None, Some(0)]);
+        let taken = take_record_batch(&batch, &indices).unwrap();
+        assert_eq!(taken.num_columns(), 0);
+        assert_eq!(taken.num_rows(), 3);
+
+        let indices = UInt32Array::from(vec![Some(5), None]);
+        let err = take_record_batch(&batch, &indices).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Array index out of bounds, cannot get item at index 5 from 5 entries"
+        );
+    }
 }
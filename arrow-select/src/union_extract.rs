@@ -15,12 +15,12 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! Defines union_extract kernel for [UnionArray]
+//! Defines union_extract kernel and conversion utilities for [UnionArray]
 
 use crate::take::take;
 use arrow_array::{
-    Array, ArrayRef, BooleanArray, Int32Array, Scalar, UnionArray, make_array, new_empty_array,
-    new_null_array,
+    Array, ArrayRef, BooleanArray, Int32Array, Scalar, UInt32Array, UnionArray, make_array,
+    new_empty_array, new_null_array,
 };
 use arrow_buffer::{BooleanBuffer, MutableBuffer, NullBuffer, ScalarBuffer, bit_util};
 use arrow_data::layout;
@@ -118,6 +118,158 @@ pub fn union_extract_by_id(
     union_extract_impl(union_array, fields, target_type_id)
 }
 
+/// Returns the values of the rows of `union_array` with `type_id`, along with the
+/// indices of those rows within `union_array`
+///
+/// Unlike [`union_extract_by_id`], which returns an array of the same length as
+/// `union_array` with nulls for the rows of other types, this returns only the
+/// selected rows. The indices can be used with [`take`] or
+/// [`interleave`](crate::interleave::interleave) to reassemble a result.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_schema::{DataType, Field, UnionFields};
+/// # use arrow_array::{Int32Array, StringArray, UInt32Array, UnionArray};
+/// # use arrow_select::union_extract::union_extract_rows;
+/// let fields = UnionFields::try_new(
+///     [0, 1],
+///     [Field::new("A", DataType::Int32, true), Field::new("B", DataType::Utf8, true)],
+/// ).unwrap();
+/// let union = UnionArray::try_new(
+///     fields,
+///     vec![0, 1, 0, 0].into(),
+///     Some(vec![0, 0, 1, 2].into()),
+///     vec![
+///         Arc::new(Int32Array::from(vec![1, 2, 3])),
+///         Arc::new(StringArray::from(vec!["a"])),
+///     ],
+/// ).unwrap();
+///
+/// let (values, indices) = union_extract_rows(&union, 0).unwrap();
+/// assert_eq!(*values, Int32Array::from(vec![1, 2, 3]));
+/// assert_eq!(indices, UInt32Array::from(vec![0, 2, 3]));
+/// ```
+///
+/// # Errors
+///
+/// Returns error if `type_id` does not correspond to a field in the union.
+pub fn union_extract_rows(
+    union_array: &UnionArray,
+    type_id: i8,
+) -> Result<(ArrayRef, UInt32Array), ArrowError> {
+    if union_array.fields().iter().all(|(id, _)| id != type_id) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "type_id {type_id} not found on union"
+        )));
+    }
+
+    let indices: UInt32Array = (0..union_array.len() as u32)
+        .filter(|i| union_array.type_id(*i as usize) == type_id)
+        .collect();
+    let values = match union_array.offsets() {
+        Some(offsets) => {
+            let offsets: UInt32Array = indices
+                .values()
+                .iter()
+                .map(|i| offsets[*i as usize] as u32)
+                .collect();
+            take(union_array.child(type_id), &offsets, None)?
+        }
+        None => take(union_array.child(type_id), &indices, None)?,
+    };
+    Ok((values, indices))
+}
+
+/// Returns the number of rows of `union_array` with each type id, in the order of
+/// the union's fields
+///
+/// Dividing the counts by the length of `union_array` gives the selectivity of each
+/// type id.
+pub fn union_type_id_counts(union_array: &UnionArray) -> Vec<(i8, usize)> {
+    let mut counts = [0_usize; 128];
+    for type_id in union_array.type_ids() {
+        counts[*type_id as usize] += 1;
+    }
+    union_array
+        .fields()
+        .iter()
+        .map(|(type_id, _)| (type_id, counts[type_id as usize]))
+        .collect()
+}
+
+/// Converts `union_array` to a dense union, returning it unchanged if already dense
+///
+/// Each child of the result contains only the values of the rows with its type id.
+pub fn union_to_dense(union_array: &UnionArray) -> Result<UnionArray, ArrowError> {
+    if union_array.is_dense() {
+        return Ok(union_array.clone());
+    }
+
+    // Partition the rows by type id, the offset of each row being its position in its partition
+    let mut rows: [Vec<u32>; 128] = std::array::from_fn(|_| Vec::new());
+    let offsets: ScalarBuffer<i32> = union_array
+        .type_ids()
+        .iter()
+        .enumerate()
+        .map(|(row, type_id)| {
+            let rows = &mut rows[*type_id as usize];
+            let offset = rows.len() as i32;
+            rows.push(row as u32);
+            offset
+        })
+        .collect();
+    let children = union_array
+        .fields()
+        .iter()
+        .map(|(type_id, _)| {
+            let indices = UInt32Array::from(std::mem::take(&mut rows[type_id as usize]));
+            take(union_array.child(type_id), &indices, None)
+        })
+        .collect::<Result<Vec<_>, ArrowError>>()?;
+
+    UnionArray::try_new(
+        union_array.fields().clone(),
+        union_array.type_ids().clone(),
+        Some(offsets),
+        children,
+    )
+}
+
+/// Converts `union_array` to a sparse union, returning it unchanged if already sparse
+///
+/// The slots of each child of the result for rows of other type ids are unspecified.
+pub fn union_to_sparse(union_array: &UnionArray) -> Result<UnionArray, ArrowError> {
+    let Some(offsets) = union_array.offsets() else {
+        return Ok(union_array.clone());
+    };
+
+    let type_ids = union_array.type_ids();
+    let children = union_array
+        .fields()
+        .iter()
+        .map(|(type_id, _)| {
+            let child = union_array.child(type_id);
+            if child.is_empty() {
+                return Ok(new_null_array(child.data_type(), union_array.len()));
+            }
+            // Rows of other types repeat the first value of the child
+            let indices: UInt32Array = type_ids
+                .iter()
+                .zip(offsets.iter())
+                .map(|(id, offset)| if *id == type_id { *offset as u32 } else { 0 })
+                .collect();
+            take(child, &indices, None)
+        })
+        .collect::<Result<Vec<_>, ArrowError>>()?;
+
+    UnionArray::try_new(
+        union_array.fields().clone(),
+        type_ids.clone(),
+        None,
+        children,
+    )
+}
+
 fn union_extract_impl(
     union_array: &UnionArray,
     fields: &UnionFields,
@@ -435,8 +587,11 @@ fn is_sequential_generic<const N: usize>(offsets: &[i32]) -> bool {
 mod tests {
     use super::{
         BoolValue, eq_scalar_inner, is_sequential_generic, union_extract, union_extract_by_id,
+        union_extract_rows, union_to_dense, union_to_sparse, union_type_id_counts,
+    };
+    use arrow_array::{
+        Array, Int32Array, NullArray, StringArray, UInt32Array, UnionArray, new_null_array,
     };
-    use arrow_array::{Array, Int32Array, NullArray, StringArray, UnionArray, new_null_array};
     use arrow_buffer::{BooleanBuffer, ScalarBuffer};
     use arrow_schema::{ArrowError, DataType, Field, UnionFields, UnionMode};
     use std::sync::Arc;
@@ -1374,4 +1529,68 @@ mod tests {
             ArrowError::InvalidArgumentError("type_id 5 not found on union".into()).to_string()
         );
     }
+
+    #[test]
+    fn test_union_dense_sparse_conversion() {
+        let fields = UnionFields::try_new(
+            [0, 2, 5],
+            [
+                Field::new("a", DataType::Int32, true),
+                Field::new("b", DataType::Utf8, true),
+                Field::new("c", DataType::Int32, false),
+            ],
+        )
+        .unwrap();
+        let dense = UnionArray::try_new(
+            fields,
+            vec![2, 0, 0, 2, 0].into(),
+            Some(vec![0, 0, 1, 1, 2].into()),
+            vec![
+                Arc::new(Int32Array::from(vec![Some(1), None, Some(3)])),
+                Arc::new(StringArray::from(vec!["x", "y"])),
+                Arc::new(Int32Array::from(Vec::<i32>::new())),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(union_type_id_counts(&dense), vec![(0, 3), (2, 2), (5, 0)]);
+
+        let sparse = union_to_sparse(&dense).unwrap();
+        assert!(!sparse.is_dense());
+        assert_eq!(sparse.len(), 5);
+        assert!(union_to_sparse(&sparse).unwrap().child(0).len() == 5);
+
+        let back = union_to_dense(&sparse.slice(1, 4)).unwrap();
+        assert!(back.is_dense());
+        assert_eq!(back.offsets().unwrap().as_ref(), &[0, 1, 0, 2]);
+        assert_eq!(back.child(0).len(), 3);
+        assert_eq!(back.child(2).len(), 1);
+
+        for union in [&dense, &sparse, &back] {
+            let (values, indices) = union_extract_rows(union, 2).unwrap();
+            let expected = &dense.slice(dense.len() - union.len(), union.len());
+            let (expected_values, expected_indices) = union_extract_rows(expected, 2).unwrap();
+            assert_eq!(&values, &expected_values);
+            assert_eq!(indices, expected_indices);
+            for name in ["a", "b", "c"] {
+                assert_eq!(
+                    &union_extract(union, name).unwrap(),
+                    &union_extract(expected, name).unwrap()
+                );
+            }
+        }
+
+        let (values, indices) = union_extract_rows(&dense, 0).unwrap();
+        assert_eq!(
+            values.as_ref(),
+            &Int32Array::from(vec![Some(1), None, Some(3)])
+        );
+        assert_eq!(indices, UInt32Array::from(vec![1, 2, 4]));
+
+        let err = union_extract_rows(&dense, 1).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: type_id 1 not found on union"
+        );
+    }
 }
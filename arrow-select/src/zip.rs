@@ -69,6 +69,9 @@ use std::sync::{Arc, OnceLock};
 /// assert_eq!(&result, &expected);
 /// ```
 ///
+/// All data types are supported, including nested types such as structs, lists
+/// and maps, for both arrays and scalars.
+///
 /// # Example: `zip` and array with a scalar
 ///
 /// Use `zip` to replace certain values in an array with a scalar
@@ -844,7 +847,9 @@ impl<T: ByteViewType> ZipImpl for ByteViewScalarImpl<T> {
 #[cfg(test)]
 mod test {
     use super::*;
+    use arrow_array::builder::{Int32Builder, MapBuilder, StringBuilder};
     use arrow_array::types::Int32Type;
+    use arrow_schema::{Field, Fields};
 
     #[test]
     fn test_zip_kernel_one() {
@@ -1560,4 +1565,101 @@ mod test {
         ]);
         assert_eq!(actual, &expected);
     }
+
+    #[test]
+    fn test_zip_nested() {
+        let mask = BooleanArray::from(vec![Some(true), Some(false), None, Some(true)]);
+
+        let list = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1)]),
+            None,
+            Some(vec![Some(2), None]),
+            Some(vec![]),
+        ]);
+        let scalar = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(0)]),
+            Some(vec![Some(8), Some(9)]),
+        ])
+        .slice(1, 1);
+        let out = zip(&mask, &list, &Scalar::new(&scalar)).unwrap();
+        let expected = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1)]),
+            Some(vec![Some(8), Some(9)]),
+            Some(vec![Some(8), Some(9)]),
+            Some(vec![]),
+        ]);
+        assert_eq!(out.as_list::<i32>(), &expected);
+
+        let out = zip(&mask, &Scalar::new(&scalar), &list).unwrap();
+        let expected = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(8), Some(9)]),
+            None,
+            Some(vec![Some(2), None]),
+            Some(vec![Some(8), Some(9)]),
+        ]);
+        assert_eq!(out.as_list::<i32>(), &expected);
+
+        let null_scalar = new_null_array(list.data_type(), 1);
+        let out = zip(&mask, &Scalar::new(&scalar), &Scalar::new(&null_scalar)).unwrap();
+        assert_eq!(out.logical_null_count(), 2);
+        assert_eq!(
+            out.as_list::<i32>()
+                .value(3)
+                .as_primitive::<Int32Type>()
+                .values(),
+            &[8, 9]
+        );
+
+        let fields = Fields::from(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new_list("b", Field::new_list_field(DataType::Int32, true), true),
+        ]);
+        let structs = StructArray::new(
+            fields.clone(),
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2, 3, 4])),
+                Arc::new(list.clone()),
+            ],
+            Some(vec![true, true, false, true].into()),
+        );
+        let scalar = StructArray::new(
+            fields.clone(),
+            vec![Arc::new(Int32Array::from(vec![7])), Arc::new(scalar)],
+            None,
+        );
+        let out = zip(&mask, &structs, &Scalar::new(&scalar)).unwrap();
+        let out = out.as_struct();
+        assert!(out.is_valid(2));
+        assert_eq!(
+            out.column(0).as_primitive::<Int32Type>().values(),
+            &[1, 7, 7, 4]
+        );
+        assert_eq!(out.column(1).as_list::<i32>().value(2).len(), 2);
+
+        let mut builder = MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+        for (k, v) in [("a", 1), ("b", 2), ("c", 3), ("d", 4)] {
+            builder.keys().append_value(k);
+            builder.values().append_value(v);
+            builder.append(true).unwrap();
+        }
+        let map = builder.finish();
+        let out = zip(&mask, &Scalar::new(map.slice(2, 1)), &map).unwrap();
+        let out = out.as_map();
+        let keys: Vec<_> = (0..4)
+            .map(|i| {
+                out.value(i)
+                    .column(0)
+                    .as_string::<i32>()
+                    .value(0)
+                    .to_string()
+            })
+            .collect();
+        assert_eq!(keys, ["c", "b", "c", "c"]);
+
+        let err = zip(&mask, &list, &structs).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: arguments need to have the same data type"
+        );
+    }
 }
@@ -0,0 +1,310 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines kernels formatting the values of multiple arrays into strings
+
+use arrow_array::builder::{
+    LargeStringBuilder, StringBuilder, StringLikeArrayBuilder, StringViewBuilder,
+};
+use arrow_array::{Array, ArrayRef};
+use arrow_cast::display::{ArrayFormatter, FormatOptions};
+use arrow_schema::{ArrowError, DataType};
+
+/// Returns the length of `arrays`, which must all have the same length
+fn arrays_len(name: &str, arrays: &[&dyn Array]) -> Result<usize, ArrowError> {
+    let Some(first) = arrays.first() else {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "{name}() requires at least one array"
+        )));
+    };
+    match arrays.iter().find(|a| a.len() != first.len()) {
+        Some(a) => Err(ArrowError::ComputeError(format!(
+            "Arrays must have the same length: {} != {}",
+            first.len(),
+            a.len()
+        ))),
+        None => Ok(first.len()),
+    }
+}
+
+/// Returns an array of type `output` of `len` strings, each written by `write`
+fn write_rows<F>(len: usize, output: &DataType, write: F) -> Result<ArrayRef, ArrowError>
+where
+    F: FnMut(usize, &mut String) -> Result<(), ArrowError>,
+{
+    fn write_rows_impl<B, F>(len: usize, mut write: F) -> Result<ArrayRef, ArrowError>
+    where
+        B: StringLikeArrayBuilder,
+        F: FnMut(usize, &mut String) -> Result<(), ArrowError>,
+    {
+        let mut builder = B::with_capacity(len);
+        let mut row = String::new();
+        for idx in 0..len {
+            row.clear();
+            write(idx, &mut row)?;
+            builder.append_value(&row);
+        }
+        Ok(builder.finish())
+    }
+
+    match output {
+        DataType::Utf8 => write_rows_impl::<StringBuilder, _>(len, write),
+        DataType::LargeUtf8 => write_rows_impl::<LargeStringBuilder, _>(len, write),
+        DataType::Utf8View => write_rows_impl::<StringViewBuilder, _>(len, write),
+        _ => Err(ArrowError::InvalidArgumentError(format!(
+            "Output must be either Utf8, Utf8View or LargeUtf8, got {output}"
+        ))),
+    }
+}
+
+/// Returns the elementwise concatenation of the values of `arrays` separated by `separator`
+///
+/// Values of any type supported by [`ArrayFormatter`] are rendered with the default
+/// [`FormatOptions`], and null values are skipped, so that a row with only null values is
+/// the empty string. The result has type `output`, which must be `Utf8`, `LargeUtf8` or
+/// `Utf8View`, and no nulls.
+///
+/// ```
+/// # use arrow_array::{Int32Array, StringArray};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_schema::DataType;
+/// # use arrow_string::format::concat_ws;
+/// let a = StringArray::from(vec![Some("a"), None, None]);
+/// let b = Int32Array::from(vec![Some(1), Some(2), None]);
+///
+/// let result = concat_ws("-", &[&a, &b], &DataType::Utf8).unwrap();
+/// assert_eq!(result.as_string::<i32>(), &StringArray::from(vec!["a-1", "2", ""]));
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if `arrays` is empty or the arrays have different lengths
+pub fn concat_ws(
+    separator: &str,
+    arrays: &[&dyn Array],
+    output: &DataType,
+) -> Result<ArrayRef, ArrowError> {
+    let len = arrays_len("concat_ws", arrays)?;
+    let options = FormatOptions::default();
+    let columns = arrays
+        .iter()
+        .map(|a| Ok((ArrayFormatter::try_new(*a, &options)?, a.logical_nulls())))
+        .collect::<Result<Vec<_>, ArrowError>>()?;
+
+    write_rows(len, output, |idx, row| {
+        let mut first = true;
+        for (formatter, nulls) in &columns {
+            if nulls.as_ref().is_some_and(|n| n.is_null(idx)) {
+                continue;
+            }
+            if !first {
+                row.push_str(separator);
+            }
+            first = false;
+            formatter.value(idx).write(row)?;
+        }
+        Ok(())
+    })
+}
+
+/// A part of a pattern of [`format`]
+#[derive(Debug, PartialEq)]
+enum Segment {
+    /// Text written verbatim
+    Literal(String),
+    /// The value of the array at the index
+    Placeholder(usize),
+}
+
+/// Parses `pattern` into [`Segment`]s, validating placeholders against `num_arrays`
+fn parse_pattern(pattern: &str, num_arrays: usize) -> Result<Vec<Segment>, ArrowError> {
+    let err = |msg: String| {
+        ArrowError::InvalidArgumentError(format!("Invalid format pattern \"{pattern}\": {msg}"))
+    };
+
+    let mut segments = vec![];
+    let mut literal = String::new();
+    let mut next = 0;
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.as_str().starts_with('{') => {
+                chars.next();
+                literal.push('{');
+            }
+            '}' if chars.as_str().starts_with('}') => {
+                chars.next();
+                literal.push('}');
+            }
+            '{' => {
+                let rest = chars.as_str();
+                let end = rest
+                    .find('}')
+                    .ok_or_else(|| err("unterminated placeholder".to_string()))?;
+                let idx = match &rest[..end] {
+                    "" => {
+                        next += 1;
+                        next - 1
+                    }
+                    s => s
+                        .parse::<usize>()
+                        .map_err(|_| err(format!("invalid placeholder \"{{{s}}}\"")))?,
+                };
+                if idx >= num_arrays {
+                    return Err(err(format!(
+                        "placeholder {idx} out of bounds for {num_arrays} arrays"
+                    )));
+                }
+                chars = rest[end + 1..].chars();
+                if !literal.is_empty() {
+                    segments.push(Segment::Literal(std::mem::take(&mut literal)));
+                }
+                segments.push(Segment::Placeholder(idx));
+            }
+            '}' => return Err(err("unmatched '}'".to_string())),
+            c => literal.push(c),
+        }
+    }
+    if !literal.is_empty() {
+        segments.push(Segment::Literal(literal));
+    }
+    Ok(segments)
+}
+
+/// Returns the elementwise formatting of the values of `arrays` according to `pattern`
+///
+/// The `pattern` is written for each row with its placeholders replaced by values:
+///
+/// * `{}` is replaced by the value of the next array, starting from the first
+/// * `{n}` is replaced by the value of the `n`th array, counting from `0`
+/// * `{{` and `}}` are replaced by `{` and `}`
+///
+/// Values of any type supported by [`ArrayFormatter`] are rendered according to `options`,
+/// including null values which are rendered as [`FormatOptions::with_null`]. The result has
+/// type `output`, which must be `Utf8`, `LargeUtf8` or `Utf8View`, and no nulls.
+///
+/// ```
+/// # use arrow_array::{Float64Array, StringArray};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_cast::display::FormatOptions;
+/// # use arrow_schema::DataType;
+/// # use arrow_string::format::format;
+/// let name = StringArray::from(vec![Some("a"), Some("b")]);
+/// let value = Float64Array::from(vec![Some(1.5), None]);
+///
+/// let options = FormatOptions::default().with_null("n/a");
+/// let result = format("{}: {} {{{0}}}", &[&name, &value], &options, &DataType::Utf8).unwrap();
+/// let expected = StringArray::from(vec!["a: 1.5 {a}", "b: n/a {b}"]);
+/// assert_eq!(result.as_string::<i32>(), &expected);
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if `arrays` is empty, the arrays have different lengths, or `pattern`
+/// is invalid or references an array not in `arrays`
+pub fn format(
+    pattern: &str,
+    arrays: &[&dyn Array],
+    options: &FormatOptions,
+    output: &DataType,
+) -> Result<ArrayRef, ArrowError> {
+    let len = arrays_len("format", arrays)?;
+    let segments = parse_pattern(pattern, arrays.len())?;
+    let formatters = arrays
+        .iter()
+        .map(|a| ArrayFormatter::try_new(*a, options))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    write_rows(len, output, |idx, row| {
+        for segment in &segments {
+            match segment {
+                Segment::Literal(s) => row.push_str(s),
+                Segment::Placeholder(i) => formatters[*i].value(idx).write(row)?,
+            }
+        }
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::cast::AsArray;
+    use arrow_array::{Date32Array, Int64Array, StringArray, StringViewArray};
+
+    #[test]
+    fn test_concat_ws() {
+        let a = StringViewArray::from(vec![Some("x"), None, Some("y"), None]);
+        let b = Int64Array::from(vec![Some(1), Some(2), None, None]);
+        let c = Date32Array::from(vec![Some(0), None, Some(1), None]);
+
+        for output in [DataType::Utf8, DataType::LargeUtf8, DataType::Utf8View] {
+            let result = concat_ws(", ", &[&a, &b, &c], &output).unwrap();
+            assert_eq!(result.data_type(), &output);
+            assert_eq!(result.null_count(), 0);
+            let result = arrow_cast::cast(&result, &DataType::Utf8).unwrap();
+            let expected = StringArray::from(vec!["x, 1, 1970-01-01", "2", "y, 1970-01-02", ""]);
+            assert_eq!(result.as_string::<i32>(), &expected);
+        }
+
+        let err = concat_ws(",", &[&a, &b.slice(0, 2)], &DataType::Utf8).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Arrays must have the same length: 4 != 2"
+        );
+        let err = concat_ws(",", &[], &DataType::Utf8).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: concat_ws() requires at least one array"
+        );
+        let err = concat_ws(",", &[&a], &DataType::Binary).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Output must be either Utf8, Utf8View or LargeUtf8, got Binary"
+        );
+    }
+
+    #[test]
+    fn test_format() {
+        let a = StringArray::from(vec![Some("x"), None]);
+        let b = Int64Array::from(vec![Some(-1), Some(2)]);
+
+        let options = FormatOptions::default();
+        let result = format("{1}{}|{}{{}}{0}", &[&a, &b], &options, &DataType::Utf8View).unwrap();
+        let result = result.as_string_view();
+        assert_eq!(result.value(0), "-1x|-1{}x");
+        assert_eq!(result.value(1), "2|2{}");
+
+        let result = format("no placeholders", &[&a], &options, &DataType::Utf8).unwrap();
+        assert_eq!(result.as_string::<i32>().value(1), "no placeholders");
+
+        let cases = [
+            ("{2}", "placeholder 2 out of bounds for 2 arrays"),
+            ("{}{}{}", "placeholder 2 out of bounds for 2 arrays"),
+            ("{a}", "invalid placeholder \"{a}\""),
+            ("{0", "unterminated placeholder"),
+            ("a}", "unmatched '}'"),
+        ];
+        for (pattern, msg) in cases {
+            let err = format(pattern, &[&a, &b], &options, &DataType::Utf8).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                format!("Invalid argument error: Invalid format pattern \"{pattern}\": {msg}")
+            );
+        }
+    }
+}
@@ -27,8 +27,10 @@
 mod binary_like;
 mod binary_predicate;
 pub mod concat_elements;
+pub mod format;
 pub mod length;
 pub mod like;
 mod predicate;
 pub mod regexp;
+pub mod split;
 pub mod substring;
@@ -0,0 +1,422 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines kernels for splitting strings on a delimiter
+
+use arrow_array::builder::make_view;
+use arrow_array::cast::AsArray;
+use arrow_array::*;
+use arrow_buffer::{OffsetBuffer, ScalarBuffer};
+use arrow_data::ByteView;
+use arrow_schema::{ArrowError, DataType, Field};
+use memchr::memmem::Finder;
+use std::ops::Range;
+use std::sync::Arc;
+
+/// Returns the byte ranges of the parts of `haystack` separated by the needle of `finder`
+///
+/// An empty needle does not split `haystack`, yielding it as a single part
+fn split_ranges<'a>(
+    haystack: &'a [u8],
+    finder: &'a Finder<'_>,
+) -> impl Iterator<Item = Range<usize>> + 'a {
+    let len = finder.needle().len();
+    let mut matches = (len != 0)
+        .then(|| finder.find_iter(haystack))
+        .into_iter()
+        .flatten();
+    let mut start = Some(0);
+    std::iter::from_fn(move || {
+        let s = start?;
+        match matches.next() {
+            Some(end) => {
+                start = Some(end + len);
+                Some(s..end)
+            }
+            None => {
+                start = None;
+                Some(s..haystack.len())
+            }
+        }
+    })
+}
+
+/// Returns the byte range of the `n`th part of `haystack`, counting from the end if negative
+fn part_range(haystack: &[u8], finder: &Finder<'_>, n: i64) -> Option<Range<usize>> {
+    let idx = match n {
+        1.. => usize::try_from(n - 1).ok()?,
+        _ => {
+            let count = split_ranges(haystack, finder).count();
+            count.checked_sub(usize::try_from(n.unsigned_abs()).ok()?)?
+        }
+    };
+    split_ranges(haystack, finder).nth(idx)
+}
+
+/// Splits each string of `array` on `delimiter`, returning a list of the parts of each string
+///
+/// * `Utf8` input returns `List(Utf8)`
+/// * `LargeUtf8` input returns `LargeList(LargeUtf8)`
+/// * `Utf8View` input returns `List(Utf8View)`, whose parts reference the buffers of `array`
+///
+/// The split of a null string is null. An empty `delimiter` does not split strings,
+/// and so each list contains the whole string.
+///
+/// ```
+/// # use arrow_array::{Array, StringArray};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_string::split::split;
+/// let array = StringArray::from(vec![Some("a,b,,c"), None, Some("")]);
+/// let result = split(&array, ",").unwrap();
+/// let lists = result.as_list::<i32>();
+///
+/// let parts = lists.value(0);
+/// let parts: Vec<_> = parts.as_string::<i32>().iter().flatten().collect();
+/// assert_eq!(parts, ["a", "b", "", "c"]);
+/// assert!(lists.is_null(1));
+/// assert_eq!(lists.value_length(2), 1);
+/// ```
+pub fn split(array: &dyn Array, delimiter: &str) -> Result<ArrayRef, ArrowError> {
+    let finder = Finder::new(delimiter);
+    match array.data_type() {
+        DataType::Utf8 => split_bytes(array.as_string::<i32>(), &finder),
+        DataType::LargeUtf8 => split_bytes(array.as_string::<i64>(), &finder),
+        DataType::Utf8View => split_view(array.as_string_view(), &finder),
+        _ => Err(ArrowError::ComputeError(
+            "split() requires array to be either Utf8, Utf8View or LargeUtf8".to_string(),
+        )),
+    }
+}
+
+/// Returns `len` as an offset of type `O`, or an error if it overflows
+fn to_offset<O: OffsetSizeTrait>(len: usize) -> Result<O, ArrowError> {
+    O::from_usize(len).ok_or(ArrowError::OffsetOverflowError(len))
+}
+
+fn split_bytes<O: OffsetSizeTrait>(
+    array: &GenericStringArray<O>,
+    finder: &Finder<'_>,
+) -> Result<ArrayRef, ArrowError> {
+    let mut values = Vec::with_capacity(array.value_data().len());
+    let mut value_offsets = vec![O::usize_as(0)];
+    let mut list_offsets = Vec::with_capacity(array.len() + 1);
+    list_offsets.push(O::usize_as(0));
+
+    for i in 0..array.len() {
+        if array.is_valid(i) {
+            let haystack = array.value(i).as_bytes();
+            for range in split_ranges(haystack, finder) {
+                values.extend_from_slice(&haystack[range]);
+                value_offsets.push(to_offset(values.len())?);
+            }
+        }
+        list_offsets.push(to_offset(value_offsets.len() - 1)?);
+    }
+
+    // SAFETY: the offsets are monotonically increasing, and as the delimiter is valid
+    // UTF-8 the parts of each string start and end on character boundaries
+    let parts = unsafe {
+        GenericStringArray::<O>::new_unchecked(
+            OffsetBuffer::new_unchecked(value_offsets.into()),
+            values.into(),
+            None,
+        )
+    };
+    let field = Arc::new(Field::new_list_field(parts.data_type().clone(), true));
+    Ok(Arc::new(GenericListArray::<O>::new(
+        field,
+        OffsetBuffer::new(list_offsets.into()),
+        Arc::new(parts),
+        array.nulls().cloned(),
+    )))
+}
+
+/// Returns the view of the bytes `range` of the string with view `raw` and bytes `haystack`
+fn part_view(raw: u128, haystack: &[u8], range: Range<usize>) -> u128 {
+    // Parts longer than can be inlined are of strings that are not inlined, and
+    // so can reference the buffer of the string
+    let view = ByteView::from(raw);
+    make_view(
+        &haystack[range.clone()],
+        view.buffer_index,
+        view.offset + range.start as u32,
+    )
+}
+
+fn split_view(array: &StringViewArray, finder: &Finder<'_>) -> Result<ArrayRef, ArrowError> {
+    let mut views = Vec::with_capacity(array.len());
+    let mut list_offsets = Vec::with_capacity(array.len() + 1);
+    list_offsets.push(0);
+
+    for (i, raw) in array.views().iter().enumerate() {
+        if array.is_valid(i) {
+            let haystack = array.value(i).as_bytes();
+            views.extend(split_ranges(haystack, finder).map(|r| part_view(*raw, haystack, r)));
+        }
+        let offset =
+            i32::try_from(views.len()).map_err(|_| ArrowError::OffsetOverflowError(views.len()))?;
+        list_offsets.push(offset);
+    }
+
+    // SAFETY: the views reference the buffers of `array` within the bounds of its strings,
+    // and as the delimiter is valid UTF-8 the parts start and end on character boundaries
+    let parts = unsafe {
+        StringViewArray::new_unchecked(
+            ScalarBuffer::from(views),
+            array.data_buffers().to_vec(),
+            None,
+        )
+    };
+    let field = Arc::new(Field::new_list_field(DataType::Utf8View, true));
+    Ok(Arc::new(ListArray::new(
+        field,
+        OffsetBuffer::new(list_offsets.into()),
+        Arc::new(parts),
+        array.nulls().cloned(),
+    )))
+}
+
+/// Returns the `n`th part of each string of `array` split on `delimiter`
+///
+/// Parts are numbered from `1`, with negative `n` counting from the last part. The part of
+/// a string with fewer than `n` parts is the empty string, and the part of a null string
+/// is null. An empty `delimiter` does not split strings, and so the first and last part is
+/// the whole string.
+///
+/// Returns an array of the same type as `array`, which must be `Utf8`, `LargeUtf8` or
+/// `Utf8View`. The parts of a `Utf8View` array reference its buffers.
+///
+/// ```
+/// # use arrow_array::StringArray;
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_string::split::split_part;
+/// let array = StringArray::from(vec![Some("a.b.c"), Some("d"), None]);
+///
+/// let result = split_part(&array, ".", 2).unwrap();
+/// assert_eq!(result.as_string::<i32>(), &StringArray::from(vec![Some("b"), Some(""), None]));
+///
+/// let result = split_part(&array, ".", -1).unwrap();
+/// assert_eq!(result.as_string::<i32>(), &StringArray::from(vec![Some("c"), Some("d"), None]));
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if `n` is `0`
+pub fn split_part(array: &dyn Array, delimiter: &str, n: i64) -> Result<ArrayRef, ArrowError> {
+    if n == 0 {
+        return Err(ArrowError::InvalidArgumentError(
+            "split_part() part number must not be zero".to_string(),
+        ));
+    }
+    let finder = Finder::new(delimiter);
+    match array.data_type() {
+        DataType::Utf8 => Ok(split_part_bytes(array.as_string::<i32>(), &finder, n)),
+        DataType::LargeUtf8 => Ok(split_part_bytes(array.as_string::<i64>(), &finder, n)),
+        DataType::Utf8View => Ok(split_part_view(array.as_string_view(), &finder, n)),
+        _ => Err(ArrowError::ComputeError(
+            "split_part() requires array to be either Utf8, Utf8View or LargeUtf8".to_string(),
+        )),
+    }
+}
+
+fn split_part_bytes<O: OffsetSizeTrait>(
+    array: &GenericStringArray<O>,
+    finder: &Finder<'_>,
+    n: i64,
+) -> ArrayRef {
+    let mut values = Vec::with_capacity(array.value_data().len());
+    let mut offsets = Vec::with_capacity(array.len() + 1);
+    offsets.push(O::usize_as(0));
+
+    for i in 0..array.len() {
+        if array.is_valid(i) {
+            let haystack = array.value(i).as_bytes();
+            if let Some(range) = part_range(haystack, finder, n) {
+                values.extend_from_slice(&haystack[range]);
+            }
+        }
+        offsets.push(O::usize_as(values.len()));
+    }
+
+    // SAFETY: the offsets are monotonically increasing, and as the delimiter is valid
+    // UTF-8 the parts of each string start and end on character boundaries
+    Arc::new(unsafe {
+        GenericStringArray::<O>::new_unchecked(
+            OffsetBuffer::new_unchecked(offsets.into()),
+            values.into(),
+            array.nulls().cloned(),
+        )
+    })
+}
+
+fn split_part_view(array: &StringViewArray, finder: &Finder<'_>, n: i64) -> ArrayRef {
+    let views: Vec<u128> = array
+        .views()
+        .iter()
+        .enumerate()
+        .map(|(i, raw)| {
+            if array.is_null(i) {
+                return 0;
+            }
+            let haystack = array.value(i).as_bytes();
+            match part_range(haystack, finder, n) {
+                Some(range) => part_view(*raw, haystack, range),
+                None => 0,
+            }
+        })
+        .collect();
+
+    // SAFETY: the views reference the buffers of `array` within the bounds of its strings,
+    // and as the delimiter is valid UTF-8 the parts start and end on character boundaries
+    Arc::new(unsafe {
+        StringViewArray::new_unchecked(
+            views.into(),
+            array.data_buffers().to_vec(),
+            array.nulls().cloned(),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(array: &dyn Array) -> Vec<Option<String>> {
+        let to_string = |s: Option<&str>| s.map(String::from);
+        match array.data_type() {
+            DataType::Utf8 => array.as_string::<i32>().iter().map(to_string).collect(),
+            DataType::LargeUtf8 => array.as_string::<i64>().iter().map(to_string).collect(),
+            _ => array.as_string_view().iter().map(to_string).collect(),
+        }
+    }
+
+    fn parts(list: &dyn Array) -> Vec<Option<Vec<String>>> {
+        let parts = |a: ArrayRef| strings(&a).into_iter().map(Option::unwrap).collect();
+        match list.data_type() {
+            DataType::List(_) => list.as_list::<i32>().iter().map(|l| l.map(parts)).collect(),
+            _ => list.as_list::<i64>().iter().map(|l| l.map(parts)).collect(),
+        }
+    }
+
+    #[test]
+    fn test_split() {
+        let input = vec![
+            Some("a::b::c"),
+            None,
+            Some(""),
+            Some("::"),
+            Some("a string longer than twelve::bytes"),
+            Some("ünïcödé::✓"),
+        ];
+        let expected = vec![
+            Some(vec!["a", "b", "c"]),
+            None,
+            Some(vec![""]),
+            Some(vec!["", ""]),
+            Some(vec!["a string longer than twelve", "bytes"]),
+            Some(vec!["ünïcödé", "✓"]),
+        ];
+        let expected: Vec<_> = expected
+            .into_iter()
+            .map(|p| p.map(|p| p.into_iter().map(String::from).collect()))
+            .collect();
+
+        let arrays: Vec<ArrayRef> = vec![
+            Arc::new(StringArray::from(input.clone())),
+            Arc::new(LargeStringArray::from(input.clone())),
+            Arc::new(StringViewArray::from(input.clone())),
+        ];
+        for array in arrays {
+            let result = split(&array, "::").unwrap();
+            assert_eq!(parts(&result), expected, "{}", array.data_type());
+
+            // Slices are split
+            let result = split(&array.slice(3, 3), "::").unwrap();
+            assert_eq!(parts(&result), expected[3..], "{}", array.data_type());
+
+            // An empty delimiter does not split
+            let result = split(&array, "").unwrap();
+            assert_eq!(parts(&result)[0], Some(vec!["a::b::c".to_string()]));
+        }
+
+        let result = split(&StringArray::from(vec!["a,b"]), ",").unwrap();
+        assert_eq!(
+            result.data_type(),
+            &DataType::List(Arc::new(Field::new_list_field(DataType::Utf8, true)))
+        );
+
+        let err = split(&Int32Array::from(vec![1]), ",").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: split() requires array to be either Utf8, Utf8View or LargeUtf8"
+        );
+    }
+
+    #[test]
+    fn test_split_part() {
+        let input = vec![
+            Some("a::b::a string longer than twelve"),
+            None,
+            Some(""),
+            Some("single"),
+        ];
+        let cases = [
+            (1, vec![Some("a"), None, Some(""), Some("single")]),
+            (
+                3,
+                vec![
+                    Some("a string longer than twelve"),
+                    None,
+                    Some(""),
+                    Some(""),
+                ],
+            ),
+            (4, vec![Some(""), None, Some(""), Some("")]),
+            (
+                -1,
+                vec![
+                    Some("a string longer than twelve"),
+                    None,
+                    Some(""),
+                    Some("single"),
+                ],
+            ),
+            (-3, vec![Some("a"), None, Some(""), Some("")]),
+            (i64::MIN, vec![Some(""), None, Some(""), Some("")]),
+        ];
+
+        let arrays: Vec<ArrayRef> = vec![
+            Arc::new(StringArray::from(input.clone())),
+            Arc::new(LargeStringArray::from(input.clone())),
+            Arc::new(StringViewArray::from(input.clone())),
+        ];
+        for array in arrays {
+            for (n, expected) in &cases {
+                let result = split_part(&array, "::", *n).unwrap();
+                assert_eq!(result.data_type(), array.data_type());
+                let expected: Vec<_> = expected.iter().map(|s| s.map(String::from)).collect();
+                assert_eq!(strings(&result), expected, "{} {n}", array.data_type());
+            }
+        }
+
+        let err = split_part(&StringArray::from(vec!["a"]), ",", 0).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: split_part() part number must not be zero"
+        );
+    }
+}
@@ -29,6 +29,7 @@ pub use self::kernels::comparison::*;
 pub use self::kernels::concat::*;
 pub use self::kernels::filter::*;
 pub use self::kernels::interleave::*;
+pub use self::kernels::map::*;
 pub use self::kernels::nullif::*;
 pub use self::kernels::partition::*;
 pub use self::kernels::rank::*;
@@ -21,16 +21,16 @@
 use crate::{VariantArray, VariantArrayBuilder};
 use arrow::array::{Array, ArrayRef, LargeStringArray, StringArray, StringViewArray};
 use arrow_schema::ArrowError;
-use parquet_variant_json::JsonToVariant;
+use parquet_variant_json::{JsonToVariant, JsonToVariantOptions};
 
 /// Macro to convert string array to variant array
 macro_rules! string_array_to_variant {
-    ($input:expr, $array:expr, $builder:expr) => {{
+    ($input:expr, $array:expr, $builder:expr, $options:expr) => {{
         for i in 0..$input.len() {
             if $input.is_null(i) {
                 $builder.append_null();
             } else {
-                $builder.append_json($array.value(i))?;
+                $builder.append_json_with_options($array.value(i), $options)?;
             }
         }
     }};
@@ -45,15 +45,24 @@ macro_rules! string_array_to_variant {
 /// - [`LargeStringArray`]
 /// - [`StringViewArray`]
 pub fn json_to_variant(input: &ArrayRef) -> Result<VariantArray, ArrowError> {
+    json_to_variant_with_options(input, &JsonToVariantOptions::default())
+}
+
+/// Like [`json_to_variant`], but parses the JSON strings with the given [`JsonToVariantOptions`],
+/// controlling how numbers and duplicate keys are converted
+pub fn json_to_variant_with_options(
+    input: &ArrayRef,
+    options: &JsonToVariantOptions,
+) -> Result<VariantArray, ArrowError> {
     let mut variant_array_builder = VariantArrayBuilder::new(input.len());
 
     // Try each string array type in sequence
     if let Some(string_array) = input.as_any().downcast_ref::<StringArray>() {
-        string_array_to_variant!(input, string_array, variant_array_builder);
+        string_array_to_variant!(input, string_array, variant_array_builder, options);
     } else if let Some(large_string_array) = input.as_any().downcast_ref::<LargeStringArray>() {
-        string_array_to_variant!(input, large_string_array, variant_array_builder);
+        string_array_to_variant!(input, large_string_array, variant_array_builder, options);
     } else if let Some(string_view_array) = input.as_any().downcast_ref::<StringViewArray>() {
-        string_array_to_variant!(input, string_view_array, variant_array_builder);
+        string_array_to_variant!(input, string_view_array, variant_array_builder, options);
     } else {
         return Err(ArrowError::CastError(
             "Expected reference to StringArray, LargeStringArray, or StringViewArray as input"
@@ -66,10 +75,13 @@ pub fn json_to_variant(input: &ArrayRef) -> Result<VariantArray, ArrowError> {
 
 #[cfg(test)]
 mod test {
-    use crate::json_to_variant;
+    use crate::{
+        DuplicateKeyPolicy, JsonNumberMode, JsonToVariantOptions, json_to_variant,
+        json_to_variant_with_options,
+    };
     use arrow::array::{Array, ArrayRef, LargeStringArray, StringArray, StringViewArray};
     use arrow_schema::ArrowError;
-    use parquet_variant::{Variant, VariantBuilder};
+    use parquet_variant::{Variant, VariantBuilder, VariantDecimal4};
     use std::sync::Arc;
 
     #[test]
@@ -218,4 +230,28 @@ mod test {
         assert!(!value_array.is_null(4));
         Ok(())
     }
+
+    #[test]
+    fn test_json_to_variant_with_options() {
+        let input: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("1.25"),
+            None,
+            Some("{\"a\": 1, \"a\": 2}"),
+        ]));
+        let options = JsonToVariantOptions::new()
+            .with_number_mode(JsonNumberMode::IntOrDecimal)
+            .with_duplicate_keys(DuplicateKeyPolicy::FirstWins);
+        let variant_array = json_to_variant_with_options(&input, &options).unwrap();
+        assert_eq!(
+            variant_array.value(0),
+            Variant::from(VariantDecimal4::try_new(125, 2).unwrap())
+        );
+        assert!(variant_array.is_null(1));
+        let object = variant_array.value(2);
+        assert_eq!(object.get_object_field("a"), Some(Variant::Int8(1)));
+
+        let options = JsonToVariantOptions::new().with_duplicate_keys(DuplicateKeyPolicy::Error);
+        let err = json_to_variant_with_options(&input, &options).unwrap_err();
+        assert!(err.to_string().contains("duplicate key 'a'"), "{err}");
+    }
 }
@@ -22,12 +22,17 @@
 //! - [`VariantArrayBuilder`]: For building [`VariantArray`]
 //!
 //! # Compute Kernels
-//! - [`json_to_variant()`]: Function to convert Arrays of JSON strings to a `VariantArray`.
+//! - [`json_to_variant()`]: Function to convert Arrays of JSON strings to a `VariantArray`,
+//!   see [`json_to_variant_with_options()`] to configure the conversion.
 //! - [`variant_to_json()`]: Function to convert a `VariantArray` to arrays of JSON strings.
+//! - [`variant_path_to_json()`]: Convert the values at a path within a `VariantArray` to JSON strings.
 //! - [`cast_to_variant()`]: Cast Arrow arrays to `VariantArray`.
 //! - [`variant_get()`]: Convert `VariantArray` (or an inner path) to a strongly-typed Arrow array.
-//! - [`shred_variant()`]: Shred a `VariantArray` according to the provided shredding schema
+//! - [`shred_variant()`]: Shred a `VariantArray` according to the provided shredding schema,
+//!   see [`infer_shredding_type()`] to infer the schema from the data.
 //! - [`unshred_variant()`]: Unshred a `VariantArray` to pure binary variant.
+//! - [`variant_hash()`], [`variant_eq()`] and [`variant_sort_to_indices()`]: Hash, compare and
+//!   sort the values of a `VariantArray`, for example to use them as grouping or join keys.
 //!
 //! ## 🚧 Work In Progress
 //!
@@ -48,6 +53,7 @@ mod type_conversion;
 mod unshred_variant;
 mod variant_array;
 mod variant_array_builder;
+mod variant_compare;
 mod variant_get;
 mod variant_to_arrow;
 
@@ -55,8 +61,12 @@ pub use variant_array::{ShreddingState, VariantArray, VariantType};
 pub use variant_array_builder::{VariantArrayBuilder, VariantValueArrayBuilder};
 
 pub use cast_to_variant::{cast_to_variant, cast_to_variant_with_options};
-pub use from_json::json_to_variant;
-pub use shred_variant::{IntoShreddingField, ShreddedSchemaBuilder, shred_variant};
-pub use to_json::variant_to_json;
+pub use from_json::{json_to_variant, json_to_variant_with_options};
+pub use parquet_variant_json::{DuplicateKeyPolicy, JsonNumberMode, JsonToVariantOptions};
+pub use shred_variant::{
+    IntoShreddingField, ShreddedSchemaBuilder, infer_shredding_type, shred_variant,
+};
+pub use to_json::{variant_path_to_json, variant_to_json};
 pub use unshred_variant::unshred_variant;
+pub use variant_compare::{variant_eq, variant_hash, variant_sort_to_indices};
 pub use variant_get::{GetOptions, variant_get};
@@ -697,6 +697,138 @@ impl VariantSchemaNode {
     }
 }
 
+/// Infers a shredding type for the values of `array`, for example from a sample
+/// of the data to be written.
+///
+/// Object fields and list elements are inferred recursively, with integers of
+/// any width shredded as `Int64`. If the values at a path have different types,
+/// or are all null, that path is not shredded and remains in the residual
+/// `value` column.
+///
+/// Returns `None` if no part of the values can be shredded, otherwise the type
+/// to pass to [`shred_variant`].
+///
+/// # Example
+/// ```
+/// # use arrow::datatypes::{DataType, Field, Fields};
+/// # use parquet_variant_compute::{VariantArrayBuilder, infer_shredding_type};
+/// # use parquet_variant::VariantBuilderExt;
+/// let mut builder = VariantArrayBuilder::new(2);
+/// builder.new_object().with_field("id", 1i32).with_field("tag", "a").finish();
+/// builder.new_object().with_field("id", 2i64).with_field("tag", 3).finish();
+/// let array = builder.build();
+///
+/// // "tag" has values of different types, so only "id" is shredded
+/// let expected = DataType::Struct(Fields::from(vec![Field::new("id", DataType::Int64, true)]));
+/// assert_eq!(infer_shredding_type(&array), Some(expected));
+/// ```
+pub fn infer_shredding_type(array: &VariantArray) -> Option<DataType> {
+    let mut inferred = InferredType::Null;
+    for i in 0..array.len() {
+        if !array.is_null(i) {
+            inferred = inferred.merge(InferredType::of(&array.value(i)));
+        }
+    }
+    inferred.into_data_type()
+}
+
+/// The type of a set of variant values, see [`infer_shredding_type`]
+enum InferredType {
+    /// Only null values
+    Null,
+    Primitive(DataType),
+    Object(BTreeMap<String, InferredType>),
+    List(Box<InferredType>),
+    /// Values of different types
+    Mixed,
+}
+
+impl InferredType {
+    fn of(value: &Variant<'_, '_>) -> Self {
+        let data_type = match value {
+            Variant::Null => return Self::Null,
+            Variant::Object(obj) => {
+                return Self::Object(
+                    obj.iter()
+                        .map(|(name, value)| (name.to_string(), Self::of(&value)))
+                        .collect(),
+                );
+            }
+            Variant::List(list) => {
+                let element = list
+                    .iter()
+                    .fold(Self::Null, |acc, value| acc.merge(Self::of(&value)));
+                return Self::List(Box::new(element));
+            }
+            Variant::Int8(_) | Variant::Int16(_) | Variant::Int32(_) | Variant::Int64(_) => {
+                DataType::Int64
+            }
+            Variant::BooleanTrue | Variant::BooleanFalse => DataType::Boolean,
+            Variant::Float(_) => DataType::Float32,
+            Variant::Double(_) => DataType::Float64,
+            Variant::Decimal4(d) => DataType::Decimal32(9, d.scale() as i8),
+            Variant::Decimal8(d) => DataType::Decimal64(18, d.scale() as i8),
+            Variant::Decimal16(d) => DataType::Decimal128(38, d.scale() as i8),
+            Variant::Date(_) => DataType::Date32,
+            Variant::Time(_) => DataType::Time64(TimeUnit::Microsecond),
+            Variant::TimestampMicros(_) => {
+                DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into()))
+            }
+            Variant::TimestampNtzMicros(_) => DataType::Timestamp(TimeUnit::Microsecond, None),
+            Variant::TimestampNanos(_) => {
+                DataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".into()))
+            }
+            Variant::TimestampNtzNanos(_) => DataType::Timestamp(TimeUnit::Nanosecond, None),
+            Variant::String(_) | Variant::ShortString(_) => DataType::Utf8View,
+            Variant::Binary(_) => DataType::BinaryView,
+            Variant::Uuid(_) => DataType::FixedSizeBinary(16),
+        };
+        Self::Primitive(data_type)
+    }
+
+    fn merge(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::Null, other) | (other, Self::Null) => other,
+            (Self::Primitive(a), Self::Primitive(b)) if a == b => Self::Primitive(a),
+            (Self::Object(mut a), Self::Object(b)) => {
+                for (name, b) in b {
+                    let merged = match a.remove(&name) {
+                        Some(a) => a.merge(b),
+                        None => b,
+                    };
+                    a.insert(name, merged);
+                }
+                Self::Object(a)
+            }
+            (Self::List(a), Self::List(b)) => Self::List(Box::new(a.merge(*b))),
+            _ => Self::Mixed,
+        }
+    }
+
+    fn into_data_type(self) -> Option<DataType> {
+        match self {
+            Self::Null | Self::Mixed => None,
+            Self::Primitive(data_type) => Some(data_type),
+            Self::Object(fields) => {
+                let fields: Vec<_> = fields
+                    .into_iter()
+                    .filter_map(|(name, field)| {
+                        let data_type = field.into_data_type()?;
+                        Some(Field::new(name, data_type, true))
+                    })
+                    .collect();
+                (!fields.is_empty()).then(|| DataType::Struct(Fields::from(fields)))
+            }
+            Self::List(element) => {
+                let data_type = element.into_data_type()?;
+                Some(DataType::List(Arc::new(Field::new_list_field(
+                    data_type, true,
+                ))))
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2829,4 +2961,69 @@ mod tests {
         let shredding_type = ShreddedSchemaBuilder::default().build();
         assert_eq!(shredding_type, DataType::Null);
     }
+
+    #[test]
+    fn test_infer_shredding_type() {
+        let input = build_variant_array(vec![
+            VariantRow::Object(vec![
+                ("a", VariantValue::from(1i64)),
+                (
+                    "b",
+                    VariantValue::List(vec!["x".into(), VariantValue::Null]),
+                ),
+                ("c", VariantValue::from(1.5f64)),
+                ("d", VariantValue::Null),
+            ]),
+            VariantRow::Null,
+            VariantRow::Object(vec![
+                ("a", VariantValue::from(1000i16)),
+                ("c", VariantValue::from("mixed")),
+                (
+                    "e",
+                    VariantValue::Object(vec![("f", VariantValue::from(true))]),
+                ),
+            ]),
+        ]);
+
+        let expected = DataType::Struct(Fields::from(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new(
+                "b",
+                DataType::List(Arc::new(Field::new_list_field(DataType::Utf8View, true))),
+                true,
+            ),
+            Field::new(
+                "e",
+                DataType::Struct(Fields::from(vec![Field::new("f", DataType::Boolean, true)])),
+                true,
+            ),
+        ]));
+        let inferred = infer_shredding_type(&input).unwrap();
+        assert_eq!(inferred, expected);
+
+        // the inferred type can be used to shred the input
+        let shredded = shred_variant(&input, &inferred).unwrap();
+        let unshredded = crate::unshred_variant(&shredded).unwrap();
+        assert_eq!(unshredded.value(0), input.value(0));
+        assert!(unshredded.is_null(1));
+        // integers are widened to the shredded type
+        let row = unshredded.value(2);
+        assert_eq!(row.get_object_field("a"), Some(Variant::Int64(1000)));
+        assert_eq!(row.get_object_field("c"), Some(Variant::from("mixed")));
+    }
+
+    #[test]
+    fn test_infer_shredding_type_unshreddable() {
+        let mixed = build_variant_array(vec![
+            VariantRow::Value(VariantValue::from(1i32)),
+            VariantRow::Value(VariantValue::from("one")),
+        ]);
+        assert_eq!(infer_shredding_type(&mixed), None);
+
+        let nulls = build_variant_array(vec![
+            VariantRow::Null,
+            VariantRow::Value(VariantValue::Null),
+        ]);
+        assert_eq!(infer_shredding_type(&nulls), None);
+    }
 }
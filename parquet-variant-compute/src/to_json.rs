@@ -22,9 +22,11 @@ use arrow::array::{Array, ArrayRef, BinaryArray, BooleanBufferBuilder, StringArr
 use arrow::buffer::{Buffer, NullBuffer, OffsetBuffer, ScalarBuffer};
 use arrow::datatypes::DataType;
 use arrow_schema::ArrowError;
-use parquet_variant::Variant;
+use parquet_variant::{Variant, VariantPath};
 use parquet_variant_json::VariantToJson;
 
+use crate::VariantArray;
+
 /// Transform a batch of Variant represented as STRUCT<metadata: BINARY, value: BINARY> to a batch
 /// of JSON strings where nulls are preserved. The JSON strings in the input must be valid.
 pub fn variant_to_json(input: &ArrayRef) -> Result<StringArray, ArrowError> {
@@ -98,6 +100,51 @@ pub fn variant_to_json(input: &ArrayRef) -> Result<StringArray, ArrowError> {
     StringArray::try_new(offsets_buffer, value_buffer, Some(null_buffer))
 }
 
+/// Transform a batch of Variants to a batch of JSON strings of the values at `path` within each
+/// Variant, such as `$.a.b[2]` parsed with [`VariantPath::try_from_json_path`]
+///
+/// Rows that are null, or have no value at `path`, are null in the output. Unlike extracting the
+/// values with [`variant_get`] and then calling [`variant_to_json`], this renders the projected
+/// values directly, without building an intermediate `VariantArray`. Shredded inputs are
+/// supported.
+///
+/// [`variant_get`]: crate::variant_get
+pub fn variant_path_to_json(
+    input: &ArrayRef,
+    path: &VariantPath,
+) -> Result<StringArray, ArrowError> {
+    let variant_array = VariantArray::try_new(input)?;
+
+    let mut json_buffer: Vec<u8> = Vec::with_capacity(variant_array.len() * 16);
+    let mut offsets: Vec<i32> = Vec::with_capacity(variant_array.len() + 1);
+    let mut validity = BooleanBufferBuilder::new(variant_array.len());
+    offsets.push(0);
+
+    for i in 0..variant_array.len() {
+        let variant = match variant_array.is_null(i) {
+            true => None,
+            false => Some(variant_array.try_value(i)?),
+        };
+        let found = match variant.as_ref().and_then(|v| v.get_path(path)) {
+            Some(value) => {
+                value.to_json(&mut json_buffer)?;
+                true
+            }
+            None => false,
+        };
+        let offset = i32::try_from(json_buffer.len())
+            .map_err(|_| ArrowError::ComputeError("JSON output exceeds i32 offsets".into()))?;
+        offsets.push(offset);
+        validity.append(found);
+    }
+
+    let offsets_buffer = OffsetBuffer::new(ScalarBuffer::from(offsets));
+    let value_buffer = Buffer::from_vec(json_buffer);
+    let null_buffer = NullBuffer::new(validity.finish());
+
+    StringArray::try_new(offsets_buffer, value_buffer, Some(null_buffer))
+}
+
 #[cfg(test)]
 mod test {
     use crate::variant_to_json;
@@ -174,4 +221,38 @@ mod test {
 
         assert_eq!(result_vec, expected);
     }
+
+    #[test]
+    fn test_variant_path_to_json() {
+        use crate::{json_to_variant, shred_variant, variant_path_to_json};
+        use arrow::array::StringArray;
+        use parquet_variant::VariantPath;
+
+        let input: ArrayRef = Arc::new(StringArray::from(vec![
+            Some(r#"{"a": {"b": [1, 2, {"c": "x"}]}}"#),
+            None,
+            Some(r#"{"a": {"b": [3]}}"#),
+            Some(r#"{"a": 5}"#),
+            Some(r#"{"a": {"b": [4, 5, null]}}"#),
+        ]));
+        let variant_array = json_to_variant(&input).unwrap();
+        let expected =
+            StringArray::from(vec![Some(r#"{"c":"x"}"#), None, None, None, Some("null")]);
+
+        let path = VariantPath::try_from_json_path("$.a.b[2]").unwrap();
+        let array: ArrayRef = variant_array.clone().into();
+        assert_eq!(variant_path_to_json(&array, &path).unwrap(), expected);
+
+        let path = VariantPath::try_from_json_path("$.a").unwrap();
+        let result = variant_path_to_json(&array, &path).unwrap();
+        assert_eq!(result.value(3), "5");
+
+        // shredded input
+        let shredded = shred_variant(&variant_array, &DataType::Int64).unwrap();
+        let shredded: ArrayRef = shredded.into();
+        let path = VariantPath::try_from_json_path("$").unwrap();
+        let result = variant_path_to_json(&shredded, &path).unwrap();
+        assert_eq!(result.value(2), r#"{"a":{"b":[3]}}"#);
+        assert!(result.is_null(1));
+    }
 }
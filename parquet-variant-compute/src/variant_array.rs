@@ -1712,6 +1712,31 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_variant_array_ipc_roundtrip() {
+        use arrow::array::RecordBatch;
+        use arrow::ipc::reader::StreamReader;
+        use arrow::ipc::writer::StreamWriter;
+        use arrow_schema::Schema;
+
+        let array = VariantArray::from_iter([Some(Variant::from(1)), None, Some("a".into())]);
+        let schema = Arc::new(Schema::new(vec![array.field("v")]));
+        let batch =
+            RecordBatch::try_new(schema.clone(), vec![ArrayRef::from(array.clone())]).unwrap();
+
+        let mut buf = vec![];
+        let mut writer = StreamWriter::try_new(&mut buf, &schema).unwrap();
+        writer.write(&batch).unwrap();
+        writer.finish().unwrap();
+        drop(writer);
+
+        let mut reader = StreamReader::try_new(buf.as_slice(), None).unwrap();
+        let read = reader.next().unwrap().unwrap();
+        let field = read.schema().field(0).clone();
+        field.try_extension_type::<VariantType>().unwrap();
+        assert_eq!(VariantArray::try_new(read.column(0)).unwrap(), array);
+    }
+
     #[test]
     fn binary_typed_value_roundtrips() {
         // Verify that a shredded variant with Binary typed_value can be read back
@@ -0,0 +1,217 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Kernels comparing and hashing the values of a [`VariantArray`], for example to use them as
+//! sort, grouping or join keys.
+
+use std::borrow::Cow;
+use std::hash::{DefaultHasher, Hasher};
+
+use arrow::array::{BooleanArray, UInt32Array, UInt64Array};
+use arrow::buffer::NullBuffer;
+use arrow::compute::SortOptions;
+use arrow::error::Result;
+use arrow_schema::ArrowError;
+use parquet_variant::Variant;
+
+use crate::{VariantArray, unshred_variant};
+
+/// Returns `array`, unshredding it if needed so that all its values can be read
+fn unshredded(array: &VariantArray) -> Result<Cow<'_, VariantArray>> {
+    match array.typed_value_column() {
+        Some(_) => Ok(Cow::Owned(unshred_variant(array)?)),
+        None => Ok(Cow::Borrowed(array)),
+    }
+}
+
+/// Reads the non null values of `array`
+fn values(array: &VariantArray) -> Result<Vec<Option<Variant<'_, '_>>>> {
+    (0..array.len())
+        .map(|i| array.is_valid(i).then(|| array.try_value(i)).transpose())
+        .collect()
+}
+
+/// Computes the hash of each value of `array`, consistent with [`Variant::total_cmp`]: equal
+/// values, such as `1` and `1.0` stored as an integer and a decimal, have the same hash.
+///
+/// Null rows have a null hash. The hashes are deterministic, but are not guaranteed to be
+/// the same across releases and should not be persisted.
+///
+/// # Example
+/// ```
+/// # use parquet_variant::{Variant, VariantDecimal4};
+/// # use arrow::array::Array;
+/// # use parquet_variant_compute::{VariantArray, variant_hash};
+/// let array = VariantArray::from_iter([
+///     Some(Variant::from(1i8)),
+///     Some(Variant::from(VariantDecimal4::try_new(10, 1).unwrap())),
+///     None,
+/// ]);
+/// let hashes = variant_hash(&array).unwrap();
+/// assert_eq!(hashes.value(0), hashes.value(1));
+/// assert!(hashes.is_null(2));
+/// ```
+pub fn variant_hash(array: &VariantArray) -> Result<UInt64Array> {
+    let array = unshredded(array)?;
+    let hashes = values(&array)?
+        .into_iter()
+        .map(|value| {
+            value.map(|value| {
+                let mut hasher = DefaultHasher::new();
+                value.total_hash(&mut hasher);
+                hasher.finish()
+            })
+        })
+        .collect();
+    Ok(hashes)
+}
+
+/// Compares the values of `left` and `right` row by row for equality according to
+/// [`Variant::total_cmp`], for example to evaluate a join condition.
+///
+/// The result is null if either value is null.
+///
+/// # Errors
+///
+/// If the arrays have different lengths.
+pub fn variant_eq(left: &VariantArray, right: &VariantArray) -> Result<BooleanArray> {
+    if left.len() != right.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Cannot compare VariantArrays of different lengths, got {} and {}",
+            left.len(),
+            right.len()
+        )));
+    }
+    let (left, right) = (unshredded(left)?, unshredded(right)?);
+    let nulls = NullBuffer::union(left.nulls(), right.nulls());
+    let values = values(&left)?
+        .into_iter()
+        .zip(values(&right)?)
+        .map(|(l, r)| match (l, r) {
+            (Some(l), Some(r)) => l.total_cmp(&r).is_eq(),
+            _ => false,
+        })
+        .collect();
+    Ok(BooleanArray::new(values, nulls))
+}
+
+/// Returns the indices that would sort `array` according to [`Variant::total_cmp`], similar
+/// to [`arrow::compute::sort_to_indices`]. The sort is stable.
+///
+/// # Example
+/// ```
+/// # use parquet_variant::Variant;
+/// # use parquet_variant_compute::{VariantArray, variant_sort_to_indices};
+/// let array = VariantArray::from_iter([
+///     Some(Variant::from("a")),
+///     None,
+///     Some(Variant::from(2.5)),
+///     Some(Variant::from(1)),
+/// ]);
+/// let indices = variant_sort_to_indices(&array, None).unwrap();
+/// assert_eq!(indices.values(), &[1, 3, 2, 0]);
+/// ```
+pub fn variant_sort_to_indices(
+    array: &VariantArray,
+    options: Option<SortOptions>,
+) -> Result<UInt32Array> {
+    let options = options.unwrap_or_default();
+    let array = unshredded(array)?;
+    let values = values(&array)?;
+    let mut indices: Vec<u32> = (0..values.len() as u32).collect();
+    indices.sort_by(|a, b| match (&values[*a as usize], &values[*b as usize]) {
+        (Some(a), Some(b)) => {
+            let ordering = a.total_cmp(b);
+            if options.descending {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        }
+        (None, None) => std::cmp::Ordering::Equal,
+        (None, Some(_)) if options.nulls_first => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (Some(_), None) if options.nulls_first => std::cmp::Ordering::Greater,
+        (Some(_), None) => std::cmp::Ordering::Less,
+    });
+    Ok(UInt32Array::from(indices))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shred_variant;
+    use arrow::datatypes::DataType;
+    use parquet_variant::VariantDecimal8;
+
+    #[test]
+    fn test_variant_hash_shredded() {
+        let array = VariantArray::from_iter([
+            Some(Variant::from(1i64)),
+            Some(Variant::from("1")),
+            Some(Variant::from(VariantDecimal8::try_new(100, 2).unwrap())),
+        ]);
+        let shredded = shred_variant(&array, &DataType::Int64).unwrap();
+        let expected = variant_hash(&array).unwrap();
+        assert_eq!(variant_hash(&shredded).unwrap(), expected);
+        assert_eq!(expected.value(0), expected.value(2));
+        assert_ne!(expected.value(0), expected.value(1));
+    }
+
+    #[test]
+    fn test_variant_eq() {
+        let left = VariantArray::from_iter([
+            Some(Variant::from(1i8)),
+            Some(Variant::from("a")),
+            None,
+            Some(Variant::Null),
+        ]);
+        let right = VariantArray::from_iter([
+            Some(Variant::from(1i32)),
+            Some(Variant::from("b")),
+            Some(Variant::from("c")),
+            Some(Variant::Null),
+        ]);
+        let eq = variant_eq(&left, &right).unwrap();
+        assert_eq!(
+            eq,
+            BooleanArray::from(vec![Some(true), Some(false), None, Some(true)])
+        );
+
+        let err = variant_eq(&left, &right.slice(0, 2)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot compare VariantArrays of different lengths, got 4 and 2"
+        );
+    }
+
+    #[test]
+    fn test_variant_sort_to_indices_options() {
+        let array = VariantArray::from_iter([
+            Some(Variant::from(2)),
+            None,
+            Some(Variant::from(1)),
+            Some(Variant::from(2i8)),
+        ]);
+        let options = SortOptions {
+            descending: true,
+            nulls_first: false,
+        };
+        let indices = variant_sort_to_indices(&array, Some(options)).unwrap();
+        assert_eq!(indices.values(), &[0, 3, 2, 1]);
+    }
+}
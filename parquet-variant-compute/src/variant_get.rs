@@ -494,7 +494,7 @@ mod test {
         Date64Array, Decimal32Array, Decimal64Array, Decimal128Array, Decimal256Array,
         FixedSizeListArray, Float32Array, Float64Array, Int8Array, Int16Array, Int32Array,
         Int64Array, LargeBinaryArray, LargeListArray, LargeListViewArray, LargeStringArray,
-        ListArray, ListViewArray, NullArray, NullBuilder, StringArray, StringViewArray,
+        ListArray, ListViewArray, MapArray, NullArray, NullBuilder, StringArray, StringViewArray,
         StructArray, Time32MillisecondArray, Time32SecondArray, Time64MicrosecondArray,
         Time64NanosecondArray,
     };
@@ -4907,6 +4907,96 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_variant_get_map() {
+        let string_array: ArrayRef = Arc::new(StringArray::from(vec![
+            r#"{"m": {"b": 1, "a": "two"}}"#,
+            r#"{"m": "not an object"}"#,
+            r#"{"other": 1}"#,
+            r#"{"m": {}}"#,
+        ]));
+        let variant_array = ArrayRef::from(json_to_variant(&string_array).unwrap());
+
+        let entries = Arc::new(Field::new(
+            "entries",
+            DataType::Struct(Fields::from(vec![
+                Field::new("key", DataType::Utf8, false),
+                Field::new("value", Int64, true),
+            ])),
+            false,
+        ));
+        let request_type = DataType::Map(entries.clone(), false);
+        let options =
+            GetOptions::new_with_path(VariantPath::try_from("m").unwrap()).with_as_type(Some(
+                FieldRef::from(Field::new("result", request_type.clone(), true)),
+            ));
+        let result = variant_get(&variant_array, options).unwrap();
+
+        // object fields are sorted by name, and values that fail to cast are null
+        let expected: ArrayRef = Arc::new(MapArray::new(
+            entries,
+            OffsetBuffer::new(ScalarBuffer::from(vec![0, 2, 2, 2, 2])),
+            StructArray::new(
+                Fields::from(vec![
+                    Field::new("key", DataType::Utf8, false),
+                    Field::new("value", Int64, true),
+                ]),
+                vec![
+                    Arc::new(StringArray::from(vec!["a", "b"])),
+                    Arc::new(Int64Array::from(vec![None, Some(1)])),
+                ],
+                None,
+            ),
+            Some(NullBuffer::from(vec![true, false, false, true])),
+            false,
+        ));
+        assert_eq!(&result, &expected);
+
+        // With `safe` set to false, a non-object value is an error
+        let string_array: ArrayRef = Arc::new(StringArray::from(vec![
+            r#"{"m": {"a": 1}}"#,
+            r#"{"m": "not an object"}"#,
+        ]));
+        let variant_array = ArrayRef::from(json_to_variant(&string_array).unwrap());
+        let options = GetOptions::new_with_path(VariantPath::try_from("m").unwrap())
+            .with_as_type(Some(FieldRef::from(Field::new(
+                "result",
+                request_type,
+                true,
+            ))))
+            .with_cast_options(CastOptions {
+                safe: false,
+                ..Default::default()
+            });
+        let err = variant_get(&variant_array, options).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Failed to extract map from variant"),
+            "got: {err}",
+        );
+    }
+
+    #[test]
+    fn test_variant_get_map_rejects_non_string_keys() {
+        let string_array: ArrayRef = Arc::new(StringArray::from(vec![r#"{"a": 1}"#]));
+        let variant_array = ArrayRef::from(json_to_variant(&string_array).unwrap());
+        let entries = Arc::new(Field::new(
+            "entries",
+            DataType::Struct(Fields::from(vec![
+                Field::new("key", Int32, false),
+                Field::new("value", Int64, true),
+            ])),
+            false,
+        ));
+        let options = GetOptions::new().with_as_type(Some(FieldRef::from(Field::new(
+            "result",
+            DataType::Map(entries, false),
+            true,
+        ))));
+        let err = variant_get(&variant_array, options).unwrap_err();
+        assert!(err.to_string().contains("Int32 keys"), "got: {err}");
+    }
+
     macro_rules! perfectly_shredded_preserves_top_level_nulls_test {
         ($name:ident, $result_type:expr, $typed_value:expr, $expected_array:expr) => {
             perfectly_shredded_preserves_top_level_nulls_test!(
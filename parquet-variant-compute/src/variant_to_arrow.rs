@@ -28,9 +28,9 @@ use crate::{VariantArray, VariantValueArrayBuilder};
 use arrow::array::{
     ArrayRef, ArrowNativeTypeOp, BinaryBuilder, BinaryLikeArrayBuilder, BinaryViewBuilder,
     BooleanBuilder, FixedSizeBinaryBuilder, FixedSizeListArray, GenericListArray,
-    GenericListViewArray, LargeBinaryBuilder, LargeStringBuilder, NullArray, NullBufferBuilder,
-    OffsetSizeTrait, PrimitiveBuilder, StringBuilder, StringLikeArrayBuilder, StringViewBuilder,
-    StructArray,
+    GenericListViewArray, LargeBinaryBuilder, LargeStringBuilder, MapArray, NullArray,
+    NullBufferBuilder, OffsetSizeTrait, PrimitiveBuilder, StringBuilder, StringLikeArrayBuilder,
+    StringViewBuilder, StructArray,
 };
 use arrow::buffer::{OffsetBuffer, ScalarBuffer};
 use arrow::compute::{CastOptions, DecimalCast, cast_with_options};
@@ -48,6 +48,7 @@ pub(crate) enum VariantToArrowRowBuilder<'a> {
     Primitive(PrimitiveVariantToArrowRowBuilder<'a>),
     Array(ArrayVariantToArrowRowBuilder<'a>),
     Struct(StructVariantToArrowRowBuilder<'a>),
+    Map(MapVariantToArrowRowBuilder<'a>),
     Encoded(EncodedVariantToArrowRowBuilder<'a>),
     BinaryVariant(VariantToBinaryVariantArrowRowBuilder),
 
@@ -62,6 +63,7 @@ impl<'a> VariantToArrowRowBuilder<'a> {
             Primitive(b) => b.append_null(),
             Array(b) => b.append_null(),
             Struct(b) => b.append_null(),
+            Map(b) => b.append_null(),
             Encoded(b) => b.append_null(),
             BinaryVariant(b) => b.append_null(),
             WithPath(path_builder) => path_builder.append_null(),
@@ -74,6 +76,7 @@ impl<'a> VariantToArrowRowBuilder<'a> {
             Primitive(b) => b.append_value(&value),
             Array(b) => b.append_value(&value),
             Struct(b) => b.append_value(&value),
+            Map(b) => b.append_value(&value),
             Encoded(b) => b.append_value(value),
             BinaryVariant(b) => b.append_value(value),
             WithPath(path_builder) => path_builder.append_value(value),
@@ -86,6 +89,7 @@ impl<'a> VariantToArrowRowBuilder<'a> {
             Primitive(b) => b.finish(),
             Array(b) => b.finish(),
             Struct(b) => b.finish(),
+            Map(b) => b.finish(),
             Encoded(b) => b.finish(),
             BinaryVariant(b) => b.finish(),
             WithPath(path_builder) => path_builder.finish(),
@@ -105,6 +109,11 @@ fn make_typed_variant_to_arrow_row_builder<'a>(
             let builder = StructVariantToArrowRowBuilder::try_new(fields, cast_options, capacity)?;
             Ok(Struct(builder))
         }
+        DataType::Map(entries, sorted) => {
+            let builder =
+                MapVariantToArrowRowBuilder::try_new(entries, *sorted, cast_options, capacity)?;
+            Ok(Map(builder))
+        }
         data_type @ (DataType::List(_)
         | DataType::LargeList(_)
         | DataType::ListView(_)
@@ -648,6 +657,113 @@ impl<'a> StructVariantToArrowRowBuilder<'a> {
     }
 }
 
+/// Builds a [`MapArray`] from variant objects, with one entry per object field
+///
+/// The keys are the field names, and the values are converted to the value type of the map.
+pub(crate) struct MapVariantToArrowRowBuilder<'a> {
+    entries: &'a FieldRef,
+    sorted: bool,
+    offsets: Vec<i32>,
+    keys: PrimitiveVariantToArrowRowBuilder<'a>,
+    values: Box<VariantToArrowRowBuilder<'a>>,
+    nulls: NullBufferBuilder,
+    current_offset: i32,
+    cast_options: &'a CastOptions<'a>,
+}
+
+impl<'a> MapVariantToArrowRowBuilder<'a> {
+    fn try_new(
+        entries: &'a FieldRef,
+        sorted: bool,
+        cast_options: &'a CastOptions<'a>,
+        capacity: usize,
+    ) -> Result<Self> {
+        let (key_field, value_field) = match entries.data_type() {
+            DataType::Struct(fields) if fields.len() == 2 => (&fields[0], &fields[1]),
+            other => {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Map entries must be a struct of key and value fields, got {other:?}"
+                )));
+            }
+        };
+        if !matches!(
+            key_field.data_type(),
+            DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View
+        ) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Casting to a map with {:?} keys is not applicable for object Variant types",
+                key_field.data_type()
+            )));
+        }
+        let mut offsets = Vec::with_capacity(capacity + 1);
+        offsets.push(0);
+        Ok(Self {
+            entries,
+            sorted,
+            offsets,
+            keys: make_primitive_variant_to_arrow_row_builder(
+                key_field.data_type(),
+                cast_options,
+                capacity,
+            )?,
+            values: Box::new(make_typed_variant_to_arrow_row_builder(
+                value_field.data_type(),
+                cast_options,
+                capacity,
+            )?),
+            nulls: NullBufferBuilder::new(capacity),
+            current_offset: 0,
+            cast_options,
+        })
+    }
+
+    fn append_null(&mut self) -> Result<()> {
+        self.offsets.push(self.current_offset);
+        self.nulls.append_null();
+        Ok(())
+    }
+
+    fn append_value(&mut self, value: &Variant<'_, '_>) -> Result<bool> {
+        match variant_cast_with_options(value, self.cast_options, Variant::as_object) {
+            Ok(Some(obj)) => {
+                for (name, field_value) in obj.iter() {
+                    self.keys.append_value(&Variant::from(name))?;
+                    self.values.append_value(field_value)?;
+                    self.current_offset = self.current_offset.add_checked(1)?;
+                }
+                self.offsets.push(self.current_offset);
+                self.nulls.append_non_null();
+                Ok(true)
+            }
+            Ok(None) => {
+                self.append_null()?;
+                Ok(false)
+            }
+            Err(_) => Err(ArrowError::CastError(format!(
+                "Failed to extract map from variant {value:?}"
+            ))),
+        }
+    }
+
+    fn finish(mut self) -> Result<ArrayRef> {
+        let DataType::Struct(fields) = self.entries.data_type() else {
+            unreachable!("map entries validated in try_new")
+        };
+        let entries = StructArray::try_new(
+            fields.clone(),
+            vec![self.keys.finish()?, self.values.finish()?],
+            None,
+        )?;
+        Ok(Arc::new(MapArray::try_new(
+            self.entries.clone(),
+            OffsetBuffer::new(ScalarBuffer::from(self.offsets)),
+            entries,
+            self.nulls.finish(),
+            self.sorted,
+        )?))
+    }
+}
+
 impl<'a> ArrayVariantToArrowRowBuilder<'a> {
     /// Creates a new list builder for the given data type.
     ///
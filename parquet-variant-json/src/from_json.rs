@@ -17,10 +17,95 @@
 
 //! Module for parsing JSON strings as Variant
 
+use std::borrow::Cow;
+use std::collections::HashSet;
+
 use arrow_schema::ArrowError;
-use parquet_variant::{ObjectFieldBuilder, Variant, VariantBuilderExt};
+use parquet_variant::{
+    ObjectFieldBuilder, Variant, VariantBuilderExt, VariantDecimal4, VariantDecimal8,
+    VariantDecimal16,
+};
 use serde_json::{Number, Value};
 
+/// How JSON numbers are converted to Variant values
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum JsonNumberMode {
+    /// Integers that fit in 64 bits are converted to the narrowest Variant integer type, and all
+    /// other numbers to [`Variant::Double`] (default)
+    #[default]
+    IntOrDouble,
+    /// Like [`Self::IntOrDouble`], except that numbers are converted to the narrowest Variant
+    /// decimal type that represents them exactly, falling back to [`Variant::Double`] if they
+    /// need more than 38 digits
+    IntOrDecimal,
+    /// All numbers are converted to [`Variant::Double`]
+    Double,
+}
+
+/// How duplicate keys in a JSON object are handled
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DuplicateKeyPolicy {
+    /// The last value of a duplicate key is kept (default)
+    #[default]
+    LastWins,
+    /// The first value of a duplicate key is kept
+    FirstWins,
+    /// Duplicate keys produce an error
+    Error,
+}
+
+/// The default value of [`JsonToVariantOptions::max_depth`]
+pub const DEFAULT_MAX_DEPTH: usize = 128;
+
+/// Options for converting JSON strings to Variant, see [`JsonToVariant::append_json_with_options`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonToVariantOptions {
+    /// How JSON numbers are converted
+    pub number_mode: JsonNumberMode,
+    /// How duplicate keys in JSON objects are handled
+    pub duplicate_keys: DuplicateKeyPolicy,
+    /// The maximum nesting depth of arrays and objects, beyond which an error is returned
+    pub max_depth: usize,
+}
+
+impl Default for JsonToVariantOptions {
+    fn default() -> Self {
+        Self {
+            number_mode: JsonNumberMode::default(),
+            duplicate_keys: DuplicateKeyPolicy::default(),
+            max_depth: DEFAULT_MAX_DEPTH,
+        }
+    }
+}
+
+impl JsonToVariantOptions {
+    /// Create a new [`JsonToVariantOptions`] with the default settings
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets how JSON numbers are converted (defaults to [`JsonNumberMode::IntOrDouble`])
+    pub fn with_number_mode(mut self, number_mode: JsonNumberMode) -> Self {
+        self.number_mode = number_mode;
+        self
+    }
+
+    /// Sets how duplicate keys in JSON objects are handled (defaults to
+    /// [`DuplicateKeyPolicy::LastWins`])
+    pub fn with_duplicate_keys(mut self, duplicate_keys: DuplicateKeyPolicy) -> Self {
+        self.duplicate_keys = duplicate_keys;
+        self
+    }
+
+    /// Sets the maximum nesting depth of arrays and objects (defaults to [`DEFAULT_MAX_DEPTH`])
+    ///
+    /// Nested values are parsed recursively, so very large depths may overflow the stack.
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+}
+
 /// Converts a JSON string to Variant using a [`VariantBuilderExt`], such as
 /// [`VariantBuilder`].
 ///
@@ -65,31 +150,487 @@ use serde_json::{Number, Value};
 /// ```
 pub trait JsonToVariant {
     /// Create a Variant from a JSON string
-    fn append_json(&mut self, json: &str) -> Result<(), ArrowError>;
+    fn append_json(&mut self, json: &str) -> Result<(), ArrowError> {
+        self.append_json_with_options(json, &JsonToVariantOptions::default())
+    }
+
+    /// Create a Variant from a JSON string, with the given [`JsonToVariantOptions`]
+    ///
+    /// The JSON is parsed directly into the builder, without an intermediate representation.
+    ///
+    /// ```rust
+    /// # use parquet_variant::{Variant, VariantBuilder, VariantDecimal4};
+    /// # use parquet_variant_json::{JsonNumberMode, JsonToVariant, JsonToVariantOptions};
+    /// let options = JsonToVariantOptions::new().with_number_mode(JsonNumberMode::IntOrDecimal);
+    /// let mut builder = VariantBuilder::new();
+    /// builder.append_json_with_options("1.23", &options)?;
+    /// let (metadata, value) = builder.finish();
+    /// let variant = Variant::try_new(&metadata, &value)?;
+    /// assert_eq!(variant, Variant::from(VariantDecimal4::try_new(123, 2)?));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    fn append_json_with_options(
+        &mut self,
+        json: &str,
+        options: &JsonToVariantOptions,
+    ) -> Result<(), ArrowError>;
 }
 
 impl<T: VariantBuilderExt> JsonToVariant for T {
-    fn append_json(&mut self, json: &str) -> Result<(), ArrowError> {
-        let json: Value = serde_json::from_str(json)
-            .map_err(|e| ArrowError::InvalidArgumentError(format!("JSON format error: {e}")))?;
+    fn append_json_with_options(
+        &mut self,
+        json: &str,
+        options: &JsonToVariantOptions,
+    ) -> Result<(), ArrowError> {
+        JsonParser::new(json, options).parse(self)
+    }
+}
+
+/// A streaming JSON parser appending the parsed values to a [`VariantBuilderExt`]
+struct JsonParser<'a> {
+    json: &'a str,
+    pos: usize,
+    depth: usize,
+    options: &'a JsonToVariantOptions,
+}
+
+impl<'a> JsonParser<'a> {
+    fn new(json: &'a str, options: &'a JsonToVariantOptions) -> Self {
+        Self {
+            json,
+            pos: 0,
+            depth: 0,
+            options,
+        }
+    }
+
+    /// Parses the whole input as a single JSON value
+    fn parse(&mut self, builder: &mut impl VariantBuilderExt) -> Result<(), ArrowError> {
+        self.skip_whitespace();
+        self.parse_value(builder)?;
+        self.skip_whitespace();
+        match self.peek() {
+            None => Ok(()),
+            Some(_) => Err(self.error("trailing characters")),
+        }
+    }
+
+    fn parse_value(&mut self, builder: &mut impl VariantBuilderExt) -> Result<(), ArrowError> {
+        match self.peek() {
+            Some(b'n') => {
+                self.expect_literal("null")?;
+                builder.append_value(Variant::Null);
+            }
+            Some(b't') => {
+                self.expect_literal("true")?;
+                builder.append_value(true);
+            }
+            Some(b'f') => {
+                self.expect_literal("false")?;
+                builder.append_value(false);
+            }
+            Some(b'"') => {
+                let s = self.parse_string()?;
+                builder.append_value(s.as_ref());
+            }
+            Some(b'-' | b'0'..=b'9') => {
+                let number = self.parse_number()?;
+                builder.append_value(number);
+            }
+            Some(b'[') => {
+                self.enter()?;
+                let mut list_builder = builder.try_new_list()?;
+                if !self.consume_if(b']') {
+                    loop {
+                        self.skip_whitespace();
+                        self.parse_value(&mut list_builder)?;
+                        if !self.next_element(b']')? {
+                            break;
+                        }
+                    }
+                }
+                list_builder.finish();
+                self.depth -= 1;
+            }
+            Some(b'{') => {
+                self.enter()?;
+                let mut obj_builder = builder.try_new_object()?;
+                let mut keys = HashSet::new();
+                if !self.consume_if(b'}') {
+                    loop {
+                        self.skip_whitespace();
+                        let key_pos = self.pos;
+                        let key = self.parse_string()?;
+                        self.skip_whitespace();
+                        if !self.consume_if(b':') {
+                            return Err(self.error("expected ':'"));
+                        }
+                        self.skip_whitespace();
+                        let duplicate = match self.options.duplicate_keys {
+                            DuplicateKeyPolicy::LastWins => false,
+                            _ => !keys.insert(key.clone()),
+                        };
+                        match (duplicate, self.options.duplicate_keys) {
+                            (true, DuplicateKeyPolicy::Error) => {
+                                let message = format!("duplicate key '{key}'");
+                                return Err(self.error_at(key_pos, &message));
+                            }
+                            (true, _) => self.skip_value()?,
+                            (false, _) => {
+                                let mut field_builder =
+                                    ObjectFieldBuilder::new(&key, &mut obj_builder);
+                                self.parse_value(&mut field_builder)?;
+                            }
+                        }
+                        if !self.next_element(b'}')? {
+                            break;
+                        }
+                    }
+                }
+                obj_builder.finish();
+                self.depth -= 1;
+            }
+            Some(_) => return Err(self.error("expected value")),
+            None => return Err(self.error("unexpected end of input")),
+        }
+        Ok(())
+    }
+
+    /// Parses and discards a value, such as that of an ignored duplicate key
+    fn skip_value(&mut self) -> Result<(), ArrowError> {
+        match self.peek() {
+            Some(b'n') => self.expect_literal("null"),
+            Some(b't') => self.expect_literal("true"),
+            Some(b'f') => self.expect_literal("false"),
+            Some(b'"') => self.parse_string().map(|_| ()),
+            Some(b'-' | b'0'..=b'9') => self.scan_number().map(|_| ()),
+            Some(b'[') => {
+                self.enter()?;
+                if !self.consume_if(b']') {
+                    loop {
+                        self.skip_whitespace();
+                        self.skip_value()?;
+                        if !self.next_element(b']')? {
+                            break;
+                        }
+                    }
+                }
+                self.depth -= 1;
+                Ok(())
+            }
+            Some(b'{') => {
+                self.enter()?;
+                if !self.consume_if(b'}') {
+                    loop {
+                        self.skip_whitespace();
+                        self.parse_string()?;
+                        self.skip_whitespace();
+                        if !self.consume_if(b':') {
+                            return Err(self.error("expected ':'"));
+                        }
+                        self.skip_whitespace();
+                        self.skip_value()?;
+                        if !self.next_element(b'}')? {
+                            break;
+                        }
+                    }
+                }
+                self.depth -= 1;
+                Ok(())
+            }
+            Some(_) => Err(self.error("expected value")),
+            None => Err(self.error("unexpected end of input")),
+        }
+    }
 
-        append_json(&json, self)?;
+    /// Consumes the opening bracket of an array or object, checking the nesting depth
+    fn enter(&mut self) -> Result<(), ArrowError> {
+        if self.depth >= self.options.max_depth {
+            return Err(self.error(&format!(
+                "exceeded maximum nesting depth of {}",
+                self.options.max_depth
+            )));
+        }
+        self.depth += 1;
+        self.pos += 1;
+        self.skip_whitespace();
         Ok(())
     }
+
+    /// Consumes the separator after an array or object element, returning `true` if another
+    /// element follows, or `false` if `close` ended the array or object
+    fn next_element(&mut self, close: u8) -> Result<bool, ArrowError> {
+        self.skip_whitespace();
+        if self.consume_if(b',') {
+            Ok(true)
+        } else if self.consume_if(close) {
+            Ok(false)
+        } else {
+            Err(self.error(&format!("expected ',' or '{}'", close as char)))
+        }
+    }
+
+    fn parse_string(&mut self) -> Result<Cow<'a, str>, ArrowError> {
+        if !self.consume_if(b'"') {
+            return Err(self.error("expected string"));
+        }
+        let bytes = self.json.as_bytes();
+        let start = self.pos;
+        // Fast path for strings without escapes
+        loop {
+            match bytes.get(self.pos) {
+                Some(b'"') => {
+                    self.pos += 1;
+                    return Ok(Cow::Borrowed(&self.json[start..self.pos - 1]));
+                }
+                Some(b'\\') => break,
+                Some(0..=0x1F) => return Err(self.error("control character in string")),
+                Some(_) => self.pos += 1,
+                None => return Err(self.error("unterminated string")),
+            }
+        }
+
+        let mut s = String::from(&self.json[start..self.pos]);
+        loop {
+            let chunk_start = self.pos;
+            while let Some(b) = bytes.get(self.pos) {
+                if matches!(b, b'"' | b'\\' | 0..=0x1F) {
+                    break;
+                }
+                self.pos += 1;
+            }
+            s.push_str(&self.json[chunk_start..self.pos]);
+            match bytes.get(self.pos) {
+                Some(b'"') => {
+                    self.pos += 1;
+                    return Ok(Cow::Owned(s));
+                }
+                Some(b'\\') => {
+                    self.pos += 1;
+                    s.push(self.parse_escape()?);
+                }
+                Some(_) => return Err(self.error("control character in string")),
+                None => return Err(self.error("unterminated string")),
+            }
+        }
+    }
+
+    /// Parses an escape sequence, following the backslash
+    fn parse_escape(&mut self) -> Result<char, ArrowError> {
+        let c = match self.peek() {
+            Some(b'"') => '"',
+            Some(b'\\') => '\\',
+            Some(b'/') => '/',
+            Some(b'b') => '\u{8}',
+            Some(b'f') => '\u{c}',
+            Some(b'n') => '\n',
+            Some(b'r') => '\r',
+            Some(b't') => '\t',
+            Some(b'u') => {
+                self.pos += 1;
+                let high = self.parse_hex4()?;
+                let code = match high {
+                    0xD800..=0xDBFF => {
+                        if !self.json[self.pos..].starts_with("\\u") {
+                            return Err(self.error("unpaired surrogate in string"));
+                        }
+                        self.pos += 2;
+                        let low = self.parse_hex4()?;
+                        if !(0xDC00..=0xDFFF).contains(&low) {
+                            return Err(self.error("unpaired surrogate in string"));
+                        }
+                        0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+                    }
+                    0xDC00..=0xDFFF => return Err(self.error("unpaired surrogate in string")),
+                    code => code,
+                };
+                return char::from_u32(code).ok_or_else(|| self.error("invalid unicode escape"));
+            }
+            _ => return Err(self.error("invalid escape")),
+        };
+        self.pos += 1;
+        Ok(c)
+    }
+
+    fn parse_hex4(&mut self) -> Result<u32, ArrowError> {
+        let hex = self
+            .json
+            .get(self.pos..self.pos + 4)
+            .filter(|hex| hex.bytes().all(|b| b.is_ascii_hexdigit()))
+            .ok_or_else(|| self.error("invalid unicode escape"))?;
+        self.pos += 4;
+        Ok(u32::from_str_radix(hex, 16).unwrap())
+    }
+
+    /// Scans a number, returning its text and whether it is an integer, i.e. has neither a
+    /// fraction nor an exponent
+    fn scan_number(&mut self) -> Result<(&'a str, bool), ArrowError> {
+        let bytes = self.json.as_bytes();
+        let start = self.pos;
+        self.consume_if(b'-');
+        match bytes.get(self.pos) {
+            Some(b'0') => self.pos += 1,
+            Some(b'1'..=b'9') => self.skip_digits(),
+            _ => return Err(self.error("invalid number")),
+        }
+        let mut is_integer = true;
+        if self.consume_if(b'.') {
+            is_integer = false;
+            if !matches!(bytes.get(self.pos), Some(b'0'..=b'9')) {
+                return Err(self.error("invalid number"));
+            }
+            self.skip_digits();
+        }
+        if matches!(bytes.get(self.pos), Some(b'e' | b'E')) {
+            is_integer = false;
+            self.pos += 1;
+            if matches!(bytes.get(self.pos), Some(b'+' | b'-')) {
+                self.pos += 1;
+            }
+            if !matches!(bytes.get(self.pos), Some(b'0'..=b'9')) {
+                return Err(self.error("invalid number"));
+            }
+            self.skip_digits();
+        }
+        Ok((&self.json[start..self.pos], is_integer))
+    }
+
+    fn parse_number(&mut self) -> Result<Variant<'static, 'static>, ArrowError> {
+        let start = self.pos;
+        let (text, is_integer) = self.scan_number()?;
+        let mode = self.options.number_mode;
+        if is_integer && mode != JsonNumberMode::Double {
+            if let Ok(i) = text.parse::<i64>() {
+                return Ok(variant_from_i64(i));
+            }
+        }
+        if mode == JsonNumberMode::IntOrDecimal {
+            if let Some(decimal) = variant_decimal_from_str(text) {
+                return Ok(decimal);
+            }
+        }
+        // Parse with serde_json, to produce the same doubles as `append_json`
+        match serde_json::from_str::<f64>(text) {
+            Ok(f) if f.is_finite() => Ok(f.into()),
+            _ => Err(self.error_at(start, "number out of range")),
+        }
+    }
+
+    fn skip_digits(&mut self) {
+        let bytes = self.json.as_bytes();
+        while matches!(bytes.get(self.pos), Some(b'0'..=b'9')) {
+            self.pos += 1;
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        let bytes = self.json.as_bytes();
+        while matches!(bytes.get(self.pos), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect_literal(&mut self, literal: &str) -> Result<(), ArrowError> {
+        if !self.json[self.pos..].starts_with(literal) {
+            return Err(self.error("expected value"));
+        }
+        self.pos += literal.len();
+        Ok(())
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.json.as_bytes().get(self.pos).copied()
+    }
+
+    fn consume_if(&mut self, b: u8) -> bool {
+        let matched = self.peek() == Some(b);
+        if matched {
+            self.pos += 1;
+        }
+        matched
+    }
+
+    /// Returns an error for the current position, reported as a line and column
+    fn error(&self, message: &str) -> ArrowError {
+        self.error_at(self.pos, message)
+    }
+
+    fn error_at(&self, pos: usize, message: &str) -> ArrowError {
+        let consumed = &self.json.as_bytes()[..pos.min(self.json.len())];
+        let line = consumed.iter().filter(|b| **b == b'\n').count() + 1;
+        let column = consumed.iter().rev().take_while(|b| **b != b'\n').count() + 1;
+        ArrowError::InvalidArgumentError(format!(
+            "JSON format error: {message} at line {line} column {column}"
+        ))
+    }
+}
+
+/// Returns `i` as the narrowest Variant integer type that fits it
+fn variant_from_i64(i: i64) -> Variant<'static, 'static> {
+    if i as i8 as i64 == i {
+        (i as i8).into()
+    } else if i as i16 as i64 == i {
+        (i as i16).into()
+    } else if i as i32 as i64 == i {
+        (i as i32).into()
+    } else {
+        i.into()
+    }
+}
+
+/// Returns the valid JSON number `text` as the narrowest Variant decimal type that represents it
+/// exactly, or `None` if it does not fit in a [`VariantDecimal16`]
+fn variant_decimal_from_str(text: &str) -> Option<Variant<'static, 'static>> {
+    let (negative, text) = match text.strip_prefix('-') {
+        Some(text) => (true, text),
+        None => (false, text),
+    };
+    let (mantissa, exponent) = match text.find(['e', 'E']) {
+        Some(idx) => (&text[..idx], text[idx + 1..].parse::<i64>().ok()?),
+        None => (text, 0),
+    };
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+
+    let scale = i64::try_from(fraction.len()).ok()? - exponent;
+    let digits = integer.bytes().chain(fraction.bytes());
+    let mut unscaled: i128 = 0;
+    let mut num_digits = 0;
+    for digit in digits.skip_while(|d| *d == b'0') {
+        unscaled = unscaled * 10 + i128::from(digit - b'0');
+        num_digits += 1;
+        if num_digits > VariantDecimal16::MAX_PRECISION {
+            return None;
+        }
+    }
+    // A negative scale is applied to the unscaled value
+    let scale = match u8::try_from(scale) {
+        Ok(scale) => scale,
+        Err(_) if scale < 0 => {
+            let zeros = u32::try_from(-scale).ok()?;
+            unscaled = unscaled.checked_mul(10_i128.checked_pow(zeros)?)?;
+            0
+        }
+        Err(_) => return None,
+    };
+    if negative {
+        unscaled = -unscaled;
+    }
+
+    if let Ok(Ok(d)) = i32::try_from(unscaled).map(|i| VariantDecimal4::try_new(i, scale)) {
+        return Some(d.into());
+    }
+    if let Ok(Ok(d)) = i64::try_from(unscaled).map(|i| VariantDecimal8::try_new(i, scale)) {
+        return Some(d.into());
+    }
+    VariantDecimal16::try_new(unscaled, scale)
+        .ok()
+        .map(Into::into)
 }
 
 fn variant_from_number<'m, 'v>(n: &Number) -> Result<Variant<'m, 'v>, ArrowError> {
     if let Some(i) = n.as_i64() {
         // Find minimum Integer width to fit
-        if i as i8 as i64 == i {
-            Ok((i as i8).into())
-        } else if i as i16 as i64 == i {
-            Ok((i as i16).into())
-        } else if i as i32 as i64 == i {
-            Ok((i as i32).into())
-        } else {
-            Ok(i.into())
-        }
+        Ok(variant_from_i64(i))
     } else {
         // Todo: Try decimal once we implement custom JSON parsing where we have access to strings
         // Try double - currently json_to_variant does not produce decimal
@@ -138,6 +679,18 @@ mod test {
         ShortString, Variant, VariantBuilder, VariantDecimal4, VariantDecimal8, VariantDecimal16,
     };
 
+    fn decimal_options() -> JsonToVariantOptions {
+        JsonToVariantOptions::new().with_number_mode(JsonNumberMode::IntOrDecimal)
+    }
+
+    fn json_to_variant_error(json: &str, options: &JsonToVariantOptions) -> String {
+        let mut variant_builder = VariantBuilder::new();
+        let err = variant_builder
+            .append_json_with_options(json, options)
+            .unwrap_err();
+        err.to_string()
+    }
+
     struct JsonToVariantTest<'a> {
         json: &'a str,
         expected: Variant<'a, 'a>,
@@ -145,8 +698,12 @@ mod test {
 
     impl JsonToVariantTest<'_> {
         fn run(self) -> Result<(), ArrowError> {
+            self.run_with_options(&JsonToVariantOptions::default())
+        }
+
+        fn run_with_options(self, options: &JsonToVariantOptions) -> Result<(), ArrowError> {
             let mut variant_builder = VariantBuilder::new();
-            variant_builder.append_json(self.json)?;
+            variant_builder.append_json_with_options(self.json, options)?;
             let (metadata, value) = variant_builder.finish();
             let variant = Variant::try_new(&metadata, &value)?;
             assert_eq!(variant, self.expected);
@@ -226,147 +783,132 @@ mod test {
         .run()
     }
 
-    #[ignore]
     #[test]
     fn test_json_to_variant_decimal4_basic() -> Result<(), ArrowError> {
         JsonToVariantTest {
             json: "1.23",
             expected: Variant::from(VariantDecimal4::try_new(123, 2)?),
         }
-        .run()
+        .run_with_options(&decimal_options())
     }
 
-    #[ignore]
     #[test]
     fn test_json_to_variant_decimal4_large_positive() -> Result<(), ArrowError> {
         JsonToVariantTest {
             json: "99999999.9",
             expected: Variant::from(VariantDecimal4::try_new(999999999, 1)?),
         }
-        .run()
+        .run_with_options(&decimal_options())
     }
 
-    #[ignore]
     #[test]
     fn test_json_to_variant_decimal4_large_negative() -> Result<(), ArrowError> {
         JsonToVariantTest {
             json: "-99999999.9",
             expected: Variant::from(VariantDecimal4::try_new(-999999999, 1)?),
         }
-        .run()
+        .run_with_options(&decimal_options())
     }
 
-    #[ignore]
     #[test]
     fn test_json_to_variant_decimal4_small_positive() -> Result<(), ArrowError> {
         JsonToVariantTest {
             json: "0.999999999",
             expected: Variant::from(VariantDecimal4::try_new(999999999, 9)?),
         }
-        .run()
+        .run_with_options(&decimal_options())
     }
 
-    #[ignore]
     #[test]
     fn test_json_to_variant_decimal4_tiny_positive() -> Result<(), ArrowError> {
         JsonToVariantTest {
             json: "0.000000001",
             expected: Variant::from(VariantDecimal4::try_new(1, 9)?),
         }
-        .run()
+        .run_with_options(&decimal_options())
     }
 
-    #[ignore]
     #[test]
     fn test_json_to_variant_decimal4_small_negative() -> Result<(), ArrowError> {
         JsonToVariantTest {
             json: "-0.999999999",
             expected: Variant::from(VariantDecimal4::try_new(-999999999, 9)?),
         }
-        .run()
+        .run_with_options(&decimal_options())
     }
 
-    #[ignore]
     #[test]
     fn test_json_to_variant_decimal8_positive() -> Result<(), ArrowError> {
         JsonToVariantTest {
             json: "999999999.0",
             expected: Variant::from(VariantDecimal8::try_new(9999999990, 1)?),
         }
-        .run()
+        .run_with_options(&decimal_options())
     }
 
-    #[ignore]
     #[test]
     fn test_json_to_variant_decimal8_negative() -> Result<(), ArrowError> {
         JsonToVariantTest {
             json: "-999999999.0",
             expected: Variant::from(VariantDecimal8::try_new(-9999999990, 1)?),
         }
-        .run()
+        .run_with_options(&decimal_options())
     }
 
-    #[ignore]
     #[test]
     fn test_json_to_variant_decimal8_high_precision() -> Result<(), ArrowError> {
         JsonToVariantTest {
             json: "0.999999999999999999",
             expected: Variant::from(VariantDecimal8::try_new(999999999999999999, 18)?),
         }
-        .run()
+        .run_with_options(&decimal_options())
     }
 
-    #[ignore]
     #[test]
     fn test_json_to_variant_decimal8_large_with_scale() -> Result<(), ArrowError> {
         JsonToVariantTest {
             json: "9999999999999999.99",
             expected: Variant::from(VariantDecimal8::try_new(999999999999999999, 2)?),
         }
-        .run()
+        .run_with_options(&decimal_options())
     }
 
-    #[ignore]
     #[test]
     fn test_json_to_variant_decimal8_large_negative_with_scale() -> Result<(), ArrowError> {
         JsonToVariantTest {
             json: "-9999999999999999.99",
             expected: Variant::from(VariantDecimal8::try_new(-999999999999999999, 2)?),
         }
-        .run()
+        .run_with_options(&decimal_options())
     }
 
-    #[ignore]
     #[test]
     fn test_json_to_variant_decimal16_large_integer() -> Result<(), ArrowError> {
         JsonToVariantTest {
             json: "9999999999999999999", // integer larger than i64
             expected: Variant::from(VariantDecimal16::try_new(9999999999999999999, 0)?),
         }
-        .run()
+        .run_with_options(&decimal_options())
     }
 
-    #[ignore]
     #[test]
     fn test_json_to_variant_decimal16_high_precision() -> Result<(), ArrowError> {
         JsonToVariantTest {
             json: "0.9999999999999999999",
             expected: Variant::from(VariantDecimal16::try_new(9999999999999999999, 19)?),
         }
-        .run()
+        .run_with_options(&decimal_options())
     }
 
-    #[ignore]
     #[test]
     fn test_json_to_variant_decimal16_max_value() -> Result<(), ArrowError> {
         JsonToVariantTest {
             json: "79228162514264337593543950335", // 2 ^ 96 - 1
             expected: Variant::from(VariantDecimal16::try_new(79228162514264337593543950335, 0)?),
         }
-        .run()
+        .run_with_options(&decimal_options())
     }
 
-    #[ignore]
     #[test]
     fn test_json_to_variant_decimal16_max_scale() -> Result<(), ArrowError> {
         JsonToVariantTest {
@@ -377,7 +919,7 @@ mod test {
                 28,
             )?),
         }
-        .run()
+        .run_with_options(&decimal_options())
     }
 
     #[test]
@@ -667,4 +1209,134 @@ mod test {
         }
         .run()
     }
+
+    #[test]
+    fn test_json_to_variant_decimal_exponent() -> Result<(), ArrowError> {
+        JsonToVariantTest {
+            json: "1.5e2",
+            expected: Variant::from(VariantDecimal4::try_new(150, 0)?),
+        }
+        .run_with_options(&decimal_options())?;
+        JsonToVariantTest {
+            json: "-25E-3",
+            expected: Variant::from(VariantDecimal4::try_new(-25, 3)?),
+        }
+        .run_with_options(&decimal_options())
+    }
+
+    #[test]
+    fn test_json_to_variant_decimal_falls_back_to_double() -> Result<(), ArrowError> {
+        JsonToVariantTest {
+            json: "0.123456789012345678901234567890123456789",
+            expected: Variant::Double(0.123_456_789_012_345_68),
+        }
+        .run_with_options(&decimal_options())?;
+        JsonToVariantTest {
+            json: "1e-40",
+            expected: Variant::Double(1e-40),
+        }
+        .run_with_options(&decimal_options())
+    }
+
+    #[test]
+    fn test_json_to_variant_number_mode_double() -> Result<(), ArrowError> {
+        let options = JsonToVariantOptions::new().with_number_mode(JsonNumberMode::Double);
+        let mut variant_builder = VariantBuilder::new();
+        variant_builder.append_json_with_options("[1, -2.5, 9999999999999999999]", &options)?;
+        let (metadata, value) = variant_builder.finish();
+        let variant = Variant::try_new(&metadata, &value)?;
+        let list = variant.as_list().unwrap();
+        assert_eq!(list.get(0), Some(Variant::Double(1.0)));
+        assert_eq!(list.get(1), Some(Variant::Double(-2.5)));
+        assert_eq!(list.get(2), Some(Variant::Double(1e19)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_to_variant_duplicate_keys() -> Result<(), ArrowError> {
+        let json = r#"{"a": 1, "b": [true], "a": {"c": [2, 3]}}"#;
+        let options =
+            JsonToVariantOptions::new().with_duplicate_keys(DuplicateKeyPolicy::FirstWins);
+        let mut variant_builder = VariantBuilder::new();
+        variant_builder.append_json_with_options(json, &options)?;
+        let (metadata, value) = variant_builder.finish();
+        let variant = Variant::try_new(&metadata, &value)?;
+        assert_eq!(variant.to_json_string()?, r#"{"a":1,"b":[true]}"#);
+
+        let mut variant_builder = VariantBuilder::new();
+        variant_builder.append_json(json)?;
+        let (metadata, value) = variant_builder.finish();
+        let variant = Variant::try_new(&metadata, &value)?;
+        assert_eq!(variant.to_json_string()?, r#"{"a":{"c":[2,3]},"b":[true]}"#);
+
+        let options = JsonToVariantOptions::new().with_duplicate_keys(DuplicateKeyPolicy::Error);
+        assert_eq!(
+            json_to_variant_error(json, &options),
+            "Invalid argument error: JSON format error: duplicate key 'a' at line 1 column 23"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_to_variant_max_depth() -> Result<(), ArrowError> {
+        let json = r#"[{"a": [1]}]"#;
+        let options = JsonToVariantOptions::new().with_max_depth(3);
+        let mut variant_builder = VariantBuilder::new();
+        variant_builder.append_json_with_options(json, &options)?;
+
+        let options = JsonToVariantOptions::new().with_max_depth(2);
+        assert_eq!(
+            json_to_variant_error(json, &options),
+            "Invalid argument error: JSON format error: exceeded maximum nesting depth of 2 at line 1 column 8"
+        );
+
+        let json = "[".repeat(DEFAULT_MAX_DEPTH + 1);
+        let err = json_to_variant_error(&json, &JsonToVariantOptions::default());
+        assert!(
+            err.contains("exceeded maximum nesting depth of 128"),
+            "{err}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_to_variant_string_escapes() -> Result<(), ArrowError> {
+        JsonToVariantTest {
+            json: r#""a\"\\\/\b\f\n\r\t\u00e9\ud83d\ude00""#,
+            expected: Variant::from("a\"\\/\u{8}\u{c}\n\r\té😀"),
+        }
+        .run()
+    }
+
+    #[test]
+    fn test_json_to_variant_invalid() {
+        let options = JsonToVariantOptions::default();
+        for (json, expected) in [
+            ("", "unexpected end of input at line 1 column 1"),
+            ("[1, 2", "expected ',' or ']' at line 1 column 6"),
+            ("{\"a\" 1}", "expected ':' at line 1 column 6"),
+            ("[1]\n  x", "trailing characters at line 2 column 3"),
+            ("01", "trailing characters at line 1 column 2"),
+            ("-", "invalid number at line 1 column 2"),
+            ("1.", "invalid number at line 1 column 3"),
+            ("1e400", "number out of range at line 1 column 1"),
+            ("tru", "expected value at line 1 column 1"),
+            ("[1,]", "expected value at line 1 column 4"),
+            ("\"abc", "unterminated string at line 1 column 5"),
+            ("\"\\x\"", "invalid escape at line 1 column 3"),
+            (
+                "\"\\ud800\"",
+                "unpaired surrogate in string at line 1 column 8",
+            ),
+            ("\"\\u12\"", "invalid unicode escape at line 1 column 4"),
+            ("\"a\tb\"", "control character in string at line 1 column 3"),
+        ] {
+            let err = json_to_variant_error(json, &options);
+            assert_eq!(
+                err,
+                format!("Invalid argument error: JSON format error: {expected}"),
+                "{json}"
+            );
+        }
+    }
 }
@@ -21,8 +21,10 @@
 //! [Variant Binary Encoding]: https://github.com/apache/parquet-format/blob/master/VariantEncoding.md
 //! [Apache Parquet]: https://parquet.apache.org/
 //!
-//! * See [`JsonToVariant`] trait for converting a JSON string to a Variant.
-//! * See [`VariantToJson`] trait for converting a Variant to a JSON string.
+//! * See [`JsonToVariant`] trait for converting a JSON string to a Variant, optionally
+//!   configured with [`JsonToVariantOptions`].
+//! * See [`VariantToJson`] trait for converting a Variant to a JSON string, and
+//!   [`variant_path_to_json`] for converting the value at a path within a Variant.
 //!
 //! ## 🚧 Work In Progress
 //!
@@ -34,5 +36,8 @@
 mod from_json;
 mod to_json;
 
-pub use from_json::{JsonToVariant, append_json};
-pub use to_json::VariantToJson;
+pub use from_json::{
+    DEFAULT_MAX_DEPTH, DuplicateKeyPolicy, JsonNumberMode, JsonToVariant, JsonToVariantOptions,
+    append_json,
+};
+pub use to_json::{VariantToJson, variant_path_to_json, variant_to_json};
@@ -19,7 +19,7 @@
 use arrow_schema::ArrowError;
 use base64::{Engine as _, engine::general_purpose};
 use chrono::Timelike;
-use parquet_variant::{Variant, VariantList, VariantObject};
+use parquet_variant::{Variant, VariantList, VariantObject, VariantPath};
 use serde_json::Value;
 use std::io::Write;
 
@@ -338,6 +338,50 @@ impl<'m, 'v> VariantToJson for Variant<'m, 'v> {
 // Format string constants to avoid duplication and reduce errors
 const DATE_FORMAT: &str = "%Y-%m-%d";
 
+/// Writes `variant` as JSON to `writer`
+///
+/// This is equivalent to [`VariantToJson::to_json`].
+pub fn variant_to_json(variant: &Variant, writer: &mut impl Write) -> Result<(), ArrowError> {
+    variant.to_json(writer)
+}
+
+/// Writes the value at `path` within `variant` as JSON to `writer`, returning `false`, without
+/// writing anything, if `variant` has no value at `path`
+///
+/// Only the projected value is rendered, so that path expressions can be evaluated without
+/// rendering the whole `variant`.
+///
+/// ```rust
+/// # use parquet_variant::{VariantBuilder, VariantPath};
+/// # use parquet_variant_json::{JsonToVariant, variant_path_to_json};
+/// let mut builder = VariantBuilder::new();
+/// builder.append_json(r#"{"a": {"b": [1, 2, {"c": true}]}}"#)?;
+/// let (metadata, value) = builder.finish();
+/// let variant = parquet_variant::Variant::try_new(&metadata, &value)?;
+///
+/// let mut buffer = Vec::new();
+/// let path = VariantPath::try_from_json_path("$.a.b[2]")?;
+/// assert!(variant_path_to_json(&variant, &path, &mut buffer)?);
+/// assert_eq!(buffer, br#"{"c":true}"#);
+///
+/// let path = VariantPath::try_from_json_path("$.a.x")?;
+/// assert!(!variant_path_to_json(&variant, &path, &mut Vec::new())?);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn variant_path_to_json(
+    variant: &Variant,
+    path: &VariantPath,
+    writer: &mut impl Write,
+) -> Result<bool, ArrowError> {
+    match variant.get_path(path) {
+        Some(value) => {
+            value.to_json(writer)?;
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
 // Helper functions for consistent formatting
 fn format_date_string(date: &chrono::NaiveDate) -> String {
     date.format(DATE_FORMAT).to_string()
@@ -417,7 +461,7 @@ fn convert_array_to_json(buffer: &mut impl Write, arr: &VariantList) -> Result<(
 mod tests {
     use super::*;
     use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
-    use parquet_variant::{VariantDecimal4, VariantDecimal8, VariantDecimal16};
+    use parquet_variant::{VariantBuilder, VariantDecimal4, VariantDecimal8, VariantDecimal16};
 
     #[test]
     fn test_decimal_edge_cases() -> Result<(), ArrowError> {
@@ -1353,4 +1397,40 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_variant_path_to_json() -> Result<(), ArrowError> {
+        let mut builder = VariantBuilder::new();
+        let mut obj = builder.new_object();
+        obj.insert("id", 1);
+        let mut list = obj.new_list("items");
+        list.append_value("first");
+        list.new_object().with_field("name", "second").finish();
+        list.finish();
+        obj.finish();
+        let (metadata, value) = builder.finish();
+        let variant = Variant::try_new(&metadata, &value)?;
+
+        let mut buffer = Vec::new();
+        variant_to_json(&variant, &mut buffer)?;
+        assert_eq!(buffer, variant.to_json_string()?.as_bytes());
+
+        for (path, expected) in [
+            ("$", Some(r#"{"id":1,"items":["first",{"name":"second"}]}"#)),
+            ("$.id", Some("1")),
+            ("$.items[0]", Some(r#""first""#)),
+            ("$.items[1].name", Some(r#""second""#)),
+            ("$['items'][1]", Some(r#"{"name":"second"}"#)),
+            ("$.items[2]", None),
+            ("$.id.x", None),
+            ("$.missing", None),
+        ] {
+            let path = VariantPath::try_from_json_path(path)?;
+            let mut buffer = Vec::new();
+            let found = variant_path_to_json(&variant, &path, &mut buffer)?;
+            assert_eq!(found, expected.is_some());
+            assert_eq!(buffer, expected.unwrap_or_default().as_bytes());
+        }
+        Ok(())
+    }
 }
@@ -15,12 +15,12 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 
 use arrow_schema::ArrowError;
 use indexmap::IndexSet;
 
-use crate::{VariantMetadata, int_size};
+use crate::{Variant, VariantMetadata, int_size};
 
 /// Write little-endian integer to buffer
 fn write_offset(buf: &mut Vec<u8>, value: usize, nbytes: u8) {
@@ -265,12 +265,128 @@ impl<S: AsRef<str>> Extend<S> for WritableMetadataBuilder {
     }
 }
 
+/// Builder for a sorted metadata dictionary shared by many [`Variant`] values.
+///
+/// [`VariantBuilder`] creates a new metadata dictionary for every value it builds. When building
+/// many values with overlapping object keys, [`VariantMetadataBuilder`] can instead accumulate the
+/// field names of all the values into a single dictionary, which is emitted sorted and
+/// deduplicated with the `sorted_strings` flag set. Sorted dictionaries allow field names to be
+/// looked up with a binary search when reading the values.
+///
+/// The values can then be encoded against the shared dictionary with a [`ValueBuilder`] and a
+/// [`ReadOnlyMetadataBuilder`].
+///
+/// # Example
+/// ```
+/// # use parquet_variant::{
+/// #     ParentState, ReadOnlyMetadataBuilder, ValueBuilder, Variant, VariantBuilder,
+/// #     VariantMetadata, VariantMetadataBuilder,
+/// # };
+/// let mut builder = VariantBuilder::new();
+/// builder.new_object().with_field("name", "Alice").with_field("age", 30).finish();
+/// let (m1, v1) = builder.finish();
+/// let mut builder = VariantBuilder::new();
+/// builder.new_object().with_field("name", "Bob").with_field("city", "Paris").finish();
+/// let (m2, v2) = builder.finish();
+/// let values = [Variant::new(&m1, &v1), Variant::new(&m2, &v2)];
+///
+/// // collect the field names of all the values
+/// let mut builder = VariantMetadataBuilder::new();
+/// for value in &values {
+///     builder.add_variant_field_names(value);
+/// }
+/// let metadata = builder.build();
+/// let metadata = VariantMetadata::try_new(&metadata).unwrap();
+/// assert!(metadata.is_sorted());
+/// assert_eq!(metadata.iter().collect::<Vec<_>>(), vec!["age", "city", "name"]);
+///
+/// // encode each value against the shared dictionary
+/// for value in values {
+///     let mut metadata_builder = ReadOnlyMetadataBuilder::new(&metadata);
+///     let mut value_builder = ValueBuilder::new();
+///     let state = ParentState::variant(&mut value_builder, &mut metadata_builder);
+///     ValueBuilder::try_append_variant(state, value.clone()).unwrap();
+///     let bytes = value_builder.into_inner();
+///     assert_eq!(Variant::try_new_with_metadata(metadata.clone(), &bytes).unwrap(), value);
+/// }
+/// ```
+///
+/// [`VariantBuilder`]: crate::VariantBuilder
+/// [`ValueBuilder`]: crate::ValueBuilder
+#[derive(Default, Debug, Clone)]
+pub struct VariantMetadataBuilder {
+    field_names: BTreeSet<String>,
+}
+
+impl VariantMetadataBuilder {
+    /// Create a new, empty [`VariantMetadataBuilder`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builder-style API for adding field names to the dictionary
+    pub fn with_field_names<'a>(mut self, field_names: impl IntoIterator<Item = &'a str>) -> Self {
+        self.extend(field_names);
+        self
+    }
+
+    /// Adds a single field name to the dictionary, if not already present
+    pub fn add_field_name(&mut self, field_name: &str) {
+        if !self.field_names.contains(field_name) {
+            self.field_names.insert(field_name.to_string());
+        }
+    }
+
+    /// Adds the field names of all the objects in `variant`, including nested ones, to the
+    /// dictionary
+    pub fn add_variant_field_names(&mut self, variant: &Variant<'_, '_>) {
+        match variant {
+            Variant::Object(object) => {
+                for (field_name, value) in object.iter() {
+                    self.add_field_name(field_name);
+                    self.add_variant_field_names(&value);
+                }
+            }
+            Variant::List(list) => {
+                for value in list.iter() {
+                    self.add_variant_field_names(&value);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Returns the number of distinct field names in the dictionary
+    pub fn num_field_names(&self) -> usize {
+        self.field_names.len()
+    }
+
+    /// Returns the serialized metadata dictionary, with the field names sorted
+    ///
+    /// The builder is not reset, so that more field names can be added to build a larger
+    /// dictionary. Note that values encoded against a dictionary are not valid with a dictionary
+    /// built after adding more field names, as the field ids may have changed.
+    pub fn build(&self) -> Vec<u8> {
+        let mut builder = WritableMetadataBuilder::from_iter(&self.field_names);
+        builder.finish();
+        builder.into_inner()
+    }
+}
+
+impl<S: AsRef<str>> Extend<S> for VariantMetadataBuilder {
+    fn extend<T: IntoIterator<Item = S>>(&mut self, iter: T) {
+        for field_name in iter {
+            self.add_field_name(field_name.as_ref());
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::{
-        ParentState, ValueBuilder, VariantMetadata,
+        ParentState, ValueBuilder, Variant, VariantBuilder, VariantMetadata,
         builder::{
-            metadata::{ReadOnlyMetadataBuilder, WritableMetadataBuilder},
+            metadata::{ReadOnlyMetadataBuilder, VariantMetadataBuilder, WritableMetadataBuilder},
             object::ObjectBuilder,
         },
     };
@@ -387,4 +503,84 @@ mod test {
             );
         }
     }
+
+    #[test]
+    fn test_variant_metadata_builder() {
+        let mut builder = VariantMetadataBuilder::new().with_field_names(["zebra", "apple"]);
+        builder.add_field_name("monkey");
+        builder.add_field_name("apple");
+        assert_eq!(builder.num_field_names(), 3);
+
+        let bytes = builder.build();
+        let metadata = VariantMetadata::try_new(&bytes).unwrap();
+        assert!(metadata.is_sorted());
+        assert_eq!(
+            metadata.iter().collect::<Vec<_>>(),
+            vec!["apple", "monkey", "zebra"]
+        );
+        assert_eq!(metadata.get_entry("zebra"), Some((2, "zebra")));
+
+        // the builder is not reset by build
+        builder.add_field_name("banana");
+        let bytes = builder.build();
+        let metadata = VariantMetadata::try_new(&bytes).unwrap();
+        assert_eq!(metadata.len(), 4);
+        assert_eq!(&metadata[1], "banana");
+    }
+
+    #[test]
+    fn test_variant_metadata_builder_empty() {
+        let bytes = VariantMetadataBuilder::new().build();
+        let metadata = VariantMetadata::try_new(&bytes).unwrap();
+        assert_eq!(metadata.len(), 0);
+    }
+
+    #[test]
+    fn test_variant_metadata_builder_shared_dictionary() {
+        let mut builder = VariantBuilder::new();
+        let mut obj = builder.new_object();
+        obj.insert("b", 1);
+        let mut list = obj.new_list("list");
+        list.new_object().with_field("c", "nested").finish();
+        list.finish();
+        obj.finish();
+        let (m1, v1) = builder.finish();
+
+        let mut builder = VariantBuilder::new();
+        builder
+            .new_object()
+            .with_field("a", true)
+            .with_field("b", 2)
+            .finish();
+        let (m2, v2) = builder.finish();
+
+        let (m3, v3) = VariantBuilder::new().with_value("no fields").finish();
+
+        let values = [
+            Variant::new(&m1, &v1),
+            Variant::new(&m2, &v2),
+            Variant::new(&m3, &v3),
+        ];
+        let mut metadata_builder = VariantMetadataBuilder::new();
+        for value in &values {
+            metadata_builder.add_variant_field_names(value);
+        }
+        let bytes = metadata_builder.build();
+        let metadata = VariantMetadata::try_new(&bytes).unwrap();
+        assert!(metadata.is_sorted());
+        assert_eq!(
+            metadata.iter().collect::<Vec<_>>(),
+            vec!["a", "b", "c", "list"]
+        );
+
+        for value in values {
+            let mut read_only = ReadOnlyMetadataBuilder::new(&metadata);
+            let mut value_builder = ValueBuilder::new();
+            let state = ParentState::variant(&mut value_builder, &mut read_only);
+            ValueBuilder::try_append_variant(state, value.clone()).unwrap();
+            let encoded = value_builder.into_inner();
+            let decoded = Variant::try_new_with_metadata(metadata.clone(), &encoded).unwrap();
+            assert_eq!(decoded, value);
+        }
+    }
 }
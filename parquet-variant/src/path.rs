@@ -114,6 +114,35 @@ impl<'a> VariantPath<'a> {
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
+
+    /// Parses a JSONPath style path rooted at `$`, such as `$.a.b[2]` or `$['a.b'][0]`
+    ///
+    /// The elements following the root use the same syntax as [`VariantPath::try_from`], and
+    /// `$` alone is the empty path. Wildcards, slices and filters are not supported.
+    ///
+    /// ```
+    /// # use parquet_variant::VariantPath;
+    /// let path = VariantPath::try_from_json_path("$.a.b[2]").unwrap();
+    /// assert_eq!(path, VariantPath::try_from("a.b[2]").unwrap());
+    /// ```
+    pub fn try_from_json_path(path: &'a str) -> Result<Self, ArrowError> {
+        let Some(elements) = path.strip_prefix('$') else {
+            return Err(ArrowError::ParseError(format!(
+                "JSON path '{path}' must start with '$'"
+            )));
+        };
+        match elements.as_bytes().first() {
+            None => Ok(Self::default()),
+            Some(b'.') if elements.len() == 1 => {
+                Err(ArrowError::ParseError("Unexpected trailing '.'".into()))
+            }
+            Some(b'.') => Self::try_from(&elements[1..]),
+            Some(b'[') => Self::try_from(elements),
+            Some(_) => Err(ArrowError::ParseError(format!(
+                "Expected '.' or '[' after '$' in JSON path '{path}'"
+            ))),
+        }
+    }
 }
 
 impl<'a> From<Vec<VariantPathElement<'a>>> for VariantPath<'a> {
@@ -222,6 +251,32 @@ mod tests {
         assert!(path.is_empty());
     }
 
+    #[test]
+    fn test_variant_path_from_json_path() {
+        let path = VariantPath::try_from_json_path("$.a.b[2]").unwrap();
+        let expected = VariantPath::try_from("a").unwrap().join("b").join(2);
+        assert_eq!(path, expected);
+
+        let path = VariantPath::try_from_json_path("$['a.b'][0]").unwrap();
+        let expected = VariantPath::from_iter(["a.b".into(), 0.into()]);
+        assert_eq!(path, expected);
+
+        assert!(VariantPath::try_from_json_path("$").unwrap().is_empty());
+
+        for (path, expected) in [
+            ("a.b", "Parser error: JSON path 'a.b' must start with '$'"),
+            (
+                "$a",
+                "Parser error: Expected '.' or '[' after '$' in JSON path '$a'",
+            ),
+            ("$.", "Parser error: Unexpected trailing '.'"),
+            ("$..a", "Parser error: Unexpected leading '.'"),
+        ] {
+            let err = VariantPath::try_from_json_path(path).unwrap_err();
+            assert_eq!(err.to_string(), expected);
+        }
+    }
+
     #[test]
     fn test_variant_path_empty_str() {
         let path = VariantPath::try_from("").unwrap();
@@ -19,6 +19,7 @@ pub use self::decimal::{VariantDecimal4, VariantDecimal8, VariantDecimal16, Vari
 pub use self::list::VariantList;
 pub use self::metadata::{EMPTY_VARIANT_METADATA, EMPTY_VARIANT_METADATA_BYTES, VariantMetadata};
 pub use self::object::VariantObject;
+pub use self::ordering::VariantKey;
 
 // Publically export types used in the API
 pub use half::f16;
@@ -46,6 +47,7 @@ mod decimal;
 mod list;
 mod metadata;
 mod object;
+mod ordering;
 
 const MAX_SHORT_STRING_BYTES: usize = 0x3F;
 
@@ -228,6 +230,10 @@ impl Deref for ShortString<'_> {
 /// fallible alternatives are provided as panic-free alternatives. [`Self::with_full_validation`] can also be
 /// used to _validate_ an _unvalidated_ instance, if desired.
 ///
+/// [`Self::try_new_with_validation`] allows choosing the [`VariantValidation`] performed, for
+/// example to skip the full validation of values from a trusted writer while still returning an
+/// error, rather than panicking, if their structure is invalid.
+///
 /// _Unvalidated_ instances can be constructed in constant time. This can be useful if the caller
 /// knows the underlying bytes were already validated previously, or if the caller intends to
 /// perform a small number of (fallible) accesses to a large variant value.
@@ -334,6 +340,32 @@ impl_decimal_cast_target!(f16, NumericKind::Float);
 impl_decimal_cast_target!(f32, NumericKind::Float);
 impl_decimal_cast_target!(f64, NumericKind::Float);
 
+/// The validation performed when decoding a [`Variant`], see [`Variant::try_new_with_validation`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum VariantValidation {
+    /// Only perform the constant-cost checks of [`Variant::new`], for values from a trusted writer.
+    ///
+    /// The instance is [unvalidated], and infallible accesses to nested values may panic if the
+    /// bytes are invalid.
+    ///
+    /// [unvalidated]: Variant#Validation
+    Trusted,
+    /// Additionally check, recursively, that all offsets and field ids are in bounds and that
+    /// all nested values can be decoded, without checking that the metadata dictionary entries
+    /// are UTF-8 encoded or that object fields are sorted by name.
+    ///
+    /// The cost is linear in the number of nested values, but the instance is still
+    /// [unvalidated]: accessing a field name may panic if it is not UTF-8 encoded.
+    ///
+    /// [unvalidated]: Variant#Validation
+    Structural,
+    /// Fully [validate] the value, as [`Variant::try_new`] does
+    ///
+    /// [validate]: Variant#Validation
+    #[default]
+    Full,
+}
+
 impl<'m, 'v> Variant<'m, 'v> {
     /// Attempts to interpret a metadata and value buffer pair as a new `Variant`.
     ///
@@ -357,6 +389,41 @@ impl<'m, 'v> Variant<'m, 'v> {
         Self::try_new_with_metadata(metadata, value)
     }
 
+    /// Attempts to interpret a metadata and value buffer pair as a new `Variant`, performing the
+    /// given [`VariantValidation`].
+    ///
+    /// # Example
+    /// ```
+    /// use parquet_variant::{Variant, VariantValidation};
+    /// let metadata = [0x01, 0x00, 0x00];
+    /// let value = [0x09, 0x48, 0x49];
+    /// assert_eq!(
+    ///   Variant::from("HI"),
+    ///   Variant::try_new_with_validation(&metadata, &value, VariantValidation::Trusted).unwrap()
+    /// );
+    ///
+    /// // the structure is checked even for trusted values
+    /// assert!(Variant::try_new_with_validation(&metadata, &[0x09], VariantValidation::Trusted).is_err());
+    /// ```
+    pub fn try_new_with_validation(
+        metadata: &'m [u8],
+        value: &'v [u8],
+        validation: VariantValidation,
+    ) -> Result<Self, ArrowError> {
+        if validation == VariantValidation::Full {
+            return Self::try_new(metadata, value);
+        }
+        let metadata = VariantMetadata::try_new_with_shallow_validation(metadata)?;
+        if validation == VariantValidation::Structural {
+            metadata.validate_structure()?;
+        }
+        let variant = Self::try_new_with_metadata_and_shallow_validation(metadata, value)?;
+        if validation == VariantValidation::Structural {
+            variant.validate_structure()?;
+        }
+        Ok(variant)
+    }
+
     /// Attempts to interpret a metadata and value buffer pair as a new `Variant`.
     ///
     /// The instance is [unvalidated].
@@ -414,6 +481,15 @@ impl<'m, 'v> Variant<'m, 'v> {
             .expect("Invalid variant")
     }
 
+    // Recursively validates the structure of nested values, see `VariantValidation::Structural`
+    fn validate_structure(&self) -> Result<(), ArrowError> {
+        match self {
+            Variant::List(list) => list.validate_structure(),
+            Variant::Object(obj) => obj.validate_structure(),
+            _ => Ok(()),
+        }
+    }
+
     // The actual constructor, which only performs shallow (constant-time) validation.
     fn try_new_with_metadata_and_shallow_validation(
         metadata: VariantMetadata<'m>,
@@ -1872,6 +1948,53 @@ impl<'a> std::fmt::Debug for HexString<'a> {
     }
 }
 
+/// Variants of the same primitive type are ordered by their values, consistent with
+/// [`PartialEq`]. For example, [`Variant::TimestampNanos`] values are ordered by instant,
+/// and [`Variant::Uuid`] values by their bytes.
+///
+/// Values of different types, decimals with different scales, objects, and lists are not
+/// ordered unless they are equal.
+impl PartialOrd for Variant<'_, '_> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        use std::cmp::Ordering;
+        match (self, other) {
+            (Variant::Null, Variant::Null) => Some(Ordering::Equal),
+            (Variant::BooleanFalse, Variant::BooleanFalse)
+            | (Variant::BooleanTrue, Variant::BooleanTrue) => Some(Ordering::Equal),
+            (Variant::BooleanFalse, Variant::BooleanTrue) => Some(Ordering::Less),
+            (Variant::BooleanTrue, Variant::BooleanFalse) => Some(Ordering::Greater),
+            (Variant::Int8(a), Variant::Int8(b)) => a.partial_cmp(b),
+            (Variant::Int16(a), Variant::Int16(b)) => a.partial_cmp(b),
+            (Variant::Int32(a), Variant::Int32(b)) => a.partial_cmp(b),
+            (Variant::Int64(a), Variant::Int64(b)) => a.partial_cmp(b),
+            (Variant::Float(a), Variant::Float(b)) => a.partial_cmp(b),
+            (Variant::Double(a), Variant::Double(b)) => a.partial_cmp(b),
+            (Variant::Decimal4(a), Variant::Decimal4(b)) if a.scale() == b.scale() => {
+                a.integer().partial_cmp(&b.integer())
+            }
+            (Variant::Decimal8(a), Variant::Decimal8(b)) if a.scale() == b.scale() => {
+                a.integer().partial_cmp(&b.integer())
+            }
+            (Variant::Decimal16(a), Variant::Decimal16(b)) if a.scale() == b.scale() => {
+                a.integer().partial_cmp(&b.integer())
+            }
+            (Variant::Date(a), Variant::Date(b)) => a.partial_cmp(b),
+            (Variant::Time(a), Variant::Time(b)) => a.partial_cmp(b),
+            (Variant::TimestampMicros(a), Variant::TimestampMicros(b))
+            | (Variant::TimestampNanos(a), Variant::TimestampNanos(b)) => a.partial_cmp(b),
+            (Variant::TimestampNtzMicros(a), Variant::TimestampNtzMicros(b))
+            | (Variant::TimestampNtzNanos(a), Variant::TimestampNtzNanos(b)) => a.partial_cmp(b),
+            (Variant::Binary(a), Variant::Binary(b)) => a.partial_cmp(b),
+            (Variant::String(a), Variant::String(b)) => a.partial_cmp(b),
+            (Variant::ShortString(a), Variant::ShortString(b)) => {
+                a.as_str().partial_cmp(b.as_str())
+            }
+            (Variant::Uuid(a), Variant::Uuid(b)) => a.partial_cmp(b),
+            _ => (self == other).then_some(Ordering::Equal),
+        }
+    }
+}
+
 impl std::fmt::Debug for Variant<'_, '_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -2217,4 +2340,97 @@ mod tests {
 }"#;
         assert_eq!(alt_debug_output, expected);
     }
+
+    #[test]
+    fn test_variant_validation_modes() {
+        use VariantValidation::*;
+
+        let (metadata, value) = {
+            let mut builder = crate::VariantBuilder::new();
+            builder.new_object().with_field("a", 1).finish();
+            builder.finish()
+        };
+        for validation in [Trusted, Structural, Full] {
+            let variant = Variant::try_new_with_validation(&metadata, &value, validation).unwrap();
+            assert_eq!(variant.get_object_field("a"), Some(Variant::from(1)));
+        }
+
+        // the metadata dictionary entry is not valid UTF-8
+        let metadata = [0x01, 1, 0, 1, 0xFF];
+        let value = [0x00];
+        Variant::try_new_with_validation(&metadata, &value, Trusted).unwrap();
+        Variant::try_new_with_validation(&metadata, &value, Structural).unwrap();
+        Variant::try_new_with_validation(&metadata, &value, Full).unwrap_err();
+
+        // a list containing an object with a field id not in the (empty) dictionary
+        let metadata = [0x01, 0, 0];
+        let value = [0x03, 1, 0, 6, 0x02, 1, 0, 0, 1, 0x00];
+        Variant::try_new_with_validation(&metadata, &value, Trusted).unwrap();
+        let err = Variant::try_new_with_validation(&metadata, &value, Structural).unwrap_err();
+        assert!(err.to_string().contains("field id is not valid"), "{err}");
+        Variant::try_new_with_validation(&metadata, &value, Full).unwrap_err();
+
+        // a list of two nulls with offsets that are not monotonically increasing
+        let metadata = [0x01, 0, 0];
+        let value = [0x03, 2, 0, 2, 1, 0x00, 0x00];
+        Variant::try_new_with_validation(&metadata, &value, Trusted).unwrap();
+        let err = Variant::try_new_with_validation(&metadata, &value, Structural).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("offsets not monotonically increasing"),
+            "{err}"
+        );
+        Variant::try_new_with_validation(&metadata, &value, Full).unwrap_err();
+    }
+
+    #[test]
+    fn test_variant_ordering() {
+        use std::cmp::Ordering;
+
+        let ts = DateTime::from_timestamp_nanos(1_755_259_384_123_456_789);
+        let later = DateTime::from_timestamp_nanos(1_755_259_384_123_456_790);
+        assert!(Variant::TimestampNanos(ts) < Variant::TimestampNanos(later));
+        assert!(
+            Variant::TimestampNtzNanos(later.naive_utc())
+                > Variant::TimestampNtzNanos(ts.naive_utc())
+        );
+        // timestamps with and without time zone, or of different units, are not ordered
+        assert_eq!(
+            Variant::TimestampNanos(ts).partial_cmp(&Variant::TimestampNtzNanos(ts.naive_utc())),
+            None
+        );
+        assert_eq!(
+            Variant::TimestampNanos(ts).partial_cmp(&Variant::TimestampMicros(ts)),
+            None
+        );
+
+        let uuid1 = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+        let uuid2 = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c9").unwrap();
+        assert!(Variant::Uuid(uuid1) < Variant::Uuid(uuid2));
+        assert_eq!(
+            Variant::Uuid(uuid1).partial_cmp(&Variant::Uuid(uuid1)),
+            Some(Ordering::Equal)
+        );
+
+        assert!(Variant::BooleanFalse < Variant::BooleanTrue);
+        assert!(Variant::from("a") < Variant::from("b"));
+        assert_eq!(Variant::Int8(1).partial_cmp(&Variant::Int32(2)), None);
+        assert_eq!(
+            Variant::Double(f64::NAN).partial_cmp(&Variant::Double(1.0)),
+            None
+        );
+
+        let d1 = VariantDecimal4::try_new(100, 2).unwrap();
+        let d2 = VariantDecimal4::try_new(15, 1).unwrap();
+        assert_eq!(Variant::from(d1).partial_cmp(&Variant::from(d2)), None);
+        let d3 = VariantDecimal4::try_new(101, 2).unwrap();
+        assert!(Variant::from(d1) < Variant::from(d3));
+
+        let mut builder = crate::VariantBuilder::new();
+        builder.new_list().with_value(1).finish();
+        let (metadata, value) = builder.finish();
+        let list = Variant::new(&metadata, &value);
+        assert_eq!(list.partial_cmp(&list.clone()), Some(Ordering::Equal));
+        assert_eq!(list.partial_cmp(&Variant::Int32(1)), None);
+    }
 }
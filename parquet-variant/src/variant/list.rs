@@ -21,6 +21,7 @@ use crate::utils::{
 use crate::variant::{Variant, VariantMetadata};
 
 use arrow_schema::ArrowError;
+use std::ops::Range;
 
 // The value header occupies one byte; use a named constant for readability
 const NUM_HEADER_BYTES: u32 = 1;
@@ -240,6 +241,33 @@ impl<'m, 'v> VariantList<'m, 'v> {
         Ok(self)
     }
 
+    /// Performs a [structural] validation of this list and its nested values, checking that all
+    /// offsets are in bounds and monotonically increasing. The metadata dictionary must be
+    /// validated separately.
+    ///
+    /// [structural]: crate::VariantValidation::Structural
+    pub(crate) fn validate_structure(&self) -> Result<(), ArrowError> {
+        if self.validated {
+            return Ok(());
+        }
+        let offset_bytes = slice_from_slice(
+            self.value,
+            self.header.first_offset_byte() as _..self.first_value_byte as _,
+        )?;
+        // Shallow validation ensures the first and last offsets are in bounds
+        let offsets = map_bytes_to_offsets(offset_bytes, self.header.offset_size);
+        if !offsets.is_sorted_by(|a, b| a <= b) {
+            return Err(ArrowError::InvalidArgumentError(
+                "offsets not monotonically increasing".to_string(),
+            ));
+        }
+        for i in 0..self.len() {
+            self.try_get_with_shallow_validation(i)?
+                .validate_structure()?;
+        }
+        Ok(())
+    }
+
     /// Return the length of this array
     pub fn len(&self) -> usize {
         self.num_elements as _
@@ -268,25 +296,48 @@ impl<'m, 'v> VariantList<'m, 'v> {
 
     // Fallible version of `get`, performing only basic (constant-time) validation.
     fn try_get_with_shallow_validation(&self, index: usize) -> Result<Variant<'m, 'v>, ArrowError> {
-        // Fetch the value bytes between the two offsets for this index, from the value array region
-        // of the byte buffer
-        let byte_range = self.get_offset(index)? as _..self.get_offset(index + 1)? as _;
-        let value_bytes =
-            slice_from_slice_at_offset(self.value, self.first_value_byte as _, byte_range)?;
+        let value_bytes = slice_from_slice(self.value, self.try_element_byte_range(index)?)?;
         Variant::try_new_with_metadata_and_shallow_validation(self.metadata.clone(), value_bytes)
     }
 
+    /// Returns the byte range of the element at `index` in [`Self::value`], without decoding it.
+    ///
+    /// Fails if `index` is not in `0..self.len()`, or if the offsets of the element are [invalid].
+    ///
+    /// [invalid]: Self#Validation
+    pub fn try_element_byte_range(&self, index: usize) -> Result<Range<usize>, ArrowError> {
+        if index >= self.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Index {index} out of bounds for variant list of length {}",
+                self.len()
+            )));
+        }
+        // The element is between the two offsets for this index, in the value array region of the
+        // byte buffer
+        let start = self.get_offset(index)? as usize;
+        let end = self.get_offset(index + 1)? as usize;
+        let value_bytes =
+            slice_from_slice_at_offset(self.value, self.first_value_byte as _, start..end)?;
+        let start = self.first_value_byte as usize + start;
+        Ok(start..start + value_bytes.len())
+    }
+
     /// Iterates over the values of this list. When working with [unvalidated] input, consider
     /// [`Self::iter_try`] to avoid panics due to invalid data.
     ///
     /// [unvalidated]: Self#Validation
-    pub fn iter(&self) -> impl Iterator<Item = Variant<'m, 'v>> + '_ {
+    pub fn iter(
+        &self,
+    ) -> impl ExactSizeIterator<Item = Variant<'m, 'v>> + DoubleEndedIterator + '_ {
         self.iter_try_with_shallow_validation()
             .map(|result| result.expect("Invalid variant list entry"))
     }
 
     /// Fallible iteration over the elements of this list.
-    pub fn iter_try(&self) -> impl Iterator<Item = Result<Variant<'m, 'v>, ArrowError>> + '_ {
+    pub fn iter_try(
+        &self,
+    ) -> impl ExactSizeIterator<Item = Result<Variant<'m, 'v>, ArrowError>> + DoubleEndedIterator + '_
+    {
         self.iter_try_with_shallow_validation()
             .map(|result| result?.with_full_validation())
     }
@@ -294,7 +345,8 @@ impl<'m, 'v> VariantList<'m, 'v> {
     // Fallible iteration that only performs basic (constant-time) validation.
     fn iter_try_with_shallow_validation(
         &self,
-    ) -> impl Iterator<Item = Result<Variant<'m, 'v>, ArrowError>> + '_ {
+    ) -> impl ExactSizeIterator<Item = Result<Variant<'m, 'v>, ArrowError>> + DoubleEndedIterator + '_
+    {
         (0..self.len()).map(|i| self.try_get_with_shallow_validation(i))
     }
 
@@ -744,4 +796,30 @@ mod tests {
         list_builder.finish();
         variant_builder.finish()
     }
+
+    #[test]
+    fn test_variant_list_iter_and_byte_ranges() {
+        let (metadata, value) = make_listi32(0..5);
+        let variant = Variant::new(&metadata, &value);
+        let list = variant.as_list().unwrap();
+
+        let mut iter = list.iter();
+        assert_eq!(iter.len(), 5);
+        assert_eq!(iter.next_back(), Some(Variant::from(4i32)));
+        assert_eq!(iter.next(), Some(Variant::from(0i32)));
+        assert_eq!(iter.len(), 3);
+        assert_eq!(list.iter_try().rev().count(), 5);
+
+        for i in 0..list.len() {
+            let range = list.try_element_byte_range(i).unwrap();
+            let element = Variant::try_new_with_metadata(list.metadata.clone(), &list.value[range]);
+            assert_eq!(element.unwrap(), list.get(i).unwrap());
+        }
+
+        let err = list.try_element_byte_range(5).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Index 5 out of bounds for variant list of length 5"
+        );
+    }
 }
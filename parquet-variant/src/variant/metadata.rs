@@ -326,6 +326,29 @@ impl<'m> VariantMetadata<'m> {
         Ok(self)
     }
 
+    /// Performs a [structural] validation of this metadata dictionary, checking that the offsets
+    /// are in bounds and monotonically increasing, but not that the entries are UTF-8 encoded,
+    /// unique, or sorted.
+    ///
+    /// [structural]: crate::VariantValidation::Structural
+    pub(crate) fn validate_structure(&self) -> Result<(), ArrowError> {
+        if self.validated {
+            return Ok(());
+        }
+        let offset_bytes = slice_from_slice(
+            self.bytes,
+            self.header.first_offset_byte() as _..self.first_value_byte as _,
+        )?;
+        // Shallow validation ensures the first and last offsets are in bounds
+        let offsets = map_bytes_to_offsets(offset_bytes, self.header.offset_size);
+        if !offsets.is_sorted_by(|a, b| a <= b) {
+            return Err(ArrowError::InvalidArgumentError(
+                "offsets not monotonically increasing".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
     /// Whether the dictionary keys are sorted and unique
     pub fn is_sorted(&self) -> bool {
         self.header.is_sorted
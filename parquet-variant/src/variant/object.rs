@@ -17,11 +17,13 @@
 
 use crate::decoder::{OffsetSizeBytes, map_bytes_to_offsets};
 use crate::utils::{
-    first_byte_from_slice, overflow_error, slice_from_slice, try_binary_search_range_by,
+    first_byte_from_slice, overflow_error, slice_from_slice, slice_from_slice_at_offset,
+    try_binary_search_range_by,
 };
 use crate::variant::{Variant, VariantMetadata};
 
 use arrow_schema::ArrowError;
+use std::ops::Range;
 
 // The value header occupies one byte; use a named constant for readability
 const NUM_HEADER_BYTES: u32 = 1;
@@ -300,6 +302,28 @@ impl<'m, 'v> VariantObject<'m, 'v> {
         Ok(self)
     }
 
+    /// Performs a [structural] validation of this object and its nested values, checking that all
+    /// field ids and offsets are in bounds, but not that the field names are sorted. The metadata
+    /// dictionary must be validated separately.
+    ///
+    /// [structural]: crate::VariantValidation::Structural
+    pub(crate) fn validate_structure(&self) -> Result<(), ArrowError> {
+        if self.validated {
+            return Ok(());
+        }
+        let dictionary_size = self.metadata.len();
+        for i in 0..self.len() {
+            if self.try_field_id(i)? as usize >= dictionary_size {
+                return Err(ArrowError::InvalidArgumentError(
+                    "field id is not valid".to_string(),
+                ));
+            }
+            self.try_field_with_shallow_validation(i)?
+                .validate_structure()?;
+        }
+        Ok(())
+    }
+
     /// Returns the number of key-value pairs in this object
     pub fn len(&self) -> usize {
         self.num_elements as _
@@ -338,6 +362,40 @@ impl<'m, 'v> VariantObject<'m, 'v> {
         Variant::try_new_with_metadata_and_shallow_validation(self.metadata.clone(), value_bytes)
     }
 
+    /// Returns the byte range of the ith field's value in [`Self::value`], without decoding it.
+    ///
+    /// Field values need not be stored in field order, so finding the end of the value requires
+    /// scanning the field offsets, at a cost linear in the number of fields.
+    ///
+    /// Fails if `i` is not in `0..self.len()`, or if the field offsets are [invalid].
+    ///
+    /// [invalid]: Self#Validation
+    pub fn try_field_byte_range(&self, i: usize) -> Result<Range<usize>, ArrowError> {
+        if i >= self.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Index {i} out of bounds for variant object with {} fields",
+                self.len()
+            )));
+        }
+        let start = self.get_offset(i)?;
+        // The value ends at the next larger offset, or at the end of the values. The last offset
+        // points to the byte after the end of the last value.
+        let mut end = self.get_offset(self.len())?;
+        for j in 0..self.len() {
+            let offset = self.get_offset(j)?;
+            if offset > start && offset < end {
+                end = offset;
+            }
+        }
+        let value_bytes = slice_from_slice_at_offset(
+            self.value,
+            self.first_value_byte as _,
+            start as _..end as _,
+        )?;
+        let start = self.first_value_byte as usize + start as usize;
+        Ok(start..start + value_bytes.len())
+    }
+
     // Attempts to retrieve the ith offset from the field offset region of the byte buffer.
     fn get_offset(&self, i: usize) -> Result<u32, ArrowError> {
         let byte_range = self.first_field_offset_byte as _..self.first_value_byte as _;
@@ -359,14 +417,20 @@ impl<'m, 'v> VariantObject<'m, 'v> {
 
     /// Fallible version of `field_name`. Returns field name by index, capturing validation errors
     fn try_field_name(&self, i: usize) -> Result<&'m str, ArrowError> {
+        self.metadata.get(self.try_field_id(i)? as _)
+    }
+
+    // Attempts to retrieve the ith field id from the field id region of the byte buffer.
+    fn try_field_id(&self, i: usize) -> Result<u32, ArrowError> {
         let byte_range = self.header.field_ids_start_byte() as _..self.first_field_offset_byte as _;
         let field_id_bytes = slice_from_slice(self.value, byte_range)?;
-        let field_id = self.header.field_id_size.unpack_u32(field_id_bytes, i)?;
-        self.metadata.get(field_id as _)
+        self.header.field_id_size.unpack_u32(field_id_bytes, i)
     }
 
     /// Returns an iterator of (name, value) pairs over the fields of this object.
-    pub fn iter(&self) -> impl Iterator<Item = (&'m str, Variant<'m, 'v>)> + '_ {
+    pub fn iter(
+        &self,
+    ) -> impl ExactSizeIterator<Item = (&'m str, Variant<'m, 'v>)> + DoubleEndedIterator + '_ {
         self.iter_try_with_shallow_validation()
             .map(|result| result.expect("Invalid variant object field value"))
     }
@@ -374,7 +438,9 @@ impl<'m, 'v> VariantObject<'m, 'v> {
     /// Fallible iteration over the fields of this object.
     pub fn iter_try(
         &self,
-    ) -> impl Iterator<Item = Result<(&'m str, Variant<'m, 'v>), ArrowError>> + '_ {
+    ) -> impl ExactSizeIterator<Item = Result<(&'m str, Variant<'m, 'v>), ArrowError>>
+    + DoubleEndedIterator
+    + '_ {
         self.iter_try_with_shallow_validation().map(|result| {
             let (name, value) = result?;
             Ok((name, value.with_full_validation()?))
@@ -385,7 +451,9 @@ impl<'m, 'v> VariantObject<'m, 'v> {
     // validation of field values.
     fn iter_try_with_shallow_validation(
         &self,
-    ) -> impl Iterator<Item = Result<(&'m str, Variant<'m, 'v>), ArrowError>> + '_ {
+    ) -> impl ExactSizeIterator<Item = Result<(&'m str, Variant<'m, 'v>), ArrowError>>
+    + DoubleEndedIterator
+    + '_ {
         (0..self.len()).map(|i| {
             let field = self.try_field_with_shallow_validation(i)?;
             Ok((self.try_field_name(i)?, field))
@@ -396,14 +464,27 @@ impl<'m, 'v> VariantObject<'m, 'v> {
     ///
     /// Returns `Some(Variant)` if the field exists, or `None` if the field does not exist.
     pub fn get(&self, name: &str) -> Option<Variant<'m, 'v>> {
+        self.field(self.field_index(name)?)
+    }
+
+    /// Returns the index in `0..self.len()` of the field with the specified name, if any.
+    ///
+    /// The search cost is logarithmic in the number of fields.
+    pub fn field_index(&self, name: &str) -> Option<usize> {
+        if self.metadata.is_sorted() {
+            // With a sorted dictionary, field ids are ordered like their names, so look up the
+            // field id once and binary search the field ids without comparing strings.
+            let (field_id, _) = self.metadata.get_entry(name)?;
+            let cmp = |i| Some(self.try_field_id(i).ok()?.cmp(&field_id));
+            return try_binary_search_range_by(0..self.len(), cmp)?.ok();
+        }
         // Binary search through the field IDs of this object to find the requested field name.
         //
         // NOTE: This does not require a sorted metadata dictionary, because the variant spec
         // requires object field ids to be lexically sorted by their corresponding string values,
         // and probing the dictionary for a field id is always O(1) work.
         let cmp = |i| Some(self.field_name(i)?.cmp(name));
-        let i = try_binary_search_range_by(0..self.len(), cmp)?.ok()?;
-        self.field(i)
+        try_binary_search_range_by(0..self.len(), cmp)?.ok()
     }
 }
 
@@ -994,4 +1075,74 @@ mod tests {
         let v2 = Variant::new_with_metadata(m, &v);
         assert_eq!(v1, v2);
     }
+
+    #[test]
+    fn test_variant_object_field_index() {
+        // field ids in insertion order "c", "a", "b": the dictionary is not sorted
+        let mut b = VariantBuilder::new();
+        let mut o = b.new_object();
+        o.insert("c", 3);
+        o.insert("a", 1);
+        o.insert("b", 2);
+        o.finish();
+        let (unsorted_metadata, unsorted_value) = b.finish();
+        let unsorted = Variant::new(&unsorted_metadata, &unsorted_value);
+        assert!(!unsorted.metadata().is_sorted());
+
+        let mut b = VariantBuilder::new().with_field_names(["a", "b", "c", "d"]);
+        let mut o = b.new_object();
+        o.insert("c", 3);
+        o.insert("a", 1);
+        o.insert("b", 2);
+        o.finish();
+        let (sorted_metadata, sorted_value) = b.finish();
+        let sorted = Variant::new(&sorted_metadata, &sorted_value);
+        assert!(sorted.metadata().is_sorted());
+
+        for variant in [unsorted, sorted] {
+            let obj = variant.as_object().unwrap();
+            assert_eq!(obj.field_index("a"), Some(0));
+            assert_eq!(obj.field_index("b"), Some(1));
+            assert_eq!(obj.field_index("c"), Some(2));
+            // "d" is in the sorted dictionary, but not in the object
+            assert_eq!(obj.field_index("d"), None);
+            assert_eq!(obj.field_index("e"), None);
+            assert_eq!(obj.get("c"), Some(Variant::from(3i32)));
+        }
+    }
+
+    #[test]
+    fn test_variant_object_iter_and_byte_ranges() {
+        let mut b = VariantBuilder::new();
+        let mut o = b.new_object();
+        // values are written in insertion order, not field order
+        o.insert("b", "a longer string value");
+        o.insert("a", 1i64);
+        o.insert("c", true);
+        o.finish();
+        let (m, v) = b.finish();
+        let variant = Variant::new(&m, &v);
+        let obj = variant.as_object().unwrap();
+
+        let mut iter = obj.iter();
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next_back().unwrap().0, "c");
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.next().unwrap().0, "a");
+        assert_eq!(obj.iter_try().len(), 3);
+
+        for i in 0..obj.len() {
+            let range = obj.try_field_byte_range(i).unwrap();
+            let field = Variant::try_new_with_metadata(obj.metadata.clone(), &obj.value[range]);
+            assert_eq!(field.unwrap(), obj.field(i).unwrap());
+        }
+        let range_b = obj.try_field_byte_range(1).unwrap();
+        assert_eq!(range_b.len(), 1 + "a longer string value".len());
+
+        let err = obj.try_field_byte_range(3).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Index 3 out of bounds for variant object with 3 fields"
+        );
+    }
 }
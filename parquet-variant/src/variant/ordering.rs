@@ -0,0 +1,411 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Total ordering and hashing of [`Variant`] values, see [`Variant::total_cmp`]
+
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+use crate::Variant;
+
+/// The equivalence classes of variant types, in the order they are sorted by
+/// [`Variant::total_cmp`]. Values of different types in the same class are compared by
+/// their logical value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum TypeClass {
+    Null,
+    Boolean,
+    /// Integers and decimals
+    Exact,
+    /// Float and double
+    Floating,
+    Date,
+    Time,
+    Timestamp,
+    TimestampNtz,
+    String,
+    Binary,
+    Uuid,
+    Object,
+    List,
+}
+
+/// An exact numeric value `unscaled * 10^-scale`, normalized so that equal values have
+/// the same representation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct ExactNumeric {
+    unscaled: i128,
+    scale: u8,
+}
+
+impl ExactNumeric {
+    fn new(mut unscaled: i128, mut scale: u8) -> Self {
+        while scale > 0 && unscaled % 10 == 0 {
+            unscaled /= 10;
+            scale -= 1;
+        }
+        Self { unscaled, scale }
+    }
+
+    fn cmp(&self, other: &Self) -> Ordering {
+        match self.scale.cmp(&other.scale) {
+            Ordering::Equal => self.unscaled.cmp(&other.unscaled),
+            Ordering::Less => Self::cmp_rescaled(self, other),
+            Ordering::Greater => Self::cmp_rescaled(other, self).reverse(),
+        }
+    }
+
+    /// Compares `a` and `b` where `a.scale < b.scale`, by rescaling `a` to the scale of `b`
+    fn cmp_rescaled(a: &Self, b: &Self) -> Ordering {
+        let rescaled = 10_i128
+            .checked_pow((b.scale - a.scale) as u32)
+            .and_then(|multiplier| a.unscaled.checked_mul(multiplier));
+        match rescaled {
+            Some(unscaled) => unscaled.cmp(&b.unscaled),
+            // The magnitude of `a` exceeds that of any value with the scale of `b`
+            None => a.unscaled.cmp(&0),
+        }
+    }
+}
+
+impl<'m, 'v> Variant<'m, 'v> {
+    fn type_class(&self) -> TypeClass {
+        match self {
+            Variant::Null => TypeClass::Null,
+            Variant::BooleanTrue | Variant::BooleanFalse => TypeClass::Boolean,
+            Variant::Int8(_)
+            | Variant::Int16(_)
+            | Variant::Int32(_)
+            | Variant::Int64(_)
+            | Variant::Decimal4(_)
+            | Variant::Decimal8(_)
+            | Variant::Decimal16(_) => TypeClass::Exact,
+            Variant::Float(_) | Variant::Double(_) => TypeClass::Floating,
+            Variant::Date(_) => TypeClass::Date,
+            Variant::Time(_) => TypeClass::Time,
+            Variant::TimestampMicros(_) | Variant::TimestampNanos(_) => TypeClass::Timestamp,
+            Variant::TimestampNtzMicros(_) | Variant::TimestampNtzNanos(_) => {
+                TypeClass::TimestampNtz
+            }
+            Variant::String(_) | Variant::ShortString(_) => TypeClass::String,
+            Variant::Binary(_) => TypeClass::Binary,
+            Variant::Uuid(_) => TypeClass::Uuid,
+            Variant::Object(_) => TypeClass::Object,
+            Variant::List(_) => TypeClass::List,
+        }
+    }
+
+    fn as_exact_numeric(&self) -> Option<ExactNumeric> {
+        let (unscaled, scale) = match self {
+            Variant::Int8(v) => (*v as i128, 0),
+            Variant::Int16(v) => (*v as i128, 0),
+            Variant::Int32(v) => (*v as i128, 0),
+            Variant::Int64(v) => (*v as i128, 0),
+            Variant::Decimal4(d) => (d.integer() as i128, d.scale()),
+            Variant::Decimal8(d) => (d.integer() as i128, d.scale()),
+            Variant::Decimal16(d) => (d.integer(), d.scale()),
+            _ => return None,
+        };
+        Some(ExactNumeric::new(unscaled, scale))
+    }
+
+    /// Returns the ordering between `self` and `other` according to a total order over all
+    /// variant values, for example to sort them or to use them as grouping or join keys.
+    ///
+    /// Values are first ordered by type, in the order: null, boolean, exact numeric, floating
+    /// point, date, time, timestamp, timestamp without time zone, string, binary, UUID,
+    /// object and list. Within each type, values are ordered as follows:
+    ///
+    /// * Integers and decimals of any width and scale are compared by their numeric value, so
+    ///   that `Variant::Int8(1)` is equal to `Variant::Int64(1)` and to the decimal `1.00`.
+    /// * Floats and doubles are compared by their value as a double, using [`f64::total_cmp`].
+    ///   Note that this distinguishes `-0.0` from `0.0` and orders `NaN`s after all numbers.
+    /// * Timestamps of microsecond and nanosecond precision are compared by instant.
+    /// * Strings and short strings are compared by their bytes.
+    /// * Objects are compared field by field, in the order of their field names, first by field
+    ///   name and then by value, so that objects with the same fields are equal regardless of
+    ///   the order in which their values were written.
+    /// * Lists are compared lexicographically by their elements.
+    ///
+    /// Unlike [`PartialEq`], which compares the variant types exactly, this ordering is
+    /// consistent with [`Self::total_hash`].
+    ///
+    /// # Example
+    /// ```
+    /// # use std::cmp::Ordering;
+    /// # use parquet_variant::{Variant, VariantDecimal4};
+    /// let one = Variant::from(1i8);
+    /// let decimal = Variant::from(VariantDecimal4::try_new(100, 2).unwrap());
+    /// assert_eq!(one.total_cmp(&decimal), Ordering::Equal);
+    /// assert_ne!(one, decimal);
+    ///
+    /// // numbers are ordered before strings
+    /// assert_eq!(Variant::from(1000).total_cmp(&Variant::from("a")), Ordering::Less);
+    /// ```
+    pub fn total_cmp(&self, other: &Variant<'_, '_>) -> Ordering {
+        let (class, other_class) = (self.type_class(), other.type_class());
+        if class != other_class {
+            return class.cmp(&other_class);
+        }
+        match (self, other) {
+            (Variant::BooleanFalse, Variant::BooleanTrue) => Ordering::Less,
+            (Variant::BooleanTrue, Variant::BooleanFalse) => Ordering::Greater,
+            (Variant::Date(a), Variant::Date(b)) => a.cmp(b),
+            (Variant::Time(a), Variant::Time(b)) => a.cmp(b),
+            (
+                Variant::TimestampMicros(a) | Variant::TimestampNanos(a),
+                Variant::TimestampMicros(b) | Variant::TimestampNanos(b),
+            ) => a.cmp(b),
+            (
+                Variant::TimestampNtzMicros(a) | Variant::TimestampNtzNanos(a),
+                Variant::TimestampNtzMicros(b) | Variant::TimestampNtzNanos(b),
+            ) => a.cmp(b),
+            (Variant::Binary(a), Variant::Binary(b)) => a.cmp(b),
+            (Variant::Uuid(a), Variant::Uuid(b)) => a.cmp(b),
+            (Variant::Object(a), Variant::Object(b)) => {
+                let mut a = a.iter();
+                let mut b = b.iter();
+                loop {
+                    match (a.next(), b.next()) {
+                        (None, None) => return Ordering::Equal,
+                        (None, Some(_)) => return Ordering::Less,
+                        (Some(_), None) => return Ordering::Greater,
+                        (Some((name_a, value_a)), Some((name_b, value_b))) => {
+                            let ordering =
+                                name_a.cmp(name_b).then_with(|| value_a.total_cmp(&value_b));
+                            if ordering.is_ne() {
+                                return ordering;
+                            }
+                        }
+                    }
+                }
+            }
+            (Variant::List(a), Variant::List(b)) => {
+                let mut a = a.iter();
+                let mut b = b.iter();
+                loop {
+                    match (a.next(), b.next()) {
+                        (None, None) => return Ordering::Equal,
+                        (None, Some(_)) => return Ordering::Less,
+                        (Some(_), None) => return Ordering::Greater,
+                        (Some(a), Some(b)) => {
+                            let ordering = a.total_cmp(&b);
+                            if ordering.is_ne() {
+                                return ordering;
+                            }
+                        }
+                    }
+                }
+            }
+            _ => match class {
+                TypeClass::Exact => {
+                    let a = self.as_exact_numeric().unwrap();
+                    let b = other.as_exact_numeric().unwrap();
+                    a.cmp(&b)
+                }
+                TypeClass::Floating => self.as_f64().unwrap().total_cmp(&other.as_f64().unwrap()),
+                TypeClass::String => self.as_string().unwrap().cmp(other.as_string().unwrap()),
+                // Null and equal booleans
+                _ => Ordering::Equal,
+            },
+        }
+    }
+
+    /// Feeds this value into `state`, consistently with [`Self::total_cmp`]: values that are
+    /// equal according to [`Self::total_cmp`] have the same hash, regardless of their exact
+    /// variant type or the order in which their object fields were written.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::hash::{BuildHasher, RandomState};
+    /// # use parquet_variant::Variant;
+    /// let state = RandomState::new();
+    /// let hash = |v: &Variant| state.hash_one(v.total_hash_key());
+    /// assert_eq!(hash(&Variant::from(1i8)), hash(&Variant::from(1i64)));
+    /// ```
+    pub fn total_hash<H: Hasher>(&self, state: &mut H) {
+        let class = self.type_class();
+        class.hash(state);
+        match self {
+            Variant::Null | Variant::BooleanTrue => {}
+            Variant::BooleanFalse => false.hash(state),
+            Variant::Date(v) => v.hash(state),
+            Variant::Time(v) => v.hash(state),
+            Variant::TimestampMicros(v) | Variant::TimestampNanos(v) => v.hash(state),
+            Variant::TimestampNtzMicros(v) | Variant::TimestampNtzNanos(v) => v.hash(state),
+            Variant::Binary(v) => v.hash(state),
+            Variant::Uuid(v) => v.hash(state),
+            Variant::Object(obj) => {
+                obj.len().hash(state);
+                for (name, value) in obj.iter() {
+                    name.hash(state);
+                    value.total_hash(state);
+                }
+            }
+            Variant::List(list) => {
+                list.len().hash(state);
+                for value in list.iter() {
+                    value.total_hash(state);
+                }
+            }
+            _ => match class {
+                TypeClass::Exact => self.as_exact_numeric().unwrap().hash(state),
+                TypeClass::Floating => self.as_f64().unwrap().to_bits().hash(state),
+                TypeClass::String => self.as_string().unwrap().hash(state),
+                _ => unreachable!("all other types are matched above"),
+            },
+        }
+    }
+
+    /// Returns a wrapper around this value that implements [`Eq`], [`Ord`] and [`Hash`]
+    /// according to [`Self::total_cmp`] and [`Self::total_hash`], for example to use variant
+    /// values as the keys of a `HashMap` or `BTreeMap`.
+    pub fn total_hash_key(&self) -> VariantKey<'_, 'm, 'v> {
+        VariantKey(self)
+    }
+}
+
+/// A reference to a [`Variant`] compared and hashed using [`Variant::total_cmp`] and
+/// [`Variant::total_hash`], see [`Variant::total_hash_key`]
+#[derive(Debug, Clone, Copy)]
+pub struct VariantKey<'a, 'm, 'v>(&'a Variant<'m, 'v>);
+
+impl<'a, 'm, 'v> VariantKey<'a, 'm, 'v> {
+    /// Returns the wrapped value
+    pub fn variant(&self) -> &'a Variant<'m, 'v> {
+        self.0
+    }
+}
+
+impl PartialEq for VariantKey<'_, '_, '_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.total_cmp(other.0).is_eq()
+    }
+}
+
+impl Eq for VariantKey<'_, '_, '_> {}
+
+impl PartialOrd for VariantKey<'_, '_, '_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for VariantKey<'_, '_, '_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(other.0)
+    }
+}
+
+impl Hash for VariantKey<'_, '_, '_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.total_hash(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{VariantBuilder, VariantDecimal4, VariantDecimal8, VariantDecimal16};
+    use std::hash::{BuildHasher, RandomState};
+
+    fn assert_total_eq(a: &Variant, b: &Variant) {
+        assert_eq!(a.total_cmp(b), Ordering::Equal, "{a:?} != {b:?}");
+        let state = RandomState::new();
+        assert_eq!(
+            state.hash_one(a.total_hash_key()),
+            state.hash_one(b.total_hash_key()),
+            "hash of {a:?} != hash of {b:?}"
+        );
+    }
+
+    #[test]
+    fn test_total_cmp_exact_numeric() {
+        let one = Variant::from(1i8);
+        assert_total_eq(&one, &Variant::from(1i64));
+        assert_total_eq(&one, &VariantDecimal4::try_new(100, 2).unwrap().into());
+        assert_total_eq(&one, &VariantDecimal16::try_new(10, 1).unwrap().into());
+
+        let half = Variant::from(VariantDecimal8::try_new(5, 1).unwrap());
+        assert_eq!(half.total_cmp(&one), Ordering::Less);
+        assert_eq!(Variant::from(-1).total_cmp(&half), Ordering::Less);
+
+        // rescaling the integer to the scale of the decimal overflows
+        let tiny = Variant::from(VariantDecimal16::try_new(1, 38).unwrap());
+        assert_eq!(Variant::from(i64::MAX).total_cmp(&tiny), Ordering::Greater);
+        assert_eq!(Variant::from(i64::MIN).total_cmp(&tiny), Ordering::Less);
+    }
+
+    #[test]
+    fn test_total_cmp_types() {
+        let ordered = [
+            Variant::Null,
+            Variant::BooleanFalse,
+            Variant::BooleanTrue,
+            Variant::from(100),
+            Variant::from(-1.5f32),
+            Variant::from(2.0f64),
+            Variant::from("a"),
+            Variant::from("b"),
+            Variant::from(&b"a"[..]),
+        ];
+        for (i, a) in ordered.iter().enumerate() {
+            for (j, b) in ordered.iter().enumerate() {
+                assert_eq!(a.total_cmp(b), i.cmp(&j), "{a:?} {b:?}");
+            }
+        }
+        assert_total_eq(&Variant::from(1.5f32), &Variant::from(1.5f64));
+    }
+
+    #[test]
+    fn test_total_cmp_nested() {
+        let mut builder = VariantBuilder::new();
+        builder
+            .new_object()
+            .with_field("b", 2i32)
+            .with_field("a", 1i8)
+            .finish();
+        let (m1, v1) = builder.finish();
+        let mut builder = VariantBuilder::new();
+        builder
+            .new_object()
+            .with_field("a", 1i64)
+            .with_field("b", 2i16)
+            .finish();
+        let (m2, v2) = builder.finish();
+        let mut builder = VariantBuilder::new();
+        builder.new_object().with_field("a", 1i8).finish();
+        let (m3, v3) = builder.finish();
+
+        let o1 = Variant::new(&m1, &v1);
+        let o2 = Variant::new(&m2, &v2);
+        let o3 = Variant::new(&m3, &v3);
+        assert_total_eq(&o1, &o2);
+        assert_eq!(o3.total_cmp(&o1), Ordering::Less);
+
+        let mut builder = VariantBuilder::new();
+        builder.new_list().with_value(1).with_value("x").finish();
+        let (m, v) = builder.finish();
+        let mut builder = VariantBuilder::new();
+        builder.new_list().with_value(1).finish();
+        let (m_short, v_short) = builder.finish();
+        let list = Variant::new(&m, &v);
+        let short = Variant::new(&m_short, &v_short);
+        assert_eq!(short.total_cmp(&list), Ordering::Less);
+        assert_eq!(list.total_cmp(&o1), Ordering::Greater);
+    }
+}
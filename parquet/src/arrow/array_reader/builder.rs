@@ -20,6 +20,8 @@ use std::sync::{Arc, RwLock};
 use arrow_schema::{DataType, Fields, SchemaBuilder};
 
 use crate::arrow::ProjectionMask;
+#[cfg(feature = "variant_experimental")]
+use crate::arrow::array_reader::VariantArrayReader;
 use crate::arrow::array_reader::byte_view_array::make_byte_view_array_reader;
 use crate::arrow::array_reader::cached_array_reader::CacheRole;
 use crate::arrow::array_reader::cached_array_reader::CachedArrayReader;
@@ -34,7 +36,9 @@ use crate::arrow::array_reader::{
     make_byte_array_dictionary_reader, make_byte_array_reader,
 };
 use crate::arrow::arrow_reader::DEFAULT_BATCH_SIZE;
-use crate::arrow::arrow_reader::metrics::ArrowReaderMetrics;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::arrow::arrow_reader::metrics::MetricsArrayReader;
+use crate::arrow::arrow_reader::metrics::{ArrowReaderMetrics, MetricsPageIterator};
 use crate::arrow::schema::{ParquetField, ParquetFieldType, VirtualColumnType};
 use crate::basic::Type as PhysicalType;
 use crate::data_type::{BoolType, DoubleType, FloatType, Int32Type, Int64Type, Int96Type};
@@ -180,6 +184,14 @@ impl<'a> ArrayReaderBuilder<'a> {
                 let Some(reader) = self.build_primitive_reader(args)? else {
                     return Ok(None);
                 };
+                #[cfg(not(target_arch = "wasm32"))]
+                let reader: Box<dyn ArrayReader> = match self.metrics.decode_timing_enabled() {
+                    true => {
+                        let counters = self.metrics.column_counters(col_idx).unwrap();
+                        Box::new(MetricsArrayReader::new(reader, counters))
+                    }
+                    false => reader,
+                };
                 let Some(cache_options) = self.cache_options.as_ref() else {
                     return Ok(Some(reader));
                 };
@@ -216,6 +228,10 @@ impl<'a> ArrayReaderBuilder<'a> {
                 DataType::FixedSizeList(_, _) => self.build_fixed_size_list_reader(args),
                 d => unimplemented!("reading group type {} not implemented", d),
             },
+            #[cfg(feature = "variant_experimental")]
+            ParquetFieldType::Variant { ref shredded } => Ok(self
+                .build_reader(args.with_field(shredded))?
+                .map(VariantArrayReader::wrap)),
         }
     }
 
@@ -428,7 +444,10 @@ impl<'a> ArrayReaderBuilder<'a> {
             ColumnPath::new(vec![]),
         ));
 
-        let page_iterator = self.row_groups.column_chunks(col_idx)?;
+        let mut page_iterator = self.row_groups.column_chunks(col_idx)?;
+        if let Some(counters) = self.metrics.column_counters(col_idx) {
+            page_iterator = Box::new(MetricsPageIterator::new(page_iterator, counters));
+        }
         let arrow_type = Some(field.arrow_type.clone());
 
         // LogicalType::Unknown maps to DataType::Null. In the past it has been assumed
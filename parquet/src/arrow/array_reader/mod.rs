@@ -47,6 +47,8 @@ mod row_group_cache;
 mod row_group_index;
 mod row_number;
 mod struct_array;
+#[cfg(feature = "variant_experimental")]
+mod variant_array;
 
 #[cfg(test)]
 pub(crate) mod test_util;
@@ -68,6 +70,8 @@ pub use null_array::NullArrayReader;
 pub use primitive_array::PrimitiveArrayReader;
 pub use row_group_cache::RowGroupCache;
 pub use struct_array::StructArrayReader;
+#[cfg(feature = "variant_experimental")]
+pub(crate) use variant_array::{VariantArrayReader, unshredded_variant_type};
 
 /// Reads Parquet data into Arrow Arrays.
 ///
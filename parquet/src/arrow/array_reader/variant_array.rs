@@ -0,0 +1,102 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::arrow::array_reader::ArrayReader;
+use crate::errors::Result;
+use arrow_array::{ArrayRef, StructArray};
+use arrow_schema::{DataType as ArrowType, Field, Fields};
+use parquet_variant_compute::{VariantArray, unshred_variant};
+use std::any::Any;
+use std::sync::Arc;
+
+/// Returns the type of a shredded Variant of type `shredded` once unshredded,
+/// or `None` if it is not shredded or has no `metadata` field
+///
+/// The `metadata` field is kept as it appears in `shredded`
+pub(crate) fn unshredded_variant_type(shredded: &ArrowType) -> Option<ArrowType> {
+    let ArrowType::Struct(fields) = shredded else {
+        return None;
+    };
+    fields.find("typed_value")?;
+    let (_, metadata) = fields.find("metadata")?;
+    Some(ArrowType::Struct(Fields::from(vec![
+        Arc::clone(metadata),
+        Arc::new(Field::new("value", ArrowType::BinaryView, true)),
+    ])))
+}
+
+/// An [`ArrayReader`] that reads a shredded Variant column as an unshredded
+/// [`VariantArray`], folding the `typed_value` columns back into `value`
+pub(crate) struct VariantArrayReader {
+    inner: Box<dyn ArrayReader>,
+    data_type: ArrowType,
+}
+
+impl VariantArrayReader {
+    /// Wraps `inner`, which reads the shredded Variant struct, or returns
+    /// `inner` unchanged if it does not read the `metadata` and `typed_value`
+    /// fields, for example due to a projection
+    ///
+    /// The output type is derived from the children `inner` actually reads,
+    /// rather than from the Variant field of the file schema
+    pub(crate) fn wrap(inner: Box<dyn ArrayReader>) -> Box<dyn ArrayReader> {
+        match unshredded_variant_type(inner.get_data_type()) {
+            Some(data_type) => Box::new(Self { inner, data_type }),
+            None => inner,
+        }
+    }
+}
+
+impl ArrayReader for VariantArrayReader {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_data_type(&self) -> &ArrowType {
+        &self.data_type
+    }
+
+    fn read_records(&mut self, batch_size: usize) -> Result<usize> {
+        self.inner.read_records(batch_size)
+    }
+
+    fn consume_batch(&mut self) -> Result<ArrayRef> {
+        let shredded = self.inner.consume_batch()?;
+        let unshredded = unshred_variant(&VariantArray::try_new(&shredded)?)?;
+        let ArrowType::Struct(fields) = &self.data_type else {
+            unreachable!("unshredded Variant must be a struct");
+        };
+        let (_, columns, nulls) = unshredded.into_inner().into_parts();
+        Ok(Arc::new(StructArray::try_new(
+            fields.clone(),
+            columns,
+            nulls,
+        )?))
+    }
+
+    fn skip_records(&mut self, num_records: usize) -> Result<usize> {
+        self.inner.skip_records(num_records)
+    }
+
+    fn get_def_levels(&self) -> Option<&[i16]> {
+        self.inner.get_def_levels()
+    }
+
+    fn get_rep_levels(&self) -> Option<&[i16]> {
+        self.inner.get_rep_levels()
+    }
+}
@@ -0,0 +1,325 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Decoding of column chunks directly into [`ArrayBuilder`]s
+
+use crate::column::page::PageReader;
+use crate::column::reader::ColumnReaderImpl;
+use crate::data_type::{
+    BoolType, ByteArray, ByteArrayType, DataType, DoubleType, FloatType, Int32Type, Int64Type,
+};
+use crate::errors::{ParquetError, Result};
+use crate::schema::types::ColumnDescPtr;
+use arrow_array::builder::{
+    ArrayBuilder, BinaryViewBuilder, BooleanBuilder, GenericBinaryBuilder, GenericStringBuilder,
+    PrimitiveBuilder, StringViewBuilder,
+};
+use arrow_array::{OffsetSizeTrait, types as arrow_types};
+
+/// An [`ArrayBuilder`] that values of the parquet physical type [`Self::Physical`]
+/// can be appended to by a [`ColumnChunkDecoder`]
+pub trait DecodeTarget: ArrayBuilder {
+    /// The parquet physical type of the values appended to this builder
+    type Physical: DataType;
+
+    /// Appends the non-null `values`
+    fn append_decoded(&mut self, values: &[<Self::Physical as DataType>::T]) -> Result<()>;
+
+    /// Appends a null
+    fn append_decoded_null(&mut self);
+}
+
+macro_rules! primitive_target {
+    ($physical:ty, $arrow:ty) => {
+        impl DecodeTarget for PrimitiveBuilder<$arrow> {
+            type Physical = $physical;
+
+            fn append_decoded(&mut self, values: &[<$physical as DataType>::T]) -> Result<()> {
+                self.append_slice(values);
+                Ok(())
+            }
+
+            fn append_decoded_null(&mut self) {
+                self.append_null()
+            }
+        }
+    };
+}
+
+primitive_target!(Int32Type, arrow_types::Int32Type);
+primitive_target!(Int64Type, arrow_types::Int64Type);
+primitive_target!(FloatType, arrow_types::Float32Type);
+primitive_target!(DoubleType, arrow_types::Float64Type);
+
+impl DecodeTarget for BooleanBuilder {
+    type Physical = BoolType;
+
+    fn append_decoded(&mut self, values: &[bool]) -> Result<()> {
+        self.append_slice(values);
+        Ok(())
+    }
+
+    fn append_decoded_null(&mut self) {
+        self.append_null()
+    }
+}
+
+impl<O: OffsetSizeTrait> DecodeTarget for GenericBinaryBuilder<O> {
+    type Physical = ByteArrayType;
+
+    fn append_decoded(&mut self, values: &[ByteArray]) -> Result<()> {
+        values.iter().for_each(|v| self.append_value(v.data()));
+        Ok(())
+    }
+
+    fn append_decoded_null(&mut self) {
+        self.append_null()
+    }
+}
+
+impl<O: OffsetSizeTrait> DecodeTarget for GenericStringBuilder<O> {
+    type Physical = ByteArrayType;
+
+    fn append_decoded(&mut self, values: &[ByteArray]) -> Result<()> {
+        for v in values {
+            self.append_value(v.as_utf8()?);
+        }
+        Ok(())
+    }
+
+    fn append_decoded_null(&mut self) {
+        self.append_null()
+    }
+}
+
+impl DecodeTarget for BinaryViewBuilder {
+    type Physical = ByteArrayType;
+
+    fn append_decoded(&mut self, values: &[ByteArray]) -> Result<()> {
+        values.iter().for_each(|v| self.append_value(v.data()));
+        Ok(())
+    }
+
+    fn append_decoded_null(&mut self) {
+        self.append_null()
+    }
+}
+
+impl DecodeTarget for StringViewBuilder {
+    type Physical = ByteArrayType;
+
+    fn append_decoded(&mut self, values: &[ByteArray]) -> Result<()> {
+        for v in values {
+            self.append_value(v.as_utf8()?);
+        }
+        Ok(())
+    }
+
+    fn append_decoded_null(&mut self) {
+        self.append_null()
+    }
+}
+
+/// Decodes the values of a non-repeated column chunk directly into a caller-provided
+/// [`DecodeTarget`], without producing an intermediate array for each batch
+///
+/// This allows callers to reuse a single builder across batches, for example to
+/// aggregate the values of a column without materializing the full column
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+/// # use arrow_array::builder::Int32Builder;
+/// # use bytes::Bytes;
+/// # use parquet::arrow::ArrowWriter;
+/// # use parquet::arrow::arrow_reader::ColumnChunkDecoder;
+/// # use parquet::data_type::Int32Type;
+/// # use parquet::file::reader::{FileReader, RowGroupReader, SerializedFileReader};
+/// # let array: ArrayRef = Arc::new(Int32Array::from_iter(0..100));
+/// # let batch = RecordBatch::try_from_iter([("a", array)]).unwrap();
+/// # let mut buffer = vec![];
+/// # let mut writer = ArrowWriter::try_new(&mut buffer, batch.schema(), None).unwrap();
+/// # writer.write(&batch).unwrap();
+/// # writer.close().unwrap();
+/// let reader = SerializedFileReader::new(Bytes::from(buffer)).unwrap();
+/// let row_group = reader.get_row_group(0).unwrap();
+/// let descr = row_group.metadata().column(0).column_descr_ptr();
+/// let pages = row_group.get_column_page_reader(0).unwrap();
+///
+/// let mut decoder = ColumnChunkDecoder::<Int32Type>::try_new(descr, pages).unwrap();
+/// let mut builder = Int32Builder::new();
+/// let mut sum = 0;
+/// while decoder.decode(&mut builder, 32).unwrap() != 0 {
+///     // Take the decoded values, leaving the builder empty for the next batch
+///     let values = builder.finish();
+///     sum += values.values().iter().sum::<i32>();
+/// }
+/// assert_eq!(sum, 4950);
+/// ```
+pub struct ColumnChunkDecoder<T: DataType> {
+    reader: ColumnReaderImpl<T>,
+    max_def_level: i16,
+    def_levels: Vec<i16>,
+    values: Vec<T::T>,
+}
+
+impl<T: DataType> std::fmt::Debug for ColumnChunkDecoder<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ColumnChunkDecoder")
+            .field("max_def_level", &self.max_def_level)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: DataType> ColumnChunkDecoder<T> {
+    /// Create a new [`ColumnChunkDecoder`] reading the pages of the column described by
+    /// `descr` from `page_reader`
+    ///
+    /// Returns an error if the physical type of the column is not `T`, or the column is
+    /// repeated
+    pub fn try_new(descr: ColumnDescPtr, page_reader: Box<dyn PageReader>) -> Result<Self> {
+        if descr.physical_type() != T::get_physical_type() {
+            return Err(general_err!(
+                "Cannot decode column {} of type {} as {}",
+                descr.path(),
+                descr.physical_type(),
+                T::get_physical_type()
+            ));
+        }
+        if descr.max_rep_level() != 0 {
+            return Err(general_err!(
+                "Cannot decode repeated column {} into a builder",
+                descr.path()
+            ));
+        }
+        Ok(Self {
+            max_def_level: descr.max_def_level(),
+            reader: ColumnReaderImpl::new(descr, page_reader),
+            def_levels: vec![],
+            values: vec![],
+        })
+    }
+
+    /// Decodes up to `max_records` records, appending them to `builder`
+    ///
+    /// Values whose definition level is below the maximum are appended as nulls
+    ///
+    /// Returns the number of records appended, which is `0` once the column chunk
+    /// is exhausted
+    pub fn decode<B>(&mut self, builder: &mut B, max_records: usize) -> Result<usize>
+    where
+        B: DecodeTarget<Physical = T>,
+    {
+        self.def_levels.clear();
+        self.values.clear();
+        let def_levels = (self.max_def_level != 0).then_some(&mut self.def_levels);
+        let (records, values, _) =
+            self.reader
+                .read_records(max_records, def_levels, None, &mut self.values)?;
+
+        if values == records {
+            builder.append_decoded(&self.values)?;
+            return Ok(records);
+        }
+
+        let mut values = self.values.as_slice();
+        let mut levels = self.def_levels.as_slice();
+        while !levels.is_empty() {
+            let valid = levels
+                .iter()
+                .take_while(|l| **l == self.max_def_level)
+                .count();
+            let (head, tail) = values.split_at(valid);
+            builder.append_decoded(head)?;
+            values = tail;
+
+            let nulls = levels[valid..]
+                .iter()
+                .take_while(|l| **l != self.max_def_level)
+                .count();
+            (0..nulls).for_each(|_| builder.append_decoded_null());
+            levels = &levels[valid + nulls..];
+        }
+        Ok(records)
+    }
+
+    /// Skips up to `num_records` records, returning the number of records skipped
+    pub fn skip(&mut self, num_records: usize) -> Result<usize> {
+        self.reader.skip_records(num_records)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrow::ArrowWriter;
+    use crate::file::properties::WriterProperties;
+    use crate::file::reader::{FileReader, SerializedFileReader};
+    use arrow_array::builder::{Int32Builder, StringBuilder};
+    use arrow_array::{ArrayRef, Int32Array, RecordBatch, StringArray};
+    use bytes::Bytes;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_decode_into_builder() {
+        let ints = Int32Array::from_iter((0..200).map(|i| (i % 3 != 0).then_some(i)));
+        let strings = StringArray::from_iter_values((0..200).map(|i| format!("s{i}")));
+        let batch = RecordBatch::try_from_iter([
+            ("i", Arc::new(ints.clone()) as ArrayRef),
+            ("s", Arc::new(strings.clone()) as ArrayRef),
+        ])
+        .unwrap();
+
+        let props = WriterProperties::builder()
+            .set_data_page_row_count_limit(50)
+            .set_write_batch_size(10)
+            .build();
+        let mut buffer = vec![];
+        let mut writer = ArrowWriter::try_new(&mut buffer, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let reader = SerializedFileReader::new(Bytes::from(buffer)).unwrap();
+        let row_group = reader.get_row_group(0).unwrap();
+        let column = |i: usize| {
+            let descr = row_group.metadata().column(i).column_descr_ptr();
+            (descr, row_group.get_column_page_reader(i).unwrap())
+        };
+
+        let (descr, pages) = column(0);
+        let mut decoder = ColumnChunkDecoder::<Int32Type>::try_new(descr, pages).unwrap();
+        let mut builder = Int32Builder::new();
+        assert_eq!(decoder.skip(5).unwrap(), 5);
+        assert_eq!(decoder.decode(&mut builder, 60).unwrap(), 60);
+        assert_eq!(decoder.decode(&mut builder, 200).unwrap(), 135);
+        assert_eq!(decoder.decode(&mut builder, 200).unwrap(), 0);
+        assert_eq!(builder.finish(), ints.slice(5, 195));
+
+        let (descr, pages) = column(1);
+        let mut decoder = ColumnChunkDecoder::<ByteArrayType>::try_new(descr, pages).unwrap();
+        let mut builder = StringBuilder::new();
+        assert_eq!(decoder.decode(&mut builder, 300).unwrap(), 200);
+        assert_eq!(builder.finish(), strings);
+
+        let (descr, pages) = column(1);
+        let err = ColumnChunkDecoder::<Int32Type>::try_new(descr, pages).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Cannot decode column \"s\" of type BYTE_ARRAY as INT32"
+        );
+    }
+}
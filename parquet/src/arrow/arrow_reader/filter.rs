@@ -16,8 +16,12 @@
 // under the License.
 
 use crate::arrow::ProjectionMask;
-use arrow_array::{BooleanArray, RecordBatch};
+use crate::arrow::arrow_reader::pruning::PruningPredicate;
+use arrow_array::cast::AsArray;
+use arrow_array::{Array, ArrayRef, BooleanArray, RecordBatch};
+use arrow_data::ArrayData;
 use arrow_schema::ArrowError;
+use arrow_select::take::take;
 use std::fmt::{Debug, Formatter};
 
 /// A predicate operating on [`RecordBatch`]
@@ -44,6 +48,32 @@ pub trait ArrowPredicate: Send + 'static {
     /// * `true`:the row should be returned
     /// * `false` or `null`: the row should not be returned
     fn evaluate(&mut self, batch: RecordBatch) -> Result<BooleanArray, ArrowError>;
+
+    /// Returns [`PruningPredicate`]s implied by this predicate, used to skip row
+    /// groups and pages based on their statistics before any data is decoded
+    ///
+    /// For example, a predicate evaluating `starts_with(name, 'foo')` can return
+    /// [`PruningPredicate::starts_with`] to advertise its prefix semantics.
+    ///
+    /// The returned predicates must not skip any rows for which
+    /// [`evaluate`](Self::evaluate) would return `true`. Defaults to none.
+    fn pruning_predicates(&self) -> &[PruningPredicate] {
+        &[]
+    }
+
+    /// Returns `true` if the string and binary columns in [`Self::projection`]
+    /// should be passed to [`evaluate`](Self::evaluate) as [`DictionaryArray`]s
+    ///
+    /// This preserves the dictionary encoding of the parquet data, so that the
+    /// predicate can be evaluated once for each dictionary value, instead of once
+    /// for each row. The columns are passed as `Dictionary(Int32, _)`, where
+    /// `Utf8View` and `BinaryView` columns are passed as dictionaries of `Utf8`
+    /// and `Binary`. See [`DictionaryPredicateFn`]. Defaults to `false`.
+    ///
+    /// [`DictionaryArray`]: arrow_array::DictionaryArray
+    fn preserve_dictionary(&self) -> bool {
+        false
+    }
 }
 
 /// An [`ArrowPredicate`] created from an [`FnMut`] and a [`ProjectionMask`]
@@ -106,6 +136,7 @@ pub trait ArrowPredicate: Send + 'static {
 pub struct ArrowPredicateFn<F> {
     f: F,
     projection: ProjectionMask,
+    pruning_predicates: Vec<PruningPredicate>,
 }
 
 impl<F> ArrowPredicateFn<F>
@@ -115,7 +146,18 @@ where
     /// Create a new [`ArrowPredicateFn`] that invokes `f` on the columns
     /// specified in `projection`.
     pub fn new(projection: ProjectionMask, f: F) -> Self {
-        Self { f, projection }
+        Self {
+            f,
+            projection,
+            pruning_predicates: vec![],
+        }
+    }
+
+    /// Add a [`PruningPredicate`] implied by `f`, see
+    /// [`ArrowPredicate::pruning_predicates`]
+    pub fn with_pruning_predicate(mut self, predicate: PruningPredicate) -> Self {
+        self.pruning_predicates.push(predicate);
+        self
     }
 }
 
@@ -130,6 +172,152 @@ where
     fn evaluate(&mut self, batch: RecordBatch) -> Result<BooleanArray, ArrowError> {
         (self.f)(batch)
     }
+
+    fn pruning_predicates(&self) -> &[PruningPredicate] {
+        &self.pruning_predicates
+    }
+}
+
+/// An [`ArrowPredicate`] on a single string or binary column, evaluated once
+/// for each value of the parquet dictionary
+///
+/// The column is decoded as a [`DictionaryArray`], see
+/// [`ArrowPredicate::preserve_dictionary`], and the function is called with the
+/// dictionary values. The results are then mapped to the rows using the
+/// dictionary keys. As all the batches decoded from a column chunk share the
+/// same dictionary, the function is only called once for each column chunk.
+/// Otherwise, for example if the column chunk is not dictionary encoded or a
+/// batch spans multiple row groups, the function is called with the values of
+/// each batch.
+///
+/// This is therefore suited to predicates such as `=`, `IN` or `LIKE` on
+/// columns with few distinct values. The function must be evaluated for each
+/// value independently, and return a [`BooleanArray`] with the same length as
+/// its input, where each value indicates whether the rows with this value should
+/// be returned:
+/// * `true`: the row should be returned
+/// * `false` or `null`: the row should not be returned
+///
+/// # Example:
+///
+/// Given an input schema: `"name:utf8"`, you can create a predicate that
+/// evaluates `name LIKE 'foo%'` like this:
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow::compute::kernels::comparison::like;
+/// # use arrow_array::{ArrayRef, StringArray};
+/// # use parquet::arrow::arrow_reader::DictionaryPredicateFn;
+/// # use parquet::arrow::ProjectionMask;
+/// # use parquet::schema::types::{SchemaDescriptor, Type};
+/// # use parquet::basic;
+/// # let descriptor = SchemaDescriptor::new(
+/// #  Arc::new(
+/// #    Type::group_type_builder("my_schema")
+/// #      .with_fields(vec![
+/// #        Arc::new(
+/// #         Type::primitive_type_builder("name", basic::Type::BYTE_ARRAY)
+/// #          .with_logical_type(Some(basic::LogicalType::String))
+/// #          .build().unwrap()
+/// #        ),
+/// #     ])
+/// #     .build().unwrap()
+/// #  )
+/// # );
+/// let projection_mask = ProjectionMask::leaves(&descriptor, [0]);
+/// let pattern = StringArray::new_scalar("foo%");
+/// // `values` are the distinct values of the dictionary
+/// let predicate = DictionaryPredicateFn::new(projection_mask, move |values: &ArrayRef| {
+///     like(values, &pattern)
+/// });
+/// ```
+///
+/// [`DictionaryArray`]: arrow_array::DictionaryArray
+pub struct DictionaryPredicateFn<F> {
+    f: F,
+    projection: ProjectionMask,
+    pruning_predicates: Vec<PruningPredicate>,
+    /// The last dictionary values, and the result of `f` for them
+    dictionary: Option<(ArrayData, BooleanArray)>,
+}
+
+impl<F> DictionaryPredicateFn<F>
+where
+    F: FnMut(&ArrayRef) -> Result<BooleanArray, ArrowError> + Send + 'static,
+{
+    /// Create a new [`DictionaryPredicateFn`] that invokes `f` on the values of
+    /// the single column specified in `projection`
+    pub fn new(projection: ProjectionMask, f: F) -> Self {
+        Self {
+            f,
+            projection,
+            pruning_predicates: vec![],
+            dictionary: None,
+        }
+    }
+
+    /// Add a [`PruningPredicate`] implied by `f`, see
+    /// [`ArrowPredicate::pruning_predicates`]
+    pub fn with_pruning_predicate(mut self, predicate: PruningPredicate) -> Self {
+        self.pruning_predicates.push(predicate);
+        self
+    }
+
+    /// Calls `f` with `values`, checking the length of the result
+    fn evaluate_values(&mut self, values: &ArrayRef) -> Result<BooleanArray, ArrowError> {
+        let result = (self.f)(values)?;
+        if result.len() != values.len() {
+            return Err(ArrowError::ComputeError(format!(
+                "DictionaryPredicateFn returned {} values, expected {}",
+                result.len(),
+                values.len()
+            )));
+        }
+        Ok(result)
+    }
+}
+
+impl<F> ArrowPredicate for DictionaryPredicateFn<F>
+where
+    F: FnMut(&ArrayRef) -> Result<BooleanArray, ArrowError> + Send + 'static,
+{
+    fn projection(&self) -> &ProjectionMask {
+        &self.projection
+    }
+
+    fn evaluate(&mut self, batch: RecordBatch) -> Result<BooleanArray, ArrowError> {
+        if batch.num_columns() != 1 {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "DictionaryPredicateFn expects a single column, got {}",
+                batch.num_columns()
+            )));
+        }
+        let column = batch.column(0);
+        let Some(dictionary) = column.as_any_dictionary_opt() else {
+            return self.evaluate_values(column);
+        };
+
+        let values = dictionary.values();
+        let data = values.to_data();
+        let matches = match &self.dictionary {
+            Some((cached, matches)) if cached.ptr_eq(&data) => matches.clone(),
+            _ => {
+                let matches = self.evaluate_values(values)?;
+                self.dictionary = Some((data, matches.clone()));
+                matches
+            }
+        };
+        let result = take(&matches, dictionary.keys(), None)?;
+        Ok(result.as_boolean().clone())
+    }
+
+    fn pruning_predicates(&self) -> &[PruningPredicate] {
+        &self.pruning_predicates
+    }
+
+    fn preserve_dictionary(&self) -> bool {
+        true
+    }
 }
 
 /// Filter applied *during* the parquet read process
@@ -17,8 +17,21 @@
 
 //! [ArrowReaderMetrics] for collecting metrics about the Arrow reader
 
-use std::sync::Arc;
-use std::sync::atomic::AtomicUsize;
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::io::Read;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use arrow_array::ArrayRef;
+use arrow_schema::DataType as ArrowType;
+use bytes::Bytes;
+
+use crate::arrow::array_reader::ArrayReader;
+use crate::column::page::{Page, PageIterator, PageMetadata, PageReader};
+use crate::errors::Result;
+use crate::file::reader::{ChunkReader, Length};
 
 /// This enum represents the state of Arrow reader metrics collection.
 ///
@@ -44,8 +57,21 @@ impl ArrowReaderMetrics {
     }
 
     /// Creates a new instance of [`ArrowReaderMetrics::Enabled`]
+    ///
+    /// This does not record [`ColumnReaderMetrics::decode_time`], see
+    /// [`Self::enabled_with_decode_timing`]
     pub fn enabled() -> Self {
-        Self::Enabled(Arc::new(ArrowReaderMetricsInner::new()))
+        Self::Enabled(Arc::new(ArrowReaderMetricsInner::new(false)))
+    }
+
+    /// Creates a new instance of [`ArrowReaderMetrics::Enabled`] that also records
+    /// [`ColumnReaderMetrics::decode_time`]
+    ///
+    /// Timing each batch decoded adds overhead, and is not available on `wasm32`
+    /// targets as [`std::time::Instant`] is not supported on `wasm32-unknown-unknown`
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn enabled_with_decode_timing() -> Self {
+        Self::Enabled(Arc::new(ArrowReaderMetricsInner::new(true)))
     }
 
     /// Predicate Cache: number of records read directly from the inner reader
@@ -90,6 +116,81 @@ impl ArrowReaderMetrics {
         }
     }
 
+    /// Returns the [`ColumnReaderMetrics`] for the leaf column with index
+    /// `leaf_idx` in the parquet schema
+    ///
+    /// Returns None if metrics are disabled. Columns which were not read have
+    /// all metrics set to zero.
+    pub fn column_metrics(&self, leaf_idx: usize) -> Option<ColumnReaderMetrics> {
+        match self {
+            Self::Disabled => None,
+            Self::Enabled(inner) => {
+                let columns = inner.columns.lock().unwrap();
+                let counters = columns.get(&leaf_idx);
+                Some(counters.map(|c| c.load()).unwrap_or_default())
+            }
+        }
+    }
+
+    /// Page Index: number of rows skipped using the statistics in the page index
+    ///
+    /// These are the rows of the selected row groups which were skipped based on
+    /// [`ArrowPredicate::pruning_predicates`], before any data was decoded.
+    ///
+    /// Returns None if metrics are disabled.
+    ///
+    /// [`ArrowPredicate::pruning_predicates`]: crate::arrow::arrow_reader::ArrowPredicate::pruning_predicates
+    pub fn rows_pruned_by_page_index(&self) -> Option<usize> {
+        match self {
+            Self::Disabled => None,
+            Self::Enabled(inner) => Some(
+                inner
+                    .rows_pruned_by_page_index
+                    .load(std::sync::atomic::Ordering::Relaxed),
+            ),
+        }
+    }
+
+    /// Returns `true` if metrics are collected
+    pub(crate) fn is_enabled(&self) -> bool {
+        matches!(self, Self::Enabled(_))
+    }
+
+    /// Returns `true` if [`ColumnReaderMetrics::decode_time`] is recorded
+    #[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+    pub(crate) fn decode_timing_enabled(&self) -> bool {
+        matches!(self, Self::Enabled(inner) if inner.decode_timing)
+    }
+
+    /// Returns the [`ColumnCounters`] of the leaf column `leaf_idx`, if metrics are enabled
+    ///
+    /// The counters are allocated on first use, so that they can then be updated
+    /// without synchronizing with the readers of other columns
+    pub(crate) fn column_counters(&self, leaf_idx: usize) -> Option<Arc<ColumnCounters>> {
+        let Self::Enabled(inner) = self else {
+            return None;
+        };
+        let mut columns = inner.columns.lock().unwrap();
+        Some(Arc::clone(columns.entry(leaf_idx).or_default()))
+    }
+
+    /// Increments the bytes fetched from storage for the leaf column `leaf_idx`
+    pub(crate) fn increment_column_bytes_read(&self, leaf_idx: usize, bytes: u64) {
+        if let Some(counters) = self.column_counters(leaf_idx) {
+            counters.bytes_read.fetch_add(bytes, Ordering::Relaxed);
+        }
+    }
+
+    /// Increments the count of rows skipped using the page index
+    pub(crate) fn increment_rows_pruned_by_page_index(&self, count: usize) {
+        let Self::Enabled(inner) = self else {
+            return;
+        };
+        inner
+            .rows_pruned_by_page_index
+            .fetch_add(count, std::sync::atomic::Ordering::Relaxed);
+    }
+
     /// Increments the count of records read from the inner reader
     pub(crate) fn increment_inner_reads(&self, count: usize) {
         let Self::Enabled(inner) = self else {
@@ -122,14 +223,237 @@ pub struct ArrowReaderMetricsInner {
     records_read_from_inner: AtomicUsize,
     /// Total number of records read from previously cached pages
     records_read_from_cache: AtomicUsize,
+    /// Metrics for each leaf column read, keyed by leaf column index
+    columns: Mutex<BTreeMap<usize, Arc<ColumnCounters>>>,
+    /// Total number of rows skipped using the page index
+    rows_pruned_by_page_index: AtomicUsize,
+    /// Whether to record the time spent decoding each column
+    decode_timing: bool,
 }
 
 impl ArrowReaderMetricsInner {
     /// Creates a new instance of `ArrowReaderMetricsInner`
-    pub(crate) fn new() -> Self {
+    pub(crate) fn new(decode_timing: bool) -> Self {
         Self {
             records_read_from_inner: AtomicUsize::new(0),
             records_read_from_cache: AtomicUsize::new(0),
+            columns: Mutex::new(BTreeMap::new()),
+            rows_pruned_by_page_index: AtomicUsize::new(0),
+            decode_timing,
+        }
+    }
+}
+
+/// The counters of [`ColumnReaderMetrics`] for a single leaf column
+#[derive(Debug, Default)]
+pub(crate) struct ColumnCounters {
+    bytes_read: AtomicU64,
+    bytes_decompressed: AtomicU64,
+    pages_decoded: AtomicU64,
+    decode_nanos: AtomicU64,
+}
+
+impl ColumnCounters {
+    fn load(&self) -> ColumnReaderMetrics {
+        ColumnReaderMetrics {
+            bytes_read: self.bytes_read.load(Ordering::Relaxed),
+            bytes_decompressed: self.bytes_decompressed.load(Ordering::Relaxed),
+            pages_decoded: self.pages_decoded.load(Ordering::Relaxed),
+            decode_time: Duration::from_nanos(self.decode_nanos.load(Ordering::Relaxed)),
+        }
+    }
+}
+
+/// Metrics for reading a single leaf column, see [`ArrowReaderMetrics::column_metrics`]
+///
+/// Columns read for both a [`RowFilter`] and the output projection are counted
+/// twice, unless they are read from the predicate cache.
+///
+/// [`RowFilter`]: crate::arrow::arrow_reader::RowFilter
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ColumnReaderMetrics {
+    /// Number of bytes of page headers and compressed page data read
+    pub bytes_read: u64,
+    /// Number of bytes of page data after decompression
+    pub bytes_decompressed: u64,
+    /// Number of pages decoded, including dictionary pages
+    pub pages_decoded: u64,
+    /// Time spent reading, decompressing and decoding pages
+    ///
+    /// This is only recorded for [`ArrowReaderMetrics::enabled_with_decode_timing`]
+    pub decode_time: Duration,
+}
+
+/// A [`ChunkReader`] that records the bytes read for a column in [`ArrowReaderMetrics`]
+pub(crate) struct MetricsChunkReader<R> {
+    inner: Arc<R>,
+    counters: Arc<ColumnCounters>,
+}
+
+impl<R> MetricsChunkReader<R> {
+    pub(crate) fn new(inner: Arc<R>, counters: Arc<ColumnCounters>) -> Self {
+        Self { inner, counters }
+    }
+}
+
+impl<R: Length> Length for MetricsChunkReader<R> {
+    fn len(&self) -> u64 {
+        self.inner.len()
+    }
+}
+
+impl<R: ChunkReader> ChunkReader for MetricsChunkReader<R> {
+    type T = MetricsRead<R::T>;
+
+    fn get_read(&self, start: u64) -> Result<Self::T> {
+        Ok(MetricsRead {
+            inner: self.inner.get_read(start)?,
+            counters: Arc::clone(&self.counters),
+        })
+    }
+
+    fn get_bytes(&self, start: u64, length: usize) -> Result<Bytes> {
+        let bytes = self.inner.get_bytes(start, length)?;
+        let len = bytes.len() as u64;
+        self.counters.bytes_read.fetch_add(len, Ordering::Relaxed);
+        Ok(bytes)
+    }
+}
+
+/// The [`Read`] of a [`MetricsChunkReader`]
+pub(crate) struct MetricsRead<R> {
+    inner: R,
+    counters: Arc<ColumnCounters>,
+}
+
+impl<R: Read> Read for MetricsRead<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        let len = read as u64;
+        self.counters.bytes_read.fetch_add(len, Ordering::Relaxed);
+        Ok(read)
+    }
+}
+
+/// A [`PageIterator`] that records the pages decoded for a column in [`ArrowReaderMetrics`]
+pub(crate) struct MetricsPageIterator {
+    inner: Box<dyn PageIterator>,
+    counters: Arc<ColumnCounters>,
+}
+
+impl MetricsPageIterator {
+    pub(crate) fn new(inner: Box<dyn PageIterator>, counters: Arc<ColumnCounters>) -> Self {
+        Self { inner, counters }
+    }
+}
+
+impl Iterator for MetricsPageIterator {
+    type Item = Result<Box<dyn PageReader>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let reader = self.inner.next()?;
+        Some(reader.map(|inner| {
+            Box::new(MetricsPageReader {
+                inner,
+                counters: Arc::clone(&self.counters),
+            }) as _
+        }))
+    }
+}
+
+impl PageIterator for MetricsPageIterator {}
+
+/// The [`PageReader`] of a [`MetricsPageIterator`]
+struct MetricsPageReader {
+    inner: Box<dyn PageReader>,
+    counters: Arc<ColumnCounters>,
+}
+
+impl Iterator for MetricsPageReader {
+    type Item = Result<Page>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.get_next_page().transpose()
+    }
+}
+
+impl PageReader for MetricsPageReader {
+    fn get_next_page(&mut self) -> Result<Option<Page>> {
+        let page = self.inner.get_next_page()?;
+        if let Some(page) = &page {
+            let len = page.buffer().len() as u64;
+            self.counters
+                .bytes_decompressed
+                .fetch_add(len, Ordering::Relaxed);
+            self.counters.pages_decoded.fetch_add(1, Ordering::Relaxed);
         }
+        Ok(page)
+    }
+
+    fn peek_next_page(&mut self) -> Result<Option<PageMetadata>> {
+        self.inner.peek_next_page()
+    }
+
+    fn skip_next_page(&mut self) -> Result<()> {
+        self.inner.skip_next_page()
+    }
+
+    fn at_record_boundary(&mut self) -> Result<bool> {
+        self.inner.at_record_boundary()
+    }
+}
+
+/// An [`ArrayReader`] that records the time spent decoding a column in [`ArrowReaderMetrics`]
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) struct MetricsArrayReader {
+    inner: Box<dyn ArrayReader>,
+    counters: Arc<ColumnCounters>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl MetricsArrayReader {
+    pub(crate) fn new(inner: Box<dyn ArrayReader>, counters: Arc<ColumnCounters>) -> Self {
+        Self { inner, counters }
+    }
+
+    fn timed<T>(&mut self, f: impl FnOnce(&mut dyn ArrayReader) -> T) -> T {
+        let start = std::time::Instant::now();
+        let result = f(self.inner.as_mut());
+        let elapsed = start.elapsed().as_nanos().try_into().unwrap_or(u64::MAX);
+        self.counters
+            .decode_nanos
+            .fetch_add(elapsed, Ordering::Relaxed);
+        result
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl ArrayReader for MetricsArrayReader {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_data_type(&self) -> &ArrowType {
+        self.inner.get_data_type()
+    }
+
+    fn read_records(&mut self, batch_size: usize) -> Result<usize> {
+        self.timed(|r| r.read_records(batch_size))
+    }
+
+    fn consume_batch(&mut self) -> Result<ArrayRef> {
+        self.timed(|r| r.consume_batch())
+    }
+
+    fn skip_records(&mut self, num_records: usize) -> Result<usize> {
+        self.timed(|r| r.skip_records(num_records))
+    }
+
+    fn get_def_levels(&self) -> Option<&[i16]> {
+        self.inner.get_def_levels()
+    }
+
+    fn get_rep_levels(&self) -> Option<&[i16]> {
+        self.inner.get_rep_levels()
     }
 }
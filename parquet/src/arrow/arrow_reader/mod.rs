@@ -21,18 +21,23 @@ use arrow_array::cast::AsArray;
 use arrow_array::{Array, RecordBatch, RecordBatchReader};
 use arrow_schema::{ArrowError, DataType as ArrowType, FieldRef, Schema, SchemaRef};
 use arrow_select::filter::filter_record_batch;
-pub use filter::{ArrowPredicate, ArrowPredicateFn, RowFilter};
+pub use column_decoder::{ColumnChunkDecoder, DecodeTarget};
+pub use filter::{ArrowPredicate, ArrowPredicateFn, DictionaryPredicateFn, RowFilter};
 pub use selection::{RowSelection, RowSelectionCursor, RowSelectionPolicy, RowSelector};
 use std::fmt::{Debug, Formatter};
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
 
 pub use crate::arrow::array_reader::RowGroups;
-use crate::arrow::array_reader::{ArrayReader, ArrayReaderBuilder};
+use crate::arrow::array_reader::{
+    ArrayReader, ArrayReaderBuilder, CacheOptionsBuilder, RowGroupCache,
+};
 use crate::arrow::schema::{
     ParquetField, parquet_to_arrow_schema_and_fields, virtual_type::is_virtual_column,
 };
 use crate::arrow::{FieldLevels, ProjectionMask, parquet_to_arrow_field_levels_with_virtual};
-use crate::basic::{BloomFilterAlgorithm, BloomFilterCompression, BloomFilterHash};
+use crate::basic::{
+    BloomFilterAlgorithm, BloomFilterCompression, BloomFilterHash, Type as PhysicalType,
+};
 use crate::bloom_filter::{
     SBBF_HEADER_SIZE_ESTIMATE, Sbbf, chunk_read_bloom_filter_header_and_offset,
 };
@@ -41,18 +46,21 @@ use crate::column::page::{PageIterator, PageReader};
 use crate::encryption::decrypt::FileDecryptionProperties;
 use crate::errors::{ParquetError, Result};
 use crate::file::metadata::{
-    PageIndexPolicy, ParquetMetaData, ParquetMetaDataOptions, ParquetMetaDataReader,
-    ParquetStatisticsPolicy, RowGroupMetaData,
+    ColumnChunkMetaData, PageIndexPolicy, ParquetMetaData, ParquetMetaDataOptions,
+    ParquetMetaDataReader, ParquetStatisticsPolicy, RowGroupMetaData,
 };
 use crate::file::reader::{ChunkReader, SerializedPageReader};
-use crate::schema::types::SchemaDescriptor;
+use crate::schema::types::{ColumnPath, SchemaDescriptor};
 
-use crate::arrow::arrow_reader::metrics::ArrowReaderMetrics;
+use crate::arrow::arrow_reader::metrics::{ArrowReaderMetrics, MetricsChunkReader};
+use crate::arrow::arrow_reader::pruning::{PruningPredicate, StatisticsPruner};
 // Exposed so integration tests and benchmarks can temporarily override the threshold.
 pub use read_plan::{PredicateOptions, ReadPlan, ReadPlanBuilder};
 
+mod column_decoder;
 mod filter;
 pub mod metrics;
+pub mod pruning;
 mod read_plan;
 pub(crate) mod selection;
 pub mod statistics;
@@ -200,6 +208,76 @@ impl<T> ArrowReaderBuilder<T> {
         &self.schema
     }
 
+    /// Returns the index of the leaf column with the given `path` in the parquet schema
+    pub(crate) fn leaf_column_index(&self, path: &ColumnPath) -> Result<usize> {
+        self.parquet_schema()
+            .columns()
+            .iter()
+            .position(|c| c.path() == path)
+            .ok_or_else(|| {
+                general_err!(
+                    "Leaf column '{}' not found in parquet schema",
+                    path.string()
+                )
+            })
+    }
+
+    /// Returns the estimated size in bytes of a decoded row of the selected row groups
+    /// and projected columns, based on the size statistics of the column chunks
+    ///
+    /// This can be used to choose a batch size before decoding any data, for example to
+    /// bound the memory used by each [`RecordBatch`]:
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use bytes::Bytes;
+    /// # use arrow_array::{ArrayRef, Int64Array, RecordBatch};
+    /// # use parquet::arrow::ArrowWriter;
+    /// # use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    /// # let col = Arc::new(Int64Array::from_iter_values(0..100)) as ArrayRef;
+    /// # let batch = RecordBatch::try_from_iter([("a", col)]).unwrap();
+    /// # let mut file = Vec::new();
+    /// # let mut writer = ArrowWriter::try_new(&mut file, batch.schema(), None).unwrap();
+    /// # writer.write(&batch).unwrap();
+    /// # writer.close().unwrap();
+    /// # let file = Bytes::from(file);
+    /// let builder = ParquetRecordBatchReaderBuilder::try_new(file).unwrap();
+    /// let row_size = builder.estimated_row_size().unwrap_or(1);
+    /// assert_eq!(row_size, 8);
+    ///
+    /// // Target batches of about 1MB
+    /// let batch_size = (1024 * 1024 / row_size.max(1)).clamp(1, 8192);
+    /// let reader = builder.with_batch_size(batch_size).build().unwrap();
+    /// ```
+    ///
+    /// The estimate accounts for the values, offsets and validity of each leaf column, but
+    /// not for the overhead of nested types. Returns `None` if the selected row groups have
+    /// no rows, or if the size of a projected `BYTE_ARRAY` column is unknown because it was
+    /// written without size statistics
+    pub fn estimated_row_size(&self) -> Option<usize> {
+        let row_groups = self.metadata.row_groups();
+        let selected: Vec<usize> = match &self.row_groups {
+            Some(row_groups) => row_groups.clone(),
+            None => (0..row_groups.len()).collect(),
+        };
+
+        let mut num_rows = 0;
+        let mut total = 0;
+        for idx in selected {
+            let row_group = row_groups.get(idx)?;
+            num_rows += row_group.num_rows() as u64;
+            for (leaf, column) in row_group.columns().iter().enumerate() {
+                if self.projection.leaf_included(leaf) {
+                    total += estimated_decoded_size(column)?;
+                }
+            }
+        }
+        match num_rows {
+            0 => None,
+            _ => Some(total.div_ceil(num_rows) as usize),
+        }
+    }
+
     /// Set the size of [`RecordBatch`] to produce. Defaults to [`DEFAULT_BATCH_SIZE`]
     /// If the batch_size more than the file row count, use the file row count.
     pub fn with_batch_size(self, batch_size: usize) -> Self {
@@ -302,9 +380,101 @@ impl<T> ArrowReaderBuilder<T> {
         }
     }
 
+    /// Skip row groups and data pages that can not contain rows matching all of
+    /// `predicates`, based on their statistics
+    ///
+    /// Only the row groups configured with [`Self::with_row_groups`], or all row groups
+    /// if none were, are evaluated. Data pages are only pruned if the page index was
+    /// loaded, see [`ArrowReaderOptions::with_page_index_policy`].
+    ///
+    /// This must be called before [`Self::with_row_selection`], and returns an error
+    /// otherwise. Rows which are not skipped may still not match the predicates,
+    /// see [`pruning`] for more details.
+    pub fn with_pruning_predicates(
+        self,
+        predicates: impl IntoIterator<Item = PruningPredicate>,
+    ) -> Result<Self> {
+        if self.selection.is_some() {
+            return Err(general_err!(
+                "with_pruning_predicates must be called before with_row_selection"
+            ));
+        }
+        let row_groups = match &self.row_groups {
+            Some(row_groups) => row_groups.clone(),
+            None => (0..self.metadata.num_row_groups()).collect(),
+        };
+
+        let (metadata, schema) = (Arc::clone(&self.metadata), Arc::clone(&self.schema));
+        let pruner = StatisticsPruner::new(&metadata, &schema).with_predicates(predicates);
+        let row_groups = pruner.prune_row_groups(&row_groups)?;
+        let selection = pruner.prune_pages(&row_groups)?;
+        Ok(Self {
+            row_groups: Some(row_groups),
+            selection,
+            ..self
+        })
+    }
+
+    /// Skips row groups and pages using the [`ArrowPredicate::pruning_predicates`]
+    /// of the [`RowFilter`], if any
+    ///
+    /// Any existing row selection is restricted to the remaining row groups
+    pub(crate) fn prune_with_row_filter(self) -> Result<Self> {
+        let predicates: Vec<_> = self
+            .filter
+            .iter()
+            .flat_map(|filter| &filter.predicates)
+            .flat_map(|predicate| predicate.pruning_predicates().iter().cloned())
+            .collect();
+        if predicates.is_empty() {
+            return Ok(self);
+        }
+
+        let row_groups = match &self.row_groups {
+            Some(row_groups) => row_groups.clone(),
+            None => (0..self.metadata.num_row_groups()).collect(),
+        };
+        let pruner =
+            StatisticsPruner::new(&self.metadata, &self.schema).with_predicates(predicates);
+        let kept = pruner.prune_row_groups(&row_groups)?;
+        let page_selection = pruner.prune_pages(&kept)?;
+        if let Some(page_selection) = &page_selection {
+            self.metrics
+                .increment_rows_pruned_by_page_index(page_selection.skipped_row_count());
+        }
+
+        // drop the parts of the selection for the skipped row groups
+        let selection = self.selection.as_ref().map(|selection| {
+            let mut remaining = selection.clone();
+            let mut kept_iter = kept.iter().peekable();
+            let mut selectors = vec![];
+            for row_group in &row_groups {
+                let num_rows = self.metadata.row_group(*row_group).num_rows() as usize;
+                let part = remaining.split_off(num_rows);
+                if kept_iter.next_if_eq(&row_group).is_some() {
+                    selectors.extend(part.iter().copied());
+                }
+            }
+            RowSelection::from(selectors)
+        });
+        let selection = match (selection, page_selection) {
+            (Some(a), Some(b)) => Some(a.intersection(&b)),
+            (a, b) => a.or(b),
+        };
+
+        Ok(Self {
+            row_groups: Some(kept),
+            selection,
+            ..self
+        })
+    }
+
     /// Provide a [`RowFilter`] to skip decoding rows
     ///
-    /// Row filters are applied after row group selection and row selection
+    /// Row filters are applied after row group selection and row selection.
+    /// Row groups and pages are first skipped using the statistics of any
+    /// [`ArrowPredicate::pruning_predicates`], which requires the page index to
+    /// skip pages.
     ///
     /// It is recommended to enable reading the page index if using this functionality, to allow
     /// more efficient skipping over data pages. See [`ArrowReaderOptions::with_page_index`].
@@ -414,20 +584,24 @@ impl<T> ArrowReaderBuilder<T> {
         Self { metrics, ..self }
     }
 
-    /// Set the maximum size (per row group) of the predicate cache in bytes for
-    /// the async decoder.
+    /// Set the maximum size of the predicate cache in bytes
     ///
-    /// Defaults to 100MB (across all columns). Set to `usize::MAX` to use
-    /// unlimited cache size.
+    /// Defaults to 100MB (across all columns) for the async decoder, and to `0`,
+    /// disabling the cache, for the sync reader. Set to `usize::MAX` to use
+    /// unlimited cache size, or `0` to disable the cache.
     ///
     /// This cache is used to store decoded arrays that are used in
-    /// predicate evaluation ([`Self::with_row_filter`]).
+    /// predicate evaluation ([`Self::with_row_filter`]), so that columns in
+    /// both the predicates and the output projection are not decoded twice.
+    /// Arrays that do not fit within the limit are decoded again.
     ///
-    /// This cache is only used for the "async" decoder, [`ParquetRecordBatchStream`]. See
-    /// [this ticket] for more details and alternatives.
+    /// For the async decoder, [`ParquetRecordBatchStream`], the limit applies
+    /// to each row group. As [`ParquetRecordBatchReaderBuilder::build`]
+    /// evaluates the predicates for all row groups up front, for the sync
+    /// reader the limit applies to the whole read, and so the cache must be
+    /// explicitly enabled by setting a limit.
     ///
     /// [`ParquetRecordBatchStream`]: https://docs.rs/parquet/latest/parquet/arrow/async_reader/struct.ParquetRecordBatchStream.html
-    /// [this ticket]: https://github.com/apache/arrow-rs/issues/8000
     pub fn with_max_predicate_cache_size(self, max_predicate_cache_size: usize) -> Self {
         Self {
             max_predicate_cache_size,
@@ -470,6 +644,10 @@ pub struct ArrowReaderOptions {
     pub(crate) file_decryption_properties: Option<Arc<FileDecryptionProperties>>,
 
     virtual_columns: Vec<FieldRef>,
+
+    /// Should shredded Variant columns be read as unshredded Variants
+    #[cfg(feature = "variant_experimental")]
+    unshred_variants: bool,
 }
 
 impl ArrowReaderOptions {
@@ -792,6 +970,27 @@ impl ArrowReaderOptions {
         })
     }
 
+    /// Read shredded Variant columns as unshredded Variants (defaults to `false`)
+    ///
+    /// Columns annotated with the Parquet `VARIANT` logical type are read as
+    /// structs with the [`VariantType`] extension type. By default, shredded
+    /// Variants are returned as written, with their `typed_value` columns. If
+    /// this is enabled, the `typed_value` columns are folded back into `value`,
+    /// so that every Variant column has the same `metadata` and `value` layout
+    /// regardless of how it was shredded.
+    ///
+    /// Variants nested within lists or maps are not unshredded. If a schema is
+    /// supplied with [`Self::with_schema`], it must describe the shredded layout.
+    ///
+    /// [`VariantType`]: parquet_variant_compute::VariantType
+    #[cfg(feature = "variant_experimental")]
+    pub fn with_unshred_variants(self, unshred_variants: bool) -> Self {
+        Self {
+            unshred_variants,
+            ..self
+        }
+    }
+
     #[deprecated(
         since = "57.2.0",
         note = "Use `column_index_policy` or `offset_index_policy` instead"
@@ -894,7 +1093,7 @@ impl ArrowReaderMetadata {
     /// This function will not attempt to load the PageIndex if not present in the metadata, regardless
     /// of the settings in `options`. See [`Self::load`] to load metadata including the page index if needed.
     pub fn try_new(metadata: Arc<ParquetMetaData>, options: ArrowReaderOptions) -> Result<Self> {
-        match options.supplied_schema {
+        let reader_metadata = match options.supplied_schema {
             Some(supplied_schema) => Self::with_supplied_schema(
                 metadata,
                 supplied_schema.clone(),
@@ -919,7 +1118,13 @@ impl ArrowReaderMetadata {
                     fields: fields.map(Arc::new),
                 })
             }
+        }?;
+
+        #[cfg(feature = "variant_experimental")]
+        if options.unshred_variants {
+            return Ok(reader_metadata.with_unshredded_variants());
         }
+        Ok(reader_metadata)
     }
 
     fn with_supplied_schema(
@@ -992,6 +1197,31 @@ impl ArrowReaderMetadata {
         })
     }
 
+    /// Returns a copy of `self` reading the shredded Variant columns as
+    /// unshredded Variants, see [`ArrowReaderOptions::with_unshred_variants`]
+    #[cfg(feature = "variant_experimental")]
+    fn with_unshredded_variants(self) -> Self {
+        let Some(fields) = self.fields.as_deref() else {
+            return self;
+        };
+        let fields = fields.with_unshredded_variants();
+        let ArrowType::Struct(root) = &fields.arrow_type else {
+            unreachable!("root field must be a struct");
+        };
+        // The schema may omit trailing virtual columns
+        let schema_fields: Vec<_> = root
+            .iter()
+            .take(self.schema.fields().len())
+            .cloned()
+            .collect();
+        let schema = Schema::new_with_metadata(schema_fields, self.schema.metadata().clone());
+        Self {
+            metadata: self.metadata,
+            schema: Arc::new(schema),
+            fields: Some(Arc::new(fields)),
+        }
+    }
+
     /// Returns a reference to the [`ParquetMetaData`] for this parquet file
     pub fn metadata(&self) -> &Arc<ParquetMetaData> {
         &self.metadata
@@ -1012,6 +1242,37 @@ impl ArrowReaderMetadata {
 // A newtype used within `ReaderOptionsBuilder` to distinguish sync readers from async
 pub struct SyncReader<T: ChunkReader>(T);
 
+/// Returns the estimated size in bytes of `column` once decoded to arrow
+fn estimated_decoded_size(column: &ColumnChunkMetaData) -> Option<u64> {
+    let descr = column.column_descr();
+    let max_def_level = descr.max_def_level();
+    let num_values = column.num_values().max(0) as u64;
+    let non_null = match column.definition_level_histogram() {
+        Some(histogram) => histogram.get(max_def_level as usize).unwrap_or(0) as u64,
+        None if max_def_level == 0 => num_values,
+        None => match column.statistics().and_then(|s| s.null_count_opt()) {
+            Some(null_count) => num_values.saturating_sub(null_count),
+            None => num_values,
+        },
+    };
+
+    let values = match descr.physical_type() {
+        PhysicalType::BOOLEAN => non_null.div_ceil(8),
+        PhysicalType::INT32 | PhysicalType::FLOAT => non_null * 4,
+        PhysicalType::INT64 | PhysicalType::DOUBLE | PhysicalType::INT96 => non_null * 8,
+        PhysicalType::FIXED_LEN_BYTE_ARRAY => non_null * descr.type_length().max(0) as u64,
+        PhysicalType::BYTE_ARRAY => {
+            let data = column.unencoded_byte_array_data_bytes()?;
+            data.max(0) as u64 + num_values * 4
+        }
+    };
+    let validity = match max_def_level {
+        0 => 0,
+        _ => num_values.div_ceil(8),
+    };
+    Some(values + validity)
+}
+
 impl<T: Debug + ChunkReader> Debug for SyncReader<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_tuple("SyncReader").field(&self.0).finish()
@@ -1109,7 +1370,12 @@ impl<T: ChunkReader + 'static> ParquetRecordBatchReaderBuilder<T> {
     /// assert_eq!(a.next().unwrap().unwrap(), b.next().unwrap().unwrap());
     /// ```
     pub fn new_with_metadata(input: T, metadata: ArrowReaderMetadata) -> Self {
-        Self::new_builder(SyncReader(input), metadata)
+        Self {
+            // The predicate cache of the sync reader spans all row groups, see
+            // `ArrowReaderBuilder::with_max_predicate_cache_size`
+            max_predicate_cache_size: 0,
+            ..Self::new_builder(SyncReader(input), metadata)
+        }
     }
 
     /// Read bloom filter for a column in a row group
@@ -1172,6 +1438,20 @@ impl<T: ChunkReader + 'static> ParquetRecordBatchReaderBuilder<T> {
         Ok(Some(Sbbf::new(&bitset)))
     }
 
+    /// Read bloom filter for a leaf column in a row group, identified by its path
+    ///
+    /// This allows reading the bloom filters of nested columns, for example the path of
+    /// the field `b` of a struct column `a` is `a.b`. See
+    /// [`Self::get_row_group_column_bloom_filter`] for more details.
+    pub fn get_row_group_column_bloom_filter_by_path(
+        &self,
+        row_group_idx: usize,
+        path: &ColumnPath,
+    ) -> Result<Option<Sbbf>> {
+        let column_idx = self.leaf_column_index(path)?;
+        self.get_row_group_column_bloom_filter(row_group_idx, column_idx)
+    }
+
     /// Build a [`ParquetRecordBatchReader`]
     ///
     /// Note: this will eagerly evaluate any `RowFilter` before returning
@@ -1190,48 +1470,98 @@ impl<T: ChunkReader + 'static> ParquetRecordBatchReaderBuilder<T> {
             limit,
             offset,
             metrics,
-            // Not used for the sync reader, see https://github.com/apache/arrow-rs/issues/8000
-            max_predicate_cache_size: _,
-        } = self;
+            max_predicate_cache_size,
+        } = self.prune_with_row_filter()?;
 
         // Try to avoid allocate large buffer
         let batch_size = batch_size.min(metadata.file_metadata().num_rows() as usize);
 
         let row_groups = row_groups.unwrap_or_else(|| (0..metadata.num_row_groups()).collect());
 
+        // Without a filter the rows of each row group are known upfront, and so the
+        // row groups outside the offset and limit need never be read
+        let (row_groups, selection, offset) = match filter {
+            Some(_) => (row_groups, selection, offset),
+            None => prune_row_groups(&metadata, row_groups, selection, offset, limit),
+        };
+
         let reader = ReaderRowGroups {
             reader: Arc::new(input.0),
             metadata,
             row_groups,
+            metrics: metrics.clone(),
         };
 
         let mut plan_builder = ReadPlanBuilder::new(batch_size)
             .with_selection(selection)
             .with_row_selection_policy(row_selection_policy);
 
+        // Columns decoded by the predicates and also in the output projection,
+        // which are cached to avoid decoding them twice
+        let cache_projection = filter
+            .as_ref()
+            .filter(|_| max_predicate_cache_size > 0)
+            .and_then(|filter| {
+                let mut cache_projection = ProjectionMask::none(
+                    reader.metadata.file_metadata().schema_descr().num_columns(),
+                );
+                for predicate in filter.predicates.iter() {
+                    cache_projection.union(predicate.projection());
+                }
+                cache_projection.intersect(&projection);
+                cache_projection
+                    .without_nested_types(reader.metadata.file_metadata().schema_descr())
+            });
+        let row_group_cache = Arc::new(RwLock::new(RowGroupCache::new(
+            batch_size,
+            max_predicate_cache_size,
+        )));
+        let cache_builder = cache_projection
+            .as_ref()
+            .map(|projection| CacheOptionsBuilder::new(projection, &row_group_cache));
+
         // Update selection based on any filters
         if let Some(filter) = filter.as_mut() {
-            for predicate in filter.predicates.iter_mut() {
+            let cache_options = cache_builder.clone().map(|builder| builder.producer());
+            let num_predicates = filter.predicates.len();
+            for (idx, predicate) in filter.predicates.iter_mut().enumerate() {
                 // break early if we have ruled out all rows
                 if !plan_builder.selects_any() {
                     break;
                 }
 
-                let mut cache_projection = predicate.projection().clone();
-                cache_projection.intersect(&projection);
-
+                // Columns decoded as dictionaries are not shared with the output
+                let dictionary_fields = predicate.preserve_dictionary().then(|| {
+                    fields
+                        .as_deref()
+                        .map(ParquetField::with_dictionary_byte_arrays)
+                });
+                let (predicate_fields, cache_options) = match &dictionary_fields {
+                    Some(dictionary_fields) => (dictionary_fields.as_ref(), None),
+                    None => (fields.as_deref(), cache_options.as_ref()),
+                };
                 let array_reader = ArrayReaderBuilder::new(&reader, &metrics)
                     .with_batch_size(batch_size)
                     .with_parquet_metadata(&reader.metadata)
-                    .build_array_reader(fields.as_deref(), predicate.projection())?;
-
-                plan_builder = plan_builder.with_predicate(array_reader, predicate.as_mut())?;
+                    .with_cache_options(cache_options)
+                    .build_array_reader(predicate_fields, predicate.projection())?;
+
+                // The last predicate can stop once enough rows for the offset and
+                // limit have been selected
+                let mut options = PredicateOptions::new(array_reader, predicate.as_mut());
+                if let Some(limit) = limit.filter(|_| idx + 1 == num_predicates) {
+                    let limit = limit.saturating_add(offset.unwrap_or(0));
+                    options = options.with_limit(limit, reader.num_rows());
+                }
+                plan_builder = plan_builder.with_predicate_options(options)?;
             }
         }
 
+        let cache_options = cache_builder.map(|builder| builder.consumer());
         let array_reader = ArrayReaderBuilder::new(&reader, &metrics)
             .with_batch_size(batch_size)
             .with_parquet_metadata(&reader.metadata)
+            .with_cache_options(cache_options.as_ref())
             .build_array_reader(fields.as_deref(), &projection)?;
 
         let read_plan = plan_builder
@@ -1245,12 +1575,65 @@ impl<T: ChunkReader + 'static> ParquetRecordBatchReaderBuilder<T> {
     }
 }
 
+/// Removes the row groups of `row_groups` that contain none of the rows selected by
+/// `selection` after applying `offset` and `limit`, returning the remaining row groups
+/// and their selection and offset
+fn prune_row_groups(
+    metadata: &ParquetMetaData,
+    row_groups: Vec<usize>,
+    mut selection: Option<RowSelection>,
+    offset: Option<usize>,
+    limit: Option<usize>,
+) -> (Vec<usize>, Option<RowSelection>, Option<usize>) {
+    if offset.is_none() && limit.is_none() {
+        return (row_groups, selection, offset);
+    }
+
+    let mut remaining_offset = offset.unwrap_or(0);
+    let mut remaining_limit = limit;
+    let mut pruned_offset = None;
+    let mut pruned = vec![];
+    let mut selectors = vec![];
+    for row_group in row_groups {
+        if remaining_limit == Some(0) {
+            break;
+        }
+        let num_rows = metadata.row_group(row_group).num_rows() as usize;
+        let row_group_selection = selection.as_mut().map(|s| s.split_off(num_rows));
+        let selected = match &row_group_selection {
+            Some(s) => s.row_count(),
+            None => num_rows,
+        };
+        if selected <= remaining_offset {
+            remaining_offset -= selected;
+            continue;
+        }
+
+        if let Some(limit) = &mut remaining_limit {
+            *limit = limit.saturating_sub(selected - remaining_offset);
+        }
+        pruned_offset.get_or_insert(remaining_offset);
+        remaining_offset = 0;
+        pruned.push(row_group);
+        match row_group_selection {
+            Some(s) => selectors.extend(s.iter().copied()),
+            None => selectors.push(RowSelector::select(num_rows)),
+        }
+    }
+
+    let selection = selection.map(|_| RowSelection::from(selectors));
+    let offset = offset.map(|_| pruned_offset.unwrap_or(0));
+    (pruned, selection, offset)
+}
+
 struct ReaderRowGroups<T: ChunkReader> {
     reader: Arc<T>,
 
     metadata: Arc<ParquetMetaData>,
     /// Optional list of row group indices to scan
     row_groups: Vec<usize>,
+    /// Metrics to record the bytes read for each column
+    metrics: ArrowReaderMetrics,
 }
 
 impl<T: ChunkReader + 'static> RowGroups for ReaderRowGroups<T> {
@@ -1268,6 +1651,7 @@ impl<T: ChunkReader + 'static> RowGroups for ReaderRowGroups<T> {
             reader: self.reader.clone(),
             metadata: self.metadata.clone(),
             row_groups: self.row_groups.clone().into_iter(),
+            metrics: self.metrics.clone(),
         }))
     }
 
@@ -1289,11 +1673,28 @@ struct ReaderPageIterator<T: ChunkReader> {
     column_idx: usize,
     row_groups: std::vec::IntoIter<usize>,
     metadata: Arc<ParquetMetaData>,
+    metrics: ArrowReaderMetrics,
 }
 
 impl<T: ChunkReader + 'static> ReaderPageIterator<T> {
-    /// Return the next SerializedPageReader
-    fn next_page_reader(&mut self, rg_idx: usize) -> Result<SerializedPageReader<T>> {
+    /// Return the next [`PageReader`], recording the bytes read if metrics are enabled
+    fn next_page_reader(&mut self, rg_idx: usize) -> Result<Box<dyn PageReader>> {
+        if let Some(counters) = self.metrics.column_counters(self.column_idx) {
+            let reader = Arc::new(MetricsChunkReader::new(self.reader.clone(), counters));
+            Ok(Box::new(self.serialized_page_reader(reader, rg_idx)?))
+        } else {
+            Ok(Box::new(
+                self.serialized_page_reader(self.reader.clone(), rg_idx)?,
+            ))
+        }
+    }
+
+    /// Return a SerializedPageReader for row group `rg_idx` reading from `reader`
+    fn serialized_page_reader<R: ChunkReader + 'static>(
+        &self,
+        reader: Arc<R>,
+        rg_idx: usize,
+    ) -> Result<SerializedPageReader<R>> {
         let rg = self.metadata.row_group(rg_idx);
         let column_chunk_metadata = rg.column(self.column_idx);
         let offset_index = self.metadata.offset_index();
@@ -1303,7 +1704,6 @@ impl<T: ChunkReader + 'static> ReaderPageIterator<T> {
             .filter(|i| !i[rg_idx].is_empty())
             .map(|i| i[rg_idx][self.column_idx].page_locations.clone());
         let total_rows = rg.num_rows() as usize;
-        let reader = self.reader.clone();
 
         SerializedPageReader::new(reader, column_chunk_metadata, total_rows, page_locations)?
             .add_crypto_context(
@@ -1320,10 +1720,7 @@ impl<T: ChunkReader + 'static> Iterator for ReaderPageIterator<T> {
 
     fn next(&mut self) -> Option<Self::Item> {
         let rg_idx = self.row_groups.next()?;
-        let page_reader = self
-            .next_page_reader(rg_idx)
-            .map(|page_reader| Box::new(page_reader) as _);
-        Some(page_reader)
+        Some(self.next_page_reader(rg_idx))
     }
 }
 
@@ -1619,9 +2016,13 @@ pub(crate) mod tests {
         Time64MicrosecondType,
     };
     use arrow_array::*;
-    use arrow_buffer::{ArrowNativeType, Buffer, IntervalDayTime, NullBuffer, i256};
+    use arrow_buffer::{
+        ArrowNativeType, Buffer, IntervalDayTime, IntervalMonthDayNano, NullBuffer, i256,
+    };
     use arrow_data::{ArrayData, ArrayDataBuilder};
-    use arrow_schema::{DataType as ArrowDataType, Field, Fields, Schema, SchemaRef, TimeUnit};
+    use arrow_schema::{
+        DataType as ArrowDataType, Field, Fields, IntervalUnit, Schema, SchemaRef, TimeUnit,
+    };
     use arrow_select::concat::concat_batches;
     use bytes::Bytes;
     use half::f16;
@@ -1762,6 +2163,50 @@ pub(crate) mod tests {
         }
     }
 
+    #[test]
+    fn test_estimated_row_size() {
+        let ints = Int32Array::from_iter_values(0..100);
+        let strings: StringArray = (0..100).map(|i| (i % 2 == 0).then_some("abc")).collect();
+        let batch = RecordBatch::try_from_iter([
+            ("int", Arc::new(ints) as ArrayRef),
+            ("string", Arc::new(strings) as ArrayRef),
+        ])
+        .unwrap();
+
+        let mut buf = Vec::new();
+        let props = WriterProperties::builder()
+            .set_max_row_group_row_count(Some(60))
+            .build();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let buf = Bytes::from(buf);
+
+        let builder = ParquetRecordBatchReaderBuilder::try_new(buf.clone()).unwrap();
+        // 4 bytes per int, and 50 * 3 bytes of string data, 100 * 4 bytes of offsets
+        // and 8 + 5 bytes of validity for the two row groups
+        assert_eq!(
+            builder.estimated_row_size(),
+            Some((400 + 150 + 400 + 13_usize).div_ceil(100))
+        );
+
+        let mask = ProjectionMask::leaves(builder.parquet_schema(), [0]);
+        let builder = builder.with_projection(mask);
+        assert_eq!(builder.estimated_row_size(), Some(4));
+
+        let builder = builder.with_row_groups(vec![]);
+        assert_eq!(builder.estimated_row_size(), None);
+
+        // The size of string columns is unknown without size statistics
+        let options =
+            ArrowReaderOptions::new().with_size_stats_policy(ParquetStatisticsPolicy::SkipAll);
+        let builder =
+            ParquetRecordBatchReaderBuilder::try_new_with_options(buf.clone(), options).unwrap();
+        assert_eq!(builder.estimated_row_size(), None);
+        let mask = ProjectionMask::leaves(builder.parquet_schema(), [0]);
+        assert_eq!(builder.with_projection(mask).estimated_row_size(), Some(4));
+    }
+
     #[test]
     fn test_arrow_reader_single_column() {
         let file = get_test_file("parquet/generated_simple_numerics/blogs.parquet");
@@ -2240,6 +2685,33 @@ pub(crate) mod tests {
         );
     }
 
+    #[test]
+    fn test_interval_month_day_nano_column_reader() {
+        run_single_column_reader_tests::<FixedLenByteArrayType, _, RandFixedLenGen>(
+            12,
+            ConvertedType::INTERVAL,
+            Some(ArrowDataType::Interval(IntervalUnit::MonthDayNano)),
+            |vals| {
+                Arc::new(
+                    vals.iter()
+                        .map(|x| {
+                            x.as_ref().map(|b| {
+                                let b = b.as_ref();
+                                let millis = i32::from_le_bytes(b[8..12].try_into().unwrap());
+                                IntervalMonthDayNano {
+                                    months: i32::from_le_bytes(b[0..4].try_into().unwrap()),
+                                    days: i32::from_le_bytes(b[4..8].try_into().unwrap()),
+                                    nanoseconds: millis as i64 * 1_000_000,
+                                }
+                            })
+                        })
+                        .collect::<IntervalMonthDayNanoArray>(),
+                )
+            },
+            &[Encoding::PLAIN, Encoding::RLE_DICTIONARY],
+        );
+    }
+
     #[test]
     fn test_int96_single_column_reader_test() {
         let encodings = &[Encoding::PLAIN, Encoding::RLE_DICTIONARY];
@@ -0,0 +1,865 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`StatisticsPruner`] to skip row groups and data pages using the statistics
+//! in [`ParquetMetaData`]
+//!
+//! Row groups are pruned using the column chunk statistics, and data pages are
+//! pruned using the page index, if it was loaded (see
+//! [`ArrowReaderOptions::with_page_index_policy`]). The results can be passed to
+//! [`ArrowReaderBuilder::with_row_groups`] and [`ArrowReaderBuilder::with_row_selection`],
+//! or applied directly with [`ArrowReaderBuilder::with_pruning_predicates`].
+//!
+//! Pruning is conservative: a row group or page is only skipped if its statistics
+//! prove it contains no rows matching the predicates. Any rows that are not skipped
+//! must still be filtered, for example with a [`RowFilter`].
+//!
+//! [`ArrowReaderOptions::with_page_index_policy`]: super::ArrowReaderOptions::with_page_index_policy
+//! [`ArrowReaderBuilder::with_row_groups`]: super::ArrowReaderBuilder::with_row_groups
+//! [`ArrowReaderBuilder::with_row_selection`]: super::ArrowReaderBuilder::with_row_selection
+//! [`ArrowReaderBuilder::with_pruning_predicates`]: super::ArrowReaderBuilder::with_pruning_predicates
+//! [`RowFilter`]: super::RowFilter
+
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::{Array, ArrayRef, BooleanArray, Scalar, UInt64Array};
+use arrow_ord::cmp;
+use arrow_schema::{DataType, Schema};
+
+use super::statistics::StatisticsConverter;
+use super::{RowSelection, RowSelector};
+use crate::errors::{ParquetError, Result};
+use crate::file::metadata::ParquetMetaData;
+
+/// A comparison operator of a [`PruningPredicate`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PruningOp {
+    /// `column = value`
+    Eq,
+    /// `column != value`
+    NotEq,
+    /// `column < value`
+    Lt,
+    /// `column <= value`
+    LtEq,
+    /// `column > value`
+    Gt,
+    /// `column >= value`
+    GtEq,
+}
+
+/// A callback evaluated against the [`PruningStatistics`] of a column, see
+/// [`PruningPredicate::custom`]
+pub type PruningFn = Arc<dyn Fn(&PruningStatistics) -> Result<BooleanArray> + Send + Sync>;
+
+#[derive(Clone)]
+enum PredicateKind {
+    Compare(PruningOp, Scalar<ArrayRef>),
+    IsNull,
+    IsNotNull,
+    StartsWith(Vec<u8>),
+    Custom(PruningFn),
+}
+
+/// A predicate on a top level column, used by [`StatisticsPruner`]
+///
+/// ```
+/// # use arrow_array::{Int32Array, StringArray};
+/// # use parquet::arrow::arrow_reader::pruning::{PruningOp, PruningPredicate};
+/// // id > 100
+/// let id = PruningPredicate::new("id", PruningOp::Gt, Int32Array::new_scalar(100));
+/// // name = 'foo'
+/// let name = PruningPredicate::new("name", PruningOp::Eq, StringArray::new_scalar("foo"));
+/// // name IS NOT NULL
+/// let not_null = PruningPredicate::is_not_null("name");
+/// ```
+#[derive(Clone)]
+pub struct PruningPredicate {
+    column: String,
+    kind: PredicateKind,
+}
+
+impl Debug for PruningPredicate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut d = f.debug_struct("PruningPredicate");
+        d.field("column", &self.column);
+        match &self.kind {
+            PredicateKind::Compare(op, value) => d
+                .field("op", op)
+                .field("value", value.clone().into_inner().as_ref()),
+            PredicateKind::IsNull => d.field("op", &"IS NULL"),
+            PredicateKind::IsNotNull => d.field("op", &"IS NOT NULL"),
+            PredicateKind::StartsWith(prefix) => d.field("starts_with", prefix),
+            PredicateKind::Custom(_) => d.field("op", &"custom"),
+        };
+        d.finish()
+    }
+}
+
+impl PruningPredicate {
+    /// Create a predicate comparing `column` to `value` with `op`
+    ///
+    /// `value` must have the same data type as the column in the Arrow schema
+    /// passed to [`StatisticsPruner::new`], otherwise pruning will return an error
+    pub fn new<T: Array + 'static>(
+        column: impl Into<String>,
+        op: PruningOp,
+        value: Scalar<T>,
+    ) -> Self {
+        let value = Scalar::new(Arc::new(value.into_inner()) as ArrayRef);
+        Self {
+            column: column.into(),
+            kind: PredicateKind::Compare(op, value),
+        }
+    }
+
+    /// Create a predicate matching rows where `column` is null
+    pub fn is_null(column: impl Into<String>) -> Self {
+        Self {
+            column: column.into(),
+            kind: PredicateKind::IsNull,
+        }
+    }
+
+    /// Create a predicate matching rows where `column` is not null
+    pub fn is_not_null(column: impl Into<String>) -> Self {
+        Self {
+            column: column.into(),
+            kind: PredicateKind::IsNotNull,
+        }
+    }
+
+    /// Create a predicate matching rows where the string or binary `column` starts
+    /// with `prefix`
+    ///
+    /// The statistics of `BYTE_ARRAY` columns may be truncated, in which case the
+    /// minimum is a lower bound and the maximum an upper bound of the values. A row
+    /// group or page is therefore only skipped if its maximum is less than `prefix`,
+    /// or its minimum is not less than the smallest value greater than all values
+    /// starting with `prefix`.
+    pub fn starts_with(column: impl Into<String>, prefix: impl Into<Vec<u8>>) -> Self {
+        Self {
+            column: column.into(),
+            kind: PredicateKind::StartsWith(prefix.into()),
+        }
+    }
+
+    /// Create a predicate evaluated by calling `f` with the [`PruningStatistics`] of
+    /// `column`
+    ///
+    /// `f` must return a [`BooleanArray`] with one value for each row group or page,
+    /// which is `false` if it can not contain any matching rows. Null values are
+    /// treated as `true`.
+    pub fn custom<F>(column: impl Into<String>, f: F) -> Self
+    where
+        F: Fn(&PruningStatistics) -> Result<BooleanArray> + Send + Sync + 'static,
+    {
+        Self {
+            column: column.into(),
+            kind: PredicateKind::Custom(Arc::new(f)),
+        }
+    }
+
+    /// Returns the name of the column this predicate applies to
+    pub fn column(&self) -> &str {
+        &self.column
+    }
+
+    /// Returns for each container in `stats` whether it may contain matching rows
+    fn evaluate(&self, stats: &PruningStatistics) -> Result<Vec<bool>> {
+        let mut keep: Vec<bool> = match &self.kind {
+            PredicateKind::Compare(op, value) => {
+                let (mins, maxes) = (stats.mins.as_ref(), stats.maxes.as_ref());
+                match op {
+                    // min <= value AND max >= value
+                    PruningOp::Eq => {
+                        let lower = cmp::lt_eq(&mins, value)?;
+                        let upper = cmp::gt_eq(&maxes, value)?;
+                        lower
+                            .iter()
+                            .zip(upper.iter())
+                            .map(|(l, u)| l != Some(false) && u != Some(false))
+                            .collect()
+                    }
+                    // NOT (min = value AND max = value), if both are exact
+                    PruningOp::NotEq => {
+                        let (Some(min_exact), Some(max_exact)) =
+                            (&stats.min_exact, &stats.max_exact)
+                        else {
+                            return Ok(vec![true; stats.len()]);
+                        };
+                        let min_eq = cmp::eq(&mins, value)?;
+                        let max_eq = cmp::eq(&maxes, value)?;
+                        (0..stats.len())
+                            .map(|i| {
+                                let all_eq = [&min_eq, &max_eq, min_exact, max_exact]
+                                    .iter()
+                                    .all(|a| a.is_valid(i) && a.value(i));
+                                !all_eq
+                            })
+                            .collect()
+                    }
+                    PruningOp::Lt => keep_unless_false(&cmp::lt(&mins, value)?),
+                    PruningOp::LtEq => keep_unless_false(&cmp::lt_eq(&mins, value)?),
+                    PruningOp::Gt => keep_unless_false(&cmp::gt(&maxes, value)?),
+                    PruningOp::GtEq => keep_unless_false(&cmp::gt_eq(&maxes, value)?),
+                }
+            }
+            PredicateKind::IsNull => stats
+                .null_counts
+                .iter()
+                .map(|n| n.is_none_or(|n| n > 0))
+                .collect(),
+            PredicateKind::IsNotNull => match &stats.row_counts {
+                Some(row_counts) => stats
+                    .null_counts
+                    .iter()
+                    .zip(row_counts.iter())
+                    .map(|(n, r)| n.zip(r).is_none_or(|(n, r)| n < r))
+                    .collect(),
+                None => vec![true; stats.len()],
+            },
+            PredicateKind::StartsWith(prefix) => {
+                let mins = byte_array_values(&self.column, &stats.mins)?;
+                let maxes = byte_array_values(&self.column, &stats.maxes)?;
+                let upper = prefix_upper_bound(prefix);
+                mins.iter()
+                    .zip(&maxes)
+                    .map(|(min, max)| {
+                        let below = max.is_some_and(|max| max < prefix.as_slice());
+                        let above = min
+                            .zip(upper.as_deref())
+                            .is_some_and(|(min, upper)| min >= upper);
+                        !below && !above
+                    })
+                    .collect()
+            }
+            PredicateKind::Custom(f) => {
+                let keep = f(stats)?;
+                if keep.len() != stats.len() {
+                    return Err(general_err!(
+                        "Pruning predicate on column '{}' returned {} values, expected {}",
+                        self.column,
+                        keep.len(),
+                        stats.len()
+                    ));
+                }
+                keep_unless_false(&keep)
+            }
+        };
+
+        // comparisons never match null values
+        let is_comparison = matches!(
+            self.kind,
+            PredicateKind::Compare(..) | PredicateKind::StartsWith(_)
+        );
+        if let (true, Some(row_counts)) = (is_comparison, &stats.row_counts) {
+            let all_null = stats.null_counts.iter().zip(row_counts.iter());
+            for (k, (n, r)) in keep.iter_mut().zip(all_null) {
+                *k &= n.zip(r).is_none_or(|(n, r)| n < r);
+            }
+        }
+        Ok(keep)
+    }
+}
+
+fn keep_unless_false(keep: &BooleanArray) -> Vec<bool> {
+    keep.iter().map(|k| k != Some(false)).collect()
+}
+
+/// Returns the bytes of the values of a string or binary array
+fn byte_array_values<'a>(column: &str, array: &'a ArrayRef) -> Result<Vec<Option<&'a [u8]>>> {
+    let values = match array.data_type() {
+        DataType::Utf8 => array
+            .as_string::<i32>()
+            .iter()
+            .map(|v| v.map(str::as_bytes))
+            .collect(),
+        DataType::LargeUtf8 => array
+            .as_string::<i64>()
+            .iter()
+            .map(|v| v.map(str::as_bytes))
+            .collect(),
+        DataType::Utf8View => array
+            .as_string_view()
+            .iter()
+            .map(|v| v.map(str::as_bytes))
+            .collect(),
+        DataType::Binary => array.as_binary::<i32>().iter().collect(),
+        DataType::LargeBinary => array.as_binary::<i64>().iter().collect(),
+        DataType::BinaryView => array.as_binary_view().iter().collect(),
+        DataType::FixedSizeBinary(_) => array.as_fixed_size_binary().iter().collect(),
+        // e.g. a column missing from the file
+        DataType::Null => vec![None; array.len()],
+        other => {
+            return Err(general_err!(
+                "Prefix pruning predicate on column '{}' requires a string or binary column, got {}",
+                column,
+                other
+            ));
+        }
+    };
+    Ok(values)
+}
+
+/// Returns the smallest value greater than all values starting with `prefix`, or
+/// `None` if there is no such value
+fn prefix_upper_bound(prefix: &[u8]) -> Option<Vec<u8>> {
+    let mut upper = prefix.to_vec();
+    while let Some(last) = upper.pop() {
+        if last != u8::MAX {
+            upper.push(last + 1);
+            return Some(upper);
+        }
+    }
+    None
+}
+
+/// The statistics of a column for a set of row groups or data pages, evaluated by a
+/// [`PruningPredicate`]
+///
+/// Each array has one value per row group or page, which is null if unknown. See
+/// [`StatisticsConverter`] for details.
+#[derive(Debug, Clone)]
+pub struct PruningStatistics {
+    mins: ArrayRef,
+    maxes: ArrayRef,
+    null_counts: UInt64Array,
+    row_counts: Option<UInt64Array>,
+    min_exact: Option<BooleanArray>,
+    max_exact: Option<BooleanArray>,
+}
+
+impl PruningStatistics {
+    /// Returns the number of row groups or pages
+    pub fn len(&self) -> usize {
+        self.mins.len()
+    }
+
+    /// Returns `true` if there are no row groups or pages
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the minimum values, which may be truncated lower bounds
+    pub fn mins(&self) -> &ArrayRef {
+        &self.mins
+    }
+
+    /// Returns the maximum values, which may be truncated upper bounds
+    pub fn maxes(&self) -> &ArrayRef {
+        &self.maxes
+    }
+
+    /// Returns the number of nulls
+    pub fn null_counts(&self) -> &UInt64Array {
+        &self.null_counts
+    }
+
+    /// Returns the number of rows, or `None` if the column is not in the file
+    pub fn row_counts(&self) -> Option<&UInt64Array> {
+        self.row_counts.as_ref()
+    }
+
+    /// Returns whether the minimum values are exact, or `None` if unknown, as is the
+    /// case for data pages
+    pub fn is_min_value_exact(&self) -> Option<&BooleanArray> {
+        self.min_exact.as_ref()
+    }
+
+    /// Returns whether the maximum values are exact, or `None` if unknown, as is the
+    /// case for data pages
+    pub fn is_max_value_exact(&self) -> Option<&BooleanArray> {
+        self.max_exact.as_ref()
+    }
+}
+
+/// Evaluates a conjunction of [`PruningPredicate`]s against the statistics in
+/// [`ParquetMetaData`]
+///
+/// See the [module level documentation](self) for more details
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+/// # use bytes::Bytes;
+/// # use parquet::arrow::ArrowWriter;
+/// # use parquet::arrow::arrow_reader::{ArrowReaderOptions, ParquetRecordBatchReaderBuilder};
+/// # use parquet::arrow::arrow_reader::pruning::{PruningOp, PruningPredicate, StatisticsPruner};
+/// # use parquet::file::metadata::PageIndexPolicy;
+/// # use parquet::file::properties::WriterProperties;
+/// # let ids = Arc::new(Int32Array::from_iter_values(0..100)) as ArrayRef;
+/// # let batch = RecordBatch::try_from_iter([("id", ids)]).unwrap();
+/// # let props = WriterProperties::builder().set_max_row_group_row_count(Some(10)).build();
+/// # let mut buffer = Vec::new();
+/// # let mut writer = ArrowWriter::try_new(&mut buffer, batch.schema(), Some(props)).unwrap();
+/// # writer.write(&batch).unwrap();
+/// # writer.close().unwrap();
+/// # let data = Bytes::from(buffer);
+/// let options = ArrowReaderOptions::new().with_page_index_policy(PageIndexPolicy::Required);
+/// let builder = ParquetRecordBatchReaderBuilder::try_new_with_options(data, options).unwrap();
+/// let (metadata, schema) = (Arc::clone(builder.metadata()), Arc::clone(builder.schema()));
+///
+/// // id >= 95
+/// let predicate = PruningPredicate::new("id", PruningOp::GtEq, Int32Array::new_scalar(95));
+/// let pruner = StatisticsPruner::new(&metadata, &schema).with_predicate(predicate);
+///
+/// let all_row_groups: Vec<_> = (0..metadata.num_row_groups()).collect();
+/// let row_groups = pruner.prune_row_groups(&all_row_groups).unwrap();
+/// assert_eq!(row_groups, vec![9]);
+///
+/// let mut builder = builder.with_row_groups(row_groups.clone());
+/// if let Some(selection) = pruner.prune_pages(&row_groups).unwrap() {
+///     builder = builder.with_row_selection(selection);
+/// }
+/// let reader = builder.build().unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct StatisticsPruner<'a> {
+    metadata: &'a ParquetMetaData,
+    arrow_schema: &'a Schema,
+    predicates: Vec<PruningPredicate>,
+}
+
+impl<'a> StatisticsPruner<'a> {
+    /// Create a new [`StatisticsPruner`] for a file with the given metadata, and
+    /// Arrow schema
+    pub fn new(metadata: &'a ParquetMetaData, arrow_schema: &'a Schema) -> Self {
+        Self {
+            metadata,
+            arrow_schema,
+            predicates: vec![],
+        }
+    }
+
+    /// Add a predicate, which all rows must match
+    pub fn with_predicate(mut self, predicate: PruningPredicate) -> Self {
+        self.predicates.push(predicate);
+        self
+    }
+
+    /// Add predicates, which all rows must match
+    pub fn with_predicates(
+        mut self,
+        predicates: impl IntoIterator<Item = PruningPredicate>,
+    ) -> Self {
+        self.predicates.extend(predicates);
+        self
+    }
+
+    /// Returns the predicates of this pruner
+    pub fn predicates(&self) -> &[PruningPredicate] {
+        &self.predicates
+    }
+
+    fn converter(&self, column: &str) -> Result<StatisticsConverter<'a>> {
+        let parquet_schema = self.metadata.file_metadata().schema_descr();
+        Ok(
+            StatisticsConverter::try_new(column, self.arrow_schema, parquet_schema)?
+                .with_missing_null_counts_as_zero(false),
+        )
+    }
+
+    /// Returns the indexes of `row_groups` that may contain rows matching the
+    /// predicates, based on their column chunk statistics
+    pub fn prune_row_groups(&self, row_groups: &[usize]) -> Result<Vec<usize>> {
+        let mut keep = vec![true; row_groups.len()];
+        let metadatas = || row_groups.iter().map(|i| self.metadata.row_group(*i));
+        for predicate in &self.predicates {
+            let converter = self.converter(predicate.column())?;
+            let stats = PruningStatistics {
+                mins: converter.row_group_mins(metadatas())?,
+                maxes: converter.row_group_maxes(metadatas())?,
+                null_counts: converter.row_group_null_counts(metadatas())?,
+                row_counts: converter.row_group_row_counts(metadatas())?,
+                min_exact: Some(converter.row_group_is_min_value_exact(metadatas())?),
+                max_exact: Some(converter.row_group_is_max_value_exact(metadatas())?),
+            };
+            for (k, v) in keep.iter_mut().zip(predicate.evaluate(&stats)?) {
+                *k &= v;
+            }
+        }
+
+        let kept = row_groups.iter().zip(keep).filter(|(_, k)| *k);
+        Ok(kept.map(|(i, _)| *i).collect())
+    }
+
+    /// Returns a [`RowSelection`] of the rows in `row_groups` that may match the
+    /// predicates, based on the statistics in the page index
+    ///
+    /// Returns `None` if the page index has not been loaded
+    pub fn prune_pages(&self, row_groups: &[usize]) -> Result<Option<RowSelection>> {
+        let (Some(column_index), Some(offset_index)) =
+            (self.metadata.column_index(), self.metadata.offset_index())
+        else {
+            return Ok(None);
+        };
+
+        let converters = self
+            .predicates
+            .iter()
+            .map(|p| self.converter(p.column()))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut selectors = vec![];
+        for row_group in row_groups {
+            let num_rows = self.metadata.row_group(*row_group).num_rows() as usize;
+            let mut selection = RowSelection::from(vec![RowSelector::select(num_rows)]);
+            for (predicate, converter) in self.predicates.iter().zip(&converters) {
+                let indices = [*row_group];
+                let Some(row_counts) = converter.data_page_row_counts(
+                    offset_index,
+                    self.metadata.row_groups(),
+                    &indices,
+                )?
+                else {
+                    continue;
+                };
+                let stats = PruningStatistics {
+                    mins: converter.data_page_mins(column_index, offset_index, &indices)?,
+                    maxes: converter.data_page_maxes(column_index, offset_index, &indices)?,
+                    null_counts: converter.data_page_null_counts(
+                        column_index,
+                        offset_index,
+                        &indices,
+                    )?,
+                    row_counts: Some(row_counts.clone()),
+                    min_exact: None,
+                    max_exact: None,
+                };
+                let keep = predicate.evaluate(&stats)?;
+                let pages = row_counts.values().iter().zip(keep).map(|(rows, keep)| {
+                    let rows = *rows as usize;
+                    match keep {
+                        true => RowSelector::select(rows),
+                        false => RowSelector::skip(rows),
+                    }
+                });
+                selection = selection.intersection(&pages.collect());
+            }
+            selectors.extend(selection.iter().copied());
+        }
+        Ok(Some(selectors.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrow::arrow_reader::{
+        ArrowPredicateFn, ArrowReaderOptions, ParquetRecordBatchReaderBuilder, RowFilter,
+    };
+    use crate::arrow::{ArrowWriter, ProjectionMask};
+    use crate::file::metadata::PageIndexPolicy;
+    use crate::file::properties::{EnabledStatistics, WriterProperties};
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int32Type;
+    use arrow_array::{Int32Array, RecordBatch, StringArray};
+    use bytes::Bytes;
+
+    /// Writes 4 row groups of 100 rows, with pages of 10 rows
+    ///
+    /// * `id`: 0..400
+    /// * `name`: null for the first 150 rows, `"a"` for the next 100, then `"b"`
+    fn test_file() -> Bytes {
+        let ids = Int32Array::from_iter_values(0..400);
+        let names: StringArray = (0..400)
+            .map(|i| match i {
+                0..150 => None,
+                150..250 => Some("a"),
+                _ => Some("b"),
+            })
+            .collect();
+        let batch = RecordBatch::try_from_iter([
+            ("id", Arc::new(ids) as ArrayRef),
+            ("name", Arc::new(names) as ArrayRef),
+        ])
+        .unwrap();
+
+        let props = WriterProperties::builder()
+            .set_max_row_group_row_count(Some(100))
+            .set_data_page_row_count_limit(10)
+            .set_write_batch_size(10)
+            .set_statistics_enabled(EnabledStatistics::Page)
+            .build();
+        let mut buffer = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buffer, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        Bytes::from(buffer)
+    }
+
+    fn builder(data: Bytes) -> ParquetRecordBatchReaderBuilder<Bytes> {
+        let options = ArrowReaderOptions::new().with_page_index_policy(PageIndexPolicy::Required);
+        ParquetRecordBatchReaderBuilder::try_new_with_options(data, options).unwrap()
+    }
+
+    fn prune_row_groups(predicates: Vec<PruningPredicate>) -> Vec<usize> {
+        let builder = builder(test_file());
+        StatisticsPruner::new(builder.metadata(), builder.schema())
+            .with_predicates(predicates)
+            .prune_row_groups(&[0, 1, 2, 3])
+            .unwrap()
+    }
+
+    fn id(op: PruningOp, value: i32) -> PruningPredicate {
+        PruningPredicate::new("id", op, Int32Array::new_scalar(value))
+    }
+
+    fn name(op: PruningOp, value: &str) -> PruningPredicate {
+        PruningPredicate::new("name", op, StringArray::new_scalar(value))
+    }
+
+    #[test]
+    fn test_prune_row_groups() {
+        assert_eq!(prune_row_groups(vec![id(PruningOp::Eq, 150)]), vec![1]);
+        assert_eq!(prune_row_groups(vec![id(PruningOp::Lt, 100)]), vec![0]);
+        assert_eq!(prune_row_groups(vec![id(PruningOp::LtEq, 100)]), vec![0, 1]);
+        assert_eq!(prune_row_groups(vec![id(PruningOp::Gt, 299)]), vec![3]);
+        assert_eq!(prune_row_groups(vec![id(PruningOp::GtEq, 299)]), vec![2, 3]);
+        assert_eq!(
+            prune_row_groups(vec![id(PruningOp::NotEq, 5)]),
+            vec![0, 1, 2, 3]
+        );
+        assert_eq!(
+            prune_row_groups(vec![name(PruningOp::NotEq, "b")]),
+            vec![1, 2]
+        );
+        assert_eq!(prune_row_groups(vec![name(PruningOp::Eq, "a")]), vec![1, 2]);
+        assert!(prune_row_groups(vec![name(PruningOp::Eq, "c")]).is_empty());
+        assert_eq!(
+            prune_row_groups(vec![PruningPredicate::is_null("name")]),
+            vec![0, 1]
+        );
+        assert_eq!(
+            prune_row_groups(vec![PruningPredicate::is_not_null("name")]),
+            vec![1, 2, 3]
+        );
+
+        // predicates are combined with AND
+        let predicates = vec![id(PruningOp::Lt, 200), name(PruningOp::Eq, "a")];
+        assert_eq!(prune_row_groups(predicates), vec![1]);
+    }
+
+    #[test]
+    fn test_prune_starts_with() {
+        let starts_with = |prefix: &str| PruningPredicate::starts_with("name", prefix);
+        assert_eq!(prune_row_groups(vec![starts_with("a")]), vec![1, 2]);
+        assert_eq!(prune_row_groups(vec![starts_with("b")]), vec![2, 3]);
+        assert_eq!(prune_row_groups(vec![starts_with("")]), vec![1, 2, 3]);
+        // "ab" lies between "a" and "b"
+        assert_eq!(prune_row_groups(vec![starts_with("ab")]), vec![2]);
+        assert!(prune_row_groups(vec![starts_with("c")]).is_empty());
+
+        let builder = builder(test_file());
+        let err = StatisticsPruner::new(builder.metadata(), builder.schema())
+            .with_predicate(PruningPredicate::starts_with("id", "a"))
+            .prune_row_groups(&[0])
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("requires a string or binary column"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_prune_starts_with_truncated() {
+        let values = StringArray::from(vec!["apple", "apricot", "banana", "blueberry"]);
+        let batch = RecordBatch::try_from_iter([("name", Arc::new(values) as ArrayRef)]).unwrap();
+        // row group statistics are truncated to "ap".."aq" and "ba".."bm"
+        let props = WriterProperties::builder()
+            .set_max_row_group_row_count(Some(2))
+            .set_statistics_truncate_length(Some(2))
+            .build();
+        let mut buffer = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buffer, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let builder = builder(Bytes::from(buffer));
+        let prune = |prefix: &str| {
+            StatisticsPruner::new(builder.metadata(), builder.schema())
+                .with_predicate(PruningPredicate::starts_with("name", prefix))
+                .prune_row_groups(&[0, 1])
+                .unwrap()
+        };
+        assert_eq!(prune("apr"), vec![0]);
+        assert_eq!(prune("apricots"), vec![0]);
+        assert_eq!(prune("blue"), vec![1]);
+        // may match the truncated bounds, so cannot be pruned
+        assert_eq!(prune("aq"), vec![0]);
+        assert!(prune("ao").is_empty());
+        assert!(prune("c").is_empty());
+    }
+
+    #[test]
+    fn test_prefix_upper_bound() {
+        assert_eq!(prefix_upper_bound(b"abc"), Some(b"abd".to_vec()));
+        assert_eq!(prefix_upper_bound(b"a\xff\xff"), Some(b"b".to_vec()));
+        assert_eq!(prefix_upper_bound(b"\xff"), None);
+        assert_eq!(prefix_upper_bound(b""), None);
+    }
+
+    #[test]
+    fn test_prune_custom() {
+        // keep row groups where max - min < 50, i.e. none of them
+        let predicate = PruningPredicate::custom("id", |stats| {
+            let mins = stats.mins().as_primitive::<Int32Type>();
+            let maxes = stats.maxes().as_primitive::<Int32Type>();
+            Ok(mins
+                .iter()
+                .zip(maxes.iter())
+                .map(|(min, max)| Some(max? - min? < 50))
+                .collect())
+        });
+        assert!(prune_row_groups(vec![predicate]).is_empty());
+
+        let predicate = PruningPredicate::custom("id", |_| Ok(BooleanArray::from(vec![true])));
+        let builder = builder(test_file());
+        let err = StatisticsPruner::new(builder.metadata(), builder.schema())
+            .with_predicate(predicate)
+            .prune_row_groups(&[0, 1])
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Pruning predicate on column 'id' returned 1 values, expected 2"
+        );
+    }
+
+    #[test]
+    fn test_prune_errors() {
+        let builder = builder(test_file());
+        let pruner = StatisticsPruner::new(builder.metadata(), builder.schema());
+
+        let predicate = PruningPredicate::is_null("missing");
+        let err = pruner
+            .clone()
+            .with_predicate(predicate)
+            .prune_row_groups(&[0])
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("Column 'missing' not found"),
+            "{err}"
+        );
+
+        // mismatched data type
+        let predicate = name(PruningOp::Eq, "a");
+        let predicate = PruningPredicate {
+            column: "id".to_string(),
+            ..predicate
+        };
+        let err = pruner
+            .with_predicate(predicate)
+            .prune_row_groups(&[0])
+            .unwrap_err();
+        assert!(err.to_string().contains("Invalid comparison"), "{err}");
+    }
+
+    #[test]
+    fn test_prune_pages() {
+        let data = test_file();
+        let builder = builder(data.clone());
+        let pruner = StatisticsPruner::new(builder.metadata(), builder.schema())
+            .with_predicate(id(PruningOp::GtEq, 125))
+            .with_predicate(name(PruningOp::Eq, "a"));
+        let row_groups = pruner.prune_row_groups(&[0, 1, 2, 3]).unwrap();
+        assert_eq!(row_groups, vec![1, 2]);
+
+        let selection = pruner.prune_pages(&row_groups).unwrap().unwrap();
+        // rows 150..200 of row group 1, and 200..250 of row group 2
+        let expected = RowSelection::from(vec![
+            RowSelector::skip(50),
+            RowSelector::select(100),
+            RowSelector::skip(50),
+        ]);
+        assert_eq!(selection, expected);
+
+        // without the page index there is no selection
+        let options = ArrowReaderOptions::new().with_page_index_policy(PageIndexPolicy::Skip);
+        let builder = ParquetRecordBatchReaderBuilder::try_new_with_options(data, options).unwrap();
+        let pruner = StatisticsPruner::new(builder.metadata(), builder.schema())
+            .with_predicate(id(PruningOp::Eq, 5));
+        assert!(pruner.prune_pages(&[0]).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_with_pruning_predicates() {
+        let reader = builder(test_file())
+            .with_row_groups(vec![3, 0, 1])
+            .with_pruning_predicates([id(PruningOp::Gt, 95), id(PruningOp::Lt, 105)])
+            .unwrap()
+            .build()
+            .unwrap();
+        let batches = reader.collect::<std::result::Result<Vec<_>, _>>().unwrap();
+        let ids: Vec<_> = batches
+            .iter()
+            .flat_map(|b| b.column(0).as_primitive::<Int32Type>().values().to_vec())
+            .collect();
+        // the pages containing rows 90..110
+        assert_eq!(ids, (90..110).collect::<Vec<_>>());
+
+        let err = builder(test_file())
+            .with_row_selection(RowSelection::from(vec![RowSelector::select(400)]))
+            .with_pruning_predicates([id(PruningOp::Gt, 95)])
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: with_pruning_predicates must be called before with_row_selection"
+        );
+    }
+
+    #[test]
+    fn test_row_filter_pruning_predicates() {
+        let read_ids = |builder: ParquetRecordBatchReaderBuilder<Bytes>| {
+            // advertise `starts_with(name, 'a')` but keep all rows, so the output
+            // shows which rows were skipped using statistics
+            let projection = ProjectionMask::columns(builder.parquet_schema(), ["name"]);
+            let predicate = ArrowPredicateFn::new(projection, |batch| {
+                Ok(BooleanArray::from(vec![true; batch.num_rows()]))
+            })
+            .with_pruning_predicate(PruningPredicate::starts_with("name", "a"));
+            let reader = builder
+                .with_row_filter(RowFilter::new(vec![Box::new(predicate)]))
+                .build()
+                .unwrap();
+            let batches = reader.collect::<std::result::Result<Vec<_>, _>>().unwrap();
+            batches
+                .iter()
+                .flat_map(|b| b.column(0).as_primitive::<Int32Type>().values().to_vec())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            read_ids(builder(test_file())),
+            (150..250).collect::<Vec<_>>()
+        );
+
+        // an existing selection is restricted to the remaining row groups and pages
+        let builder = builder(test_file())
+            .with_row_groups(vec![2, 0, 1])
+            .with_row_selection(RowSelection::from(vec![
+                RowSelector::skip(20),
+                RowSelector::select(180),
+                RowSelector::skip(60),
+                RowSelector::select(40),
+            ]));
+        let expected: Vec<_> = (220..250).chain(160..200).collect();
+        assert_eq!(read_ids(builder), expected);
+    }
+}
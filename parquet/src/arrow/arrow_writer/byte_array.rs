@@ -16,7 +16,7 @@
 // under the License.
 
 use crate::basic::Encoding;
-use crate::bloom_filter::Sbbf;
+use crate::bloom_filter::{Sbbf, SbbfBuilder};
 use crate::column::writer::encoder::{
     ColumnValueEncoder, DataPageValues, DictionaryPage, create_bloom_filter,
 };
@@ -101,11 +101,24 @@ macro_rules! downcast_op {
     };
 }
 
+/// The number of values used to select the encoding when adaptive byte array
+/// encoding is enabled, see [`WriterProperties::adaptive_byte_array_encoding`]
+const ADAPTIVE_ENCODING_SAMPLE_SIZE: usize = 1024;
+
+/// The encodings considered when adaptive byte array encoding is enabled
+const ADAPTIVE_ENCODINGS: [Encoding; 3] = [
+    Encoding::PLAIN,
+    Encoding::DELTA_LENGTH_BYTE_ARRAY,
+    Encoding::DELTA_BYTE_ARRAY,
+];
+
 /// A fallback encoder, i.e. non-dictionary, for [`ByteArray`]
 struct FallbackEncoder {
     encoder: FallbackEncoderImpl,
     num_values: usize,
     variable_length_bytes: i64,
+    /// If the encoding should be selected from the first values encoded
+    adaptive: bool,
 }
 
 /// The fallback encoder in use
@@ -127,25 +140,15 @@ enum FallbackEncoderImpl {
     },
 }
 
-impl FallbackEncoder {
-    /// Create the fallback encoder for the given [`ColumnDescPtr`] and [`WriterProperties`]
-    fn new(descr: &ColumnDescPtr, props: &WriterProperties) -> Result<Self> {
-        // Set either main encoder or fallback encoder.
-        let encoding =
-            props
-                .encoding(descr.path())
-                .unwrap_or_else(|| match props.writer_version() {
-                    WriterVersion::PARQUET_1_0 => Encoding::PLAIN,
-                    WriterVersion::PARQUET_2_0 => Encoding::DELTA_BYTE_ARRAY,
-                });
-
-        let encoder = match encoding {
-            Encoding::PLAIN => FallbackEncoderImpl::Plain { buffer: vec![] },
-            Encoding::DELTA_LENGTH_BYTE_ARRAY => FallbackEncoderImpl::DeltaLength {
+impl FallbackEncoderImpl {
+    fn try_new(encoding: Encoding) -> Result<Self> {
+        Ok(match encoding {
+            Encoding::PLAIN => Self::Plain { buffer: vec![] },
+            Encoding::DELTA_LENGTH_BYTE_ARRAY => Self::DeltaLength {
                 buffer: vec![],
                 lengths: Box::new(DeltaBitPackEncoder::new()),
             },
-            Encoding::DELTA_BYTE_ARRAY => FallbackEncoderImpl::Delta {
+            Encoding::DELTA_BYTE_ARRAY => Self::Delta {
                 buffer: vec![],
                 last_value: vec![],
                 prefix_lengths: Box::new(DeltaBitPackEncoder::new()),
@@ -157,21 +160,69 @@ impl FallbackEncoder {
                     encoding
                 ));
             }
-        };
+        })
+    }
+}
+
+impl FallbackEncoder {
+    /// Create the fallback encoder for the given [`ColumnDescPtr`] and [`WriterProperties`]
+    fn new(descr: &ColumnDescPtr, props: &WriterProperties) -> Result<Self> {
+        // Set either main encoder or fallback encoder.
+        let encoding =
+            props
+                .encoding(descr.path())
+                .unwrap_or_else(|| match props.writer_version() {
+                    WriterVersion::PARQUET_1_0 => Encoding::PLAIN,
+                    WriterVersion::PARQUET_2_0 => Encoding::DELTA_BYTE_ARRAY,
+                });
 
         Ok(Self {
-            encoder,
+            encoder: FallbackEncoderImpl::try_new(encoding)?,
             num_values: 0,
             variable_length_bytes: 0,
+            adaptive: props.adaptive_byte_array_encoding(descr.path()),
         })
     }
 
+    /// Returns the encoding with the smallest encoded size for `indices`
+    fn select_encoding<T>(values: T, indices: &[usize]) -> Encoding
+    where
+        T: ArrayAccessor + Copy,
+        T::Item: AsRef<[u8]>,
+    {
+        let encoded_size = |encoding| {
+            let mut encoder = Self {
+                encoder: FallbackEncoderImpl::try_new(encoding).ok()?,
+                num_values: 0,
+                variable_length_bytes: 0,
+                adaptive: false,
+            };
+            encoder.encode(values, indices.iter().copied());
+            let page = encoder.flush_data_page(None, None).ok()?;
+            Some(page.buf.len())
+        };
+        ADAPTIVE_ENCODINGS
+            .into_iter()
+            .min_by_key(|encoding| encoded_size(*encoding).unwrap_or(usize::MAX))
+            .unwrap()
+    }
+
     /// Encode `values` to the in-progress page
     fn encode<T>(&mut self, values: T, indices: impl ExactSizeIterator<Item = usize>)
     where
         T: ArrayAccessor + Copy,
         T::Item: AsRef<[u8]>,
     {
+        if self.adaptive && indices.len() != 0 {
+            self.adaptive = false;
+            let indices: Vec<_> = indices.collect();
+            let sample = &indices[..indices.len().min(ADAPTIVE_ENCODING_SAMPLE_SIZE)];
+            let encoding = Self::select_encoding(values, sample);
+            // the candidate encodings are all supported
+            self.encoder = FallbackEncoderImpl::try_new(encoding).unwrap();
+            return self.encode(values, indices.into_iter());
+        }
+
         self.num_values += indices.len();
         match &mut self.encoder {
             FallbackEncoderImpl::Plain { buffer } => {
@@ -426,8 +477,7 @@ pub struct ByteArrayEncoder {
     statistics_enabled: EnabledStatistics,
     min_value: Option<ByteArray>,
     max_value: Option<ByteArray>,
-    bloom_filter: Option<Sbbf>,
-    bloom_filter_target_fpp: f64,
+    bloom_filter: Option<SbbfBuilder>,
     geo_stats_accumulator: Option<Box<dyn GeoStatsAccumulator>>,
 }
 
@@ -435,9 +485,7 @@ impl ColumnValueEncoder for ByteArrayEncoder {
     type T = ByteArray;
     type Values = dyn Array;
     fn flush_bloom_filter(&mut self) -> Option<Sbbf> {
-        let mut sbbf = self.bloom_filter.take()?;
-        sbbf.fold_to_target_fpp(self.bloom_filter_target_fpp);
-        Some(sbbf)
+        self.bloom_filter.take().map(SbbfBuilder::finish)
     }
 
     fn try_new(descr: &ColumnDescPtr, props: &WriterProperties) -> Result<Self>
@@ -450,7 +498,7 @@ impl ColumnValueEncoder for ByteArrayEncoder {
 
         let fallback = FallbackEncoder::new(descr, props)?;
 
-        let (bloom_filter, bloom_filter_target_fpp) = create_bloom_filter(props, descr)?;
+        let bloom_filter = create_bloom_filter(props, descr)?;
 
         let statistics_enabled = props.statistics_enabled(descr.path());
 
@@ -460,7 +508,6 @@ impl ColumnValueEncoder for ByteArrayEncoder {
             fallback,
             statistics_enabled,
             bloom_filter,
-            bloom_filter_target_fpp,
             dict_encoder: dictionary,
             min_value: None,
             max_value: None,
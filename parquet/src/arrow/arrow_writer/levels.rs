@@ -45,13 +45,60 @@ use crate::column::writer::LevelDataRef;
 use crate::errors::{ParquetError, Result};
 use arrow_array::cast::AsArray;
 use arrow_array::types::RunEndIndexType;
-use arrow_array::{Array, ArrayRef, Int32Array, OffsetSizeTrait, RunArray, downcast_run_array};
+use arrow_array::{
+    Array, ArrayRef, DictionaryArray, Int32Array, OffsetSizeTrait, RunArray, downcast_run_array,
+};
 use arrow_buffer::bit_iterator::BitIndexIterator;
-use arrow_buffer::{NullBuffer, OffsetBuffer, ScalarBuffer};
+use arrow_buffer::{ArrowNativeType, NullBuffer, OffsetBuffer, ScalarBuffer};
 use arrow_schema::{DataType, Field};
 use std::ops::Range;
 use std::sync::Arc;
 
+/// Converts a [`DataType::RunEndEncoded`] array with leaf values into a
+/// [`DataType::Dictionary`] array, with each run becoming a repeated key into the
+/// run values.
+///
+/// This avoids materializing the values, allowing byte array columns to be
+/// dictionary encoded directly from the runs. Returns `None` if the values cannot
+/// be written as a dictionary, in which case [`expand_ree_array`] should be used.
+fn ree_to_dictionary(array: &ArrayRef) -> Option<ArrayRef> {
+    downcast_run_array!(
+        array => typed_ree_to_dictionary(array),
+        _ => unreachable!("ree_to_dictionary called on non-REE array"),
+    )
+}
+
+fn typed_ree_to_dictionary<R: RunEndIndexType>(run_array: &RunArray<R>) -> Option<ArrayRef> {
+    let values = run_array.values();
+    match values.data_type() {
+        // Dictionaries of view types are not supported by the byte array writer
+        DataType::Utf8View | DataType::BinaryView => return None,
+        d if !is_leaf(d) => return None,
+        _ => {}
+    }
+    if values.len() > i32::MAX as usize {
+        return None;
+    }
+
+    let run_ends = run_array.run_ends();
+    let (offset, len) = (run_ends.offset(), run_ends.len());
+    let mut keys = Vec::with_capacity(len);
+    if len != 0 {
+        let start = run_ends.get_start_physical_index();
+        let end = run_ends.get_end_physical_index();
+        for (physical, run_end) in run_ends.values()[start..=end].iter().enumerate() {
+            let run_end = (run_end.as_usize() - offset).min(len);
+            let run_len = run_end - keys.len();
+            keys.extend(std::iter::repeat_n((start + physical) as i32, run_len));
+        }
+    }
+
+    let keys = Int32Array::from(keys);
+    // Safety: every key is a valid physical index into `values`
+    let dictionary = unsafe { DictionaryArray::new_unchecked(keys, values.clone()) };
+    Some(Arc::new(dictionary))
+}
+
 /// Expands a [`DataType::RunEndEncoded`] array into a flat (logical) array of its values type.
 ///
 /// use `arrow_select::take` to materialize the  full-length flat array.
@@ -209,7 +256,10 @@ impl LevelInfoBuilder {
                 Ok(Self::Primitive(levels))
             }
             DataType::RunEndEncoded(_, value_field) => {
-                let flat = expand_ree_array(array)?;
+                let flat = match ree_to_dictionary(array) {
+                    Some(dictionary) => dictionary,
+                    None => expand_ree_array(array)?,
+                };
                 let flat_field = Field::new(
                     field.name(),
                     value_field.data_type().clone(),
@@ -29,7 +29,7 @@ use arrow_array::cast::AsArray;
 use arrow_array::types::*;
 use arrow_array::{ArrayRef, Int32Array, RecordBatch, RecordBatchWriter};
 use arrow_schema::{
-    ArrowError, DataType as ArrowDataType, Field, IntervalUnit, SchemaRef, TimeUnit,
+    ArrowError, DataType as ArrowDataType, Field, IntervalUnit, Schema, SchemaRef, TimeUnit,
 };
 
 use super::schema::{add_encoded_arrow_schema_to_metadata, decimal_length_from_precision};
@@ -53,9 +53,16 @@ use crate::file::writer::{SerializedFileWriter, SerializedRowGroupWriter};
 use crate::parquet_thrift::{ThriftCompactOutputProtocol, WriteThrift};
 use crate::schema::types::{ColumnDescPtr, SchemaDescPtr, SchemaDescriptor};
 use levels::{ArrayLevels, calculate_array_levels};
+use sorting::SortingColumnsValidator;
 
 mod byte_array;
 mod levels;
+mod row;
+mod sorting;
+#[cfg(feature = "variant_experimental")]
+mod variant;
+
+pub use row::ArrowRowWriter;
 
 #[doc(inline)]
 pub use crate::column::page_store::{
@@ -127,9 +134,11 @@ pub use crate::column::page_store::{
 /// The writer supports writing all Arrow [`DataType`]s that have a direct mapping to
 /// Parquet types including  [`StructArray`] and [`ListArray`].
 ///
-/// The following are not supported:
-///
-/// * [`IntervalMonthDayNanoArray`]: Parquet does not [support nanosecond intervals].
+/// Note that Parquet does not [support nanosecond intervals], and so the nanoseconds of an
+/// [`IntervalMonthDayNanoArray`] are truncated to milliseconds when written. An error is
+/// returned if the number of milliseconds does not fit in 32 bits. Such columns are read
+/// back as [`IntervalMonthDayNanoArray`] using the embedded Arrow schema; without it,
+/// Parquet INTERVAL columns are read as `IntervalDayTime`, ignoring the months.
 ///
 /// [`DataType`]: https://docs.rs/arrow/latest/arrow/datatypes/enum.DataType.html
 /// [`StructArray`]: https://docs.rs/arrow/latest/arrow/array/struct.StructArray.html
@@ -202,6 +211,13 @@ pub struct ArrowWriter<W: Write> {
 
     /// CDC chunkers persisted across row groups (one per leaf column).
     cdc_chunkers: Option<Vec<ContentDefinedChunker>>,
+
+    /// Checks the rows are sorted by the sorting columns, if enabled
+    sorting_validator: Option<SortingColumnsValidator>,
+
+    /// Shreds the Variant columns of each batch written, if configured
+    #[cfg(feature = "variant_experimental")]
+    variant_shredder: Option<variant::VariantShredder>,
 }
 
 impl<W: Write + Send> std::fmt::Debug for ArrowWriter<W> {
@@ -245,6 +261,15 @@ impl<W: Write + Send> ArrowWriter<W> {
     ) -> Result<Self> {
         let mut props = options.properties;
 
+        #[cfg(feature = "variant_experimental")]
+        let variant_shredder =
+            variant::VariantShredder::try_new(&arrow_schema, &options.variant_shredding)?;
+        #[cfg(feature = "variant_experimental")]
+        let arrow_schema = match &variant_shredder {
+            Some(shredder) => shredder.schema().clone(),
+            None => arrow_schema,
+        };
+
         let schema = if let Some(parquet_schema) = options.schema_descr {
             parquet_schema.clone()
         } else {
@@ -256,6 +281,10 @@ impl<W: Write + Send> ArrowWriter<W> {
             converter.convert(&arrow_schema)?
         };
 
+        if !options.skip_dictionary_hints {
+            add_dictionary_hints(&arrow_schema, &schema, &mut props);
+        }
+
         if !options.skip_arrow_metadata {
             // add serialized arrow schema
             add_encoded_arrow_schema_to_metadata(&arrow_schema, &mut props);
@@ -264,6 +293,12 @@ impl<W: Write + Send> ArrowWriter<W> {
         let max_row_group_row_count = props.max_row_group_row_count();
         let max_row_group_bytes = props.max_row_group_bytes();
 
+        let sorting_validator = props
+            .sorting_columns()
+            .filter(|_| props.validate_sorting_columns())
+            .map(|columns| SortingColumnsValidator::try_new(&schema, columns))
+            .transpose()?;
+
         let props_ptr = Arc::new(props);
         let file_writer =
             SerializedFileWriter::new(writer, schema.root_schema_ptr(), Arc::clone(&props_ptr))?;
@@ -295,6 +330,9 @@ impl<W: Write + Send> ArrowWriter<W> {
             max_row_group_row_count,
             max_row_group_bytes,
             cdc_chunkers,
+            sorting_validator,
+            #[cfg(feature = "variant_experimental")]
+            variant_shredder,
         })
     }
 
@@ -356,37 +394,79 @@ impl<W: Write + Send> ArrowWriter<W> {
     ///
     /// This will fail if the `batch`'s schema does not match the writer's schema.
     pub fn write(&mut self, batch: &RecordBatch) -> Result<()> {
-        if batch.num_rows() == 0 {
-            return Ok(());
+        let batch = self.shred(batch)?;
+        let mut offset = 0;
+        while offset < batch.num_rows() {
+            match self.next_write_step(batch.num_rows() - offset)? {
+                WriteStep::Flush => self.flush()?,
+                WriteStep::Encode(len) => {
+                    let batch = batch.slice(offset, len);
+                    if let Some(validator) = self.sorting_validator.as_mut() {
+                        validator.validate(&batch)?;
+                    }
+
+                    let in_progress = match &mut self.in_progress {
+                        Some(in_progress) => in_progress,
+                        None => return Err(general_err!("no row group in progress")),
+                    };
+                    match self.cdc_chunkers.as_mut() {
+                        Some(chunkers) => in_progress.write_with_chunkers(&batch, chunkers)?,
+                        None => in_progress.write(&batch)?,
+                    }
+
+                    if self.in_progress_full() {
+                        self.flush()?;
+                    }
+                    offset += len;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns `batch` with any Variant columns shredded
+    pub(crate) fn shred(&self, batch: &RecordBatch) -> Result<RecordBatch> {
+        #[cfg(feature = "variant_experimental")]
+        if let Some(shredder) = &self.variant_shredder {
+            return shredder.shred(batch);
         }
+        Ok(batch.clone())
+    }
 
-        let in_progress = match &mut self.in_progress {
-            Some(in_progress) => in_progress,
-            x => x.insert(
+    /// Returns the in progress row group, creating it if necessary
+    fn in_progress_row_group(&mut self) -> Result<&mut ArrowRowGroupWriter> {
+        match &mut self.in_progress {
+            Some(in_progress) => Ok(in_progress),
+            x => Ok(x.insert(
                 self.row_group_writer_factory
                     .create_row_group_writer(self.writer.flushed_row_groups().len())?,
-            ),
-        };
+            )),
+        }
+    }
 
-        if let Some(max_rows) = self.max_row_group_row_count {
-            if in_progress.buffered_rows + batch.num_rows() > max_rows {
-                let to_write = max_rows - in_progress.buffered_rows;
-                let a = batch.slice(0, to_write);
-                let b = batch.slice(to_write, batch.num_rows() - to_write);
-                self.write(&a)?;
-                return self.write(&b);
-            }
+    /// Returns the next [`WriteStep`] to write `num_rows` rows of a batch
+    ///
+    /// The rows are split such that each row group respects
+    /// [`WriterProperties::max_row_group_row_count`] and
+    /// [`WriterProperties::max_row_group_bytes`], see [`Self::write`]
+    pub(crate) fn next_write_step(&mut self, num_rows: usize) -> Result<WriteStep> {
+        let max_row_group_row_count = self.max_row_group_row_count;
+        let max_row_group_bytes = self.max_row_group_bytes;
+        let in_progress = self.in_progress_row_group()?;
+
+        let mut len = num_rows;
+        if let Some(max_rows) = max_row_group_row_count {
+            len = len.min(max_rows - in_progress.buffered_rows);
         }
 
         // Check byte limit: if we have buffered data, use measured average row size
         // to split batch proactively before exceeding byte limit
-        if let Some(max_bytes) = self.max_row_group_bytes {
+        if let Some(max_bytes) = max_row_group_bytes {
             if in_progress.buffered_rows > 0 {
                 let current_bytes = in_progress.get_estimated_total_bytes();
 
                 if current_bytes >= max_bytes {
-                    self.flush()?;
-                    return self.write(batch);
+                    return Ok(WriteStep::Flush);
                 }
 
                 let avg_row_bytes = current_bytes / in_progress.buffered_rows;
@@ -395,35 +475,84 @@ impl<W: Write + Send> ArrowWriter<W> {
                     let remaining_bytes = max_bytes - current_bytes;
                     let rows_that_fit = remaining_bytes / avg_row_bytes;
 
-                    if batch.num_rows() > rows_that_fit {
-                        if rows_that_fit > 0 {
-                            let a = batch.slice(0, rows_that_fit);
-                            let b = batch.slice(rows_that_fit, batch.num_rows() - rows_that_fit);
-                            self.write(&a)?;
-                            return self.write(&b);
-                        } else {
-                            self.flush()?;
-                            return self.write(batch);
+                    if len > rows_that_fit {
+                        if rows_that_fit == 0 {
+                            return Ok(WriteStep::Flush);
                         }
+                        len = rows_that_fit;
                     }
                 }
             }
         }
+        Ok(WriteStep::Encode(len))
+    }
 
-        match self.cdc_chunkers.as_mut() {
-            Some(chunkers) => in_progress.write_with_chunkers(batch, chunkers)?,
-            None => in_progress.write(batch)?,
-        }
-
-        let should_flush = self
-            .max_row_group_row_count
+    /// Returns true if the in progress row group has reached
+    /// [`WriterProperties::max_row_group_row_count`] or
+    /// [`WriterProperties::max_row_group_bytes`] and should be flushed
+    pub(crate) fn in_progress_full(&self) -> bool {
+        let Some(in_progress) = &self.in_progress else {
+            return false;
+        };
+        self.max_row_group_row_count
             .is_some_and(|max| in_progress.buffered_rows >= max)
             || self
                 .max_row_group_bytes
-                .is_some_and(|max| in_progress.get_estimated_total_bytes() >= max);
+                .is_some_and(|max| in_progress.get_estimated_total_bytes() >= max)
+    }
+
+    /// Moves the column writers of the in progress row group, together with the
+    /// leaf columns of `batch` to encode, out of this writer
+    ///
+    /// This allows encoding the columns elsewhere, e.g. in parallel, after which
+    /// they must be returned with [`Self::return_columns`]. `batch` must have been
+    /// sized by [`Self::next_write_step`].
+    #[cfg(feature = "async")]
+    pub(crate) fn take_columns(&mut self, batch: &RecordBatch) -> Result<Vec<EncodeColumn>> {
+        if let Some(validator) = self.sorting_validator.as_mut() {
+            validator.validate(batch)?;
+        }
 
-        if should_flush {
-            self.flush()?
+        let in_progress = match &mut self.in_progress {
+            Some(in_progress) => in_progress,
+            None => return Err(general_err!("no row group in progress")),
+        };
+        let leaves = in_progress.compute_leaves(batch)?;
+        if in_progress.writers.len() != leaves.len() {
+            return Err(general_err!(
+                "the columns of the in progress row group have not been returned"
+            ));
+        }
+        in_progress.buffered_rows += batch.num_rows();
+
+        let writers = std::mem::take(&mut in_progress.writers);
+        let mut chunkers = self
+            .cdc_chunkers
+            .as_mut()
+            .map(|c| std::mem::take(c).into_iter());
+        Ok(writers
+            .into_iter()
+            .zip(leaves)
+            .map(|(writer, leaf)| EncodeColumn {
+                writer,
+                leaf,
+                chunker: chunkers.as_mut().and_then(Iterator::next),
+            })
+            .collect())
+    }
+
+    /// Returns the columns taken by [`Self::take_columns`] once encoded
+    #[cfg(feature = "async")]
+    pub(crate) fn return_columns(&mut self, columns: Vec<EncodeColumn>) -> Result<()> {
+        let in_progress = match &mut self.in_progress {
+            Some(in_progress) => in_progress,
+            None => return Err(general_err!("no row group in progress")),
+        };
+        for column in columns {
+            in_progress.writers.push(column.writer);
+            if let (Some(chunkers), Some(chunker)) = (&mut self.cdc_chunkers, column.chunker) {
+                chunkers.push(chunker);
+            }
         }
         Ok(())
     }
@@ -446,13 +575,32 @@ impl<W: Write + Send> ArrowWriter<W> {
     /// Note the underlying writer is not flushed with this call.
     /// If this is a desired behavior, please call [`ArrowWriter::sync`].
     pub fn flush(&mut self) -> Result<()> {
-        let in_progress = match self.in_progress.take() {
-            Some(in_progress) => in_progress,
+        let writers = match self.take_row_group() {
+            Some(writers) => writers,
             None => return Ok(()),
         };
+        let chunks = writers
+            .into_iter()
+            .map(|writer| writer.close())
+            .collect::<Result<Vec<_>>>()?;
+        self.write_row_group(chunks)
+    }
+
+    /// Moves the column writers of the in progress row group, if any, out of this writer
+    ///
+    /// Once closed, the resulting chunks must be written with [`Self::write_row_group`]
+    pub(crate) fn take_row_group(&mut self) -> Option<Vec<ArrowColumnWriter>> {
+        let in_progress = self.in_progress.take()?;
+        if let Some(validator) = self.sorting_validator.as_mut() {
+            validator.reset();
+        }
+        Some(in_progress.writers)
+    }
 
+    /// Writes the chunks of the columns taken by [`Self::take_row_group`] as a new row group
+    pub(crate) fn write_row_group(&mut self, chunks: Vec<ArrowColumnChunk>) -> Result<()> {
         let mut row_group_writer = self.writer.next_row_group()?;
-        for chunk in in_progress.close()? {
+        for chunk in chunks {
             chunk.append_to_row_group(&mut row_group_writer)?;
         }
         row_group_writer.close()?;
@@ -466,6 +614,13 @@ impl<W: Write + Send> ArrowWriter<W> {
         self.writer.append_key_value_metadata(kv_metadata)
     }
 
+    /// Writes `data` as the index blob `name`, see [`SerializedFileWriter::write_index_blob`]
+    ///
+    /// Any buffered rows are written to the file after the blob.
+    pub fn write_index_blob(&mut self, name: &str, data: &[u8]) -> Result<()> {
+        self.writer.write_index_blob(name, data)
+    }
+
     /// Returns a reference to the underlying writer.
     pub fn inner(&self) -> &W {
         self.writer.inner()
@@ -556,6 +711,34 @@ impl<W: Write + Send> RecordBatchWriter for ArrowWriter<W> {
     }
 }
 
+/// The next step to write the rows of a batch, see [`ArrowWriter::next_write_step`]
+pub(crate) enum WriteStep {
+    /// Flush the in progress row group
+    Flush,
+    /// Encode this many rows into the in progress row group
+    Encode(usize),
+}
+
+/// A leaf column to encode with its column writer, see [`ArrowWriter::take_columns`]
+#[cfg(feature = "async")]
+pub(crate) struct EncodeColumn {
+    writer: ArrowColumnWriter,
+    leaf: ArrowLeafColumn,
+    chunker: Option<ContentDefinedChunker>,
+}
+
+#[cfg(feature = "async")]
+impl EncodeColumn {
+    /// Encodes the leaf column
+    pub(crate) fn encode(mut self) -> Result<Self> {
+        match &mut self.chunker {
+            Some(chunker) => self.writer.write_with_chunker(&self.leaf, chunker)?,
+            None => self.writer.write(&self.leaf)?,
+        }
+        Ok(self)
+    }
+}
+
 /// Arrow-specific configuration settings for writing parquet files.
 ///
 /// See [`ArrowWriter`] for how to configure the writer.
@@ -563,9 +746,12 @@ impl<W: Write + Send> RecordBatchWriter for ArrowWriter<W> {
 pub struct ArrowWriterOptions {
     properties: WriterProperties,
     skip_arrow_metadata: bool,
+    skip_dictionary_hints: bool,
     schema_root: Option<String>,
     schema_descr: Option<SchemaDescriptor>,
     page_store_factory: Option<Arc<dyn PageStoreFactory>>,
+    #[cfg(feature = "variant_experimental")]
+    variant_shredding: std::collections::HashMap<String, ArrowDataType>,
 }
 
 impl ArrowWriterOptions {
@@ -684,6 +870,27 @@ impl ArrowWriterOptions {
         }
     }
 
+    /// Skip enabling dictionary encoding for dictionary columns (defaults to `false`)
+    ///
+    /// By default, leaf columns with a [`DataType::Dictionary`] or
+    /// [`DataType::RunEndEncoded`] Arrow type are dictionary encoded, unless
+    /// [`WriterPropertiesBuilder::set_column_dictionary_enabled`] has been
+    /// explicitly called for that column. This takes precedence over
+    /// [`WriterPropertiesBuilder::set_dictionary_enabled`].
+    ///
+    /// Set `skip_dictionary_hints` to true to only use the [`WriterProperties`].
+    ///
+    /// [`DataType::Dictionary`]: ArrowDataType::Dictionary
+    /// [`DataType::RunEndEncoded`]: ArrowDataType::RunEndEncoded
+    /// [`WriterPropertiesBuilder::set_column_dictionary_enabled`]: crate::file::properties::WriterPropertiesBuilder::set_column_dictionary_enabled
+    /// [`WriterPropertiesBuilder::set_dictionary_enabled`]: crate::file::properties::WriterPropertiesBuilder::set_dictionary_enabled
+    pub fn with_skip_dictionary_hints(self, skip_dictionary_hints: bool) -> Self {
+        Self {
+            skip_dictionary_hints,
+            ..self
+        }
+    }
+
     /// Set the name of the root parquet schema element (defaults to `"arrow_schema"`)
     pub fn with_schema_root(self, schema_root: String) -> Self {
         Self {
@@ -703,6 +910,34 @@ impl ArrowWriterOptions {
             ..self
         }
     }
+
+    /// Shred the top level Variant column `name` with the shredding type `as_type`
+    ///
+    /// Each batch written is shredded with [`shred_variant`], storing the values
+    /// matching `as_type` in typed `typed_value` columns, which readers can use
+    /// for statistics and projection, and the remainder in the `value` column.
+    /// The column must be unshredded in the written batches, and is shredded
+    /// in the schema of the file. Readers unaware of shredding can still read
+    /// the full Variant values, see [`ArrowReaderOptions::with_unshred_variants`].
+    ///
+    /// See [`ShreddedSchemaBuilder`] to build `as_type`, or [`infer_shredding_type`]
+    /// to infer it from a sample of the data.
+    ///
+    /// Requires the `variant_experimental` feature.
+    ///
+    /// [`shred_variant`]: crate::variant::shred_variant
+    /// [`ShreddedSchemaBuilder`]: crate::variant::ShreddedSchemaBuilder
+    /// [`infer_shredding_type`]: crate::variant::infer_shredding_type
+    /// [`ArrowReaderOptions::with_unshred_variants`]: crate::arrow::arrow_reader::ArrowReaderOptions::with_unshred_variants
+    #[cfg(feature = "variant_experimental")]
+    pub fn with_variant_shredding(
+        mut self,
+        name: impl Into<String>,
+        as_type: ArrowDataType,
+    ) -> Self {
+        self.variant_shredding.insert(name.into(), as_type);
+        self
+    }
 }
 
 /// A single column chunk produced by [`ArrowColumnWriter`].
@@ -1234,13 +1469,20 @@ impl ArrowRowGroupWriter {
         }
     }
 
+    /// Computes the leaf columns of `batch`, in the order of [`Self::writers`]
+    fn compute_leaves(&self, batch: &RecordBatch) -> Result<Vec<ArrowLeafColumn>> {
+        let mut leaves = Vec::with_capacity(self.writers.len());
+        for (field, column) in self.schema.fields().iter().zip(batch.columns()) {
+            leaves.extend(compute_leaves(field.as_ref(), column)?);
+        }
+        Ok(leaves)
+    }
+
     fn write(&mut self, batch: &RecordBatch) -> Result<()> {
+        let leaves = self.compute_leaves(batch)?;
         self.buffered_rows += batch.num_rows();
-        let mut writers = self.writers.iter_mut();
-        for (field, column) in self.schema.fields().iter().zip(batch.columns()) {
-            for leaf in compute_leaves(field.as_ref(), column)? {
-                writers.next().unwrap().write(&leaf)?;
-            }
+        for (writer, leaf) in self.writers.iter_mut().zip(leaves) {
+            writer.write(&leaf)?;
         }
         Ok(())
     }
@@ -1250,16 +1492,11 @@ impl ArrowRowGroupWriter {
         batch: &RecordBatch,
         chunkers: &mut [ContentDefinedChunker],
     ) -> Result<()> {
+        let leaves = self.compute_leaves(batch)?;
         self.buffered_rows += batch.num_rows();
-        let mut writers = self.writers.iter_mut();
-        let mut chunkers = chunkers.iter_mut();
-        for (field, column) in self.schema.fields().iter().zip(batch.columns()) {
-            for leaf in compute_leaves(field.as_ref(), column)? {
-                writers
-                    .next()
-                    .unwrap()
-                    .write_with_chunker(&leaf, chunkers.next().unwrap())?;
-            }
+        let columns = self.writers.iter_mut().zip(leaves).zip(chunkers);
+        for ((writer, leaf), chunker) in columns {
+            writer.write_with_chunker(&leaf, chunker)?;
         }
         Ok(())
     }
@@ -1271,13 +1508,6 @@ impl ArrowRowGroupWriter {
             .map(|x| x.get_estimated_total_bytes())
             .sum()
     }
-
-    fn close(self) -> Result<Vec<ArrowColumnChunk>> {
-        self.writers
-            .into_iter()
-            .map(|writer| writer.close())
-            .collect()
-    }
 }
 
 /// Factory that creates new column writers for each row group in the Parquet file.
@@ -1535,6 +1765,44 @@ impl ArrowColumnWriterFactory {
     }
 }
 
+/// Enables dictionary encoding for the leaf columns of `parquet` whose Arrow type
+/// in `arrow` is a dictionary or run-end encoded
+fn add_dictionary_hints(arrow: &Schema, parquet: &SchemaDescriptor, props: &mut WriterProperties) {
+    fn visit(data_type: &ArrowDataType, hinted: bool, out: &mut Vec<bool>) {
+        match data_type {
+            ArrowDataType::List(f)
+            | ArrowDataType::LargeList(f)
+            | ArrowDataType::FixedSizeList(f, _)
+            | ArrowDataType::ListView(f)
+            | ArrowDataType::LargeListView(f) => visit(f.data_type(), false, out),
+            ArrowDataType::Struct(fields) => {
+                for field in fields {
+                    visit(field.data_type(), false, out)
+                }
+            }
+            ArrowDataType::Map(f, _) => visit(f.data_type(), false, out),
+            ArrowDataType::Dictionary(_, v) => visit(v, true, out),
+            ArrowDataType::RunEndEncoded(_, v) => visit(v.data_type(), true, out),
+            _ => out.push(hinted),
+        }
+    }
+
+    let mut hints = Vec::with_capacity(parquet.num_columns());
+    for field in arrow.fields() {
+        visit(field.data_type(), false, &mut hints);
+    }
+    // A user-provided parquet schema may not line up with the arrow schema,
+    // in which case the error is reported when writing
+    if hints.len() != parquet.num_columns() {
+        return;
+    }
+    for (column, hinted) in parquet.columns().iter().zip(hints) {
+        if hinted {
+            props.set_column_dictionary_hint(column.path().clone());
+        }
+    }
+}
+
 fn write_leaf(
     writer: &mut ColumnWriter<'_>,
     column: &dyn arrow_array::Array,
@@ -1750,10 +2018,9 @@ fn write_leaf(
                         let array = column.as_primitive::<IntervalDayTimeType>();
                         get_interval_dt_array_slice(array, indices.iter().copied())
                     }
-                    _ => {
-                        return Err(ParquetError::NYI(format!(
-                            "Attempting to write an Arrow interval type {interval_unit:?} to parquet that is not yet implemented"
-                        )));
+                    IntervalUnit::MonthDayNano => {
+                        let array = column.as_primitive::<IntervalMonthDayNanoType>();
+                        get_interval_mdn_array_slice(array, indices.iter().copied())?
                     }
                 },
                 ArrowDataType::FixedSizeBinary(_) => {
@@ -1859,6 +2126,30 @@ fn get_interval_dt_array_slice(
     values
 }
 
+/// Returns 12-byte values representing 3 values of months, days and milliseconds (4-bytes each).
+/// The nanoseconds of an Arrow MonthDayNano interval are truncated to milliseconds.
+fn get_interval_mdn_array_slice(
+    array: &arrow_array::IntervalMonthDayNanoArray,
+    indices: impl ExactSizeIterator<Item = usize>,
+) -> Result<Vec<FixedLenByteArray>> {
+    let mut values = Vec::with_capacity(indices.len());
+    for i in indices {
+        let mut out = [0; 12];
+        let value = array.value(i);
+        let millis = i32::try_from(value.nanoseconds / 1_000_000).map_err(|_| {
+            general_err!(
+                "Interval of {} nanoseconds cannot be represented as 32-bit milliseconds",
+                value.nanoseconds
+            )
+        })?;
+        out[0..4].copy_from_slice(&value.months.to_le_bytes());
+        out[4..8].copy_from_slice(&value.days.to_le_bytes());
+        out[8..12].copy_from_slice(&millis.to_le_bytes());
+        values.push(FixedLenByteArray::from(ByteArray::from(out.to_vec())));
+    }
+    Ok(values)
+}
+
 fn get_decimal_32_array_slice(
     array: &arrow_array::Decimal32Array,
     indices: impl ExactSizeIterator<Item = usize>,
@@ -3231,6 +3522,15 @@ mod tests {
             DataType::Float32 | DataType::Float64 => {
                 vec![Encoding::PLAIN, Encoding::BYTE_STREAM_SPLIT]
             }
+            // stored as FIXED_LEN_BYTE_ARRAY, or INT32/INT64 for smaller decimals
+            DataType::Float16
+            | DataType::FixedSizeBinary(_)
+            | DataType::Decimal32(_, _)
+            | DataType::Decimal64(_, _)
+            | DataType::Decimal128(_, _)
+            | DataType::Decimal256(_, _) => {
+                vec![Encoding::PLAIN, Encoding::BYTE_STREAM_SPLIT]
+            }
             _ => vec![Encoding::PLAIN],
         };
 
@@ -3575,18 +3875,47 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(
-        expected = "Attempting to write an Arrow interval type MonthDayNano to parquet that is not yet implemented"
-    )]
     fn interval_month_day_nano_single_column() {
         required_and_optional::<IntervalMonthDayNanoArray, _>(vec![
-            IntervalMonthDayNano::new(0, 1, 5),
-            IntervalMonthDayNano::new(0, 3, 2),
-            IntervalMonthDayNano::new(3, -2, -5),
-            IntervalMonthDayNano::new(-200, 4, -1),
+            IntervalMonthDayNano::new(0, 1, 5_000_000),
+            IntervalMonthDayNano::new(0, 3, 2_000_000),
+            IntervalMonthDayNano::new(3, -2, -5_000_000),
+            IntervalMonthDayNano::new(-200, 4, -1_000_000),
         ]);
     }
 
+    #[test]
+    fn interval_month_day_nano_truncation() {
+        let array = IntervalMonthDayNanoArray::from(vec![
+            IntervalMonthDayNano::new(1, 2, 5_999_999),
+            IntervalMonthDayNano::new(-1, -2, -5_999_999),
+        ]);
+        let batch = RecordBatch::try_from_iter([("a", Arc::new(array) as ArrayRef)]).unwrap();
+        let file = tempfile::tempfile().unwrap();
+        let mut writer = ArrowWriter::try_new(file.try_clone().unwrap(), batch.schema(), None)
+            .expect("Unable to write file");
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let mut reader = ParquetRecordBatchReader::try_new(file, 1024).unwrap();
+        let read = reader.next().unwrap().unwrap();
+        let expected = IntervalMonthDayNanoArray::from(vec![
+            IntervalMonthDayNano::new(1, 2, 5_000_000),
+            IntervalMonthDayNano::new(-1, -2, -5_000_000),
+        ]);
+        assert_eq!(read.column(0).as_ref(), &expected as &dyn Array);
+
+        let array =
+            IntervalMonthDayNanoArray::from(vec![IntervalMonthDayNano::new(0, 0, i64::MAX)]);
+        let batch = RecordBatch::try_from_iter([("a", Arc::new(array) as ArrayRef)]).unwrap();
+        let mut writer = ArrowWriter::try_new(vec![], batch.schema(), None).unwrap();
+        let err = writer.write(&batch).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Interval of 9223372036854775807 nanoseconds cannot be represented as 32-bit milliseconds"
+        );
+    }
+
     #[test]
     fn binary_single_column() {
         let one_vec: Vec<u8> = (0..SMALL_SIZE as u8).collect();
@@ -3673,6 +4002,69 @@ mod tests {
         );
     }
 
+    #[test]
+    fn nested_column_bloom_filter() {
+        let ints = Int32Array::from_iter_values(0..100);
+        let strings = StringArray::from_iter_values((0..100).map(|i| format!("s{i}")));
+        let structs = StructArray::from(vec![
+            (
+                Arc::new(Field::new("a", DataType::Int32, false)),
+                Arc::new(ints.clone()) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("b", DataType::Utf8, false)),
+                Arc::new(strings) as ArrayRef,
+            ),
+        ]);
+        let offsets = OffsetBuffer::from_lengths(std::iter::repeat_n(1, 100));
+        let field = Arc::new(Field::new_list_field(DataType::Int32, false));
+        let list = ListArray::new(field, offsets, Arc::new(ints), None);
+        let batch = RecordBatch::try_from_iter([
+            ("s", Arc::new(structs) as ArrayRef),
+            ("l", Arc::new(list) as ArrayRef),
+        ])
+        .unwrap();
+
+        for adaptive in [false, true] {
+            let props = WriterProperties::builder()
+                .set_column_bloom_filter_enabled("s".into(), true)
+                .set_column_bloom_filter_fpp(ColumnPath::from(vec!["s".into(), "b".into()]), 0.01)
+                .set_column_bloom_filter_adaptive("l".into(), adaptive)
+                .build();
+            let mut buf = Vec::new();
+            let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+            writer.write(&batch).unwrap();
+            writer.close().unwrap();
+
+            let builder = ParquetRecordBatchReaderBuilder::try_new(Bytes::from(buf)).unwrap();
+            let bloom_filter = |path: &str| {
+                let path = ColumnPath::new(path.split('.').map(String::from).collect());
+                builder
+                    .get_row_group_column_bloom_filter_by_path(0, &path)
+                    .unwrap()
+                    .unwrap()
+            };
+
+            let a = bloom_filter("s.a");
+            let b = bloom_filter("s.b");
+            let l = bloom_filter("l.list.item");
+            for i in 0..100 {
+                assert!(a.check(&i));
+                assert!(b.check(format!("s{i}").as_str()));
+                assert!(l.check(&i));
+            }
+            assert!(!b.check("s100"));
+
+            let err = builder
+                .get_row_group_column_bloom_filter_by_path(0, &"s".into())
+                .unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "Parquet error: Leaf column 's' not found in parquet schema"
+            );
+        }
+    }
+
     #[test]
     fn binary_column_bloom_filter() {
         let one_vec: Vec<u8> = (0..SMALL_SIZE as u8).collect();
@@ -3734,6 +4126,52 @@ mod tests {
         one_column_roundtrip(array, true);
     }
 
+    #[test]
+    fn byte_stream_split_fixed_len_byte_array() {
+        let f16 = (0..SMALL_SIZE)
+            .map(|i| Some(f16::from_f32(i as f32 / 8.0)).filter(|_| i % 5 != 0))
+            .collect::<Float16Array>();
+        let decimal128 =
+            Decimal128Array::from_iter_values((0..SMALL_SIZE as i128).map(|i| i * 1000))
+                .with_precision_and_scale(20, 3)
+                .unwrap();
+        let decimal256 = Decimal256Array::from_iter_values((0..SMALL_SIZE as i64).map(i256::from))
+            .with_precision_and_scale(40, 0)
+            .unwrap();
+
+        for values in [
+            Arc::new(f16) as ArrayRef,
+            Arc::new(decimal128),
+            Arc::new(decimal256),
+        ] {
+            for file in one_column_roundtrip(values, true) {
+                let reader = SerializedFileReader::new(file).unwrap();
+                let column = reader.metadata().row_group(0).column(0);
+                assert_eq!(
+                    column.column_type(),
+                    crate::basic::Type::FIXED_LEN_BYTE_ARRAY
+                );
+            }
+        }
+
+        // the encoding is used if requested
+        let batch = RecordBatch::try_from_iter([(
+            "f16",
+            Arc::new(Float16Array::from_iter_values(
+                (0..10).map(|i| f16::from_f32(i as f32)),
+            )) as ArrayRef,
+        )])
+        .unwrap();
+        let props = WriterProperties::builder()
+            .set_dictionary_enabled(false)
+            .set_column_encoding(ColumnPath::from("f16"), Encoding::BYTE_STREAM_SPLIT)
+            .build();
+        let file = roundtrip_opts(&batch, props);
+        let reader = SerializedFileReader::new(file).unwrap();
+        let column = reader.metadata().row_group(0).column(0);
+        assert!(column.encodings().any(|e| e == Encoding::BYTE_STREAM_SPLIT));
+    }
+
     #[test]
     fn string_single_column() {
         let raw_values: Vec<_> = (0..SMALL_SIZE).map(|i| i.to_string()).collect();
@@ -3966,6 +4404,54 @@ mod tests {
         }
     }
 
+    #[test]
+    fn adaptive_byte_array_encoding() {
+        // shared prefixes favour DELTA_BYTE_ARRAY
+        let prefixed: StringArray = (0..1000)
+            .map(|i| Some(format!("https://example.com/path/{i:06}")))
+            .collect();
+        // consecutive values without a shared prefix favour DELTA_LENGTH_BYTE_ARRAY
+        let unprefixed: StringArray = (0..1000)
+            .map(|i| Some(format!("{}{i}", (b'a' + (i % 26) as u8) as char)))
+            .collect();
+        let batch = RecordBatch::try_from_iter([
+            ("prefixed", Arc::new(prefixed) as ArrayRef),
+            ("unprefixed", Arc::new(unprefixed) as ArrayRef),
+            (
+                "plain",
+                Arc::new(StringArray::from(vec!["a"; 1000])) as ArrayRef,
+            ),
+        ])
+        .unwrap();
+
+        let props = WriterProperties::builder()
+            .set_dictionary_enabled(false)
+            .set_adaptive_byte_array_encoding(true)
+            .set_column_adaptive_byte_array_encoding(ColumnPath::from("plain"), false)
+            .build();
+        let file = roundtrip_opts(&batch, props);
+
+        let reader = SerializedFileReader::new(file).unwrap();
+        let row_group = reader.metadata().row_group(0);
+        let encodings: Vec<_> = row_group
+            .columns()
+            .iter()
+            .map(|c| {
+                c.encodings()
+                    .filter(|e| *e != Encoding::RLE)
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        assert_eq!(
+            encodings,
+            vec![
+                vec![Encoding::DELTA_BYTE_ARRAY],
+                vec![Encoding::DELTA_LENGTH_BYTE_ARRAY],
+                vec![Encoding::PLAIN],
+            ]
+        );
+    }
+
     #[test]
     fn arrow_writer_string_dictionary() {
         // define schema
@@ -5050,6 +5536,174 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg(feature = "arrow_canonical_extension_types")]
+    fn test_arrow_writer_uuid_float16_logical_types() {
+        use crate::basic::LogicalType;
+        use arrow_schema::extension::Uuid;
+
+        let file_schema = Arc::new(Schema::new(vec![
+            Field::new("uuid", DataType::FixedSizeBinary(16), true).with_extension_type(Uuid),
+            Field::new("float16", DataType::Float16, true),
+        ]));
+        let uuids = FixedSizeBinaryArray::try_from_sparse_iter_with_size(
+            [Some([1_u8; 16]), None, Some([2_u8; 16])].into_iter(),
+            16,
+        )
+        .unwrap();
+        let halves = Float16Array::from(vec![Some(f16::ONE), Some(f16::NAN), None]);
+        let batch = RecordBatch::try_new(
+            file_schema.clone(),
+            vec![Arc::new(uuids) as _, Arc::new(halves) as _],
+        )
+        .unwrap();
+
+        for skip_arrow_metadata in [false, true] {
+            let options = ArrowWriterOptions::new().with_skip_arrow_metadata(skip_arrow_metadata);
+            let mut buf = Vec::with_capacity(1024);
+            let mut writer =
+                ArrowWriter::try_new_with_options(&mut buf, file_schema.clone(), options).unwrap();
+            writer.write(&batch).unwrap();
+            writer.close().unwrap();
+
+            let reader_builder =
+                ParquetRecordBatchReaderBuilder::try_new(Bytes::from(buf)).unwrap();
+            let parquet_schema = reader_builder.parquet_schema();
+            assert_eq!(
+                parquet_schema.column(0).logical_type_ref(),
+                Some(&LogicalType::Uuid)
+            );
+            assert_eq!(
+                parquet_schema.column(1).logical_type_ref(),
+                Some(&LogicalType::Float16)
+            );
+
+            // the extension type is restored with or without the embedded arrow schema
+            assert_eq!(file_schema, *reader_builder.schema());
+            let read = reader_builder
+                .build()
+                .unwrap()
+                .collect::<ArrowResult<Vec<_>>>()
+                .unwrap();
+            assert_eq!(read, vec![batch.clone()]);
+        }
+    }
+
+    #[test]
+    fn test_validate_sorting_columns() {
+        use crate::arrow::arrow_reader::ArrowReaderOptions;
+        use crate::basic::BoundaryOrder;
+        use crate::file::metadata::{PageIndexPolicy, SortingColumn};
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, false),
+            Field::new(
+                "s",
+                DataType::Struct(vec![Field::new("c", DataType::Int32, false)].into()),
+                false,
+            ),
+        ]));
+        let batch = |a: Vec<Option<i32>>, b: Vec<&str>| {
+            let len = a.len();
+            let c: ArrayRef = Arc::new(Int32Array::from(vec![0; len]));
+            let s = StructArray::from(vec![(Arc::new(Field::new("c", DataType::Int32, false)), c)]);
+            RecordBatch::try_new(
+                schema.clone(),
+                vec![
+                    Arc::new(Int32Array::from(a)),
+                    Arc::new(StringArray::from(b)),
+                    Arc::new(s),
+                ],
+            )
+            .unwrap()
+        };
+        // a ASC NULLS FIRST, b DESC
+        let sorting_columns = vec![
+            SortingColumn {
+                column_idx: 0,
+                descending: false,
+                nulls_first: true,
+            },
+            SortingColumn {
+                column_idx: 1,
+                descending: true,
+                nulls_first: false,
+            },
+        ];
+        let writer = |sorting_columns: Vec<SortingColumn>| {
+            let props = WriterProperties::builder()
+                .set_sorting_columns(Some(sorting_columns))
+                .set_validate_sorting_columns(true)
+                .set_max_row_group_row_count(Some(4))
+                .set_data_page_row_count_limit(2)
+                .set_write_batch_size(2)
+                .build();
+            ArrowWriter::try_new(Vec::new(), schema.clone(), Some(props))
+        };
+
+        // sorted within each row group, but not across row groups
+        let mut w = writer(sorting_columns.clone()).unwrap();
+        w.write(&batch(vec![None, Some(1), Some(1)], vec!["x", "z", "y"]))
+            .unwrap();
+        w.write(&batch(vec![Some(1), Some(0), Some(2)], vec!["y", "a", "a"]))
+            .unwrap();
+        let buf = w.into_inner().unwrap();
+        let builder = ParquetRecordBatchReaderBuilder::try_new_with_options(
+            Bytes::from(buf),
+            ArrowReaderOptions::new().with_page_index_policy(PageIndexPolicy::Required),
+        )
+        .unwrap();
+        let metadata = builder.metadata();
+        assert_eq!(metadata.num_row_groups(), 2);
+        for row_group in metadata.row_groups() {
+            assert_eq!(row_group.sorting_columns(), Some(&sorting_columns));
+        }
+        // the boundary order is computed from the data, "b" is only sorted for
+        // equal values of "a"
+        let column_index = &metadata.column_index().unwrap()[0];
+        assert_eq!(
+            column_index[0].get_boundary_order(),
+            Some(BoundaryOrder::ASCENDING)
+        );
+        assert_eq!(
+            column_index[1].get_boundary_order(),
+            Some(BoundaryOrder::UNORDERED)
+        );
+
+        // not sorted within a batch
+        let mut w = writer(sorting_columns.clone()).unwrap();
+        let err = w
+            .write(&batch(vec![Some(1), Some(1), Some(0)], vec!["a", "a", "a"]))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Row 2 of the row group is not sorted by the sorting columns"
+        );
+
+        // not sorted across batches
+        let mut w = writer(sorting_columns.clone()).unwrap();
+        w.write(&batch(vec![Some(1), Some(1)], vec!["b", "a"]))
+            .unwrap();
+        let err = w.write(&batch(vec![Some(1)], vec!["b"])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Row 2 of the row group is not sorted by the sorting columns"
+        );
+
+        // nested columns are not supported
+        let nested = SortingColumn {
+            column_idx: 2,
+            descending: false,
+            nulls_first: false,
+        };
+        let err = writer(vec![nested]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Cannot validate sorting column \"s.c\", only top level primitive columns are supported"
+        );
+    }
+
     #[test]
     fn test_arrow_writer_skip_path_in_schema() {
         let batch_schema = Schema::new(vec![Field::new("int32", DataType::Int32, false)]);
@@ -5996,4 +6650,69 @@ mod tests {
         );
         assert_eq!(parquet_schema.column(1).path().string(), "row.b");
     }
+
+    #[test]
+    fn ree_sliced_multiple_runs() {
+        // run_ends [2, 4, 7, 8] → [a,a, null,null, b,b,b, c]
+        // After slice(3, 4) the logical view is [null, b, b, b]
+        let full: ArrayRef = Arc::new(
+            RunArray::try_new(
+                &Int64Array::from(vec![2i64, 4, 7, 8]),
+                &StringArray::from(vec![Some("a"), None, Some("b"), Some("c")]),
+            )
+            .unwrap(),
+        );
+        let flat: ArrayRef = Arc::new(StringArray::from(vec![
+            None,
+            Some("b"),
+            Some("b"),
+            Some("b"),
+        ]));
+        ree_write_read_roundtrip(full.slice(3, 4), flat);
+    }
+
+    #[test]
+    fn dictionary_hints() {
+        let ree: ArrayRef = Arc::new(["a", "a", "b"].into_iter().collect::<Int32RunArray>());
+        let dict: ArrayRef = Arc::new(
+            ["a", "a", "b"]
+                .into_iter()
+                .collect::<Int32DictionaryArray>(),
+        );
+        let plain: ArrayRef = Arc::new(StringArray::from(vec!["a", "a", "b"]));
+        let batch =
+            RecordBatch::try_from_iter([("ree", ree), ("dict", dict), ("plain", plain)]).unwrap();
+
+        let write = |options: ArrowWriterOptions| {
+            let mut buf = Vec::new();
+            let mut writer =
+                ArrowWriter::try_new_with_options(&mut buf, batch.schema(), options).unwrap();
+            writer.write(&batch).unwrap();
+            let metadata = writer.close().unwrap();
+            metadata.row_groups()[0]
+                .columns()
+                .iter()
+                .map(|c| c.dictionary_page_offset().is_some())
+                .collect::<Vec<_>>()
+        };
+
+        let props = WriterProperties::builder()
+            .set_dictionary_enabled(false)
+            .build();
+        let options = ArrowWriterOptions::new().with_properties(props.clone());
+        assert_eq!(write(options), [true, true, false]);
+
+        let options = ArrowWriterOptions::new()
+            .with_properties(props.clone())
+            .with_skip_dictionary_hints(true);
+        assert_eq!(write(options), [false, false, false]);
+
+        // An explicit column setting takes precedence over the hint
+        let props = props
+            .into_builder()
+            .set_column_dictionary_enabled(ColumnPath::from("ree"), false)
+            .build();
+        let options = ArrowWriterOptions::new().with_properties(props);
+        assert_eq!(write(options), [false, true, false]);
+    }
 }
@@ -0,0 +1,487 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::io::Write;
+
+use arrow_array::RecordBatch;
+use arrow_array::builder::{ArrayBuilder, make_builder};
+use arrow_schema::{DataType, SchemaRef, TimeUnit};
+
+use super::{ArrowWriter, ArrowWriterOptions};
+use crate::errors::{ParquetError, Result};
+use crate::file::metadata::{ParquetMetaData, RowGroupMetaData};
+use crate::file::properties::WriterProperties;
+
+/// Encodes rows, appended one at a time to Arrow builders, to parquet
+///
+/// Each column of the schema has an [`ArrayBuilder`], created with [`make_builder`],
+/// to which the values of a row are appended. Once [`WriterProperties::write_batch_size`]
+/// rows are buffered, they are encoded with an [`ArrowWriter`], so the memory used by
+/// the builders remains bounded regardless of the number of rows written.
+///
+/// Row groups are flushed according to [`WriterProperties::max_row_group_row_count`] and
+/// [`WriterProperties::max_row_group_bytes`], the latter being based on the estimated
+/// encoded size of the in progress row group. This allows writing well sized files from
+/// input of unpredictable size.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::builder::{Int64Builder, StringBuilder};
+/// # use arrow_schema::{DataType, Field, Schema};
+/// # use parquet::arrow::arrow_writer::ArrowRowWriter;
+/// # use parquet::file::properties::WriterProperties;
+/// let schema = Arc::new(Schema::new(vec![
+///     Field::new("id", DataType::Int64, false),
+///     Field::new("name", DataType::Utf8, true),
+/// ]));
+/// let props = WriterProperties::builder()
+///     .set_max_row_group_bytes(Some(128 * 1024 * 1024))
+///     .build();
+///
+/// let mut buffer = Vec::new();
+/// let mut writer = ArrowRowWriter::try_new(&mut buffer, schema, Some(props)).unwrap();
+/// for (id, name) in [(1, Some("a")), (2, None)] {
+///     writer
+///         .write_row(|builders| {
+///             let ids = builders[0].as_any_mut().downcast_mut::<Int64Builder>().unwrap();
+///             ids.append_value(id);
+///             let names = builders[1].as_any_mut().downcast_mut::<StringBuilder>().unwrap();
+///             names.append_option(name);
+///             Ok(())
+///         })
+///         .unwrap();
+/// }
+/// writer.close().unwrap();
+/// ```
+pub struct ArrowRowWriter<W: Write> {
+    /// Encodes the buffered rows
+    writer: ArrowWriter<W>,
+
+    /// The builders for each column of the schema
+    builders: Vec<Box<dyn ArrayBuilder>>,
+
+    /// The number of complete rows in `builders`
+    buffered_rows: usize,
+
+    /// The number of rows to buffer before encoding them
+    batch_size: usize,
+}
+
+impl<W: Write + Send> std::fmt::Debug for ArrowRowWriter<W> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ArrowRowWriter")
+            .field("writer", &self.writer)
+            .field("buffered_rows", &self.buffered_rows)
+            .field("batch_size", &self.batch_size)
+            .finish()
+    }
+}
+
+impl<W: Write + Send> ArrowRowWriter<W> {
+    /// Try to create a new Arrow row writer
+    ///
+    /// The writer will fail if:
+    ///  * a `SerializedFileWriter` cannot be created from the ParquetWriter
+    ///  * the Arrow schema contains unsupported datatypes such as Unions
+    ///  * [`make_builder`] does not support a data type in `arrow_schema`
+    pub fn try_new(
+        writer: W,
+        arrow_schema: SchemaRef,
+        props: Option<WriterProperties>,
+    ) -> Result<Self> {
+        let options = ArrowWriterOptions::new().with_properties(props.unwrap_or_default());
+        Self::try_new_with_options(writer, arrow_schema, options)
+    }
+
+    /// Try to create a new Arrow row writer with [`ArrowWriterOptions`].
+    ///
+    /// See [`Self::try_new`]
+    pub fn try_new_with_options(
+        writer: W,
+        arrow_schema: SchemaRef,
+        options: ArrowWriterOptions,
+    ) -> Result<Self> {
+        for field in arrow_schema.fields() {
+            check_builder_type(field.data_type())?;
+        }
+        let batch_size = options.properties.write_batch_size().max(1);
+        let builders = arrow_schema
+            .fields()
+            .iter()
+            .map(|f| make_builder(f.data_type(), batch_size))
+            .collect();
+        let writer = ArrowWriter::try_new_with_options(writer, arrow_schema, options)?;
+        Ok(Self {
+            writer,
+            builders,
+            buffered_rows: 0,
+            batch_size,
+        })
+    }
+
+    /// Returns the builders for each column of the schema
+    ///
+    /// Values appended to the builders must be followed by a call to [`Self::end_row`]
+    pub fn builders_mut(&mut self) -> &mut [Box<dyn ArrayBuilder>] {
+        &mut self.builders
+    }
+
+    /// Returns the builder for column `i`, if it is of type `T`
+    ///
+    /// Values appended to the builder must be followed by a call to [`Self::end_row`]
+    pub fn column_builder<T: ArrayBuilder>(&mut self, i: usize) -> Option<&mut T> {
+        self.builders.get_mut(i)?.as_any_mut().downcast_mut()
+    }
+
+    /// Completes a row, to which exactly one value must have been appended to the
+    /// builder of each column
+    ///
+    /// Once [`WriterProperties::write_batch_size`] rows are buffered they are encoded,
+    /// which may flush a row group.
+    ///
+    /// Returns an error if the builders do not contain the same number of values, in
+    /// which case the buffered rows can not be recovered.
+    pub fn end_row(&mut self) -> Result<()> {
+        let expected = self.buffered_rows + 1;
+        if let Some((idx, builder)) = self
+            .builders
+            .iter()
+            .enumerate()
+            .find(|(_, b)| b.len() != expected)
+        {
+            return Err(general_err!(
+                "Expected {} values in the builder of column {}, got {}",
+                expected,
+                idx,
+                builder.len()
+            ));
+        }
+
+        self.buffered_rows = expected;
+        if self.buffered_rows >= self.batch_size {
+            self.write_buffered()?;
+        }
+        Ok(())
+    }
+
+    /// Appends a row by calling `f` with the builders for each column of the
+    /// schema, followed by [`Self::end_row`]
+    ///
+    /// If `f` returns an error the values it appended remain in the builders,
+    /// and must be completed before calling [`Self::end_row`] again.
+    pub fn write_row<F>(&mut self, f: F) -> Result<()>
+    where
+        F: FnOnce(&mut [Box<dyn ArrayBuilder>]) -> Result<()>,
+    {
+        f(&mut self.builders)?;
+        self.end_row()
+    }
+
+    /// Encodes the provided [`RecordBatch`], after any buffered rows
+    ///
+    /// See [`ArrowWriter::write`]
+    pub fn write(&mut self, batch: &RecordBatch) -> Result<()> {
+        self.write_buffered()?;
+        self.writer.write(batch)
+    }
+
+    /// Returns the number of rows buffered in the builders, that have not yet been encoded
+    pub fn buffered_rows(&self) -> usize {
+        self.buffered_rows
+    }
+
+    /// Returns the number of rows in the in progress row group, including
+    /// those buffered in the builders
+    pub fn in_progress_rows(&self) -> usize {
+        self.writer.in_progress_rows() + self.buffered_rows
+    }
+
+    /// Anticipated encoded size of the in progress row group, not including
+    /// the rows buffered in the builders
+    ///
+    /// See [`ArrowWriter::in_progress_size`]
+    pub fn in_progress_size(&self) -> usize {
+        self.writer.in_progress_size()
+    }
+
+    /// Returns metadata for any flushed row groups
+    pub fn flushed_row_groups(&self) -> &[RowGroupMetaData] {
+        self.writer.flushed_row_groups()
+    }
+
+    /// Returns the number of bytes written by this instance
+    pub fn bytes_written(&self) -> usize {
+        self.writer.bytes_written()
+    }
+
+    /// Flushes all buffered rows into a new row group
+    ///
+    /// See [`ArrowWriter::flush`]
+    pub fn flush(&mut self) -> Result<()> {
+        self.write_buffered()?;
+        self.writer.flush()
+    }
+
+    /// Flushes any outstanding data and returns the underlying writer.
+    pub fn into_inner(mut self) -> Result<W> {
+        self.write_buffered()?;
+        self.writer.into_inner()
+    }
+
+    /// Close and finalize the underlying Parquet writer
+    ///
+    /// Unlike [`Self::close`] this does not consume self
+    ///
+    /// Attempting to write after calling finish will result in an error
+    pub fn finish(&mut self) -> Result<ParquetMetaData> {
+        self.write_buffered()?;
+        self.writer.finish()
+    }
+
+    /// Close and finalize the underlying Parquet writer
+    pub fn close(mut self) -> Result<ParquetMetaData> {
+        self.finish()
+    }
+
+    /// Encodes the rows buffered in the builders
+    fn write_buffered(&mut self) -> Result<()> {
+        if self.buffered_rows == 0 {
+            return Ok(());
+        }
+        let columns = self.builders.iter_mut().map(|b| b.finish()).collect();
+        self.buffered_rows = 0;
+        let batch = RecordBatch::try_new(self.writer.arrow_schema.clone(), columns)?;
+        self.writer.write(&batch)
+    }
+}
+
+/// Returns an error if [`make_builder`] does not support `data_type`, as it
+/// panics instead
+fn check_builder_type(data_type: &DataType) -> Result<()> {
+    match data_type {
+        DataType::Null
+        | DataType::Boolean
+        | DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Int64
+        | DataType::UInt8
+        | DataType::UInt16
+        | DataType::UInt32
+        | DataType::UInt64
+        | DataType::Float16
+        | DataType::Float32
+        | DataType::Float64
+        | DataType::Binary
+        | DataType::LargeBinary
+        | DataType::BinaryView
+        | DataType::FixedSizeBinary(_)
+        | DataType::Decimal32(_, _)
+        | DataType::Decimal64(_, _)
+        | DataType::Decimal128(_, _)
+        | DataType::Decimal256(_, _)
+        | DataType::Utf8
+        | DataType::LargeUtf8
+        | DataType::Utf8View
+        | DataType::Date32
+        | DataType::Date64
+        | DataType::Time32(TimeUnit::Second | TimeUnit::Millisecond)
+        | DataType::Time64(TimeUnit::Microsecond | TimeUnit::Nanosecond)
+        | DataType::Timestamp(_, _)
+        | DataType::Interval(_)
+        | DataType::Duration(_) => Ok(()),
+        DataType::List(f)
+        | DataType::LargeList(f)
+        | DataType::FixedSizeList(f, _)
+        | DataType::ListView(f)
+        | DataType::LargeListView(f) => check_builder_type(f.data_type()),
+        DataType::Map(f, _) => match f.data_type() {
+            DataType::Struct(fields) if fields.len() == 2 => fields
+                .iter()
+                .try_for_each(|f| check_builder_type(f.data_type())),
+            _ => Err(nyi_err!(
+                "Writing rows of data type {} is not supported",
+                data_type
+            )),
+        },
+        DataType::Struct(fields) => fields
+            .iter()
+            .try_for_each(|f| check_builder_type(f.data_type())),
+        DataType::Dictionary(key, value)
+            if matches!(
+                key.as_ref(),
+                DataType::Int8 | DataType::Int16 | DataType::Int32 | DataType::Int64
+            ) && matches!(
+                value.as_ref(),
+                DataType::Utf8 | DataType::LargeUtf8 | DataType::Binary | DataType::LargeBinary
+            ) =>
+        {
+            Ok(())
+        }
+        _ => Err(nyi_err!(
+            "Writing rows of data type {} is not supported",
+            data_type
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file::reader::{FileReader, SerializedFileReader};
+    use arrow_array::Array;
+    use arrow_array::builder::{Int32Builder, ListBuilder, StringBuilder};
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int32Type;
+    use arrow_schema::{Field, Schema};
+    use bytes::Bytes;
+    use std::sync::Arc;
+
+    use crate::arrow::arrow_reader::ParquetRecordBatchReader;
+
+    fn schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new("name", DataType::Utf8, true),
+            Field::new_list("tags", Field::new_list_field(DataType::Int32, true), true),
+        ]))
+    }
+
+    fn write_row(writer: &mut ArrowRowWriter<&mut Vec<u8>>, id: i32) {
+        writer
+            .write_row(|builders| {
+                let ids = builders[0].as_any_mut().downcast_mut::<Int32Builder>();
+                ids.unwrap().append_value(id);
+                let names = builders[1].as_any_mut().downcast_mut::<StringBuilder>();
+                names
+                    .unwrap()
+                    .append_option((id % 3 != 0).then(|| format!("name{id}")));
+                let tags = builders[2]
+                    .as_any_mut()
+                    .downcast_mut::<ListBuilder<Box<dyn ArrayBuilder>>>()
+                    .unwrap();
+                let values = tags.values().as_any_mut().downcast_mut::<Int32Builder>();
+                values.unwrap().append_slice(&[id, id + 1]);
+                tags.append(true);
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn test_row_writer_round_trip() {
+        let props = WriterProperties::builder()
+            .set_write_batch_size(7)
+            .set_max_row_group_row_count(Some(20))
+            .build();
+        let mut buffer = Vec::new();
+        let mut writer = ArrowRowWriter::try_new(&mut buffer, schema(), Some(props)).unwrap();
+
+        for id in 0..10 {
+            write_row(&mut writer, id);
+        }
+        // the first 7 rows are encoded
+        assert_eq!(writer.buffered_rows(), 3);
+        assert_eq!(writer.in_progress_rows(), 10);
+
+        for id in 10..50 {
+            write_row(&mut writer, id);
+        }
+        assert_eq!(writer.flushed_row_groups().len(), 2);
+        let metadata = writer.close().unwrap();
+        let row_counts: Vec<_> = metadata
+            .row_groups()
+            .iter()
+            .map(|rg| rg.num_rows())
+            .collect();
+        assert_eq!(row_counts, vec![20, 20, 10]);
+
+        let reader = ParquetRecordBatchReader::try_new(Bytes::from(buffer), 1024).unwrap();
+        let batches = reader.collect::<std::result::Result<Vec<_>, _>>().unwrap();
+        assert_eq!(batches.len(), 1);
+        let batch = &batches[0];
+        let ids = batch.column(0).as_primitive::<Int32Type>();
+        assert_eq!(ids.values().to_vec(), (0..50).collect::<Vec<_>>());
+        let names = batch.column(1).as_string::<i32>();
+        assert!(names.is_null(3));
+        assert_eq!(names.value(4), "name4");
+        let tags = batch.column(2).as_list::<i32>();
+        assert_eq!(
+            tags.value(5).as_primitive::<Int32Type>().values().to_vec(),
+            vec![5, 6]
+        );
+    }
+
+    #[test]
+    fn test_row_writer_max_row_group_bytes() {
+        let props = WriterProperties::builder()
+            .set_write_batch_size(10)
+            .set_max_row_group_row_count(None)
+            .set_max_row_group_bytes(Some(2000))
+            .build();
+        let mut buffer = Vec::new();
+        let mut writer = ArrowRowWriter::try_new(&mut buffer, schema(), Some(props)).unwrap();
+        for id in 0..1000 {
+            write_row(&mut writer, id);
+        }
+        writer.close().unwrap();
+
+        let reader = SerializedFileReader::new(Bytes::from(buffer)).unwrap();
+        let metadata = reader.metadata();
+        assert!(metadata.num_row_groups() > 1);
+        let total: i64 = metadata.row_groups().iter().map(|rg| rg.num_rows()).sum();
+        assert_eq!(total, 1000);
+    }
+
+    #[test]
+    fn test_row_writer_incomplete_row() {
+        let mut buffer = Vec::new();
+        let mut writer = ArrowRowWriter::try_new(&mut buffer, schema(), None).unwrap();
+        writer
+            .column_builder::<Int32Builder>(0)
+            .unwrap()
+            .append_value(1);
+        assert!(writer.column_builder::<StringBuilder>(0).is_none());
+
+        let err = writer.end_row().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Expected 1 values in the builder of column 1, got 0"
+        );
+    }
+
+    #[test]
+    fn test_row_writer_unsupported_type() {
+        let dictionary = DataType::Dictionary(Box::new(DataType::UInt8), Box::new(DataType::Utf8));
+        let time = DataType::Time32(TimeUnit::Microsecond);
+        for (data_type, unsupported) in [
+            (time.clone(), time),
+            (
+                DataType::new_list(dictionary.clone(), true),
+                dictionary.clone(),
+            ),
+            (dictionary.clone(), dictionary),
+        ] {
+            let schema = Arc::new(Schema::new(vec![Field::new("a", data_type, true)]));
+            let err = ArrowRowWriter::try_new(Vec::new(), schema, None).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                format!("NYI: Writing rows of data type {unsupported} is not supported")
+            );
+        }
+    }
+}
@@ -0,0 +1,152 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Validation of the sorting columns of the row groups written by
+//! [`ArrowWriter`](super::ArrowWriter)
+
+use std::cmp::Ordering;
+
+use arrow_array::{Array, ArrayRef, RecordBatch};
+use arrow_ord::ord::{DynComparator, make_comparator};
+use arrow_schema::SortOptions;
+
+use crate::errors::{ParquetError, Result};
+use crate::file::metadata::SortingColumn;
+use crate::schema::types::SchemaDescriptor;
+
+/// Checks that the rows written to a row group are sorted by the
+/// [`SortingColumn`]s, see [`WriterPropertiesBuilder::set_validate_sorting_columns`]
+///
+/// [`WriterPropertiesBuilder::set_validate_sorting_columns`]: crate::file::properties::WriterPropertiesBuilder::set_validate_sorting_columns
+#[derive(Debug)]
+pub(super) struct SortingColumnsValidator {
+    /// The index of each sorting column in the written batches, and its order
+    columns: Vec<(usize, SortOptions)>,
+    /// The sorting columns of the last row written to the current row group
+    last_row: Option<Vec<ArrayRef>>,
+    /// The number of rows written to the current row group
+    num_rows: usize,
+}
+
+impl SortingColumnsValidator {
+    /// Create a new validator for `sorting_columns`, which must refer to top
+    /// level columns of `schema`
+    pub(super) fn try_new(
+        schema: &SchemaDescriptor,
+        sorting_columns: &[SortingColumn],
+    ) -> Result<Self> {
+        let columns = sorting_columns
+            .iter()
+            .map(|sorting_column| {
+                let leaf_idx = usize::try_from(sorting_column.column_idx)
+                    .ok()
+                    .filter(|idx| *idx < schema.num_columns())
+                    .ok_or_else(|| {
+                        general_err!(
+                            "Sorting column index {} out of bounds for {} columns",
+                            sorting_column.column_idx,
+                            schema.num_columns()
+                        )
+                    })?;
+                let column = schema.column(leaf_idx);
+                if column.path().parts().len() != 1 || column.max_rep_level() != 0 {
+                    return Err(general_err!(
+                        "Cannot validate sorting column {}, only top level primitive columns are supported",
+                        column.path()
+                    ));
+                }
+                let options = SortOptions {
+                    descending: sorting_column.descending,
+                    nulls_first: sorting_column.nulls_first,
+                };
+                Ok((schema.get_column_root_idx(leaf_idx), options))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            columns,
+            last_row: None,
+            num_rows: 0,
+        })
+    }
+
+    /// Checks that the rows of `batch` are sorted, and follow the rows previously
+    /// written to the current row group
+    pub(super) fn validate(&mut self, batch: &RecordBatch) -> Result<()> {
+        if batch.num_rows() == 0 {
+            return Ok(());
+        }
+        let arrays: Vec<&ArrayRef> = self
+            .columns
+            .iter()
+            .map(|(idx, _)| batch.column(*idx))
+            .collect();
+
+        if let Some(last_row) = &self.last_row {
+            let comparators = self.comparators(last_row.iter(), arrays.iter().copied())?;
+            if compare(&comparators, 0, 0).is_gt() {
+                return Err(self.unsorted_err(0));
+            }
+        }
+
+        let comparators = self.comparators(arrays.iter().copied(), arrays.iter().copied())?;
+        if let Some(row) = (1..batch.num_rows()).find(|i| compare(&comparators, i - 1, *i).is_gt())
+        {
+            return Err(self.unsorted_err(row));
+        }
+
+        let last = batch.num_rows() - 1;
+        self.last_row = Some(arrays.iter().map(|a| a.slice(last, 1)).collect());
+        self.num_rows += batch.num_rows();
+        Ok(())
+    }
+
+    /// Resets the validator for the next row group
+    pub(super) fn reset(&mut self) {
+        self.last_row = None;
+        self.num_rows = 0;
+    }
+
+    fn comparators<'a>(
+        &self,
+        left: impl Iterator<Item = &'a ArrayRef>,
+        right: impl Iterator<Item = &'a ArrayRef>,
+    ) -> Result<Vec<DynComparator>> {
+        let comparators = left
+            .zip(right)
+            .zip(&self.columns)
+            .map(|((l, r), (_, options))| make_comparator(l.as_ref(), r.as_ref(), *options))
+            .collect::<Result<_, _>>()?;
+        Ok(comparators)
+    }
+
+    fn unsorted_err(&self, row: usize) -> ParquetError {
+        general_err!(
+            "Row {} of the row group is not sorted by the sorting columns",
+            self.num_rows + row
+        )
+    }
+}
+
+/// Compares row `left` and row `right` lexicographically by `comparators`
+fn compare(comparators: &[DynComparator], left: usize, right: usize) -> Ordering {
+    comparators
+        .iter()
+        .map(|c| c(left, right))
+        .find(|o| o.is_ne())
+        .unwrap_or(Ordering::Equal)
+}
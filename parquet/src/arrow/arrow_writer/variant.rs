@@ -0,0 +1,95 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Shredding of the Variant columns written by [`ArrowWriter`](super::ArrowWriter)
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, RecordBatch};
+use arrow_schema::{DataType, Schema, SchemaRef};
+use parquet_variant_compute::{VariantArray, VariantArrayBuilder, VariantType, shred_variant};
+
+use crate::errors::{ParquetError, Result};
+
+/// Shreds the Variant columns of the written batches, see
+/// [`ArrowWriterOptions::with_variant_shredding`]
+///
+/// [`ArrowWriterOptions::with_variant_shredding`]: super::ArrowWriterOptions::with_variant_shredding
+#[derive(Debug)]
+pub(super) struct VariantShredder {
+    /// The index of each shredded column, and its shredding type
+    columns: Vec<(usize, DataType)>,
+    /// The schema of the written batches, with the shredded columns
+    schema: SchemaRef,
+}
+
+impl VariantShredder {
+    /// Create a new shredder for the top level Variant columns of `schema`
+    /// named in `shredding`, returning `None` if there are none
+    pub(super) fn try_new(
+        schema: &SchemaRef,
+        shredding: &HashMap<String, DataType>,
+    ) -> Result<Option<Self>> {
+        if shredding.is_empty() {
+            return Ok(None);
+        }
+
+        let mut fields = schema.fields().to_vec();
+        let mut columns = Vec::with_capacity(shredding.len());
+        for (name, as_type) in shredding {
+            let (idx, field) = schema.column_with_name(name).ok_or_else(|| {
+                general_err!("Cannot shred Variant column '{}': no such column", name)
+            })?;
+            if field.try_extension_type::<VariantType>().is_err() {
+                return Err(general_err!(
+                    "Cannot shred column '{}': not a Variant column",
+                    name
+                ));
+            }
+            // Shred an empty array to determine the type of the shredded column
+            let empty = VariantArrayBuilder::new(0).build();
+            let shredded = shred_variant(&empty, as_type)?;
+            let data_type = shredded.data_type().clone();
+            fields[idx] = Arc::new(field.clone().with_data_type(data_type));
+            columns.push((idx, as_type.clone()));
+        }
+        columns.sort_unstable_by_key(|(idx, _)| *idx);
+
+        let schema = Schema::new_with_metadata(fields, schema.metadata().clone());
+        Ok(Some(Self {
+            columns,
+            schema: Arc::new(schema),
+        }))
+    }
+
+    /// Returns the schema of the shredded batches
+    pub(super) fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    /// Shreds the Variant columns of `batch`
+    pub(super) fn shred(&self, batch: &RecordBatch) -> Result<RecordBatch> {
+        let mut columns = batch.columns().to_vec();
+        for (idx, as_type) in &self.columns {
+            let array = VariantArray::try_new(&columns[*idx])?;
+            let shredded = shred_variant(&array, as_type)?;
+            columns[*idx] = ArrayRef::from(shredded);
+        }
+        Ok(RecordBatch::try_new(self.schema.clone(), columns)?)
+    }
+}
@@ -0,0 +1,90 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use bytes::Bytes;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+/// A source of owned buffers that an [`AsyncFileReader`] reads data into
+///
+/// Completion-based I/O interfaces, such as io_uring or IOCP, require the buffer
+/// a read is performed into to be owned by the operation until it completes,
+/// rather than borrowed. A [`BufferPool`] passed to
+/// [`AsyncFileReader::get_byte_ranges_into`] allows such implementations to
+/// obtain those buffers from the caller, which can, for example, allocate them
+/// with a particular alignment.
+///
+/// Implementations that convert the buffers into [`Bytes`] with [`pooled_bytes`]
+/// return them to the pool with [`BufferPool::release`] once the data is no
+/// longer referenced, allowing the pool to recycle them between requests.
+///
+/// See [`ParquetRecordBatchStreamBuilder::with_buffer_pool`]
+///
+/// [`AsyncFileReader`]: super::AsyncFileReader
+/// [`AsyncFileReader::get_byte_ranges_into`]: super::AsyncFileReader::get_byte_ranges_into
+/// [`ParquetRecordBatchStreamBuilder::with_buffer_pool`]: super::ParquetRecordBatchStreamBuilder::with_buffer_pool
+pub trait BufferPool: Debug + Send + Sync {
+    /// Returns an owned buffer with a length of `len` bytes
+    ///
+    /// The contents of the buffer are overwritten by the read
+    fn get(&self, len: usize) -> Vec<u8>;
+
+    /// Returns a buffer obtained from [`Self::get`] to the pool
+    ///
+    /// This is called by [`pooled_bytes`] once the last [`Bytes`] referencing the
+    /// buffer is dropped, which may be after the data read into it has been
+    /// decoded, as decoded arrays may reference it. The default implementation
+    /// drops the buffer.
+    fn release(&self, buffer: Vec<u8>) {
+        drop(buffer)
+    }
+}
+
+/// Returns a [`Bytes`] referencing `buffer`, obtained from `pool`, that returns it
+/// to `pool` with [`BufferPool::release`] once it, and any [`Bytes`] sliced from
+/// it, are dropped
+pub fn pooled_bytes(pool: Arc<dyn BufferPool>, buffer: Vec<u8>) -> Bytes {
+    Bytes::from_owner(PooledBuffer { pool, buffer })
+}
+
+/// A buffer that is returned to its [`BufferPool`] when dropped
+struct PooledBuffer {
+    pool: Arc<dyn BufferPool>,
+    buffer: Vec<u8>,
+}
+
+impl AsRef<[u8]> for PooledBuffer {
+    fn as_ref(&self) -> &[u8] {
+        &self.buffer
+    }
+}
+
+impl Drop for PooledBuffer {
+    fn drop(&mut self) {
+        self.pool.release(std::mem::take(&mut self.buffer));
+    }
+}
+
+/// A [`BufferPool`] that allocates a new zeroed buffer for each request
+#[derive(Debug, Default, Clone, Copy)]
+pub struct AllocatingBufferPool;
+
+impl BufferPool for AllocatingBufferPool {
+    fn get(&self, len: usize) -> Vec<u8> {
+        vec![0; len]
+    }
+}
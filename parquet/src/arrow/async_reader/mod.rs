@@ -21,6 +21,7 @@
 //!
 //! See example on [`ParquetRecordBatchStreamBuilder::new`]
 
+use std::collections::VecDeque;
 use std::fmt::Formatter;
 use std::io::SeekFrom;
 use std::ops::Range;
@@ -46,8 +47,11 @@ use crate::bloom_filter::{
 };
 use crate::errors::{ParquetError, Result};
 use crate::file::metadata::{ParquetMetaData, ParquetMetaDataReader};
+use crate::schema::types::ColumnPath;
 
+mod buffer_pool;
 mod metadata;
+pub use buffer_pool::*;
 pub use metadata::*;
 
 #[cfg(feature = "object_store")]
@@ -90,6 +94,21 @@ pub trait AsyncFileReader: Send {
         .boxed()
     }
 
+    /// Retrieve multiple byte ranges, reading them into buffers obtained from `pool`
+    ///
+    /// Implementations with completion-based I/O, such as io_uring, can use this to
+    /// pass owned buffers down to the I/O layer, and convert them with [`pooled_bytes`]
+    /// so that they are returned to `pool` once no longer referenced. The default
+    /// implementation ignores `pool` and calls `get_byte_ranges`
+    fn get_byte_ranges_into(
+        &mut self,
+        ranges: Vec<Range<u64>>,
+        pool: Arc<dyn BufferPool>,
+    ) -> BoxFuture<'_, Result<Vec<Bytes>>> {
+        let _ = pool;
+        self.get_byte_ranges(ranges)
+    }
+
     /// Return a future which results in the [`ParquetMetaData`] for this Parquet file.
     ///
     /// This is an asynchronous operation as it may involve reading the file
@@ -122,6 +141,14 @@ impl AsyncFileReader for Box<dyn AsyncFileReader + '_> {
         self.as_mut().get_byte_ranges(ranges)
     }
 
+    fn get_byte_ranges_into(
+        &mut self,
+        ranges: Vec<Range<u64>>,
+        pool: Arc<dyn BufferPool>,
+    ) -> BoxFuture<'_, Result<Vec<Bytes>>> {
+        self.as_mut().get_byte_ranges_into(ranges, pool)
+    }
+
     fn get_metadata<'a>(
         &'a mut self,
         options: Option<&'a ArrowReaderOptions>,
@@ -159,6 +186,24 @@ impl<T: AsyncRead + AsyncSeek + Unpin + Send> AsyncFileReader for T {
         .boxed()
     }
 
+    fn get_byte_ranges_into(
+        &mut self,
+        ranges: Vec<Range<u64>>,
+        pool: Arc<dyn BufferPool>,
+    ) -> BoxFuture<'_, Result<Vec<Bytes>>> {
+        async move {
+            let mut result = Vec::with_capacity(ranges.len());
+            for range in ranges {
+                self.seek(SeekFrom::Start(range.start)).await?;
+                let mut buffer = pool.get((range.end - range.start).try_into()?);
+                self.read_exact(&mut buffer).await?;
+                result.push(pooled_bytes(Arc::clone(&pool), buffer));
+            }
+            Ok(result)
+        }
+        .boxed()
+    }
+
     fn get_metadata<'a>(
         &'a mut self,
         options: Option<&'a ArrowReaderOptions>,
@@ -199,12 +244,23 @@ impl ArrowReaderMetadata {
     }
 }
 
+/// The maximum number of bytes between two ranges for them to be fetched in a
+/// single request when prefetching row groups, see
+/// [`ParquetRecordBatchStreamBuilder::with_prefetch_row_groups`]
+pub const PREFETCH_COALESCE_GAP: u64 = 1024 * 1024;
+
 #[doc(hidden)]
 /// Newtype (wrapper) used within [`ArrowReaderBuilder`] to distinguish sync readers from async
 ///
 /// Allows sharing the same builder for different readers while keeping the same
 /// ParquetRecordBatchStreamBuilder API
-pub struct AsyncReader<T>(T);
+pub struct AsyncReader<T> {
+    input: T,
+    /// The number of row groups to fetch ahead of the one being decoded
+    prefetch_row_groups: usize,
+    /// The pool to read data into, if any
+    buffer_pool: Option<Arc<dyn BufferPool>>,
+}
 
 /// A builder for reading parquet files from an `async` source as  [`ParquetRecordBatchStream`]
 ///
@@ -500,7 +556,52 @@ impl<T: AsyncFileReader + Send + 'static> ParquetRecordBatchStreamBuilder<T> {
     /// # }
     /// ```
     pub fn new_with_metadata(input: T, metadata: ArrowReaderMetadata) -> Self {
-        Self::new_builder(AsyncReader(input), metadata)
+        let input = AsyncReader {
+            input,
+            prefetch_row_groups: 0,
+            buffer_pool: None,
+        };
+        Self::new_builder(input, metadata)
+    }
+
+    /// Fetch the data of up to `prefetch_row_groups` row groups ahead of the row
+    /// group being decoded (defaults to 0)
+    ///
+    /// By default, [`ParquetRecordBatchStream`] fetches the data for a row group
+    /// once the previous row group has been decoded, so decoding waits on each
+    /// I/O request. When set, the stream issues the requests for the next row
+    /// groups while returning batches from the current one, so that the latency
+    /// of fetching data, for example from an object store, overlaps with decoding.
+    ///
+    /// The data for the prefetched row groups is buffered in memory, so memory
+    /// usage grows with the number of prefetched row groups. Any [`RowFilter`]s
+    /// are also evaluated on the prefetched row groups when their data arrives.
+    ///
+    /// When the stream requests the data for a row group, it also requests the
+    /// column chunks of the following row groups that are not yet fetched, up
+    /// to `prefetch_row_groups` ahead of the one being decoded. Ranges separated
+    /// by at most [`PREFETCH_COALESCE_GAP`] bytes are merged into a single range
+    /// before calling [`AsyncFileReader::get_byte_ranges`], so that the column
+    /// chunks of consecutive row groups are usually fetched in one request.
+    ///
+    /// Entire column chunks are prefetched, so data that is later skipped, for
+    /// example by a [`RowSelection`] or [`RowFilter`], may still be fetched.
+    ///
+    /// [`RowSelection`]: crate::arrow::arrow_reader::RowSelection
+    /// [`RowFilter`]: crate::arrow::arrow_reader::RowFilter
+    pub fn with_prefetch_row_groups(mut self, prefetch_row_groups: usize) -> Self {
+        self.input.prefetch_row_groups = prefetch_row_groups;
+        self
+    }
+
+    /// Read the data of row groups into buffers obtained from `pool`
+    ///
+    /// When set, the stream fetches data with [`AsyncFileReader::get_byte_ranges_into`]
+    /// rather than [`AsyncFileReader::get_byte_ranges`], allowing readers with
+    /// completion-based I/O to read directly into owned buffers provided by `pool`.
+    pub fn with_buffer_pool(mut self, pool: Arc<dyn BufferPool>) -> Self {
+        self.input.buffer_pool = Some(pool);
+        self
     }
 
     /// Read bloom filter for a column in a row group
@@ -525,10 +626,10 @@ impl<T: AsyncFileReader + Send + 'static> ParquetRecordBatchStreamBuilder<T> {
         };
 
         let buffer = match column_metadata.bloom_filter_length() {
-            Some(length) => self.input.0.get_bytes(offset..offset + length as u64),
+            Some(length) => self.input.input.get_bytes(offset..offset + length as u64),
             None => self
                 .input
-                .0
+                .input
                 .get_bytes(offset..offset + SBBF_HEADER_SIZE_ESTIMATE as u64),
         }
         .await?;
@@ -562,7 +663,7 @@ impl<T: AsyncFileReader + Send + 'static> ParquetRecordBatchStreamBuilder<T> {
                     ParquetError::General("Bloom filter length is invalid".to_string())
                 })?;
                 self.input
-                    .0
+                    .input
                     .get_bytes(bitset_offset..bitset_offset + bitset_length)
                     .await?
             }
@@ -570,6 +671,21 @@ impl<T: AsyncFileReader + Send + 'static> ParquetRecordBatchStreamBuilder<T> {
         Ok(Some(Sbbf::new(&bitset)))
     }
 
+    /// Read bloom filter for a leaf column in a row group, identified by its path
+    ///
+    /// This allows reading the bloom filters of nested columns, for example the path of
+    /// the field `b` of a struct column `a` is `a.b`. See
+    /// [`Self::get_row_group_column_bloom_filter`] for more details.
+    pub async fn get_row_group_column_bloom_filter_by_path(
+        &mut self,
+        row_group_idx: usize,
+        path: &ColumnPath,
+    ) -> Result<Option<Sbbf>> {
+        let column_idx = self.leaf_column_index(path)?;
+        self.get_row_group_column_bloom_filter(row_group_idx, column_idx)
+            .await
+    }
+
     /// Build a new [`ParquetRecordBatchStream`]
     ///
     /// See examples on [`ParquetRecordBatchStreamBuilder::new`]
@@ -599,6 +715,20 @@ impl<T: AsyncFileReader + Send + 'static> ParquetRecordBatchStreamBuilder<T> {
             .filter_leaves(|idx, _| idx < projection_len && projection.leaf_included(idx));
         let projected_schema = Arc::new(Schema::new(projected_fields));
 
+        // The leaf columns whose chunks are fetched ahead when prefetching
+        let prefetch_columns = (0..metadata.file_metadata().schema_descr().num_columns())
+            .filter(|&idx| {
+                projection.leaf_included(idx)
+                    || filter.as_ref().is_some_and(|filter| {
+                        filter
+                            .predicates
+                            .iter()
+                            .any(|predicate| predicate.projection().leaf_included(idx))
+                    })
+            })
+            .collect();
+        let parquet_metadata = Arc::clone(&metadata);
+
         let decoder = ParquetPushDecoderBuilder {
             input: PushDecoderInput::default(),
             metadata,
@@ -617,12 +747,24 @@ impl<T: AsyncFileReader + Send + 'static> ParquetRecordBatchStreamBuilder<T> {
         }
         .build()?;
 
-        let request_state = RequestState::None { input: input.0 };
+        let AsyncReader {
+            input,
+            prefetch_row_groups,
+            buffer_pool,
+        } = input;
+        let request_state = RequestState::None { input };
 
         Ok(ParquetRecordBatchStream {
             schema: projected_schema,
             decoder,
             request_state,
+            prefetch_row_groups,
+            buffer_pool,
+            metadata: parquet_metadata,
+            prefetch_columns,
+            prefetched: VecDeque::new(),
+            readers: VecDeque::new(),
+            decoder_finished: false,
         })
     }
 }
@@ -652,8 +794,42 @@ impl<T> RequestState<T>
 where
     T: AsyncFileReader + Unpin + Send + 'static,
 {
+    /// Issue a request to fetch `ranges`, merging ranges separated by at most
+    /// `gap` bytes into a single range, returning the Outstanding state
+    ///
+    /// The data of each of `ranges` is sliced out of the merged ranges once
+    /// the request completes.
+    fn begin_coalesced_request(
+        mut input: T,
+        ranges: Vec<Range<u64>>,
+        gap: u64,
+        pool: Option<Arc<dyn BufferPool>>,
+    ) -> Self {
+        let fetch_ranges = coalesce_ranges(&ranges, gap);
+        let ranges_captured = ranges.clone();
+
+        let future = async move {
+            let fetched = match pool {
+                Some(pool) => {
+                    input
+                        .get_byte_ranges_into(fetch_ranges.clone(), pool)
+                        .await?
+                }
+                None => input.get_byte_ranges(fetch_ranges.clone()).await?,
+            };
+            let data = split_ranges(&fetch_ranges, &fetched, &ranges_captured)?;
+            Ok((input, data))
+        }
+        .boxed();
+        RequestState::Outstanding { ranges, future }
+    }
+
     /// Issue a request to fetch `ranges`, returning the Outstanding state
-    fn begin_request(mut input: T, ranges: Vec<Range<u64>>) -> Self {
+    fn begin_request(
+        mut input: T,
+        ranges: Vec<Range<u64>>,
+        pool: Option<Arc<dyn BufferPool>>,
+    ) -> Self {
         let ranges_captured = ranges.clone();
 
         // Note this must move the input *into* the future
@@ -661,7 +837,10 @@ where
         // (aka can have references internally) and thus must
         // own the input while the request is outstanding.
         let future = async move {
-            let data = input.get_byte_ranges(ranges_captured).await?;
+            let data = match pool {
+                Some(pool) => input.get_byte_ranges_into(ranges_captured, pool).await?,
+                None => input.get_byte_ranges(ranges_captured).await?,
+            };
             Ok((input, data))
         }
         .boxed();
@@ -669,6 +848,57 @@ where
     }
 }
 
+/// Sorts `ranges` and merges those separated by at most `gap` bytes
+fn coalesce_ranges(ranges: &[Range<u64>], gap: u64) -> Vec<Range<u64>> {
+    let mut sorted = ranges.to_vec();
+    sorted.sort_unstable_by_key(|range| range.start);
+
+    let mut merged: Vec<Range<u64>> = Vec::with_capacity(sorted.len());
+    for range in sorted {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end.saturating_add(gap) => {
+                last.end = last.end.max(range.end);
+            }
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
+/// Slices the data of each of `ranges` out of the data fetched for the sorted,
+/// non-overlapping `fetch_ranges` that contain them
+fn split_ranges(
+    fetch_ranges: &[Range<u64>],
+    fetched: &[Bytes],
+    ranges: &[Range<u64>],
+) -> Result<Vec<Bytes>> {
+    if fetched.len() != fetch_ranges.len() {
+        return Err(ParquetError::General(format!(
+            "Expected {} buffers for the requested ranges, got {}",
+            fetch_ranges.len(),
+            fetched.len()
+        )));
+    }
+    ranges
+        .iter()
+        .map(|range| {
+            let idx = fetch_ranges.partition_point(|fetch| fetch.start <= range.start) - 1;
+            let fetch = &fetch_ranges[idx];
+            let data = &fetched[idx];
+            let start = (range.start - fetch.start) as usize;
+            let end = (range.end - fetch.start) as usize;
+            if end > data.len() {
+                return Err(ParquetError::EOF(format!(
+                    "Expected {} bytes for range {fetch:?}, got {}",
+                    fetch.end - fetch.start,
+                    data.len()
+                )));
+            }
+            Ok(data.slice(start..end))
+        })
+        .collect()
+}
+
 impl<T> std::fmt::Debug for RequestState<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -713,12 +943,32 @@ pub struct ParquetRecordBatchStream<T> {
     request_state: RequestState<T>,
     /// Decoding state machine (no IO)
     decoder: ParquetPushDecoder,
+    /// The number of row groups to fetch ahead, see
+    /// [`ParquetRecordBatchStreamBuilder::with_prefetch_row_groups`]
+    prefetch_row_groups: usize,
+    /// The pool to read data into, see
+    /// [`ParquetRecordBatchStreamBuilder::with_buffer_pool`]
+    buffer_pool: Option<Arc<dyn BufferPool>>,
+    /// The metadata of the file, used to locate the column chunks to prefetch
+    metadata: Arc<ParquetMetaData>,
+    /// The leaf columns whose chunks are fetched ahead, if prefetching
+    prefetch_columns: Vec<usize>,
+    /// The row groups whose column chunks have been requested ahead of the
+    /// decoder, and the ranges requested for them, if prefetching
+    prefetched: VecDeque<(usize, Vec<Range<u64>>)>,
+    /// Readers for the row groups whose data has been fetched, if prefetching
+    readers: VecDeque<ParquetRecordBatchReader>,
+    /// True once the decoder has no more row groups to return, if prefetching
+    decoder_finished: bool,
 }
 
 impl<T> std::fmt::Debug for ParquetRecordBatchStream<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("ParquetRecordBatchStream")
             .field("request_state", &self.request_state)
+            .field("prefetch_row_groups", &self.prefetch_row_groups)
+            .field("prefetched", &self.prefetched)
+            .field("readers", &self.readers.len())
             .finish()
     }
 }
@@ -760,7 +1010,11 @@ where
                 RequestState::None { input } => {
                     match self.decoder.try_next_reader()? {
                         DecodeResult::NeedsData(ranges) => {
-                            self.request_state = RequestState::begin_request(input, ranges);
+                            self.request_state = RequestState::begin_request(
+                                input,
+                                ranges,
+                                self.buffer_pool.clone(),
+                            );
                             continue; // poll again (as the input might be ready immediately)
                         }
                         DecodeResult::Data(reader) => {
@@ -815,6 +1069,9 @@ where
     /// Note this is separate from poll_next so we can use ? operator to check for errors
     /// as it returns `Result<Poll<Option<RecordBatch>>>`
     fn poll_next_inner(&mut self, cx: &mut Context<'_>) -> Result<Poll<Option<RecordBatch>>> {
+        if self.prefetch_row_groups > 0 {
+            return self.poll_next_prefetch(cx);
+        }
         loop {
             let request_state = std::mem::replace(&mut self.request_state, RequestState::Done);
             match request_state {
@@ -822,7 +1079,11 @@ where
                     // No outstanding requests, proceed to decode the next batch
                     match self.decoder.try_decode()? {
                         DecodeResult::NeedsData(ranges) => {
-                            self.request_state = RequestState::begin_request(input, ranges);
+                            self.request_state = RequestState::begin_request(
+                                input,
+                                ranges,
+                                self.buffer_pool.clone(),
+                            );
                             continue; // poll again (as the input might be ready immediately)
                         }
                         DecodeResult::Data(batch) => {
@@ -857,6 +1118,128 @@ where
             }
         }
     }
+
+    /// State machine used when prefetching row groups
+    ///
+    /// Each call first drives any outstanding request, and requests the data
+    /// for the next row group if fewer than `prefetch_row_groups` row groups
+    /// are buffered ahead of the current one. Batches are then decoded from
+    /// the reader of the current row group.
+    ///
+    /// See [`Self::prefetch_ranges`] for how the requests of consecutive row
+    /// groups are combined.
+    fn poll_next_prefetch(&mut self, cx: &mut Context<'_>) -> Result<Poll<Option<RecordBatch>>> {
+        loop {
+            let request_state = std::mem::replace(&mut self.request_state, RequestState::Done);
+            match request_state {
+                RequestState::None { input } => {
+                    if !self.decoder_finished && self.readers.len() <= self.prefetch_row_groups {
+                        match self.decoder.try_next_reader()? {
+                            DecodeResult::NeedsData(ranges) => {
+                                let ranges = self.prefetch_ranges(ranges);
+                                self.request_state = RequestState::begin_coalesced_request(
+                                    input,
+                                    ranges,
+                                    PREFETCH_COALESCE_GAP,
+                                    self.buffer_pool.clone(),
+                                );
+                                continue; // poll the request (it might be ready immediately)
+                            }
+                            DecodeResult::Data(reader) => {
+                                self.readers.push_back(reader);
+                                self.release_prefetched();
+                                self.request_state = RequestState::None { input };
+                                continue; // try and fetch the next row group
+                            }
+                            DecodeResult::Finished => self.decoder_finished = true,
+                        }
+                    }
+                    self.request_state = RequestState::None { input };
+                }
+                RequestState::Outstanding { ranges, mut future } => match future.poll_unpin(cx) {
+                    Poll::Ready(result) => {
+                        let (input, data) = result?;
+                        self.decoder.push_ranges(ranges, data)?;
+                        self.request_state = RequestState::None { input };
+                    }
+                    Poll::Pending => {
+                        self.request_state = RequestState::Outstanding { ranges, future };
+                    }
+                },
+                RequestState::Done => {
+                    self.request_state = RequestState::Done;
+                    return Ok(Poll::Ready(None));
+                }
+            }
+
+            if let Some(reader) = self.readers.front_mut() {
+                match reader.next().transpose()? {
+                    Some(batch) => return Ok(Poll::Ready(Some(batch))),
+                    None => {
+                        // the current row group is done, proceed to the next one
+                        self.readers.pop_front();
+                        continue;
+                    }
+                }
+            }
+
+            // No data is ready to decode
+            match &self.request_state {
+                // the request was polled above, and will wake the task when ready
+                RequestState::Outstanding { .. } => return Ok(Poll::Pending),
+                RequestState::None { .. } if self.decoder_finished => {
+                    self.request_state = RequestState::Done;
+                    return Ok(Poll::Ready(None));
+                }
+                _ => {} // request the data for the next row group
+            }
+        }
+    }
+
+    /// Adds the column chunks of the row groups queued after the one the
+    /// decoder requested `ranges` for to the request, up to
+    /// `prefetch_row_groups` row groups ahead of the one being decoded
+    ///
+    /// The decoder then finds the data of those row groups already buffered
+    /// when it reaches them, so that they need no further requests.
+    fn prefetch_ranges(&mut self, mut ranges: Vec<Range<u64>>) -> Vec<Range<u64>> {
+        // the row group being requested is decoded after those in `readers`
+        let ahead = self.prefetch_row_groups.saturating_sub(self.readers.len());
+        for row_group_idx in self.decoder.queued_row_groups().into_iter().take(ahead) {
+            if self.prefetched.iter().any(|(idx, _)| *idx == row_group_idx) {
+                continue;
+            }
+            let row_group = self.metadata.row_group(row_group_idx);
+            let chunks: Vec<_> = self
+                .prefetch_columns
+                .iter()
+                .map(|&column| {
+                    let (start, len) = row_group.column(column).byte_range();
+                    start..start + len
+                })
+                .collect();
+            ranges.extend(chunks.iter().cloned());
+            self.prefetched.push_back((row_group_idx, chunks));
+        }
+        ranges
+    }
+
+    /// Releases the prefetched data of row groups that the decoder has moved
+    /// past
+    ///
+    /// The decoder only releases the ranges it requested itself, which differ
+    /// from the prefetched column chunks when it reads a subset of the pages
+    /// of a row group, or skips the row group entirely.
+    fn release_prefetched(&mut self) {
+        let queued = self.decoder.queued_row_groups();
+        while let Some((row_group_idx, _)) = self.prefetched.front() {
+            if queued.contains(row_group_idx) {
+                break;
+            }
+            let (_, ranges) = self.prefetched.pop_front().unwrap();
+            self.decoder.clear_ranges(&ranges);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -885,6 +1268,7 @@ mod tests {
     use futures::{StreamExt, TryStreamExt};
     use rand::{Rng, rng};
     use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
     use std::sync::{Arc, Mutex};
     use tempfile::tempfile;
 
@@ -1412,6 +1796,282 @@ mod tests {
         assert_eq!(col2.values(), &[4, 5]);
     }
 
+    /// A [`TestReader`] whose requests are pending until polled again
+    struct YieldingReader(TestReader);
+
+    impl AsyncFileReader for YieldingReader {
+        fn get_bytes(&mut self, range: Range<u64>) -> BoxFuture<'_, Result<Bytes>> {
+            let data = self.get_byte_ranges(vec![range]);
+            async move { Ok(data.await?.remove(0)) }.boxed()
+        }
+
+        fn get_byte_ranges(
+            &mut self,
+            ranges: Vec<Range<u64>>,
+        ) -> BoxFuture<'_, Result<Vec<Bytes>>> {
+            let ranges: Vec<_> = ranges
+                .into_iter()
+                .map(|r| r.start as usize..r.end as usize)
+                .collect();
+            self.0
+                .requests
+                .lock()
+                .unwrap()
+                .extend(ranges.iter().cloned());
+            let data = ranges.into_iter().map(|r| self.0.data.slice(r)).collect();
+            async move {
+                tokio::task::yield_now().await;
+                Ok(data)
+            }
+            .boxed()
+        }
+
+        fn get_metadata<'a>(
+            &'a mut self,
+            options: Option<&'a ArrowReaderOptions>,
+        ) -> BoxFuture<'a, Result<Arc<ParquetMetaData>>> {
+            self.0.get_metadata(options)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_prefetch_row_groups() {
+        let a = Int32Array::from_iter_values(0..400);
+        let b = StringArray::from_iter_values((0..400).map(|x| format!("value{x}")));
+        let data = RecordBatch::try_from_iter([
+            ("a", Arc::new(a) as ArrayRef),
+            ("b", Arc::new(b) as ArrayRef),
+        ])
+        .unwrap();
+
+        let mut buf = Vec::with_capacity(1024);
+        let props = WriterProperties::builder()
+            .set_max_row_group_row_count(Some(100))
+            .build();
+        let mut writer = ArrowWriter::try_new(&mut buf, data.schema(), Some(props)).unwrap();
+        writer.write(&data).unwrap();
+        writer.close().unwrap();
+        let data: Bytes = buf.into();
+
+        let metadata = ParquetMetaDataReader::new()
+            .parse_and_finish(&data)
+            .unwrap();
+        let chunk = |row_group: usize, column: usize| {
+            let (start, len) = metadata.row_group(row_group).column(column).byte_range();
+            start as usize..(start + len) as usize
+        };
+
+        let read = |prefetch_row_groups: usize, filter: bool| {
+            let test = TestReader::new(data.clone());
+            let requests = Arc::clone(&test.requests);
+            async move {
+                let mut builder = ParquetRecordBatchStreamBuilder::new(YieldingReader(test))
+                    .await
+                    .unwrap()
+                    .with_batch_size(30)
+                    .with_prefetch_row_groups(prefetch_row_groups);
+                if filter {
+                    let mask = ProjectionMask::leaves(builder.parquet_schema(), [0]);
+                    let predicate = ArrowPredicateFn::new(mask, |batch: RecordBatch| {
+                        let a = batch.column(0).as_primitive::<Int32Type>();
+                        Ok(BooleanArray::from_iter(
+                            a.iter().map(|v| v.map(|v| v % 3 == 0)),
+                        ))
+                    });
+                    builder = builder
+                        .with_row_filter(RowFilter::new(vec![Box::new(predicate)]))
+                        .with_offset(10)
+                        .with_limit(70);
+                }
+                let mut stream = builder.build().unwrap();
+
+                // the ranges requested before the first batch is returned
+                let first = stream.next().await.unwrap().unwrap();
+                let requests_before_first_batch = requests.lock().unwrap().clone();
+
+                let mut batches = vec![first];
+                while let Some(batch) = stream.next().await {
+                    batches.push(batch.unwrap());
+                }
+                (batches, requests_before_first_batch)
+            }
+        };
+
+        for filter in [false, true] {
+            let (expected, requests) = read(0, filter).await;
+            assert!(requests.iter().all(|r| r.end <= chunk(0, 1).end));
+
+            for prefetch_row_groups in [1, 2, 10] {
+                let (batches, prefetched_requests) = read(prefetch_row_groups, filter).await;
+                assert_eq!(batches, expected);
+
+                // the column chunks of the next row groups are requested
+                // before returning the first batch, coalesced with those of
+                // the first row group
+                let last = prefetch_row_groups.min(3);
+                let prefetched = chunk(0, 0).start..chunk(last, 1).end;
+                if filter {
+                    // the output column of the first row group is requested
+                    // once the filter has been evaluated
+                    assert_eq!(prefetched_requests, vec![prefetched, chunk(0, 1)]);
+                } else {
+                    assert_eq!(prefetched_requests, vec![prefetched]);
+                }
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_prefetch_row_groups_releases_data() {
+        let a = Int32Array::from_iter_values(0..400);
+        let data = RecordBatch::try_from_iter([("a", Arc::new(a) as ArrayRef)]).unwrap();
+
+        let mut buf = Vec::with_capacity(1024);
+        let props = WriterProperties::builder()
+            .set_max_row_group_row_count(Some(100))
+            .set_data_page_row_count_limit(10)
+            .set_write_batch_size(10)
+            .build();
+        let mut writer = ArrowWriter::try_new(&mut buf, data.schema(), Some(props)).unwrap();
+        writer.write(&data).unwrap();
+        writer.close().unwrap();
+        let data: Bytes = buf.into();
+
+        // select a few pages of each row group, so that the decoder requests
+        // ranges within the prefetched column chunks
+        let selection = RowSelection::from(vec![
+            RowSelector::skip(15),
+            RowSelector::select(20),
+            RowSelector::skip(150),
+            RowSelector::select(30),
+            RowSelector::skip(100),
+            RowSelector::select(85),
+        ]);
+
+        let read = |prefetch_row_groups: usize| {
+            let test = TestReader::new(data.clone());
+            let selection = selection.clone();
+            async move {
+                let options =
+                    ArrowReaderOptions::new().with_page_index_policy(PageIndexPolicy::Required);
+                let mut stream = ParquetRecordBatchStreamBuilder::new_with_options(
+                    YieldingReader(test),
+                    options,
+                )
+                .await
+                .unwrap()
+                .with_row_selection(selection)
+                .with_prefetch_row_groups(prefetch_row_groups)
+                .build()
+                .unwrap();
+
+                let mut batches = vec![];
+                while let Some(batch) = stream.next().await {
+                    batches.push(batch.unwrap());
+                    // the prefetched data is released once the reader for
+                    // its row group has been created
+                    assert_eq!(stream.decoder.buffered_bytes(), 0);
+                }
+                batches
+            }
+        };
+
+        let expected = read(0).await;
+        assert_eq!(read(2).await, expected);
+    }
+
+    #[test]
+    fn test_coalesce_and_split_ranges() {
+        let ranges = vec![30..40, 0..10, 12..20, 100..110, 35..38];
+        let fetch_ranges = coalesce_ranges(&ranges, 2);
+        assert_eq!(fetch_ranges, vec![0..20, 30..40, 100..110]);
+        assert_eq!(
+            coalesce_ranges(&ranges, 0),
+            vec![0..10, 12..20, 30..40, 100..110]
+        );
+        assert_eq!(coalesce_ranges(&ranges, 100), vec![0..110]);
+
+        let file = Bytes::from_iter(0..=u8::MAX);
+        let fetched: Vec<_> = fetch_ranges
+            .iter()
+            .map(|r| file.slice(r.start as usize..r.end as usize))
+            .collect();
+        let data = split_ranges(&fetch_ranges, &fetched, &ranges).unwrap();
+        for (range, data) in ranges.iter().zip(data) {
+            assert_eq!(data, file.slice(range.start as usize..range.end as usize));
+        }
+
+        let err = split_ranges(&fetch_ranges, &fetched[..2], &ranges).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Expected 3 buffers for the requested ranges, got 2"
+        );
+        let short = vec![
+            fetched[0].clone(),
+            fetched[1].slice(..5),
+            fetched[2].clone(),
+        ];
+        let err = split_ranges(&fetch_ranges, &short, &ranges).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "EOF: Expected 10 bytes for range 30..40, got 5"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_buffer_pool() {
+        #[derive(Debug, Default)]
+        struct CountingPool {
+            allocated: AtomicUsize,
+            released: AtomicUsize,
+        }
+
+        impl BufferPool for CountingPool {
+            fn get(&self, len: usize) -> Vec<u8> {
+                self.allocated.fetch_add(len, Ordering::Relaxed);
+                AllocatingBufferPool.get(len)
+            }
+
+            fn release(&self, buffer: Vec<u8>) {
+                self.released.fetch_add(buffer.len(), Ordering::Relaxed);
+            }
+        }
+
+        let a = Int32Array::from_iter_values(0..400);
+        let data = RecordBatch::try_from_iter([("a", Arc::new(a) as ArrayRef)]).unwrap();
+        let mut buf = Vec::with_capacity(1024);
+        let props = WriterProperties::builder()
+            .set_max_row_group_row_count(Some(100))
+            .build();
+        let mut writer = ArrowWriter::try_new(&mut buf, data.schema(), Some(props)).unwrap();
+        writer.write(&data).unwrap();
+        writer.close().unwrap();
+
+        let pool = Arc::new(CountingPool::default());
+        let buf = Bytes::from(buf);
+        let stream = ParquetRecordBatchStreamBuilder::new(std::io::Cursor::new(buf.clone()))
+            .await
+            .unwrap()
+            .with_buffer_pool(Arc::clone(&pool) as _)
+            .build()
+            .unwrap();
+        let batches: Vec<_> = stream.try_collect().await.unwrap();
+        let batch = arrow::compute::concat_batches(&data.schema(), &batches).unwrap();
+        assert_eq!(batch, data);
+        drop(batches);
+
+        // the data of every column chunk was read into the pool
+        let metadata = ParquetMetaDataReader::new().parse_and_finish(&buf).unwrap();
+        let expected: i64 = metadata
+            .row_groups()
+            .iter()
+            .map(|rg| rg.column(0).compressed_size())
+            .sum();
+        assert_eq!(pool.allocated.load(Ordering::Relaxed), expected as usize);
+        // and every buffer was released once no longer referenced
+        assert_eq!(pool.released.load(Ordering::Relaxed), expected as usize);
+    }
+
     #[tokio::test]
     async fn test_batch_size_overallocate() {
         let testdata = arrow::util::test_util::parquet_test_data();
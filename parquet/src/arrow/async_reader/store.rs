@@ -15,18 +15,57 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::fmt::Debug;
 use std::{ops::Range, sync::Arc};
 
 use crate::arrow::arrow_reader::ArrowReaderOptions;
 use crate::arrow::async_reader::{AsyncFileReader, MetadataSuffixFetch};
 use crate::errors::{ParquetError, Result};
-use crate::file::metadata::{PageIndexPolicy, ParquetMetaData, ParquetMetaDataReader};
+use crate::file::metadata::{
+    PageIndexPolicy, ParquetMetaData, ParquetMetaDataOptions, ParquetMetaDataReader,
+};
 use bytes::Bytes;
 use futures::{FutureExt, TryFutureExt, future::BoxFuture};
 use object_store::ObjectStoreExt;
 use object_store::{GetOptions, GetRange};
 use object_store::{ObjectStore, path::Path};
 use tokio::runtime::Handle;
+
+/// The key of the [`ParquetMetaData`] of an object in a [`ParquetMetadataCache`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MetadataCacheKey {
+    /// The location of the object
+    pub path: Path,
+    /// The [`ObjectMeta::e_tag`] of the object, identifying its version
+    ///
+    /// [`ObjectMeta::e_tag`]: object_store::ObjectMeta::e_tag
+    pub e_tag: String,
+    /// The policy the column index was read with
+    pub column_index: PageIndexPolicy,
+    /// The policy the offset index was read with
+    pub offset_index: PageIndexPolicy,
+    /// The options the metadata was decoded with
+    pub metadata_options: ParquetMetaDataOptions,
+}
+
+/// A cache of [`ParquetMetaData`] shared between [`ParquetObjectReader`]s
+///
+/// Opening a parquet file requires fetching and decoding its footer. Services that
+/// repeatedly open the same objects can avoid this by caching the decoded metadata,
+/// see [`ParquetObjectReader::with_metadata_cache`].
+///
+/// The metadata is keyed by the page index policies and [`ParquetMetaDataOptions`] it
+/// was read with, as well as the version of the object, so readers configured
+/// differently do not share metadata. Readers configured with file decryption properties
+/// never use the cache, as the metadata of encrypted files holds the decryption keys.
+pub trait ParquetMetadataCache: Debug + Send + Sync {
+    /// Returns the metadata cached for `key`, if any
+    fn get(&self, key: &MetadataCacheKey) -> Option<Arc<ParquetMetaData>>;
+
+    /// Caches `metadata` for `key`
+    fn put(&self, key: MetadataCacheKey, metadata: Arc<ParquetMetaData>);
+}
+
 /// Reads Parquet files in object storage using [`ObjectStore`].
 ///
 /// ```no_run
@@ -60,6 +99,9 @@ pub struct ParquetObjectReader {
     preload_column_index: bool,
     preload_offset_index: bool,
     runtime: Option<Handle>,
+    e_tag: Option<String>,
+    metadata_cache: Option<Arc<dyn ParquetMetadataCache>>,
+    coalesce: Option<u64>,
 }
 
 impl ParquetObjectReader {
@@ -73,6 +115,9 @@ impl ParquetObjectReader {
             preload_column_index: false,
             preload_offset_index: false,
             runtime: None,
+            e_tag: None,
+            metadata_cache: None,
+            coalesce: None,
         }
     }
 
@@ -140,6 +185,72 @@ impl ParquetObjectReader {
         }
     }
 
+    /// Provide the [`ObjectMeta::e_tag`] of this file, identifying its version
+    ///
+    /// This is required for the metadata to be cached, see [`Self::with_metadata_cache`].
+    /// Like the file size, it can be obtained using [`ObjectStore::list`] or
+    /// [`ObjectStoreExt::head`].
+    ///
+    /// [`ObjectMeta::e_tag`]: object_store::ObjectMeta::e_tag
+    pub fn with_e_tag(self, e_tag: impl Into<String>) -> Self {
+        Self {
+            e_tag: Some(e_tag.into()),
+            ..self
+        }
+    }
+
+    /// Cache the [`ParquetMetaData`] of this file in `cache`
+    ///
+    /// [`Self::get_metadata`] returns the metadata from `cache` without any requests if
+    /// present, otherwise it is loaded and inserted into `cache`. The metadata is keyed
+    /// by the path and the e-tag of the file, and the options it is read with, see
+    /// [`MetadataCacheKey`]. It is only cached if the e-tag has been provided with
+    /// [`Self::with_e_tag`], and not if file decryption properties are provided.
+    pub fn with_metadata_cache(self, cache: Arc<dyn ParquetMetadataCache>) -> Self {
+        Self {
+            metadata_cache: Some(cache),
+            ..self
+        }
+    }
+
+    /// Coalesce requests for ranges separated by less than `coalesce` bytes
+    ///
+    /// By default, [`AsyncFileReader::get_byte_ranges`] uses [`ObjectStore::get_ranges`],
+    /// which coalesces ranges separated by less than [`OBJECT_STORE_COALESCE_DEFAULT`].
+    /// Smaller values read fewer unused bytes, at the cost of more requests.
+    ///
+    /// [`OBJECT_STORE_COALESCE_DEFAULT`]: object_store::OBJECT_STORE_COALESCE_DEFAULT
+    pub fn with_coalesce(self, coalesce: u64) -> Self {
+        Self {
+            coalesce: Some(coalesce),
+            ..self
+        }
+    }
+
+    /// Returns the key of this file in the metadata cache, if any
+    fn metadata_cache_key(
+        &self,
+        options: Option<&ArrowReaderOptions>,
+        column_index: PageIndexPolicy,
+        offset_index: PageIndexPolicy,
+    ) -> Option<(Arc<dyn ParquetMetadataCache>, MetadataCacheKey)> {
+        #[cfg(feature = "encryption")]
+        if options.is_some_and(|o| o.file_decryption_properties.is_some()) {
+            return None;
+        }
+        let cache = Arc::clone(self.metadata_cache.as_ref()?);
+        let key = MetadataCacheKey {
+            path: self.path.clone(),
+            e_tag: self.e_tag.clone()?,
+            column_index,
+            offset_index,
+            metadata_options: options
+                .map(|o| o.metadata_options().clone())
+                .unwrap_or_default(),
+        };
+        Some((cache, key))
+    }
+
     fn spawn<F, O, E>(&self, f: F) -> BoxFuture<'_, Result<O>>
     where
         F: for<'a> FnOnce(&'a Arc<dyn ObjectStore>, &'a Path) -> BoxFuture<'a, Result<O, E>>
@@ -193,7 +304,17 @@ impl AsyncFileReader for ParquetObjectReader {
     where
         Self: Send,
     {
-        self.spawn(|store, path| async move { store.get_ranges(path, &ranges).await }.boxed())
+        match self.coalesce {
+            Some(coalesce) => self.spawn(move |store, path| {
+                async move {
+                    let fetch = |range| store.get_range(path, range);
+                    object_store::coalesce_ranges(&ranges, fetch, coalesce).await
+                }
+                .boxed()
+            }),
+            None => self
+                .spawn(|store, path| async move { store.get_ranges(path, &ranges).await }.boxed()),
+        }
     }
 
     // This method doesn't directly call `self.spawn` because all of the IO that is done down the
@@ -207,41 +328,53 @@ impl AsyncFileReader for ParquetObjectReader {
         options: Option<&'a ArrowReaderOptions>,
     ) -> BoxFuture<'a, Result<Arc<ParquetMetaData>>> {
         Box::pin(async move {
-            let metadata_opts = options.map(|o| o.metadata_options().clone());
-            let mut metadata = ParquetMetaDataReader::new()
-                .with_metadata_options(metadata_opts)
-                .with_column_index_policy(PageIndexPolicy::from(self.preload_column_index))
-                .with_offset_index_policy(PageIndexPolicy::from(self.preload_offset_index))
-                .with_prefetch_hint(self.metadata_size_hint);
-
-            #[cfg(feature = "encryption")]
-            if let Some(options) = options {
-                metadata = metadata.with_decryption_properties(
-                    options.file_decryption_properties.as_ref().map(Arc::clone),
-                );
-            }
-
             // Override page index policies from ArrowReaderOptions if specified and not Skip.
             // When page_index_policy is Skip (default), use the reader's preload flags.
             // When page_index_policy is Optional or Required, override the preload flags
             // to ensure the specified policy takes precedence.
-            if let Some(options) = options {
-                if options.column_index_policy() != PageIndexPolicy::Skip
-                    || options.offset_index_policy() != PageIndexPolicy::Skip
+            let (column_index, offset_index) = match options {
+                Some(options)
+                    if options.column_index_policy() != PageIndexPolicy::Skip
+                        || options.offset_index_policy() != PageIndexPolicy::Skip =>
                 {
-                    metadata = metadata
-                        .with_column_index_policy(options.column_index_policy())
-                        .with_offset_index_policy(options.offset_index_policy());
+                    (options.column_index_policy(), options.offset_index_policy())
+                }
+                _ => (
+                    PageIndexPolicy::from(self.preload_column_index),
+                    PageIndexPolicy::from(self.preload_offset_index),
+                ),
+            };
+
+            let cache_key = self.metadata_cache_key(options, column_index, offset_index);
+            if let Some((cache, key)) = &cache_key {
+                if let Some(metadata) = cache.get(key) {
+                    return Ok(metadata);
                 }
             }
 
+            let metadata_opts = options.map(|o| o.metadata_options().clone());
+            let metadata = ParquetMetaDataReader::new()
+                .with_metadata_options(metadata_opts)
+                .with_column_index_policy(column_index)
+                .with_offset_index_policy(offset_index)
+                .with_prefetch_hint(self.metadata_size_hint);
+
+            #[cfg(feature = "encryption")]
+            let metadata = metadata.with_decryption_properties(
+                options.and_then(|o| o.file_decryption_properties.as_ref().map(Arc::clone)),
+            );
+
             let metadata = if let Some(file_size) = self.file_size {
                 metadata.load_and_finish(self, file_size).await?
             } else {
                 metadata.load_via_suffix_and_finish(self).await?
             };
 
-            Ok(Arc::new(metadata))
+            let metadata = Arc::new(metadata);
+            if let Some((cache, key)) = cache_key {
+                cache.put(key, Arc::clone(&metadata));
+            }
+            Ok(metadata)
         })
     }
 }
@@ -257,14 +390,22 @@ mod tests {
 
     use futures::TryStreamExt;
 
-    use crate::arrow::ParquetRecordBatchStreamBuilder;
-    use crate::arrow::async_reader::{AsyncFileReader, ParquetObjectReader};
+    use crate::arrow::async_reader::{
+        AsyncFileReader, MetadataCacheKey, ParquetMetadataCache, ParquetObjectReader,
+    };
+    use crate::arrow::{ArrowWriter, ParquetRecordBatchStreamBuilder};
     use crate::errors::ParquetError;
+    use crate::file::metadata::ParquetMetaData;
+    use crate::file::properties::WriterProperties;
     use arrow::util::test_util::parquet_test_data;
+    use arrow_array::{ArrayRef, Int32Array, RecordBatch};
     use futures::FutureExt;
     use object_store::local::LocalFileSystem;
+    use object_store::memory::InMemory;
     use object_store::path::Path;
     use object_store::{ObjectMeta, ObjectStore, ObjectStoreExt};
+    use std::collections::HashMap;
+    use std::sync::Mutex;
 
     async fn get_meta_store() -> (ObjectMeta, Arc<dyn ObjectStore>) {
         let res = parquet_test_data();
@@ -507,4 +648,191 @@ mod tests {
         // The test file has page indexes, so they will be some
         assert!(metadata.column_index().is_some() && metadata.column_index().is_some());
     }
+
+    async fn put_test_file(store: &dyn ObjectStore, path: &Path) -> ObjectMeta {
+        let a = Int32Array::from_iter_values(0..1000);
+        let batch = RecordBatch::try_from_iter([("a", Arc::new(a) as ArrayRef)]).unwrap();
+        let props = WriterProperties::builder()
+            .set_max_row_group_row_count(Some(100))
+            .build();
+        let mut buf = vec![];
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        store.put(path, buf.into()).await.unwrap();
+        store.head(path).await.unwrap()
+    }
+
+    #[derive(Debug, Default)]
+    struct TestCache(Mutex<HashMap<MetadataCacheKey, Arc<ParquetMetaData>>>);
+
+    impl ParquetMetadataCache for TestCache {
+        fn get(&self, key: &MetadataCacheKey) -> Option<Arc<ParquetMetaData>> {
+            self.0.lock().unwrap().get(key).cloned()
+        }
+
+        fn put(&self, key: MetadataCacheKey, metadata: Arc<ParquetMetaData>) {
+            self.0.lock().unwrap().insert(key, metadata);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_metadata_cache() {
+        let store: Arc<dyn ObjectStore> = Arc::new(InMemory::new());
+        let path = Path::from("test.parquet");
+        let meta = put_test_file(store.as_ref(), &path).await;
+        let e_tag = meta.e_tag.unwrap();
+
+        let cache = Arc::new(TestCache::default());
+        let reader = || {
+            ParquetObjectReader::new(Arc::clone(&store), path.clone())
+                .with_metadata_cache(Arc::clone(&cache) as _)
+        };
+
+        // without an e-tag the metadata is not cached
+        let metadata = reader().get_metadata(None).await.unwrap();
+        assert_eq!(metadata.num_row_groups(), 10);
+        assert!(cache.0.lock().unwrap().is_empty());
+
+        let cached = reader()
+            .with_e_tag(&e_tag)
+            .get_metadata(None)
+            .await
+            .unwrap();
+        assert_eq!(cache.0.lock().unwrap().len(), 1);
+
+        // the cached metadata is returned without reading the object
+        store.delete(&path).await.unwrap();
+        let metadata = reader()
+            .with_e_tag(&e_tag)
+            .get_metadata(None)
+            .await
+            .unwrap();
+        assert!(Arc::ptr_eq(&metadata, &cached));
+
+        // a different version of the object must be read
+        let result = reader().with_e_tag("other").get_metadata(None).await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_metadata_cache_page_index() {
+        let store: Arc<dyn ObjectStore> = Arc::new(InMemory::new());
+        let path = Path::from("test.parquet");
+        let meta = put_test_file(store.as_ref(), &path).await;
+
+        let cache = Arc::new(TestCache::default());
+        let reader = || {
+            ParquetObjectReader::new(Arc::clone(&store), path.clone())
+                .with_e_tag(meta.e_tag.as_ref().unwrap())
+                .with_metadata_cache(Arc::clone(&cache) as _)
+        };
+        let with_page_index =
+            ArrowReaderOptions::new().with_page_index_policy(PageIndexPolicy::Required);
+
+        let metadata = reader().get_metadata(Some(&with_page_index)).await.unwrap();
+        assert!(metadata.column_index().is_some());
+        assert!(metadata.offset_index().is_some());
+
+        // the metadata read with the page index is not returned to readers without it
+        let without = reader().get_metadata(None).await.unwrap();
+        assert!(without.column_index().is_none());
+        assert!(without.offset_index().is_none());
+        assert_eq!(cache.0.lock().unwrap().len(), 2);
+
+        let cached = reader().get_metadata(Some(&with_page_index)).await.unwrap();
+        assert!(Arc::ptr_eq(&metadata, &cached));
+    }
+
+    #[cfg(feature = "encryption")]
+    #[tokio::test]
+    async fn test_metadata_cache_encryption() {
+        use crate::encryption::decrypt::FileDecryptionProperties;
+        use crate::encryption::encrypt::FileEncryptionProperties;
+
+        const FOOTER_KEY: &[u8] = b"0123456789012345";
+        const COLUMN_KEY: &[u8] = b"1234567890123450";
+
+        // a plaintext footer can be read without keys, but the column can not
+        let encryption = FileEncryptionProperties::builder(FOOTER_KEY.into())
+            .with_column_key("a", COLUMN_KEY.into())
+            .with_plaintext_footer(true)
+            .build()
+            .unwrap();
+        let props = WriterProperties::builder()
+            .with_file_encryption_properties(encryption)
+            .build();
+        let batch = RecordBatch::try_from_iter([(
+            "a",
+            Arc::new(Int32Array::from_iter_values(0..10)) as ArrayRef,
+        )])
+        .unwrap();
+        let mut buf = vec![];
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let store: Arc<dyn ObjectStore> = Arc::new(InMemory::new());
+        let path = Path::from("encrypted.parquet");
+        store.put(&path, buf.into()).await.unwrap();
+        let e_tag = store.head(&path).await.unwrap().e_tag.unwrap();
+
+        let cache = Arc::new(TestCache::default());
+        let reader = || {
+            ParquetObjectReader::new(Arc::clone(&store), path.clone())
+                .with_e_tag(&e_tag)
+                .with_metadata_cache(Arc::clone(&cache) as _)
+        };
+
+        let decryption = FileDecryptionProperties::builder(FOOTER_KEY.into())
+            .with_column_key("a", COLUMN_KEY.into())
+            .build()
+            .unwrap();
+        let options = ArrowReaderOptions::new().with_file_decryption_properties(decryption);
+        let builder = ParquetRecordBatchStreamBuilder::new_with_options(reader(), options)
+            .await
+            .unwrap();
+        let batches: Vec<_> = builder.build().unwrap().try_collect().await.unwrap();
+        assert_eq!(batches, vec![batch]);
+        assert!(cache.0.lock().unwrap().is_empty());
+
+        // the decryption keys of the previous reader are not reused
+        let builder = ParquetRecordBatchStreamBuilder::new(reader())
+            .await
+            .unwrap();
+        let result: Result<Vec<_>, _> = builder.build().unwrap().try_collect().await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_coalesce() {
+        let store: Arc<dyn ObjectStore> = Arc::new(InMemory::new());
+        let path = Path::from("test.parquet");
+        let meta = put_test_file(store.as_ref(), &path).await;
+
+        let read = |coalesce: Option<u64>| {
+            let mut reader = ParquetObjectReader::new(Arc::clone(&store), path.clone())
+                .with_file_size(meta.size);
+            if let Some(coalesce) = coalesce {
+                reader = reader.with_coalesce(coalesce);
+            }
+            async move {
+                let builder = ParquetRecordBatchStreamBuilder::new(reader).await.unwrap();
+                let batches: Vec<_> = builder.build().unwrap().try_collect().await.unwrap();
+                batches
+            }
+        };
+
+        let expected = read(None).await;
+        assert_eq!(expected.iter().map(|b| b.num_rows()).sum::<usize>(), 1000);
+        for coalesce in [0, 10, 1024 * 1024] {
+            assert_eq!(read(Some(coalesce)).await, expected);
+        }
+
+        let ranges = vec![0..4, 4..8, 10..12, 6..20];
+        let expected = store.get_ranges(&path, &ranges).await.unwrap();
+        let mut reader = ParquetObjectReader::new(store, path).with_coalesce(4);
+        assert_eq!(reader.get_byte_ranges(ranges).await.unwrap(), expected);
+    }
 }
@@ -62,7 +62,7 @@ pub use store::*;
 
 use crate::{
     arrow::ArrowWriter,
-    arrow::arrow_writer::ArrowWriterOptions,
+    arrow::arrow_writer::{ArrowWriterOptions, WriteStep},
     errors::{ParquetError, Result},
     file::{
         metadata::{KeyValue, ParquetMetaData, RowGroupMetaData},
@@ -136,6 +136,13 @@ impl<T: AsyncWrite + Unpin + Send> AsyncFileWriter for T {
 /// although this will likely increase overall file size and reduce query performance.
 /// See [ArrowWriter] for more information.
 ///
+/// ## Parallelism
+///
+/// By default columns are encoded on the calling task, blocking it while each
+/// [`RecordBatch`] is encoded. For wide tables, [`Self::with_encoding_parallelism`]
+/// can be used to encode the columns on tokio's blocking threads instead, with only
+/// the final ordered flush of each row group to the [`AsyncFileWriter`] being serialized.
+///
 /// ```no_run
 /// # use tokio::fs::File;
 /// # use arrow_array::RecordBatch;
@@ -156,6 +163,9 @@ pub struct AsyncArrowWriter<W> {
 
     /// Async writer provided by caller
     async_writer: W,
+
+    /// The maximum number of blocking tasks used to encode columns
+    encoding_parallelism: usize,
 }
 
 impl<W: AsyncFileWriter> AsyncArrowWriter<W> {
@@ -180,9 +190,36 @@ impl<W: AsyncFileWriter> AsyncArrowWriter<W> {
         Ok(Self {
             sync_writer,
             async_writer: writer,
+            encoding_parallelism: 1,
         })
     }
 
+    /// Sets the maximum number of tasks used to encode the columns of each
+    /// [`RecordBatch`] written (defaults to `1`)
+    ///
+    /// If greater than `1`, the leaf columns of each batch are split into up to
+    /// `parallelism` contiguous groups, each of which is encoded on a
+    /// [`tokio::task::spawn_blocking`] task, and the column chunks are likewise closed
+    /// in parallel when a row group is flushed. The column chunks are then written
+    /// to the [`AsyncFileWriter`] in order.
+    ///
+    /// This allows writing wide tables to use more than one core, but requires
+    /// the writer to be used within a tokio runtime.
+    ///
+    /// # Panics
+    ///
+    /// If `parallelism` is `0`
+    pub fn with_encoding_parallelism(self, parallelism: usize) -> Self {
+        assert!(
+            parallelism > 0,
+            "encoding parallelism must be greater than 0"
+        );
+        Self {
+            encoding_parallelism: parallelism,
+            ..self
+        }
+    }
+
     /// Returns metadata for any flushed row groups
     pub fn flushed_row_groups(&self) -> &[RowGroupMetaData] {
         self.sync_writer.flushed_row_groups()
@@ -217,6 +254,10 @@ impl<W: AsyncFileWriter> AsyncArrowWriter<W> {
     /// After every sync write by the inner [ArrowWriter], the inner buffer will be
     /// checked and flush if at least half full
     pub async fn write(&mut self, batch: &RecordBatch) -> Result<()> {
+        if self.encoding_parallelism > 1 {
+            return self.write_parallel(batch).await;
+        }
+
         let before = self.sync_writer.flushed_row_groups().len();
         self.sync_writer.write(batch)?;
         if before != self.sync_writer.flushed_row_groups().len() {
@@ -225,14 +266,54 @@ impl<W: AsyncFileWriter> AsyncArrowWriter<W> {
         Ok(())
     }
 
+    /// Encodes `batch` on up to `encoding_parallelism` blocking tasks
+    ///
+    /// This mirrors [`ArrowWriter::write`]
+    async fn write_parallel(&mut self, batch: &RecordBatch) -> Result<()> {
+        let batch = self.sync_writer.shred(batch)?;
+        let mut offset = 0;
+        while offset < batch.num_rows() {
+            match self
+                .sync_writer
+                .next_write_step(batch.num_rows() - offset)?
+            {
+                WriteStep::Flush => self.flush().await?,
+                WriteStep::Encode(len) => {
+                    let columns = self.sync_writer.take_columns(&batch.slice(offset, len))?;
+                    let columns =
+                        map_blocking(columns, self.encoding_parallelism, |c| c.encode()).await?;
+                    self.sync_writer.return_columns(columns)?;
+
+                    if self.sync_writer.in_progress_full() {
+                        self.flush().await?;
+                    }
+                    offset += len;
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Flushes all buffered rows into a new row group
     pub async fn flush(&mut self) -> Result<()> {
-        self.sync_writer.flush()?;
+        self.flush_row_group().await?;
         self.do_write().await?;
 
         Ok(())
     }
 
+    /// Closes the in progress row group, if any, in parallel if configured
+    async fn flush_row_group(&mut self) -> Result<()> {
+        if self.encoding_parallelism <= 1 {
+            return self.sync_writer.flush();
+        }
+        if let Some(writers) = self.sync_writer.take_row_group() {
+            let chunks = map_blocking(writers, self.encoding_parallelism, |w| w.close()).await?;
+            self.sync_writer.write_row_group(chunks)?;
+        }
+        Ok(())
+    }
+
     /// Append [`KeyValue`] metadata in addition to those in [`WriterProperties`]
     ///
     /// This method allows to append metadata after [`RecordBatch`]es are written.
@@ -248,6 +329,7 @@ impl<W: AsyncFileWriter> AsyncArrowWriter<W> {
     ///
     /// Attempting to write after calling finish will result in an error
     pub async fn finish(&mut self) -> Result<ParquetMetaData> {
+        self.flush_row_group().await?;
         let metadata = self.sync_writer.finish()?;
 
         // Force to flush the remaining data.
@@ -292,11 +374,50 @@ impl<W: AsyncFileWriter> AsyncArrowWriter<W> {
     }
 }
 
+/// Applies `f` to each of `items`, returning the results in order
+///
+/// `items` are split into up to `parallelism` contiguous groups, each of which
+/// is processed on a [`tokio::task::spawn_blocking`] task
+async fn map_blocking<T, R>(
+    items: Vec<T>,
+    parallelism: usize,
+    f: fn(T) -> Result<R>,
+) -> Result<Vec<R>>
+where
+    T: Send + 'static,
+    R: Send + 'static,
+{
+    let len = items.len();
+    let chunk_size = len.div_ceil(parallelism).max(1);
+    let mut items = items.into_iter();
+    let tasks: Vec<_> = std::iter::from_fn(|| {
+        let chunk: Vec<T> = items.by_ref().take(chunk_size).collect();
+        (!chunk.is_empty()).then(|| {
+            tokio::task::spawn_blocking(move || {
+                chunk.into_iter().map(f).collect::<Result<Vec<_>>>()
+            })
+        })
+    })
+    .collect();
+
+    let mut results = Vec::with_capacity(len);
+    for task in tasks {
+        match task.await {
+            Ok(chunk) => results.extend(chunk?),
+            Err(e) if e.is_panic() => std::panic::resume_unwind(e.into_panic()),
+            Err(e) => return Err(ParquetError::External(Box::new(e))),
+        }
+    }
+    Ok(results)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::arrow::arrow_reader::{ParquetRecordBatchReader, ParquetRecordBatchReaderBuilder};
     use arrow::datatypes::{DataType, Field, Schema};
-    use arrow_array::{ArrayRef, BinaryArray, Int32Array, Int64Array, RecordBatchReader};
+    use arrow_array::{
+        ArrayRef, BinaryArray, Float64Array, Int32Array, Int64Array, RecordBatchReader, StringArray,
+    };
     use bytes::Bytes;
     use std::sync::Arc;
 
@@ -413,6 +534,77 @@ mod tests {
         assert_eq!(to_write, read);
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_async_writer_encoding_parallelism() {
+        let columns: Vec<(String, ArrayRef)> = (0..9)
+            .map(|i| {
+                let array: ArrayRef = match i % 3 {
+                    0 => Arc::new(Int64Array::from_iter_values(i..i + 1000)),
+                    1 => Arc::new(StringArray::from_iter_values(
+                        (0..1000).map(|v| format!("value{}", v % (i + 30))),
+                    )),
+                    _ => Arc::new(Float64Array::from_iter(
+                        (0..1000).map(|v| (v % 7 != 0).then_some((v * i) as f64)),
+                    )),
+                };
+                (format!("col{i}"), array)
+            })
+            .collect();
+        let batch = RecordBatch::try_from_iter(columns).unwrap();
+
+        async fn write(
+            batch: &RecordBatch,
+            props: WriterProperties,
+            parallelism: usize,
+        ) -> Vec<u8> {
+            let mut buffer = Vec::new();
+            let mut writer = AsyncArrowWriter::try_new(&mut buffer, batch.schema(), Some(props))
+                .unwrap()
+                .with_encoding_parallelism(parallelism);
+            for offset in (0..1000).step_by(100) {
+                writer.write(&batch.slice(offset, 100)).await.unwrap();
+            }
+            writer.close().await.unwrap();
+            buffer
+        }
+
+        let row_count_props = WriterProperties::builder()
+            .set_max_row_group_row_count(Some(300))
+            .build();
+        let bytes_props = WriterProperties::builder()
+            .set_max_row_group_row_count(None)
+            .set_max_row_group_bytes(Some(10_000))
+            .build();
+        let cdc_props = WriterProperties::builder()
+            .set_content_defined_chunking(Some(crate::file::properties::CdcOptions {
+                min_chunk_size: 256,
+                max_chunk_size: 1024,
+                norm_level: 0,
+            }))
+            .build();
+        for props in [row_count_props, bytes_props, cdc_props] {
+            let expected = write(&batch, props.clone(), 1).await;
+            for parallelism in [2, 4, 16] {
+                assert_eq!(write(&batch, props.clone(), parallelism).await, expected);
+            }
+        }
+
+        let buffer = write(&batch, WriterProperties::default(), 4).await;
+        let reader = ParquetRecordBatchReader::try_new(Bytes::from(buffer), 1000).unwrap();
+        let read = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(read, vec![batch]);
+    }
+
+    #[test]
+    #[should_panic(expected = "encoding parallelism must be greater than 0")]
+    fn test_async_writer_encoding_parallelism_zero() {
+        let col = Arc::new(Int64Array::from_iter_values([1, 2, 3])) as ArrayRef;
+        let batch = RecordBatch::try_from_iter([("col", col)]).unwrap();
+        AsyncArrowWriter::try_new(Vec::new(), batch.schema(), None)
+            .unwrap()
+            .with_encoding_parallelism(0);
+    }
+
     #[tokio::test]
     async fn in_progress_accounting() {
         // define schema
@@ -258,6 +258,14 @@ pub(crate) enum ColumnChunkData {
 }
 
 impl ColumnChunkData {
+    /// Return the number of bytes actually held for this column chunk
+    pub(crate) fn fetched_len(&self) -> usize {
+        match self {
+            ColumnChunkData::Sparse { data, .. } => data.iter().map(|(_, b)| b.len()).sum(),
+            ColumnChunkData::Dense { data, .. } => data.len(),
+        }
+    }
+
     /// Return the data for this column chunk at the given offset
     fn get(&self, start: u64) -> crate::errors::Result<Bytes> {
         match &self {
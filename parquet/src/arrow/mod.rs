@@ -197,6 +197,7 @@ mod record_reader;
 
 experimental!(mod schema);
 
+use std::collections::HashSet;
 use std::fmt::Debug;
 
 pub use self::arrow_writer::ArrowWriter;
@@ -204,9 +205,10 @@ pub use self::arrow_writer::ArrowWriter;
 pub use self::async_reader::ParquetRecordBatchStreamBuilder;
 #[cfg(feature = "async")]
 pub use self::async_writer::AsyncArrowWriter;
-use crate::schema::types::SchemaDescriptor;
+use crate::schema::types::{SchemaDescriptor, Type};
 use arrow_schema::{FieldRef, Schema};
 
+pub(crate) use self::schema::decimal_length_from_precision;
 pub use self::schema::{
     ArrowSchemaConverter, FieldLevels, add_encoded_arrow_schema_to_metadata, encode_arrow_schema,
     parquet_to_arrow_field_levels, parquet_to_arrow_field_levels_with_virtual,
@@ -376,6 +378,46 @@ impl ProjectionMask {
         Self { mask: Some(mask) }
     }
 
+    /// Create a [`ProjectionMask`] which selects only the columns with the given field ids
+    ///
+    /// All leaf columns that fall below a field with one of the given ids will be selected,
+    /// in the same way as [`Self::columns`]. For example, given the schema
+    /// ```ignore
+    /// message schema {
+    ///   OPTIONAL group a = 1 {
+    ///     REQUIRED INT32 b = 2;              // leaf index 0
+    ///     REQUIRED INT32 c = 3;              // leaf index 1
+    ///   }
+    ///   REQUIRED DOUBLE d = 4;               // leaf index 2
+    /// }
+    /// ```
+    /// `[1]` would return leaf columns 0 and 1, and `[3, 4]` would return leaf columns 1 and 2.
+    ///
+    /// Field ids are read into the [`PARQUET_FIELD_ID_META_KEY`] metadata of the arrow
+    /// fields, and written from it by the [`ArrowWriter`]. Ids that do not match any
+    /// field are ignored.
+    pub fn field_ids(schema: &SchemaDescriptor, ids: impl IntoIterator<Item = i32>) -> Self {
+        fn visit(tpe: &Type, ids: &HashSet<i32>, selected: bool, mask: &mut Vec<bool>) {
+            let info = tpe.get_basic_info();
+            let selected = selected || (info.has_id() && ids.contains(&info.id()));
+            match tpe.is_group() {
+                true => {
+                    for field in tpe.get_fields() {
+                        visit(field, ids, selected, mask);
+                    }
+                }
+                false => mask.push(selected),
+            }
+        }
+
+        let ids = ids.into_iter().collect();
+        let mut mask = Vec::with_capacity(schema.num_columns());
+        for field in schema.root_schema().get_fields() {
+            visit(field, &ids, false, &mut mask);
+        }
+        Self { mask: Some(mask) }
+    }
+
     /// Returns true if the leaf column `leaf_idx` is included by the mask
     pub fn leaf_included(&self, leaf_idx: usize) -> bool {
         self.mask.as_ref().map(|m| m[leaf_idx]).unwrap_or(true)
@@ -753,6 +795,75 @@ mod test {
         assert_eq!(mask.mask.unwrap(), [true, false]);
     }
 
+    #[test]
+    fn test_mask_from_field_ids() {
+        let schema = parse_schema(
+            "
+            message test_schema {
+                OPTIONAL group a (MAP) = 1 {
+                    REPEATED group key_value {
+                        REQUIRED BYTE_ARRAY key (UTF8) = 2;
+                        OPTIONAL group value = 3 {
+                            REQUIRED INT32 x = 4;
+                            REQUIRED BOOLEAN y = 5;
+                        }
+                    }
+                }
+                REQUIRED INT32 b = 6;
+                REQUIRED DOUBLE c;
+            }
+            ",
+        );
+
+        let mask = ProjectionMask::field_ids(&schema, []);
+        assert_eq!(mask.mask.unwrap(), vec![false; 5]);
+
+        let mask = ProjectionMask::field_ids(&schema, [1]);
+        assert_eq!(mask.mask.unwrap(), [true, true, true, false, false]);
+
+        let mask = ProjectionMask::field_ids(&schema, [6, 3, 100]);
+        assert_eq!(mask.mask.unwrap(), [false, true, true, true, false]);
+
+        let mask = ProjectionMask::field_ids(&schema, [2, 5, 2]);
+        assert_eq!(mask.mask.unwrap(), [true, false, true, false, false]);
+    }
+
+    #[test]
+    fn test_field_id_round_trip() {
+        use crate::arrow::PARQUET_FIELD_ID_META_KEY;
+        use crate::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+        use arrow_schema::{DataType, Field, Schema};
+        use std::collections::HashMap;
+
+        let field = |name: &str, id: i32| {
+            let metadata = HashMap::from([(PARQUET_FIELD_ID_META_KEY.to_string(), id.to_string())]);
+            Field::new(name, DataType::Int32, false).with_metadata(metadata)
+        };
+        let schema = Arc::new(Schema::new(vec![field("a", 10), field("b", 20)]));
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(Int32Array::from(vec![1, 2])),
+            Arc::new(Int32Array::from(vec![3, 4])),
+        ];
+        let batch = RecordBatch::try_new(schema.clone(), columns).unwrap();
+
+        let mut buf = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buf, schema, None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let builder = ParquetRecordBatchReaderBuilder::try_new(Bytes::from(buf)).unwrap();
+        let mask = ProjectionMask::field_ids(builder.parquet_schema(), [20]);
+        let batches: Vec<_> = builder
+            .with_projection(mask)
+            .build()
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0].schema().field(0), &field("b", 20));
+        assert_eq!(batches[0].column(0).as_ref(), batch.column(1).as_ref());
+    }
+
     #[test]
     fn test_projection_mask_union() {
         let mut mask1 = ProjectionMask {
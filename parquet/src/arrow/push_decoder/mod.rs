@@ -266,7 +266,7 @@ impl ParquetPushDecoderBuilder {
             metrics,
             row_selection_policy,
             max_predicate_cache_size,
-        } = self;
+        } = self.prune_with_row_filter()?;
 
         // If no row groups were specified, read all of them
         let row_groups =
@@ -518,6 +518,23 @@ impl ParquetPushDecoder {
         self.state.clear_all_ranges();
     }
 
+    /// Clear the buffered byte ranges that exactly match `ranges`, such as
+    /// data pushed ahead of a request that was not needed after all
+    #[cfg(feature = "async")]
+    pub(crate) fn clear_ranges(&mut self, ranges: &[Range<u64>]) {
+        self.state.clear_ranges(ranges);
+    }
+
+    /// Row group indices that have not yet been planned, in decode order
+    ///
+    /// This excludes the row group currently being fetched or decoded, and
+    /// may include row groups that are later skipped due to the offset, limit,
+    /// or row selection.
+    #[cfg(feature = "async")]
+    pub(crate) fn queued_row_groups(&self) -> Vec<usize> {
+        self.state.queued_row_groups()
+    }
+
     /// True iff the decoder is at a row-group boundary, where
     /// [`Self::into_builder`] can reconfigure the scan.
     ///
@@ -814,6 +831,35 @@ impl ParquetDecoderState {
         }
     }
 
+    /// Clear the buffered ranges that exactly match `ranges`
+    #[cfg(feature = "async")]
+    fn clear_ranges(&mut self, ranges: &[Range<u64>]) {
+        match self {
+            ParquetDecoderState::ReadingRowGroup {
+                remaining_row_groups,
+            }
+            | ParquetDecoderState::DecodingRowGroup {
+                remaining_row_groups,
+                ..
+            } => remaining_row_groups.clear_ranges(ranges),
+            ParquetDecoderState::Finished => {}
+        }
+    }
+
+    #[cfg(feature = "async")]
+    fn queued_row_groups(&self) -> Vec<usize> {
+        match self {
+            ParquetDecoderState::ReadingRowGroup {
+                remaining_row_groups,
+            }
+            | ParquetDecoderState::DecodingRowGroup {
+                remaining_row_groups,
+                ..
+            } => remaining_row_groups.queued_row_groups().collect(),
+            ParquetDecoderState::Finished => vec![],
+        }
+    }
+
     fn is_at_row_group_boundary(&self) -> bool {
         match self {
             ParquetDecoderState::ReadingRowGroup {
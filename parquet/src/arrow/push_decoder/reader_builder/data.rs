@@ -19,6 +19,7 @@
 
 use crate::arrow::ProjectionMask;
 use crate::arrow::arrow_reader::RowSelection;
+use crate::arrow::arrow_reader::metrics::ArrowReaderMetrics;
 use crate::arrow::in_memory_row_group::{ColumnChunkData, FetchRanges, InMemoryRowGroup};
 use crate::errors::ParquetError;
 use crate::file::metadata::ParquetMetaData;
@@ -83,6 +84,7 @@ impl DataRequest {
         parquet_metadata: &'a ParquetMetaData,
         projection: &ProjectionMask,
         buffers: &mut PushBuffers,
+        metrics: &ArrowReaderMetrics,
     ) -> Result<InMemoryRowGroup<'a>, ParquetError> {
         let chunks = self.get_chunks(buffers)?;
 
@@ -103,8 +105,27 @@ impl DataRequest {
             metadata: parquet_metadata,
         };
 
+        let previously_fetched: Vec<bool> = in_memory_row_group
+            .column_chunks
+            .iter()
+            .map(Option::is_some)
+            .collect();
         in_memory_row_group.fill_column_chunks(projection, page_start_offsets, chunks);
 
+        if metrics.is_enabled() {
+            let newly_fetched = in_memory_row_group
+                .column_chunks
+                .iter()
+                .zip(previously_fetched)
+                .enumerate()
+                .filter_map(|(idx, (chunk, previous))| {
+                    Some((idx, chunk.as_ref().filter(|_| !previous)?))
+                });
+            for (idx, chunk) in newly_fetched {
+                metrics.increment_column_bytes_read(idx, chunk.fetched_len() as u64);
+            }
+        }
+
         // Clear the ranges that were explicitly requested
         buffers.clear_ranges(&ranges);
 
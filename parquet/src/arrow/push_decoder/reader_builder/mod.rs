@@ -373,6 +373,12 @@ impl RowGroupReaderBuilder {
         self.buffers.clear_all_ranges();
     }
 
+    /// Clear the buffered ranges that exactly match `ranges`
+    #[cfg(feature = "async")]
+    pub fn clear_ranges(&mut self, ranges: &[Range<u64>]) {
+        self.buffers.clear_ranges(ranges);
+    }
+
     /// take the current state, leaving None in its place.
     ///
     /// Returns an error if there the state wasn't put back after the previous
@@ -603,15 +609,27 @@ impl RowGroupReaderBuilder {
                     &self.metadata,
                     predicate.projection(),
                     &mut self.buffers,
+                    &self.metrics,
                 )?;
 
                 let cache_options = filter_info.cache_builder().producer();
 
+                // Columns decoded as dictionaries are not shared with the output
+                let dictionary_fields = predicate.preserve_dictionary().then(|| {
+                    self.fields
+                        .as_deref()
+                        .map(ParquetField::with_dictionary_byte_arrays)
+                });
+                let (predicate_fields, cache_options) = match &dictionary_fields {
+                    Some(dictionary_fields) => (dictionary_fields.as_ref(), None),
+                    None => (self.fields.as_deref(), Some(&cache_options)),
+                };
+
                 let array_reader = ArrayReaderBuilder::new(&row_group, &self.metrics)
                     .with_batch_size(self.batch_size)
-                    .with_cache_options(Some(&cache_options))
+                    .with_cache_options(cache_options)
                     .with_parquet_metadata(&self.metadata)
-                    .build_array_reader(self.fields.as_deref(), predicate.projection())?;
+                    .build_array_reader(predicate_fields, predicate.projection())?;
 
                 // Reset to original policy before each predicate so the override
                 // can detect page skipping for THIS predicate's columns.
@@ -781,6 +799,7 @@ impl RowGroupReaderBuilder {
                     &self.metadata,
                     &self.projection,
                     &mut self.buffers,
+                    &self.metrics,
                 )?;
 
                 let plan = plan_builder.build();
@@ -287,6 +287,18 @@ impl RemainingRowGroups {
         self.row_group_reader_builder.clear_all_ranges();
     }
 
+    /// Clear the buffered ranges that exactly match `ranges`
+    #[cfg(feature = "async")]
+    pub fn clear_ranges(&mut self, ranges: &[Range<u64>]) {
+        self.row_group_reader_builder.clear_ranges(ranges);
+    }
+
+    /// Row group indices not yet handed to the reader builder, in order
+    #[cfg(feature = "async")]
+    pub fn queued_row_groups(&self) -> impl Iterator<Item = usize> + '_ {
+        self.frontier.row_groups.iter().copied()
+    }
+
     /// True iff the inner row-group reader is between row groups (state
     /// `Finished`). Forward to [`RowGroupReaderBuilder::is_finished`].
     pub fn is_at_row_group_boundary(&self) -> bool {
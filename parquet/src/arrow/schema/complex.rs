@@ -18,11 +18,11 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use crate::arrow::schema::extension::try_add_extension_type;
+use crate::arrow::schema::extension::{has_extension_type, try_add_extension_type};
 use crate::arrow::schema::primitive::convert_primitive;
 use crate::arrow::schema::virtual_type::{RowGroupIndex, RowNumber};
 use crate::arrow::{PARQUET_FIELD_ID_META_KEY, ProjectionMask};
-use crate::basic::{ConvertedType, Repetition};
+use crate::basic::{ConvertedType, Repetition, Type as PhysicalType};
 use crate::errors::ParquetError;
 use crate::errors::Result;
 use crate::schema::types::{SchemaDescriptor, Type, TypePtr};
@@ -129,6 +129,101 @@ impl ParquetField {
             ParquetFieldType::Primitive { .. } => None,
             ParquetFieldType::Group { children } => Some(children),
             ParquetFieldType::Virtual(_) => None,
+            #[cfg(feature = "variant_experimental")]
+            ParquetFieldType::Variant { .. } => None,
+        }
+    }
+
+    /// Returns a copy of `self` with the `BYTE_ARRAY` string and binary leaves,
+    /// outside of lists and maps, read as `Dictionary(Int32, _)`
+    ///
+    /// View types are read as dictionaries of `Utf8` and `Binary`, as they are
+    /// not supported as dictionary values by the reader
+    pub(crate) fn with_dictionary_byte_arrays(&self) -> Self {
+        let field_type = match &self.field_type {
+            ParquetFieldType::Primitive { primitive_type, .. } => {
+                let value_type = match &self.arrow_type {
+                    DataType::Utf8 | DataType::Utf8View => DataType::Utf8,
+                    DataType::LargeUtf8 => DataType::LargeUtf8,
+                    DataType::Binary | DataType::BinaryView => DataType::Binary,
+                    DataType::LargeBinary => DataType::LargeBinary,
+                    _ => return self.clone(),
+                };
+                if primitive_type.get_physical_type() != PhysicalType::BYTE_ARRAY {
+                    return self.clone();
+                }
+                return Self {
+                    arrow_type: DataType::Dictionary(
+                        Box::new(DataType::Int32),
+                        Box::new(value_type),
+                    ),
+                    ..self.clone()
+                };
+            }
+            ParquetFieldType::Group { children }
+                if matches!(self.arrow_type, DataType::Struct(_)) =>
+            {
+                ParquetFieldType::Group {
+                    children: children
+                        .iter()
+                        .map(Self::with_dictionary_byte_arrays)
+                        .collect(),
+                }
+            }
+            _ => return self.clone(),
+        };
+        Self {
+            field_type,
+            ..self.clone()
+        }
+    }
+
+    /// Returns a copy of `self` with the shredded Variant groups, outside of
+    /// lists and maps, read as unshredded Variants
+    ///
+    /// `arrow_type` must be the struct type with the [`Field`]s of `self`, so
+    /// that the Variant groups can be identified by their extension type
+    #[cfg(feature = "variant_experimental")]
+    pub(crate) fn with_unshredded_variants(&self) -> Self {
+        use crate::arrow::array_reader::unshredded_variant_type;
+        use parquet_variant_compute::VariantType;
+
+        let (ParquetFieldType::Group { children }, DataType::Struct(fields)) =
+            (&self.field_type, &self.arrow_type)
+        else {
+            return self.clone();
+        };
+
+        let (fields, children): (Vec<_>, Vec<_>) = fields
+            .iter()
+            .zip(children)
+            .map(|(field, child)| {
+                if field.has_valid_extension_type::<VariantType>() {
+                    if let Some(arrow_type) = unshredded_variant_type(&child.arrow_type) {
+                        let field = field.as_ref().clone().with_data_type(arrow_type.clone());
+                        let child = Self {
+                            arrow_type,
+                            field_type: ParquetFieldType::Variant {
+                                shredded: Box::new(child.clone()),
+                            },
+                            ..child.clone()
+                        };
+                        return (Arc::new(field), child);
+                    }
+                }
+                let child = child.with_unshredded_variants();
+                let field = field
+                    .as_ref()
+                    .clone()
+                    .with_data_type(child.arrow_type.clone());
+                (Arc::new(field), child)
+            })
+            .unzip();
+
+        Self {
+            arrow_type: DataType::Struct(fields.into()),
+            field_type: ParquetFieldType::Group { children },
+            ..self.clone()
         }
     }
 }
@@ -156,6 +251,11 @@ pub enum ParquetFieldType {
     /// Virtual column that doesn't exist in the parquet file
     /// but is computed at read time (e.g., row_number)
     Virtual(VirtualColumnType),
+    /// Shredded Variant group, read as `shredded` and then unshredded
+    #[cfg(feature = "variant_experimental")]
+    Variant {
+        shredded: Box<ParquetField>,
+    },
 }
 
 /// Encodes the context of the parent of the field currently under consideration
@@ -758,7 +858,15 @@ fn convert_field(
                 _ => Field::new(name, data_type, nullable),
             };
 
-            Ok(field.with_metadata(hint.metadata().clone()))
+            let field = field.with_metadata(hint.metadata().clone());
+            if field.extension_type_name().is_some() || !has_extension_type(parquet_type) {
+                return Ok(field);
+            }
+            // The embedded schema may have been written without the extension type
+            // metadata, e.g. by another writer, so add it based on the logical type.
+            // The hint may however request a data type the extension type does not
+            // support, in which case the field is returned unchanged.
+            Ok(try_add_extension_type(field.clone(), parquet_type).unwrap_or(field))
         }
         None => {
             let mut ret = Field::new(name, data_type, nullable);
@@ -2369,6 +2369,68 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    #[cfg(feature = "arrow_canonical_extension_types")]
+    fn parquet_uuid_with_arrow_hint() -> Result<()> {
+        use arrow_schema::extension::Uuid;
+        let message_type = "
+        message test_schema {
+            REQUIRED FIXED_LEN_BYTE_ARRAY (16) uuid (UUID);
+            REQUIRED FIXED_LEN_BYTE_ARRAY (16) other (UUID);
+        }
+        ";
+        let parquet_schema = SchemaDescriptor::new(Arc::new(parse_message_type(message_type)?));
+
+        // an embedded schema written without the extension type metadata
+        let mut other_metadata = HashMap::new();
+        other_metadata.insert("foo".to_string(), "bar".to_string());
+        let hint = Schema::new(vec![
+            Field::new("uuid", DataType::FixedSizeBinary(16), false),
+            Field::new("other", DataType::FixedSizeBinary(16), false).with_metadata(other_metadata),
+        ]);
+        let key_value_metadata = vec![KeyValue::new(
+            super::super::ARROW_SCHEMA_META_KEY.to_string(),
+            encode_arrow_schema(&hint),
+        )];
+
+        let arrow_schema = parquet_to_arrow_schema(&parquet_schema, Some(&key_value_metadata))?;
+        assert_eq!(arrow_schema.field(0).try_extension_type::<Uuid>()?, Uuid);
+        assert_eq!(arrow_schema.field(1).try_extension_type::<Uuid>()?, Uuid);
+        // other metadata of the embedded schema is preserved
+        assert_eq!(
+            arrow_schema.field(1).metadata().get("foo"),
+            Some(&"bar".to_string())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parquet_float16_without_logical_type_with_arrow_hint() -> Result<()> {
+        let message_type = "
+        message test_schema {
+            REQUIRED FIXED_LEN_BYTE_ARRAY (2) float16;
+        }
+        ";
+        let parquet_schema = SchemaDescriptor::new(Arc::new(parse_message_type(message_type)?));
+
+        let arrow_schema = parquet_to_arrow_schema(&parquet_schema, None)?;
+        assert_eq!(
+            arrow_schema.field(0).data_type(),
+            &DataType::FixedSizeBinary(2)
+        );
+
+        let hint = Schema::new(vec![Field::new("float16", DataType::Float16, false)]);
+        let key_value_metadata = vec![KeyValue::new(
+            super::super::ARROW_SCHEMA_META_KEY.to_string(),
+            encode_arrow_schema(&hint),
+        )];
+        let arrow_schema = parquet_to_arrow_schema(&parquet_schema, Some(&key_value_metadata))?;
+        assert_eq!(arrow_schema.field(0).data_type(), &DataType::Float16);
+
+        Ok(())
+    }
+
     #[test]
     fn test_parquet_to_arrow_field_levels_with_virtual_rejects_non_virtual() {
         let message_type = "
@@ -87,6 +87,9 @@ fn apply_hint(parquet: DataType, hint: DataType) -> DataType {
         // Determine interval time unit (#1666)
         (DataType::Interval(_), DataType::Interval(_)) => hint,
 
+        // Float16 written without the FLOAT16 logical type annotation
+        (DataType::FixedSizeBinary(2), DataType::Float16) => hint,
+
         // Promote to Decimal256 or narrow to Decimal32 or Decimal64
         (DataType::Decimal128(_, _), DataType::Decimal32(_, _)) => hint,
         (DataType::Decimal128(_, _), DataType::Decimal64(_, _)) => hint,
@@ -329,7 +332,9 @@ fn from_fixed_len_byte_array(
             }
             // There is currently no reliable way of determining which IntervalUnit
             // to return. Thus without the original Arrow schema, the results
-            // would be incorrect if all 12 bytes of the interval are populated
+            // would be incorrect if all 12 bytes of the interval are populated.
+            // IntervalUnit::MonthDayNano, which preserves all 12 bytes, can be
+            // requested with a schema hint, see ArrowReaderOptions::with_schema
             Ok(DataType::Interval(IntervalUnit::DayTime))
         }
         (Some(LogicalType::Float16), _) => {
@@ -456,5 +461,10 @@ mod tests {
             convert_primitive(&flba_interval(12), None).unwrap(),
             DataType::Interval(IntervalUnit::DayTime)
         );
+        let hint = DataType::Interval(IntervalUnit::MonthDayNano);
+        assert_eq!(
+            convert_primitive(&flba_interval(12), Some(hint.clone())).unwrap(),
+            hint
+        );
     }
 }
@@ -33,16 +33,14 @@
 //! cargo run --features=cli --bin parquet-concat out.parquet a.parquet b.parquet
 //! ```
 //!
-//! Note: this does not currently support preserving the page index or bloom filters
+//! The row groups of the inputs are copied without being decoded, along with
+//! their page index and bloom filters, see [`SerializedFileWriter::append_row_group`]
 //!
 
 use clap::Parser;
-use parquet::bloom_filter::Sbbf;
-use parquet::column::writer::ColumnCloseResult;
 use parquet::errors::{ParquetError, Result};
-use parquet::file::metadata::{ColumnChunkMetaData, PageIndexPolicy, ParquetMetaDataReader};
+use parquet::file::metadata::{PageIndexPolicy, ParquetMetaDataReader};
 use parquet::file::properties::WriterProperties;
-use parquet::file::reader::ChunkReader;
 use parquet::file::writer::SerializedFileWriter;
 use std::fs::File;
 use std::sync::Arc;
@@ -58,10 +56,6 @@ struct Args {
     input: Vec<String>,
 }
 
-fn read_bloom_filter<R: ChunkReader>(column: &ColumnChunkMetaData, input: &R) -> Option<Sbbf> {
-    Sbbf::read_from_column_chunk(column, input).ok().flatten()
-}
-
 impl Args {
     fn run(&self) -> Result<()> {
         if self.input.is_empty() {
@@ -99,31 +93,9 @@ impl Args {
         let schema = inputs[0].1.file_metadata().schema_descr().root_schema_ptr();
         let mut writer = SerializedFileWriter::new(output, schema, props)?;
 
-        for (input, metadata) in inputs {
-            let column_indexes = metadata.column_index();
-            let offset_indexes = metadata.offset_index();
-
-            for (rg_idx, rg) in metadata.row_groups().iter().enumerate() {
-                let rg_column_indexes = column_indexes.and_then(|ci| ci.get(rg_idx));
-                let rg_offset_indexes = offset_indexes.and_then(|oi| oi.get(rg_idx));
-                let mut rg_out = writer.next_row_group()?;
-                for (col_idx, column) in rg.columns().iter().enumerate() {
-                    let bloom_filter = read_bloom_filter(column, &input);
-                    let column_index = rg_column_indexes.and_then(|row| row.get(col_idx)).cloned();
-
-                    let offset_index = rg_offset_indexes.and_then(|row| row.get(col_idx)).cloned();
-
-                    let result = ColumnCloseResult {
-                        bytes_written: column.compressed_size() as _,
-                        rows_written: rg.num_rows() as _,
-                        metadata: column.clone(),
-                        bloom_filter,
-                        column_index,
-                        offset_index,
-                    };
-                    rg_out.append_column(&input, result)?;
-                }
-                rg_out.close()?;
+        for (input, metadata) in &inputs {
+            for row_group_idx in 0..metadata.num_row_groups() {
+                writer.append_row_group(input, metadata, row_group_idx)?;
             }
         }
 
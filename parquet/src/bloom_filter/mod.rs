@@ -108,6 +108,16 @@
 //! After inserting all values into a bloom filter it can be "folded" to minimize it's size.
 //! See [`Sbbf::fold_to_target_fpp`] for details  on the algorithm and its mathematical basis.
 //!
+//! # Adaptive Sizing
+//!
+//! Instead of allocating a filter for the maximum expected NDV up front, the writer can size
+//! the filter from the number of distinct hashes observed in each column chunk, see
+//! [`BloomFilterPropertiesBuilder::with_adaptive`]. The hashes are buffered until the chunk is
+//! flushed, or until more than the maximum NDV distinct hashes have been seen, at which point
+//! the writer falls back to a filter sized for the maximum NDV.
+//!
+//! [`BloomFilterPropertiesBuilder::with_adaptive`]: crate::file::properties::BloomFilterPropertiesBuilder::with_adaptive
+//!
 //! [parquet-bf-spec]: https://github.com/apache/parquet-format/blob/master/BloomFilter.md
 //! [sbbf-paper]: https://arxiv.org/pdf/2101.01719
 //! [bf-formulae]: http://tfk.mit.edu/pdf/bloom.pdf
@@ -116,13 +126,22 @@ use crate::basic::{BloomFilterAlgorithm, BloomFilterCompression, BloomFilterHash
 use crate::data_type::AsBytes;
 use crate::errors::{ParquetError, Result};
 use crate::file::metadata::ColumnChunkMetaData;
+use crate::file::properties::BloomFilterProperties;
 use crate::file::reader::ChunkReader;
 use crate::parquet_thrift::{
     ElementType, FieldType, ReadThrift, ThriftCompactInputProtocol, ThriftCompactOutputProtocol,
     ThriftSliceInputProtocol, WriteThrift, WriteThriftField,
 };
 use crate::thrift_struct;
+#[cfg(feature = "arrow")]
+use arrow_array::{Array, ArrowPrimitiveType, cast::AsArray, types::*};
+#[cfg(feature = "arrow")]
+use arrow_buffer::{BooleanBuffer, i256};
+#[cfg(feature = "arrow")]
+use arrow_schema::{DataType, TimeUnit};
 use bytes::Bytes;
+use hashbrown::HashTable;
+use hashbrown::hash_table::Entry;
 use std::io::Write;
 use twox_hash::XxHash64;
 
@@ -565,6 +584,41 @@ impl Sbbf {
         self.0[block_index].check(hash as u32)
     }
 
+    /// Returns the hash of an [AsBytes] value, as used by [`Self::insert`] and [`Self::check`]
+    ///
+    /// This allows the hashes of values to be computed once and then checked against the
+    /// filters of many column chunks with [`Self::check_hashes`]
+    pub fn hash<T: AsBytes + ?Sized>(value: &T) -> u64 {
+        hash_as_bytes(value)
+    }
+
+    /// Check if each of `hashes`, as returned by [`Self::hash`], is probably present or
+    /// definitely absent in the filter
+    #[cfg(feature = "arrow")]
+    pub fn check_hashes(&self, hashes: &[u64]) -> BooleanBuffer {
+        BooleanBuffer::collect_bool(hashes.len(), |i| self.check_hash(hashes[i]))
+    }
+
+    /// Check if each value of `array` is probably present or definitely absent in the filter,
+    /// returning `false` for null values
+    ///
+    /// Values are hashed as the [`ArrowWriter`] writes them with its default options, for
+    /// example [`DataType::Int8`] values are hashed as parquet `INT32`, and decimals as
+    /// `INT32`, `INT64` or `FIXED_LEN_BYTE_ARRAY` depending on their precision. Dictionary
+    /// arrays are hashed by their values.
+    ///
+    /// Returns an error if `array` has a data type that cannot be written with a bloom filter
+    ///
+    /// [`ArrowWriter`]: crate::arrow::ArrowWriter
+    #[cfg(feature = "arrow")]
+    pub fn check_array(&self, array: &dyn Array) -> Result<BooleanBuffer> {
+        let present = self.check_hashes(&hash_array(array)?);
+        Ok(match array.logical_nulls() {
+            Some(nulls) => &present & nulls.inner(),
+            None => present,
+        })
+    }
+
     /// Return the total in memory size of this bloom filter in bytes
     pub(crate) fn estimated_memory_size(&self) -> usize {
         self.0.capacity() * std::mem::size_of::<Block>()
@@ -753,6 +807,85 @@ impl Sbbf {
     }
 }
 
+/// Accumulates the values of a column chunk into a [`Sbbf`], as configured by
+/// [`BloomFilterProperties`]
+#[derive(Debug, Clone)]
+pub(crate) struct SbbfBuilder {
+    state: SbbfBuilderState,
+    fpp: f64,
+    max_ndv: u64,
+}
+
+#[derive(Debug, Clone)]
+enum SbbfBuilderState {
+    /// The distinct hashes observed so far, used to size the filter when finished
+    Hashes(HashTable<u64>),
+    /// A filter sized for the maximum NDV, folded when finished
+    Filter(Sbbf),
+}
+
+impl SbbfBuilder {
+    /// Create a new [`SbbfBuilder`] with the given properties
+    pub(crate) fn try_new(props: &BloomFilterProperties) -> Result<Self, ParquetError> {
+        let state = match props.adaptive() {
+            true => SbbfBuilderState::Hashes(HashTable::new()),
+            false => SbbfBuilderState::Filter(Sbbf::new_with_ndv_fpp(props.ndv(), props.fpp())?),
+        };
+        Ok(Self {
+            state,
+            fpp: props.fpp(),
+            max_ndv: props.ndv(),
+        })
+    }
+
+    /// Insert an [AsBytes] value into the filter
+    pub(crate) fn insert<T: AsBytes + ?Sized>(&mut self, value: &T) {
+        let hash = hash_as_bytes(value);
+        match &mut self.state {
+            SbbfBuilderState::Filter(sbbf) => sbbf.insert_hash(hash),
+            SbbfBuilderState::Hashes(hashes) => {
+                // the hash is already uniformly distributed, so is used to index the table
+                if let Entry::Vacant(entry) = hashes.entry(hash, |h| *h == hash, |h| *h) {
+                    entry.insert(hash);
+                }
+                if hashes.len() as u64 > self.max_ndv {
+                    let sbbf = Self::filter_from_hashes(self.max_ndv, self.fpp, hashes);
+                    self.state = SbbfBuilderState::Filter(sbbf);
+                }
+            }
+        }
+    }
+
+    fn filter_from_hashes(ndv: u64, fpp: f64, hashes: &HashTable<u64>) -> Sbbf {
+        let num_bits = num_of_bits_from_ndv_fpp(ndv, fpp);
+        let mut sbbf = Sbbf::new_with_num_of_bytes(num_bits / 8);
+        hashes.iter().for_each(|h| sbbf.insert_hash(*h));
+        sbbf
+    }
+
+    /// Return the total in memory size of this builder in bytes
+    pub(crate) fn estimated_memory_size(&self) -> usize {
+        match &self.state {
+            // each bucket stores the hash and one control byte
+            SbbfBuilderState::Hashes(hashes) => hashes.capacity() * (size_of::<u64>() + 1),
+            SbbfBuilderState::Filter(sbbf) => sbbf.estimated_memory_size(),
+        }
+    }
+
+    /// Returns the [`Sbbf`] of the inserted values, at its smallest size meeting the
+    /// target false positive probability
+    pub(crate) fn finish(self) -> Sbbf {
+        let mut sbbf = match self.state {
+            SbbfBuilderState::Hashes(hashes) => {
+                Self::filter_from_hashes(hashes.len() as u64, self.fpp, &hashes)
+            }
+            SbbfBuilderState::Filter(sbbf) => sbbf,
+        };
+        sbbf.fold_to_target_fpp(self.fpp);
+        sbbf
+    }
+}
+
 // per spec we use xxHash with seed=0
 const SEED: u64 = 0;
 
@@ -761,6 +894,114 @@ fn hash_as_bytes<A: AsBytes + ?Sized>(value: &A) -> u64 {
     XxHash64::oneshot(SEED, value.as_bytes())
 }
 
+/// Hashes each value of `array` as written by the `ArrowWriter`, see [`Sbbf::check_array`]
+///
+/// The hashes of null values are unspecified
+#[cfg(feature = "arrow")]
+fn hash_array(array: &dyn Array) -> Result<Vec<u64>> {
+    fn hash_primitive<T: ArrowPrimitiveType, H: AsBytes>(
+        array: &dyn Array,
+        f: impl Fn(T::Native) -> H,
+    ) -> Vec<u64> {
+        let values = array.as_primitive::<T>().values();
+        values.iter().map(|v| hash_as_bytes(&f(*v))).collect()
+    }
+
+    fn hash_decimal<T: ArrowPrimitiveType>(
+        array: &dyn Array,
+        precision: u8,
+        to_i256: impl Fn(T::Native) -> i256,
+    ) -> Vec<u64> {
+        // Matches the physical type chosen for the decimal by the ArrowSchemaConverter
+        if precision > 1 && precision <= 9 {
+            hash_primitive::<T, _>(array, |v| to_i256(v).as_i128() as i32)
+        } else if precision <= 18 {
+            hash_primitive::<T, _>(array, |v| to_i256(v).as_i128() as i64)
+        } else {
+            let size = crate::arrow::decimal_length_from_precision(precision);
+            hash_primitive::<T, _>(array, |v| to_i256(v).to_be_bytes()[32 - size..].to_vec())
+        }
+    }
+
+    fn hash_bytes<'a>(values: impl Iterator<Item = Option<&'a [u8]>>) -> Vec<u64> {
+        values
+            .map(|v| hash_as_bytes(v.unwrap_or_default()))
+            .collect()
+    }
+
+    Ok(match array.data_type() {
+        DataType::Boolean => {
+            let values = array.as_boolean().values();
+            values.iter().map(|v| hash_as_bytes(&v)).collect()
+        }
+        DataType::Int8 => hash_primitive::<Int8Type, _>(array, |v| v as i32),
+        DataType::Int16 => hash_primitive::<Int16Type, _>(array, |v| v as i32),
+        DataType::Int32 => hash_primitive::<Int32Type, _>(array, |v| v),
+        DataType::Int64 => hash_primitive::<Int64Type, _>(array, |v| v),
+        DataType::UInt8 => hash_primitive::<UInt8Type, _>(array, |v| v as i32),
+        DataType::UInt16 => hash_primitive::<UInt16Type, _>(array, |v| v as i32),
+        DataType::UInt32 => hash_primitive::<UInt32Type, _>(array, |v| v as i32),
+        DataType::UInt64 => hash_primitive::<UInt64Type, _>(array, |v| v as i64),
+        DataType::Float16 => hash_primitive::<Float16Type, _>(array, |v| v.to_le_bytes().to_vec()),
+        DataType::Float32 => hash_primitive::<Float32Type, _>(array, |v| v),
+        DataType::Float64 => hash_primitive::<Float64Type, _>(array, |v| v),
+        DataType::Date32 => hash_primitive::<Date32Type, _>(array, |v| v),
+        DataType::Date64 => hash_primitive::<Date64Type, _>(array, |v| v),
+        DataType::Time32(TimeUnit::Second) => hash_primitive::<Time32SecondType, _>(array, |v| v),
+        DataType::Time32(TimeUnit::Millisecond) => {
+            hash_primitive::<Time32MillisecondType, _>(array, |v| v)
+        }
+        DataType::Time64(TimeUnit::Microsecond) => {
+            hash_primitive::<Time64MicrosecondType, _>(array, |v| v)
+        }
+        DataType::Time64(TimeUnit::Nanosecond) => {
+            hash_primitive::<Time64NanosecondType, _>(array, |v| v)
+        }
+        DataType::Timestamp(_, _) | DataType::Duration(_) => {
+            let data = array.to_data();
+            data.buffer::<i64>(0)[..data.len()]
+                .iter()
+                .map(hash_as_bytes)
+                .collect()
+        }
+        DataType::Decimal32(p, _) => {
+            hash_decimal::<Decimal32Type>(array, *p, |v| i256::from_i128(v as i128))
+        }
+        DataType::Decimal64(p, _) => {
+            hash_decimal::<Decimal64Type>(array, *p, |v| i256::from_i128(v as i128))
+        }
+        DataType::Decimal128(p, _) => hash_decimal::<Decimal128Type>(array, *p, i256::from_i128),
+        DataType::Decimal256(p, _) => hash_decimal::<Decimal256Type>(array, *p, |v| v),
+        DataType::Utf8 => hash_bytes(
+            array
+                .as_string::<i32>()
+                .iter()
+                .map(|v| v.map(str::as_bytes)),
+        ),
+        DataType::LargeUtf8 => hash_bytes(
+            array
+                .as_string::<i64>()
+                .iter()
+                .map(|v| v.map(str::as_bytes)),
+        ),
+        DataType::Utf8View => {
+            hash_bytes(array.as_string_view().iter().map(|v| v.map(str::as_bytes)))
+        }
+        DataType::Binary => hash_bytes(array.as_binary::<i32>().iter()),
+        DataType::LargeBinary => hash_bytes(array.as_binary::<i64>().iter()),
+        DataType::BinaryView => hash_bytes(array.as_binary_view().iter()),
+        DataType::FixedSizeBinary(_) => hash_bytes(array.as_fixed_size_binary().iter()),
+        DataType::Dictionary(_, _) => {
+            let dictionary = array.as_any_dictionary();
+            let values = hash_array(dictionary.values().as_ref())?;
+            let keys = dictionary.normalized_keys().into_iter();
+            keys.map(|k| values.get(k).copied().unwrap_or_default())
+                .collect()
+        }
+        d => return Err(nyi_err!("Checking bloom filter for values of type {}", d)),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1237,4 +1478,157 @@ mod tests {
             assert_eq!(folded_fp, fresh_fp);
         }
     }
+
+    #[test]
+    fn test_sbbf_builder_adaptive() {
+        let props = |adaptive| {
+            BloomFilterProperties::builder()
+                .with_max_ndv(1_000_000)
+                .with_adaptive(adaptive)
+                .build()
+        };
+        let mut fixed = SbbfBuilder::try_new(&props(false)).unwrap();
+        let mut adaptive = SbbfBuilder::try_new(&props(true)).unwrap();
+        for i in 0..10_000 {
+            fixed.insert(&(i % 1000));
+            adaptive.insert(&(i % 1000));
+        }
+        assert!(adaptive.estimated_memory_size() < fixed.estimated_memory_size() / 10);
+
+        let (fixed, adaptive) = (fixed.finish(), adaptive.finish());
+        assert!(adaptive.num_blocks() <= fixed.num_blocks());
+        for i in 0..1000 {
+            assert!(adaptive.check(&i));
+        }
+    }
+
+    #[test]
+    fn test_sbbf_builder_adaptive_exceeds_max_ndv() {
+        let props = BloomFilterProperties::builder()
+            .with_max_ndv(10)
+            .with_adaptive(true)
+            .build();
+        let mut builder = SbbfBuilder::try_new(&props).unwrap();
+        for i in 0..10 {
+            builder.insert(&i);
+        }
+        assert!(matches!(builder.state, SbbfBuilderState::Hashes(_)));
+
+        // falls back to a filter sized for the maximum NDV
+        for i in 10..100 {
+            builder.insert(&i);
+        }
+        assert!(matches!(builder.state, SbbfBuilderState::Filter(_)));
+        let sbbf = builder.finish();
+        for i in 0..100 {
+            assert!(sbbf.check(&i));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "arrow")]
+    fn test_check_array() {
+        use crate::arrow::ArrowWriter;
+        use crate::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+        use crate::file::properties::WriterProperties;
+        use arrow_array::*;
+        use std::sync::Arc;
+
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(BooleanArray::from(vec![true, true, true])),
+            Arc::new(Int8Array::from(vec![-1, 2, 3])),
+            Arc::new(UInt32Array::from(vec![u32::MAX, 2, 3])),
+            Arc::new(UInt64Array::from(vec![u64::MAX, 2, 3])),
+            Arc::new(Float16Array::from_iter_values(
+                [1.0, 2.0, 3.0].map(half::f16::from_f32),
+            )),
+            Arc::new(Float64Array::from(vec![1.5, 2.5, 3.5])),
+            Arc::new(Date64Array::from(vec![86_400_000, 2 * 86_400_000, 0])),
+            Arc::new(TimestampSecondArray::from(vec![1, 2, 3]).with_timezone("UTC")),
+            Arc::new(
+                Decimal128Array::from(vec![-100, 200, 300])
+                    .with_precision_and_scale(5, 2)
+                    .unwrap(),
+            ),
+            Arc::new(
+                Decimal128Array::from(vec![-100, 200, 300])
+                    .with_precision_and_scale(15, 2)
+                    .unwrap(),
+            ),
+            Arc::new(
+                Decimal128Array::from(vec![-100, 200, 300])
+                    .with_precision_and_scale(30, 2)
+                    .unwrap(),
+            ),
+            Arc::new(StringViewArray::from(vec![
+                "a",
+                "b",
+                "longer than twelve bytes",
+            ])),
+            Arc::new(DictionaryArray::<types::Int32Type>::from_iter([
+                "x", "y", "x",
+            ])),
+        ];
+        let batch = RecordBatch::try_from_iter(
+            columns
+                .iter()
+                .enumerate()
+                .map(|(i, c)| (i.to_string(), Arc::clone(c))),
+        )
+        .unwrap();
+
+        let props = WriterProperties::builder()
+            .set_bloom_filter_enabled(true)
+            .build();
+        let mut buffer = vec![];
+        let mut writer = ArrowWriter::try_new(&mut buffer, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let builder = ParquetRecordBatchReaderBuilder::try_new(Bytes::from(buffer)).unwrap();
+        for (i, column) in columns.iter().enumerate() {
+            let sbbf = builder
+                .get_row_group_column_bloom_filter(0, i)
+                .unwrap()
+                .unwrap();
+
+            // Every written value, but not null, is present
+            let candidates = arrow_select::concat::concat(&[
+                column.as_ref(),
+                &new_null_array(column.data_type(), 1),
+            ])
+            .unwrap();
+            let present = sbbf.check_array(&candidates).unwrap();
+            assert_eq!(
+                present.iter().collect::<Vec<_>>(),
+                [true, true, true, false],
+                "column {i}"
+            );
+        }
+
+        // Values that were not written are absent
+        let sbbf = builder
+            .get_row_group_column_bloom_filter(0, 8)
+            .unwrap()
+            .unwrap();
+        let candidates = Decimal128Array::from(vec![-100, 201, 100])
+            .with_precision_and_scale(5, 2)
+            .unwrap();
+        let present = sbbf.check_array(&candidates).unwrap();
+        assert_eq!(present.iter().collect::<Vec<_>>(), [true, false, false]);
+
+        let hashes: Vec<_> = [-1_i32, 4, 2].iter().map(Sbbf::hash).collect();
+        let sbbf = builder
+            .get_row_group_column_bloom_filter(0, 1)
+            .unwrap()
+            .unwrap();
+        let present = sbbf.check_hashes(&hashes);
+        assert_eq!(present.iter().collect::<Vec<_>>(), [true, false, true]);
+
+        let err = sbbf.check_array(&NullArray::new(1)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "NYI: Checking bloom filter for values of type Null"
+        );
+    }
 }
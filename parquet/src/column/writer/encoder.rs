@@ -19,7 +19,7 @@ use bytes::Bytes;
 use half::f16;
 
 use crate::basic::{ConvertedType, Encoding, LogicalType, Type};
-use crate::bloom_filter::Sbbf;
+use crate::bloom_filter::{Sbbf, SbbfBuilder};
 use crate::column::writer::{
     compare_greater, fallback_encoding, has_dictionary_support, is_nan, update_max, update_min,
 };
@@ -173,8 +173,7 @@ pub struct ColumnValueEncoderImpl<T: DataType> {
     statistics_enabled: EnabledStatistics,
     min_value: Option<T::T>,
     max_value: Option<T::T>,
-    bloom_filter: Option<Sbbf>,
-    bloom_filter_target_fpp: f64,
+    bloom_filter: Option<SbbfBuilder>,
     variable_length_bytes: Option<i64>,
     geo_stats_accumulator: Option<Box<dyn GeoStatsAccumulator>>,
 }
@@ -224,9 +223,7 @@ impl<T: DataType> ColumnValueEncoder for ColumnValueEncoderImpl<T> {
     type Values = [T::T];
 
     fn flush_bloom_filter(&mut self) -> Option<Sbbf> {
-        let mut sbbf = self.bloom_filter.take()?;
-        sbbf.fold_to_target_fpp(self.bloom_filter_target_fpp);
-        Some(sbbf)
+        self.bloom_filter.take().map(SbbfBuilder::finish)
     }
 
     fn try_new(descr: &ColumnDescPtr, props: &WriterProperties) -> Result<Self> {
@@ -244,7 +241,7 @@ impl<T: DataType> ColumnValueEncoder for ColumnValueEncoderImpl<T> {
 
         let statistics_enabled = props.statistics_enabled(descr.path());
 
-        let (bloom_filter, bloom_filter_target_fpp) = create_bloom_filter(props, descr)?;
+        let bloom_filter = create_bloom_filter(props, descr)?;
 
         let geo_stats_accumulator = try_new_geo_stats_accumulator(descr);
 
@@ -255,7 +252,6 @@ impl<T: DataType> ColumnValueEncoder for ColumnValueEncoderImpl<T> {
             num_values: 0,
             statistics_enabled,
             bloom_filter,
-            bloom_filter_target_fpp,
             min_value: None,
             max_value: None,
             variable_length_bytes: None,
@@ -454,19 +450,15 @@ fn replace_zero<T: ParquetValueType>(val: &T, descr: &ColumnDescriptor, replace:
     }
 }
 
-/// Creates a bloom filter sized for the column's configured NDV, returning the filter
-/// and the target FPP for folding.
+/// Creates a [`SbbfBuilder`] for the column's bloom filter, if enabled
 pub(crate) fn create_bloom_filter(
     props: &WriterProperties,
     descr: &ColumnDescPtr,
-) -> Result<(Option<Sbbf>, f64)> {
-    match props.bloom_filter_properties(descr.path()) {
-        Some(bf_props) => Ok((
-            Some(Sbbf::new_with_ndv_fpp(bf_props.ndv(), bf_props.fpp())?),
-            bf_props.fpp(),
-        )),
-        None => Ok((None, 0.0)),
-    }
+) -> Result<Option<SbbfBuilder>> {
+    props
+        .bloom_filter_properties(descr.path())
+        .map(SbbfBuilder::try_new)
+        .transpose()
 }
 
 fn update_geo_stats_accumulator<'a, T, I>(bounder: &mut dyn GeoStatsAccumulator, iter: I)
@@ -44,7 +44,7 @@ use crate::file::metadata::{
     OffsetIndexBuilder, PageEncodingStats,
 };
 use crate::file::properties::{
-    EnabledStatistics, WriterProperties, WriterPropertiesPtr, WriterVersion,
+    EnabledStatistics, StatisticsTruncation, WriterProperties, WriterPropertiesPtr, WriterVersion,
 };
 use crate::file::statistics::{Statistics, ValueStatistics};
 use crate::schema::types::{ColumnDescPtr, ColumnDescriptor};
@@ -577,7 +577,11 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
         }
 
         // We can only set the distinct count if there are no other writes
-        if self.encoder.num_values() == 0 {
+        if self.encoder.num_values() == 0
+            && self
+                .props
+                .statistics_distinct_count_enabled(self.descr.path())
+        {
             self.column_metrics.column_distinct_count = distinct_count;
         } else {
             self.column_metrics.column_distinct_count = None;
@@ -1008,6 +1012,14 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
         }
 
         if self.should_dict_fallback() {
+            let path = self.descr.path();
+            if !self.props.dictionary_fallback_enabled(path) {
+                return Err(general_err!(
+                    "Dictionary page size limit of {} bytes exceeded for column {} with dictionary fallback disabled",
+                    self.props.column_dictionary_page_size_limit(path),
+                    path
+                ));
+            }
             self.dict_fallback()?;
         }
 
@@ -1109,18 +1121,21 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
                     self.last_non_null_data_page_min_max = Some((new_min.clone(), new_max.clone()));
 
                     if self.can_truncate_value() {
+                        // The column index requires min/max values for every page, and
+                        // so exact values are written in full rather than omitted
+                        let truncate_length =
+                            match self.props.statistics_truncation(self.descr.path()) {
+                                StatisticsTruncation::Exact => None,
+                                StatisticsTruncation::Truncate => {
+                                    self.props.column_index_truncate_length()
+                                }
+                            };
                         self.column_index_builder.append(
                             null_page,
-                            self.truncate_min_value(
-                                self.props.column_index_truncate_length(),
-                                stat.min_bytes_opt().unwrap(),
-                            )
-                            .0,
-                            self.truncate_max_value(
-                                self.props.column_index_truncate_length(),
-                                stat.max_bytes_opt().unwrap(),
-                            )
-                            .0,
+                            self.truncate_min_value(truncate_length, stat.min_bytes_opt().unwrap())
+                                .0,
+                            self.truncate_max_value(truncate_length, stat.max_bytes_opt().unwrap())
+                                .0,
                             self.page_metrics.num_page_nulls as i64,
                         );
                     } else {
@@ -1236,56 +1251,55 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
     /// Truncate the min and max values that will be written to a data page
     /// header or column chunk Statistics
     fn truncate_statistics(&self, statistics: Statistics) -> Statistics {
-        let backwards_compatible_min_max = self.descr.sort_order().is_signed();
         match statistics {
             Statistics::ByteArray(stats) if stats._internal_has_min_max_set() => {
-                let (min, did_truncate_min) = self.truncate_min_value(
-                    self.props.statistics_truncate_length(),
-                    stats.min_bytes_opt().unwrap(),
-                );
-                let (max, did_truncate_max) = self.truncate_max_value(
-                    self.props.statistics_truncate_length(),
-                    stats.max_bytes_opt().unwrap(),
-                );
-                Statistics::ByteArray(
-                    ValueStatistics::new(
-                        Some(min.into()),
-                        Some(max.into()),
-                        stats.distinct_count(),
-                        stats.null_count_opt(),
-                        backwards_compatible_min_max,
-                    )
-                    .with_max_is_exact(!did_truncate_max)
-                    .with_min_is_exact(!did_truncate_min),
-                )
+                Statistics::ByteArray(self.truncate_value_statistics(stats))
             }
             Statistics::FixedLenByteArray(stats)
                 if (stats._internal_has_min_max_set() && self.can_truncate_value()) =>
             {
-                let (min, did_truncate_min) = self.truncate_min_value(
-                    self.props.statistics_truncate_length(),
-                    stats.min_bytes_opt().unwrap(),
-                );
-                let (max, did_truncate_max) = self.truncate_max_value(
-                    self.props.statistics_truncate_length(),
-                    stats.max_bytes_opt().unwrap(),
-                );
-                Statistics::FixedLenByteArray(
-                    ValueStatistics::new(
-                        Some(min.into()),
-                        Some(max.into()),
-                        stats.distinct_count(),
-                        stats.null_count_opt(),
-                        backwards_compatible_min_max,
-                    )
-                    .with_max_is_exact(!did_truncate_max)
-                    .with_min_is_exact(!did_truncate_min),
-                )
+                Statistics::FixedLenByteArray(self.truncate_value_statistics(stats))
             }
             stats => stats,
         }
     }
 
+    /// Truncate the min and max values of binary statistics, according to the
+    /// [`StatisticsTruncation`] of this column
+    fn truncate_value_statistics<T>(&self, stats: ValueStatistics<T>) -> ValueStatistics<T>
+    where
+        T: ParquetValueType + From<Vec<u8>>,
+    {
+        let path = self.descr.path();
+        let truncate_length = self.props.column_statistics_truncate_length(path);
+        let (min, did_truncate_min) =
+            self.truncate_min_value(truncate_length, stats.min_bytes_opt().unwrap());
+        let (max, did_truncate_max) =
+            self.truncate_max_value(truncate_length, stats.max_bytes_opt().unwrap());
+
+        let backwards_compatible_min_max = self.descr.sort_order().is_signed();
+        let exact = self.props.statistics_truncation(path) == StatisticsTruncation::Exact;
+        if exact && (did_truncate_min || did_truncate_max) {
+            return ValueStatistics::new(
+                None,
+                None,
+                stats.distinct_count(),
+                stats.null_count_opt(),
+                backwards_compatible_min_max,
+            );
+        }
+
+        ValueStatistics::new(
+            Some(min.into()),
+            Some(max.into()),
+            stats.distinct_count(),
+            stats.null_count_opt(),
+            backwards_compatible_min_max,
+        )
+        .with_max_is_exact(!did_truncate_max)
+        .with_min_is_exact(!did_truncate_min)
+    }
+
     /// Adds data page.
     /// Data page is either buffered in case of dictionary encoding or written directly.
     pub(crate) fn add_data_page(&mut self) -> Result<()> {
@@ -2663,6 +2677,41 @@ mod tests {
         column_roundtrip_random::<Int32Type>(props, 1024, i32::MIN, i32::MAX, 10, 10);
     }
 
+    #[test]
+    fn test_column_writer_dictionary_fallback_disabled() {
+        let write = |fallback: bool| {
+            let props = WriterProperties::builder()
+                .set_dictionary_page_size_limit(32)
+                .set_write_batch_size(4)
+                .set_dictionary_fallback_enabled(fallback)
+                .build();
+            let mut writer =
+                get_test_column_writer::<Int32Type>(get_test_page_writer(), 0, 0, Arc::new(props));
+            let values: Vec<i32> = (0..16).collect();
+            writer.write_batch(&values, None, None)?;
+            writer.close()
+        };
+
+        let r = write(true).unwrap();
+        assert_eq!(r.metadata.dictionary_fallback(), Some(true));
+
+        let err = write(false).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Dictionary page size limit of 32 bytes exceeded for column \"col\" with dictionary fallback disabled"
+        );
+
+        // a column within its dictionary page size limit does not fall back
+        let props = WriterProperties::builder()
+            .set_dictionary_fallback_enabled(false)
+            .build();
+        let mut writer =
+            get_test_column_writer::<Int32Type>(get_test_page_writer(), 0, 0, Arc::new(props));
+        writer.write_batch(&[1, 2, 1, 2], None, None).unwrap();
+        let r = writer.close().unwrap();
+        assert_eq!(r.metadata.dictionary_fallback(), Some(false));
+    }
+
     #[test]
     fn test_column_writer_small_write_batch_size() {
         for i in &[1usize, 2, 5, 10, 11, 1023] {
@@ -3815,6 +3864,43 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_column_index_truncation_exact() {
+        let props = WriterProperties::builder()
+            .set_column_index_truncate_length(Some(2))
+            .set_column_statistics_truncation(
+                ColumnPath::from("exact"),
+                StatisticsTruncation::Exact,
+            )
+            .build();
+        let props = Arc::new(props);
+
+        let column_index = |path: &str| {
+            let mut writer = get_test_column_writer_with_path::<ByteArrayType>(
+                get_test_page_writer(),
+                0,
+                0,
+                props.clone(),
+                ColumnPath::from(path),
+            );
+            let data = [ByteArray::from("abcd"), ByteArray::from("bcde")];
+            writer.write_batch(&data, None, None).unwrap();
+            match writer.close().unwrap().column_index.unwrap() {
+                ColumnIndexMetaData::BYTE_ARRAY(column_index) => column_index,
+                _ => panic!("wrong stats type"),
+            }
+        };
+
+        // exact values are written untruncated
+        let exact = column_index("exact");
+        assert_eq!(exact.min_value(0).unwrap(), b"abcd");
+        assert_eq!(exact.max_value(0).unwrap(), b"bcde");
+
+        let truncated = column_index("truncated");
+        assert_eq!(truncated.min_value(0).unwrap(), b"ab");
+        assert_eq!(truncated.max_value(0).unwrap(), b"bd");
+    }
+
     #[test]
     fn test_float16_min_max_no_truncation() {
         // Even if we set truncation to occur at 1 byte, we should not truncate for Float16
@@ -4080,6 +4166,80 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_statistics_truncating_column_override() {
+        let props = WriterProperties::builder()
+            .set_statistics_truncate_length(Some(1))
+            .set_column_statistics_truncate_length(ColumnPath::from("col"), None)
+            .build();
+        let mut writer =
+            get_test_column_writer::<ByteArrayType>(get_test_page_writer(), 0, 0, Arc::new(props));
+        writer
+            .write_batch(&[ByteArray::from("Blart Versenwald III")], None, None)
+            .unwrap();
+        let r = writer.close().unwrap();
+
+        let stats = r.metadata.statistics().expect("statistics");
+        assert!(stats.min_is_exact());
+        assert!(stats.max_is_exact());
+        assert_eq!(stats.min_bytes_opt().unwrap(), b"Blart Versenwald III");
+        assert_eq!(stats.max_bytes_opt().unwrap(), b"Blart Versenwald III");
+    }
+
+    #[test]
+    fn test_statistics_truncation_exact() {
+        let props = WriterProperties::builder()
+            .set_statistics_truncate_length(Some(4))
+            .set_statistics_truncation(StatisticsTruncation::Exact)
+            .set_write_page_header_statistics(true)
+            .build();
+        let props = Arc::new(props);
+
+        // values no longer than the truncation length are written
+        let mut writer =
+            get_test_column_writer::<ByteArrayType>(get_test_page_writer(), 0, 0, props.clone());
+        let data = [ByteArray::from("abc"), ByteArray::from("abcd")];
+        writer.write_batch(&data, None, None).unwrap();
+        let r = writer.close().unwrap();
+        let stats = r.metadata.statistics().expect("statistics");
+        assert!(stats.min_is_exact());
+        assert!(stats.max_is_exact());
+        assert_eq!(stats.min_bytes_opt().unwrap(), b"abc");
+        assert_eq!(stats.max_bytes_opt().unwrap(), b"abcd");
+
+        // if either value is too long, neither is written
+        let mut writer =
+            get_test_column_writer::<ByteArrayType>(get_test_page_writer(), 0, 0, props);
+        let data = [ByteArray::from("abc"), ByteArray::from("abcde")];
+        writer.write_batch(&data, None, None).unwrap();
+        let r = writer.close().unwrap();
+        let stats = r.metadata.statistics().expect("statistics");
+        assert_eq!(stats.null_count_opt(), Some(0));
+        assert!(stats.min_bytes_opt().is_none());
+        assert!(stats.max_bytes_opt().is_none());
+        assert!(!stats.min_is_exact());
+        assert!(!stats.max_is_exact());
+    }
+
+    #[test]
+    fn test_statistics_distinct_count_disabled() {
+        let props = WriterProperties::builder()
+            .set_statistics_enabled(EnabledStatistics::Chunk)
+            .set_column_statistics_distinct_count_enabled(ColumnPath::from("col"), false)
+            .build();
+        let mut writer =
+            get_test_column_writer::<Int32Type>(get_test_page_writer(), 0, 0, Arc::new(props));
+        writer
+            .write_batch_with_statistics(&[1, 2, 3, 4], None, None, Some(&1), Some(&4), Some(4))
+            .unwrap();
+        let r = writer.close().unwrap();
+
+        let stats = r.metadata.statistics().expect("statistics");
+        assert_eq!(stats.distinct_count_opt(), None);
+        assert_eq!(stats.min_bytes_opt().unwrap(), 1_i32.to_le_bytes());
+        assert_eq!(stats.max_bytes_opt().unwrap(), 4_i32.to_le_bytes());
+    }
+
     #[test]
     fn test_send() {
         fn test<T: Send>() {}
@@ -29,28 +29,100 @@ use std::collections::{HashMap, HashSet};
 use std::io::Write;
 use std::sync::Arc;
 
+/// An encryption key, and the optional metadata stored in the file to allow readers to
+/// recover it, see [`KeyRetriever`]
+///
+/// [`KeyRetriever`]: crate::encryption::decrypt::KeyRetriever
 #[derive(Debug, Clone, PartialEq)]
-struct EncryptionKey {
+pub struct EncryptionKey {
     key: Vec<u8>,
     key_metadata: Option<Vec<u8>>,
 }
 
 impl EncryptionKey {
-    fn new(key: Vec<u8>) -> EncryptionKey {
+    /// Create a new [`EncryptionKey`] without metadata
+    pub fn new(key: Vec<u8>) -> EncryptionKey {
         Self {
             key,
             key_metadata: None,
         }
     }
 
-    fn with_metadata(mut self, metadata: Vec<u8>) -> Self {
+    /// Create a new [`EncryptionKey`] with `key_length` random bytes, generated by a
+    /// cryptographically secure random number generator
+    pub fn random(key_length: usize) -> Result<EncryptionKey> {
+        let mut key = vec![0u8; key_length];
+        SystemRandom::new().fill(&mut key)?;
+        Ok(Self::new(key))
+    }
+
+    /// Set the metadata of this key
+    pub fn with_metadata(mut self, metadata: Vec<u8>) -> Self {
         self.key_metadata = Some(metadata);
         self
     }
 
-    fn key(&self) -> &Vec<u8> {
+    /// Returns the key
+    pub fn key(&self) -> &Vec<u8> {
         &self.key
     }
+
+    /// Returns the metadata of this key
+    pub fn key_metadata(&self) -> Option<&Vec<u8>> {
+        self.key_metadata.as_ref()
+    }
+}
+
+/// Trait for generating encryption keys and their key metadata when writing a file
+///
+/// This allows integrating with a Key Management Service (KMS), for example by
+/// generating a random data key and storing it wrapped with a master key held by the
+/// KMS in the key metadata. When reading, a [`KeyRetriever`] can then unwrap the data
+/// key using the KMS.
+///
+/// # Example
+///
+/// ```
+/// # use std::collections::HashMap;
+/// # use std::sync::Arc;
+/// # use parquet::encryption::decrypt::KeyRetriever;
+/// # use parquet::encryption::encrypt::{EncryptionKey, FileEncryptionProperties, KeyGenerator};
+/// # use parquet::errors::Result;
+/// /// Stands in for a KMS, storing generated keys by identifier
+/// struct Kms {
+///     master_key_ids: HashMap<String, String>,
+/// }
+///
+/// impl KeyGenerator for Kms {
+///     fn generate_footer_key(&self) -> Result<EncryptionKey> {
+///         // A real implementation would wrap the key with the footer master key
+///         Ok(EncryptionKey::random(16)?.with_metadata(b"footer".to_vec()))
+///     }
+///
+///     fn generate_column_key(&self, column_path: &str) -> Result<EncryptionKey> {
+///         let master_key_id = &self.master_key_ids[column_path];
+///         Ok(EncryptionKey::random(16)?.with_metadata(master_key_id.as_bytes().to_vec()))
+///     }
+/// }
+///
+/// let kms = Kms {
+///     master_key_ids: HashMap::from([("x".to_string(), "k1".to_string())]),
+/// };
+/// let encryption_properties = FileEncryptionProperties::with_key_generator(Arc::new(kms))
+///     .with_encrypted_column("x")
+///     .with_plaintext_footer(true)
+///     .build()?;
+/// # Ok::<(), parquet::errors::ParquetError>(())
+/// ```
+///
+/// [`KeyRetriever`]: crate::encryption::decrypt::KeyRetriever
+pub trait KeyGenerator: Send + Sync {
+    /// Generate the key for encrypting the footer, and all columns if no encrypted
+    /// columns are specified
+    fn generate_footer_key(&self) -> Result<EncryptionKey>;
+
+    /// Generate the key for encrypting the column with the given path
+    fn generate_column_key(&self, column_path: &str) -> Result<EncryptionKey>;
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -104,6 +176,14 @@ impl FileEncryptionProperties {
         EncryptionPropertiesBuilder::new(footer_key)
     }
 
+    /// Create a new builder for encryption properties that uses a [`KeyGenerator`] to
+    /// generate the footer and column keys, and their metadata
+    pub fn with_key_generator(
+        key_generator: Arc<dyn KeyGenerator>,
+    ) -> EncryptionPropertiesBuilderWithGenerator {
+        EncryptionPropertiesBuilderWithGenerator::new(key_generator)
+    }
+
     /// Should the footer be encrypted
     pub fn encrypt_footer(&self) -> bool {
         self.encrypt_footer
@@ -286,6 +366,91 @@ impl EncryptionPropertiesBuilder {
     }
 }
 
+/// Builder for [`FileEncryptionProperties`] that uses a [`KeyGenerator`]
+///
+/// The keys are generated when [`Self::build`] is called, so to encrypt each file with
+/// different keys, new properties should be built for each file.
+///
+/// See the [`KeyGenerator`] documentation for example usage.
+pub struct EncryptionPropertiesBuilderWithGenerator {
+    key_generator: Arc<dyn KeyGenerator>,
+    encrypt_footer: bool,
+    encrypted_columns: Vec<String>,
+    aad_prefix: Option<Vec<u8>>,
+    store_aad_prefix: bool,
+}
+
+impl EncryptionPropertiesBuilderWithGenerator {
+    /// Create a new [`EncryptionPropertiesBuilderWithGenerator`] by providing a
+    /// [`KeyGenerator`] used to generate the encryption keys
+    pub fn new(key_generator: Arc<dyn KeyGenerator>) -> Self {
+        Self {
+            key_generator,
+            encrypt_footer: true,
+            encrypted_columns: vec![],
+            aad_prefix: None,
+            store_aad_prefix: false,
+        }
+    }
+
+    /// Set if the footer should be stored in plaintext (not encrypted). Defaults to false.
+    pub fn with_plaintext_footer(mut self, plaintext_footer: bool) -> Self {
+        self.encrypt_footer = !plaintext_footer;
+        self
+    }
+
+    /// Encrypt a column with a key generated by [`KeyGenerator::generate_column_key`].
+    /// Note that if no encrypted columns are specified then all columns will be encrypted
+    /// with the footer key. If any are specified then only those columns will be encrypted.
+    pub fn with_encrypted_column(mut self, column_path: &str) -> Self {
+        self.encrypted_columns.push(column_path.to_string());
+        self
+    }
+
+    /// Encrypt columns with keys generated by [`KeyGenerator::generate_column_key`].
+    /// Analogous to [`Self::with_encrypted_column`] but for multiple columns.
+    pub fn with_encrypted_columns<'a>(
+        mut self,
+        column_paths: impl IntoIterator<Item = &'a str>,
+    ) -> Self {
+        self.encrypted_columns
+            .extend(column_paths.into_iter().map(String::from));
+        self
+    }
+
+    /// See [`EncryptionPropertiesBuilder::with_aad_prefix`]
+    pub fn with_aad_prefix(mut self, aad_prefix: Vec<u8>) -> Self {
+        self.aad_prefix = Some(aad_prefix);
+        self
+    }
+
+    /// See [`EncryptionPropertiesBuilder::with_aad_prefix_storage`]
+    pub fn with_aad_prefix_storage(mut self, store_aad_prefix: bool) -> Self {
+        self.store_aad_prefix = store_aad_prefix;
+        self
+    }
+
+    /// Generate the keys and build the encryption properties
+    pub fn build(self) -> Result<Arc<FileEncryptionProperties>> {
+        let footer_key = self.key_generator.generate_footer_key()?;
+        let column_keys = self
+            .encrypted_columns
+            .into_iter()
+            .map(|column| {
+                let key = self.key_generator.generate_column_key(&column)?;
+                Ok((column, key))
+            })
+            .collect::<Result<HashMap<_, _>>>()?;
+        Ok(Arc::new(FileEncryptionProperties {
+            encrypt_footer: self.encrypt_footer,
+            footer_key,
+            column_keys,
+            aad_prefix: self.aad_prefix,
+            store_aad_prefix: self.store_aad_prefix,
+        }))
+    }
+}
+
 #[derive(Debug)]
 /// The encryption configuration for a single Parquet file
 pub(crate) struct FileEncryptor {
@@ -0,0 +1,782 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Integration of encryption with a Key Management Service (KMS), see [`KmsClient`]
+//!
+//! Data keys are generated randomly for each file and stored in its key metadata as
+//! key material wrapped with master keys held by the KMS, following the format of the
+//! [Parquet key management tools], so that files can be read by other implementations
+//! configured with the same KMS.
+//!
+//! With double wrapping, which is the default, each data key is wrapped locally with a
+//! key encryption key (KEK), and only the KEKs are wrapped by the KMS. A KEK is generated
+//! per master key for each [`KmsKeyGenerator`], and unwrapped KEKs are cached by each
+//! [`KmsKeyRetriever`], so that the KMS is called once per master key rather than once
+//! per column of each file.
+//!
+//! [Parquet key management tools]: https://github.com/apache/parquet-format/blob/master/Encryption.md#7-key-management
+
+use crate::encryption::ciphers::{
+    BlockDecryptor, BlockEncryptor, RingGcmBlockDecryptor, RingGcmBlockEncryptor, SIZE_LEN,
+};
+use crate::encryption::decrypt::KeyRetriever;
+use crate::encryption::encrypt::{
+    EncryptionKey, EncryptionPropertiesBuilderWithGenerator, KeyGenerator,
+};
+use crate::errors::{ParquetError, Result};
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
+#[cfg(feature = "async")]
+use futures::future::BoxFuture;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// The type of the key material written by this module
+const KEY_MATERIAL_TYPE: &str = "PKMT1";
+
+/// The length of the key encryption keys and their identifiers
+const KEK_LENGTH: usize = 16;
+
+/// The methods preparing keys ahead of time with an asynchronous client
+const PREPARE_GENERATOR: &str = "KmsKeyGenerator::encryption_properties_builder_async";
+const PREPARE_RETRIEVER: &str = "KmsKeyRetriever::prefetch_key";
+
+/// A client of a Key Management Service (KMS), which wraps and unwraps keys with master
+/// keys that never leave the KMS
+///
+/// The methods are called synchronously, when [`FileEncryptionProperties`] are built
+/// with a [`KmsKeyGenerator`] and when the metadata of a file is decrypted with a
+/// [`KmsKeyRetriever`]. Clients of asynchronous KMS APIs can instead implement
+/// [`AsyncKmsClient`], in which case the KMS is called ahead of time.
+///
+/// Master keys are rotated by the KMS. The wrapped keys returned by [`Self::wrap_key`]
+/// should identify the version of the master key used, so that keys wrapped before a
+/// rotation can still be unwrapped, and re-wrapped with [`rotate_key_metadata`].
+///
+/// # Example
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use parquet::encryption::decrypt::{FileDecryptionProperties, KeyRetriever};
+/// # use parquet::encryption::encrypt::FileEncryptionProperties;
+/// # use parquet::encryption::kms::{KmsClient, KmsKeyGenerator, KmsKeyRetriever};
+/// # use parquet::errors::{ParquetError, Result};
+/// /// Stands in for a KMS, which would encrypt keys with the master key
+/// struct Kms;
+///
+/// impl KmsClient for Kms {
+///     fn wrap_key(&self, key: &[u8], master_key_identifier: &str) -> Result<String> {
+///         let key: Vec<_> = key.iter().map(|b| format!("{b:02x}")).collect();
+///         Ok(format!("{master_key_identifier}:{}", key.join("")))
+///     }
+///
+///     fn unwrap_key(&self, wrapped_key: &str, master_key_identifier: &str) -> Result<Vec<u8>> {
+///         let key = wrapped_key
+///             .strip_prefix(&format!("{master_key_identifier}:"))
+///             .ok_or_else(|| ParquetError::General("Wrong master key".to_string()))?;
+///         (0..key.len())
+///             .step_by(2)
+///             .map(|i| u8::from_str_radix(&key[i..i + 2], 16))
+///             .collect::<Result<_, _>>()
+///             .map_err(|e| ParquetError::General(e.to_string()))
+///     }
+/// }
+///
+/// let kms = Arc::new(Kms);
+/// let key_generator = KmsKeyGenerator::new(kms.clone(), "footer_master_key")
+///     .with_column_master_key("x", "x_master_key");
+/// let encryption_properties = key_generator.encryption_properties_builder().build()?;
+///
+/// // Write a file with the encryption properties
+/// // ...
+///
+/// let key_retriever = KmsKeyRetriever::new(kms);
+/// let decryption_properties =
+///     FileDecryptionProperties::with_key_retriever(Arc::new(key_retriever)).build()?;
+/// # let footer_key_metadata = encryption_properties.footer_key_metadata().unwrap();
+/// # let footer_key = decryption_properties.footer_key(Some(footer_key_metadata.as_slice()))?;
+/// # assert_eq!(footer_key.as_slice(), encryption_properties.footer_key().as_slice());
+/// # Ok::<(), ParquetError>(())
+/// ```
+///
+/// [`FileEncryptionProperties`]: crate::encryption::encrypt::FileEncryptionProperties
+pub trait KmsClient: Send + Sync {
+    /// Wraps `key` with the master key with the given identifier, returning the wrapped
+    /// key as a string that is stored in the key material of files
+    fn wrap_key(&self, key: &[u8], master_key_identifier: &str) -> Result<String>;
+
+    /// Unwraps a key wrapped by [`Self::wrap_key`] with the master key with the given
+    /// identifier
+    fn unwrap_key(&self, wrapped_key: &str, master_key_identifier: &str) -> Result<Vec<u8>>;
+}
+
+/// An asynchronous [`KmsClient`]
+///
+/// As data keys are generated and retrieved synchronously, the KMS is called ahead of
+/// time: [`KmsKeyGenerator::encryption_properties_builder_async`] wraps a KEK for each
+/// master key, and [`KmsKeyRetriever::prefetch_key`] unwraps the key of some key
+/// metadata, after which keys are generated and retrieved without calling the KMS.
+#[cfg(feature = "async")]
+pub trait AsyncKmsClient: Send + Sync {
+    /// Wraps `key` with the master key with the given identifier, see
+    /// [`KmsClient::wrap_key`]
+    fn wrap_key<'a>(
+        &'a self,
+        key: &'a [u8],
+        master_key_identifier: &'a str,
+    ) -> BoxFuture<'a, Result<String>>;
+
+    /// Unwraps a key wrapped by [`Self::wrap_key`] with the master key with the given
+    /// identifier, see [`KmsClient::unwrap_key`]
+    fn unwrap_key<'a>(
+        &'a self,
+        wrapped_key: &'a str,
+        master_key_identifier: &'a str,
+    ) -> BoxFuture<'a, Result<Vec<u8>>>;
+}
+
+/// The client of the KMS used by a [`KmsKeyGenerator`] or [`KmsKeyRetriever`]
+#[derive(Clone)]
+enum Client {
+    Sync(Arc<dyn KmsClient>),
+    #[cfg(feature = "async")]
+    Async(Arc<dyn AsyncKmsClient>),
+}
+
+impl Client {
+    /// Returns the synchronous client, or an error naming the `prepare` method that
+    /// must be called beforehand with an asynchronous client
+    #[cfg_attr(not(feature = "async"), allow(unused_variables))]
+    fn sync(&self, prepare: &str) -> Result<&dyn KmsClient> {
+        match self {
+            Self::Sync(client) => Ok(client.as_ref()),
+            #[cfg(feature = "async")]
+            Self::Async(_) => Err(general_err!(
+                "Key not available from asynchronous KMS client, {} must be called first",
+                prepare
+            )),
+        }
+    }
+
+    #[cfg(feature = "async")]
+    async fn wrap_key(&self, key: &[u8], master_key_identifier: &str) -> Result<String> {
+        match self {
+            Self::Sync(client) => client.wrap_key(key, master_key_identifier),
+            Self::Async(client) => client.wrap_key(key, master_key_identifier).await,
+        }
+    }
+
+    #[cfg(feature = "async")]
+    async fn unwrap_key(&self, wrapped_key: &str, master_key_identifier: &str) -> Result<Vec<u8>> {
+        match self {
+            Self::Sync(client) => client.unwrap_key(wrapped_key, master_key_identifier),
+            Self::Async(client) => client.unwrap_key(wrapped_key, master_key_identifier).await,
+        }
+    }
+}
+
+/// A key encryption key (KEK) used for double wrapping
+#[derive(Clone)]
+struct KeyEncryptionKey {
+    id: Vec<u8>,
+    key: Vec<u8>,
+    /// The KEK wrapped with the master key by the KMS
+    wrapped: String,
+}
+
+/// A [`KeyGenerator`] generating random data keys, stored in the key metadata wrapped
+/// with master keys of a [`KmsClient`]
+///
+/// See [`KmsClient`] for example usage.
+pub struct KmsKeyGenerator {
+    client: Client,
+    footer_master_key: String,
+    column_master_keys: HashMap<String, String>,
+    key_length: usize,
+    double_wrapping: bool,
+    /// The KEKs generated for each master key
+    keks: Mutex<HashMap<String, KeyEncryptionKey>>,
+}
+
+impl KmsKeyGenerator {
+    /// Create a new [`KmsKeyGenerator`] wrapping the footer key with the master key with
+    /// identifier `footer_master_key`
+    pub fn new(client: Arc<dyn KmsClient>, footer_master_key: &str) -> Self {
+        Self::new_with_client(Client::Sync(client), footer_master_key)
+    }
+
+    /// Create a new [`KmsKeyGenerator`] with an [`AsyncKmsClient`], wrapping the footer
+    /// key with the master key with identifier `footer_master_key`
+    ///
+    /// The [`FileEncryptionProperties`] must be built from
+    /// [`Self::encryption_properties_builder_async`], and double wrapping is required.
+    ///
+    /// [`FileEncryptionProperties`]: crate::encryption::encrypt::FileEncryptionProperties
+    #[cfg(feature = "async")]
+    pub fn new_async(client: Arc<dyn AsyncKmsClient>, footer_master_key: &str) -> Self {
+        Self::new_with_client(Client::Async(client), footer_master_key)
+    }
+
+    fn new_with_client(client: Client, footer_master_key: &str) -> Self {
+        Self {
+            client,
+            footer_master_key: footer_master_key.to_string(),
+            column_master_keys: HashMap::new(),
+            key_length: 16,
+            double_wrapping: true,
+            keks: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Wrap the key of the column with the given path with the master key with identifier
+    /// `master_key`
+    pub fn with_column_master_key(mut self, column_path: &str, master_key: &str) -> Self {
+        self.column_master_keys
+            .insert(column_path.to_string(), master_key.to_string());
+        self
+    }
+
+    /// Set the length in bytes of the generated data keys, either 16 or 32.
+    /// Defaults to 16.
+    pub fn with_key_length(mut self, key_length: usize) -> Self {
+        self.key_length = key_length;
+        self
+    }
+
+    /// Set if data keys should be wrapped with locally generated KEKs, which are in turn
+    /// wrapped by the KMS, rather than directly by the KMS. Defaults to true.
+    pub fn with_double_wrapping(mut self, double_wrapping: bool) -> Self {
+        self.double_wrapping = double_wrapping;
+        self
+    }
+
+    /// Returns a builder for [`FileEncryptionProperties`] using this generator, which
+    /// encrypts the columns with a master key set by [`Self::with_column_master_key`]
+    ///
+    /// [`FileEncryptionProperties`]: crate::encryption::encrypt::FileEncryptionProperties
+    pub fn encryption_properties_builder(self) -> EncryptionPropertiesBuilderWithGenerator {
+        let mut columns: Vec<_> = self.column_master_keys.keys().cloned().collect();
+        columns.sort_unstable();
+        EncryptionPropertiesBuilderWithGenerator::new(Arc::new(self))
+            .with_encrypted_columns(columns.iter().map(String::as_str))
+    }
+
+    /// Returns a builder for [`FileEncryptionProperties`] using this generator, like
+    /// [`Self::encryption_properties_builder`], after wrapping a KEK for each master key
+    /// with the KMS, so that no further KMS calls are made when generating keys
+    ///
+    /// Returns an error if double wrapping is disabled, as each data key would then
+    /// need to be wrapped by the KMS.
+    ///
+    /// [`FileEncryptionProperties`]: crate::encryption::encrypt::FileEncryptionProperties
+    #[cfg(feature = "async")]
+    pub async fn encryption_properties_builder_async(
+        self,
+    ) -> Result<EncryptionPropertiesBuilderWithGenerator> {
+        if !self.double_wrapping {
+            return Err(general_err!(
+                "Double wrapping is required to generate keys ahead of time"
+            ));
+        }
+        let mut master_keys: Vec<_> = std::iter::once(&self.footer_master_key)
+            .chain(self.column_master_keys.values())
+            .collect();
+        master_keys.sort_unstable();
+        master_keys.dedup();
+        for master_key in master_keys {
+            if self.keks.lock().unwrap().contains_key(master_key) {
+                continue;
+            }
+            let (id, key) = random_kek()?;
+            let wrapped = self.client.wrap_key(&key, master_key).await?;
+            let kek = KeyEncryptionKey { id, key, wrapped };
+            self.keks.lock().unwrap().insert(master_key.clone(), kek);
+        }
+        Ok(self.encryption_properties_builder())
+    }
+
+    /// Generates a data key and its key material, wrapped with `master_key`
+    fn generate_key(&self, master_key: &str, is_footer_key: bool) -> Result<EncryptionKey> {
+        let key = EncryptionKey::random(self.key_length)?;
+        let mut material = Map::new();
+        material.insert("keyMaterialType".into(), KEY_MATERIAL_TYPE.into());
+        material.insert("internalStorage".into(), true.into());
+        material.insert("isFooterKey".into(), is_footer_key.into());
+        if is_footer_key {
+            material.insert("kmsInstanceID".into(), "DEFAULT".into());
+            material.insert("kmsInstanceURL".into(), "DEFAULT".into());
+        }
+        material.insert("masterKeyID".into(), master_key.into());
+        material.insert("doubleWrapping".into(), self.double_wrapping.into());
+        match self.double_wrapping {
+            true => {
+                let kek = self.kek(master_key)?;
+                let wrapped = wrap_key_locally(key.key(), &kek.key, &kek.id)?;
+                material.insert("wrappedDEK".into(), wrapped.into());
+                let kek_id = BASE64_STANDARD.encode(&kek.id);
+                material.insert("keyEncryptionKeyID".into(), kek_id.into());
+                material.insert("wrappedKEK".into(), kek.wrapped.into());
+            }
+            false => {
+                let wrapped = self
+                    .client
+                    .sync(PREPARE_GENERATOR)?
+                    .wrap_key(key.key(), master_key)?;
+                material.insert("wrappedDEK".into(), wrapped.into());
+            }
+        }
+        let metadata = Value::Object(material).to_string().into_bytes();
+        Ok(key.with_metadata(metadata))
+    }
+
+    /// Returns the KEK for `master_key`, generating and wrapping it if needed
+    fn kek(&self, master_key: &str) -> Result<KeyEncryptionKey> {
+        let mut keks = self.keks.lock().unwrap();
+        if let Some(kek) = keks.get(master_key) {
+            return Ok(kek.clone());
+        }
+        let (id, key) = random_kek()?;
+        let wrapped = self
+            .client
+            .sync(PREPARE_GENERATOR)?
+            .wrap_key(&key, master_key)?;
+        let kek = KeyEncryptionKey { id, key, wrapped };
+        keks.insert(master_key.to_string(), kek.clone());
+        Ok(kek)
+    }
+}
+
+impl KeyGenerator for KmsKeyGenerator {
+    fn generate_footer_key(&self) -> Result<EncryptionKey> {
+        self.generate_key(&self.footer_master_key, true)
+    }
+
+    fn generate_column_key(&self, column_path: &str) -> Result<EncryptionKey> {
+        let master_key = self.column_master_keys.get(column_path).ok_or_else(|| {
+            general_err!(
+                "No master key specified for encrypted column '{}'",
+                column_path
+            )
+        })?;
+        self.generate_key(master_key, false)
+    }
+}
+
+/// A [`KeyRetriever`] unwrapping the data keys stored in key metadata by a
+/// [`KmsKeyGenerator`] with a [`KmsClient`]
+///
+/// See [`KmsClient`] for example usage.
+pub struct KmsKeyRetriever {
+    client: Client,
+    /// The unwrapped KEKs by their identifier
+    keks: Mutex<HashMap<Vec<u8>, Vec<u8>>>,
+    /// The prefetched data keys without double wrapping, by their key metadata
+    keys: Mutex<HashMap<Vec<u8>, Vec<u8>>>,
+}
+
+impl KmsKeyRetriever {
+    /// Create a new [`KmsKeyRetriever`] unwrapping keys with `client`
+    pub fn new(client: Arc<dyn KmsClient>) -> Self {
+        Self::new_with_client(Client::Sync(client))
+    }
+
+    /// Create a new [`KmsKeyRetriever`] unwrapping keys with an [`AsyncKmsClient`]
+    ///
+    /// Keys can only be retrieved after [`Self::prefetch_key`] has been called with
+    /// their key metadata, or, with double wrapping, with key metadata sharing the
+    /// same KEK.
+    #[cfg(feature = "async")]
+    pub fn new_async(client: Arc<dyn AsyncKmsClient>) -> Self {
+        Self::new_with_client(Client::Async(client))
+    }
+
+    fn new_with_client(client: Client) -> Self {
+        Self {
+            client,
+            keks: Mutex::new(HashMap::new()),
+            keys: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Unwraps the KEK, or the data key without double wrapping, of `key_metadata`
+    /// with the KMS, so that the key can then be retrieved without calling the KMS
+    #[cfg(feature = "async")]
+    pub async fn prefetch_key(&self, key_metadata: &[u8]) -> Result<()> {
+        let material = KeyMaterial::try_new(key_metadata)?;
+        if !material.bool_field("doubleWrapping")? {
+            if self.keys.lock().unwrap().contains_key(key_metadata) {
+                return Ok(());
+            }
+            let wrapped = material.field("wrappedDEK")?;
+            let key = self
+                .client
+                .unwrap_key(wrapped, material.master_key()?)
+                .await?;
+            self.keys.lock().unwrap().insert(key_metadata.to_vec(), key);
+            return Ok(());
+        }
+
+        let kek_id = material.base64_field("keyEncryptionKeyID")?;
+        if self.keks.lock().unwrap().contains_key(&kek_id) {
+            return Ok(());
+        }
+        let wrapped = material.field("wrappedKEK")?;
+        let kek = self
+            .client
+            .unwrap_key(wrapped, material.master_key()?)
+            .await?;
+        self.keks.lock().unwrap().insert(kek_id, kek);
+        Ok(())
+    }
+}
+
+impl KeyRetriever for KmsKeyRetriever {
+    fn retrieve_key(&self, key_metadata: &[u8]) -> Result<Vec<u8>> {
+        let material = KeyMaterial::try_new(key_metadata)?;
+        if !material.bool_field("doubleWrapping")? {
+            if let Some(key) = self.keys.lock().unwrap().get(key_metadata) {
+                return Ok(key.clone());
+            }
+            return self
+                .client
+                .sync(PREPARE_RETRIEVER)?
+                .unwrap_key(material.field("wrappedDEK")?, material.master_key()?);
+        }
+
+        let kek_id = material.base64_field("keyEncryptionKeyID")?;
+        let cached = self.keks.lock().unwrap().get(&kek_id).cloned();
+        let kek = match cached {
+            Some(kek) => kek,
+            None => {
+                let wrapped = material.field("wrappedKEK")?;
+                let kek = self
+                    .client
+                    .sync(PREPARE_RETRIEVER)?
+                    .unwrap_key(wrapped, material.master_key()?)?;
+                self.keks
+                    .lock()
+                    .unwrap()
+                    .insert(kek_id.clone(), kek.clone());
+                kek
+            }
+        };
+        let wrapped = material.base64_field("wrappedDEK")?;
+        unwrap_key_locally(&wrapped, &kek, &kek_id)
+    }
+}
+
+/// Re-wraps the key in `key_metadata` written by a [`KmsKeyGenerator`] with the current
+/// version of its master key, returning the new key metadata
+///
+/// This is used after rotating master keys in the KMS, so that the key metadata no
+/// longer depends on the previous versions of the master keys, for example when key
+/// metadata is stored outside of files and resolved by a custom [`KeyRetriever`], or when
+/// rewriting the metadata of files. The data key itself is unchanged, and with double
+/// wrapping only its KEK is re-wrapped by the KMS.
+pub fn rotate_key_metadata(client: &dyn KmsClient, key_metadata: &[u8]) -> Result<Vec<u8>> {
+    let mut material = KeyMaterial::try_new(key_metadata)?;
+    let master_key = material.master_key()?.to_string();
+    let field = material.wrapped_field()?;
+    let key = client.unwrap_key(material.field(field)?, &master_key)?;
+    let wrapped = client.wrap_key(&key, &master_key)?;
+    material.0.insert(field.into(), wrapped.into());
+    Ok(Value::Object(material.0).to_string().into_bytes())
+}
+
+/// Re-wraps the key in `key_metadata` with an [`AsyncKmsClient`], see
+/// [`rotate_key_metadata`]
+#[cfg(feature = "async")]
+pub async fn rotate_key_metadata_async(
+    client: &dyn AsyncKmsClient,
+    key_metadata: &[u8],
+) -> Result<Vec<u8>> {
+    let mut material = KeyMaterial::try_new(key_metadata)?;
+    let master_key = material.master_key()?.to_string();
+    let field = material.wrapped_field()?;
+    let key = client
+        .unwrap_key(material.field(field)?, &master_key)
+        .await?;
+    let wrapped = client.wrap_key(&key, &master_key).await?;
+    material.0.insert(field.into(), wrapped.into());
+    Ok(Value::Object(material.0).to_string().into_bytes())
+}
+
+/// The key material stored in key metadata
+struct KeyMaterial(Map<String, Value>);
+
+impl KeyMaterial {
+    fn try_new(key_metadata: &[u8]) -> Result<Self> {
+        let material = match serde_json::from_slice(key_metadata) {
+            Ok(Value::Object(material)) => material,
+            _ => return Err(general_err!("Key metadata is not valid key material")),
+        };
+        let material = Self(material);
+        match material.field("keyMaterialType")? {
+            KEY_MATERIAL_TYPE => Ok(material),
+            other => Err(general_err!("Unsupported key material type '{}'", other)),
+        }
+    }
+
+    fn master_key(&self) -> Result<&str> {
+        self.field("masterKeyID")
+    }
+
+    /// Returns the name of the field holding the key wrapped by the KMS
+    fn wrapped_field(&self) -> Result<&'static str> {
+        match self.bool_field("doubleWrapping")? {
+            true => Ok("wrappedKEK"),
+            false => Ok("wrappedDEK"),
+        }
+    }
+
+    fn field(&self, name: &str) -> Result<&str> {
+        self.0
+            .get(name)
+            .and_then(Value::as_str)
+            .ok_or_else(|| general_err!("Key material is missing '{}'", name))
+    }
+
+    fn bool_field(&self, name: &str) -> Result<bool> {
+        self.0
+            .get(name)
+            .and_then(Value::as_bool)
+            .ok_or_else(|| general_err!("Key material is missing '{}'", name))
+    }
+
+    fn base64_field(&self, name: &str) -> Result<Vec<u8>> {
+        BASE64_STANDARD
+            .decode(self.field(name)?)
+            .map_err(|e| general_err!("Invalid '{}' in key material: {}", name, e))
+    }
+}
+
+/// Generates the identifier and key of a new KEK
+fn random_kek() -> Result<(Vec<u8>, Vec<u8>)> {
+    let id = EncryptionKey::random(KEK_LENGTH)?.key().clone();
+    let key = EncryptionKey::random(KEK_LENGTH)?.key().clone();
+    Ok((id, key))
+}
+
+/// Wraps `key` with AES-GCM, returning the nonce, ciphertext and tag encoded as base64
+fn wrap_key_locally(key: &[u8], kek: &[u8], aad: &[u8]) -> Result<String> {
+    let ciphertext = RingGcmBlockEncryptor::new(kek)?.encrypt(key, aad)?;
+    // Strip the length prefix of the ciphertext
+    Ok(BASE64_STANDARD.encode(&ciphertext[SIZE_LEN..]))
+}
+
+/// Unwraps a key wrapped by [`wrap_key_locally`]
+fn unwrap_key_locally(wrapped: &[u8], kek: &[u8], aad: &[u8]) -> Result<Vec<u8>> {
+    let length =
+        u32::try_from(wrapped.len()).map_err(|e| general_err!("Wrapped key too long: {}", e))?;
+    let ciphertext = [length.to_le_bytes().as_slice(), wrapped].concat();
+    RingGcmBlockDecryptor::new(kek)?
+        .decrypt(&ciphertext, aad)
+        .map_err(|_| general_err!("Failed to unwrap data key with key encryption key"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encryption::decrypt::FileDecryptionProperties;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// A KMS with versioned master keys that counts its calls
+    #[derive(Default)]
+    struct TestKms {
+        version: AtomicUsize,
+        calls: AtomicUsize,
+    }
+
+    impl TestKms {
+        fn master_key(id: &str, version: &str) -> Vec<u8> {
+            format!("{id}{version}").bytes().cycle().take(16).collect()
+        }
+    }
+
+    impl KmsClient for TestKms {
+        fn wrap_key(&self, key: &[u8], master_key_identifier: &str) -> Result<String> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            let version = self.version.load(Ordering::SeqCst).to_string();
+            let master_key = Self::master_key(master_key_identifier, &version);
+            let wrapped = wrap_key_locally(key, &master_key, &[])?;
+            Ok(format!("{version}:{wrapped}"))
+        }
+
+        fn unwrap_key(&self, wrapped_key: &str, master_key_identifier: &str) -> Result<Vec<u8>> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            let (version, wrapped) = wrapped_key.split_once(':').unwrap();
+            let master_key = Self::master_key(master_key_identifier, version);
+            let wrapped = BASE64_STANDARD.decode(wrapped).unwrap();
+            unwrap_key_locally(&wrapped, &master_key, &[])
+        }
+    }
+
+    #[test]
+    fn test_kms_round_trip() {
+        for double_wrapping in [true, false] {
+            let kms = Arc::new(TestKms::default());
+            let generator = KmsKeyGenerator::new(kms.clone(), "kf")
+                .with_column_master_key("a", "k1")
+                .with_column_master_key("b", "k1")
+                .with_key_length(32)
+                .with_double_wrapping(double_wrapping);
+            let properties = generator.encryption_properties_builder().build().unwrap();
+            let (columns, keys, metadata) = properties.column_keys();
+            assert_eq!(columns.len(), 2);
+            assert!(keys.iter().all(|k| k.len() == 32));
+            assert_ne!(keys[0], keys[1]);
+            let expected_calls = match double_wrapping {
+                true => 2,
+                false => 3,
+            };
+            assert_eq!(kms.calls.swap(0, Ordering::SeqCst), expected_calls);
+
+            let retriever = Arc::new(KmsKeyRetriever::new(kms.clone()));
+            let decryption = FileDecryptionProperties::with_key_retriever(retriever)
+                .build()
+                .unwrap();
+            let footer_metadata = properties.footer_key_metadata().unwrap();
+            let footer_key = decryption
+                .footer_key(Some(footer_metadata.as_slice()))
+                .unwrap();
+            assert_eq!(footer_key.as_slice(), properties.footer_key().as_slice());
+            for ((column, key), metadata) in columns.iter().zip(&keys).zip(&metadata) {
+                let retrieved = decryption
+                    .column_key(column, Some(metadata.as_slice()))
+                    .unwrap();
+                assert_eq!(retrieved.as_slice(), key.as_slice());
+            }
+            assert_eq!(kms.calls.swap(0, Ordering::SeqCst), expected_calls);
+
+            // After a rotation, the rotated key material unwraps to the same key
+            kms.version.store(1, Ordering::SeqCst);
+            let rotated = rotate_key_metadata(kms.as_ref(), &metadata[0]).unwrap();
+            assert_ne!(&rotated, &metadata[0]);
+            let retriever = KmsKeyRetriever::new(kms.clone());
+            assert_eq!(retriever.retrieve_key(&rotated).unwrap(), keys[0]);
+            assert_eq!(retriever.retrieve_key(&metadata[0]).unwrap(), keys[0]);
+        }
+    }
+
+    #[cfg(feature = "async")]
+    impl AsyncKmsClient for TestKms {
+        fn wrap_key<'a>(
+            &'a self,
+            key: &'a [u8],
+            master_key_identifier: &'a str,
+        ) -> BoxFuture<'a, Result<String>> {
+            Box::pin(async move { KmsClient::wrap_key(self, key, master_key_identifier) })
+        }
+
+        fn unwrap_key<'a>(
+            &'a self,
+            wrapped_key: &'a str,
+            master_key_identifier: &'a str,
+        ) -> BoxFuture<'a, Result<Vec<u8>>> {
+            Box::pin(async move { KmsClient::unwrap_key(self, wrapped_key, master_key_identifier) })
+        }
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_async_kms_round_trip() {
+        let kms = Arc::new(TestKms::default());
+        let generator = KmsKeyGenerator::new_async(kms.clone(), "kf")
+            .with_column_master_key("a", "k1")
+            .with_column_master_key("b", "k1");
+        let properties = generator
+            .encryption_properties_builder_async()
+            .await
+            .unwrap()
+            .build()
+            .unwrap();
+        let (_, keys, metadata) = properties.column_keys();
+        // One KEK is wrapped for each of the two master keys
+        assert_eq!(kms.calls.swap(0, Ordering::SeqCst), 2);
+
+        let retriever = KmsKeyRetriever::new_async(kms.clone());
+        let err = retriever.retrieve_key(&metadata[0]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Key not available from asynchronous KMS client, \
+             KmsKeyRetriever::prefetch_key must be called first"
+        );
+        retriever.prefetch_key(&metadata[0]).await.unwrap();
+        retriever.prefetch_key(&metadata[1]).await.unwrap();
+        assert_eq!(kms.calls.swap(0, Ordering::SeqCst), 1);
+        assert_eq!(retriever.retrieve_key(&metadata[0]).unwrap(), keys[0]);
+        assert_eq!(retriever.retrieve_key(&metadata[1]).unwrap(), keys[1]);
+
+        // Without double wrapping, keys are unwrapped individually
+        let sync_kms: Arc<dyn KmsClient> = kms.clone();
+        let key = KmsKeyGenerator::new(sync_kms, "kf")
+            .with_double_wrapping(false)
+            .generate_footer_key()
+            .unwrap();
+        kms.version.store(1, Ordering::SeqCst);
+        let rotated = rotate_key_metadata_async(kms.as_ref(), key.key_metadata().unwrap())
+            .await
+            .unwrap();
+        assert!(retriever.retrieve_key(&rotated).is_err());
+        retriever.prefetch_key(&rotated).await.unwrap();
+        assert_eq!(&retriever.retrieve_key(&rotated).unwrap(), key.key());
+
+        let err = KmsKeyGenerator::new_async(kms, "kf")
+            .with_double_wrapping(false)
+            .encryption_properties_builder_async()
+            .await
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Double wrapping is required to generate keys ahead of time"
+        );
+    }
+
+    #[test]
+    fn test_kms_errors() {
+        let kms = Arc::new(TestKms::default());
+        let generator = KmsKeyGenerator::new(kms.clone(), "kf");
+        let err = generator.generate_column_key("a").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: No master key specified for encrypted column 'a'"
+        );
+
+        let retriever = KmsKeyRetriever::new(kms);
+        let err = retriever.retrieve_key(b"key").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Key metadata is not valid key material"
+        );
+        let err = retriever
+            .retrieve_key(br#"{"keyMaterialType":"PKMT2"}"#)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Unsupported key material type 'PKMT2'"
+        );
+        let err = retriever
+            .retrieve_key(br#"{"keyMaterialType":"PKMT1","doubleWrapping":false}"#)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Key material is missing 'wrappedDEK'"
+        );
+    }
+}
@@ -109,4 +109,5 @@
 pub(crate) mod ciphers;
 pub mod decrypt;
 pub mod encrypt;
+pub mod kms;
 pub(crate) mod modules;
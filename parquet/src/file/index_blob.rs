@@ -0,0 +1,204 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Custom index blobs embedded in a parquet file
+//!
+//! An index blob is an arbitrary named payload, such as a vector index or zone
+//! maps, written into the parquet file after the data pages with
+//! [`SerializedFileWriter::write_index_blob`]. Its location is recorded in the
+//! key value metadata of the file, under the key `parquet.index_blob.<name>` with
+//! the value `<offset>:<length>`, so that it can be read with a single range
+//! request, see [`read_index_blob`].
+//!
+//! Other parquet readers ignore index blobs, as they are not referenced by the
+//! parquet metadata. Index blobs are never encrypted.
+//!
+//! ```
+//! # use std::sync::Arc;
+//! # use bytes::Bytes;
+//! # use parquet::file::index_blob::{index_blob_names, read_index_blob};
+//! # use parquet::file::metadata::ParquetMetaDataReader;
+//! # use parquet::file::writer::SerializedFileWriter;
+//! # use parquet::schema::parser::parse_message_type;
+//! let schema = Arc::new(parse_message_type("message schema { REQUIRED INT32 a; }").unwrap());
+//! let mut writer = SerializedFileWriter::new(vec![], schema, Default::default()).unwrap();
+//! // ... write row groups
+//! writer.write_index_blob("zone_map", b"custom index").unwrap();
+//! let data = Bytes::from(writer.into_inner().unwrap());
+//!
+//! let metadata = ParquetMetaDataReader::new().parse_and_finish(&data).unwrap();
+//! let file_metadata = metadata.file_metadata();
+//! assert_eq!(index_blob_names(file_metadata).collect::<Vec<_>>(), vec!["zone_map"]);
+//! let blob = read_index_blob(&data, file_metadata, "zone_map").unwrap();
+//! assert_eq!(blob.as_deref(), Some(b"custom index".as_slice()));
+//! ```
+//!
+//! [`SerializedFileWriter::write_index_blob`]: crate::file::writer::SerializedFileWriter::write_index_blob
+
+use std::ops::Range;
+
+use bytes::Bytes;
+
+use crate::errors::{ParquetError, Result};
+use crate::file::metadata::{FileMetaData, KeyValue};
+use crate::file::reader::ChunkReader;
+
+/// The prefix of the key value metadata keys of index blobs
+pub const INDEX_BLOB_KEY_PREFIX: &str = "parquet.index_blob.";
+
+/// Returns the [`KeyValue`] recording the location of the index blob `name`
+pub(crate) fn index_blob_key_value(name: &str, range: Range<u64>) -> KeyValue {
+    KeyValue::new(
+        format!("{INDEX_BLOB_KEY_PREFIX}{name}"),
+        format!("{}:{}", range.start, range.end - range.start),
+    )
+}
+
+/// Returns the names of the index blobs in `metadata`
+pub fn index_blob_names(metadata: &FileMetaData) -> impl Iterator<Item = &str> {
+    metadata
+        .key_value_metadata()
+        .into_iter()
+        .flatten()
+        .filter_map(|kv| kv.key.strip_prefix(INDEX_BLOB_KEY_PREFIX))
+}
+
+/// Returns the byte range of the index blob `name` in the file, or `None` if
+/// there is no such blob
+///
+/// Returns an error if the location recorded in the key value metadata is invalid
+pub fn index_blob_range(metadata: &FileMetaData, name: &str) -> Result<Option<Range<u64>>> {
+    let key_value = metadata
+        .key_value_metadata()
+        .into_iter()
+        .flatten()
+        .find(|kv| kv.key.strip_prefix(INDEX_BLOB_KEY_PREFIX) == Some(name));
+    let Some(key_value) = key_value else {
+        return Ok(None);
+    };
+
+    let invalid = || general_err!("Invalid location for index blob '{}'", name);
+    let (offset, length) = key_value
+        .value
+        .as_deref()
+        .and_then(|value| value.split_once(':'))
+        .ok_or_else(invalid)?;
+    let offset: u64 = offset.parse().map_err(|_| invalid())?;
+    let length: u64 = length.parse().map_err(|_| invalid())?;
+    let end = offset.checked_add(length).ok_or_else(invalid)?;
+    Ok(Some(offset..end))
+}
+
+/// Reads the index blob `name` from `reader`, or returns `None` if there is no
+/// such blob
+pub fn read_index_blob<R: ChunkReader>(
+    reader: &R,
+    metadata: &FileMetaData,
+    name: &str,
+) -> Result<Option<Bytes>> {
+    let Some(range) = index_blob_range(metadata, name)? else {
+        return Ok(None);
+    };
+    let length = (range.end - range.start).try_into()?;
+    Ok(Some(reader.get_bytes(range.start, length)?))
+}
+
+/// Reads the index blob `name` from `reader` with a single range request, or
+/// returns `None` if there is no such blob
+///
+/// See [`read_index_blob`] for the synchronous version
+#[cfg(all(feature = "arrow", feature = "async"))]
+pub async fn read_index_blob_async<R: crate::arrow::async_reader::AsyncFileReader>(
+    reader: &mut R,
+    metadata: &FileMetaData,
+    name: &str,
+) -> Result<Option<Bytes>> {
+    let Some(range) = index_blob_range(metadata, name)? else {
+        return Ok(None);
+    };
+    Ok(Some(reader.get_bytes(range).await?))
+}
+
+/// Checks that the index blob `name` is not in the `key_value_metadata` written so far
+pub(crate) fn validate_index_blob_name(name: &str, key_value_metadata: &[KeyValue]) -> Result<()> {
+    if name.is_empty() {
+        return Err(general_err!("Index blob name must not be empty"));
+    }
+    let exists = key_value_metadata
+        .iter()
+        .any(|kv| kv.key.strip_prefix(INDEX_BLOB_KEY_PREFIX) == Some(name));
+    if exists {
+        return Err(general_err!("Index blob '{}' already written", name));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::parser::parse_message_type;
+    use crate::schema::types::SchemaDescriptor;
+    use std::sync::Arc;
+
+    fn file_metadata(key_value_metadata: Vec<KeyValue>) -> FileMetaData {
+        let schema = parse_message_type("message schema { REQUIRED INT32 a; }").unwrap();
+        let schema_descr = Arc::new(SchemaDescriptor::new(Arc::new(schema)));
+        FileMetaData::new(1, 0, None, Some(key_value_metadata), schema_descr, None)
+    }
+
+    #[test]
+    fn test_index_blob_range() {
+        let metadata = file_metadata(vec![
+            KeyValue::new("other".to_string(), "value".to_string()),
+            index_blob_key_value("a", 4..10),
+            KeyValue::new(format!("{INDEX_BLOB_KEY_PREFIX}b"), "4".to_string()),
+            KeyValue::new(format!("{INDEX_BLOB_KEY_PREFIX}c"), None),
+        ]);
+
+        assert_eq!(
+            index_blob_names(&metadata).collect::<Vec<_>>(),
+            vec!["a", "b", "c"]
+        );
+        assert_eq!(index_blob_range(&metadata, "a").unwrap(), Some(4..10));
+        assert_eq!(index_blob_range(&metadata, "other").unwrap(), None);
+        for name in ["b", "c"] {
+            let err = index_blob_range(&metadata, name).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                format!("Parquet error: Invalid location for index blob '{name}'")
+            );
+        }
+    }
+
+    #[tokio::test]
+    #[cfg(all(feature = "arrow", feature = "async"))]
+    async fn test_read_index_blob_async() {
+        let mut data = b"PAR1".to_vec();
+        data.extend_from_slice(b"blob");
+        let metadata = file_metadata(vec![index_blob_key_value("blob", 4..8)]);
+
+        let mut reader = std::io::Cursor::new(data);
+        let blob = read_index_blob_async(&mut reader, &metadata, "blob")
+            .await
+            .unwrap();
+        assert_eq!(blob.as_deref(), Some(b"blob".as_slice()));
+        let missing = read_index_blob_async(&mut reader, &metadata, "missing")
+            .await
+            .unwrap();
+        assert_eq!(missing, None);
+    }
+}
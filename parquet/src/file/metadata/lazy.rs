@@ -0,0 +1,374 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::ops::Range;
+use std::sync::{Arc, OnceLock};
+
+use bytes::Bytes;
+
+use crate::errors::{ParquetError, Result};
+use crate::file::metadata::thrift::{
+    OrdinalAssigner, lazy_metadata_from_bytes, read_column_chunk_from_bytes,
+    read_row_group_from_bytes,
+};
+use crate::file::metadata::{
+    ColumnChunkMetaData, FileMetaData, HeapSize, ParquetMetaData, ParquetMetaDataOptions,
+    RowGroupMetaData,
+};
+use crate::file::page_index::column_index::ColumnIndexMetaData;
+use crate::file::page_index::index_reader::{decode_column_index, decode_offset_index};
+use crate::file::page_index::offset_index::OffsetIndexMetaData;
+use crate::file::reader::ChunkReader;
+
+/// Lazily decoded Parquet metadata
+///
+/// Decoding the footer of a file with many row groups or columns into
+/// [`ParquetMetaData`] can take hundreds of megabytes. [`LazyParquetMetaData`]
+/// instead only decodes the [`FileMetaData`], and retains the encoded footer so
+/// that the metadata of individual row groups and column chunks can be decoded
+/// on demand. The page indexes are likewise read per column chunk, keeping
+/// memory proportional to the parts of the file actually accessed.
+///
+/// Use [`ParquetMetaDataReader::parse_lazy`] to read it from a file, and
+/// [`Self::decode_row_groups`] to obtain a [`ParquetMetaData`] for the selected
+/// row groups, which can be used with the existing readers.
+///
+/// Files with encrypted metadata are not supported.
+///
+/// # Example
+/// ```no_run
+/// # use std::fs::File;
+/// # use parquet::file::metadata::ParquetMetaDataReader;
+/// let file = File::open("some.parquet").unwrap();
+/// let metadata = ParquetMetaDataReader::new().parse_lazy(&file).unwrap();
+/// println!("{} row groups", metadata.num_row_groups());
+///
+/// // only decode the metadata of the column chunk being read
+/// let column = metadata.column_chunk(3, 1000).unwrap();
+/// let offset_index = metadata.read_offset_index(&file, 3, 1000).unwrap();
+/// ```
+///
+/// [`ParquetMetaDataReader::parse_lazy`]: crate::file::metadata::ParquetMetaDataReader::parse_lazy
+#[derive(Debug, Clone)]
+pub struct LazyParquetMetaData {
+    /// The thrift encoded footer metadata
+    buf: Bytes,
+    file_metadata: FileMetaData,
+    /// The byte range of each thrift encoded row group in `buf`
+    row_groups: Vec<Range<usize>>,
+    options: Option<Arc<ParquetMetaDataOptions>>,
+    /// Validates the row group ordinals against the first row group
+    ordinals: OnceLock<OrdinalAssigner>,
+}
+
+impl LazyParquetMetaData {
+    /// Create a new [`LazyParquetMetaData`] from the thrift encoded footer
+    /// metadata in `buf`, without the trailing 8 byte footer
+    pub fn try_new(buf: Bytes) -> Result<Self> {
+        Self::try_new_with_options(buf, None)
+    }
+
+    /// Like [`Self::try_new`], but decodes the row groups and column chunks
+    /// with the given [`ParquetMetaDataOptions`]
+    pub fn try_new_with_options(
+        buf: Bytes,
+        options: Option<Arc<ParquetMetaDataOptions>>,
+    ) -> Result<Self> {
+        let (file_metadata, row_groups) = lazy_metadata_from_bytes(&buf, options.as_deref())?;
+        #[cfg(feature = "encryption")]
+        if file_metadata.encryption_algorithm.is_some() {
+            return Err(general_err!(
+                "Lazily decoding the metadata of encrypted files is not supported"
+            ));
+        }
+        Ok(Self {
+            buf,
+            file_metadata,
+            row_groups,
+            options,
+            ordinals: OnceLock::new(),
+        })
+    }
+
+    /// Returns the [`FileMetaData`]
+    pub fn file_metadata(&self) -> &FileMetaData {
+        &self.file_metadata
+    }
+
+    /// Returns the number of row groups in the file
+    pub fn num_row_groups(&self) -> usize {
+        self.row_groups.len()
+    }
+
+    /// Decodes the metadata of row group `i`, including all its column chunks
+    pub fn row_group(&self, i: usize) -> Result<RowGroupMetaData> {
+        let mut assigner = self.ordinal_assigner()?;
+        self.decode_row_group(i, &mut assigner)
+    }
+
+    /// Decodes the metadata of column chunk `column` of row group `row_group`,
+    /// without decoding the other column chunks of the row group
+    pub fn column_chunk(&self, row_group: usize, column: usize) -> Result<ColumnChunkMetaData> {
+        read_column_chunk_from_bytes(
+            self.row_group_bytes(row_group)?,
+            column,
+            &self.file_metadata.schema_descr_ptr(),
+            self.options.as_deref(),
+        )
+    }
+
+    /// Decodes the metadata of the row groups `row_groups`, returning a
+    /// [`ParquetMetaData`] containing only these row groups, in the given order
+    pub fn decode_row_groups(&self, row_groups: &[usize]) -> Result<ParquetMetaData> {
+        let row_groups = row_groups
+            .iter()
+            .map(|i| self.row_group(*i))
+            .collect::<Result<_>>()?;
+        Ok(ParquetMetaData::new(self.file_metadata.clone(), row_groups))
+    }
+
+    /// Reads the offset index of column chunk `column` of row group `row_group`
+    /// from `reader`, or returns `None` if it has no offset index
+    pub fn read_offset_index<R: ChunkReader>(
+        &self,
+        reader: &R,
+        row_group: usize,
+        column: usize,
+    ) -> Result<Option<OffsetIndexMetaData>> {
+        let column = self.column_chunk(row_group, column)?;
+        let Some(range) = column.offset_index_range() else {
+            return Ok(None);
+        };
+        let bytes = get_range(reader, range)?;
+        decode_offset_index(&bytes).map(Some)
+    }
+
+    /// Reads the column index of column chunk `column` of row group `row_group`
+    /// from `reader`, or returns `None` if it has no column index
+    pub fn read_column_index<R: ChunkReader>(
+        &self,
+        reader: &R,
+        row_group: usize,
+        column: usize,
+    ) -> Result<Option<ColumnIndexMetaData>> {
+        let column = self.column_chunk(row_group, column)?;
+        let Some(range) = column.column_index_range() else {
+            return Ok(None);
+        };
+        let bytes = get_range(reader, range)?;
+        decode_column_index(&bytes, column.column_type()).map(Some)
+    }
+
+    /// Returns the estimated total memory allocated by this object, in bytes
+    ///
+    /// This includes the retained footer bytes, but not any row group or column
+    /// chunk metadata decoded from them.
+    pub fn memory_size(&self) -> usize {
+        std::mem::size_of::<Self>()
+            + self.buf.len()
+            + self.file_metadata.heap_size()
+            + self.row_groups.capacity() * std::mem::size_of::<Range<usize>>()
+    }
+
+    /// Returns an [`OrdinalAssigner`] that has seen the first row group, so
+    /// that the ordinals are validated as when decoding all the row groups
+    fn ordinal_assigner(&self) -> Result<OrdinalAssigner> {
+        if let Some(assigner) = self.ordinals.get() {
+            return Ok(assigner.clone());
+        }
+        let mut assigner = OrdinalAssigner::new();
+        if !self.row_groups.is_empty() {
+            self.decode_row_group(0, &mut assigner)?;
+        }
+        Ok(self.ordinals.get_or_init(|| assigner).clone())
+    }
+
+    fn decode_row_group(
+        &self,
+        i: usize,
+        assigner: &mut OrdinalAssigner,
+    ) -> Result<RowGroupMetaData> {
+        read_row_group_from_bytes(
+            self.row_group_bytes(i)?,
+            i,
+            assigner,
+            &self.file_metadata.schema_descr_ptr(),
+            self.options.as_deref(),
+        )
+    }
+
+    fn row_group_bytes(&self, i: usize) -> Result<&[u8]> {
+        let range = self.row_groups.get(i).ok_or_else(|| {
+            general_err!(
+                "Row group index {} out of bounds for {} row groups",
+                i,
+                self.row_groups.len()
+            )
+        })?;
+        Ok(&self.buf[range.clone()])
+    }
+}
+
+fn get_range<R: ChunkReader>(reader: &R, range: Range<u64>) -> Result<Bytes> {
+    let length = usize::try_from(range.end - range.start)?;
+    reader.get_bytes(range.start, length)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_type::Int32Type;
+    use crate::file::metadata::{PageIndexPolicy, ParquetMetaDataReader, ParquetMetaDataWriter};
+    use crate::file::properties::WriterProperties;
+    use crate::file::writer::SerializedFileWriter;
+    use crate::schema::parser::parse_message_type;
+
+    /// A file with 3 row groups of 3 columns
+    fn test_file() -> Bytes {
+        let schema = parse_message_type(
+            "message schema { REQUIRED INT32 a; OPTIONAL INT32 b; REQUIRED INT32 c; }",
+        )
+        .unwrap();
+        let props = WriterProperties::builder()
+            .set_data_page_row_count_limit(10)
+            .set_write_batch_size(10)
+            .build();
+        let mut writer =
+            SerializedFileWriter::new(vec![], Arc::new(schema), Arc::new(props)).unwrap();
+        for rg in 0..3 {
+            let mut row_group = writer.next_row_group().unwrap();
+            let values: Vec<i32> = (0..25).map(|v| v + rg * 100).collect();
+            let mut col_idx = 0;
+            while let Some(mut column) = row_group.next_column().unwrap() {
+                let def_levels = vec![1; values.len()];
+                let def_levels = (col_idx == 1).then_some(def_levels.as_slice());
+                column
+                    .typed::<Int32Type>()
+                    .write_batch(&values, def_levels, None)
+                    .unwrap();
+                column.close().unwrap();
+                col_idx += 1;
+            }
+            row_group.close().unwrap();
+        }
+        Bytes::from(writer.into_inner().unwrap())
+    }
+
+    #[test]
+    fn test_lazy_metadata() {
+        let data = test_file();
+        let expected = ParquetMetaDataReader::new()
+            .with_page_index_policy(PageIndexPolicy::Required)
+            .parse_and_finish(&data)
+            .unwrap();
+        let lazy = ParquetMetaDataReader::new().parse_lazy(&data).unwrap();
+
+        assert_eq!(lazy.file_metadata(), expected.file_metadata());
+        assert_eq!(lazy.num_row_groups(), 3);
+        for rg in 0..3 {
+            assert_eq!(&lazy.row_group(rg).unwrap(), expected.row_group(rg));
+            for col in 0..3 {
+                assert_eq!(
+                    &lazy.column_chunk(rg, col).unwrap(),
+                    expected.row_group(rg).column(col)
+                );
+                let offset_index = lazy.read_offset_index(&data, rg, col).unwrap();
+                assert_eq!(
+                    offset_index.as_ref(),
+                    Some(&expected.offset_index().unwrap()[rg][col])
+                );
+                let column_index = lazy.read_column_index(&data, rg, col).unwrap();
+                assert_eq!(
+                    column_index.as_ref(),
+                    Some(&expected.column_index().unwrap()[rg][col])
+                );
+            }
+        }
+
+        let decoded = lazy.decode_row_groups(&[2, 0]).unwrap();
+        assert_eq!(decoded.num_row_groups(), 2);
+        assert_eq!(decoded.row_group(0), expected.row_group(2));
+        assert_eq!(decoded.row_group(1), expected.row_group(0));
+        assert_eq!(decoded.row_group(0).ordinal(), Some(2));
+    }
+
+    #[test]
+    fn test_lazy_metadata_out_of_bounds() {
+        let lazy = ParquetMetaDataReader::new()
+            .parse_lazy(&test_file())
+            .unwrap();
+        let err = lazy.row_group(3).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Row group index 3 out of bounds for 3 row groups"
+        );
+        let err = lazy.column_chunk(0, 3).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Column index 3 out of bounds for 3 columns"
+        );
+    }
+
+    #[test]
+    fn test_lazy_metadata_inconsistent_ordinals() {
+        let metadata = ParquetMetaDataReader::new()
+            .parse_and_finish(&test_file())
+            .unwrap();
+        // Drop the ordinal of the second row group only
+        let rg = metadata.row_group(1);
+        let rg = RowGroupMetaData::builder(rg.schema_descr_ptr())
+            .set_num_rows(rg.num_rows())
+            .set_total_byte_size(rg.total_byte_size())
+            .set_column_metadata(rg.columns().to_vec())
+            .build()
+            .unwrap();
+        let mut builder = metadata.clone().into_builder();
+        builder = builder.set_row_groups(vec![
+            metadata.row_group(0).clone(),
+            rg,
+            metadata.row_group(2).clone(),
+        ]);
+        let metadata = builder.build();
+
+        let mut buf = vec![];
+        ParquetMetaDataWriter::new(&mut buf, &metadata)
+            .finish()
+            .unwrap();
+        let lazy = ParquetMetaDataReader::new()
+            .parse_lazy(&Bytes::from(buf))
+            .unwrap();
+
+        assert_eq!(lazy.row_group(0).unwrap().ordinal(), Some(0));
+        assert_eq!(lazy.row_group(2).unwrap().ordinal(), Some(2));
+        let err = lazy.row_group(1).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Inconsistent ordinal assignment: first_has_ordinal is set to \
+            true but row-group with actual ordinal 1 has rg_has_ordinal set to false"
+        );
+    }
+
+    #[test]
+    fn test_lazy_metadata_memory_size() {
+        let data = test_file();
+        let metadata = ParquetMetaDataReader::new()
+            .parse_and_finish(&data)
+            .unwrap();
+        let lazy = ParquetMetaDataReader::new().parse_lazy(&data).unwrap();
+        assert!(lazy.memory_size() < metadata.memory_size());
+    }
+}
@@ -87,6 +87,7 @@
 //!                         * Same name, different struct
 //! ```
 mod footer_tail;
+mod lazy;
 mod memory;
 mod options;
 mod parser;
@@ -125,6 +126,7 @@ use crate::{
 };
 
 pub use footer_tail::FooterTail;
+pub use lazy::LazyParquetMetaData;
 pub use options::{ParquetMetaDataOptions, ParquetStatisticsPolicy};
 pub use push_decoder::ParquetMetaDataPushDecoder;
 pub use reader::{PageIndexPolicy, ParquetMetaDataReader};
@@ -1128,6 +1130,34 @@ impl ColumnChunkMetaData {
         }
     }
 
+    /// Returns `true` if this column chunk fell back from dictionary encoding, that is
+    /// it has a dictionary page and data pages that are not dictionary encoded, or
+    /// `None` if no page encoding statistics are available.
+    ///
+    /// See [`WriterPropertiesBuilder::set_dictionary_fallback_enabled`]
+    ///
+    /// [`WriterPropertiesBuilder::set_dictionary_fallback_enabled`]: crate::file::properties::WriterPropertiesBuilder::set_dictionary_fallback_enabled
+    pub fn dictionary_fallback(&self) -> Option<bool> {
+        let is_dictionary =
+            |e: &Encoding| matches!(e, Encoding::PLAIN_DICTIONARY | Encoding::RLE_DICTIONARY);
+        Some(match self.encoding_stats.as_ref()? {
+            ParquetPageEncodingStats::Full(stats) => {
+                let mut data_pages = stats
+                    .iter()
+                    .filter(|s| s.page_type != PageType::DICTIONARY_PAGE);
+                let has_dictionary = self.dictionary_page_offset.is_some()
+                    || stats
+                        .iter()
+                        .any(|s| s.page_type == PageType::DICTIONARY_PAGE);
+                has_dictionary && data_pages.any(|s| !is_dictionary(&s.encoding))
+            }
+            ParquetPageEncodingStats::Mask(mask) => {
+                self.dictionary_page_offset.is_some()
+                    && mask.encodings().any(|e| !is_dictionary(&e))
+            }
+        })
+    }
+
     /// Returns the offset for the bloom filter.
     pub fn bloom_filter_offset(&self) -> Option<i64> {
         self.bloom_filter_offset
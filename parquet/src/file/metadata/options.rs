@@ -18,6 +18,7 @@
 //! Options used to control metadata parsing
 
 use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
 use crate::schema::types::SchemaDescPtr;
@@ -44,7 +45,7 @@ use crate::schema::types::SchemaDescPtr;
 /// let options = ArrowReaderOptions::new()
 ///     .with_encoding_stats_policy(ParquetStatisticsPolicy::skip_except(&[0, 1]));
 /// ```
-#[derive(Default, Debug, Clone)]
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
 pub enum ParquetStatisticsPolicy {
     /// Decode the relevant statistics for all columns.
     #[default]
@@ -80,6 +81,16 @@ impl ParquetStatisticsPolicy {
     }
 }
 
+impl Hash for ParquetStatisticsPolicy {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        // The columns of a `HashSet` are unordered, so only hash their number
+        if let Self::SkipExcept(keep) = self {
+            keep.len().hash(state);
+        }
+    }
+}
+
 /// Options that can be set to control what parts of the Parquet file footer
 /// metadata will be decoded and made present in the [`ParquetMetaData`] returned
 /// by [`ParquetMetaDataReader`] and [`ParquetMetaDataPushDecoder`].
@@ -87,7 +98,7 @@ impl ParquetStatisticsPolicy {
 /// [`ParquetMetaData`]: crate::file::metadata::ParquetMetaData
 /// [`ParquetMetaDataReader`]: crate::file::metadata::ParquetMetaDataReader
 /// [`ParquetMetaDataPushDecoder`]: crate::file::metadata::ParquetMetaDataPushDecoder
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ParquetMetaDataOptions {
     schema_descr: Option<SchemaDescPtr>,
     encoding_stats_as_mask: bool,
@@ -108,6 +119,19 @@ impl Default for ParquetMetaDataOptions {
     }
 }
 
+impl Eq for ParquetMetaDataOptions {}
+
+impl Hash for ParquetMetaDataOptions {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Schemas are not hashable, equal options only need to agree on whether one is set
+        self.schema_descr.is_some().hash(state);
+        self.encoding_stats_as_mask.hash(state);
+        self.encoding_stats_policy.hash(state);
+        self.column_stats_policy.hash(state);
+        self.size_stats_policy.hash(state);
+    }
+}
+
 impl ParquetMetaDataOptions {
     /// Return a new default [`ParquetMetaDataOptions`].
     pub fn new() -> Self {
@@ -22,7 +22,8 @@ use crate::file::FOOTER_SIZE;
 use crate::file::metadata::parser::decode_metadata;
 use crate::file::metadata::thrift::parquet_schema_from_bytes;
 use crate::file::metadata::{
-    FooterTail, ParquetMetaData, ParquetMetaDataOptions, ParquetMetaDataPushDecoder,
+    FooterTail, LazyParquetMetaData, ParquetMetaData, ParquetMetaDataOptions,
+    ParquetMetaDataPushDecoder,
 };
 use crate::file::reader::ChunkReader;
 use crate::schema::types::SchemaDescriptor;
@@ -82,7 +83,7 @@ pub struct ParquetMetaDataReader {
 }
 
 /// Describes the policy for reading page indexes
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum PageIndexPolicy {
     /// Do not read the page index.
     #[default]
@@ -204,6 +205,40 @@ impl ParquetMetaDataReader {
         self.finish()
     }
 
+    /// Parses the footer metadata given a [`ChunkReader`], returning a
+    /// [`LazyParquetMetaData`] that decodes the row group and column chunk
+    /// metadata, and reads the page indexes, on demand
+    ///
+    /// This keeps memory proportional to the parts of the file accessed, which
+    /// matters for files with very large footers, such as those with many
+    /// thousands of columns or row groups. The [`PageIndexPolicy`] is ignored.
+    ///
+    /// Returns an error for files with an encrypted footer.
+    pub fn parse_lazy<R: ChunkReader>(&self, reader: &R) -> Result<LazyParquetMetaData> {
+        let file_size = reader.len();
+        if file_size < FOOTER_SIZE as u64 {
+            return Err(ParquetError::NeedMoreData(FOOTER_SIZE));
+        }
+        let mut footer = [0_u8; FOOTER_SIZE];
+        reader
+            .get_read(file_size - FOOTER_SIZE as u64)?
+            .read_exact(&mut footer)?;
+        let footer = FooterTail::try_new(&footer)?;
+        if footer.is_encrypted_footer() {
+            return Err(general_err!(
+                "Lazily decoding the metadata of encrypted files is not supported"
+            ));
+        }
+
+        let metadata_len = footer.metadata_length();
+        let footer_metadata_len = FOOTER_SIZE + metadata_len;
+        if footer_metadata_len as u64 > file_size {
+            return Err(ParquetError::NeedMoreData(footer_metadata_len));
+        }
+        let buf = reader.get_bytes(file_size - footer_metadata_len as u64, metadata_len)?;
+        LazyParquetMetaData::try_new_with_options(buf, self.metadata_options.clone())
+    }
+
     /// Attempts to parse the footer metadata (and optionally page indexes) given a [`ChunkReader`].
     ///
     /// If `reader` is [`Bytes`] based, then the buffer must contain sufficient bytes to complete
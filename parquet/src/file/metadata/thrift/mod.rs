@@ -24,6 +24,7 @@
 //! [Parquet specification]: https://github.com/apache/parquet-format/tree/master
 
 use std::io::Write;
+use std::ops::Range;
 use std::sync::Arc;
 
 #[cfg(feature = "encryption")]
@@ -746,12 +747,109 @@ pub(crate) fn parquet_metadata_from_bytes(
     buf: &[u8],
     options: Option<&ParquetMetaDataOptions>,
 ) -> Result<ParquetMetaData> {
+    match file_metadata_from_bytes(buf, options, true)? {
+        (file_metadata, RowGroups::Decoded(row_groups)) => {
+            Ok(ParquetMetaData::new(file_metadata, row_groups))
+        }
+        (_, RowGroups::Encoded(_)) => unreachable!("row groups are decoded"),
+    }
+}
+
+/// Create [`FileMetaData`] from thrift input, returning the byte range of each
+/// encoded row group in `buf` instead of decoding them, see [`read_row_group_from_bytes`]
+///
+/// [`FileMetaData`]: crate::file::metadata::FileMetaData
+pub(crate) fn lazy_metadata_from_bytes(
+    buf: &[u8],
+    options: Option<&ParquetMetaDataOptions>,
+) -> Result<(crate::file::metadata::FileMetaData, Vec<Range<usize>>)> {
+    match file_metadata_from_bytes(buf, options, false)? {
+        (file_metadata, RowGroups::Encoded(ranges)) => Ok((file_metadata, ranges)),
+        (_, RowGroups::Decoded(_)) => unreachable!("row groups are not decoded"),
+    }
+}
+
+/// Decode the thrift encoded row group in `buf`, located at `ordinal` in the file metadata
+///
+/// The ordinal is validated and assigned by `assigner`, which must have seen
+/// the first row group of the file, as when decoding all the row groups
+pub(crate) fn read_row_group_from_bytes(
+    buf: &[u8],
+    ordinal: usize,
+    assigner: &mut OrdinalAssigner,
+    schema_descr: &Arc<SchemaDescriptor>,
+    options: Option<&ParquetMetaDataOptions>,
+) -> Result<RowGroupMetaData> {
+    let ordinal: i16 = ordinal
+        .try_into()
+        .map_err(|_| general_err!("Row group ordinal {} exceeds i16 max value", ordinal))?;
+    let mut prot = ThriftSliceInputProtocol::new(buf);
+    let row_group = read_row_group(&mut prot, schema_descr, options)?;
+    assigner.ensure(ordinal, row_group)
+}
+
+/// Decode column chunk `col_index` of the thrift encoded row group in `buf`,
+/// skipping over the other column chunks
+pub(crate) fn read_column_chunk_from_bytes(
+    buf: &[u8],
+    col_index: usize,
+    schema_descr: &Arc<SchemaDescriptor>,
+    options: Option<&ParquetMetaDataOptions>,
+) -> Result<ColumnChunkMetaData> {
+    if col_index >= schema_descr.num_columns() {
+        return Err(general_err!(
+            "Column index {} out of bounds for {} columns",
+            col_index,
+            schema_descr.num_columns()
+        ));
+    }
+    let mut prot = ThriftSliceInputProtocol::new(buf);
+    let mut last_field_id = 0i16;
+    loop {
+        let field_ident = prot.read_field_begin(last_field_id)?;
+        match (field_ident.field_type, field_ident.id) {
+            (FieldType::Stop, _) => break,
+            (_, 1) => {
+                let list_ident = prot.read_list_begin()?;
+                validate_list_type(ElementType::Struct, &list_ident)?;
+                if schema_descr.num_columns() != list_ident.size as usize {
+                    return Err(general_err!(
+                        "Column count mismatch. Schema has {} columns while Row Group has {}",
+                        schema_descr.num_columns(),
+                        list_ident.size
+                    ));
+                }
+                for _ in 0..col_index {
+                    prot.skip(FieldType::Struct)?;
+                }
+                let column_descr = &schema_descr.columns()[col_index];
+                return read_column_chunk(&mut prot, column_descr, col_index, options);
+            }
+            _ => prot.skip(field_ident.field_type)?,
+        }
+        last_field_id = field_ident.id;
+    }
+    Err(general_err!("Required field columns is missing"))
+}
+
+/// The row groups of the file metadata, see [`file_metadata_from_bytes`]
+enum RowGroups {
+    Decoded(Vec<RowGroupMetaData>),
+    /// The byte range of each thrift encoded row group
+    Encoded(Vec<Range<usize>>),
+}
+
+fn file_metadata_from_bytes(
+    buf: &[u8],
+    options: Option<&ParquetMetaDataOptions>,
+    decode_row_groups: bool,
+) -> Result<(crate::file::metadata::FileMetaData, RowGroups)> {
     let mut prot = ThriftSliceInputProtocol::new(buf);
 
     // begin reading the file metadata
     let mut version: Option<i32> = None;
     let mut num_rows: Option<i64> = None;
-    let mut row_groups: Option<Vec<RowGroupMetaData>> = None;
+    let mut row_groups: Option<RowGroups> = None;
     let mut key_value_metadata: Option<Vec<KeyValue>> = None;
     let mut created_by: Option<&str> = None;
     let mut column_orders: Option<Vec<ColumnOrder>> = None;
@@ -812,6 +910,17 @@ pub(crate) fn parquet_metadata_from_bytes(
                 let list_ident = prot.read_list_begin()?;
                 // check for list of struct
                 validate_list_type(ElementType::Struct, &list_ident)?;
+                if !decode_row_groups {
+                    let mut ranges = Vec::with_capacity(list_ident.size as usize);
+                    for _ in 0..list_ident.size {
+                        let start = buf.len() - prot.as_slice().len();
+                        prot.skip(FieldType::Struct)?;
+                        ranges.push(start..buf.len() - prot.as_slice().len());
+                    }
+                    row_groups = Some(RowGroups::Encoded(ranges));
+                    last_field_id = field_ident.id;
+                    continue;
+                }
                 let mut rg_vec = Vec::with_capacity(list_ident.size as usize);
 
                 // Read row groups and handle ordinal assignment
@@ -825,7 +934,7 @@ pub(crate) fn parquet_metadata_from_bytes(
                     let rg = read_row_group(&mut prot, schema_descr, options)?;
                     rg_vec.push(assigner.ensure(ordinal, rg)?);
                 }
-                row_groups = Some(rg_vec);
+                row_groups = Some(RowGroups::Decoded(rg_vec));
             }
             5 => {
                 let val = read_thrift_vec::<KeyValue, ThriftSliceInputProtocol>(&mut prot)?;
@@ -912,17 +1021,17 @@ pub(crate) fn parquet_metadata_from_bytes(
     .with_encryption_algorithm(encryption_algorithm)
     .with_footer_signing_key_metadata(footer_signing_key_metadata.map(|v| v.to_vec()));
 
-    Ok(ParquetMetaData::new(fmd, row_groups))
+    Ok((fmd, row_groups))
 }
 
 /// Assign [`RowGroupMetaData::ordinal`]  if it is missing.
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub(crate) struct OrdinalAssigner {
     first_has_ordinal: Option<bool>,
 }
 
 impl OrdinalAssigner {
-    fn new() -> Self {
+    pub(crate) fn new() -> Self {
         Default::default()
     }
 
@@ -177,21 +177,15 @@ impl<'a, W: Write> ThriftMetadataWriter<'a, W> {
             self.write_offset_indexes(offset_indexes)?;
         }
 
-        // check to see if the index is `None` for every row group and column chunk
-        let all_none = offset_indexes
-            .as_ref()
-            .is_some_and(|oi| oi.iter().all(|oii| oii.iter().all(|idx| idx.is_none())));
-
-        let offset_indexes: Option<ParquetOffsetIndex> = if all_none {
-            None
-        } else {
-            // FIXME(ets): this will panic if there's a missing index.
-            offset_indexes.map(|ovvi| {
+        // the offset index is only returned if present for every column chunk,
+        // consistent with how missing offset indexes are handled when reading
+        let offset_indexes: Option<ParquetOffsetIndex> = offset_indexes
+            .and_then(|ovvi| {
                 ovvi.into_iter()
-                    .map(|vi| vi.into_iter().map(|oi| oi.unwrap()).collect())
+                    .map(|vi| vi.into_iter().collect::<Option<Vec<_>>>())
                     .collect()
             })
-        };
+            .filter(|oi: &ParquetOffsetIndex| oi.iter().any(|oii| !oii.is_empty()));
 
         Ok(offset_indexes)
     }
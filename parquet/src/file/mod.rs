@@ -99,12 +99,14 @@
 //! ```
 #[cfg(feature = "encryption")]
 pub mod column_crypto_metadata;
+pub mod index_blob;
 pub mod metadata;
 pub mod page_index;
 pub mod properties;
 pub mod reader;
 pub mod serialized_reader;
 pub mod statistics;
+pub mod validation;
 pub mod writer;
 
 /// The length of the parquet footer in bytes
@@ -36,6 +36,10 @@ pub const DEFAULT_WRITER_VERSION: WriterVersion = WriterVersion::PARQUET_1_0;
 pub const DEFAULT_COMPRESSION: Compression = Compression::UNCOMPRESSED;
 /// Default value for [`WriterProperties::dictionary_enabled`]
 pub const DEFAULT_DICTIONARY_ENABLED: bool = true;
+/// Default value for [`WriterProperties::adaptive_byte_array_encoding`]
+pub const DEFAULT_ADAPTIVE_BYTE_ARRAY_ENCODING: bool = false;
+/// Default value for [`WriterProperties::dictionary_fallback_enabled`]
+pub const DEFAULT_DICTIONARY_FALLBACK_ENABLED: bool = true;
 /// Default value for [`WriterProperties::dictionary_page_size_limit`]
 pub const DEFAULT_DICTIONARY_PAGE_SIZE_LIMIT: usize = DEFAULT_PAGE_SIZE;
 /// Default value for [`WriterProperties::data_page_row_count_limit`]
@@ -62,12 +66,20 @@ pub const DEFAULT_BLOOM_FILTER_FPP: f64 = 0.05;
 /// [`WriterProperties::max_row_group_row_count`], which may differ from this constant
 /// if the user configured a custom row group size.
 pub const DEFAULT_BLOOM_FILTER_NDV: u64 = DEFAULT_MAX_ROW_GROUP_ROW_COUNT as u64;
+/// Default value for [`BloomFilterProperties::adaptive()`]
+pub const DEFAULT_BLOOM_FILTER_ADAPTIVE: bool = false;
 /// Default values for [`WriterProperties::statistics_truncate_length`]
 pub const DEFAULT_STATISTICS_TRUNCATE_LENGTH: Option<usize> = Some(64);
+/// Default value for [`WriterProperties::statistics_truncation`]
+pub const DEFAULT_STATISTICS_TRUNCATION: StatisticsTruncation = StatisticsTruncation::Truncate;
+/// Default value for [`WriterProperties::statistics_distinct_count_enabled`]
+pub const DEFAULT_STATISTICS_DISTINCT_COUNT_ENABLED: bool = true;
 /// Default value for [`WriterProperties::offset_index_disabled`]
 pub const DEFAULT_OFFSET_INDEX_DISABLED: bool = false;
 /// Default values for [`WriterProperties::coerce_types`]
 pub const DEFAULT_COERCE_TYPES: bool = false;
+/// Default value for [`WriterProperties::validate_sorting_columns`]
+pub const DEFAULT_VALIDATE_SORTING_COLUMNS: bool = false;
 /// Default value for [`WriterProperties::data_page_v2_compression_ratio_threshold`]
 pub const DEFAULT_DATA_PAGE_V2_COMPRESSION_RATIO_THRESHOLD: f64 = 1.0;
 /// Default value for [`WriterProperties::write_path_in_schema`]
@@ -251,6 +263,7 @@ pub struct WriterProperties {
     default_column_properties: ColumnProperties,
     column_properties: HashMap<ColumnPath, ColumnProperties>,
     sorting_columns: Option<Vec<SortingColumn>>,
+    validate_sorting_columns: bool,
     column_index_truncate_length: Option<usize>,
     statistics_truncate_length: Option<usize>,
     coerce_types: bool,
@@ -414,6 +427,13 @@ impl WriterProperties {
         self.sorting_columns.as_ref()
     }
 
+    /// Returns `true` if the rows are checked to be sorted by the sorting columns.
+    ///
+    /// For more details see [`WriterPropertiesBuilder::set_validate_sorting_columns`]
+    pub fn validate_sorting_columns(&self) -> bool {
+        self.validate_sorting_columns
+    }
+
     /// Returns the maximum length of truncated min/max values in the column index.
     ///
     /// `None` if truncation is disabled, must be greater than 0 otherwise.
@@ -434,6 +454,21 @@ impl WriterProperties {
         self.statistics_truncate_length
     }
 
+    /// Returns the maximum length of truncated min/max values in [`Statistics`] for a
+    /// specific column.
+    ///
+    /// Takes precedence over [`Self::statistics_truncate_length`].
+    ///
+    /// For more details see [`WriterPropertiesBuilder::set_column_statistics_truncate_length`]
+    ///
+    /// [`Statistics`]: crate::file::statistics::Statistics
+    pub fn column_statistics_truncate_length(&self, col: &ColumnPath) -> Option<usize> {
+        self.column_properties
+            .get(col)
+            .and_then(|c| c.statistics_truncate_length())
+            .unwrap_or(self.statistics_truncate_length)
+    }
+
     /// Returns `true` if type coercion is enabled.
     ///
     /// For more details see [`WriterPropertiesBuilder::set_coerce_types`]
@@ -513,6 +548,21 @@ impl WriterProperties {
             .or_else(|| self.default_column_properties.encoding())
     }
 
+    /// Returns `true` if the encoding of a `BYTE_ARRAY` column is selected based on
+    /// the data written.
+    ///
+    /// For more details see [`WriterPropertiesBuilder::set_adaptive_byte_array_encoding`]
+    pub fn adaptive_byte_array_encoding(&self, col: &ColumnPath) -> bool {
+        self.column_properties
+            .get(col)
+            .and_then(|c| c.adaptive_byte_array_encoding())
+            .or_else(|| {
+                self.default_column_properties
+                    .adaptive_byte_array_encoding()
+            })
+            .unwrap_or(DEFAULT_ADAPTIVE_BYTE_ARRAY_ENCODING)
+    }
+
     /// Returns compression codec for a column.
     ///
     /// For more details see [`WriterPropertiesBuilder::set_column_compression`]
@@ -535,6 +585,28 @@ impl WriterProperties {
             .unwrap_or(DEFAULT_DICTIONARY_ENABLED)
     }
 
+    /// Enables dictionary encoding for `col`, unless dictionary encoding has
+    /// already been explicitly configured for that column.
+    #[cfg(feature = "arrow")]
+    pub(crate) fn set_column_dictionary_hint(&mut self, col: ColumnPath) {
+        let props = self.column_properties.entry(col).or_default();
+        if props.dictionary_enabled().is_none() {
+            props.set_dictionary_enabled(true);
+        }
+    }
+
+    /// Returns `true` if a column may fall back from dictionary encoding once its
+    /// dictionary page size limit is exceeded.
+    ///
+    /// For more details see [`WriterPropertiesBuilder::set_dictionary_fallback_enabled`]
+    pub fn dictionary_fallback_enabled(&self, col: &ColumnPath) -> bool {
+        self.column_properties
+            .get(col)
+            .and_then(|c| c.dictionary_fallback_enabled())
+            .or_else(|| self.default_column_properties.dictionary_fallback_enabled())
+            .unwrap_or(DEFAULT_DICTIONARY_FALLBACK_ENABLED)
+    }
+
     /// Returns which statistics are written for a column.
     ///
     /// For more details see [`WriterPropertiesBuilder::set_statistics_enabled`]
@@ -562,16 +634,53 @@ impl WriterProperties {
             .unwrap_or(DEFAULT_WRITE_PAGE_HEADER_STATISTICS)
     }
 
+    /// Returns how min/max values longer than the statistics truncation length are
+    /// written for a column.
+    ///
+    /// For more details see [`WriterPropertiesBuilder::set_statistics_truncation`]
+    pub fn statistics_truncation(&self, col: &ColumnPath) -> StatisticsTruncation {
+        self.column_properties
+            .get(col)
+            .and_then(|c| c.statistics_truncation())
+            .or_else(|| self.default_column_properties.statistics_truncation())
+            .unwrap_or(DEFAULT_STATISTICS_TRUNCATION)
+    }
+
+    /// Returns `true` if the distinct count is written in the [`Statistics`] of a column.
+    ///
+    /// For more details see [`WriterPropertiesBuilder::set_statistics_distinct_count_enabled`]
+    ///
+    /// [`Statistics`]: crate::file::statistics::Statistics
+    pub fn statistics_distinct_count_enabled(&self, col: &ColumnPath) -> bool {
+        self.column_properties
+            .get(col)
+            .and_then(|c| c.statistics_distinct_count_enabled())
+            .or_else(|| {
+                self.default_column_properties
+                    .statistics_distinct_count_enabled()
+            })
+            .unwrap_or(DEFAULT_STATISTICS_DISTINCT_COUNT_ENABLED)
+    }
+
     /// Returns the [`BloomFilterProperties`] for the given column
     ///
     /// Returns `None` if bloom filter is disabled
     ///
     /// For more details see [`WriterPropertiesBuilder::set_column_bloom_filter_enabled`]
     pub fn bloom_filter_properties(&self, col: &ColumnPath) -> Option<&BloomFilterProperties> {
-        self.column_properties
-            .get(col)
-            .and_then(|c| c.bloom_filter_properties())
+        // properties set for a group apply to all leaf columns within it, unless overridden,
+        // including disabled, for a more specific path
+        let parts = col.parts();
+        (1..=parts.len())
+            .rev()
+            .find_map(|len| {
+                let path = ColumnPath::new(parts[..len].to_vec());
+                self.column_properties
+                    .get(&path)
+                    .and_then(|c| c.bloom_filter_properties())
+            })
             .or_else(|| self.default_column_properties.bloom_filter_properties())
+            .flatten()
     }
 
     /// Return file encryption properties
@@ -600,6 +709,7 @@ pub struct WriterPropertiesBuilder {
     default_column_properties: ColumnProperties,
     column_properties: HashMap<ColumnPath, ColumnProperties>,
     sorting_columns: Option<Vec<SortingColumn>>,
+    validate_sorting_columns: bool,
     column_index_truncate_length: Option<usize>,
     statistics_truncate_length: Option<usize>,
     coerce_types: bool,
@@ -625,6 +735,7 @@ impl Default for WriterPropertiesBuilder {
             default_column_properties: Default::default(),
             column_properties: HashMap::new(),
             sorting_columns: None,
+            validate_sorting_columns: DEFAULT_VALIDATE_SORTING_COLUMNS,
             column_index_truncate_length: DEFAULT_COLUMN_INDEX_TRUNCATE_LENGTH,
             statistics_truncate_length: DEFAULT_STATISTICS_TRUNCATE_LENGTH,
             coerce_types: DEFAULT_COERCE_TYPES,
@@ -680,6 +791,7 @@ impl WriterPropertiesBuilder {
             default_column_properties,
             column_properties,
             sorting_columns: self.sorting_columns,
+            validate_sorting_columns: self.validate_sorting_columns,
             column_index_truncate_length: self.column_index_truncate_length,
             statistics_truncate_length: self.statistics_truncate_length,
             coerce_types: self.coerce_types,
@@ -815,11 +927,34 @@ impl WriterPropertiesBuilder {
     }
 
     /// Sets sorting order of rows in the row group if any (defaults to `None`).
+    ///
+    /// The sorting columns are recorded in the metadata of each row group as
+    /// provided. See [`Self::set_validate_sorting_columns`] to check that the rows
+    /// written are actually sorted.
     pub fn set_sorting_columns(mut self, value: Option<Vec<SortingColumn>>) -> Self {
         self.sorting_columns = value;
         self
     }
 
+    /// Sets whether to check that the rows of each row group are sorted by the
+    /// [sorting columns](Self::set_sorting_columns) (defaults to `false` via
+    /// [`DEFAULT_VALIDATE_SORTING_COLUMNS`]).
+    ///
+    /// Readers may rely on the sorting columns, for example to binary search
+    /// sorted data, so recording incorrect sorting columns can lead to incorrect
+    /// results. When enabled, [`ArrowWriter`] returns an error when writing a row
+    /// that is not sorted by the sorting columns with respect to the previous rows
+    /// of the row group. Only top level columns can be validated.
+    ///
+    /// Note the `boundary_order` of the column index is always computed from the
+    /// minimum and maximum values of the data pages.
+    ///
+    /// [`ArrowWriter`]: crate::arrow::ArrowWriter
+    pub fn set_validate_sorting_columns(mut self, validate_sorting_columns: bool) -> Self {
+        self.validate_sorting_columns = validate_sorting_columns;
+        self
+    }
+
     /// Sets the max length of min/max value fields when writing the column
     /// [`Index`] (defaults to `Some(64)` via [`DEFAULT_COLUMN_INDEX_TRUNCATE_LENGTH`]).
     ///
@@ -861,7 +996,10 @@ impl WriterPropertiesBuilder {
     ///
     /// # See also
     /// Truncation of Page Index statistics is controlled separately via
-    /// [`WriterPropertiesBuilder::set_column_index_truncate_length`]
+    /// [`WriterPropertiesBuilder::set_column_index_truncate_length`]. The length can be
+    /// overridden for specific columns via [`Self::set_column_statistics_truncate_length`],
+    /// and [`Self::set_statistics_truncation`] controls whether long values are truncated
+    /// or omitted.
     ///
     /// [`Statistics`]: crate::file::statistics::Statistics
     pub fn set_statistics_truncate_length(mut self, max_length: Option<usize>) -> Self {
@@ -1025,6 +1163,21 @@ impl WriterPropertiesBuilder {
         self
     }
 
+    /// Sets default flag to select the encoding of `BYTE_ARRAY` columns based on the
+    /// data written (defaults to `false` via [`DEFAULT_ADAPTIVE_BYTE_ARRAY_ENCODING`]).
+    ///
+    /// If enabled, the first values of each column chunk written with the non-dictionary
+    /// encoding are encoded with `PLAIN`, `DELTA_LENGTH_BYTE_ARRAY` and `DELTA_BYTE_ARRAY`,
+    /// and the encoding with the smallest output is used for the rest of the column chunk.
+    /// This takes precedence over the encoding set with [`Self::set_encoding`].
+    ///
+    /// This is only supported by the [`ArrowWriter`](crate::arrow::ArrowWriter).
+    pub fn set_adaptive_byte_array_encoding(mut self, value: bool) -> Self {
+        self.default_column_properties
+            .set_adaptive_byte_array_encoding(value);
+        self
+    }
+
     /// Sets default compression codec for all columns (default to [`UNCOMPRESSED`] via
     /// [`DEFAULT_COMPRESSION`]).
     ///
@@ -1044,6 +1197,24 @@ impl WriterPropertiesBuilder {
         self
     }
 
+    /// Sets default flag to allow columns to fall back from dictionary encoding for all
+    /// columns (defaults to `true` via [`DEFAULT_DICTIONARY_FALLBACK_ENABLED`]).
+    ///
+    /// Once the dictionary of a column chunk exceeds its dictionary page size limit, see
+    /// [`Self::set_dictionary_page_size_limit`], the remaining values of the column chunk
+    /// are written with the non-dictionary encoding. If disabled, writing such a column
+    /// instead returns an error, ensuring every data page written is dictionary encoded.
+    ///
+    /// Whether a column chunk fell back can be determined from its metadata with
+    /// [`ColumnChunkMetaData::dictionary_fallback`].
+    ///
+    /// [`ColumnChunkMetaData::dictionary_fallback`]: crate::file::metadata::ColumnChunkMetaData::dictionary_fallback
+    pub fn set_dictionary_fallback_enabled(mut self, value: bool) -> Self {
+        self.default_column_properties
+            .set_dictionary_fallback_enabled(value);
+        self
+    }
+
     /// Sets best effort maximum dictionary page size, in bytes (defaults to `1024 * 1024`
     /// via [`DEFAULT_DICTIONARY_PAGE_SIZE_LIMIT`]).
     ///
@@ -1113,6 +1284,37 @@ impl WriterPropertiesBuilder {
         self
     }
 
+    /// Sets how min/max values longer than the statistics truncation length are written
+    /// for all columns (defaults to [`Truncate`] via [`DEFAULT_STATISTICS_TRUNCATION`]).
+    ///
+    /// Applies to row group and data page header [`Statistics`], see
+    /// [`Self::set_statistics_truncate_length`]. As the column index must contain
+    /// min/max values for every page, columns using [`Exact`] write them untruncated,
+    /// while other columns truncate them to [`Self::set_column_index_truncate_length`].
+    ///
+    /// [`Statistics`]: crate::file::statistics::Statistics
+    /// [`Truncate`]: StatisticsTruncation::Truncate
+    /// [`Exact`]: StatisticsTruncation::Exact
+    pub fn set_statistics_truncation(mut self, value: StatisticsTruncation) -> Self {
+        self.default_column_properties
+            .set_statistics_truncation(value);
+        self
+    }
+
+    /// enable/disable writing the distinct count in [`Statistics`] for all columns
+    /// (defaults to `true` via [`DEFAULT_STATISTICS_DISTINCT_COUNT_ENABLED`]).
+    ///
+    /// The writer does not compute the distinct count itself, it is only written when
+    /// provided to [`write_batch_with_statistics`].
+    ///
+    /// [`Statistics`]: crate::file::statistics::Statistics
+    /// [`write_batch_with_statistics`]: crate::column::writer::ColumnWriterImpl::write_batch_with_statistics
+    pub fn set_statistics_distinct_count_enabled(mut self, value: bool) -> Self {
+        self.default_column_properties
+            .set_statistics_distinct_count_enabled(value);
+        self
+    }
+
     /// Sets if bloom filter should be written for all columns (defaults to `false`).
     ///
     /// # Notes
@@ -1160,6 +1362,22 @@ impl WriterPropertiesBuilder {
         self
     }
 
+    /// Sets whether bloom filters for all columns are sized from the number of distinct
+    /// values observed in each column chunk (defaults to `false` via
+    /// [`DEFAULT_BLOOM_FILTER_ADAPTIVE`]).
+    ///
+    /// See [`BloomFilterPropertiesBuilder::with_adaptive`] for more details.
+    ///
+    /// Implicitly enables bloom writing, as if [`set_bloom_filter_enabled`] had
+    /// been called.
+    ///
+    /// [`set_bloom_filter_enabled`]: Self::set_bloom_filter_enabled
+    pub fn set_bloom_filter_adaptive(mut self, value: bool) -> Self {
+        self.default_column_properties
+            .set_bloom_filter_adaptive(value);
+        self
+    }
+
     /// Deprecated alias for [`Self::set_bloom_filter_max_ndv`].
     #[deprecated(since = "59.0.0", note = "Use `set_bloom_filter_max_ndv` instead")]
     pub fn set_bloom_filter_ndv(self, value: u64) -> Self {
@@ -1192,6 +1410,16 @@ impl WriterPropertiesBuilder {
         self
     }
 
+    /// Sets flag to select the encoding of a specific `BYTE_ARRAY` column based on
+    /// the data written.
+    ///
+    /// Takes precedence over [`Self::set_adaptive_byte_array_encoding`].
+    pub fn set_column_adaptive_byte_array_encoding(mut self, col: ColumnPath, value: bool) -> Self {
+        self.get_mut_props(col)
+            .set_adaptive_byte_array_encoding(value);
+        self
+    }
+
     /// Sets compression codec for a specific column.
     ///
     /// Takes precedence over [`Self::set_compression`].
@@ -1208,6 +1436,15 @@ impl WriterPropertiesBuilder {
         self
     }
 
+    /// Sets flag to allow a specific column to fall back from dictionary encoding.
+    ///
+    /// Takes precedence over [`Self::set_dictionary_fallback_enabled`].
+    pub fn set_column_dictionary_fallback_enabled(mut self, col: ColumnPath, value: bool) -> Self {
+        self.get_mut_props(col)
+            .set_dictionary_fallback_enabled(value);
+        self
+    }
+
     /// Sets dictionary page size limit for a specific column.
     ///
     /// Takes precedence over [`Self::set_dictionary_page_size_limit`].
@@ -1248,8 +1485,66 @@ impl WriterPropertiesBuilder {
         self
     }
 
+    /// Sets the max length of min/max value fields in row group and data page header
+    /// [`Statistics`] for a specific column.
+    ///
+    /// Takes precedence over [`Self::set_statistics_truncate_length`].
+    ///
+    /// * If `Some`, must be greater than 0, otherwise will panic
+    /// * If `None`, there's no effective limit.
+    ///
+    /// [`Statistics`]: crate::file::statistics::Statistics
+    pub fn set_column_statistics_truncate_length(
+        mut self,
+        col: ColumnPath,
+        max_length: Option<usize>,
+    ) -> Self {
+        if let Some(value) = max_length {
+            assert!(
+                value > 0,
+                "Cannot have a 0 statistics truncate length. If you wish to disable min/max value truncation, set it to `None`."
+            );
+        }
+
+        self.get_mut_props(col)
+            .set_statistics_truncate_length(max_length);
+        self
+    }
+
+    /// Sets how min/max values longer than the statistics truncation length are written
+    /// for a specific column.
+    ///
+    /// Takes precedence over [`Self::set_statistics_truncation`].
+    pub fn set_column_statistics_truncation(
+        mut self,
+        col: ColumnPath,
+        value: StatisticsTruncation,
+    ) -> Self {
+        self.get_mut_props(col).set_statistics_truncation(value);
+        self
+    }
+
+    /// Sets whether to write the distinct count in [`Statistics`] for a specific column.
+    ///
+    /// Takes precedence over [`Self::set_statistics_distinct_count_enabled`].
+    ///
+    /// [`Statistics`]: crate::file::statistics::Statistics
+    pub fn set_column_statistics_distinct_count_enabled(
+        mut self,
+        col: ColumnPath,
+        value: bool,
+    ) -> Self {
+        self.get_mut_props(col)
+            .set_statistics_distinct_count_enabled(value);
+        self
+    }
+
     /// Sets whether a bloom filter should be written for a specific column.
     ///
+    /// `col` may also be the path of a group, such as a struct or list, in which case
+    /// this applies to all leaf columns within it, unless overridden for a nested path.
+    /// This is also the case for the other `set_column_bloom_filter_*` methods.
+    ///
     /// Takes precedence over [`Self::set_bloom_filter_enabled`].
     pub fn set_column_bloom_filter_enabled(mut self, col: ColumnPath, value: bool) -> Self {
         self.get_mut_props(col).set_bloom_filter_enabled(value);
@@ -1273,6 +1568,15 @@ impl WriterPropertiesBuilder {
         self
     }
 
+    /// Sets whether the bloom filter for a specific column is sized from the number of
+    /// distinct values observed in each column chunk.
+    ///
+    /// Takes precedence over [`Self::set_bloom_filter_adaptive`].
+    pub fn set_column_bloom_filter_adaptive(mut self, col: ColumnPath, value: bool) -> Self {
+        self.get_mut_props(col).set_bloom_filter_adaptive(value);
+        self
+    }
+
     /// Sets the Data Page v2 compression ratio threshold for a specific column.
     ///
     /// Takes precedence over [`Self::set_data_page_v2_compression_ratio_threshold`].
@@ -1343,6 +1647,7 @@ impl From<WriterProperties> for WriterPropertiesBuilder {
             default_column_properties: props.default_column_properties,
             column_properties: props.column_properties,
             sorting_columns: props.sorting_columns,
+            validate_sorting_columns: props.validate_sorting_columns,
             column_index_truncate_length: props.column_index_truncate_length,
             statistics_truncate_length: props.statistics_truncate_length,
             coerce_types: props.coerce_types,
@@ -1404,6 +1709,48 @@ impl Default for EnabledStatistics {
     }
 }
 
+/// Controls how min/max values longer than the statistics truncation length are
+/// written to [`Statistics`].
+///
+/// Long values, such as URLs, can make the parquet footer very large. Truncating
+/// them keeps the footer small, but the truncated values are only bounds of the
+/// actual min/max, which may be less effective for pruning by some readers.
+///
+/// See [`WriterPropertiesBuilder::set_statistics_truncate_length`]
+///
+/// [`Statistics`]: crate::file::statistics::Statistics
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum StatisticsTruncation {
+    /// Truncate min/max values longer than the truncation length.
+    ///
+    /// Truncated values are written with `is_min_value_exact` or `is_max_value_exact`
+    /// set to `false`.
+    Truncate,
+    /// Only write exact min/max values.
+    ///
+    /// If the min or max value is longer than the truncation length, neither is
+    /// written for the column chunk or page.
+    Exact,
+}
+
+impl FromStr for StatisticsTruncation {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "TRUNCATE" | "truncate" => Ok(StatisticsTruncation::Truncate),
+            "EXACT" | "exact" => Ok(StatisticsTruncation::Exact),
+            _ => Err(format!("Invalid statistics truncation arg: {s}")),
+        }
+    }
+}
+
+impl Default for StatisticsTruncation {
+    fn default() -> Self {
+        DEFAULT_STATISTICS_TRUNCATION
+    }
+}
+
 /// Controls the bloom filter to be computed by the writer.
 ///
 /// The bloom filter is initially sized for `ndv` distinct values at the given `fpp`, then
@@ -1411,6 +1758,9 @@ impl Default for EnabledStatistics {
 /// maintaining the target `fpp`. See [`Sbbf::fold_to_target_fpp`] for details on the
 /// folding algorithm.
 ///
+/// If [`adaptive`](Self::adaptive) is enabled, the filter is instead sized from the
+/// number of distinct values observed in each column chunk, up to `ndv`.
+///
 /// # Example
 ///
 /// ```rust
@@ -1439,6 +1789,7 @@ impl Default for EnabledStatistics {
 pub struct BloomFilterProperties {
     fpp: f64,
     ndv: u64,
+    adaptive: bool,
 }
 
 impl Default for BloomFilterProperties {
@@ -1446,6 +1797,7 @@ impl Default for BloomFilterProperties {
         BloomFilterProperties {
             fpp: DEFAULT_BLOOM_FILTER_FPP,
             ndv: DEFAULT_BLOOM_FILTER_NDV,
+            adaptive: DEFAULT_BLOOM_FILTER_ADAPTIVE,
         }
     }
 }
@@ -1495,6 +1847,14 @@ impl BloomFilterProperties {
     pub fn ndv(&self) -> u64 {
         self.ndv
     }
+
+    /// Whether the bloom filter is sized from the number of distinct values observed in
+    /// each column chunk. Defaults to [`DEFAULT_BLOOM_FILTER_ADAPTIVE`].
+    ///
+    /// See [`BloomFilterPropertiesBuilder::with_adaptive`] for more details.
+    pub fn adaptive(&self) -> bool {
+        self.adaptive
+    }
 }
 
 /// Builder for [`BloomFilterProperties`].
@@ -1505,6 +1865,7 @@ impl BloomFilterProperties {
 pub struct BloomFilterPropertiesBuilder {
     fpp: Option<f64>,
     ndv: Option<u64>,
+    adaptive: Option<bool>,
 }
 
 impl BloomFilterPropertiesBuilder {
@@ -1546,6 +1907,22 @@ impl BloomFilterPropertiesBuilder {
         self
     }
 
+    /// Sets whether the bloom filter is sized from the number of distinct values
+    /// observed in each column chunk, rather than for the maximum NDV.
+    ///
+    /// When enabled, the writer buffers the hashes of the distinct values of a column
+    /// chunk, using roughly 9 bytes per distinct value, and creates a filter sized for
+    /// them at the target `fpp` when the chunk is flushed. If more than the maximum NDV
+    /// distinct values are written, it falls back to a filter sized for the maximum NDV.
+    ///
+    /// This reduces memory usage while writing columns with few distinct values, at the
+    /// cost of hashing each value into the buffer. When unset, the default is `false`
+    /// (see [`DEFAULT_BLOOM_FILTER_ADAPTIVE`]).
+    pub fn with_adaptive(mut self, adaptive: bool) -> Self {
+        self.adaptive = Some(adaptive);
+        self
+    }
+
     /// Builds [`BloomFilterProperties`].
     ///
     /// Panics if the configured `fpp` is not in `(0.0, 1.0)` exclusive.
@@ -1560,7 +1937,8 @@ impl BloomFilterPropertiesBuilder {
         let fpp = self.fpp.unwrap_or(DEFAULT_BLOOM_FILTER_FPP);
         validate_bloom_filter_fpp(fpp).map_err(ParquetError::General)?;
         let ndv = self.ndv.unwrap_or(DEFAULT_BLOOM_FILTER_NDV);
-        Ok(BloomFilterProperties { fpp, ndv })
+        let adaptive = self.adaptive.unwrap_or(DEFAULT_BLOOM_FILTER_ADAPTIVE);
+        Ok(BloomFilterProperties { fpp, ndv, adaptive })
     }
 }
 
@@ -1587,10 +1965,16 @@ struct ColumnProperties {
     data_page_size_limit: Option<usize>,
     dictionary_page_size_limit: Option<usize>,
     dictionary_enabled: Option<bool>,
+    dictionary_fallback_enabled: Option<bool>,
+    adaptive_byte_array_encoding: Option<bool>,
     statistics_enabled: Option<EnabledStatistics>,
     write_page_header_statistics: Option<bool>,
-    /// bloom filter related properties
-    bloom_filter_properties: Option<BloomFilterProperties>,
+    /// `Some(None)` disables truncation for this column
+    statistics_truncate_length: Option<Option<usize>>,
+    statistics_truncation: Option<StatisticsTruncation>,
+    statistics_distinct_count_enabled: Option<bool>,
+    /// bloom filter related properties, `Some(None)` disables the bloom filter for this column
+    bloom_filter_properties: Option<Option<BloomFilterProperties>>,
     /// Whether the bloom filter NDV was explicitly set by the user
     bloom_filter_ndv_is_set: bool,
     data_page_v2_compression_ratio_threshold: Option<f64>,
@@ -1628,6 +2012,17 @@ impl ColumnProperties {
         self.dictionary_enabled = Some(enabled);
     }
 
+    /// Sets whether this column may fall back from dictionary encoding.
+    fn set_dictionary_fallback_enabled(&mut self, enabled: bool) {
+        self.dictionary_fallback_enabled = Some(enabled);
+    }
+
+    /// Sets whether the encoding of this `BYTE_ARRAY` column is selected based on the
+    /// data written.
+    fn set_adaptive_byte_array_encoding(&mut self, enabled: bool) {
+        self.adaptive_byte_array_encoding = Some(enabled);
+    }
+
     /// Sets dictionary page size limit for this column.
     fn set_dictionary_page_size_limit(&mut self, value: usize) {
         self.dictionary_page_size_limit = Some(value);
@@ -1643,17 +2038,41 @@ impl ColumnProperties {
         self.write_page_header_statistics = Some(enabled);
     }
 
-    /// If `value` is `true`, sets bloom filter properties to default values if not previously set,
-    /// otherwise it is a no-op.
-    /// If `value` is `false`, resets bloom filter properties to `None`.
+    /// Sets the max length of min/max values in statistics for this column.
+    fn set_statistics_truncate_length(&mut self, max_length: Option<usize>) {
+        self.statistics_truncate_length = Some(max_length);
+    }
+
+    /// Sets how long min/max values are written in statistics for this column.
+    fn set_statistics_truncation(&mut self, value: StatisticsTruncation) {
+        self.statistics_truncation = Some(value);
+    }
+
+    /// Sets whether to write the distinct count in statistics for this column.
+    fn set_statistics_distinct_count_enabled(&mut self, enabled: bool) {
+        self.statistics_distinct_count_enabled = Some(enabled);
+    }
+
+    /// If `value` is `true`, sets bloom filter properties to default values if not previously
+    /// enabled, otherwise it is a no-op.
+    /// If `value` is `false`, explicitly disables the bloom filter, so that it is not inherited
+    /// from a parent group or the defaults.
     fn set_bloom_filter_enabled(&mut self, value: bool) {
-        if value && self.bloom_filter_properties.is_none() {
-            self.bloom_filter_properties = Some(Default::default())
+        if value && !matches!(self.bloom_filter_properties, Some(Some(_))) {
+            self.bloom_filter_properties = Some(Some(Default::default()))
         } else if !value {
-            self.bloom_filter_properties = None
+            self.bloom_filter_properties = Some(None)
         }
     }
 
+    /// Returns the bloom filter properties, enabling the bloom filter with default values if
+    /// not previously enabled
+    fn bloom_filter_properties_mut(&mut self) -> &mut BloomFilterProperties {
+        self.bloom_filter_properties
+            .get_or_insert(None)
+            .get_or_insert_with(Default::default)
+    }
+
     /// Sets the false positive probability for bloom filter for this column, and implicitly enables
     /// bloom filter if not previously enabled.
     ///
@@ -1664,20 +2083,22 @@ impl ColumnProperties {
         if let Err(msg) = validate_bloom_filter_fpp(value) {
             panic!("{msg}");
         }
-        self.bloom_filter_properties
-            .get_or_insert_with(Default::default)
-            .fpp = value;
+        self.bloom_filter_properties_mut().fpp = value;
     }
 
     /// Sets the maximum expected number of distinct (unique) values for bloom filter for this
     /// column, and implicitly enables bloom filter if not previously enabled.
     fn set_bloom_filter_ndv(&mut self, value: u64) {
-        self.bloom_filter_properties
-            .get_or_insert_with(Default::default)
-            .ndv = value;
+        self.bloom_filter_properties_mut().ndv = value;
         self.bloom_filter_ndv_is_set = true;
     }
 
+    /// Sets whether the bloom filter for this column is sized from the observed number
+    /// of distinct values, and implicitly enables bloom filter if not previously enabled.
+    fn set_bloom_filter_adaptive(&mut self, value: bool) {
+        self.bloom_filter_properties_mut().adaptive = value;
+    }
+
     /// Sets the bloom filter properties for this column from a fully-built
     /// [`BloomFilterProperties`], implicitly enabling the bloom filter.
     ///
@@ -1685,7 +2106,7 @@ impl ColumnProperties {
     /// build-time row-group-size NDV fallback in
     /// [`WriterPropertiesBuilder::build`] will not override them.
     fn set_bloom_filter_properties(&mut self, value: BloomFilterProperties) {
-        self.bloom_filter_properties = Some(value);
+        self.bloom_filter_properties = Some(Some(value));
         self.bloom_filter_ndv_is_set = true;
     }
 
@@ -1718,6 +2139,18 @@ impl ColumnProperties {
         self.dictionary_enabled
     }
 
+    /// Returns `Some(true)` if this column may fall back from dictionary encoding. If
+    /// result is `None`, then no setting has been provided.
+    fn dictionary_fallback_enabled(&self) -> Option<bool> {
+        self.dictionary_fallback_enabled
+    }
+
+    /// Returns `Some(true)` if the encoding of this column is selected based on the
+    /// data written. If result is `None`, then no setting has been provided.
+    fn adaptive_byte_array_encoding(&self) -> Option<bool> {
+        self.adaptive_byte_array_encoding
+    }
+
     /// Returns optional dictionary page size limit for this column.
     fn dictionary_page_size_limit(&self) -> Option<usize> {
         self.dictionary_page_size_limit
@@ -1742,9 +2175,27 @@ impl ColumnProperties {
         self.write_page_header_statistics
     }
 
-    /// Returns the bloom filter properties, or `None` if not enabled
-    fn bloom_filter_properties(&self) -> Option<&BloomFilterProperties> {
-        self.bloom_filter_properties.as_ref()
+    /// Returns the optional max length of min/max values in statistics for this column,
+    /// where `Some(None)` means truncation is disabled.
+    fn statistics_truncate_length(&self) -> Option<Option<usize>> {
+        self.statistics_truncate_length
+    }
+
+    /// Returns optional [`StatisticsTruncation`] for this column.
+    fn statistics_truncation(&self) -> Option<StatisticsTruncation> {
+        self.statistics_truncation
+    }
+
+    /// Returns `Some(true)` if the distinct count is to be written in statistics for this
+    /// column.
+    fn statistics_distinct_count_enabled(&self) -> Option<bool> {
+        self.statistics_distinct_count_enabled
+    }
+
+    /// Returns `Some(None)` if the bloom filter is disabled, `Some(Some(_))` with its
+    /// properties if enabled, or `None` if not set
+    fn bloom_filter_properties(&self) -> Option<Option<&BloomFilterProperties>> {
+        self.bloom_filter_properties.as_ref().map(Option::as_ref)
     }
 
     /// Returns optional Data Page v2 compression ratio threshold for this column.
@@ -1756,7 +2207,7 @@ impl ColumnProperties {
     /// given `default_ndv` (typically derived from `max_row_group_row_count`).
     fn resolve_bloom_filter_ndv(&mut self, default_ndv: u64) {
         if !self.bloom_filter_ndv_is_set {
-            if let Some(ref mut bf) = self.bloom_filter_properties {
+            if let Some(Some(ref mut bf)) = self.bloom_filter_properties {
                 bf.ndv = default_ndv;
             }
         }
@@ -2056,7 +2507,11 @@ mod tests {
             );
             assert_eq!(
                 props.bloom_filter_properties(&ColumnPath::from("col")),
-                Some(&BloomFilterProperties { fpp: 0.1, ndv: 100 })
+                Some(&BloomFilterProperties {
+                    fpp: 0.1,
+                    ndv: 100,
+                    adaptive: false,
+                })
             );
         }
 
@@ -2094,6 +2549,7 @@ mod tests {
             Some(&BloomFilterProperties {
                 fpp: DEFAULT_BLOOM_FILTER_FPP,
                 ndv: DEFAULT_BLOOM_FILTER_NDV,
+                adaptive: false,
             })
         );
     }
@@ -2137,6 +2593,7 @@ mod tests {
             Some(&BloomFilterProperties {
                 fpp: DEFAULT_BLOOM_FILTER_FPP,
                 ndv: 100,
+                adaptive: false,
             })
         );
         assert_eq!(
@@ -2147,6 +2604,7 @@ mod tests {
             Some(&BloomFilterProperties {
                 fpp: 0.1,
                 ndv: DEFAULT_BLOOM_FILTER_NDV,
+                adaptive: false,
             })
         );
     }
@@ -2192,6 +2650,7 @@ mod tests {
             Some(&BloomFilterProperties {
                 fpp: DEFAULT_BLOOM_FILTER_FPP,
                 ndv: 100,
+                adaptive: false,
             })
         );
         assert_eq!(
@@ -2199,6 +2658,7 @@ mod tests {
             Some(&BloomFilterProperties {
                 fpp: DEFAULT_BLOOM_FILTER_FPP,
                 ndv: 200,
+                adaptive: false,
             })
         );
     }
@@ -2239,6 +2699,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_writer_properties_column_statistics_truncation() {
+        let col = ColumnPath::from("col");
+        let other = ColumnPath::from("other");
+        let props = WriterProperties::builder()
+            .set_statistics_truncate_length(Some(16))
+            .set_statistics_distinct_count_enabled(false)
+            .set_column_statistics_truncate_length(col.clone(), None)
+            .set_column_statistics_truncation(col.clone(), StatisticsTruncation::Exact)
+            .set_column_statistics_distinct_count_enabled(col.clone(), true)
+            .build();
+
+        assert_eq!(props.statistics_truncate_length(), Some(16));
+        assert_eq!(props.column_statistics_truncate_length(&col), None);
+        assert_eq!(props.column_statistics_truncate_length(&other), Some(16));
+        assert_eq!(
+            props.statistics_truncation(&col),
+            StatisticsTruncation::Exact
+        );
+        assert_eq!(
+            props.statistics_truncation(&other),
+            DEFAULT_STATISTICS_TRUNCATION
+        );
+        assert!(props.statistics_distinct_count_enabled(&col));
+        assert!(!props.statistics_distinct_count_enabled(&other));
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot have a 0 statistics truncate length")]
+    fn test_writer_properties_panic_on_zero_column_statistics_truncate_length() {
+        WriterProperties::builder()
+            .set_column_statistics_truncate_length(ColumnPath::from("col"), Some(0));
+    }
+
     #[test]
     fn test_reader_properties_default_settings() {
         let props = ReaderProperties::builder().build();
@@ -2306,6 +2800,17 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_statisticstruncation() {
+        let truncation = "TRUNCATE".parse::<StatisticsTruncation>().unwrap();
+        assert_eq!(truncation, StatisticsTruncation::Truncate);
+        let truncation = "exact".parse::<StatisticsTruncation>().unwrap();
+        assert_eq!(truncation, StatisticsTruncation::Exact);
+
+        let err = "omit".parse::<StatisticsTruncation>().unwrap_err();
+        assert_eq!(err, "Invalid statistics truncation arg: omit");
+    }
+
     #[test]
     fn test_cdc_options_equality() {
         let opts = CdcOptions::default();
@@ -2456,4 +2961,107 @@ mod tests {
             "explicit ndv must not be overridden by row-group-size fallback"
         );
     }
+
+    #[test]
+    fn test_bloom_filter_adaptive() {
+        let bf = BloomFilterProperties::builder().with_adaptive(true).build();
+        assert!(bf.adaptive());
+        assert!(!BloomFilterProperties::default().adaptive());
+
+        let col = ColumnPath::from("col");
+        let props = WriterProperties::builder()
+            .set_bloom_filter_adaptive(true)
+            .set_column_bloom_filter_adaptive(col.clone(), false)
+            .build();
+        assert!(!props.bloom_filter_properties(&col).unwrap().adaptive());
+        let other = props.bloom_filter_properties(&ColumnPath::from("other"));
+        assert!(other.unwrap().adaptive());
+    }
+
+    #[test]
+    fn test_adaptive_byte_array_encoding() {
+        let col = ColumnPath::from("col");
+        let props = WriterProperties::builder().build();
+        assert_eq!(
+            props.adaptive_byte_array_encoding(&col),
+            DEFAULT_ADAPTIVE_BYTE_ARRAY_ENCODING
+        );
+
+        let props = WriterProperties::builder()
+            .set_adaptive_byte_array_encoding(true)
+            .set_column_adaptive_byte_array_encoding(col.clone(), false)
+            .build();
+        assert!(!props.adaptive_byte_array_encoding(&col));
+        assert!(props.adaptive_byte_array_encoding(&ColumnPath::from("other")));
+    }
+
+    #[test]
+    fn test_dictionary_fallback_enabled() {
+        let col = ColumnPath::from("col");
+        let props = WriterProperties::builder().build();
+        assert_eq!(
+            props.dictionary_fallback_enabled(&col),
+            DEFAULT_DICTIONARY_FALLBACK_ENABLED
+        );
+
+        let props = WriterProperties::builder()
+            .set_dictionary_fallback_enabled(false)
+            .set_column_dictionary_fallback_enabled(col.clone(), true)
+            .build();
+        assert!(props.dictionary_fallback_enabled(&col));
+        assert!(!props.dictionary_fallback_enabled(&ColumnPath::from("other")));
+    }
+
+    #[test]
+    fn test_bloom_filter_nested_column_path() {
+        let props = WriterProperties::builder()
+            .set_column_bloom_filter_enabled(ColumnPath::from("a"), true)
+            .set_column_bloom_filter_fpp(ColumnPath::from(vec!["a".into(), "b".into()]), 0.01)
+            .set_column_bloom_filter_max_ndv(ColumnPath::from("list"), 10)
+            .build();
+
+        let path = |parts: &[&str]| ColumnPath::new(parts.iter().map(|p| p.to_string()).collect());
+        let fpp = |parts: &[&str]| props.bloom_filter_properties(&path(parts)).map(|p| p.fpp());
+
+        assert_eq!(fpp(&["a"]), Some(DEFAULT_BLOOM_FILTER_FPP));
+        assert_eq!(fpp(&["a", "c"]), Some(DEFAULT_BLOOM_FILTER_FPP));
+        assert_eq!(fpp(&["a", "b"]), Some(0.01));
+        assert_eq!(fpp(&["a", "b", "c"]), Some(0.01));
+        assert_eq!(fpp(&["b"]), None);
+        assert_eq!(fpp(&["b", "a"]), None);
+
+        let list = props.bloom_filter_properties(&path(&["list", "list", "element"]));
+        assert_eq!(list.unwrap().ndv(), 10);
+    }
+
+    #[test]
+    fn test_bloom_filter_nested_column_disabled() {
+        let path = |parts: &[&str]| ColumnPath::new(parts.iter().map(|p| p.to_string()).collect());
+        let props = WriterProperties::builder()
+            .set_column_bloom_filter_enabled(path(&["a"]), true)
+            .set_column_bloom_filter_enabled(path(&["a", "b"]), false)
+            .set_column_bloom_filter_enabled(path(&["a", "b", "c"]), true)
+            .build();
+
+        assert!(props.bloom_filter_properties(&path(&["a", "x"])).is_some());
+        assert!(props.bloom_filter_properties(&path(&["a", "b"])).is_none());
+        assert!(
+            props
+                .bloom_filter_properties(&path(&["a", "b", "d"]))
+                .is_none()
+        );
+        assert!(
+            props
+                .bloom_filter_properties(&path(&["a", "b", "c"]))
+                .is_some()
+        );
+
+        // a column disabled when enabled by default
+        let props = WriterProperties::builder()
+            .set_bloom_filter_enabled(true)
+            .set_column_bloom_filter_enabled(path(&["a"]), false)
+            .build();
+        assert!(props.bloom_filter_properties(&path(&["a", "b"])).is_none());
+        assert!(props.bloom_filter_properties(&path(&["b"])).is_some());
+    }
 }
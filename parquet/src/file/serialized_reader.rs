@@ -882,39 +882,12 @@ impl SerializedPageReaderContext {
     }
 }
 
-impl<R: ChunkReader> Iterator for SerializedPageReader<R> {
-    type Item = Result<Page>;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        self.get_next_page().transpose()
-    }
-}
-
-fn verify_page_header_len(header_len: usize, remaining_bytes: u64) -> Result<()> {
-    if header_len as u64 > remaining_bytes {
-        return Err(eof_err!("Invalid page header"));
-    }
-    Ok(())
-}
-
-fn verify_page_size(
-    compressed_size: i32,
-    uncompressed_size: i32,
-    remaining_bytes: u64,
-) -> Result<()> {
-    // The page's compressed size should not exceed the remaining bytes that are
-    // available to read. The page's uncompressed size is the expected size
-    // after decompression, which can never be negative.
-    if compressed_size < 0 || compressed_size as u64 > remaining_bytes || uncompressed_size < 0 {
-        return Err(eof_err!("Invalid page header"));
-    }
-    Ok(())
-}
-
-impl<R: ChunkReader> PageReader for SerializedPageReader<R> {
-    fn get_next_page(&mut self) -> Result<Option<Page>> {
+impl<R: ChunkReader> SerializedPageReader<R> {
+    /// Reads the next page, returning its header, its decrypted but potentially
+    /// compressed data, and its index if it is a data page
+    fn read_next_page(&mut self) -> Result<Option<(PageHeader, Bytes, Option<usize>)>> {
         loop {
-            let page = match &mut self.state {
+            return match &mut self.state {
                 SerializedPageReaderState::Values {
                     offset,
                     remaining_bytes: remaining,
@@ -961,18 +934,18 @@ impl<R: ChunkReader> PageReader for SerializedPageReader<R> {
                         self.context
                             .decrypt_page_data(buffer, *page_index, *require_dictionary)?;
 
-                    let page = decode_page(
-                        header,
-                        buffer,
-                        self.physical_type,
-                        self.decompressor.as_mut(),
-                    )?;
-                    if page.is_data_page() {
-                        *page_index += 1;
-                    } else if page.is_dictionary_page() {
-                        *require_dictionary = false;
-                    }
-                    page
+                    let data_page_index = match header.r#type {
+                        PageType::DATA_PAGE | PageType::DATA_PAGE_V2 => {
+                            *page_index += 1;
+                            Some(*page_index - 1)
+                        }
+                        PageType::DICTIONARY_PAGE => {
+                            *require_dictionary = false;
+                            None
+                        }
+                        _ => None,
+                    };
+                    Ok(Some((header, buffer, data_page_index)))
                 }
                 SerializedPageReaderState::Pages {
                     page_locations,
@@ -1002,21 +975,215 @@ impl<R: ChunkReader> PageReader for SerializedPageReader<R> {
                         self.context
                             .decrypt_page_data(bytes, *page_index, is_dictionary_page)?;
 
-                    if !is_dictionary_page {
-                        *page_index += 1;
-                    }
-                    decode_page(
-                        header,
-                        bytes,
-                        self.physical_type,
-                        self.decompressor.as_mut(),
-                    )?
+                    let data_page_index = match is_dictionary_page {
+                        true => None,
+                        false => {
+                            *page_index += 1;
+                            Some(*page_index - 1)
+                        }
+                    };
+                    Ok(Some((header, bytes, data_page_index)))
                 }
             };
+        }
+    }
+
+    /// Returns the index of the next data page in the column chunk
+    fn next_data_page_index(&self) -> usize {
+        match &self.state {
+            SerializedPageReaderState::Values { page_index, .. } => *page_index,
+            SerializedPageReaderState::Pages { page_index, .. } => *page_index,
+        }
+    }
 
-            return Ok(Some(page));
+    /// Reads the next page along with the fields of its header, returning `None` if
+    /// there are no pages left
+    ///
+    /// Unlike [`PageReader::get_next_page`], the data of the page is only decompressed
+    /// if `decompress` is true, and so can be written as is to another file, for
+    /// example with [`CompressedPage::new`] and [`RawPage::uncompressed_size`].
+    /// Encrypted pages are always decrypted.
+    ///
+    /// [`CompressedPage::new`]: crate::column::page::CompressedPage::new
+    pub fn get_next_raw_page(&mut self, decompress: bool) -> Result<Option<RawPage>> {
+        let Some((header, buffer, page_index)) = self.read_next_page()? else {
+            return Ok(None);
+        };
+        let compressed_size = usize::try_from(header.compressed_page_size)?;
+        let uncompressed_size = usize::try_from(header.uncompressed_page_size)?;
+        let crc = header.crc.map(|crc| crc as u32);
+        let decompressor = match decompress {
+            true => self.decompressor.as_mut(),
+            false => None,
+        };
+        let page = decode_page(header, buffer, self.physical_type, decompressor)?;
+        Ok(Some(RawPage {
+            page,
+            page_index,
+            compressed_size,
+            uncompressed_size,
+            crc,
+        }))
+    }
+
+    /// Returns an iterator over the remaining pages of this reader, see
+    /// [`Self::get_next_raw_page`]
+    pub fn into_raw_pages(self, decompress: bool) -> RawPageIterator<R> {
+        RawPageIterator {
+            reader: self,
+            decompress,
+            selection: None,
         }
     }
+}
+
+/// A page of a column chunk along with the fields of its header, as read by
+/// [`SerializedPageReader::get_next_raw_page`]
+///
+/// Page statistics are only read if enabled with
+/// [`ReaderPropertiesBuilder::set_read_page_statistics`]
+///
+/// [`ReaderPropertiesBuilder::set_read_page_statistics`]: crate::file::properties::ReaderPropertiesBuilder::set_read_page_statistics
+#[derive(Clone, Debug)]
+pub struct RawPage {
+    page: Page,
+    page_index: Option<usize>,
+    compressed_size: usize,
+    uncompressed_size: usize,
+    crc: Option<u32>,
+}
+
+impl RawPage {
+    /// Returns the page, whose buffer is compressed unless it was read with decompression
+    pub fn page(&self) -> &Page {
+        &self.page
+    }
+
+    /// Returns the page, whose buffer is compressed unless it was read with decompression
+    pub fn into_page(self) -> Page {
+        self.page
+    }
+
+    /// Returns the index of this page among the data pages of the column chunk, which is
+    /// also its index in the [`OffsetIndexMetaData`], or `None` for a dictionary page
+    pub fn page_index(&self) -> Option<usize> {
+        self.page_index
+    }
+
+    /// Returns the size in bytes of the page data in the file, not including its header
+    pub fn compressed_size(&self) -> usize {
+        self.compressed_size
+    }
+
+    /// Returns the size in bytes of the page data once decompressed
+    pub fn uncompressed_size(&self) -> usize {
+        self.uncompressed_size
+    }
+
+    /// Returns the CRC32 checksum of the page data if written
+    pub fn crc(&self) -> Option<u32> {
+        self.crc
+    }
+}
+
+/// An iterator over the [`RawPage`]s of a column chunk, created by
+/// [`SerializedPageReader::into_raw_pages`]
+pub struct RawPageIterator<R: ChunkReader> {
+    reader: SerializedPageReader<R>,
+    decompress: bool,
+    /// The remaining indices of the data pages to read, in ascending order
+    selection: Option<VecDeque<usize>>,
+}
+
+impl<R: ChunkReader> RawPageIterator<R> {
+    /// Only read the data pages with the given indices, along with the dictionary page
+    ///
+    /// Other data pages are skipped without being read if the reader was created with
+    /// the page locations of the [`OffsetIndexMetaData`], otherwise only their headers are
+    /// read.
+    pub fn with_page_indices(self, indices: impl IntoIterator<Item = usize>) -> Self {
+        let mut indices: Vec<_> = indices.into_iter().collect();
+        indices.sort_unstable();
+        indices.dedup();
+        Self {
+            selection: Some(indices.into()),
+            ..self
+        }
+    }
+
+    fn next_page(&mut self) -> Result<Option<RawPage>> {
+        if let Some(selection) = &mut self.selection {
+            loop {
+                match self.reader.peek_next_page()? {
+                    None => return Ok(None),
+                    Some(metadata) if metadata.is_dict => break,
+                    Some(_) => {}
+                }
+                let next = self.reader.next_data_page_index();
+                while selection.front().is_some_and(|idx| *idx < next) {
+                    selection.pop_front();
+                }
+                match selection.front() {
+                    None => return Ok(None),
+                    Some(idx) if *idx == next => break,
+                    Some(_) => self.reader.skip_next_page()?,
+                }
+            }
+        }
+        self.reader.get_next_raw_page(self.decompress)
+    }
+}
+
+impl<R: ChunkReader> Iterator for RawPageIterator<R> {
+    type Item = Result<RawPage>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_page().transpose()
+    }
+}
+
+impl<R: ChunkReader> Iterator for SerializedPageReader<R> {
+    type Item = Result<Page>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.get_next_page().transpose()
+    }
+}
+
+fn verify_page_header_len(header_len: usize, remaining_bytes: u64) -> Result<()> {
+    if header_len as u64 > remaining_bytes {
+        return Err(eof_err!("Invalid page header"));
+    }
+    Ok(())
+}
+
+fn verify_page_size(
+    compressed_size: i32,
+    uncompressed_size: i32,
+    remaining_bytes: u64,
+) -> Result<()> {
+    // The page's compressed size should not exceed the remaining bytes that are
+    // available to read. The page's uncompressed size is the expected size
+    // after decompression, which can never be negative.
+    if compressed_size < 0 || compressed_size as u64 > remaining_bytes || uncompressed_size < 0 {
+        return Err(eof_err!("Invalid page header"));
+    }
+    Ok(())
+}
+
+impl<R: ChunkReader> PageReader for SerializedPageReader<R> {
+    fn get_next_page(&mut self) -> Result<Option<Page>> {
+        let Some((header, buffer, _)) = self.read_next_page()? else {
+            return Ok(None);
+        };
+        let page = decode_page(
+            header,
+            buffer,
+            self.physical_type,
+            self.decompressor.as_mut(),
+        )?;
+        Ok(Some(page))
+    }
 
     fn peek_next_page(&mut self) -> Result<Option<PageMetadata>> {
         match &mut self.state {
@@ -2590,6 +2757,68 @@ mod tests {
         assert_eq!(vec.len(), 352);
     }
 
+    #[test]
+    fn test_raw_pages() {
+        let schema = Arc::new(parse_message_type("message test { REQUIRED INT32 a; }").unwrap());
+        let props = WriterProperties::builder()
+            .set_compression(basic::Compression::SNAPPY)
+            .set_data_page_row_count_limit(100)
+            .set_write_batch_size(10)
+            .build();
+        let mut out = Vec::new();
+        let mut writer = SerializedFileWriter::new(&mut out, schema, Arc::new(props)).unwrap();
+        let mut r = writer.next_row_group().unwrap();
+        let mut c = r.next_column().unwrap().unwrap();
+        let values: Vec<i32> = (0..1000).map(|i| i % 10).collect();
+        c.typed::<Int32Type>()
+            .write_batch(&values, None, None)
+            .unwrap();
+        c.close().unwrap();
+        r.close().unwrap();
+        writer.close().unwrap();
+        let out = Bytes::from(out);
+
+        for options in [
+            ReadOptionsBuilder::new().with_page_index().build(),
+            ReadOptionsBuilder::new().build(),
+        ] {
+            let reader = SerializedFileReader::new_with_options(out.clone(), options).unwrap();
+            let pages: Vec<_> = get_serialized_page_reader(&reader, 0, 0)
+                .unwrap()
+                .collect::<Result<_>>()
+                .unwrap();
+            assert_eq!(pages.len(), 11);
+
+            let raw_pages: Vec<_> = get_serialized_page_reader(&reader, 0, 0)
+                .unwrap()
+                .into_raw_pages(false)
+                .collect::<Result<_>>()
+                .unwrap();
+            let indices: Vec<_> = raw_pages.iter().map(|p| p.page_index()).collect();
+            let expected: Vec<_> = std::iter::once(None).chain((0..10).map(Some)).collect();
+            assert_eq!(indices, expected);
+            for (raw, page) in raw_pages.iter().zip(&pages) {
+                assert_eq!(raw.page().page_type(), page.page_type());
+                assert_eq!(raw.page().buffer().len(), raw.compressed_size());
+                assert_eq!(page.buffer().len(), raw.uncompressed_size());
+                assert_ne!(raw.page().buffer(), page.buffer());
+            }
+
+            let raw_pages: Vec<_> = get_serialized_page_reader(&reader, 0, 0)
+                .unwrap()
+                .into_raw_pages(true)
+                .with_page_indices([7, 2, 2, 20])
+                .collect::<Result<_>>()
+                .unwrap();
+            let indices: Vec<_> = raw_pages.iter().map(|p| p.page_index()).collect();
+            assert_eq!(indices, vec![None, Some(2), Some(7)]);
+            for (raw, idx) in raw_pages.iter().zip([0, 3, 8]) {
+                assert_eq!(raw.page().buffer(), pages[idx].buffer());
+                assert_eq!(raw.crc(), None);
+            }
+        }
+    }
+
     #[test]
     fn test_fixed_length_index() {
         let message_type = "
@@ -0,0 +1,901 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Deep verification of the structure of a parquet file
+//!
+//! [`validate_file`] reads the whole file and checks that it is internally
+//! consistent, reporting every problem found as a [`ValidationIssue`] rather
+//! than failing on the first one:
+//!
+//! * the footer can be parsed, and the row counts and column chunk byte ranges
+//!   of the metadata are plausible
+//! * the page headers of every column chunk can be parsed, the pages exactly
+//!   fill the column chunk and contain as many values as the column chunk metadata
+//! * the CRC checksums of the pages are correct, if the `crc` feature is enabled
+//! * the offset index and column index agree with the pages actually written
+//! * optionally, every column chunk can be decoded, see [`ValidationOptions::with_decode`]
+//!
+//! Column chunks encrypted with a column key are not checked.
+//!
+//! ```
+//! # use std::sync::Arc;
+//! # use bytes::Bytes;
+//! # use parquet::data_type::Int32Type;
+//! # use parquet::file::validation::{validate_file, ValidationOptions};
+//! # use parquet::file::writer::SerializedFileWriter;
+//! # use parquet::schema::parser::parse_message_type;
+//! let schema = Arc::new(parse_message_type("message schema { REQUIRED INT32 a; }").unwrap());
+//! let mut writer = SerializedFileWriter::new(vec![], schema, Default::default()).unwrap();
+//! let mut row_group = writer.next_row_group().unwrap();
+//! let mut column = row_group.next_column().unwrap().unwrap();
+//! column.typed::<Int32Type>().write_batch(&[1, 2, 3], None, None).unwrap();
+//! column.close().unwrap();
+//! row_group.close().unwrap();
+//! let data = Bytes::from(writer.into_inner().unwrap());
+//!
+//! let options = ValidationOptions::new().with_decode(true);
+//! let report = validate_file(data, &options).unwrap();
+//! assert!(report.is_valid(), "{report}");
+//! // a dictionary page and a data page
+//! assert_eq!(report.pages_checked, 2);
+//! ```
+
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+
+use bytes::Bytes;
+
+use crate::basic::PageType;
+use crate::column::reader::{ColumnReader, ColumnReaderImpl, get_column_reader};
+use crate::data_type::DataType;
+use crate::errors::Result;
+use crate::file::metadata::thrift::PageHeader;
+use crate::file::metadata::{
+    ColumnChunkMetaData, PageIndexPolicy, ParquetMetaData, ParquetMetaDataReader,
+};
+use crate::file::page_index::column_index::ColumnIndexMetaData;
+use crate::file::page_index::offset_index::OffsetIndexMetaData;
+use crate::file::reader::ChunkReader;
+use crate::file::serialized_reader::SerializedPageReader;
+use crate::file::{FOOTER_SIZE, PARQUET_MAGIC};
+use crate::parquet_thrift::{ReadThrift, ThriftSliceInputProtocol};
+
+/// The number of records decoded at a time by the decode dry run
+const DECODE_BATCH_SIZE: usize = 1024;
+
+/// Options for [`validate_file`]
+#[derive(Debug, Clone, Default)]
+pub struct ValidationOptions {
+    decode: bool,
+}
+
+impl ValidationOptions {
+    /// Create a new [`ValidationOptions`] with the default settings
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Also decode every column chunk, checking that the pages can be
+    /// decompressed and decoded, and contain the number of rows of the row group
+    /// (defaults to `false`)
+    ///
+    /// Column chunks with page or checksum issues are not decoded.
+    pub fn with_decode(mut self, decode: bool) -> Self {
+        self.decode = decode;
+        self
+    }
+}
+
+/// The kind of a [`ValidationIssue`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ValidationIssueKind {
+    /// The footer or the metadata is invalid
+    Metadata,
+    /// A page header is invalid, or the pages do not match the column chunk metadata
+    Page,
+    /// The CRC checksum of a page does not match its data
+    Checksum,
+    /// The offset index does not match the pages of the column chunk
+    OffsetIndex,
+    /// The column index does not match the pages of the column chunk
+    ColumnIndex,
+    /// A column chunk could not be decoded
+    Decode,
+}
+
+/// A problem found by [`validate_file`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationIssue {
+    /// The kind of the issue
+    pub kind: ValidationIssueKind,
+    /// The row group of the issue, if it is specific to a row group
+    pub row_group: Option<usize>,
+    /// The leaf column of the issue, if it is specific to a column chunk
+    pub column: Option<usize>,
+    /// The index of the page within the column chunk, including any dictionary
+    /// page, if the issue is specific to a page
+    pub page: Option<usize>,
+    /// A description of the issue
+    pub message: String,
+}
+
+impl Display for ValidationIssue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.kind)?;
+        if let Some(row_group) = self.row_group {
+            write!(f, " row group {row_group}")?;
+        }
+        if let Some(column) = self.column {
+            write!(f, " column {column}")?;
+        }
+        if let Some(page) = self.page {
+            write!(f, " page {page}")?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+/// The result of [`validate_file`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidationReport {
+    /// The problems found, in file order
+    pub issues: Vec<ValidationIssue>,
+    /// The number of pages whose header was checked, including dictionary pages
+    pub pages_checked: usize,
+    /// The number of pages whose CRC checksum was verified
+    pub checksums_verified: usize,
+    /// The number of column chunks decoded by the decode dry run
+    pub column_chunks_decoded: usize,
+    /// The number of column chunks that were not checked because they are encrypted
+    pub column_chunks_skipped: usize,
+}
+
+impl ValidationReport {
+    /// Returns true if no issues were found
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    fn push(
+        &mut self,
+        kind: ValidationIssueKind,
+        location: (Option<usize>, Option<usize>, Option<usize>),
+        message: String,
+    ) {
+        let (row_group, column, page) = location;
+        self.issues.push(ValidationIssue {
+            kind,
+            row_group,
+            column,
+            page,
+            message,
+        });
+    }
+}
+
+impl Display for ValidationReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{} issues, {} pages checked, {} checksums verified, {} column chunks decoded, {} column chunks skipped",
+            self.issues.len(),
+            self.pages_checked,
+            self.checksums_verified,
+            self.column_chunks_decoded,
+            self.column_chunks_skipped
+        )?;
+        for issue in &self.issues {
+            writeln!(f, "{issue}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Verifies the structure of the parquet file in `reader`, see the
+/// [module docs](self) for the checks performed
+///
+/// A file that cannot be validated, for example because its footer is corrupt,
+/// is reported as a [`ValidationIssue`]. Errors are only returned if `reader`
+/// fails to read a valid byte range.
+pub fn validate_file<R: ChunkReader + 'static>(
+    reader: R,
+    options: &ValidationOptions,
+) -> Result<ValidationReport> {
+    let mut report = ValidationReport::default();
+
+    let mut metadata_reader = ParquetMetaDataReader::new();
+    if let Err(e) = metadata_reader.try_parse(&reader) {
+        report.push(
+            ValidationIssueKind::Metadata,
+            (None, None, None),
+            format!("Failed to read footer: {e}"),
+        );
+        return Ok(report);
+    }
+    let metadata_size = metadata_reader.metadata_size().unwrap_or(FOOTER_SIZE) as u64;
+    let metadata = metadata_reader.finish()?;
+    let metadata = read_page_indexes(&reader, metadata, &mut report)?;
+
+    let data_end = reader.len().saturating_sub(metadata_size);
+    let reader = Arc::new(reader);
+    validate_metadata(&reader, data_end, &metadata, options, &mut report)?;
+    Ok(report)
+}
+
+/// Reads the offset and column indexes of the column chunks of `metadata`, if
+/// all column chunks have them
+fn read_page_indexes<R: ChunkReader>(
+    reader: &R,
+    metadata: ParquetMetaData,
+    report: &mut ValidationReport,
+) -> Result<ParquetMetaData> {
+    let columns = || metadata.row_groups().iter().flat_map(|rg| rg.columns());
+    let policy = |has_index: fn(&ColumnChunkMetaData) -> bool| match columns().all(has_index) {
+        true if columns().next().is_some() => PageIndexPolicy::Required,
+        _ => PageIndexPolicy::Skip,
+    };
+    let offset_index_policy = policy(|c| c.offset_index_offset().is_some());
+    let column_index_policy = policy(|c| c.column_index_offset().is_some());
+    if offset_index_policy == PageIndexPolicy::Skip && column_index_policy == PageIndexPolicy::Skip
+    {
+        return Ok(metadata);
+    }
+
+    let mut index_reader = ParquetMetaDataReader::new_with_metadata(metadata.clone())
+        .with_offset_index_policy(offset_index_policy)
+        .with_column_index_policy(column_index_policy);
+    match index_reader
+        .read_page_indexes(reader)
+        .and_then(|_| index_reader.finish())
+    {
+        Ok(metadata) => Ok(metadata),
+        Err(e) => {
+            report.push(
+                ValidationIssueKind::Metadata,
+                (None, None, None),
+                format!("Failed to read page index: {e}"),
+            );
+            Ok(metadata)
+        }
+    }
+}
+
+/// Validates the parquet file in `reader`, whose data ends at `data_end`,
+/// against its `metadata`
+fn validate_metadata<R: ChunkReader + 'static>(
+    reader: &Arc<R>,
+    data_end: u64,
+    metadata: &ParquetMetaData,
+    options: &ValidationOptions,
+    report: &mut ValidationReport,
+) -> Result<()> {
+    let file_metadata = metadata.file_metadata();
+    let row_count: i64 = metadata.row_groups().iter().map(|rg| rg.num_rows()).sum();
+    if row_count != file_metadata.num_rows() {
+        report.push(
+            ValidationIssueKind::Metadata,
+            (None, None, None),
+            format!(
+                "File has {} rows, but its row groups have {row_count} rows",
+                file_metadata.num_rows()
+            ),
+        );
+    }
+
+    let data_start = PARQUET_MAGIC.len() as u64;
+    for (rg_idx, row_group) in metadata.row_groups().iter().enumerate() {
+        if row_group.num_rows() < 0 {
+            report.push(
+                ValidationIssueKind::Metadata,
+                (Some(rg_idx), None, None),
+                format!("Row group has {} rows", row_group.num_rows()),
+            );
+        }
+
+        for (col_idx, column) in row_group.columns().iter().enumerate() {
+            let location = (Some(rg_idx), Some(col_idx), None);
+
+            #[cfg(feature = "encryption")]
+            if column.crypto_metadata().is_some() {
+                report.column_chunks_skipped += 1;
+                continue;
+            }
+
+            let start = column
+                .dictionary_page_offset()
+                .unwrap_or(column.data_page_offset());
+            let range = u64::try_from(start)
+                .ok()
+                .zip(u64::try_from(column.compressed_size()).ok())
+                .map(|(start, len)| start..start.saturating_add(len))
+                .filter(|range| range.start >= data_start && range.end <= data_end);
+            let Some(range) = range else {
+                report.push(
+                    ValidationIssueKind::Metadata,
+                    location,
+                    format!(
+                        "Column chunk of {} bytes at offset {start} is outside of the data of the file",
+                        column.compressed_size()
+                    ),
+                );
+                continue;
+            };
+
+            let data = reader.get_bytes(range.start, (range.end - range.start).try_into()?)?;
+            let num_issues = report.issues.len();
+            let pages = scan_pages(
+                data,
+                range.start,
+                column,
+                row_group.num_rows(),
+                location,
+                report,
+            );
+
+            let offset_index = metadata
+                .offset_index()
+                .and_then(|index| index.get(rg_idx)?.get(col_idx));
+            if let Some(offset_index) = offset_index {
+                validate_offset_index(offset_index, &pages, row_group.num_rows(), location, report);
+            }
+            let column_index = metadata
+                .column_index()
+                .and_then(|index| index.get(rg_idx)?.get(col_idx));
+            if let Some(column_index) = column_index {
+                validate_column_index(column_index, &pages, location, report);
+            }
+
+            let page_issues = report.issues[num_issues..].iter().any(|issue| {
+                matches!(
+                    issue.kind,
+                    ValidationIssueKind::Page | ValidationIssueKind::Checksum
+                )
+            });
+            if options.decode && !page_issues {
+                let descr = file_metadata.schema_descr().column(col_idx);
+                let result = SerializedPageReader::new(
+                    Arc::clone(reader),
+                    column,
+                    row_group.num_rows().max(0) as usize,
+                    None,
+                )
+                .and_then(|page_reader| {
+                    decode_column(get_column_reader(descr, Box::new(page_reader)))
+                });
+                match result {
+                    Ok(num_rows) if num_rows as i64 != row_group.num_rows() => report.push(
+                        ValidationIssueKind::Decode,
+                        location,
+                        format!(
+                            "Decoded {num_rows} rows, but the row group has {} rows",
+                            row_group.num_rows()
+                        ),
+                    ),
+                    Ok(_) => {}
+                    Err(e) => report.push(
+                        ValidationIssueKind::Decode,
+                        location,
+                        format!("Failed to decode column chunk: {e}"),
+                    ),
+                }
+                report.column_chunks_decoded += 1;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// A data page found by [`scan_pages`]
+#[derive(Debug)]
+struct DataPage {
+    /// The index of the page within the column chunk, including any dictionary page
+    page_idx: usize,
+    /// The offset of the page header in the file
+    offset: u64,
+    /// The size of the page, including its header
+    size: usize,
+    /// The number of rows of the page, if known from its header
+    num_rows: Option<i64>,
+    /// The number of nulls of the page, if known from its header
+    num_nulls: Option<i64>,
+}
+
+/// Checks the pages of the column chunk in `data`, located at `offset` in the
+/// file, against the column chunk metadata, returning the data pages found
+fn scan_pages(
+    data: Bytes,
+    offset: u64,
+    column: &ColumnChunkMetaData,
+    num_rows: i64,
+    location: (Option<usize>, Option<usize>, Option<usize>),
+    report: &mut ValidationReport,
+) -> Vec<DataPage> {
+    let (rg_idx, col_idx, _) = location;
+    let flat = column.column_descr().max_rep_level() == 0;
+    let mut pages = vec![];
+    let mut num_values = 0;
+    let mut pos = 0;
+    let mut page_idx = 0;
+
+    while pos < data.len() {
+        let page_location = (rg_idx, col_idx, Some(page_idx));
+        let mut prot = ThriftSliceInputProtocol::new(&data[pos..]);
+        let header = match PageHeader::read_thrift(&mut prot) {
+            Ok(header) => header,
+            Err(e) => {
+                report.push(
+                    ValidationIssueKind::Page,
+                    page_location,
+                    format!("Failed to read page header: {e}"),
+                );
+                return pages;
+            }
+        };
+        let header_len = data.len() - pos - prot.as_slice().len();
+        let data_start = pos + header_len;
+        let data_end = usize::try_from(header.compressed_page_size)
+            .ok()
+            .and_then(|size| data_start.checked_add(size))
+            .filter(|end| *end <= data.len());
+        let Some(data_end) = data_end else {
+            report.push(
+                ValidationIssueKind::Page,
+                page_location,
+                format!(
+                    "Page of {} bytes does not fit in the column chunk",
+                    header.compressed_page_size
+                ),
+            );
+            return pages;
+        };
+        report.pages_checked += 1;
+
+        #[cfg(feature = "crc")]
+        if let Some(expected_crc) = header.crc {
+            let crc = crc32fast::hash(&data[data_start..data_end]);
+            if crc != expected_crc as u32 {
+                report.push(
+                    ValidationIssueKind::Checksum,
+                    page_location,
+                    format!(
+                        "Page CRC checksum mismatch, expected {:#010x} found {crc:#010x}",
+                        expected_crc as u32
+                    ),
+                );
+            }
+            report.checksums_verified += 1;
+        }
+
+        match header.r#type {
+            PageType::DICTIONARY_PAGE if page_idx != 0 => report.push(
+                ValidationIssueKind::Page,
+                page_location,
+                "Dictionary page is not the first page of the column chunk".to_string(),
+            ),
+            PageType::DATA_PAGE | PageType::DATA_PAGE_V2 => {
+                let (values, rows, nulls) =
+                    match (&header.data_page_header, &header.data_page_header_v2) {
+                        (Some(v1), _) => (v1.num_values, flat.then_some(v1.num_values), None),
+                        (_, Some(v2)) => (v2.num_values, Some(v2.num_rows), Some(v2.num_nulls)),
+                        _ => {
+                            report.push(
+                                ValidationIssueKind::Page,
+                                page_location,
+                                format!("{:?} without a data page header", header.r#type),
+                            );
+                            (0, None, None)
+                        }
+                    };
+                num_values += i64::from(values);
+                pages.push(DataPage {
+                    page_idx,
+                    offset: offset + pos as u64,
+                    size: data_end - pos,
+                    num_rows: rows.map(i64::from),
+                    num_nulls: nulls.map(i64::from),
+                });
+            }
+            _ => {}
+        }
+
+        pos = data_end;
+        page_idx += 1;
+    }
+
+    if num_values != column.num_values() {
+        report.push(
+            ValidationIssueKind::Page,
+            location,
+            format!(
+                "Data pages contain {num_values} values, but the column chunk has {} values",
+                column.num_values()
+            ),
+        );
+    }
+    let page_rows: Option<i64> = pages.iter().map(|page| page.num_rows).sum();
+    if let Some(page_rows) = page_rows.filter(|rows| *rows != num_rows) {
+        report.push(
+            ValidationIssueKind::Page,
+            location,
+            format!("Data pages contain {page_rows} rows, but the row group has {num_rows} rows"),
+        );
+    }
+    pages
+}
+
+/// Checks that `offset_index` has a location for each of the data `pages`
+fn validate_offset_index(
+    offset_index: &OffsetIndexMetaData,
+    pages: &[DataPage],
+    num_rows: i64,
+    location: (Option<usize>, Option<usize>, Option<usize>),
+    report: &mut ValidationReport,
+) {
+    let (rg_idx, col_idx, _) = location;
+    let page_locations = offset_index.page_locations();
+    if page_locations.len() != pages.len() {
+        report.push(
+            ValidationIssueKind::OffsetIndex,
+            location,
+            format!(
+                "Offset index has {} pages, but the column chunk has {} data pages",
+                page_locations.len(),
+                pages.len()
+            ),
+        );
+    }
+
+    let mut first_row_index = Some(0);
+    for (page, page_location) in pages.iter().zip(page_locations) {
+        let location = (rg_idx, col_idx, Some(page.page_idx));
+        if page_location.offset != page.offset as i64
+            || page_location.compressed_page_size as i64 != page.size as i64
+        {
+            report.push(
+                ValidationIssueKind::OffsetIndex,
+                location,
+                format!(
+                    "Offset index locates page at offset {} with {} bytes, but it is at offset {} with {} bytes",
+                    page_location.offset,
+                    page_location.compressed_page_size,
+                    page.offset,
+                    page.size
+                ),
+            );
+        }
+        match first_row_index {
+            Some(expected) if page_location.first_row_index != expected => report.push(
+                ValidationIssueKind::OffsetIndex,
+                location,
+                format!(
+                    "Offset index has first row index {}, but the page starts at row {expected}",
+                    page_location.first_row_index
+                ),
+            ),
+            None if !(0..num_rows).contains(&page_location.first_row_index) => report.push(
+                ValidationIssueKind::OffsetIndex,
+                location,
+                format!(
+                    "Offset index has first row index {}, but the row group has {num_rows} rows",
+                    page_location.first_row_index
+                ),
+            ),
+            _ => {}
+        }
+        first_row_index = first_row_index.zip(page.num_rows).map(|(a, b)| a + b);
+    }
+}
+
+/// Checks that `column_index` has an entry for each of the data `pages`, with
+/// the null counts recorded in their headers
+fn validate_column_index(
+    column_index: &ColumnIndexMetaData,
+    pages: &[DataPage],
+    location: (Option<usize>, Option<usize>, Option<usize>),
+    report: &mut ValidationReport,
+) {
+    if matches!(column_index, ColumnIndexMetaData::NONE) {
+        return;
+    }
+    let (rg_idx, col_idx, _) = location;
+    if column_index.num_pages() != pages.len() as u64 {
+        report.push(
+            ValidationIssueKind::ColumnIndex,
+            location,
+            format!(
+                "Column index has {} pages, but the column chunk has {} data pages",
+                column_index.num_pages(),
+                pages.len()
+            ),
+        );
+        return;
+    }
+
+    for (idx, page) in pages.iter().enumerate() {
+        let null_counts = page.num_nulls.zip(column_index.null_count(idx));
+        if let Some((num_nulls, null_count)) = null_counts.filter(|(a, b)| a != b) {
+            report.push(
+                ValidationIssueKind::ColumnIndex,
+                (rg_idx, col_idx, Some(page.page_idx)),
+                format!("Column index has {null_count} nulls, but the page has {num_nulls} nulls"),
+            );
+        }
+    }
+}
+
+/// Decodes all the records of `reader`, returning the number of records read
+fn decode_column(reader: ColumnReader) -> Result<usize> {
+    match reader {
+        ColumnReader::BoolColumnReader(r) => decode_records(r),
+        ColumnReader::Int32ColumnReader(r) => decode_records(r),
+        ColumnReader::Int64ColumnReader(r) => decode_records(r),
+        ColumnReader::Int96ColumnReader(r) => decode_records(r),
+        ColumnReader::FloatColumnReader(r) => decode_records(r),
+        ColumnReader::DoubleColumnReader(r) => decode_records(r),
+        ColumnReader::ByteArrayColumnReader(r) => decode_records(r),
+        ColumnReader::FixedLenByteArrayColumnReader(r) => decode_records(r),
+    }
+}
+
+fn decode_records<T: DataType>(mut reader: ColumnReaderImpl<T>) -> Result<usize> {
+    let mut def_levels = vec![];
+    let mut rep_levels = vec![];
+    let mut values = vec![];
+    let mut num_records = 0;
+    loop {
+        def_levels.clear();
+        rep_levels.clear();
+        values.clear();
+        let (records, _, _) = reader.read_records(
+            DECODE_BATCH_SIZE,
+            Some(&mut def_levels),
+            Some(&mut rep_levels),
+            &mut values,
+        )?;
+        if records == 0 {
+            return Ok(num_records);
+        }
+        num_records += records;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_type::Int32Type;
+    use crate::file::metadata::ParquetOffsetIndex;
+    use crate::file::page_index::offset_index::PageLocation;
+    use crate::file::properties::WriterProperties;
+    use crate::file::writer::SerializedFileWriter;
+    use crate::schema::parser::parse_message_type;
+
+    /// A file with 2 row groups of an optional and a repeated column, each with
+    /// 3 data pages of 100 rows
+    fn test_file() -> Bytes {
+        let schema =
+            parse_message_type("message schema { OPTIONAL INT32 a; REPEATED INT32 b; }").unwrap();
+        let props = WriterProperties::builder()
+            .set_data_page_row_count_limit(100)
+            .set_write_batch_size(100)
+            .set_dictionary_enabled(false)
+            .build();
+        let mut writer =
+            SerializedFileWriter::new(vec![], Arc::new(schema), Arc::new(props)).unwrap();
+        for _ in 0..2 {
+            let mut row_group = writer.next_row_group().unwrap();
+            let values: Vec<i32> = (0..300).collect();
+            let def_levels: Vec<i16> = (0..300).map(|i| (i % 3 != 0) as i16).collect();
+            let rep_levels: Vec<i16> = (0..300).map(|i| (i % 2) as i16).collect();
+
+            let mut column = row_group.next_column().unwrap().unwrap();
+            let non_null: Vec<i32> = values.iter().copied().filter(|v| v % 3 != 0).collect();
+            column
+                .typed::<Int32Type>()
+                .write_batch(&non_null, Some(&def_levels), None)
+                .unwrap();
+            column.close().unwrap();
+
+            let mut column = row_group.next_column().unwrap().unwrap();
+            let typed = column.typed::<Int32Type>();
+            for _ in 0..2 {
+                typed
+                    .write_batch(&values, Some(&[1; 300]), Some(&rep_levels))
+                    .unwrap();
+            }
+            column.close().unwrap();
+            row_group.close().unwrap();
+        }
+        Bytes::from(writer.into_inner().unwrap())
+    }
+
+    fn read_metadata(data: &Bytes) -> (ParquetMetaData, u64) {
+        let mut reader =
+            ParquetMetaDataReader::new().with_page_index_policy(PageIndexPolicy::Required);
+        reader.try_parse(data).unwrap();
+        let data_end = data.len() as u64 - reader.metadata_size().unwrap() as u64;
+        (reader.finish().unwrap(), data_end)
+    }
+
+    fn validate(data: Bytes, metadata: &ParquetMetaData, data_end: u64) -> ValidationReport {
+        let mut report = ValidationReport::default();
+        let options = ValidationOptions::new().with_decode(true);
+        validate_metadata(&Arc::new(data), data_end, metadata, &options, &mut report).unwrap();
+        report
+    }
+
+    fn messages(report: &ValidationReport) -> Vec<String> {
+        report.issues.iter().map(|i| i.to_string()).collect()
+    }
+
+    #[test]
+    fn test_valid_file() {
+        let data = test_file();
+        let report = validate_file(data.clone(), &ValidationOptions::new()).unwrap();
+        assert!(report.is_valid(), "{report}");
+        assert_eq!(report.pages_checked, 12);
+        assert_eq!(report.checksums_verified, 0);
+        assert_eq!(report.column_chunks_decoded, 0);
+
+        let options = ValidationOptions::new().with_decode(true);
+        let report = validate_file(data, &options).unwrap();
+        assert!(report.is_valid(), "{report}");
+        assert_eq!(report.column_chunks_decoded, 4);
+    }
+
+    #[test]
+    fn test_invalid_footer() {
+        let mut data = test_file().to_vec();
+        let len = data.len();
+        data[len - 1] = b'X';
+        let report = validate_file(Bytes::from(data), &ValidationOptions::new()).unwrap();
+        assert_eq!(
+            messages(&report),
+            vec![
+                "Metadata: Failed to read footer: Parquet error: Invalid Parquet file. Corrupt footer"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_column_chunk_metadata_mismatch() {
+        let data = test_file();
+        let (metadata, data_end) = read_metadata(&data);
+        let mut builder = metadata.into_builder();
+        let mut row_groups = builder.take_row_groups();
+        let mut columns = row_groups[0].columns().to_vec();
+        columns[0] = columns[0]
+            .clone()
+            .into_builder()
+            .set_num_values(301)
+            .build()
+            .unwrap();
+        columns[1] = columns[1]
+            .clone()
+            .into_builder()
+            .set_total_compressed_size(i64::MAX)
+            .build()
+            .unwrap();
+        row_groups[0] = row_groups[0]
+            .clone()
+            .into_builder()
+            .set_column_metadata(columns)
+            .build()
+            .unwrap();
+        let metadata = builder.set_row_groups(row_groups).build();
+
+        let report = validate(data, &metadata, data_end);
+        let offset = metadata.row_group(0).column(1).data_page_offset();
+        assert_eq!(
+            messages(&report),
+            vec![
+                "Page row group 0 column 0: Data pages contain 300 values, but the column chunk has 301 values".to_string(),
+                format!(
+                    "Metadata row group 0 column 1: Column chunk of {} bytes at offset {offset} is outside of the data of the file",
+                    i64::MAX
+                ),
+            ]
+        );
+        assert_eq!(report.column_chunks_decoded, 2);
+    }
+
+    #[test]
+    fn test_offset_index_mismatch() {
+        let data = test_file();
+        let (metadata, data_end) = read_metadata(&data);
+        let mut offset_index: ParquetOffsetIndex = metadata.offset_index().unwrap().clone();
+        let locations = &mut offset_index[0][0].page_locations;
+        let expected = locations[1].clone();
+        locations[1] = PageLocation {
+            offset: expected.offset + 1,
+            compressed_page_size: expected.compressed_page_size,
+            first_row_index: 101,
+        };
+        offset_index[1][1].page_locations.pop();
+        let metadata = metadata
+            .into_builder()
+            .set_offset_index(Some(offset_index))
+            .build();
+
+        let report = validate(data, &metadata, data_end);
+        assert_eq!(
+            messages(&report),
+            vec![
+                format!(
+                    "OffsetIndex row group 0 column 0 page 1: Offset index locates page at offset {} with {} bytes, but it is at offset {} with {} bytes",
+                    expected.offset + 1,
+                    expected.compressed_page_size,
+                    expected.offset,
+                    expected.compressed_page_size
+                ),
+                "OffsetIndex row group 0 column 0 page 1: Offset index has first row index 101, but the page starts at row 100".to_string(),
+                "OffsetIndex row group 1 column 1: Offset index has 2 pages, but the column chunk has 3 data pages".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_corrupt_page() {
+        let data = test_file();
+        let (metadata, data_end) = read_metadata(&data);
+        let offset = metadata.offset_index().unwrap()[1][0].page_locations[2].offset as usize;
+        let mut corrupt = data.to_vec();
+        // the type of the page header
+        corrupt[offset + 1] = 0x7f;
+
+        let report = validate(Bytes::from(corrupt), &metadata, data_end);
+        let issues: Vec<_> = report
+            .issues
+            .iter()
+            .map(|i| (i.kind, i.row_group, i.column, i.page))
+            .collect();
+        assert_eq!(
+            issues,
+            vec![
+                (ValidationIssueKind::Page, Some(1), Some(0), Some(2)),
+                (ValidationIssueKind::OffsetIndex, Some(1), Some(0), None),
+                (ValidationIssueKind::ColumnIndex, Some(1), Some(0), None),
+            ]
+        );
+        // the corrupt column chunk is not decoded
+        assert_eq!(report.column_chunks_decoded, 3);
+    }
+
+    #[test]
+    #[cfg(feature = "crc")]
+    fn test_corrupt_checksum() {
+        use crate::util::test_common::file_util::get_test_file;
+
+        let file = get_test_file("datapage_v1-corrupt-checksum.parquet");
+        let options = ValidationOptions::new().with_decode(true);
+        let report = validate_file(file, &options).unwrap();
+        assert!(report.checksums_verified > 0);
+        assert!(!report.is_valid());
+        assert!(
+            report
+                .issues
+                .iter()
+                .all(|i| i.kind == ValidationIssueKind::Checksum),
+            "{report}"
+        );
+
+        let file = get_test_file("datapage_v1-snappy-compressed-checksum.parquet");
+        let report = validate_file(file, &options).unwrap();
+        assert!(report.is_valid(), "{report}");
+        assert!(report.checksums_verified > 0);
+    }
+}
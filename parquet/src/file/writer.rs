@@ -22,17 +22,22 @@ use crate::file::metadata::thrift::PageHeader;
 use crate::file::page_index::column_index::ColumnIndexMetaData;
 use crate::file::page_index::offset_index::OffsetIndexMetaData;
 use crate::parquet_thrift::{ThriftCompactOutputProtocol, WriteThrift};
+use bytes::Bytes;
 use std::fmt::Debug;
 use std::io::{BufWriter, IoSlice, Read};
 use std::{io::Write, sync::Arc};
 
 use crate::column::page_encryption::PageEncryptor;
+use crate::column::reader::{ColumnReader, ColumnReaderImpl, get_column_reader};
 use crate::column::writer::{ColumnCloseResult, ColumnWriterImpl, get_typed_column_writer_mut};
 use crate::column::{
     page::{CompressedPage, PageWriteSpec, PageWriter},
     writer::{ColumnWriter, get_column_writer},
 };
-use crate::data_type::DataType;
+use crate::data_type::{
+    BoolType, ByteArrayType, DataType, DoubleType, FixedLenByteArrayType, FloatType, Int32Type,
+    Int64Type, Int96Type,
+};
 #[cfg(feature = "encryption")]
 use crate::encryption::encrypt::{
     FileEncryptionProperties, FileEncryptor, get_column_crypto_metadata,
@@ -40,8 +45,10 @@ use crate::encryption::encrypt::{
 use crate::errors::{ParquetError, Result};
 #[cfg(feature = "encryption")]
 use crate::file::PARQUET_MAGIC_ENCR_FOOTER;
+use crate::file::index_blob;
 use crate::file::properties::{BloomFilterPosition, WriterPropertiesPtr};
-use crate::file::reader::ChunkReader;
+use crate::file::reader::{ChunkReader, Length};
+use crate::file::serialized_reader::SerializedPageReader;
 use crate::file::{PARQUET_MAGIC, metadata::*};
 use crate::schema::types::{ColumnDescPtr, SchemaDescPtr, SchemaDescriptor, TypePtr};
 
@@ -137,6 +144,93 @@ pub type OnCloseRowGroup<'a, W> = Box<
 // ----------------------------------------------------------------------
 // Serialized impl for file & row group writers
 
+/// The bytes of a column chunk, addressed by their offset in the file it was read from
+struct ColumnChunkBytes {
+    offset: u64,
+    data: Bytes,
+}
+
+impl ColumnChunkBytes {
+    fn try_new<R: ChunkReader>(reader: &R, column: &ColumnChunkMetaData) -> Result<Self> {
+        let (offset, length) = column.byte_range();
+        let data = reader.get_bytes(offset, length as usize)?;
+        Ok(Self { offset, data })
+    }
+}
+
+impl Length for ColumnChunkBytes {
+    fn len(&self) -> u64 {
+        self.offset + self.data.len() as u64
+    }
+}
+
+impl ChunkReader for ColumnChunkBytes {
+    type T = bytes::buf::Reader<Bytes>;
+
+    fn get_read(&self, start: u64) -> Result<Self::T> {
+        let start = start.checked_sub(self.offset).ok_or_else(|| {
+            general_err!("offset {} before column chunk at {}", start, self.offset)
+        })?;
+        self.data.get_read(start)
+    }
+
+    fn get_bytes(&self, start: u64, length: usize) -> Result<Bytes> {
+        let start = start.checked_sub(self.offset).ok_or_else(|| {
+            general_err!("offset {} before column chunk at {}", start, self.offset)
+        })?;
+        self.data.get_bytes(start, length)
+    }
+}
+
+/// Decodes all values of `reader` and writes them to `writer`
+fn reencode_column(
+    reader: ColumnReader,
+    writer: &mut ColumnWriter<'_>,
+    batch_size: usize,
+) -> Result<()> {
+    match reader {
+        ColumnReader::BoolColumnReader(r) => reencode_typed::<BoolType>(r, writer, batch_size),
+        ColumnReader::Int32ColumnReader(r) => reencode_typed::<Int32Type>(r, writer, batch_size),
+        ColumnReader::Int64ColumnReader(r) => reencode_typed::<Int64Type>(r, writer, batch_size),
+        ColumnReader::Int96ColumnReader(r) => reencode_typed::<Int96Type>(r, writer, batch_size),
+        ColumnReader::FloatColumnReader(r) => reencode_typed::<FloatType>(r, writer, batch_size),
+        ColumnReader::DoubleColumnReader(r) => reencode_typed::<DoubleType>(r, writer, batch_size),
+        ColumnReader::ByteArrayColumnReader(r) => {
+            reencode_typed::<ByteArrayType>(r, writer, batch_size)
+        }
+        ColumnReader::FixedLenByteArrayColumnReader(r) => {
+            reencode_typed::<FixedLenByteArrayType>(r, writer, batch_size)
+        }
+    }
+}
+
+fn reencode_typed<T: DataType>(
+    mut reader: ColumnReaderImpl<T>,
+    writer: &mut ColumnWriter<'_>,
+    batch_size: usize,
+) -> Result<()> {
+    let writer = get_typed_column_writer_mut::<T>(writer);
+    let descr = writer.get_descriptor();
+    let mut values = Vec::with_capacity(batch_size);
+    let mut def_levels = (descr.max_def_level() > 0).then(|| Vec::with_capacity(batch_size));
+    let mut rep_levels = (descr.max_rep_level() > 0).then(|| Vec::with_capacity(batch_size));
+    loop {
+        values.clear();
+        def_levels.iter_mut().for_each(Vec::clear);
+        rep_levels.iter_mut().for_each(Vec::clear);
+        let (records, _, _) = reader.read_records(
+            batch_size,
+            def_levels.as_mut(),
+            rep_levels.as_mut(),
+            &mut values,
+        )?;
+        if records == 0 {
+            return Ok(());
+        }
+        writer.write_batch(&values, def_levels.as_deref(), rep_levels.as_deref())?;
+    }
+}
+
 /// Parquet file writer API.
 ///
 /// This is a low level API for writing Parquet files directly, and handles
@@ -286,6 +380,182 @@ impl<W: Write + Send> SerializedFileWriter<W> {
         Ok(row_group_writer)
     }
 
+    /// Appends the encoded row group `row_group_idx` of another Parquet file
+    /// without decoding or re-encoding its data.
+    ///
+    /// The column chunks are copied byte-for-byte from `reader`, and only the
+    /// offsets in the metadata are rewritten. This can be used to efficiently
+    /// concatenate or compact Parquet files with the same schema.
+    ///
+    /// Arguments:
+    /// - `reader`: a [`ChunkReader`] for the file containing the row group
+    /// - `metadata`: the [`ParquetMetaData`] of that file. If it contains the
+    ///   page index, it is copied as well.
+    /// - `row_group_idx`: the index of the row group to append
+    ///
+    /// Bloom filters are read from `reader` and copied if present. The schema
+    /// of the file must be the same as the schema of this writer, and the
+    /// source file must not be encrypted.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use std::fs::File;
+    /// # use std::sync::Arc;
+    /// # use parquet::file::metadata::{PageIndexPolicy, ParquetMetaDataReader};
+    /// # use parquet::file::properties::WriterProperties;
+    /// # use parquet::file::writer::SerializedFileWriter;
+    /// # fn main() -> parquet::errors::Result<()> {
+    /// let inputs = ["a.parquet", "b.parquet"]
+    ///     .into_iter()
+    ///     .map(|path| {
+    ///         let file = File::open(path)?;
+    ///         let metadata = ParquetMetaDataReader::new()
+    ///             .with_page_index_policy(PageIndexPolicy::Optional)
+    ///             .parse_and_finish(&file)?;
+    ///         Ok((file, metadata))
+    ///     })
+    ///     .collect::<parquet::errors::Result<Vec<_>>>()?;
+    ///
+    /// let schema = inputs[0].1.file_metadata().schema_descr().root_schema_ptr();
+    /// let props = Arc::new(WriterProperties::default());
+    /// let mut writer = SerializedFileWriter::new(File::create("out.parquet")?, schema, props)?;
+    /// for (file, metadata) in &inputs {
+    ///     for row_group_idx in 0..metadata.num_row_groups() {
+    ///         writer.append_row_group(file, metadata, row_group_idx)?;
+    ///     }
+    /// }
+    /// writer.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn append_row_group<R: ChunkReader>(
+        &mut self,
+        reader: &R,
+        metadata: &ParquetMetaData,
+        row_group_idx: usize,
+    ) -> Result<()> {
+        self.rewrite_row_group(reader, metadata, row_group_idx, &[])
+    }
+
+    /// Appends the row group `row_group_idx` of another Parquet file, decoding and
+    /// re-encoding only the leaf columns with indices in `columns`.
+    ///
+    /// The other column chunks are copied byte-for-byte as in
+    /// [`Self::append_row_group`]. The re-encoded columns are written using the
+    /// [`WriterProperties`] of this writer, which allows changing the compression,
+    /// encodings or statistics of selected columns, or adding bloom filters and
+    /// page indexes to them, without rewriting the rest of the file.
+    ///
+    /// For example, to add a bloom filter to the column `id` of existing files:
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// # use std::sync::Arc;
+    /// # use parquet::file::metadata::{PageIndexPolicy, ParquetMetaDataReader};
+    /// # use parquet::file::properties::WriterProperties;
+    /// # use parquet::file::writer::SerializedFileWriter;
+    /// # use parquet::schema::types::ColumnPath;
+    /// # fn main() -> parquet::errors::Result<()> {
+    /// let file = File::open("in.parquet")?;
+    /// let metadata = ParquetMetaDataReader::new()
+    ///     .with_page_index_policy(PageIndexPolicy::Optional)
+    ///     .parse_and_finish(&file)?;
+    /// let schema_descr = metadata.file_metadata().schema_descr();
+    /// let id = ColumnPath::from("id");
+    /// let columns: Vec<_> = (0..schema_descr.num_columns())
+    ///     .filter(|idx| schema_descr.column(*idx).path() == &id)
+    ///     .collect();
+    ///
+    /// let props = WriterProperties::builder()
+    ///     .set_column_bloom_filter_enabled(id, true)
+    ///     .build();
+    /// let output = File::create("out.parquet")?;
+    /// let schema = schema_descr.root_schema_ptr();
+    /// let mut writer = SerializedFileWriter::new(output, schema, Arc::new(props))?;
+    /// for row_group_idx in 0..metadata.num_row_groups() {
+    ///     writer.rewrite_row_group(&file, &metadata, row_group_idx, &columns)?;
+    /// }
+    /// writer.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`WriterProperties`]: crate::file::properties::WriterProperties
+    pub fn rewrite_row_group<R: ChunkReader>(
+        &mut self,
+        reader: &R,
+        metadata: &ParquetMetaData,
+        row_group_idx: usize,
+        columns: &[usize],
+    ) -> Result<()> {
+        let expected = self.descr.root_schema();
+        let actual = metadata.file_metadata().schema_descr().root_schema();
+        if expected != actual {
+            return Err(general_err!(
+                "schema mismatch, expected {:#?} got {:#?}",
+                expected,
+                actual
+            ));
+        }
+        if row_group_idx >= metadata.num_row_groups() {
+            return Err(general_err!(
+                "row group index {} out of bounds for {} row groups",
+                row_group_idx,
+                metadata.num_row_groups()
+            ));
+        }
+        if let Some(col_idx) = columns.iter().find(|idx| **idx >= self.descr.num_columns()) {
+            return Err(general_err!(
+                "column index {} out of bounds for {} columns",
+                col_idx,
+                self.descr.num_columns()
+            ));
+        }
+        let row_group = metadata.row_group(row_group_idx);
+        #[cfg(feature = "encryption")]
+        if let Some(column) = row_group
+            .columns()
+            .iter()
+            .find(|c| c.crypto_metadata().is_some())
+        {
+            return Err(general_err!(
+                "cannot copy encrypted column chunk {}",
+                column.column_path()
+            ));
+        }
+        let column_indexes = metadata.column_index().and_then(|ci| ci.get(row_group_idx));
+        let offset_indexes = metadata.offset_index().and_then(|oi| oi.get(row_group_idx));
+        let batch_size = self.props.write_batch_size();
+
+        let mut row_group_writer = self.next_row_group()?;
+        for (col_idx, column) in row_group.columns().iter().enumerate() {
+            if columns.contains(&col_idx) {
+                let chunk = Arc::new(ColumnChunkBytes::try_new(reader, column)?);
+                let page_reader =
+                    SerializedPageReader::new(chunk, column, row_group.num_rows() as usize, None)?;
+                let column_reader =
+                    get_column_reader(column.column_descr_ptr(), Box::new(page_reader));
+                let mut column_writer = row_group_writer
+                    .next_column()?
+                    .ok_or_else(|| general_err!("exhausted columns in SerializedRowGroupWriter"))?;
+                reencode_column(column_reader, column_writer.untyped(), batch_size)?;
+                column_writer.close()?;
+                continue;
+            }
+            let result = ColumnCloseResult {
+                bytes_written: column.compressed_size() as _,
+                rows_written: row_group.num_rows() as _,
+                metadata: column.clone(),
+                bloom_filter: Sbbf::read_from_column_chunk(column, reader)?,
+                column_index: column_indexes.and_then(|row| row.get(col_idx)).cloned(),
+                offset_index: offset_indexes.and_then(|row| row.get(col_idx)).cloned(),
+            };
+            row_group_writer.append_column(reader, result)?;
+        }
+        row_group_writer.close()?;
+        Ok(())
+    }
+
     /// Returns metadata for any flushed row groups
     pub fn flushed_row_groups(&self) -> &[RowGroupMetaData] {
         &self.row_groups
@@ -389,6 +659,29 @@ impl<W: Write + Send> SerializedFileWriter<W> {
         self.kv_metadatas.push(kv_metadata);
     }
 
+    /// Writes `data` as the index blob `name`, after the data written so far
+    ///
+    /// The location of the blob is recorded in the key value metadata of the
+    /// file, so that it can be read with [`read_index_blob`]. See the
+    /// [`index_blob`] module for more details.
+    ///
+    /// Returns an error if a row group is being written, or if an index blob
+    /// named `name` was already written.
+    ///
+    /// [`index_blob`]: crate::file::index_blob
+    /// [`read_index_blob`]: crate::file::index_blob::read_index_blob
+    pub fn write_index_blob(&mut self, name: &str, data: &[u8]) -> Result<()> {
+        self.assert_previous_writer_closed()?;
+        index_blob::validate_index_blob_name(name, &self.kv_metadatas)?;
+
+        let start = self.buf.bytes_written() as u64;
+        self.buf.write_all(data)?;
+        let end = self.buf.bytes_written() as u64;
+        self.kv_metadatas
+            .push(index_blob::index_blob_key_value(name, start..end));
+        Ok(())
+    }
+
     /// Returns a reference to schema descriptor.
     pub fn schema_descr(&self) -> &SchemaDescriptor {
         &self.descr
@@ -2536,4 +2829,259 @@ mod tests {
         }
         writer.close().unwrap();
     }
+
+    #[test]
+    #[cfg(feature = "arrow")]
+    fn test_append_row_group() {
+        use arrow_array::{ArrayRef, Int32Array, RecordBatch, StringArray};
+
+        let write = |offset: i32| {
+            let batch = RecordBatch::try_from_iter([
+                (
+                    "a",
+                    Arc::new(Int32Array::from_iter_values(offset..offset + 100)) as ArrayRef,
+                ),
+                (
+                    "b",
+                    Arc::new(StringArray::from_iter_values(
+                        (offset..offset + 100).map(|x| x.to_string()),
+                    )) as ArrayRef,
+                ),
+            ])
+            .unwrap();
+            let props = WriterProperties::builder()
+                .set_max_row_group_row_count(Some(50))
+                .set_bloom_filter_enabled(true)
+                .build();
+            let mut buf = Vec::new();
+            let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+            writer.write(&batch).unwrap();
+            writer.close().unwrap();
+            (batch, Bytes::from(buf))
+        };
+        let inputs = [write(0), write(100)];
+
+        let metadata: Vec<_> = inputs
+            .iter()
+            .map(|(_, file)| {
+                ParquetMetaDataReader::new()
+                    .with_page_index_policy(PageIndexPolicy::Required)
+                    .parse_and_finish(file)
+                    .unwrap()
+            })
+            .collect();
+
+        let schema = metadata[0].file_metadata().schema_descr().root_schema_ptr();
+        let props = Arc::new(WriterProperties::builder().build());
+        let mut output = Vec::new();
+        let mut writer = SerializedFileWriter::new(&mut output, schema, props).unwrap();
+        for ((_, file), metadata) in inputs.iter().zip(&metadata) {
+            for row_group_idx in 0..metadata.num_row_groups() {
+                writer
+                    .append_row_group(file, metadata, row_group_idx)
+                    .unwrap();
+            }
+        }
+
+        // out of bounds row group
+        let err = writer
+            .append_row_group(&inputs[0].1, &metadata[0], 2)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: row group index 2 out of bounds for 2 row groups"
+        );
+        writer.close().unwrap();
+        let output = Bytes::from(output);
+
+        // the page index and bloom filters are copied
+        let output_metadata = ParquetMetaDataReader::new()
+            .with_page_index_policy(PageIndexPolicy::Required)
+            .parse_and_finish(&output)
+            .unwrap();
+        assert_eq!(output_metadata.num_row_groups(), 4);
+        let offset_index = output_metadata.offset_index().unwrap();
+        for (rg_idx, rg) in output_metadata.row_groups().iter().enumerate() {
+            assert_eq!(rg.num_rows(), 50);
+            for (col_idx, column) in rg.columns().iter().enumerate() {
+                let sbbf = Sbbf::read_from_column_chunk(column, &output).unwrap();
+                assert!(sbbf.is_some());
+                let first_page = &offset_index[rg_idx][col_idx].page_locations()[0];
+                assert_eq!(first_page.offset, column.data_page_offset());
+            }
+        }
+
+        let reader = ParquetRecordBatchReaderBuilder::try_new(output)
+            .unwrap()
+            .with_batch_size(100)
+            .build()
+            .unwrap();
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        let expected = vec![inputs[0].0.clone(), inputs[1].0.clone()];
+        assert_eq!(batches, expected);
+
+        // schema mismatch
+        let message_type = "
+            message test_schema {
+                REQUIRED INT32 a;
+            }
+        ";
+        let schema = Arc::new(parse_message_type(message_type).unwrap());
+        let mut writer = SerializedFileWriter::new(vec![], schema, Default::default()).unwrap();
+        let err = writer
+            .append_row_group(&inputs[0].1, &metadata[0], 0)
+            .unwrap_err();
+        assert!(err.to_string().contains("schema mismatch"), "{err}");
+    }
+
+    #[test]
+    #[cfg(feature = "arrow")]
+    fn test_rewrite_row_group() {
+        use arrow_array::builder::{Int32Builder, ListBuilder};
+        use arrow_array::{ArrayRef, Int32Array, RecordBatch, StringArray};
+
+        let mut list = ListBuilder::new(Int32Builder::new());
+        for i in 0..100 {
+            if i % 7 == 0 {
+                list.append_null();
+            } else {
+                list.append_value((0..i % 4).map(Some));
+            }
+        }
+        let batch = RecordBatch::try_from_iter([
+            (
+                "a",
+                Arc::new(Int32Array::from_iter(
+                    (0..100).map(|x| (x % 3 != 0).then_some(x)),
+                )) as ArrayRef,
+            ),
+            (
+                "b",
+                Arc::new(StringArray::from_iter_values(
+                    (0..100).map(|x| format!("value{x}")),
+                )) as ArrayRef,
+            ),
+            ("c", Arc::new(list.finish()) as ArrayRef),
+        ])
+        .unwrap();
+        let props = WriterProperties::builder()
+            .set_max_row_group_row_count(Some(50))
+            .set_compression(Compression::UNCOMPRESSED)
+            .build();
+        let mut buf = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let input = Bytes::from(buf);
+        let metadata = ParquetMetaDataReader::new()
+            .parse_and_finish(&input)
+            .unwrap();
+
+        // re-encode "b" and "c.list.item" with a different codec and bloom filters
+        let props = WriterProperties::builder()
+            .set_compression(Compression::SNAPPY)
+            .set_bloom_filter_enabled(true)
+            .build();
+        let schema = metadata.file_metadata().schema_descr().root_schema_ptr();
+        let mut output = Vec::new();
+        let mut writer = SerializedFileWriter::new(&mut output, schema, Arc::new(props)).unwrap();
+        for row_group_idx in 0..metadata.num_row_groups() {
+            writer
+                .rewrite_row_group(&input, &metadata, row_group_idx, &[1, 2])
+                .unwrap();
+        }
+        let err = writer
+            .rewrite_row_group(&input, &metadata, 0, &[3])
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: column index 3 out of bounds for 3 columns"
+        );
+        writer.close().unwrap();
+        let output = Bytes::from(output);
+
+        let output_metadata = ParquetMetaDataReader::new()
+            .with_page_index_policy(PageIndexPolicy::Optional)
+            .parse_and_finish(&output)
+            .unwrap();
+        for rg in output_metadata.row_groups() {
+            let codecs: Vec<_> = rg.columns().iter().map(|c| c.compression()).collect();
+            assert_eq!(
+                codecs,
+                vec![
+                    Compression::UNCOMPRESSED,
+                    Compression::SNAPPY,
+                    Compression::SNAPPY
+                ]
+            );
+            let bloom_filters: Vec<_> = rg
+                .columns()
+                .iter()
+                .map(|c| Sbbf::read_from_column_chunk(c, &output).unwrap().is_some())
+                .collect();
+            assert_eq!(bloom_filters, vec![false, true, true]);
+        }
+
+        let reader = ParquetRecordBatchReaderBuilder::try_new(output)
+            .unwrap()
+            .with_batch_size(100)
+            .build()
+            .unwrap();
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(batches, vec![batch]);
+    }
+
+    #[test]
+    #[cfg(feature = "arrow")]
+    fn test_write_index_blob() {
+        use crate::file::index_blob::{index_blob_names, read_index_blob};
+        use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+
+        let batch = RecordBatch::try_from_iter([(
+            "a",
+            Arc::new(Int32Array::from_iter_values(0..10)) as ArrayRef,
+        )])
+        .unwrap();
+        let mut buf = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.flush().unwrap();
+        writer.write_index_blob("first", b"first blob").unwrap();
+        writer.write(&batch).unwrap();
+        writer.write_index_blob("second", b"").unwrap();
+
+        let err = writer.write_index_blob("first", b"again").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Index blob 'first' already written"
+        );
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        // the data is not affected by the blobs
+        let builder = ParquetRecordBatchReaderBuilder::try_new(data.clone()).unwrap();
+        let metadata = Arc::clone(builder.metadata());
+        assert_eq!(metadata.num_row_groups(), 2);
+        let batches = builder
+            .with_batch_size(10)
+            .build()
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(batches, vec![batch.clone(), batch]);
+
+        let file_metadata = metadata.file_metadata();
+        assert_eq!(
+            index_blob_names(file_metadata).collect::<Vec<_>>(),
+            vec!["first", "second"]
+        );
+        let first = read_index_blob(&data, file_metadata, "first").unwrap();
+        assert_eq!(first.as_deref(), Some(b"first blob".as_slice()));
+        let second = read_index_blob(&data, file_metadata, "second").unwrap();
+        assert_eq!(second.as_deref(), Some(b"".as_slice()));
+        assert_eq!(
+            read_index_blob(&data, file_metadata, "third").unwrap(),
+            None
+        );
+    }
 }
@@ -19,6 +19,7 @@
 
 mod api;
 pub mod reader;
+mod record_enum;
 mod record_reader;
 mod record_writer;
 mod triplet;
@@ -27,6 +28,7 @@ pub use self::{
     api::{
         Field, List, ListAccessor, Map, MapAccessor, Row, RowAccessor, RowColumnIter, RowFormatter,
     },
-    record_reader::RecordReader,
-    record_writer::RecordWriter,
+    record_enum::RecordEnum,
+    record_reader::{NestedRecordReader, RecordReader},
+    record_writer::{NestedRecordWriter, RecordWriter},
 };
@@ -0,0 +1,35 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+/// Trait describing how to convert a fieldless enum to and from the strings of
+/// an `ENUM` column.
+///
+/// [`parquet_derive`] crate provides a derive macro `ParquetRecordEnum` for this
+/// trait, using the names of the variants, so that the enum can be used as an
+/// `#[parquet(enum)]` field of the structs deriving [`RecordWriter`] and
+/// [`RecordReader`].
+///
+/// [`parquet_derive`]: https://crates.io/crates/parquet_derive
+/// [`RecordWriter`]: super::RecordWriter
+/// [`RecordReader`]: super::RecordReader
+pub trait RecordEnum: Sized {
+    /// Returns the name of the variant written to the column
+    fn name(&self) -> &'static str;
+
+    /// Returns the variant named `name`, or `None` if there is no such variant
+    fn from_name(name: &str) -> Option<Self>;
+}
@@ -30,3 +30,26 @@ pub trait RecordReader<T> {
         num_records: usize,
     ) -> Result<(), ParquetError>;
 }
+
+/// Trait describing how to read a struct nested in a record, from the columns of
+/// a group.
+///
+/// [`parquet_derive`] crate implements this trait for the structs deriving
+/// [`ParquetRecordReader`], so that they can be used as `#[parquet(nested)]` fields
+/// of other derived structs.
+///
+/// [`parquet_derive`]: https://crates.io/crates/parquet_derive
+/// [`ParquetRecordReader`]: https://docs.rs/parquet_derive/53.0.0/parquet_derive/derive.ParquetRecordReader.html
+pub trait NestedRecordReader: Sized {
+    /// Reads up to `num_records` values of the group at `path` from `row_group_reader`.
+    ///
+    /// Returns the values, and for each value its definition level capped at
+    /// `definition_level`, the definition level of the group when present. The
+    /// values with a lower definition level are null, and left to their default.
+    fn read_from_columns(
+        row_group_reader: &dyn RowGroupReader,
+        path: &[String],
+        definition_level: i16,
+        num_records: usize,
+    ) -> Result<(Vec<Self>, Vec<i16>), ParquetError>;
+}
@@ -23,7 +23,7 @@ use super::super::file::writer::SerializedRowGroupWriter;
 /// Trait describing how to write a record (the implementator) to a row group writer.
 ///
 /// [`parquet_derive`] crate provides a derive macro [`ParquetRecordWriter`] for this trait
-/// for unnested structs.
+/// for structs, see [`NestedRecordWriter`] for structs nested in other structs.
 ///
 /// The type parameter `T` is used to work around the rust orphan rule
 /// when implementing on types such as `&[T]`.
@@ -40,3 +40,30 @@ pub trait RecordWriter<T> {
     /// Generated schema used by `row_group_writer`
     fn schema(&self) -> Result<TypePtr, ParquetError>;
 }
+
+/// Trait describing how to write a struct nested in a record, as the columns of
+/// a group.
+///
+/// [`parquet_derive`] crate implements this trait for the structs deriving
+/// [`ParquetRecordWriter`], so that they can be used as `#[parquet(nested)]` fields
+/// of other derived structs.
+///
+/// [`parquet_derive`]: https://crates.io/crates/parquet_derive
+/// [`ParquetRecordWriter`]: https://docs.rs/parquet_derive/53.0.0/parquet_derive/derive.ParquetRecordWriter.html
+pub trait NestedRecordWriter {
+    /// Writes the leaf columns of the group into `row_group_writer`, one value
+    /// per entry of `records`.
+    ///
+    /// The group is present with definition level `definition_level` for the
+    /// entries that are `Some`, the other entries are null with the level of
+    /// the same index in `definition_levels`.
+    fn write_to_columns<W: std::io::Write + Send>(
+        records: &[Option<&Self>],
+        definition_levels: &[i16],
+        definition_level: i16,
+        row_group_writer: &mut SerializedRowGroupWriter<W>,
+    ) -> Result<(), ParquetError>;
+
+    /// Generated fields of the group
+    fn group_fields() -> Result<Vec<TypePtr>, ParquetError>;
+}
@@ -27,6 +27,13 @@
 //! * Kernels for working with arrays of Variant values
 //!   such as conversion between `Variant` and JSON, and shredding/unshredding
 //!   (see [`parquet_variant_compute`] for more details)
+//! * Reading shredded Variant columns unshredded, see
+//!   [`ArrowReaderOptions::with_unshred_variants`]
+//! * Shredding Variant columns when writing, see
+//!   [`ArrowWriterOptions::with_variant_shredding`]
+//!
+//! [`ArrowReaderOptions::with_unshred_variants`]: crate::arrow::arrow_reader::ArrowReaderOptions::with_unshred_variants
+//! [`ArrowWriterOptions::with_variant_shredding`]: crate::arrow::arrow_writer::ArrowWriterOptions::with_variant_shredding
 //!
 //! # Example: Writing a Parquet file with Variant column
 //! ```rust
@@ -142,16 +149,17 @@ pub use parquet_variant_compute::*;
 
 #[cfg(test)]
 mod tests {
-    use crate::arrow::ArrowWriter;
-    use crate::arrow::arrow_reader::ArrowReaderBuilder;
+    use crate::arrow::arrow_reader::{ArrowReaderBuilder, ArrowReaderOptions};
+    use crate::arrow::arrow_writer::ArrowWriterOptions;
+    use crate::arrow::{ArrowWriter, ProjectionMask};
     use crate::file::metadata::{ParquetMetaData, ParquetMetaDataReader};
     use crate::file::reader::ChunkReader;
     use arrow::util::test_util::parquet_test_data;
     use arrow_array::{ArrayRef, RecordBatch};
-    use arrow_schema::Schema;
+    use arrow_schema::{DataType, Field, Schema};
     use bytes::Bytes;
     use parquet_variant::{Variant, VariantBuilderExt};
-    use parquet_variant_compute::{VariantArray, VariantArrayBuilder, VariantType};
+    use parquet_variant_compute::{VariantArray, VariantArrayBuilder, VariantType, shred_variant};
     use std::path::PathBuf;
     use std::sync::Arc;
 
@@ -203,6 +211,145 @@ mod tests {
         );
     }
 
+    /// Writes a shredded VariantArray and reads it back unshredded
+    #[test]
+    fn read_unshredded() {
+        let mut builder = VariantArrayBuilder::new(4);
+        builder.append_value(1i64);
+        builder.append_value("such wow");
+        builder.append_null();
+        builder.new_object().with_field("name", "Alice").finish();
+        let array = builder.build();
+        let shredded = shred_variant(&array, &DataType::Int64).unwrap();
+        let buffer = Bytes::from(write_to_buffer(&variant_array_to_batch(shredded)));
+
+        // shredded variants are read as written by default
+        let batch = read_to_batch(buffer.clone());
+        assert_variant_metadata(&batch, "data");
+        let data = VariantArray::try_new(batch.column(0)).unwrap();
+        assert!(data.typed_value_column().is_some());
+
+        let options = ArrowReaderOptions::new().with_unshred_variants(true);
+        let builder = ArrowReaderBuilder::try_new_with_options(buffer, options).unwrap();
+        let schema = builder.schema().clone();
+        let batch = builder
+            .build()
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap()
+            .swap_remove(0);
+        assert_eq!(batch.schema(), schema);
+        assert_variant_metadata(&batch, "data");
+
+        let data = VariantArray::try_new(batch.column(0)).unwrap();
+        assert!(data.typed_value_column().is_none());
+        let DataType::Struct(fields) = batch.column(0).data_type() else {
+            panic!("expected struct");
+        };
+        let names: Vec<_> = fields.iter().map(|f| f.name().as_str()).collect();
+        assert_eq!(names, ["metadata", "value"]);
+
+        assert_eq!(data.len(), 4);
+        assert_eq!(data.value(0), Variant::from(1i64));
+        assert_eq!(data.value(1), Variant::from("such wow"));
+        assert!(data.is_null(2));
+        assert_eq!(data.value(3), array.value(3));
+    }
+
+    /// Reads a shredded VariantArray with projections dropping some of its fields
+    #[test]
+    fn read_unshredded_projected() {
+        let mut builder = VariantArrayBuilder::new(3);
+        builder.append_value(1i64);
+        builder.append_value("such wow");
+        builder.append_null();
+        let array = builder.build();
+        let shredded = shred_variant(&array, &DataType::Int64).unwrap();
+        let buffer = Bytes::from(write_to_buffer(&variant_array_to_batch(shredded)));
+
+        // leaves are `metadata`, `value` and `typed_value`
+        for (leaves, expected) in [
+            (vec![0, 2], vec!["metadata", "value"]),
+            (vec![0, 1], vec!["metadata", "value"]),
+            (vec![1, 2], vec!["value", "typed_value"]),
+            (vec![2], vec!["typed_value"]),
+        ] {
+            let options = ArrowReaderOptions::new().with_unshred_variants(true);
+            let builder =
+                ArrowReaderBuilder::try_new_with_options(buffer.clone(), options).unwrap();
+            let mask = ProjectionMask::leaves(builder.parquet_schema(), leaves);
+            let batch = builder
+                .with_projection(mask)
+                .build()
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap()
+                .swap_remove(0);
+
+            let DataType::Struct(fields) = batch.schema().field(0).data_type().clone() else {
+                panic!("expected struct");
+            };
+            let names: Vec<_> = fields.iter().map(|f| f.name().as_str()).collect();
+            assert_eq!(names, expected);
+            assert_eq!(batch.column(0).data_type(), &DataType::Struct(fields));
+        }
+    }
+
+    /// Shreds a VariantArray while writing, and reads it back
+    #[test]
+    fn write_shredded() {
+        let array = variant_array();
+        let batch = variant_array_to_batch(array.clone());
+        let options = ArrowWriterOptions::new().with_variant_shredding("data", DataType::Utf8View);
+        let mut buffer = vec![];
+        let mut writer =
+            ArrowWriter::try_new_with_options(&mut buffer, batch.schema(), options).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let buffer = Bytes::from(buffer);
+
+        let result = read_to_batch(buffer.clone());
+        assert_variant_metadata(&result, "data");
+        let data = VariantArray::try_new(result.column(0)).unwrap();
+        let typed_value = data.typed_value_column().unwrap();
+        assert_eq!(typed_value.data_type(), &DataType::Utf8View);
+        assert_eq!(data.value(1), Variant::from("such wow"));
+
+        let options = ArrowReaderOptions::new().with_unshred_variants(true);
+        let result = ArrowReaderBuilder::try_new_with_options(buffer, options)
+            .unwrap()
+            .build()
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap()
+            .swap_remove(0);
+        let data = VariantArray::try_new(result.column(0)).unwrap();
+        assert_eq!(data.len(), 3);
+        assert_eq!(data.value(0), array.value(0));
+        assert_eq!(data.value(1), array.value(1));
+        assert!(data.is_null(2));
+    }
+
+    #[test]
+    fn write_shredded_invalid_column() {
+        let schema = Arc::new(Schema::new(vec![
+            variant_array().field("data"),
+            Field::new("id", DataType::Int64, false),
+        ]));
+        for (name, expected) in [
+            ("id", "Cannot shred column 'id': not a Variant column"),
+            (
+                "missing",
+                "Cannot shred Variant column 'missing': no such column",
+            ),
+        ] {
+            let options = ArrowWriterOptions::new().with_variant_shredding(name, DataType::Int64);
+            let err =
+                ArrowWriter::try_new_with_options(vec![], schema.clone(), options).unwrap_err();
+            assert_eq!(err.to_string(), format!("Parquet error: {expected}"));
+        }
+    }
+
     /// Return a VariantArray with 3 rows:
     ///
     /// 1. `{"name": "Alice"}`
@@ -0,0 +1,237 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Tests for the per-column metrics of [`ArrowReaderMetrics`]
+
+use super::io::TestReader;
+use arrow_array::{ArrayRef, BooleanArray, Int64Array, RecordBatch, Scalar};
+use bytes::Bytes;
+use futures::StreamExt;
+use parquet::arrow::arrow_reader::metrics::{ArrowReaderMetrics, ColumnReaderMetrics};
+use parquet::arrow::arrow_reader::pruning::{PruningOp, PruningPredicate};
+use parquet::arrow::arrow_reader::{
+    ArrowPredicateFn, ArrowReaderOptions, ParquetRecordBatchReaderBuilder, RowFilter,
+};
+use parquet::arrow::{ArrowWriter, ParquetRecordBatchStreamBuilder, ProjectionMask};
+use parquet::basic::Compression;
+use parquet::file::metadata::{PageIndexPolicy, ParquetMetaData};
+use parquet::file::properties::WriterProperties;
+use std::sync::{Arc, LazyLock};
+use std::time::Duration;
+
+#[test]
+fn test_sync_column_metrics() {
+    let builder = sync_builder();
+    let metadata = Arc::clone(builder.metadata());
+    let metrics = ArrowReaderMetrics::enabled_with_decode_timing();
+    let reader = builder.with_metrics(metrics.clone()).build().unwrap();
+    let num_rows: usize = reader.map(|b| b.unwrap().num_rows()).sum();
+    assert_eq!(num_rows, 400);
+
+    for leaf_idx in 0..2 {
+        let decode_time = verify_column_metrics(&metadata, &metrics, leaf_idx);
+        assert!(decode_time > Duration::ZERO);
+    }
+    assert_eq!(metrics.rows_pruned_by_page_index(), Some(0));
+}
+
+#[tokio::test]
+async fn test_async_column_metrics() {
+    let builder = async_builder().await;
+    let metadata = Arc::clone(builder.metadata());
+    let metrics = ArrowReaderMetrics::enabled();
+    let mut stream = builder.with_metrics(metrics.clone()).build().unwrap();
+    let mut num_rows = 0;
+    while let Some(batch) = stream.next().await {
+        num_rows += batch.unwrap().num_rows();
+    }
+    assert_eq!(num_rows, 400);
+
+    for leaf_idx in 0..2 {
+        // decode time is only recorded if requested
+        let decode_time = verify_column_metrics(&metadata, &metrics, leaf_idx);
+        assert_eq!(decode_time, Duration::ZERO);
+    }
+    assert_eq!(metrics.rows_pruned_by_page_index(), Some(0));
+}
+
+#[test]
+fn test_sync_column_metrics_projection() {
+    let builder = sync_builder();
+    let mask = ProjectionMask::leaves(builder.parquet_schema(), [1]);
+    let metrics = ArrowReaderMetrics::enabled();
+    let reader = builder
+        .with_projection(mask)
+        .with_metrics(metrics.clone())
+        .build()
+        .unwrap();
+    reader.for_each(|b| drop(b.unwrap()));
+
+    // column "a" is not read
+    assert_eq!(
+        metrics.column_metrics(0),
+        Some(ColumnReaderMetrics::default())
+    );
+    assert_eq!(metrics.column_metrics(1).unwrap().pages_decoded, 4);
+}
+
+#[test]
+fn test_column_metrics_disabled() {
+    let metrics = ArrowReaderMetrics::disabled();
+    let reader = sync_builder()
+        .with_metrics(metrics.clone())
+        .build()
+        .unwrap();
+    reader.for_each(|b| drop(b.unwrap()));
+
+    assert_eq!(metrics.column_metrics(0), None);
+    assert_eq!(metrics.rows_pruned_by_page_index(), None);
+}
+
+#[test]
+fn test_sync_rows_pruned_by_page_index() {
+    let builder = sync_builder();
+    let filter = row_filter(builder.parquet_schema());
+    let metrics = ArrowReaderMetrics::enabled();
+    let reader = builder
+        .with_row_filter(filter)
+        .with_metrics(metrics.clone())
+        .build()
+        .unwrap();
+    let num_rows: usize = reader.map(|b| b.unwrap().num_rows()).sum();
+    assert_eq!(num_rows, 50);
+
+    // the second row group is skipped entirely, and the second page of the first
+    // row group is skipped using the page index
+    assert_eq!(metrics.rows_pruned_by_page_index(), Some(100));
+    // only the first page of the first row group of column "b" is decoded
+    assert_eq!(metrics.column_metrics(1).unwrap().pages_decoded, 1);
+}
+
+#[tokio::test]
+async fn test_async_rows_pruned_by_page_index() {
+    let builder = async_builder().await;
+    let filter = row_filter(builder.parquet_schema());
+    let metrics = ArrowReaderMetrics::enabled();
+    let mut stream = builder
+        .with_row_filter(filter)
+        .with_metrics(metrics.clone())
+        .build()
+        .unwrap();
+    let mut num_rows = 0;
+    while let Some(batch) = stream.next().await {
+        num_rows += batch.unwrap().num_rows();
+    }
+    assert_eq!(num_rows, 50);
+
+    assert_eq!(metrics.rows_pruned_by_page_index(), Some(100));
+    // only the first page of the first row group of column "b" is fetched and decoded
+    let column_metrics = metrics.column_metrics(1).unwrap();
+    assert_eq!(column_metrics.pages_decoded, 1);
+    let offset_index = async_builder().await.metadata().offset_index().unwrap()[0][1].clone();
+    let first_page = &offset_index.page_locations()[0];
+    assert_eq!(
+        column_metrics.bytes_read,
+        first_page.compressed_page_size as u64
+    );
+}
+
+/// Asserts the [`ColumnReaderMetrics`] of `leaf_idx` after reading all rows,
+/// returning the decode time
+fn verify_column_metrics(
+    metadata: &ParquetMetaData,
+    metrics: &ArrowReaderMetrics,
+    leaf_idx: usize,
+) -> Duration {
+    let ColumnReaderMetrics {
+        bytes_read,
+        bytes_decompressed,
+        pages_decoded,
+        decode_time,
+    } = metrics.column_metrics(leaf_idx).unwrap();
+
+    let compressed_size: i64 = metadata
+        .row_groups()
+        .iter()
+        .map(|rg| rg.column(leaf_idx).compressed_size())
+        .sum();
+    // the data pages are read, but not the page index or bloom filters
+    assert!(bytes_read > 0 && bytes_read <= compressed_size as u64);
+    // 2 row groups of 2 data pages, no dictionary pages
+    assert_eq!(pages_decoded, 4);
+    // the values are snappy compressed
+    assert!(bytes_decompressed > 0);
+    decode_time
+}
+
+/// A filter keeping rows with `a < 50`, advertising the same condition as a
+/// [`PruningPredicate`]
+fn row_filter(schema: &parquet::schema::types::SchemaDescriptor) -> RowFilter {
+    let mask = ProjectionMask::leaves(schema, [0]);
+    let predicate = ArrowPredicateFn::new(mask, |batch: RecordBatch| {
+        let a = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap();
+        Ok(a.iter()
+            .map(|v| v.map(|v| v < 50))
+            .collect::<BooleanArray>())
+    })
+    .with_pruning_predicate(PruningPredicate::new(
+        "a",
+        PruningOp::Lt,
+        Scalar::new(Int64Array::from(vec![50])),
+    ));
+    RowFilter::new(vec![Box::new(predicate)])
+}
+
+fn sync_builder() -> ParquetRecordBatchReaderBuilder<Bytes> {
+    let options = ArrowReaderOptions::new().with_page_index_policy(PageIndexPolicy::Required);
+    ParquetRecordBatchReaderBuilder::try_new_with_options(TEST_FILE_DATA.clone(), options).unwrap()
+}
+
+async fn async_builder() -> ParquetRecordBatchStreamBuilder<TestReader> {
+    let reader = TestReader::new(TEST_FILE_DATA.clone());
+    let options = ArrowReaderOptions::new().with_page_index_policy(PageIndexPolicy::Required);
+    ParquetRecordBatchStreamBuilder::new_with_options(reader, options)
+        .await
+        .unwrap()
+}
+
+/// 2 columns "a" and "b", with values 0..400 and 400..800
+///
+/// 2 row groups of 200 rows, each data page has 100 rows
+static TEST_FILE_DATA: LazyLock<Bytes> = LazyLock::new(|| {
+    let a: ArrayRef = Arc::new(Int64Array::from_iter_values(0..400));
+    let b: ArrayRef = Arc::new(Int64Array::from_iter_values(400..800));
+    let input_batch = RecordBatch::try_from_iter(vec![("a", a), ("b", b)]).unwrap();
+
+    let props = WriterProperties::builder()
+        .set_max_row_group_row_count(Some(200))
+        .set_data_page_row_count_limit(100)
+        .set_write_batch_size(100)
+        .set_dictionary_enabled(false)
+        .set_compression(Compression::SNAPPY)
+        .build();
+
+    let mut output = Vec::new();
+    let mut writer = ArrowWriter::try_new(&mut output, input_batch.schema(), Some(props)).unwrap();
+    writer.write(&input_batch).unwrap();
+    writer.close().unwrap();
+    Bytes::from(output)
+});
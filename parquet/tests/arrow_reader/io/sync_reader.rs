@@ -219,6 +219,67 @@ fn test_read_limit() {
     "#);
 }
 
+#[test]
+fn test_read_offset_limit_no_page_index() {
+    // There are 400 total rows spread across 2 row groups of 2 data pages (100 rows each)
+    // an offset of 250 and limit of 25 should only read row group 1, even without the
+    // page index to skip its pages
+    let test_file = test_file();
+    let options = test_options().with_page_index_policy(PageIndexPolicy::from(false));
+    let builder = sync_builder(&test_file, options);
+    let schema_descr = builder.metadata().file_metadata().schema_descr_ptr();
+    let builder = builder
+        .with_projection(ProjectionMask::columns(&schema_descr, ["a"]))
+        .with_offset(250)
+        .with_limit(25);
+
+    insta::assert_debug_snapshot!(run(&test_file, builder),
+        @r#"
+    [
+        "Footer: 8 bytes",
+        "Metadata: 1162",
+        "Event: Builder Configured",
+        "Event: Reader Built",
+        "Row Group 1, column 'a': DictionaryPage   (17 bytes  , 17 requests) [header]",
+        "Row Group 1, column 'a': DictionaryPage   (1600 bytes, 1 requests) [data]",
+        "Row Group 1, column 'a': DataPage(0)      (20 bytes  , 20 requests) [header]",
+        "Row Group 1, column 'a': DataPage(0)      (93 bytes  , 1 requests) [data]",
+    ]
+    "#);
+}
+
+#[test]
+fn test_read_limit_row_filter() {
+    // filter "b" > 575 and < 625 matches rows in the last data page of row group 0,
+    // so a limit of 10 should stop evaluating the filter before row group 1
+    let test_file = test_file();
+    let builder = sync_builder(&test_file, test_options());
+    let schema_descr = builder.metadata().file_metadata().schema_descr_ptr();
+    let builder = builder
+        .with_projection(ProjectionMask::columns(&schema_descr, ["a", "b"]))
+        .with_row_filter(filter_b_575_625(&schema_descr))
+        .with_batch_size(100)
+        .with_limit(10);
+
+    insta::assert_debug_snapshot!(run(&test_file, builder),
+        @r#"
+    [
+        "Footer: 8 bytes",
+        "Metadata: 1162",
+        "UNKNOWN: 22230..22877 (maybe Page Index)",
+        "Event: Builder Configured",
+        "Row Group 0, column 'b': DictionaryPage   (1617 bytes, 1 requests) [data]",
+        "Row Group 0, column 'b': DataPage(0)      (113 bytes , 1 requests) [data]",
+        "Row Group 0, column 'b': DataPage(1)      (126 bytes , 1 requests) [data]",
+        "Event: Reader Built",
+        "Row Group 0, column 'a': DictionaryPage   (1617 bytes, 1 requests) [data]",
+        "Row Group 0, column 'a': DataPage(1)      (126 bytes , 1 requests) [data]",
+        "Row Group 0, column 'b': DictionaryPage   (1617 bytes, 1 requests) [data]",
+        "Row Group 0, column 'b': DataPage(1)      (126 bytes , 1 requests) [data]",
+    ]
+    "#);
+}
+
 #[test]
 fn test_read_single_row_filter() {
     // Values from column "b" range 400..799
@@ -237,7 +298,7 @@ fn test_read_single_row_filter() {
         .with_row_filter(filter_b_575_625(&schema_descr));
 
     // Expect to see I/O for column b in both row groups and then reading just a
-    // single pages for a in each row group
+    // single pages for a and b in each row group
     //
     // Note there is significant IO that happens during the construction of the
     // reader (between "Builder Configured" and "Reader Built")
@@ -267,6 +328,52 @@ fn test_read_single_row_filter() {
     "#);
 }
 
+#[test]
+fn test_read_single_row_filter_predicate_cache() {
+    // Values from column "b" range 400..799
+    // filter  "b" > 575 and < 625
+    // (last data page in Row Group 0 and first DataPage in Row Group 1)
+    let test_file = test_file();
+    let builder = sync_builder(&test_file, test_options());
+    let schema_descr = builder.metadata().file_metadata().schema_descr_ptr();
+
+    let builder = builder
+        .with_projection(
+            // read both "a" and "b"
+            ProjectionMask::columns(&schema_descr, ["a", "b"]),
+        )
+        // "b" > 575 and "b" < 625
+        .with_row_filter(filter_b_575_625(&schema_descr))
+        .with_max_predicate_cache_size(usize::MAX);
+
+    // Expect to see I/O for column b in both row groups and then reading just a
+    // single pages for a in each row group. Column b is not read again as it is
+    // in the predicate cache
+    //
+    // Note there is significant IO that happens during the construction of the
+    // reader (between "Builder Configured" and "Reader Built")
+    insta::assert_debug_snapshot!(run(&test_file, builder),
+        @r#"
+    [
+        "Footer: 8 bytes",
+        "Metadata: 1162",
+        "UNKNOWN: 22230..22877 (maybe Page Index)",
+        "Event: Builder Configured",
+        "Row Group 0, column 'b': DictionaryPage   (1617 bytes, 1 requests) [data]",
+        "Row Group 0, column 'b': DataPage(0)      (113 bytes , 1 requests) [data]",
+        "Row Group 0, column 'b': DataPage(1)      (126 bytes , 1 requests) [data]",
+        "Row Group 1, column 'b': DictionaryPage   (1617 bytes, 1 requests) [data]",
+        "Row Group 1, column 'b': DataPage(0)      (113 bytes , 1 requests) [data]",
+        "Row Group 1, column 'b': DataPage(1)      (126 bytes , 1 requests) [data]",
+        "Event: Reader Built",
+        "Row Group 0, column 'a': DictionaryPage   (1617 bytes, 1 requests) [data]",
+        "Row Group 0, column 'a': DataPage(1)      (126 bytes , 1 requests) [data]",
+        "Row Group 1, column 'a': DictionaryPage   (1617 bytes, 1 requests) [data]",
+        "Row Group 1, column 'a': DataPage(0)      (113 bytes , 1 requests) [data]",
+    ]
+    "#);
+}
+
 #[test]
 fn test_read_multiple_row_filter() {
     // Values in column "a" range 0..399
@@ -42,6 +42,8 @@ mod bad_data;
 mod bloom_filter;
 #[cfg(feature = "crc")]
 mod checksum;
+#[cfg(all(feature = "async", feature = "snap"))]
+mod column_metrics;
 mod int96_stats_roundtrip;
 mod invalid_utf8;
 mod io;
@@ -56,12 +56,17 @@ async fn test_async_cache_with_filters() {
 
 #[tokio::test]
 async fn test_sync_cache_with_filters() {
-    let test = ParquetPredicateCacheTest::new()
-        // The sync reader does not use the cache. See https://github.com/apache/arrow-rs/issues/8000
-        .with_expected_records_read_from_cache(0);
-
+    // The sync reader only uses the cache when it is given a size limit
+    let test = ParquetPredicateCacheTest::new().with_expected_records_read_from_cache(0);
     let sync_builder = test.sync_builder().add_project_ab_and_filter_b();
     test.run_sync(sync_builder);
+
+    let test = test.with_expected_records_read_from_cache(49);
+    let sync_builder = test
+        .sync_builder()
+        .with_max_predicate_cache_size(100 * 1024 * 1024)
+        .add_project_ab_and_filter_b();
+    test.run_sync(sync_builder);
 }
 
 #[tokio::test]
@@ -17,10 +17,11 @@
 
 use crate::io::TestReader;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 use arrow::{
     array::AsArray,
-    compute::{concat_batches, kernels::cmp::eq, or},
+    compute::{concat_batches, kernels::cmp::eq, like, or},
     datatypes::{Int32Type, TimestampNanosecondType},
 };
 use arrow_array::{
@@ -34,8 +35,8 @@ use parquet::{
     arrow::{
         ArrowWriter, ParquetRecordBatchStreamBuilder, ProjectionMask,
         arrow_reader::{
-            ArrowPredicateFn, ArrowReaderOptions, RowFilter, RowSelection, RowSelectionPolicy,
-            RowSelector,
+            ArrowPredicateFn, ArrowReaderOptions, DictionaryPredicateFn, RowFilter, RowSelection,
+            RowSelectionPolicy, RowSelector,
         },
     },
     file::{
@@ -634,3 +635,61 @@ async fn test_multi_predicate_mask_policy_carryover() {
     // Plus even-indexed rows in [200,250) with value<250 → rows 200,202,...,248 (25 rows)
     assert_eq!(batch.num_rows(), 75);
 }
+
+#[tokio::test]
+async fn test_dictionary_predicate() {
+    let id = Int32Array::from_iter_values(0..200);
+    let name = StringArray::from_iter_values((0..200).map(|i| format!("name{}", i % 20)));
+    let data = RecordBatch::try_from_iter([
+        ("id", Arc::new(id) as ArrayRef),
+        ("name", Arc::new(name) as ArrayRef),
+    ])
+    .unwrap();
+
+    let mut buf = Vec::new();
+    let props = WriterProperties::builder()
+        .set_max_row_group_row_count(Some(100))
+        .build();
+    let mut writer = ArrowWriter::try_new(&mut buf, data.schema(), Some(props)).unwrap();
+    writer.write(&data).unwrap();
+    writer.close().unwrap();
+    let data: Bytes = buf.into();
+
+    // read the name column as Utf8View
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("id", DataType::Int32, false),
+        Field::new("name", DataType::Utf8View, false),
+    ]));
+    let options = ArrowReaderOptions::new().with_schema(schema);
+    let builder = ParquetRecordBatchStreamBuilder::new_with_options(TestReader::new(data), options)
+        .await
+        .unwrap();
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let mask = ProjectionMask::leaves(builder.parquet_schema(), [1]);
+    let predicate = DictionaryPredicateFn::new(mask, {
+        let calls = Arc::clone(&calls);
+        move |values: &ArrayRef| {
+            calls.fetch_add(1, Ordering::Relaxed);
+            // view types are passed as dictionaries of Utf8
+            assert_eq!(values.data_type(), &DataType::Utf8);
+            // name LIKE 'name1%'
+            like(values, &StringArray::new_scalar("name1%"))
+        }
+    });
+
+    let stream = builder
+        .with_batch_size(30)
+        .with_row_filter(RowFilter::new(vec![Box::new(predicate)]))
+        .build()
+        .unwrap();
+    let batches: Vec<_> = stream.try_collect().await.unwrap();
+    let batch = concat_batches(&batches[0].schema(), &batches).unwrap();
+
+    assert_eq!(batch.column(1).data_type(), &DataType::Utf8View);
+    let expected: Vec<i32> = (0..200)
+        .filter(|i| format!("name{}", i % 20).starts_with("name1"))
+        .collect();
+    assert_eq!(batch.column(0).as_ref(), &Int32Array::from(expected));
+    assert_eq!(calls.load(Ordering::Relaxed), 2);
+}
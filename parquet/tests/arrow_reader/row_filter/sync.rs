@@ -16,20 +16,22 @@
 // under the License.
 
 use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 use arrow::{
     array::AsArray,
     compute::{concat_batches, kernels::cmp::eq, or},
 };
-use arrow_array::{ArrayRef, Int32Array, Int64Array, RecordBatch, RecordBatchReader};
+use arrow_array::{ArrayRef, Int32Array, Int64Array, RecordBatch, RecordBatchReader, StringArray};
 use arrow_schema::{DataType as ArrowDataType, Field, Schema};
 use bytes::Bytes;
 use parquet::{
     arrow::{
         ArrowWriter, ProjectionMask,
         arrow_reader::{
-            ArrowPredicateFn, ArrowReaderOptions, ParquetRecordBatchReaderBuilder, RowFilter,
-            RowSelection, RowSelectionPolicy, RowSelector,
+            ArrowPredicateFn, ArrowReaderOptions, DictionaryPredicateFn,
+            ParquetRecordBatchReaderBuilder, RowFilter, RowSelection, RowSelectionPolicy,
+            RowSelector,
         },
     },
     errors::Result,
@@ -419,3 +421,63 @@ fn test_list_struct_page_boundary_desync_produces_length_mismatch() {
     let total: usize = batches.iter().map(|b| b.num_rows()).sum();
     assert_eq!(total, 2, "selection should return exactly 2 rows");
 }
+
+#[test]
+fn test_dictionary_predicate() -> Result<()> {
+    let names = ["apple", "banana", "cherry"];
+    let id = Int32Array::from_iter_values(0..300);
+    let name: StringArray = (0..300)
+        .map(|i| (i % 4 != 3).then(|| names[i % 4]))
+        .collect();
+    let batch = RecordBatch::try_from_iter([
+        ("id", Arc::new(id) as ArrayRef),
+        ("name", Arc::new(name) as ArrayRef),
+    ])?;
+
+    let mut buffer = Vec::new();
+    let props = WriterProperties::builder()
+        .set_max_row_group_row_count(Some(100))
+        .build();
+    let mut writer = ArrowWriter::try_new(&mut buffer, batch.schema(), Some(props))?;
+    writer.write(&batch)?;
+    writer.close()?;
+    let data = Bytes::from(buffer);
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let builder = ParquetRecordBatchReaderBuilder::try_new(data)?;
+    let mask = ProjectionMask::leaves(builder.parquet_schema(), [1]);
+    let predicate = DictionaryPredicateFn::new(mask, {
+        let calls = Arc::clone(&calls);
+        move |values: &ArrayRef| {
+            calls.fetch_add(1, Ordering::Relaxed);
+            // name IN ('apple', 'cherry')
+            or(
+                &eq(values, &StringArray::new_scalar("apple"))?,
+                &eq(values, &StringArray::new_scalar("cherry"))?,
+            )
+        }
+    });
+
+    // batches spanning multiple row groups are not dictionary encoded
+    let reader = builder
+        .with_batch_size(50)
+        .with_row_filter(RowFilter::new(vec![Box::new(predicate)]))
+        .build()?;
+    let schema = reader.schema();
+    let batches = reader.collect::<std::result::Result<Vec<_>, _>>()?;
+    let batch = concat_batches(&schema, &batches)?;
+
+    // the output is not dictionary encoded
+    assert_eq!(schema.field(1).data_type(), &ArrowDataType::Utf8);
+    let expected_ids: Vec<i32> = (0..300).filter(|i| i % 4 == 0 || i % 4 == 2).collect();
+    assert_eq!(batch.column(0).as_ref(), &Int32Array::from(expected_ids));
+    let expected_names: StringArray = (0..300)
+        .filter(|i| i % 4 == 0 || i % 4 == 2)
+        .map(|i| Some(names[i % 4]))
+        .collect();
+    assert_eq!(batch.column(1).as_ref(), &expected_names);
+
+    // the predicate is evaluated once for the dictionary of each row group
+    assert_eq!(calls.load(Ordering::Relaxed), 3);
+    Ok(())
+}
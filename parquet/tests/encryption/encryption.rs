@@ -23,8 +23,8 @@ use crate::encryption_util::{
     AES_128_FOOTER_KEY_NAME, AES_128_KEY_NAME_KEY, AES_128_KEY_NAMES, AES_256_COLUMN_KEYS,
     AES_256_COLUMN_NAME_KEYS, AES_256_COLUMN_NAMES, AES_256_FOOTER_KEY, AES_256_FOOTER_KEY_NAME,
     AES_256_KEY_NAME_KEY, AES_256_KEY_NAMES, BAD_AES_128_FOOTER_KEY, BAD_AES_256_FOOTER_KEY,
-    TestKeyRetriever, read_and_roundtrip_to_encrypted_file, verify_column_indexes,
-    verify_encryption_test_file_read,
+    TestKeyGenerator, TestKeyRetriever, TestKmsClient, read_and_roundtrip_to_encrypted_file,
+    verify_column_indexes, verify_encryption_test_file_read,
 };
 use arrow::array::*;
 use arrow::error::Result as ArrowResult;
@@ -38,13 +38,16 @@ use parquet::arrow::arrow_reader::{
 use parquet::data_type::{ByteArray, ByteArrayType};
 use parquet::encryption::decrypt::FileDecryptionProperties;
 use parquet::encryption::encrypt::FileEncryptionProperties;
+use parquet::encryption::kms::{KmsKeyGenerator, KmsKeyRetriever};
 use parquet::errors::ParquetError;
+use parquet::file::column_crypto_metadata::ColumnCryptoMetaData;
 use parquet::file::metadata::{ColumnChunkMetaData, PageIndexPolicy, ParquetMetaData};
 use parquet::file::properties::WriterProperties;
 use parquet::file::writer::SerializedFileWriter;
 use parquet::schema::parser::parse_message_type;
 use std::fs::File;
 use std::sync::Arc;
+use std::sync::atomic::Ordering;
 
 #[test]
 fn test_non_uniform_encryption_plaintext_footer() {
@@ -1107,6 +1110,132 @@ fn write_and_read_stats(
     }
 }
 
+#[test]
+fn test_write_encryption_with_key_generator() {
+    let ids = Int32Array::from_iter_values(0..10);
+    let x = StringArray::from_iter_values((0..10).map(|i| format!("x{i}")));
+    let y = Float64Array::from_iter_values((0..10).map(|i| i as f64));
+    let batch = RecordBatch::try_from_iter([
+        ("id", Arc::new(ids) as ArrayRef),
+        ("x", Arc::new(x) as ArrayRef),
+        ("y", Arc::new(y) as ArrayRef),
+    ])
+    .unwrap();
+
+    for plaintext_footer in [false, true] {
+        let key_generator = Arc::new(TestKeyGenerator::new());
+        let encryption_properties =
+            FileEncryptionProperties::with_key_generator(Arc::clone(&key_generator) as _)
+                .with_encrypted_columns(["x", "y"])
+                .with_plaintext_footer(plaintext_footer)
+                .build()
+                .unwrap();
+        assert_eq!(
+            encryption_properties.footer_key_metadata(),
+            Some(&b"footer".to_vec())
+        );
+
+        let props = WriterProperties::builder()
+            .with_file_encryption_properties(encryption_properties)
+            .build();
+        let temp_file = tempfile::tempfile().unwrap();
+        let mut writer =
+            ArrowWriter::try_new(temp_file.try_clone().unwrap(), batch.schema(), Some(props))
+                .unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let decryption_properties =
+            FileDecryptionProperties::with_key_retriever(Arc::new(key_generator.key_retriever()))
+                .build()
+                .unwrap();
+        let options =
+            ArrowReaderOptions::default().with_file_decryption_properties(decryption_properties);
+        let builder =
+            ParquetRecordBatchReaderBuilder::try_new_with_options(temp_file, options).unwrap();
+
+        let columns = builder.metadata().row_group(0).columns();
+        let key_metadata: Vec<_> = columns
+            .iter()
+            .map(|c| match c.crypto_metadata() {
+                Some(ColumnCryptoMetaData::ENCRYPTION_WITH_COLUMN_KEY(key)) => {
+                    key.key_metadata.clone()
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            key_metadata,
+            vec![None, Some(b"column:x".to_vec()), Some(b"column:y".to_vec())]
+        );
+
+        let batches = builder
+            .build()
+            .unwrap()
+            .collect::<ArrowResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(batches, vec![batch.clone()]);
+    }
+}
+
+#[test]
+fn test_write_encryption_with_kms() {
+    let ids = Int32Array::from_iter_values(0..10);
+    let x = StringArray::from_iter_values((0..10).map(|i| format!("x{i}")));
+    let y = Float64Array::from_iter_values((0..10).map(|i| i as f64));
+    let batch = RecordBatch::try_from_iter([
+        ("id", Arc::new(ids) as ArrayRef),
+        ("x", Arc::new(x) as ArrayRef),
+        ("y", Arc::new(y) as ArrayRef),
+    ])
+    .unwrap();
+
+    for (plaintext_footer, double_wrapping) in [(false, true), (true, true), (false, false)] {
+        let kms = Arc::new(TestKmsClient::default());
+        let encryption_properties = KmsKeyGenerator::new(kms.clone(), "kf")
+            .with_column_master_key("x", "kc1")
+            .with_column_master_key("y", "kc1")
+            .with_double_wrapping(double_wrapping)
+            .encryption_properties_builder()
+            .with_plaintext_footer(plaintext_footer)
+            .with_aad_prefix(b"file".to_vec())
+            .with_aad_prefix_storage(true)
+            .build()
+            .unwrap();
+
+        let props = WriterProperties::builder()
+            .with_file_encryption_properties(encryption_properties)
+            .build();
+        let temp_file = tempfile::tempfile().unwrap();
+        let mut writer =
+            ArrowWriter::try_new(temp_file.try_clone().unwrap(), batch.schema(), Some(props))
+                .unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        kms.calls.store(0, Ordering::SeqCst);
+        let retriever = Arc::new(KmsKeyRetriever::new(kms.clone()));
+        let decryption_properties = FileDecryptionProperties::with_key_retriever(retriever)
+            .build()
+            .unwrap();
+        let options =
+            ArrowReaderOptions::default().with_file_decryption_properties(decryption_properties);
+        let builder =
+            ParquetRecordBatchReaderBuilder::try_new_with_options(temp_file, options).unwrap();
+        let batches = builder
+            .build()
+            .unwrap()
+            .collect::<ArrowResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(batches, vec![batch.clone()]);
+
+        // With double wrapping the KMS is only called once per master key
+        if double_wrapping {
+            assert_eq!(kms.calls.load(Ordering::SeqCst), 2);
+        }
+    }
+}
+
 #[test]
 fn test_write_uniform_encryption() {
     fn write_uniform_encryption(footer_key: &[u8]) {
@@ -17,18 +17,22 @@
 
 use arrow_array::cast::AsArray;
 use arrow_array::{RecordBatch, types};
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
 use parquet::arrow::ArrowWriter;
 use parquet::arrow::arrow_reader::{
     ArrowReaderMetadata, ArrowReaderOptions, ParquetRecordBatchReaderBuilder,
 };
 use parquet::encryption::decrypt::{FileDecryptionProperties, KeyRetriever};
-use parquet::encryption::encrypt::FileEncryptionProperties;
+use parquet::encryption::encrypt::{EncryptionKey, FileEncryptionProperties, KeyGenerator};
+use parquet::encryption::kms::KmsClient;
 use parquet::errors::{ParquetError, Result};
 use parquet::file::metadata::ParquetMetaData;
 use parquet::file::properties::WriterProperties;
 use ring::aead::AES_256_GCM;
 use std::collections::HashMap;
 use std::fs::File;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
 
 pub(crate) const AES_128_FOOTER_KEY: &[u8; 16] = b"0123456789012345"; // 128bit/16
@@ -387,6 +391,82 @@ impl KeyRetriever for TestKeyRetriever {
     }
 }
 
+/// A KeyGenerator to use in Parquet encryption tests, which generates random keys
+/// named after the column they encrypt, and can create a matching [`TestKeyRetriever`].
+pub struct TestKeyGenerator {
+    keys: Mutex<HashMap<String, Vec<u8>>>,
+}
+
+impl TestKeyGenerator {
+    pub fn new() -> Self {
+        Self {
+            keys: Mutex::new(HashMap::default()),
+        }
+    }
+
+    pub fn key_retriever(&self) -> TestKeyRetriever {
+        let keys = self.keys.lock().unwrap();
+        keys.iter()
+            .fold(TestKeyRetriever::new(), |retriever, (name, key)| {
+                retriever.with_key(name.clone(), key.clone())
+            })
+    }
+
+    fn generate_key(&self, key_name: String) -> Result<EncryptionKey> {
+        let key = EncryptionKey::random(16)?.with_metadata(key_name.clone().into_bytes());
+        let mut keys = self.keys.lock().unwrap();
+        keys.insert(key_name, key.key().clone());
+        Ok(key)
+    }
+}
+
+impl KeyGenerator for TestKeyGenerator {
+    fn generate_footer_key(&self) -> Result<EncryptionKey> {
+        self.generate_key("footer".to_string())
+    }
+
+    fn generate_column_key(&self, column_path: &str) -> Result<EncryptionKey> {
+        self.generate_key(format!("column:{column_path}"))
+    }
+}
+
+/// A KmsClient to use in Parquet encryption tests, which wraps keys by xoring them with
+/// the master key and counts its calls
+#[derive(Default)]
+pub struct TestKmsClient {
+    pub calls: AtomicUsize,
+}
+
+impl TestKmsClient {
+    fn master_key(master_key_identifier: &str) -> impl Iterator<Item = u8> + '_ {
+        master_key_identifier.bytes().cycle()
+    }
+}
+
+impl KmsClient for TestKmsClient {
+    fn wrap_key(&self, key: &[u8], master_key_identifier: &str) -> Result<String> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        let master_key = Self::master_key(master_key_identifier);
+        let wrapped: Vec<u8> = key.iter().zip(master_key).map(|(a, b)| a ^ b).collect();
+        Ok(format!(
+            "{master_key_identifier}:{}",
+            BASE64_STANDARD.encode(wrapped)
+        ))
+    }
+
+    fn unwrap_key(&self, wrapped_key: &str, master_key_identifier: &str) -> Result<Vec<u8>> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        let wrapped = wrapped_key
+            .strip_prefix(&format!("{master_key_identifier}:"))
+            .ok_or_else(|| ParquetError::General("Wrong master key".to_string()))?;
+        let wrapped = BASE64_STANDARD
+            .decode(wrapped)
+            .map_err(|e| ParquetError::General(e.to_string()))?;
+        let master_key = Self::master_key(master_key_identifier);
+        Ok(wrapped.iter().zip(master_key).map(|(a, b)| a ^ b).collect())
+    }
+}
+
 pub fn encrypted_data_path(footer_key: &[u8], file_name: &str) -> String {
     let test_data = arrow::util::test_util::parquet_test_data();
     let subpath = if AES_256_GCM.key_len() == footer_key.len() {
@@ -34,7 +34,7 @@ extern crate quote;
 
 extern crate parquet;
 
-use ::syn::{Data, DataStruct, DeriveInput, ext::IdentExt, parse_macro_input};
+use ::syn::{Data, DataEnum, DataStruct, DeriveInput, Fields, ext::IdentExt, parse_macro_input};
 
 mod parquet_field;
 
@@ -47,6 +47,17 @@ mod parquet_field;
 /// It is up to the programmer to keep the order of the struct
 /// fields lined up with the schema.
 ///
+/// Fields can also be:
+///
+/// - lists of primitive types from `Vec<T>` or `Option<Vec<T>>`, written
+///   with the standard 3-level `LIST` structure (`Vec<u8>` is a byte array)
+/// - structs deriving `ParquetRecordWriter`, marked with `#[parquet(nested)]`,
+///   written as a group, optionally in an `Option`
+/// - enums deriving [`ParquetRecordEnum`](derive.ParquetRecordEnum.html), marked with
+///   `#[parquet(enum)]`, written as `ENUM` byte arrays
+///
+/// Lists of nested structs are not supported.
+///
 /// Example:
 ///
 /// ```rust
@@ -125,7 +136,7 @@ mod parquet_field;
 /// }
 /// ```
 ///
-#[proc_macro_derive(ParquetRecordWriter)]
+#[proc_macro_derive(ParquetRecordWriter, attributes(parquet))]
 pub fn parquet_record_writer(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input: DeriveInput = parse_macro_input!(input as DeriveInput);
     let fields = match input.data {
@@ -136,12 +147,29 @@ pub fn parquet_record_writer(input: proc_macro::TokenStream) -> proc_macro::Toke
 
     let field_infos: Vec<_> = fields.iter().map(parquet_field::Field::from).collect();
 
-    let writer_snippets: Vec<proc_macro2::TokenStream> =
-        field_infos.iter().map(|x| x.writer_snippet()).collect();
+    let writer_snippets: Vec<proc_macro2::TokenStream> = field_infos
+        .iter()
+        .map(|x| x.record_writer_snippet())
+        .collect();
+    let nested_writer_snippets: Vec<proc_macro2::TokenStream> = field_infos
+        .iter()
+        .map(|x| x.nested_writer_snippet())
+        .collect();
 
     let derived_for = input.ident;
     let generics = input.generics;
 
+    // the fields written with levels are written as part of a group of
+    // records that are all present
+    let group_levels = field_infos.iter().any(|x| x.writer_uses_levels()).then(|| {
+        quote! {
+            let group_records: ::std::vec::Vec<Option<&#derived_for #generics>> =
+                records.iter().map(Some).collect();
+            let group_definition_levels: &[i16] = &vec![0; group_records.len()];
+            let group_definition_level = 0;
+        }
+    });
+
     let field_types: Vec<proc_macro2::TokenStream> =
         field_infos.iter().map(|x| x.parquet_type()).collect();
 
@@ -155,17 +183,10 @@ pub fn parquet_record_writer(input: proc_macro::TokenStream) -> proc_macro::Toke
 
         let mut row_group_writer = row_group_writer;
         let records = &self; // Used by all the writer snippets to be more clear
+        #group_levels
 
         #(
-          {
-              let mut some_column_writer = row_group_writer.next_column().unwrap();
-              if let Some(mut column_writer) = some_column_writer {
-                  #writer_snippets
-                  column_writer.close()?;
-              } else {
-                  return Err(::parquet::errors::ParquetError::General("Failed to get next column".into()))
-              }
-          }
+          #writer_snippets
         );*
 
         Ok(())
@@ -173,6 +194,33 @@ pub fn parquet_record_writer(input: proc_macro::TokenStream) -> proc_macro::Toke
 
       fn schema(&self) -> ::std::result::Result<::parquet::schema::types::TypePtr, ::parquet::errors::ParquetError> {
         use ::parquet::schema::types::Type as ParquetType;
+
+        let fields = <#derived_for #generics as ::parquet::record::NestedRecordWriter>::group_fields()?;
+        let group = ParquetType::group_type_builder("rust_schema")
+          .with_fields(fields)
+          .build()?;
+        Ok(group.into())
+      }
+    }
+
+    impl #generics ::parquet::record::NestedRecordWriter for #derived_for #generics {
+      fn write_to_columns<W: ::std::io::Write + Send>(
+        group_records: &[Option<&Self>],
+        group_definition_levels: &[i16],
+        group_definition_level: i16,
+        row_group_writer: &mut ::parquet::file::writer::SerializedRowGroupWriter<'_, W>
+      ) -> ::std::result::Result<(), ::parquet::errors::ParquetError> {
+        use ::parquet::column::writer::ColumnWriter;
+
+        #(
+          #nested_writer_snippets
+        );*
+
+        Ok(())
+      }
+
+      fn group_fields() -> ::std::result::Result<::std::vec::Vec<::parquet::schema::types::TypePtr>, ::parquet::errors::ParquetError> {
+        use ::parquet::schema::types::Type as ParquetType;
         use ::parquet::schema::types::TypePtr;
         use ::parquet::basic::LogicalType;
 
@@ -180,10 +228,7 @@ pub fn parquet_record_writer(input: proc_macro::TokenStream) -> proc_macro::Toke
         #(
           #field_types
         );*;
-        let group = ParquetType::group_type_builder("rust_schema")
-          .with_fields(fields)
-          .build()?;
-        Ok(group.into())
+        Ok(fields)
       }
     }
   }).into()
@@ -198,6 +243,11 @@ pub fn parquet_record_writer(input: proc_macro::TokenStream) -> proc_macro::Toke
 /// It is up to the programmer to ensure the names in the struct
 /// fields line up with the schema.
 ///
+/// The fields of `Option` types, lists, nested structs and enums written by
+/// [`ParquetRecordWriter`](derive.ParquetRecordWriter.html) are also supported,
+/// nested structs must derive `ParquetRecordReader`. These fields are looked up by
+/// their path in the schema.
+///
 /// Example:
 ///
 /// ```rust
@@ -223,7 +273,7 @@ pub fn parquet_record_writer(input: proc_macro::TokenStream) -> proc_macro::Toke
 /// }
 /// ```
 ///
-#[proc_macro_derive(ParquetRecordReader)]
+#[proc_macro_derive(ParquetRecordReader, attributes(parquet))]
 pub fn parquet_record_reader(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input: DeriveInput = parse_macro_input!(input as DeriveInput);
     let fields = match input.data {
@@ -234,24 +284,28 @@ pub fn parquet_record_reader(input: proc_macro::TokenStream) -> proc_macro::Toke
 
     let field_infos: Vec<_> = fields.iter().map(parquet_field::Field::from).collect();
     let field_names: Vec<_> = fields.iter().map(|f| f.ident.clone()).collect();
-    // unraw the identifiers, so raw identifiers like `r#type` are looked
-    // up by their column name `type` in the parquet file
-    let field_names_str: Vec<_> = fields
+    let reader_snippets: Vec<proc_macro2::TokenStream> = field_infos
         .iter()
-        .map(|f| {
-            f.ident
-                .as_ref()
-                .expect("Only structs with named fields are currently supported")
-                .unraw()
-                .to_string()
-        })
+        .map(|x| x.record_reader_snippet())
+        .collect();
+    let nested_reader_snippets: Vec<proc_macro2::TokenStream> = field_infos
+        .iter()
+        .map(|x| x.nested_reader_snippet())
         .collect();
-    let reader_snippets: Vec<proc_macro2::TokenStream> =
-        field_infos.iter().map(|x| x.reader_snippet()).collect();
 
     let derived_for = input.ident;
     let generics = input.generics;
 
+    // the fields read with levels are read as part of a group of
+    // records that are all present
+    let group_levels = field_infos.iter().any(|x| x.reader_uses_levels()).then(|| {
+        quote! {
+            let group_path: &[String] = &[];
+            let group_definition_level = 0;
+            let mut group_definition_levels = vec![0; num_records];
+        }
+    });
+
     (quote! {
 
     impl #generics ::parquet::record::RecordReader<#derived_for #generics> for Vec<#derived_for #generics> {
@@ -279,26 +333,116 @@ pub fn parquet_record_reader(input: proc_macro::TokenStream) -> proc_macro::Toke
         }
 
         let records = self; // Used by all the reader snippets to be more clear
+        #group_levels
 
         #(
-          {
-              let idx: usize = match name_to_index.get(#field_names_str) {
-                Some(&col_idx) => col_idx,
-                None => {
-                  let error_msg = format!("column name '{}' is not found in parquet file!", #field_names_str);
-                  return Err(::parquet::errors::ParquetError::General(error_msg));
-                }
-              };
-              if let Ok(column_reader) = row_group_reader.get_column_reader(idx) {
-                  #reader_snippets
-              } else {
-                  return Err(::parquet::errors::ParquetError::General("Failed to get next column".into()))
-              }
-          }
+          #reader_snippets
         );*
 
         Ok(())
       }
     }
+
+    impl #generics ::parquet::record::NestedRecordReader for #derived_for #generics {
+      fn read_from_columns(
+        row_group_reader: &dyn ::parquet::file::reader::RowGroupReader,
+        group_path: &[String],
+        group_definition_level: i16,
+        num_records: usize,
+      ) -> ::std::result::Result<(::std::vec::Vec<Self>, ::std::vec::Vec<i16>), ::parquet::errors::ParquetError> {
+        use ::parquet::column::reader::ColumnReader;
+
+        let mut records: ::std::vec::Vec<Self> = (0..num_records)
+          .map(|_| #derived_for {
+            #(
+              #field_names: Default::default()
+            ),*
+          })
+          .collect();
+        let mut group_definition_levels = vec![group_definition_level; num_records];
+
+        #(
+          #nested_reader_snippets
+        );*
+
+        Ok((records, group_definition_levels))
+      }
+    }
   }).into()
 }
+
+/// Derive RecordEnum implementations for fieldless enums.
+///
+/// Works by parsing an enum tagged with `#[derive(ParquetRecordEnum)]` and
+/// converting each variant to and from its name, so that the enum can be
+/// written and read as an `ENUM` byte array column by marking the fields of
+/// this type with `#[parquet(enum)]`. As the values repeat, these columns are
+/// dictionary encoded when dictionary encoding is enabled.
+///
+/// Example:
+///
+/// ```rust
+/// use parquet::record::RecordEnum;
+/// use parquet_derive::ParquetRecordEnum;
+///
+/// #[derive(Debug, Default, PartialEq, ParquetRecordEnum)]
+/// enum Level {
+///     #[default]
+///     Info,
+///     Warn,
+///     Error,
+/// }
+///
+/// assert_eq!(Level::Warn.name(), "Warn");
+/// assert_eq!(Level::from_name("Error"), Some(Level::Error));
+/// assert_eq!(Level::from_name("Debug"), None);
+/// ```
+///
+#[proc_macro_derive(ParquetRecordEnum)]
+pub fn parquet_record_enum(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input: DeriveInput = parse_macro_input!(input as DeriveInput);
+    let variants = match input.data {
+        Data::Enum(DataEnum { variants, .. }) => variants,
+        Data::Struct(_) => unimplemented!("Struct currently is not supported"),
+        Data::Union(_) => unimplemented!("Union currently is not supported"),
+    };
+
+    let variant_idents: Vec<_> = variants
+        .iter()
+        .map(|v| match v.fields {
+            Fields::Unit => v.ident.clone(),
+            _ => unimplemented!("Only fieldless enums are currently supported"),
+        })
+        .collect();
+    // unraw the identifiers, so a raw identifier like `r#None`
+    // is written as `None` in the parquet file
+    let variant_names: Vec<_> = variant_idents
+        .iter()
+        .map(|ident| ident.unraw().to_string())
+        .collect();
+
+    let derived_for = input.ident;
+    let generics = input.generics;
+
+    (quote! {
+      impl #generics ::parquet::record::RecordEnum for #derived_for #generics {
+        fn name(&self) -> &'static str {
+          match self {
+            #(
+              Self::#variant_idents => #variant_names,
+            )*
+          }
+        }
+
+        fn from_name(name: &str) -> Option<Self> {
+          match name {
+            #(
+              #variant_names => Some(Self::#variant_idents),
+            )*
+            _ => None,
+          }
+        }
+      }
+    })
+    .into()
+}
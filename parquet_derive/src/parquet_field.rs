@@ -23,6 +23,7 @@ pub struct Field {
     ty: Type,
     is_a_byte_buf: bool,
     third_party_type: Option<ThirdPartyType>,
+    user_defined_type: Option<UserDefinedType>,
 }
 
 /// Use third party libraries, detected
@@ -39,10 +40,48 @@ enum ThirdPartyType {
     Uuid,
 }
 
+/// User defined types, which cannot be detected at compile
+/// time and are marked with a `#[parquet(..)]` attribute.
+///
+///   Nested is a struct deriving the record writer or reader,
+///   written as a group with `#[parquet(nested)]`
+///   Enum is a fieldless enum deriving `ParquetRecordEnum`,
+///   written as an ENUM byte array with `#[parquet(enum)]`
+#[derive(Debug, PartialEq)]
+enum UserDefinedType {
+    Nested,
+    Enum,
+}
+
+impl UserDefinedType {
+    fn from_attributes(attrs: &[syn::Attribute]) -> Option<Self> {
+        let mut user_defined_type = None;
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("parquet")) {
+            let parsed = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("nested") {
+                    user_defined_type = Some(UserDefinedType::Nested);
+                    Ok(())
+                } else if meta.path.is_ident("enum") {
+                    user_defined_type = Some(UserDefinedType::Enum);
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `nested` or `enum`"))
+                }
+            });
+            if let Err(e) = parsed {
+                panic!("Invalid parquet attribute: {e}");
+            }
+        }
+        user_defined_type
+    }
+}
+
 impl Field {
     pub fn from(f: &syn::Field) -> Self {
         let ty = Type::from(f);
-        let is_a_byte_buf = ty.physical_type() == parquet::basic::Type::BYTE_ARRAY;
+        let user_defined_type = UserDefinedType::from_attributes(&f.attrs);
+        let is_a_byte_buf =
+            user_defined_type.is_none() && ty.physical_type() == parquet::basic::Type::BYTE_ARRAY;
 
         let third_party_type = match &ty.last_part()[..] {
             "NaiveDateTime" => Some(ThirdPartyType::ChronoNaiveDateTime),
@@ -59,6 +98,378 @@ impl Field {
             ty,
             is_a_byte_buf,
             third_party_type,
+            user_defined_type,
+        }
+    }
+
+    /// Whether the field is written with explicit definition levels by
+    /// [`Self::nested_writer_snippet`], as it is not a single flat column
+    pub fn writer_uses_levels(&self) -> bool {
+        self.user_defined_type == Some(UserDefinedType::Nested) || self.ty.list_element().is_some()
+    }
+
+    /// Whether the field is read with explicit definition levels by
+    /// [`Self::nested_reader_snippet`], as it is not a single required column
+    pub fn reader_uses_levels(&self) -> bool {
+        self.writer_uses_levels() || matches!(self.ty, Type::Option(_))
+    }
+
+    /// Takes the parsed field of the struct and emits the code writing
+    /// its columns to the `row_group_writer`.
+    ///
+    /// The fields written with levels expect `group_records`,
+    /// `group_definition_levels` and `group_definition_level` to be defined,
+    /// see [`Self::nested_writer_snippet`].
+    pub fn record_writer_snippet(&self) -> proc_macro2::TokenStream {
+        if self.writer_uses_levels() {
+            self.nested_writer_snippet()
+        } else {
+            next_column(self.writer_snippet())
+        }
+    }
+
+    /// Takes the parsed field of the struct and emits the code reading
+    /// its columns from the `row_group_reader`.
+    ///
+    /// The fields read with levels expect `group_path`, `group_definition_levels`
+    /// and `group_definition_level` to be defined, see [`Self::nested_reader_snippet`].
+    pub fn record_reader_snippet(&self) -> proc_macro2::TokenStream {
+        if self.reader_uses_levels() {
+            return self.nested_reader_snippet();
+        }
+
+        // unraw the identifier, so raw identifiers like `r#type` are looked
+        // up by their column name `type` in the parquet file
+        let field_name = self.ident.unraw().to_string();
+        let reader_snippet = self.reader_snippet();
+        quote! {
+            {
+                let idx: usize = match name_to_index.get(#field_name) {
+                  Some(&col_idx) => col_idx,
+                  None => {
+                    let error_msg = format!("column name '{}' is not found in parquet file!", #field_name);
+                    return Err(::parquet::errors::ParquetError::General(error_msg));
+                  }
+                };
+                if let Ok(column_reader) = row_group_reader.get_column_reader(idx) {
+                    #reader_snippet
+                } else {
+                    return Err(::parquet::errors::ParquetError::General("Failed to get next column".into()))
+                }
+            }
+        }
+    }
+
+    /// Takes the parsed field of a struct in a group and emits the code
+    /// writing its columns with definition and repetition levels.
+    ///
+    /// Relies on the variables:
+    ///
+    ///   `group_records`: the `Option<&Self>` records of the group, `None` if null
+    ///   `group_definition_levels`: the definition level of each null record
+    ///   `group_definition_level`: the definition level of the present records
+    ///
+    /// Supports the flat fields of [`Self::writer_snippet`], nested structs
+    /// marked with `#[parquet(nested)]` and optional, and lists of primitive
+    /// types from `Vec<T>`, optionally in an `Option`.
+    pub fn nested_writer_snippet(&self) -> proc_macro2::TokenStream {
+        let ident = &self.ident;
+        let is_optional = matches!(self.ty, Type::Option(_));
+
+        if self.user_defined_type == Some(UserDefinedType::Nested) {
+            let nested_type = self.nested_type();
+            let (definition_level, nested) = if is_optional {
+                (
+                    quote! { group_definition_level + 1 },
+                    quote! { rec.#ident.as_ref() },
+                )
+            } else {
+                (
+                    quote! { group_definition_level },
+                    quote! { Some(&rec.#ident) },
+                )
+            };
+
+            return quote! {
+                {
+                    let definition_level = #definition_level;
+                    let (nested_records, nested_definition_levels): (Vec<_>, Vec<_>) = group_records
+                        .iter()
+                        .zip(group_definition_levels)
+                        .map(|(rec, level)| match rec.map(|rec| #nested) {
+                            Some(Some(nested)) => (Some(nested), definition_level),
+                            Some(None) => (None, group_definition_level),
+                            None => (None, *level),
+                        })
+                        .unzip();
+                    <#nested_type as ::parquet::record::NestedRecordWriter>::write_to_columns(
+                        &nested_records,
+                        &nested_definition_levels,
+                        definition_level,
+                        row_group_writer,
+                    )?;
+                }
+            };
+        }
+
+        let column_writer = self.column_writer();
+
+        if self.ty.list_element().is_some() {
+            let value = self.write_value(quote! { (*element) });
+            let (list_level, list) = if is_optional {
+                (
+                    quote! { group_definition_level + 1 },
+                    quote! { rec.#ident.as_ref() },
+                )
+            } else {
+                (
+                    quote! { group_definition_level },
+                    quote! { Some(&rec.#ident) },
+                )
+            };
+
+            // an empty list is written as a single null element
+            return next_column(quote! {
+                {
+                    let list_level = #list_level;
+                    let mut vals = Vec::new();
+                    let mut definition_levels = Vec::new();
+                    let mut repetition_levels = Vec::new();
+                    for (rec, level) in group_records.iter().zip(group_definition_levels) {
+                        match rec.map(|rec| #list) {
+                            Some(Some(list)) if !list.is_empty() => {
+                                for (i, element) in list.iter().enumerate() {
+                                    vals.push(#value);
+                                    definition_levels.push(list_level + 1);
+                                    repetition_levels.push(if i == 0 { 0 } else { 1 });
+                                }
+                            }
+                            Some(Some(_)) => {
+                                definition_levels.push(list_level);
+                                repetition_levels.push(0);
+                            }
+                            Some(None) => {
+                                definition_levels.push(group_definition_level);
+                                repetition_levels.push(0);
+                            }
+                            None => {
+                                definition_levels.push(*level);
+                                repetition_levels.push(0);
+                            }
+                        }
+                    }
+
+                    if let #column_writer(typed) = column_writer.untyped() {
+                        typed.write_batch(&vals[..], Some(&definition_levels[..]), Some(&repetition_levels[..]))?;
+                    } else {
+                        panic!("Schema and struct disagree on type for {}", stringify!{#ident})
+                    }
+                }
+            });
+        }
+
+        let vals_builder = self.vals_builder();
+        let present_level = if self.definition_levels().is_some() {
+            quote! {
+                if rec.#ident.is_some() { group_definition_level + 1 } else { group_definition_level }
+            }
+        } else {
+            quote! { group_definition_level }
+        };
+
+        next_column(quote! {
+            {
+                let definition_levels: Vec<i16> = group_records
+                    .iter()
+                    .zip(group_definition_levels)
+                    .map(|(rec, level)| match rec {
+                        Some(rec) => #present_level,
+                        None => *level,
+                    })
+                    .collect();
+
+                let records: Vec<_> = group_records.iter().flatten().copied().collect();
+
+                #vals_builder
+
+                if let #column_writer(typed) = column_writer.untyped() {
+                    typed.write_batch(&vals[..], Some(&definition_levels[..]), None)?;
+                } else {
+                    panic!("Schema and struct disagree on type for {}", stringify!{#ident})
+                }
+            }
+        })
+    }
+
+    /// Takes the parsed field of a struct in a group and emits the code
+    /// reading its columns with definition and repetition levels into
+    /// the field of each of the `num_records` default `records`.
+    ///
+    /// Relies on the variables:
+    ///
+    ///   `group_path`: the path of the group in the parquet schema
+    ///   `group_definition_levels`: the definition level of each record, capped
+    ///   at `group_definition_level` and lowered for the null records
+    ///   `group_definition_level`: the definition level of the present records
+    ///
+    /// Supports the flat fields of [`Self::reader_snippet`], their `Option`,
+    /// nested structs marked with `#[parquet(nested)]` and lists of primitive
+    /// types from `Vec<T>`, optionally in an `Option`.
+    pub fn nested_reader_snippet(&self) -> proc_macro2::TokenStream {
+        let ident = &self.ident;
+        let field_name = self.ident.unraw().to_string();
+        let is_optional = matches!(self.ty, Type::Option(_));
+
+        if self.user_defined_type == Some(UserDefinedType::Nested) {
+            let nested_type = self.nested_type();
+            let (definition_level, assign) = if is_optional {
+                (
+                    quote! { group_definition_level + 1 },
+                    quote! {
+                        if level >= definition_level {
+                            r.#ident = Some(nested);
+                        }
+                    },
+                )
+            } else {
+                (
+                    quote! { group_definition_level },
+                    quote! { r.#ident = nested; },
+                )
+            };
+
+            return quote! {
+                {
+                    let mut column_path = group_path.to_vec();
+                    column_path.push(#field_name.to_string());
+                    let definition_level = #definition_level;
+                    let (nested_records, nested_definition_levels) =
+                        <#nested_type as ::parquet::record::NestedRecordReader>::read_from_columns(
+                            row_group_reader,
+                            &column_path,
+                            definition_level,
+                            num_records,
+                        )?;
+                    for (((r, group_level), nested), level) in records[..num_records]
+                        .iter_mut()
+                        .zip(group_definition_levels.iter_mut())
+                        .zip(nested_records)
+                        .zip(nested_definition_levels)
+                    {
+                        *group_level = (*group_level).min(level);
+                        #assign
+                    }
+                }
+            };
+        }
+
+        let column_reader = self.column_reader();
+        let column_index = column_index();
+
+        if let Some(element) = self.ty.list_element() {
+            let value = self.read_value(element);
+            let (list_level, start, push) = if is_optional {
+                (
+                    quote! { group_definition_level + 1 },
+                    quote! {
+                        if level >= list_level {
+                            records[num_read - 1].#ident = Some(Vec::new());
+                        }
+                    },
+                    quote! { records[num_read - 1].#ident.get_or_insert_with(Vec::new).push(#value) },
+                )
+            } else {
+                (
+                    quote! { group_definition_level },
+                    quote! {},
+                    quote! { records[num_read - 1].#ident.push(#value) },
+                )
+            };
+
+            return quote! {
+                {
+                    let mut column_path = group_path.to_vec();
+                    column_path.extend([#field_name.to_string(), "list".to_string(), "element".to_string()]);
+                    #column_index
+
+                    let mut vals = Vec::new();
+                    let mut definition_levels = Vec::new();
+                    let mut repetition_levels = Vec::new();
+                    if let #column_reader(mut typed) = row_group_reader.get_column_reader(idx)? {
+                        typed.read_records(
+                            num_records, Some(&mut definition_levels), Some(&mut repetition_levels), &mut vals)?;
+                    } else {
+                        panic!("Schema and struct disagree on type for {}", stringify!{#ident});
+                    }
+
+                    // a record starts at each repetition level of 0
+                    let list_level = #list_level;
+                    let mut i = 0;
+                    let mut num_read = 0;
+                    for (level, repetition_level) in definition_levels.into_iter().zip(repetition_levels) {
+                        if repetition_level == 0 {
+                            num_read += 1;
+                            let group_level = &mut group_definition_levels[num_read - 1];
+                            *group_level = (*group_level).min(level);
+                            #start
+                        }
+                        if level > list_level {
+                            #push;
+                            i += 1;
+                        }
+                    }
+                }
+            };
+        }
+
+        let value_type = match &self.ty {
+            Type::Option(ty) => ty.as_ref(),
+            ty => ty,
+        };
+        let value = self.read_value(value_type);
+        let assign = if is_optional {
+            quote! {
+                if level > group_definition_level {
+                    r.#ident = Some(#value);
+                    i += 1;
+                }
+            }
+        } else {
+            quote! {
+                if level >= group_definition_level {
+                    r.#ident = #value;
+                    i += 1;
+                }
+            }
+        };
+
+        quote! {
+            {
+                let mut column_path = group_path.to_vec();
+                column_path.push(#field_name.to_string());
+                #column_index
+
+                let mut vals = Vec::new();
+                let mut definition_levels = Vec::new();
+                if let #column_reader(mut typed) = row_group_reader.get_column_reader(idx)? {
+                    let (_, _, levels_read) = typed.read_records(
+                        num_records, Some(&mut definition_levels), None, &mut vals)?;
+                    // columns without optional ancestors have no definition levels
+                    definition_levels.resize(levels_read, group_definition_level);
+                } else {
+                    panic!("Schema and struct disagree on type for {}", stringify!{#ident});
+                }
+
+                let mut i = 0;
+                for ((r, group_level), level) in records[..num_records]
+                    .iter_mut()
+                    .zip(group_definition_levels.iter_mut())
+                    .zip(definition_levels)
+                {
+                    *group_level = (*group_level).min(level);
+                    #assign
+                }
+            }
         }
     }
 
@@ -81,110 +492,12 @@ impl Field {
     /// }
     ///
     /// because this parsing logic is not sophisticated enough for definition
-    /// levels beyond 2.
+    /// levels beyond 2, see [`Self::nested_writer_snippet`] instead.
     pub fn writer_snippet(&self) -> proc_macro2::TokenStream {
         let ident = &self.ident;
-        let column_writer = self.ty.column_writer();
-
-        let vals_builder = match &self.ty {
-            Type::TypePath(_) => self.copied_direct_vals(),
-            Type::Option(first_type) => match **first_type {
-                Type::TypePath(_) => self.option_into_vals(),
-                Type::Reference(_, ref second_type) => match **second_type {
-                    Type::TypePath(_) => self.option_into_vals(),
-                    _ => unimplemented!("Unsupported type encountered"),
-                },
-                Type::Vec(ref first_type) => match **first_type {
-                    Type::TypePath(_) => self.option_into_vals(),
-                    _ => unimplemented!("Unsupported type encountered"),
-                },
-                ref f => unimplemented!("Unsupported: {:#?}", f),
-            },
-            Type::Reference(_, first_type) => match **first_type {
-                Type::TypePath(_) => self.copied_direct_vals(),
-                Type::Option(ref second_type) => match **second_type {
-                    Type::TypePath(_) => self.option_into_vals(),
-                    Type::Reference(_, ref second_type) => match **second_type {
-                        Type::TypePath(_) => self.option_into_vals(),
-                        Type::Slice(ref second_type) => match **second_type {
-                            Type::TypePath(_) => self.option_into_vals(),
-                            ref f => unimplemented!("Unsupported: {:#?}", f),
-                        },
-                        _ => unimplemented!("Unsupported type encountered"),
-                    },
-                    Type::Vec(ref first_type) => match **first_type {
-                        Type::TypePath(_) => self.option_into_vals(),
-                        _ => unimplemented!("Unsupported type encountered"),
-                    },
-                    ref f => unimplemented!("Unsupported: {:#?}", f),
-                },
-                Type::Slice(ref second_type) => match **second_type {
-                    Type::TypePath(_) => self.copied_direct_vals(),
-                    ref f => unimplemented!("Unsupported: {:#?}", f),
-                },
-                ref f => unimplemented!("Unsupported: {:#?}", f),
-            },
-            Type::Vec(first_type) => match **first_type {
-                Type::TypePath(_) => self.copied_direct_vals(),
-                ref f => unimplemented!("Unsupported: {:#?}", f),
-            },
-            f => unimplemented!("Unsupported: {:#?}", f),
-        };
-
-        let definition_levels = match &self.ty {
-            Type::TypePath(_) => None,
-            Type::Option(first_type) => match **first_type {
-                Type::TypePath(_) => Some(self.optional_definition_levels()),
-                Type::Option(_) => unimplemented!("Unsupported nesting encountered"),
-                Type::Reference(_, ref second_type)
-                | Type::Vec(ref second_type)
-                | Type::Array(ref second_type, _)
-                | Type::Slice(ref second_type) => match **second_type {
-                    Type::TypePath(_) => Some(self.optional_definition_levels()),
-                    _ => unimplemented!("Unsupported nesting encountered"),
-                },
-            },
-            Type::Reference(_, first_type)
-            | Type::Vec(first_type)
-            | Type::Array(first_type, _)
-            | Type::Slice(first_type) => match **first_type {
-                Type::TypePath(_) => None,
-                Type::Vec(ref second_type)
-                | Type::Array(ref second_type, _)
-                | Type::Slice(ref second_type) => match **second_type {
-                    Type::TypePath(_) => None,
-                    Type::Reference(_, ref third_type) => match **third_type {
-                        Type::TypePath(_) => None,
-                        _ => unimplemented!("Unsupported definition encountered"),
-                    },
-                    _ => unimplemented!("Unsupported definition encountered"),
-                },
-                Type::Reference(_, ref second_type) | Type::Option(ref second_type) => {
-                    match **second_type {
-                        Type::TypePath(_) => Some(self.optional_definition_levels()),
-                        Type::Vec(ref third_type)
-                        | Type::Array(ref third_type, _)
-                        | Type::Slice(ref third_type) => match **third_type {
-                            Type::TypePath(_) => Some(self.optional_definition_levels()),
-                            Type::Reference(_, ref fourth_type) => match **fourth_type {
-                                Type::TypePath(_) => Some(self.optional_definition_levels()),
-                                _ => unimplemented!("Unsupported definition encountered"),
-                            },
-                            _ => unimplemented!("Unsupported definition encountered"),
-                        },
-                        Type::Reference(_, ref third_type) => match **third_type {
-                            Type::TypePath(_) => Some(self.optional_definition_levels()),
-                            Type::Slice(ref fourth_type) => match **fourth_type {
-                                Type::TypePath(_) => Some(self.optional_definition_levels()),
-                                _ => unimplemented!("Unsupported definition encountered"),
-                            },
-                            _ => unimplemented!("Unsupported definition encountered"),
-                        },
-                        _ => unimplemented!("Unsupported definition encountered"),
-                    }
-                }
-            },
-        };
+        let column_writer = self.column_writer();
+        let vals_builder = self.vals_builder();
+        let definition_levels = self.definition_levels();
 
         // "vals" is the run of primitive data being written for the column
         // "definition_levels" is a vector of bools which controls whether a value is missing or present
@@ -239,13 +552,13 @@ impl Field {
     /// }
     ///
     /// because this parsing logic is not sophisticated enough for definition
-    /// levels beyond 2.
+    /// levels beyond 2, see [`Self::nested_reader_snippet`] instead.
     ///
     /// `Option` types and references not supported, but the column itself can be nullable
     /// (i.e., def_level==1), as long as the values are all valid.
     pub fn reader_snippet(&self) -> proc_macro2::TokenStream {
         let ident = &self.ident;
-        let column_reader = self.ty.column_reader();
+        let column_reader = self.column_reader();
 
         // generate the code to read the column into a vector `vals`
         let write_batch_expr = quote! {
@@ -292,13 +605,56 @@ impl Field {
     }
 
     pub fn parquet_type(&self) -> proc_macro2::TokenStream {
-        // TODO: Support group types
         // TODO: Add length if dealing with fixedlenbinary
 
         // unraw the identifier, so a raw identifier like `r#type`
         // becomes a column named `type` in the parquet schema
         let field_name = self.ident.unraw().to_string();
-        let physical_type = match self.ty.physical_type() {
+        let repetition = self.ty.repetition();
+
+        if self.user_defined_type == Some(UserDefinedType::Nested) {
+            let nested_type = self.nested_type();
+            return quote! {
+                fields.push(ParquetType::group_type_builder(#field_name)
+                    .with_repetition(#repetition)
+                    .with_fields(<#nested_type as ::parquet::record::NestedRecordWriter>::group_fields()?)
+                    .build()?
+                    .into())
+            };
+        }
+
+        if self.ty.list_element().is_some() {
+            // lists are written with the standard 3-level structure
+            let element = self.primitive_type_builder(
+                "element",
+                quote! { ::parquet::basic::Repetition::REQUIRED },
+            );
+            return quote! {
+                fields.push(ParquetType::group_type_builder(#field_name)
+                    .with_logical_type(Some(LogicalType::List))
+                    .with_repetition(#repetition)
+                    .with_fields(vec![ParquetType::group_type_builder("list")
+                        .with_repetition(::parquet::basic::Repetition::REPEATED)
+                        .with_fields(vec![#element.build().unwrap().into()])
+                        .build()
+                        .unwrap()
+                        .into()])
+                    .build()
+                    .unwrap()
+                    .into())
+            };
+        }
+
+        let builder = self.primitive_type_builder(&field_name, repetition);
+        quote! {  fields.push(#builder.build().unwrap().into()) }
+    }
+
+    fn primitive_type_builder(
+        &self,
+        field_name: &str,
+        repetition: proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        let physical_type = match self.physical_type() {
             parquet::basic::Type::BOOLEAN => quote! {
                 ::parquet::basic::Type::BOOLEAN
             },
@@ -324,8 +680,10 @@ impl Field {
                 ::parquet::basic::Type::FIXED_LEN_BYTE_ARRAY
             },
         };
-        let logical_type = self.ty.logical_type();
-        let repetition = self.ty.repetition();
+        let logical_type = match self.user_defined_type {
+            Some(UserDefinedType::Enum) => quote! { Some(LogicalType::Enum) },
+            _ => self.ty.logical_type(),
+        };
         let converted_type = self.ty.converted_type();
         let length = self.ty.length();
 
@@ -343,7 +701,152 @@ impl Field {
             builder = quote! { #builder.with_length(#length) };
         }
 
-        quote! {  fields.push(#builder.build().unwrap().into()) }
+        builder
+    }
+
+    /// Generates code to read the values of the field from each record
+    /// into a vector `vals`
+    fn vals_builder(&self) -> proc_macro2::TokenStream {
+        match &self.ty {
+            Type::TypePath(_) => self.copied_direct_vals(),
+            Type::Option(first_type) => match **first_type {
+                Type::TypePath(_) => self.option_into_vals(),
+                Type::Reference(_, ref second_type) => match **second_type {
+                    Type::TypePath(_) => self.option_into_vals(),
+                    _ => unimplemented!("Unsupported type encountered"),
+                },
+                Type::Vec(ref first_type) => match **first_type {
+                    Type::TypePath(_) => self.option_into_vals(),
+                    _ => unimplemented!("Unsupported type encountered"),
+                },
+                ref f => unimplemented!("Unsupported: {:#?}", f),
+            },
+            Type::Reference(_, first_type) => match **first_type {
+                Type::TypePath(_) => self.copied_direct_vals(),
+                Type::Option(ref second_type) => match **second_type {
+                    Type::TypePath(_) => self.option_into_vals(),
+                    Type::Reference(_, ref second_type) => match **second_type {
+                        Type::TypePath(_) => self.option_into_vals(),
+                        Type::Slice(ref second_type) => match **second_type {
+                            Type::TypePath(_) => self.option_into_vals(),
+                            ref f => unimplemented!("Unsupported: {:#?}", f),
+                        },
+                        _ => unimplemented!("Unsupported type encountered"),
+                    },
+                    Type::Vec(ref first_type) => match **first_type {
+                        Type::TypePath(_) => self.option_into_vals(),
+                        _ => unimplemented!("Unsupported type encountered"),
+                    },
+                    ref f => unimplemented!("Unsupported: {:#?}", f),
+                },
+                Type::Slice(ref second_type) => match **second_type {
+                    Type::TypePath(_) => self.copied_direct_vals(),
+                    ref f => unimplemented!("Unsupported: {:#?}", f),
+                },
+                ref f => unimplemented!("Unsupported: {:#?}", f),
+            },
+            Type::Vec(first_type) => match **first_type {
+                Type::TypePath(_) => self.copied_direct_vals(),
+                ref f => unimplemented!("Unsupported: {:#?}", f),
+            },
+            f => unimplemented!("Unsupported: {:#?}", f),
+        }
+    }
+
+    /// Generates code to compute the `definition_levels` of an optional
+    /// field, or `None` for a required field
+    fn definition_levels(&self) -> Option<proc_macro2::TokenStream> {
+        match &self.ty {
+            Type::TypePath(_) => None,
+            Type::Option(first_type) => match **first_type {
+                Type::TypePath(_) => Some(self.optional_definition_levels()),
+                Type::Option(_) => unimplemented!("Unsupported nesting encountered"),
+                Type::Reference(_, ref second_type)
+                | Type::Vec(ref second_type)
+                | Type::Array(ref second_type, _)
+                | Type::Slice(ref second_type) => match **second_type {
+                    Type::TypePath(_) => Some(self.optional_definition_levels()),
+                    _ => unimplemented!("Unsupported nesting encountered"),
+                },
+            },
+            Type::Reference(_, first_type)
+            | Type::Vec(first_type)
+            | Type::Array(first_type, _)
+            | Type::Slice(first_type) => match **first_type {
+                Type::TypePath(_) => None,
+                Type::Vec(ref second_type)
+                | Type::Array(ref second_type, _)
+                | Type::Slice(ref second_type) => match **second_type {
+                    Type::TypePath(_) => None,
+                    Type::Reference(_, ref third_type) => match **third_type {
+                        Type::TypePath(_) => None,
+                        _ => unimplemented!("Unsupported definition encountered"),
+                    },
+                    _ => unimplemented!("Unsupported definition encountered"),
+                },
+                Type::Reference(_, ref second_type) | Type::Option(ref second_type) => {
+                    match **second_type {
+                        Type::TypePath(_) => Some(self.optional_definition_levels()),
+                        Type::Vec(ref third_type)
+                        | Type::Array(ref third_type, _)
+                        | Type::Slice(ref third_type) => match **third_type {
+                            Type::TypePath(_) => Some(self.optional_definition_levels()),
+                            Type::Reference(_, ref fourth_type) => match **fourth_type {
+                                Type::TypePath(_) => Some(self.optional_definition_levels()),
+                                _ => unimplemented!("Unsupported definition encountered"),
+                            },
+                            _ => unimplemented!("Unsupported definition encountered"),
+                        },
+                        Type::Reference(_, ref third_type) => match **third_type {
+                            Type::TypePath(_) => Some(self.optional_definition_levels()),
+                            Type::Slice(ref fourth_type) => match **fourth_type {
+                                Type::TypePath(_) => Some(self.optional_definition_levels()),
+                                _ => unimplemented!("Unsupported definition encountered"),
+                            },
+                            _ => unimplemented!("Unsupported definition encountered"),
+                        },
+                        _ => unimplemented!("Unsupported definition encountered"),
+                    }
+                }
+            },
+        }
+    }
+
+    /// The physical type of the field, see [`Type::physical_type`]
+    fn physical_type(&self) -> parquet::basic::Type {
+        match self.user_defined_type {
+            Some(UserDefinedType::Enum) => parquet::basic::Type::BYTE_ARRAY,
+            Some(UserDefinedType::Nested) => {
+                unimplemented!("Nested struct {} has no physical type", self.ident)
+            }
+            None => self.ty.physical_type(),
+        }
+    }
+
+    fn column_writer(&self) -> syn::TypePath {
+        match self.user_defined_type {
+            Some(UserDefinedType::Enum) => syn::parse_quote!(ColumnWriter::ByteArrayColumnWriter),
+            _ => self.ty.column_writer(),
+        }
+    }
+
+    fn column_reader(&self) -> syn::TypePath {
+        match self.user_defined_type {
+            Some(UserDefinedType::Enum) => syn::parse_quote!(ColumnReader::ByteArrayColumnReader),
+            _ => self.ty.column_reader(),
+        }
+    }
+
+    /// The struct type of a `#[parquet(nested)]` field
+    fn nested_type(&self) -> &syn::Type {
+        let ty = match &self.ty {
+            Type::Option(ty) => ty.as_ref(),
+            ty => ty,
+        };
+        match ty {
+            Type::TypePath(ty) => ty,
+            f => unimplemented!("Unsupported nested type: {:#?}", f),
+        }
     }
 
     fn option_into_vals(&self) -> proc_macro2::TokenStream {
@@ -353,7 +856,7 @@ impl Field {
         let is_a_date = self.third_party_type == Some(ThirdPartyType::ChronoNaiveDate);
         let is_a_uuid = self.third_party_type == Some(ThirdPartyType::Uuid);
         let copy_to_vec = !matches!(
-            self.ty.physical_type(),
+            self.physical_type(),
             parquet::basic::Type::BYTE_ARRAY | parquet::basic::Type::FIXED_LEN_BYTE_ARRAY
         );
 
@@ -363,7 +866,9 @@ impl Field {
             quote! { let Some(inner) = &rec.#field_name }
         };
 
-        let some = if is_a_timestamp {
+        let some = if self.user_defined_type == Some(UserDefinedType::Enum) {
+            quote! { Some(::parquet::record::RecordEnum::name(inner).into()) }
+        } else if is_a_timestamp {
             quote! { Some(inner.timestamp_millis()) }
         } else if is_a_date {
             quote! { Some(inner.signed_duration_since(::chrono::NaiveDate::from_ymd(1970, 1, 1)).num_days() as i32)  }
@@ -373,7 +878,7 @@ impl Field {
             quote! { Some((&inner[..]).into())}
         } else {
             // Type might need converting to a physical type
-            match self.ty.physical_type() {
+            match self.physical_type() {
                 parquet::basic::Type::INT32 => quote! { Some(inner as i32) },
                 parquet::basic::Type::INT64 => quote! { Some(inner as i64) },
                 _ => quote! { Some(inner) },
@@ -394,41 +899,73 @@ impl Field {
     // generates code to read `field_name` from each record into a vector `vals`
     fn copied_direct_vals(&self) -> proc_macro2::TokenStream {
         let field_name = &self.ident;
+        let access = self.write_value(quote! { rec.#field_name });
+
+        quote! {
+            let vals: Vec<_> = records.iter().map(|rec| #access).collect();
+        }
+    }
+
+    // generates code to convert `value` to the physical type of the column
+    fn write_value(&self, value: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        if self.user_defined_type == Some(UserDefinedType::Enum) {
+            return quote! { ::parquet::record::RecordEnum::name(&#value).into() };
+        }
 
-        let access = match self.third_party_type {
+        match self.third_party_type {
             Some(ThirdPartyType::ChronoNaiveDateTime) => {
-                quote! { rec.#field_name.timestamp_millis() }
+                quote! { #value.timestamp_millis() }
             }
             Some(ThirdPartyType::ChronoNaiveDate) => {
-                quote! { rec.#field_name.signed_duration_since(::chrono::NaiveDate::from_ymd(1970, 1, 1)).num_days() as i32 }
+                quote! { #value.signed_duration_since(::chrono::NaiveDate::from_ymd(1970, 1, 1)).num_days() as i32 }
             }
             Some(ThirdPartyType::Uuid) => {
-                quote! { rec.#field_name.as_bytes().to_vec().into() }
+                quote! { #value.as_bytes().to_vec().into() }
             }
             _ => {
                 if self.is_a_byte_buf {
-                    quote! { (&rec.#field_name[..]).into() }
+                    quote! { (&#value[..]).into() }
                 } else {
                     // Type might need converting to a physical type
-                    match self.ty.physical_type() {
-                        parquet::basic::Type::INT32 => quote! { rec.#field_name as i32 },
-                        parquet::basic::Type::INT64 => quote! { rec.#field_name as i64 },
-                        _ => quote! { rec.#field_name },
+                    match self.physical_type() {
+                        parquet::basic::Type::INT32 => quote! { #value as i32 },
+                        parquet::basic::Type::INT64 => quote! { #value as i64 },
+                        _ => quote! { #value },
                     }
                 }
             }
-        };
-
-        quote! {
-            let vals: Vec<_> = records.iter().map(|rec| #access).collect();
         }
     }
 
     // generates code to read a vector `records` into `field_name` for each record
     fn copied_direct_fields(&self) -> proc_macro2::TokenStream {
         let field_name = &self.ident;
+        let value = self.read_value(&self.ty);
 
-        let value = match self.third_party_type {
+        quote! {
+            for (i, r) in &mut records[..num_records].iter_mut().enumerate() {
+                r.#field_name = #value;
+            }
+        }
+    }
+
+    // generates code to convert `vals[i]` to the rust type `ty`
+    fn read_value(&self, ty: &Type) -> proc_macro2::TokenStream {
+        if self.user_defined_type == Some(UserDefinedType::Enum) {
+            let enum_type = ty.inner_type();
+            return quote! {
+                {
+                    let name = std::str::from_utf8(vals[i].data()).expect("invalid UTF-8 sequence");
+                    <#enum_type as ::parquet::record::RecordEnum>::from_name(name).ok_or_else(|| {
+                        ::parquet::errors::ParquetError::General(
+                            format!("Unknown variant '{}' of {}", name, stringify!{#enum_type})
+                        )
+                    })?
+                }
+            };
+        }
+
+        match self.third_party_type {
             Some(ThirdPartyType::ChronoNaiveDateTime) => {
                 quote! { ::chrono::naive::NaiveDateTime::from_timestamp_millis(vals[i]).unwrap() }
             }
@@ -441,8 +978,8 @@ impl Field {
             Some(ThirdPartyType::Uuid) => {
                 quote! { ::uuid::Uuid::from_bytes(vals[i].data().try_into().unwrap()) }
             }
-            _ => match &self.ty {
-                Type::TypePath(_) => match self.ty.last_part().as_str() {
+            _ => match ty {
+                Type::TypePath(_) => match ty.last_part().as_str() {
                     "String" => quote! { String::from(std::str::from_utf8(vals[i].data())
                     .expect("invalid UTF-8 sequence")) },
                     t => {
@@ -453,12 +990,6 @@ impl Field {
                 Type::Vec(_) => quote! { vals[i].data().to_vec() },
                 f => unimplemented!("Unsupported: {:#?}", f),
             },
-        };
-
-        quote! {
-            for (i, r) in &mut records[..num_records].iter_mut().enumerate() {
-                r.#field_name = #value;
-            }
         }
     }
 
@@ -474,6 +1005,40 @@ impl Field {
     }
 }
 
+/// Wraps the code writing a column with `column_writer` in the code
+/// getting the next column of the `row_group_writer`
+fn next_column(write_column: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    quote! {
+        {
+            let mut some_column_writer = row_group_writer.next_column().unwrap();
+            if let Some(mut column_writer) = some_column_writer {
+                #write_column
+                column_writer.close()?;
+            } else {
+                return Err(::parquet::errors::ParquetError::General("Failed to get next column".into()))
+            }
+        }
+    }
+}
+
+/// Emits the code looking up the index `idx` of the leaf column at `column_path`
+fn column_index() -> proc_macro2::TokenStream {
+    quote! {
+        let idx = row_group_reader
+            .metadata()
+            .schema_descr()
+            .columns()
+            .iter()
+            .position(|col| col.path().parts() == column_path.as_slice())
+            .ok_or_else(|| {
+                ::parquet::errors::ParquetError::General(format!(
+                    "column '{}' is not found in parquet file!",
+                    column_path.join(".")
+                ))
+            })?;
+    }
+}
+
 #[allow(clippy::enum_variant_names)]
 #[allow(clippy::large_enum_variant)]
 #[derive(Debug, PartialEq)]
@@ -533,6 +1098,26 @@ impl Type {
         }
     }
 
+    /// Returns the element type of a list, written as a repeated column
+    ///
+    /// Ex:
+    ///   `Vec<i32>` => Some(i32)
+    ///   `Option<Vec<String>>` => Some(String)
+    ///   `Vec<u8>` => None, as it is written as a byte array
+    fn list_element(&self) -> Option<&Type> {
+        let list = match self {
+            Type::Option(ty) => ty.as_ref(),
+            ty => ty,
+        };
+        match list {
+            Type::Vec(element) => match element.as_ref() {
+                Type::TypePath(_) if element.last_part() == "u8" => None,
+                element => Some(element),
+            },
+            _ => None,
+        }
+    }
+
     /// Helper to simplify a nested field definition to its leaf type
     ///
     /// Ex:
@@ -1060,18 +1645,21 @@ mod test {
                     ty: Type::TypePath(syn::parse_quote!(bool)),
                     is_a_byte_buf: false,
                     third_party_type: None,
+                    user_defined_type: None,
                 },
                 Field {
                     ident: syn::Ident::new("name", proc_macro2::Span::call_site()),
                     ty: Type::TypePath(syn::parse_quote!(String)),
                     is_a_byte_buf: true,
                     third_party_type: None,
+                    user_defined_type: None,
                 },
                 Field {
                     ident: syn::Ident::new("length", proc_macro2::Span::call_site()),
                     ty: Type::TypePath(syn::parse_quote!(usize)),
                     is_a_byte_buf: false,
                     third_party_type: None,
+                    user_defined_type: None,
                 }
             ]
         )
@@ -1486,4 +2074,79 @@ mod test {
             quote! { ::parquet::basic::ConvertedType::TIMESTAMP_MILLIS }.to_string()
         );
     }
+
+    #[test]
+    fn test_list_element() {
+        let snippet: proc_macro2::TokenStream = quote! {
+          struct AListStruct {
+            a_list: Vec<i32>,
+            maybe_a_list: Option<Vec<String>>,
+            byte_vec: Vec<u8>,
+            maybe_byte_vec: Option<Vec<u8>>,
+            a_list_of_byte_vecs: Vec<Vec<u8>>,
+          }
+        };
+
+        let fields = extract_fields(snippet);
+        let elements: Vec<_> = fields
+            .iter()
+            .map(|field| Type::from(field).list_element().map(|e| e.last_part()))
+            .collect();
+        assert_eq!(
+            elements,
+            vec![
+                Some("i32".to_string()),
+                Some("String".to_string()),
+                None,
+                None,
+                Some("u8".to_string()),
+            ]
+        );
+
+        let a_list = Field::from(&fields[0]);
+        assert!(a_list.writer_uses_levels());
+        let byte_vec = Field::from(&fields[2]);
+        assert!(!byte_vec.writer_uses_levels());
+        let maybe_byte_vec = Field::from(&fields[3]);
+        assert!(!maybe_byte_vec.writer_uses_levels());
+        assert!(maybe_byte_vec.reader_uses_levels());
+    }
+
+    #[test]
+    fn test_user_defined_type_attributes() {
+        let snippet: proc_macro2::TokenStream = quote! {
+          struct AUserDefinedStruct {
+            #[parquet(nested)]
+            nested: Inner,
+            #[parquet(enum)]
+            maybe_level: Option<Level>,
+            plain: i32,
+          }
+        };
+
+        let fields = extract_fields(snippet);
+        let processed: Vec<_> = fields.iter().map(Field::from).collect();
+        assert_eq!(
+            processed
+                .iter()
+                .map(|field| &field.user_defined_type)
+                .collect::<Vec<_>>(),
+            vec![
+                &Some(UserDefinedType::Nested),
+                &Some(UserDefinedType::Enum),
+                &None
+            ]
+        );
+
+        let snippet = processed[0].parquet_type().to_string();
+        assert!(
+            snippet.contains("group_type_builder (\"nested\")"),
+            "{snippet}"
+        );
+        assert!(snippet.contains("NestedRecordWriter"), "{snippet}");
+
+        let snippet = processed[1].parquet_type().to_string();
+        assert!(snippet.contains("LogicalType :: Enum"), "{snippet}");
+        assert!(snippet.contains("BYTE_ARRAY"), "{snippet}");
+    }
 }
@@ -22,7 +22,7 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![allow(clippy::approx_constant)]
 
-use parquet_derive::{ParquetRecordReader, ParquetRecordWriter};
+use parquet_derive::{ParquetRecordEnum, ParquetRecordReader, ParquetRecordWriter};
 use std::sync::Arc;
 
 #[derive(ParquetRecordWriter)]
@@ -118,6 +118,50 @@ struct ARecordWithRawIdentifiers {
     pub count: i32,
 }
 
+// This enum is written as an ENUM column with the names of its variants
+#[derive(PartialEq, ParquetRecordEnum, Debug, Default, Clone, Copy)]
+enum ALogLevel {
+    #[default]
+    Info,
+    Warn,
+    Error,
+}
+
+#[derive(PartialEq, ParquetRecordWriter, ParquetRecordReader, Debug, Default, Clone)]
+struct AClient {
+    pub ip: String,
+    pub port: u16,
+}
+
+#[derive(PartialEq, ParquetRecordWriter, ParquetRecordReader, Debug, Default, Clone)]
+struct ARequest {
+    pub method: String,
+    pub status: Option<i32>,
+    pub tags: Vec<String>,
+    #[parquet(nested)]
+    pub client: Option<AClient>,
+}
+
+// This struct has nested structs, lists and enums,
+// written as groups, repeated and dictionary encoded columns
+#[derive(PartialEq, ParquetRecordWriter, ParquetRecordReader, Debug)]
+struct ANestedRecord {
+    pub timestamp: i64,
+    #[parquet(enum)]
+    pub level: ALogLevel,
+    #[parquet(enum)]
+    pub maybe_level: Option<ALogLevel>,
+    pub message: Option<String>,
+    pub scores: Vec<i32>,
+    pub maybe_labels: Option<Vec<String>>,
+    #[parquet(enum)]
+    pub levels: Vec<ALogLevel>,
+    #[parquet(nested)]
+    pub request: ARequest,
+    #[parquet(nested)]
+    pub maybe_request: Option<ARequest>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -405,6 +449,183 @@ mod tests {
         assert_eq!(drs, out);
     }
 
+    #[test]
+    fn test_parquet_derive_nested_round_trip() {
+        let file = get_temp_file("test_parquet_derive_nested_round_trip", &[]);
+
+        let schema_str = "message rust_schema {
+            REQUIRED INT64 timestamp;
+            REQUIRED BINARY level (ENUM);
+            OPTIONAL BINARY maybe_level (ENUM);
+            OPTIONAL BINARY message (STRING);
+            REQUIRED group scores (LIST) {
+                REPEATED group list {
+                    REQUIRED INT32 element;
+                }
+            }
+            OPTIONAL group maybe_labels (LIST) {
+                REPEATED group list {
+                    REQUIRED BINARY element (STRING);
+                }
+            }
+            REQUIRED group levels (LIST) {
+                REPEATED group list {
+                    REQUIRED BINARY element (ENUM);
+                }
+            }
+            REQUIRED group request {
+                REQUIRED BINARY method (STRING);
+                OPTIONAL INT32 status;
+                REQUIRED group tags (LIST) {
+                    REPEATED group list {
+                        REQUIRED BINARY element (STRING);
+                    }
+                }
+                OPTIONAL group client {
+                    REQUIRED BINARY ip (STRING);
+                    REQUIRED INT32 port (INTEGER(16,false));
+                }
+            }
+            OPTIONAL group maybe_request {
+                REQUIRED BINARY method (STRING);
+                OPTIONAL INT32 status;
+                REQUIRED group tags (LIST) {
+                    REPEATED group list {
+                        REQUIRED BINARY element (STRING);
+                    }
+                }
+                OPTIONAL group client {
+                    REQUIRED BINARY ip (STRING);
+                    REQUIRED INT32 port (INTEGER(16,false));
+                }
+            }
+        }";
+        let schema = Arc::new(parse_message_type(schema_str).unwrap());
+
+        let request = ARequest {
+            method: "GET".into(),
+            status: Some(200),
+            tags: vec!["a".into(), "b".into()],
+            client: Some(AClient {
+                ip: "10.0.0.1".into(),
+                port: 8080,
+            }),
+        };
+        let drs = vec![
+            ANestedRecord {
+                timestamp: 1,
+                level: ALogLevel::Info,
+                maybe_level: Some(ALogLevel::Warn),
+                message: Some("first".into()),
+                scores: vec![1, 2, 3],
+                maybe_labels: Some(vec!["x".into()]),
+                levels: vec![ALogLevel::Error, ALogLevel::Info],
+                request: request.clone(),
+                maybe_request: Some(request.clone()),
+            },
+            ANestedRecord {
+                timestamp: 2,
+                level: ALogLevel::Error,
+                maybe_level: None,
+                message: None,
+                scores: vec![],
+                maybe_labels: None,
+                levels: vec![],
+                request: ARequest {
+                    method: "POST".into(),
+                    status: None,
+                    tags: vec![],
+                    client: None,
+                },
+                maybe_request: None,
+            },
+            ANestedRecord {
+                timestamp: 3,
+                level: ALogLevel::Warn,
+                maybe_level: Some(ALogLevel::Warn),
+                message: Some("third".into()),
+                scores: vec![4],
+                maybe_labels: Some(vec![]),
+                levels: vec![ALogLevel::Warn],
+                request,
+                maybe_request: Some(ARequest {
+                    method: "PUT".into(),
+                    status: Some(404),
+                    tags: vec!["c".into()],
+                    client: None,
+                }),
+            },
+        ];
+
+        let generated_schema = drs.as_slice().schema().unwrap();
+        assert_eq!(&schema, &generated_schema);
+
+        let props = Default::default();
+        let mut writer =
+            SerializedFileWriter::new(file.try_clone().unwrap(), generated_schema, props).unwrap();
+
+        let mut row_group = writer.next_row_group().unwrap();
+        drs.as_slice().write_to_row_group(&mut row_group).unwrap();
+        row_group.close().unwrap();
+        writer.close().unwrap();
+
+        use parquet::basic::Encoding;
+        use parquet::file::{reader::FileReader, serialized_reader::SerializedFileReader};
+        let reader = SerializedFileReader::new(file).unwrap();
+
+        // the enum columns are dictionary encoded
+        let row_group_metadata = reader.metadata().row_group(0);
+        for column in row_group_metadata.columns() {
+            if column.column_path().parts().last().unwrap() == "level" {
+                assert!(column.encodings().any(|e| e == Encoding::RLE_DICTIONARY));
+            }
+        }
+
+        let mut out: Vec<ANestedRecord> = Vec::new();
+        let mut row_group = reader.get_row_group(0).unwrap();
+        out.read_from_row_group(&mut *row_group, 3).unwrap();
+
+        assert_eq!(drs, out);
+    }
+
+    #[test]
+    fn test_parquet_derive_unknown_enum_variant() {
+        let file = get_temp_file("test_parquet_derive_unknown_enum_variant", &[]);
+
+        #[derive(ParquetRecordWriter)]
+        struct AStringRecord {
+            pub level: String,
+        }
+
+        #[derive(ParquetRecordReader)]
+        struct AnEnumRecord {
+            #[parquet(enum)]
+            pub level: ALogLevel,
+        }
+
+        let drs = vec![AStringRecord {
+            level: "Debug".into(),
+        }];
+        let generated_schema = drs.as_slice().schema().unwrap();
+        let props = Default::default();
+        let mut writer =
+            SerializedFileWriter::new(file.try_clone().unwrap(), generated_schema, props).unwrap();
+        let mut row_group = writer.next_row_group().unwrap();
+        drs.as_slice().write_to_row_group(&mut row_group).unwrap();
+        row_group.close().unwrap();
+        writer.close().unwrap();
+
+        use parquet::file::{reader::FileReader, serialized_reader::SerializedFileReader};
+        let reader = SerializedFileReader::new(file).unwrap();
+        let mut out: Vec<AnEnumRecord> = Vec::new();
+        let mut row_group = reader.get_row_group(0).unwrap();
+        let err = out.read_from_row_group(&mut *row_group, 1).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Unknown variant 'Debug' of ALogLevel"
+        );
+    }
+
     #[test]
     fn test_aliased_result() {
         // Issue 7547, Where aliasing the `Result` led to